@@ -7,7 +7,7 @@ use iggy::clients::client::{IggyClient, IggyClientConfig};
 use iggy::consumer::Consumer;
 use iggy::error::Error;
 use iggy::identifier::Identifier;
-use iggy::messages::poll_messages::{PollMessages, PollingStrategy};
+use iggy::messages::poll_messages::{IsolationLevel, PollMessages, PollingStrategy};
 use std::sync::Arc;
 use std::time::Duration;
 use tokio::time::Instant;
@@ -38,6 +38,10 @@ pub async fn run(
         strategy: PollingStrategy::offset(0),
         count: args.messages_per_batch,
         auto_commit: false,
+        include_all_states: false,
+        max_wait_ms: None,
+        isolation_level: IsolationLevel::default(),
+        filters: vec![],
     };
 
     let mut latencies: Vec<Duration> = Vec::with_capacity(args.message_batches as usize);
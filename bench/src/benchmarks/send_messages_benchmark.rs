@@ -36,6 +36,8 @@ pub async fn run(
         stream_id: Identifier::numeric(stream_id)?,
         topic_id: Identifier::numeric(topic_id)?,
         partitioning: Partitioning::partition_id(partition_id),
+        producer: None,
+        transaction_id: None,
         messages,
     };
 
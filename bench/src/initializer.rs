@@ -2,6 +2,7 @@ use crate::args::Args;
 use crate::client_factory::ClientFactory;
 use iggy::error::Error;
 use iggy::identifier::Identifier;
+use iggy::models::cleanup_policy::CleanupPolicy;
 use iggy::streams::create_stream::CreateStream;
 use iggy::streams::get_streams::GetStreams;
 use iggy::topics::create_topic::CreateTopic;
@@ -23,7 +24,11 @@ pub async fn init_streams(
             info!("Creating the test stream {}", stream_id);
             let name = format!("stream {}", stream_id);
             client
-                .create_stream(&CreateStream { stream_id, name })
+                .create_stream(&CreateStream {
+                    stream_id,
+                    name,
+                    encryption: None,
+                })
                 .await?;
 
             info!(
@@ -38,6 +43,11 @@ pub async fn init_streams(
                     partitions_count,
                     name,
                     message_expiry: None,
+                    cleanup_policy: CleanupPolicy::Delete,
+                    compaction_key: None,
+                    max_topic_size_bytes: None,
+                    max_messages: None,
+                    dead_letter_topic_id: None,
                 })
                 .await?;
         }
@@ -1,5 +1,6 @@
 use crate::{
-    consumer_groups, consumer_offsets, messages, partitions, streams, system, topics, users,
+    consumer_groups, consumer_offsets, messages, partitions, personal_access_tokens, roles,
+    streams, system, topics, transactions, users,
 };
 use iggy::client_error::ClientError;
 use iggy::clients::client::IggyClient;
@@ -17,15 +18,42 @@ pub async fn handle(input: &str, client: &IggyClient) -> Result<(), ClientError>
         Command::GetMe(payload) => system::get_me(&payload, client).await,
         Command::GetClient(payload) => system::get_client(&payload, client).await,
         Command::GetClients(payload) => system::get_clients(&payload, client).await,
+        Command::GetSessions(payload) => system::get_sessions(&payload, client).await,
+        Command::RevokeSession(payload) => system::revoke_session(&payload, client).await,
         Command::GetUser(payload) => users::get_user(&payload, client).await,
         Command::GetUsers(payload) => users::get_users(&payload, client).await,
         Command::CreateUser(payload) => users::create_user(&payload, client).await,
         Command::DeleteUser(payload) => users::delete_user(&payload, client).await,
+        Command::UnlockUser(payload) => users::unlock_user(&payload, client).await,
         Command::UpdateUser(payload) => users::update_user(&payload, client).await,
         Command::UpdatePermissions(payload) => users::update_permissions(&payload, client).await,
+        Command::UpdateQuotas(payload) => users::update_quotas(&payload, client).await,
         Command::ChangePassword(payload) => users::change_password(&payload, client).await,
         Command::LoginUser(payload) => users::login_user(&payload, client).await,
         Command::LogoutUser(payload) => users::logout_user(&payload, client).await,
+        // The SCRAM handshake is performed by the client as a part of the user login.
+        Command::ScramLoginStart(_) | Command::ScramLoginFinish(_) => {
+            Err(ClientError::InvalidCommand)
+        }
+        Command::GetPersonalAccessTokens(payload) => {
+            personal_access_tokens::get_personal_access_tokens(&payload, client).await
+        }
+        Command::CreatePersonalAccessToken(payload) => {
+            personal_access_tokens::create_personal_access_token(&payload, client).await
+        }
+        Command::DeletePersonalAccessToken(payload) => {
+            personal_access_tokens::delete_personal_access_token(&payload, client).await
+        }
+        Command::LoginWithToken(payload) => {
+            personal_access_tokens::login_with_token(&payload, client).await
+        }
+        Command::GetRole(payload) => roles::get_role(&payload, client).await,
+        Command::GetRoles(payload) => roles::get_roles(&payload, client).await,
+        Command::CreateRole(payload) => roles::create_role(&payload, client).await,
+        Command::DeleteRole(payload) => roles::delete_role(&payload, client).await,
+        Command::UpdateRole(payload) => roles::update_role(&payload, client).await,
+        Command::AddRoleMember(payload) => roles::add_role_member(&payload, client).await,
+        Command::RemoveRoleMember(payload) => roles::remove_role_member(&payload, client).await,
         Command::SendMessages(mut payload) => messages::send_messages(&mut payload, client).await,
         Command::PollMessages(payload) => {
             let format = match input.split('|').last() {
@@ -39,12 +67,23 @@ pub async fn handle(input: &str, client: &IggyClient) -> Result<(), ClientError>
             let payload = PollMessagesWithFormat { payload, format };
             messages::poll_messages(&payload, client).await
         }
+        Command::PoisonMessage(payload) => messages::poison_message(&payload, client).await,
+        Command::Subscribe(payload) => messages::subscribe(&payload, client).await,
+        Command::GrantCredits(payload) => messages::grant_credits(&payload, client).await,
+        Command::Unsubscribe(payload) => messages::unsubscribe(&payload, client).await,
+        Command::InitProducer(payload) => messages::init_producer(&payload, client).await,
         Command::StoreConsumerOffset(payload) => {
             consumer_offsets::store_consumer_offset(&payload, client).await
         }
         Command::GetConsumerOffset(payload) => {
             consumer_offsets::get_consumer_offset(&payload, client).await
         }
+        Command::GetConsumerLag(payload) => {
+            consumer_offsets::get_consumer_lag(&payload, client).await
+        }
+        Command::ResetConsumerOffsets(payload) => {
+            consumer_offsets::reset_consumer_offsets(&payload, client).await
+        }
         Command::GetStream(payload) => streams::get_stream(&payload, client).await,
         Command::GetStreams(payload) => streams::get_streams(&payload, client).await,
         Command::CreateStream(payload) => streams::create_stream(&payload, client).await,
@@ -75,6 +114,21 @@ pub async fn handle(input: &str, client: &IggyClient) -> Result<(), ClientError>
         Command::LeaveConsumerGroup(payload) => {
             consumer_groups::leave_consumer_group(&payload, client).await
         }
+        Command::HeartbeatConsumerGroup(payload) => {
+            consumer_groups::heartbeat_consumer_group(&payload, client).await
+        }
+        Command::GetConsumerGroupAssignment(payload) => {
+            consumer_groups::get_consumer_group_assignment(&payload, client).await
+        }
+        Command::BeginTransaction(payload) => {
+            transactions::begin_transaction(&payload, client).await
+        }
+        Command::CommitTransaction(payload) => {
+            transactions::commit_transaction(&payload, client).await
+        }
+        Command::AbortTransaction(payload) => {
+            transactions::abort_transaction(&payload, client).await
+        }
     }
 }
 
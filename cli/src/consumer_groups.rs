@@ -3,7 +3,9 @@ use iggy::client_error::ClientError;
 use iggy::consumer_groups::create_consumer_group::CreateConsumerGroup;
 use iggy::consumer_groups::delete_consumer_group::DeleteConsumerGroup;
 use iggy::consumer_groups::get_consumer_group::GetConsumerGroup;
+use iggy::consumer_groups::get_consumer_group_assignment::GetConsumerGroupAssignment;
 use iggy::consumer_groups::get_consumer_groups::GetConsumerGroups;
+use iggy::consumer_groups::heartbeat_consumer_group::HeartbeatConsumerGroup;
 use iggy::consumer_groups::join_consumer_group::JoinConsumerGroup;
 use iggy::consumer_groups::leave_consumer_group::LeaveConsumerGroup;
 use tracing::info;
@@ -62,3 +64,20 @@ pub async fn leave_consumer_group(
     client.leave_consumer_group(command).await?;
     Ok(())
 }
+
+pub async fn heartbeat_consumer_group(
+    command: &HeartbeatConsumerGroup,
+    client: &dyn Client,
+) -> Result<(), ClientError> {
+    client.heartbeat_consumer_group(command).await?;
+    Ok(())
+}
+
+pub async fn get_consumer_group_assignment(
+    command: &GetConsumerGroupAssignment,
+    client: &dyn Client,
+) -> Result<(), ClientError> {
+    let assignment = client.get_consumer_group_assignment(command).await?;
+    info!("Consumer group assignment: {:#?}", assignment);
+    Ok(())
+}
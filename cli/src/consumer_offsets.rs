@@ -1,6 +1,8 @@
 use iggy::client::Client;
 use iggy::client_error::ClientError;
+use iggy::consumer_offsets::get_consumer_lag::GetConsumerLag;
 use iggy::consumer_offsets::get_consumer_offset::GetConsumerOffset;
+use iggy::consumer_offsets::reset_consumer_offsets::ResetConsumerOffsets;
 use iggy::consumer_offsets::store_consumer_offset::StoreConsumerOffset;
 use tracing::info;
 
@@ -13,6 +15,15 @@ pub async fn get_consumer_offset(
     Ok(())
 }
 
+pub async fn get_consumer_lag(
+    command: &GetConsumerLag,
+    client: &dyn Client,
+) -> Result<(), ClientError> {
+    let lags = client.get_consumer_lag(command).await?;
+    info!("Consumer lag: {:#?}", lags);
+    Ok(())
+}
+
 pub async fn store_consumer_offset(
     command: &StoreConsumerOffset,
     client: &dyn Client,
@@ -20,3 +31,12 @@ pub async fn store_consumer_offset(
     client.store_consumer_offset(command).await?;
     Ok(())
 }
+
+pub async fn reset_consumer_offsets(
+    command: &ResetConsumerOffsets,
+    client: &dyn Client,
+) -> Result<(), ClientError> {
+    let resets = client.reset_consumer_offsets(command).await?;
+    info!("Consumer offsets reset: {:#?}", resets);
+    Ok(())
+}
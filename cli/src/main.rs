@@ -3,9 +3,12 @@ mod consumer_groups;
 mod consumer_offsets;
 mod messages;
 mod partitions;
+mod personal_access_tokens;
+mod roles;
 mod streams;
 mod system;
 mod topics;
+mod transactions;
 mod users;
 
 use anyhow::Result;
@@ -1,7 +1,12 @@
 use crate::command::{Format, PollMessagesWithFormat};
 use iggy::client::Client;
 use iggy::client_error::ClientError;
+use iggy::messages::grant_credits::GrantCredits;
+use iggy::messages::init_producer::InitProducer;
+use iggy::messages::poison_message::PoisonMessage;
 use iggy::messages::send_messages::SendMessages;
+use iggy::messages::subscribe::Subscribe;
+use iggy::messages::unsubscribe::Unsubscribe;
 use tracing::info;
 
 pub async fn send_messages(
@@ -12,6 +17,51 @@ pub async fn send_messages(
     Ok(())
 }
 
+pub async fn poison_message(
+    command: &PoisonMessage,
+    client: &dyn Client,
+) -> Result<(), ClientError> {
+    client.poison_message(command).await?;
+    Ok(())
+}
+
+pub async fn subscribe(command: &Subscribe, client: &dyn Client) -> Result<(), ClientError> {
+    let pushed_messages = client.subscribe(command).await?;
+    let subscription_id = command.subscription_id;
+    tokio::spawn(async move {
+        while let Ok(polled_messages) = pushed_messages.recv_async().await {
+            info!(
+                "Received {} pushed messages for subscription with ID: {}, partition ID: {}, last offset: {}.",
+                polled_messages.messages.len(),
+                subscription_id,
+                polled_messages.partition_id,
+                polled_messages
+                    .messages
+                    .last()
+                    .map(|message| message.offset)
+                    .unwrap_or(0)
+            );
+        }
+    });
+    Ok(())
+}
+
+pub async fn grant_credits(command: &GrantCredits, client: &dyn Client) -> Result<(), ClientError> {
+    client.grant_credits(command).await?;
+    Ok(())
+}
+
+pub async fn unsubscribe(command: &Unsubscribe, client: &dyn Client) -> Result<(), ClientError> {
+    client.unsubscribe(command).await?;
+    Ok(())
+}
+
+pub async fn init_producer(command: &InitProducer, client: &dyn Client) -> Result<(), ClientError> {
+    let producer = client.init_producer(command).await?;
+    info!("Initialized producer with ID: {}", producer.producer_id);
+    Ok(())
+}
+
 pub async fn poll_messages(
     command: &PollMessagesWithFormat,
     client: &dyn Client,
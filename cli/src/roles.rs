@@ -0,0 +1,58 @@
+use iggy::client::Client;
+use iggy::client_error::ClientError;
+use iggy::roles::add_role_member::AddRoleMember;
+use iggy::roles::create_role::CreateRole;
+use iggy::roles::delete_role::DeleteRole;
+use iggy::roles::get_role::GetRole;
+use iggy::roles::get_roles::GetRoles;
+use iggy::roles::remove_role_member::RemoveRoleMember;
+use iggy::roles::update_role::UpdateRole;
+use tracing::info;
+
+pub async fn get_role(command: &GetRole, client: &dyn Client) -> Result<(), ClientError> {
+    let role = client.get_role(command).await?;
+    info!("Role: {:#?}", role);
+    Ok(())
+}
+
+pub async fn get_roles(command: &GetRoles, client: &dyn Client) -> Result<(), ClientError> {
+    let roles = client.get_roles(command).await?;
+    if roles.is_empty() {
+        info!("No roles found");
+        return Ok(());
+    }
+
+    info!("Roles: {:#?}", roles);
+    Ok(())
+}
+
+pub async fn create_role(command: &CreateRole, client: &dyn Client) -> Result<(), ClientError> {
+    client.create_role(command).await?;
+    Ok(())
+}
+
+pub async fn delete_role(command: &DeleteRole, client: &dyn Client) -> Result<(), ClientError> {
+    client.delete_role(command).await?;
+    Ok(())
+}
+
+pub async fn update_role(command: &UpdateRole, client: &dyn Client) -> Result<(), ClientError> {
+    client.update_role(command).await?;
+    Ok(())
+}
+
+pub async fn add_role_member(
+    command: &AddRoleMember,
+    client: &dyn Client,
+) -> Result<(), ClientError> {
+    client.add_role_member(command).await?;
+    Ok(())
+}
+
+pub async fn remove_role_member(
+    command: &RemoveRoleMember,
+    client: &dyn Client,
+) -> Result<(), ClientError> {
+    client.remove_role_member(command).await?;
+    Ok(())
+}
@@ -3,8 +3,10 @@ use iggy::client_error::ClientError;
 use iggy::system::get_client::GetClient;
 use iggy::system::get_clients::GetClients;
 use iggy::system::get_me::GetMe;
+use iggy::system::get_sessions::GetSessions;
 use iggy::system::get_stats::GetStats;
 use iggy::system::ping::Ping;
+use iggy::system::revoke_session::RevokeSession;
 use tracing::info;
 
 pub async fn ping(command: &Ping, client: &dyn Client) -> Result<(), ClientError> {
@@ -40,3 +42,22 @@ pub async fn get_clients(command: &GetClients, client: &dyn Client) -> Result<()
     info!("Clients: {:#?}", clients);
     Ok(())
 }
+
+pub async fn get_sessions(command: &GetSessions, client: &dyn Client) -> Result<(), ClientError> {
+    let sessions = client.get_sessions(command).await?;
+    if sessions.is_empty() {
+        info!("No sessions found");
+        return Ok(());
+    }
+
+    info!("Sessions: {:#?}", sessions);
+    Ok(())
+}
+
+pub async fn revoke_session(
+    command: &RevokeSession,
+    client: &dyn Client,
+) -> Result<(), ClientError> {
+    client.revoke_session(command).await?;
+    Ok(())
+}
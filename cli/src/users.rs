@@ -7,7 +7,9 @@ use iggy::users::get_user::GetUser;
 use iggy::users::get_users::GetUsers;
 use iggy::users::login_user::LoginUser;
 use iggy::users::logout_user::LogoutUser;
+use iggy::users::unlock_user::UnlockUser;
 use iggy::users::update_permissions::UpdatePermissions;
+use iggy::users::update_quotas::UpdateQuotas;
 use iggy::users::update_user::UpdateUser;
 use tracing::info;
 
@@ -38,6 +40,11 @@ pub async fn delete_user(command: &DeleteUser, client: &dyn Client) -> Result<()
     Ok(())
 }
 
+pub async fn unlock_user(command: &UnlockUser, client: &dyn Client) -> Result<(), ClientError> {
+    client.unlock_user(command).await?;
+    Ok(())
+}
+
 pub async fn update_user(command: &UpdateUser, client: &dyn Client) -> Result<(), ClientError> {
     client.update_user(command).await?;
     Ok(())
@@ -51,6 +58,11 @@ pub async fn update_permissions(
     Ok(())
 }
 
+pub async fn update_quotas(command: &UpdateQuotas, client: &dyn Client) -> Result<(), ClientError> {
+    client.update_quotas(command).await?;
+    Ok(())
+}
+
 pub async fn change_password(
     command: &ChangePassword,
     client: &dyn Client,
@@ -28,6 +28,7 @@ impl CliCommand for StreamCreate {
             .create_stream(&CreateStream {
                 stream_id: self.id,
                 name: self.name.clone(),
+                encryption: None,
             })
             .await
             .with_context(|| {
@@ -30,6 +30,18 @@ pub struct Args {
     #[arg(long, default_value = "localhost")]
     pub tcp_tls_domain: String,
 
+    #[arg(long)]
+    pub tcp_tls_certificate_file: Option<String>,
+
+    #[arg(long)]
+    pub tcp_tls_key_file: Option<String>,
+
+    #[arg(long)]
+    pub tcp_tls_ca_file: Option<String>,
+
+    #[arg(long, default_value = "false")]
+    pub tcp_plaintext_login_fallback: bool,
+
     #[arg(long, default_value = "127.0.0.1:0")]
     pub quic_client_address: String,
 
@@ -71,4 +83,13 @@ pub struct Args {
 
     #[arg(long, default_value = "false")]
     pub quic_validate_certificate: bool,
+
+    #[arg(long)]
+    pub quic_client_certificate_file: Option<String>,
+
+    #[arg(long)]
+    pub quic_client_key_file: Option<String>,
+
+    #[arg(long, default_value = "false")]
+    pub quic_plaintext_login_fallback: bool,
 }
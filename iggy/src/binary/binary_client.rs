@@ -1,3 +1,4 @@
+use crate::binary::subscriptions::MessageSubscriptions;
 use crate::client::Client;
 use crate::error::Error;
 use async_trait::async_trait;
@@ -5,4 +6,5 @@ use async_trait::async_trait;
 #[async_trait]
 pub trait BinaryClient: Client {
     async fn send_with_response(&self, command: u32, payload: &[u8]) -> Result<Vec<u8>, Error>;
+    fn get_subscriptions(&self) -> &MessageSubscriptions;
 }
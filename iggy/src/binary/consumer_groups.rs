@@ -3,16 +3,19 @@ use crate::binary::mapper;
 use crate::bytes_serializable::BytesSerializable;
 use crate::command::{
     CREATE_CONSUMER_GROUP_CODE, DELETE_CONSUMER_GROUP_CODE, GET_CONSUMER_GROUPS_CODE,
-    GET_CONSUMER_GROUP_CODE, JOIN_CONSUMER_GROUP_CODE, LEAVE_CONSUMER_GROUP_CODE,
+    GET_CONSUMER_GROUP_ASSIGNMENT_CODE, GET_CONSUMER_GROUP_CODE, HEARTBEAT_CONSUMER_GROUP_CODE,
+    JOIN_CONSUMER_GROUP_CODE, LEAVE_CONSUMER_GROUP_CODE,
 };
 use crate::consumer_groups::create_consumer_group::CreateConsumerGroup;
 use crate::consumer_groups::delete_consumer_group::DeleteConsumerGroup;
 use crate::consumer_groups::get_consumer_group::GetConsumerGroup;
+use crate::consumer_groups::get_consumer_group_assignment::GetConsumerGroupAssignment;
 use crate::consumer_groups::get_consumer_groups::GetConsumerGroups;
+use crate::consumer_groups::heartbeat_consumer_group::HeartbeatConsumerGroup;
 use crate::consumer_groups::join_consumer_group::JoinConsumerGroup;
 use crate::consumer_groups::leave_consumer_group::LeaveConsumerGroup;
 use crate::error::Error;
-use crate::models::consumer_group::{ConsumerGroup, ConsumerGroupDetails};
+use crate::models::consumer_group::{ConsumerGroup, ConsumerGroupAssignment, ConsumerGroupDetails};
 
 pub async fn create_group(
     client: &dyn BinaryClient,
@@ -73,3 +76,23 @@ pub async fn leave_group(
         .await?;
     Ok(())
 }
+
+pub async fn heartbeat_group(
+    client: &dyn BinaryClient,
+    command: &HeartbeatConsumerGroup,
+) -> Result<(), Error> {
+    client
+        .send_with_response(HEARTBEAT_CONSUMER_GROUP_CODE, &command.as_bytes())
+        .await?;
+    Ok(())
+}
+
+pub async fn get_group_assignment(
+    client: &dyn BinaryClient,
+    command: &GetConsumerGroupAssignment,
+) -> Result<ConsumerGroupAssignment, Error> {
+    let response = client
+        .send_with_response(GET_CONSUMER_GROUP_ASSIGNMENT_CODE, &command.as_bytes())
+        .await?;
+    mapper::map_consumer_group_assignment(&response)
+}
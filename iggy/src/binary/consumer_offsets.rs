@@ -1,11 +1,18 @@
 use crate::binary::binary_client::BinaryClient;
 use crate::binary::mapper;
 use crate::bytes_serializable::BytesSerializable;
-use crate::command::{GET_CONSUMER_OFFSET_CODE, STORE_CONSUMER_OFFSET_CODE};
+use crate::command::{
+    GET_CONSUMER_LAG_CODE, GET_CONSUMER_OFFSET_CODE, RESET_CONSUMER_OFFSETS_CODE,
+    STORE_CONSUMER_OFFSET_CODE,
+};
+use crate::consumer_offsets::get_consumer_lag::GetConsumerLag;
 use crate::consumer_offsets::get_consumer_offset::GetConsumerOffset;
+use crate::consumer_offsets::reset_consumer_offsets::ResetConsumerOffsets;
 use crate::consumer_offsets::store_consumer_offset::StoreConsumerOffset;
 use crate::error::Error;
+use crate::models::consumer_lag::ConsumerLag;
 use crate::models::consumer_offset_info::ConsumerOffsetInfo;
+use crate::models::consumer_offset_reset::ConsumerOffsetReset;
 
 pub async fn store_consumer_offset(
     client: &dyn BinaryClient,
@@ -26,3 +33,23 @@ pub async fn get_consumer_offset(
         .await?;
     mapper::map_consumer_offset(&response)
 }
+
+pub async fn get_consumer_lag(
+    client: &dyn BinaryClient,
+    command: &GetConsumerLag,
+) -> Result<Vec<ConsumerLag>, Error> {
+    let response = client
+        .send_with_response(GET_CONSUMER_LAG_CODE, &command.as_bytes())
+        .await?;
+    mapper::map_consumer_lags(&response)
+}
+
+pub async fn reset_consumer_offsets(
+    client: &dyn BinaryClient,
+    command: &ResetConsumerOffsets,
+) -> Result<Vec<ConsumerOffsetReset>, Error> {
+    let response = client
+        .send_with_response(RESET_CONSUMER_OFFSETS_CODE, &command.as_bytes())
+        .await?;
+    mapper::map_consumer_offset_resets(&response)
+}
@@ -1,14 +1,27 @@
 use crate::bytes_serializable::BytesSerializable;
+use crate::consumer::ConsumerKind;
 use crate::error::Error;
+use crate::models::assignment_strategy::AssignmentStrategy;
+use crate::models::cleanup_policy::CleanupPolicy;
 use crate::models::client_info::{ClientInfo, ClientInfoDetails, ConsumerGroupInfo};
-use crate::models::consumer_group::{ConsumerGroup, ConsumerGroupDetails, ConsumerGroupMember};
+use crate::models::consumer_group::{
+    ConsumerGroup, ConsumerGroupAssignment, ConsumerGroupDetails, ConsumerGroupMember,
+};
+use crate::models::consumer_lag::{ConsumerLag, PartitionLag};
 use crate::models::consumer_offset_info::ConsumerOffsetInfo;
+use crate::models::consumer_offset_reset::ConsumerOffsetReset;
 use crate::models::messages::{Message, MessageState, PolledMessages};
 use crate::models::partition::Partition;
 use crate::models::permissions::Permissions;
+use crate::models::personal_access_token::{PersonalAccessTokenInfo, RawPersonalAccessToken};
+use crate::models::producer_info::ProducerInfo;
+use crate::models::quotas::{Quotas, QUOTAS_SIZE};
+use crate::models::role_info::{RoleInfo, RoleInfoDetails};
+use crate::models::scram_challenge::ScramChallenge;
 use crate::models::stats::Stats;
 use crate::models::stream::{Stream, StreamDetails};
 use crate::models::topic::{Topic, TopicDetails};
+use crate::models::transaction_info::TransactionInfo;
 use crate::models::user_info::{UserInfo, UserInfoDetails};
 use crate::models::user_status::UserStatus;
 use bytes::Bytes;
@@ -20,6 +33,8 @@ const EMPTY_TOPICS: Vec<Topic> = vec![];
 const EMPTY_STREAMS: Vec<Stream> = vec![];
 const EMPTY_CLIENTS: Vec<ClientInfo> = vec![];
 const EMPTY_USERS: Vec<UserInfo> = vec![];
+const EMPTY_PERSONAL_ACCESS_TOKENS: Vec<PersonalAccessTokenInfo> = vec![];
+const EMPTY_ROLES: Vec<RoleInfo> = vec![];
 const EMPTY_CONSUMER_GROUPS: Vec<ConsumerGroup> = vec![];
 
 pub fn map_stats(payload: &[u8]) -> Result<Stats, Error> {
@@ -100,17 +115,129 @@ pub fn map_consumer_offset(payload: &[u8]) -> Result<ConsumerOffsetInfo, Error>
     })
 }
 
+pub fn map_consumer_lags(payload: &[u8]) -> Result<Vec<ConsumerLag>, Error> {
+    let mut lags = Vec::new();
+    let length = payload.len();
+    let mut position = 0;
+    while position < length {
+        let consumer_kind = ConsumerKind::from_code(payload[position])?;
+        let consumer_id = u32::from_le_bytes(payload[position + 1..position + 5].try_into()?);
+        let partitions_count = u32::from_le_bytes(payload[position + 5..position + 9].try_into()?);
+        position += 9;
+        let mut partitions = Vec::with_capacity(partitions_count as usize);
+        for _ in 0..partitions_count {
+            let partition_id = u32::from_le_bytes(payload[position..position + 4].try_into()?);
+            let current_offset =
+                u64::from_le_bytes(payload[position + 4..position + 12].try_into()?);
+            let stored_offset = map_optional_offset(payload, position + 12)?;
+            let messages_lag =
+                u64::from_le_bytes(payload[position + 21..position + 29].try_into()?);
+            let time_lag = u64::from_le_bytes(payload[position + 29..position + 37].try_into()?);
+            partitions.push(PartitionLag {
+                partition_id,
+                current_offset,
+                stored_offset,
+                messages_lag,
+                time_lag,
+            });
+            position += 37;
+        }
+        lags.push(ConsumerLag {
+            consumer_kind,
+            consumer_id,
+            partitions,
+        });
+    }
+    Ok(lags)
+}
+
+pub fn map_consumer_offset_resets(payload: &[u8]) -> Result<Vec<ConsumerOffsetReset>, Error> {
+    let mut resets = Vec::new();
+    let length = payload.len();
+    let mut position = 0;
+    while position < length {
+        let partition_id = u32::from_le_bytes(payload[position..position + 4].try_into()?);
+        let current_offset = u64::from_le_bytes(payload[position + 4..position + 12].try_into()?);
+        let previous_offset = map_optional_offset(payload, position + 12)?;
+        let stored_offset = map_optional_offset(payload, position + 21)?;
+        resets.push(ConsumerOffsetReset {
+            partition_id,
+            current_offset,
+            previous_offset,
+            stored_offset,
+        });
+        position += 30;
+    }
+    Ok(resets)
+}
+
+fn map_optional_offset(payload: &[u8], position: usize) -> Result<Option<u64>, Error> {
+    let offset = u64::from_le_bytes(payload[position + 1..position + 9].try_into()?);
+    match payload[position] {
+        1 => Ok(Some(offset)),
+        _ => Ok(None),
+    }
+}
+
+pub fn map_producer_info(payload: &[u8]) -> Result<ProducerInfo, Error> {
+    let producer_id = u64::from_le_bytes(payload[..8].try_into()?);
+    Ok(ProducerInfo { producer_id })
+}
+
+pub fn map_transaction_info(payload: &[u8]) -> Result<TransactionInfo, Error> {
+    let transaction_id = u64::from_le_bytes(payload[..8].try_into()?);
+    Ok(TransactionInfo { transaction_id })
+}
+
+pub fn map_scram_challenge(payload: &[u8]) -> Result<ScramChallenge, Error> {
+    let nonce_length = payload[0] as usize;
+    let nonce = from_utf8(&payload[1..1 + nonce_length])?.to_string();
+    let mut position = 1 + nonce_length;
+    let salt_length = payload[position] as usize;
+    let salt = payload[position + 1..position + 1 + salt_length].to_vec();
+    position += 1 + salt_length;
+    let iterations = u32::from_le_bytes(payload[position..position + 4].try_into()?);
+    Ok(ScramChallenge {
+        nonce,
+        salt,
+        iterations,
+    })
+}
+
 pub fn map_user(payload: &[u8]) -> Result<UserInfoDetails, Error> {
-    let (user, position) = map_to_user_info(payload, 0)?;
+    let (user, mut position) = map_to_user_info(payload, 0)?;
     let has_permissions = payload[position];
     let permissions = if has_permissions == 1 {
         let permissions_length =
             u32::from_le_bytes(payload[position + 1..position + 5].try_into()?) as usize;
         let permissions = &payload[position + 5..position + 5 + permissions_length];
+        position += 5 + permissions_length;
         Some(Permissions::from_bytes(permissions)?)
     } else {
+        position += 4;
         None
     };
+    let quotas = match payload.get(position) {
+        Some(1) => {
+            let quotas = Quotas::from_bytes(&payload[position + 1..position + 1 + QUOTAS_SIZE])?;
+            position += 1 + QUOTAS_SIZE;
+            Some(quotas)
+        }
+        _ => {
+            position += 1;
+            None
+        }
+    };
+    let (failed_login_attempts, locked_at) = if payload.len() >= position + 12 {
+        let failed_login_attempts = u32::from_le_bytes(payload[position..position + 4].try_into()?);
+        let locked_at = u64::from_le_bytes(payload[position + 4..position + 12].try_into()?);
+        (
+            failed_login_attempts,
+            if locked_at > 0 { Some(locked_at) } else { None },
+        )
+    } else {
+        (0, None)
+    };
 
     let user = UserInfoDetails {
         id: user.id,
@@ -118,6 +245,9 @@ pub fn map_user(payload: &[u8]) -> Result<UserInfoDetails, Error> {
         status: user.status,
         username: user.username,
         permissions,
+        quotas,
+        failed_login_attempts,
+        locked_at,
     };
     Ok(user)
 }
@@ -139,26 +269,105 @@ pub fn map_users(payload: &[u8]) -> Result<Vec<UserInfo>, Error> {
     Ok(users)
 }
 
+pub fn map_raw_personal_access_token(payload: &[u8]) -> Result<RawPersonalAccessToken, Error> {
+    let token_length = payload[0];
+    let token = from_utf8(&payload[1..1 + token_length as usize])?.to_string();
+    Ok(RawPersonalAccessToken { token })
+}
+
+pub fn map_personal_access_tokens(payload: &[u8]) -> Result<Vec<PersonalAccessTokenInfo>, Error> {
+    if payload.is_empty() {
+        return Ok(EMPTY_PERSONAL_ACCESS_TOKENS);
+    }
+
+    let mut personal_access_tokens = Vec::new();
+    let length = payload.len();
+    let mut position = 0;
+    while position < length {
+        let name_length = payload[position];
+        let name =
+            from_utf8(&payload[position + 1..position + 1 + name_length as usize])?.to_string();
+        position += 1 + name_length as usize;
+        let expiry = u64::from_le_bytes(payload[position..position + 8].try_into()?);
+        let expiry = match expiry {
+            0 => None,
+            _ => Some(expiry),
+        };
+        position += 8;
+        personal_access_tokens.push(PersonalAccessTokenInfo { name, expiry });
+    }
+    personal_access_tokens.sort_by(|x, y| x.name.cmp(&y.name));
+    Ok(personal_access_tokens)
+}
+
+pub fn map_role(payload: &[u8]) -> Result<RoleInfoDetails, Error> {
+    let (role, mut position) = map_to_role_info(payload, 0)?;
+    let permissions_length = u32::from_le_bytes(payload[position..position + 4].try_into()?);
+    position += 4;
+    let permissions =
+        Permissions::from_bytes(&payload[position..position + permissions_length as usize])?;
+    position += permissions_length as usize;
+    let mut members = Vec::with_capacity(role.members_count as usize);
+    for _ in 0..role.members_count {
+        let member = u32::from_le_bytes(payload[position..position + 4].try_into()?);
+        members.push(member);
+        position += 4;
+    }
+
+    let role = RoleInfoDetails {
+        id: role.id,
+        created_at: role.created_at,
+        name: role.name,
+        members_count: role.members_count,
+        permissions,
+        members,
+    };
+    Ok(role)
+}
+
+pub fn map_roles(payload: &[u8]) -> Result<Vec<RoleInfo>, Error> {
+    if payload.is_empty() {
+        return Ok(EMPTY_ROLES);
+    }
+
+    let mut roles = Vec::new();
+    let length = payload.len();
+    let mut position = 0;
+    while position < length {
+        let (role, read_bytes) = map_to_role_info(payload, position)?;
+        roles.push(role);
+        position += read_bytes;
+    }
+    roles.sort_by_key(|x| x.id);
+    Ok(roles)
+}
+
 pub fn map_client(payload: &[u8]) -> Result<ClientInfoDetails, Error> {
     let (client, mut position) = map_to_client_info(payload, 0)?;
     let mut consumer_groups = Vec::new();
-    let length = payload.len();
-    while position < length {
-        for _ in 0..client.consumer_groups_count {
-            let stream_id = u32::from_le_bytes(payload[position..position + 4].try_into()?);
-            let topic_id = u32::from_le_bytes(payload[position + 4..position + 8].try_into()?);
-            let consumer_group_id =
-                u32::from_le_bytes(payload[position + 8..position + 12].try_into()?);
-            let consumer_group = ConsumerGroupInfo {
-                stream_id,
-                topic_id,
-                consumer_group_id,
-            };
-            consumer_groups.push(consumer_group);
-            position += 12;
-        }
+    for _ in 0..client.consumer_groups_count {
+        let stream_id = u32::from_le_bytes(payload[position..position + 4].try_into()?);
+        let topic_id = u32::from_le_bytes(payload[position + 4..position + 8].try_into()?);
+        let consumer_group_id =
+            u32::from_le_bytes(payload[position + 8..position + 12].try_into()?);
+        let consumer_group = ConsumerGroupInfo {
+            stream_id,
+            topic_id,
+            consumer_group_id,
+        };
+        consumer_groups.push(consumer_group);
+        position += 12;
     }
 
+    let (client_quotas, user_quotas) = if payload.len() >= position + 2 * QUOTAS_SIZE {
+        (
+            Quotas::from_bytes(&payload[position..position + QUOTAS_SIZE])?,
+            Quotas::from_bytes(&payload[position + QUOTAS_SIZE..])?,
+        )
+    } else {
+        (Quotas::default(), Quotas::default())
+    };
+
     consumer_groups.sort_by(|x, y| x.consumer_group_id.cmp(&y.consumer_group_id));
     let client = ClientInfoDetails {
         client_id: client.client_id,
@@ -167,6 +376,8 @@ pub fn map_client(payload: &[u8]) -> Result<ClientInfoDetails, Error> {
         transport: client.transport,
         consumer_groups_count: client.consumer_groups_count,
         consumer_groups,
+        client_quotas,
+        user_quotas,
     };
     Ok(client)
 }
@@ -194,15 +405,27 @@ pub fn map_polled_messages(payload: &[u8]) -> Result<PolledMessages, Error> {
             messages: EMPTY_MESSAGES,
             partition_id: 0,
             current_offset: 0,
+            scanned_offset: None,
+            generation_id: None,
+            assignment_changed: false,
         });
     }
 
     let length = payload.len();
     let partition_id = u32::from_le_bytes(payload[..4].try_into()?);
     let current_offset = u64::from_le_bytes(payload[4..12].try_into()?);
+    let scanned_offset = match payload[12] {
+        1 => Some(u64::from_le_bytes(payload[13..21].try_into()?)),
+        _ => None,
+    };
+    let generation_id = match u32::from_le_bytes(payload[21..25].try_into()?) {
+        0 => None,
+        generation_id => Some(generation_id),
+    };
+    let assignment_changed = payload[25] == 1;
     // Currently ignored
-    let _messages_count = u32::from_le_bytes(payload[12..16].try_into()?);
-    let mut position = 16;
+    let _messages_count = u32::from_le_bytes(payload[26..30].try_into()?);
+    let mut position = 30;
     let mut messages = Vec::new();
     while position < length {
         let offset = u64::from_le_bytes(payload[position..position + 8].try_into()?);
@@ -247,6 +470,9 @@ pub fn map_polled_messages(payload: &[u8]) -> Result<PolledMessages, Error> {
     Ok(PolledMessages {
         partition_id,
         current_offset,
+        scanned_offset,
+        generation_id,
+        assignment_changed,
         messages,
     })
 }
@@ -349,6 +575,11 @@ pub fn map_topic(payload: &[u8]) -> Result<TopicDetails, Error> {
         size_bytes: topic.size_bytes,
         messages_count: topic.messages_count,
         message_expiry: topic.message_expiry,
+        cleanup_policy: topic.cleanup_policy,
+        compaction_key: topic.compaction_key,
+        max_topic_size_bytes: topic.max_topic_size_bytes,
+        max_messages: topic.max_messages,
+        dead_letter_topic_id: topic.dead_letter_topic_id,
         #[allow(clippy::cast_possible_truncation)]
         partitions_count: partitions.len() as u32,
         partitions,
@@ -365,12 +596,55 @@ fn map_to_topic(payload: &[u8], position: usize) -> Result<(Topic, usize), Error
         0 => None,
         _ => Some(message_expiry),
     };
-    let size_bytes = u64::from_le_bytes(payload[position + 20..position + 28].try_into()?);
-    let messages_count = u64::from_le_bytes(payload[position + 28..position + 36].try_into()?);
-    let name_length = payload[position + 36];
+    let cleanup_policy = CleanupPolicy::from_code(payload[position + 20])?;
+    let max_topic_size_bytes =
+        u64::from_le_bytes(payload[position + 21..position + 29].try_into()?);
+    let max_topic_size_bytes = match max_topic_size_bytes {
+        0 => None,
+        _ => Some(max_topic_size_bytes),
+    };
+    let max_messages = u64::from_le_bytes(payload[position + 29..position + 37].try_into()?);
+    let max_messages = match max_messages {
+        0 => None,
+        _ => Some(max_messages),
+    };
+    let dead_letter_topic_id =
+        u32::from_le_bytes(payload[position + 37..position + 41].try_into()?);
+    let dead_letter_topic_id = match dead_letter_topic_id {
+        0 => None,
+        _ => Some(dead_letter_topic_id),
+    };
+    let size_bytes = u64::from_le_bytes(payload[position + 41..position + 49].try_into()?);
+    let messages_count = u64::from_le_bytes(payload[position + 49..position + 57].try_into()?);
+    let name_length = payload[position + 57];
     let name =
-        from_utf8(&payload[position + 37..position + 37 + name_length as usize])?.to_string();
-    let read_bytes = 4 + 8 + 4 + 4 + 8 + 8 + 1 + name_length as usize;
+        from_utf8(&payload[position + 58..position + 58 + name_length as usize])?.to_string();
+    let compaction_key_position = position + 58 + name_length as usize;
+    let compaction_key_length = payload[compaction_key_position];
+    let compaction_key = match compaction_key_length {
+        0 => None,
+        _ => Some(
+            from_utf8(
+                &payload[compaction_key_position + 1
+                    ..compaction_key_position + 1 + compaction_key_length as usize],
+            )?
+            .to_string(),
+        ),
+    };
+    let read_bytes = 4
+        + 8
+        + 4
+        + 4
+        + 1
+        + 8
+        + 8
+        + 8
+        + 8
+        + 4
+        + 1
+        + name_length as usize
+        + 1
+        + compaction_key_length as usize;
     Ok((
         Topic {
             id,
@@ -380,6 +654,11 @@ fn map_to_topic(payload: &[u8], position: usize) -> Result<(Topic, usize), Error
             size_bytes,
             messages_count,
             message_expiry,
+            cleanup_policy,
+            compaction_key,
+            max_topic_size_bytes,
+            max_messages,
+            dead_letter_topic_id,
         },
         read_bytes,
     ))
@@ -437,22 +716,52 @@ pub fn map_consumer_group(payload: &[u8]) -> Result<ConsumerGroupDetails, Error>
         id: consumer_group.id,
         partitions_count: consumer_group.partitions_count,
         members_count: consumer_group.members_count,
+        assignment_strategy: consumer_group.assignment_strategy,
+        session_timeout: consumer_group.session_timeout,
+        generation_id: consumer_group.generation_id,
         members,
     };
     Ok(consumer_group_details)
 }
 
+pub fn map_consumer_group_assignment(payload: &[u8]) -> Result<ConsumerGroupAssignment, Error> {
+    let consumer_group_id = u32::from_le_bytes(payload[..4].try_into()?);
+    let member_id = u32::from_le_bytes(payload[4..8].try_into()?);
+    let generation_id = u32::from_le_bytes(payload[8..12].try_into()?);
+    let partitions_count = u32::from_le_bytes(payload[12..16].try_into()?);
+    let mut partitions = Vec::with_capacity(partitions_count as usize);
+    for i in 0..partitions_count as usize {
+        let position = 16 + i * 4;
+        partitions.push(u32::from_le_bytes(
+            payload[position..position + 4].try_into()?,
+        ));
+    }
+    Ok(ConsumerGroupAssignment {
+        consumer_group_id,
+        member_id,
+        generation_id,
+        partitions_count,
+        partitions,
+    })
+}
+
 fn map_to_consumer_group(payload: &[u8], position: usize) -> Result<(ConsumerGroup, usize), Error> {
     let id = u32::from_le_bytes(payload[position..position + 4].try_into()?);
     let partitions_count = u32::from_le_bytes(payload[position + 4..position + 8].try_into()?);
     let members_count = u32::from_le_bytes(payload[position + 8..position + 12].try_into()?);
+    let assignment_strategy = AssignmentStrategy::from_code(payload[position + 12])?;
+    let session_timeout = u32::from_le_bytes(payload[position + 13..position + 17].try_into()?);
+    let generation_id = u32::from_le_bytes(payload[position + 17..position + 21].try_into()?);
     Ok((
         ConsumerGroup {
             id,
             partitions_count,
             members_count,
+            assignment_strategy,
+            session_timeout,
+            generation_id,
         },
-        12,
+        21,
     ))
 }
 
@@ -461,20 +770,22 @@ fn map_to_consumer_group_member(
     position: usize,
 ) -> Result<(ConsumerGroupMember, usize), Error> {
     let id = u32::from_le_bytes(payload[position..position + 4].try_into()?);
-    let partitions_count = u32::from_le_bytes(payload[position + 4..position + 8].try_into()?);
+    let last_heartbeat_at = u64::from_le_bytes(payload[position + 4..position + 12].try_into()?);
+    let partitions_count = u32::from_le_bytes(payload[position + 12..position + 16].try_into()?);
     let mut partitions = Vec::new();
     for i in 0..partitions_count {
         let partition_id = u32::from_le_bytes(
-            payload[position + 8 + (i * 4) as usize..position + 8 + ((i + 1) * 4) as usize]
+            payload[position + 16 + (i * 4) as usize..position + 16 + ((i + 1) * 4) as usize]
                 .try_into()?,
         );
         partitions.push(partition_id);
     }
 
-    let read_bytes = (4 + 4 + partitions_count * 4) as usize;
+    let read_bytes = (4 + 8 + 4 + partitions_count * 4) as usize;
     Ok((
         ConsumerGroupMember {
             id,
+            last_heartbeat_at,
             partitions_count,
             partitions,
         },
@@ -538,3 +849,23 @@ fn map_to_user_info(payload: &[u8], position: usize) -> Result<(UserInfo, usize)
         read_bytes,
     ))
 }
+
+fn map_to_role_info(payload: &[u8], position: usize) -> Result<(RoleInfo, usize), Error> {
+    let id = u32::from_le_bytes(payload[position..position + 4].try_into()?);
+    let created_at = u64::from_le_bytes(payload[position + 4..position + 12].try_into()?);
+    let members_count = u32::from_le_bytes(payload[position + 12..position + 16].try_into()?);
+    let name_length = payload[position + 16];
+    let name =
+        from_utf8(&payload[position + 17..position + 17 + name_length as usize])?.to_string();
+    let read_bytes = 4 + 8 + 4 + 1 + name_length as usize;
+
+    Ok((
+        RoleInfo {
+            id,
+            created_at,
+            name,
+            members_count,
+        },
+        read_bytes,
+    ))
+}
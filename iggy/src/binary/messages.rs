@@ -1,11 +1,21 @@
 use crate::binary::binary_client::BinaryClient;
 use crate::binary::mapper;
 use crate::bytes_serializable::BytesSerializable;
-use crate::command::{POLL_MESSAGES_CODE, SEND_MESSAGES_CODE};
+use crate::command::{
+    GRANT_CREDITS_CODE, INIT_PRODUCER_CODE, POISON_MESSAGE_CODE, POLL_MESSAGES_CODE,
+    SEND_MESSAGES_CODE, SUBSCRIBE_CODE, UNSUBSCRIBE_CODE,
+};
 use crate::error::Error;
+use crate::messages::grant_credits::GrantCredits;
+use crate::messages::init_producer::InitProducer;
+use crate::messages::poison_message::PoisonMessage;
 use crate::messages::poll_messages::PollMessages;
 use crate::messages::send_messages::SendMessages;
+use crate::messages::subscribe::Subscribe;
+use crate::messages::unsubscribe::Unsubscribe;
 use crate::models::messages::PolledMessages;
+use crate::models::producer_info::ProducerInfo;
+use flume::Receiver;
 
 pub async fn poll_messages(
     client: &dyn BinaryClient,
@@ -23,3 +33,56 @@ pub async fn send_messages(client: &dyn BinaryClient, command: &SendMessages) ->
         .await?;
     Ok(())
 }
+
+pub async fn poison_message(
+    client: &dyn BinaryClient,
+    command: &PoisonMessage,
+) -> Result<(), Error> {
+    client
+        .send_with_response(POISON_MESSAGE_CODE, &command.as_bytes())
+        .await?;
+    Ok(())
+}
+
+pub async fn subscribe(
+    client: &dyn BinaryClient,
+    command: &Subscribe,
+) -> Result<Receiver<PolledMessages>, Error> {
+    // Register the channel before sending the command, as the first messages might be pushed right after the response.
+    let subscriptions = client.get_subscriptions();
+    let receiver = subscriptions.register(command.subscription_id)?;
+    if let Err(error) = client
+        .send_with_response(SUBSCRIBE_CODE, &command.as_bytes())
+        .await
+    {
+        subscriptions.remove(command.subscription_id);
+        return Err(error);
+    }
+
+    Ok(receiver)
+}
+
+pub async fn grant_credits(client: &dyn BinaryClient, command: &GrantCredits) -> Result<(), Error> {
+    client
+        .send_with_response(GRANT_CREDITS_CODE, &command.as_bytes())
+        .await?;
+    Ok(())
+}
+
+pub async fn unsubscribe(client: &dyn BinaryClient, command: &Unsubscribe) -> Result<(), Error> {
+    client.get_subscriptions().remove(command.subscription_id);
+    client
+        .send_with_response(UNSUBSCRIBE_CODE, &command.as_bytes())
+        .await?;
+    Ok(())
+}
+
+pub async fn init_producer(
+    client: &dyn BinaryClient,
+    command: &InitProducer,
+) -> Result<ProducerInfo, Error> {
+    let response = client
+        .send_with_response(INIT_PRODUCER_CODE, &command.as_bytes())
+        .await?;
+    mapper::map_producer_info(&response)
+}
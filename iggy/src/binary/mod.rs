@@ -4,7 +4,11 @@ pub mod consumer_offsets;
 mod mapper;
 pub mod messages;
 pub mod partitions;
+pub mod personal_access_tokens;
+pub mod roles;
 pub mod streams;
+pub mod subscriptions;
 pub mod system;
 pub mod topics;
+pub mod transactions;
 pub mod users;
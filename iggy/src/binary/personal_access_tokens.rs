@@ -0,0 +1,53 @@
+use crate::binary::binary_client::BinaryClient;
+use crate::binary::mapper;
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::{
+    CREATE_PERSONAL_ACCESS_TOKEN_CODE, DELETE_PERSONAL_ACCESS_TOKEN_CODE,
+    GET_PERSONAL_ACCESS_TOKENS_CODE, LOGIN_WITH_TOKEN_CODE,
+};
+use crate::error::Error;
+use crate::models::personal_access_token::{PersonalAccessTokenInfo, RawPersonalAccessToken};
+use crate::personal_access_tokens::create_personal_access_token::CreatePersonalAccessToken;
+use crate::personal_access_tokens::delete_personal_access_token::DeletePersonalAccessToken;
+use crate::personal_access_tokens::get_personal_access_tokens::GetPersonalAccessTokens;
+use crate::personal_access_tokens::login_with_token::LoginWithToken;
+
+pub async fn get_personal_access_tokens(
+    client: &dyn BinaryClient,
+    command: &GetPersonalAccessTokens,
+) -> Result<Vec<PersonalAccessTokenInfo>, Error> {
+    let response = client
+        .send_with_response(GET_PERSONAL_ACCESS_TOKENS_CODE, &command.as_bytes())
+        .await?;
+    mapper::map_personal_access_tokens(&response)
+}
+
+pub async fn create_personal_access_token(
+    client: &dyn BinaryClient,
+    command: &CreatePersonalAccessToken,
+) -> Result<RawPersonalAccessToken, Error> {
+    let response = client
+        .send_with_response(CREATE_PERSONAL_ACCESS_TOKEN_CODE, &command.as_bytes())
+        .await?;
+    mapper::map_raw_personal_access_token(&response)
+}
+
+pub async fn delete_personal_access_token(
+    client: &dyn BinaryClient,
+    command: &DeletePersonalAccessToken,
+) -> Result<(), Error> {
+    client
+        .send_with_response(DELETE_PERSONAL_ACCESS_TOKEN_CODE, &command.as_bytes())
+        .await?;
+    Ok(())
+}
+
+pub async fn login_with_token(
+    client: &dyn BinaryClient,
+    command: &LoginWithToken,
+) -> Result<(), Error> {
+    client
+        .send_with_response(LOGIN_WITH_TOKEN_CODE, &command.as_bytes())
+        .await?;
+    Ok(())
+}
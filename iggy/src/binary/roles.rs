@@ -0,0 +1,77 @@
+use crate::binary::binary_client::BinaryClient;
+use crate::binary::mapper;
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::{
+    ADD_ROLE_MEMBER_CODE, CREATE_ROLE_CODE, DELETE_ROLE_CODE, GET_ROLES_CODE, GET_ROLE_CODE,
+    REMOVE_ROLE_MEMBER_CODE, UPDATE_ROLE_CODE,
+};
+use crate::error::Error;
+use crate::models::role_info::{RoleInfo, RoleInfoDetails};
+use crate::roles::add_role_member::AddRoleMember;
+use crate::roles::create_role::CreateRole;
+use crate::roles::delete_role::DeleteRole;
+use crate::roles::get_role::GetRole;
+use crate::roles::get_roles::GetRoles;
+use crate::roles::remove_role_member::RemoveRoleMember;
+use crate::roles::update_role::UpdateRole;
+
+pub async fn get_role(
+    client: &dyn BinaryClient,
+    command: &GetRole,
+) -> Result<RoleInfoDetails, Error> {
+    let response = client
+        .send_with_response(GET_ROLE_CODE, &command.as_bytes())
+        .await?;
+    mapper::map_role(&response)
+}
+
+pub async fn get_roles(
+    client: &dyn BinaryClient,
+    command: &GetRoles,
+) -> Result<Vec<RoleInfo>, Error> {
+    let response = client
+        .send_with_response(GET_ROLES_CODE, &command.as_bytes())
+        .await?;
+    mapper::map_roles(&response)
+}
+
+pub async fn create_role(client: &dyn BinaryClient, command: &CreateRole) -> Result<(), Error> {
+    client
+        .send_with_response(CREATE_ROLE_CODE, &command.as_bytes())
+        .await?;
+    Ok(())
+}
+
+pub async fn delete_role(client: &dyn BinaryClient, command: &DeleteRole) -> Result<(), Error> {
+    client
+        .send_with_response(DELETE_ROLE_CODE, &command.as_bytes())
+        .await?;
+    Ok(())
+}
+
+pub async fn update_role(client: &dyn BinaryClient, command: &UpdateRole) -> Result<(), Error> {
+    client
+        .send_with_response(UPDATE_ROLE_CODE, &command.as_bytes())
+        .await?;
+    Ok(())
+}
+
+pub async fn add_role_member(
+    client: &dyn BinaryClient,
+    command: &AddRoleMember,
+) -> Result<(), Error> {
+    client
+        .send_with_response(ADD_ROLE_MEMBER_CODE, &command.as_bytes())
+        .await?;
+    Ok(())
+}
+
+pub async fn remove_role_member(
+    client: &dyn BinaryClient,
+    command: &RemoveRoleMember,
+) -> Result<(), Error> {
+    client
+        .send_with_response(REMOVE_ROLE_MEMBER_CODE, &command.as_bytes())
+        .await?;
+    Ok(())
+}
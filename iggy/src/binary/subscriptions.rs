@@ -0,0 +1,65 @@
+use crate::binary::mapper;
+use crate::error::Error;
+use crate::models::messages::PolledMessages;
+use flume::{Receiver, Sender};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use tracing::{trace, warn};
+
+// The status reserved for the frames pushed by the server for the active subscriptions.
+pub const PUSH_STATUS: u32 = u32::MAX;
+
+#[derive(Debug, Default, Clone)]
+pub struct MessageSubscriptions {
+    senders: Arc<Mutex<HashMap<u32, Sender<PolledMessages>>>>,
+}
+
+impl MessageSubscriptions {
+    pub fn register(&self, subscription_id: u32) -> Result<Receiver<PolledMessages>, Error> {
+        let mut senders = self.senders.lock().unwrap();
+        if senders.contains_key(&subscription_id) {
+            return Err(Error::SubscriptionAlreadyExists(subscription_id));
+        }
+
+        let (sender, receiver) = flume::unbounded();
+        senders.insert(subscription_id, sender);
+        Ok(receiver)
+    }
+
+    pub fn remove(&self, subscription_id: u32) {
+        self.senders.lock().unwrap().remove(&subscription_id);
+    }
+
+    pub fn clear(&self) {
+        self.senders.lock().unwrap().clear();
+    }
+
+    pub fn dispatch(&self, payload: &[u8]) -> Result<(), Error> {
+        if payload.len() < 4 {
+            return Err(Error::InvalidCommand);
+        }
+
+        let subscription_id = u32::from_le_bytes(payload[..4].try_into()?);
+        let polled_messages = mapper::map_polled_messages(&payload[4..])?;
+        trace!(
+            "Received {} pushed messages for subscription with ID: {}",
+            polled_messages.messages.len(),
+            subscription_id
+        );
+        let mut senders = self.senders.lock().unwrap();
+        let sender = senders.get(&subscription_id);
+        if sender.is_none() {
+            warn!(
+                "Received pushed messages for unknown subscription with ID: {}",
+                subscription_id
+            );
+            return Ok(());
+        }
+
+        if sender.unwrap().send(polled_messages).is_err() {
+            senders.remove(&subscription_id);
+        }
+
+        Ok(())
+    }
+}
@@ -1,15 +1,20 @@
 use crate::binary::binary_client::BinaryClient;
 use crate::binary::mapper;
 use crate::bytes_serializable::BytesSerializable;
-use crate::command::{GET_CLIENTS_CODE, GET_CLIENT_CODE, GET_ME_CODE, GET_STATS_CODE, PING_CODE};
+use crate::command::{
+    GET_CLIENTS_CODE, GET_CLIENT_CODE, GET_ME_CODE, GET_SESSIONS_CODE, GET_STATS_CODE, PING_CODE,
+    REVOKE_SESSION_CODE,
+};
 use crate::error::Error;
 use crate::models::client_info::{ClientInfo, ClientInfoDetails};
 use crate::models::stats::Stats;
 use crate::system::get_client::GetClient;
 use crate::system::get_clients::GetClients;
 use crate::system::get_me::GetMe;
+use crate::system::get_sessions::GetSessions;
 use crate::system::get_stats::GetStats;
 use crate::system::ping::Ping;
+use crate::system::revoke_session::RevokeSession;
 
 pub async fn get_stats(client: &dyn BinaryClient, command: &GetStats) -> Result<Stats, Error> {
     let response = client
@@ -48,6 +53,26 @@ pub async fn get_clients(
     mapper::map_clients(&response)
 }
 
+pub async fn get_sessions(
+    client: &dyn BinaryClient,
+    command: &GetSessions,
+) -> Result<Vec<ClientInfo>, Error> {
+    let response = client
+        .send_with_response(GET_SESSIONS_CODE, &command.as_bytes())
+        .await?;
+    mapper::map_clients(&response)
+}
+
+pub async fn revoke_session(
+    client: &dyn BinaryClient,
+    command: &RevokeSession,
+) -> Result<(), Error> {
+    client
+        .send_with_response(REVOKE_SESSION_CODE, &command.as_bytes())
+        .await?;
+    Ok(())
+}
+
 pub async fn ping(client: &dyn BinaryClient, command: &Ping) -> Result<(), Error> {
     client
         .send_with_response(PING_CODE, &command.as_bytes())
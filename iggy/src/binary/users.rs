@@ -3,7 +3,8 @@ use crate::binary::mapper;
 use crate::bytes_serializable::BytesSerializable;
 use crate::command::{
     CHANGE_PASSWORD_CODE, CREATE_USER_CODE, DELETE_USER_CODE, GET_USERS_CODE, GET_USER_CODE,
-    LOGIN_USER_CODE, LOGOUT_USER_CODE, UPDATE_PERMISSIONS_CODE, UPDATE_USER_CODE,
+    LOGIN_USER_CODE, LOGOUT_USER_CODE, SCRAM_LOGIN_FINISH_CODE, SCRAM_LOGIN_START_CODE,
+    UNLOCK_USER_CODE, UPDATE_PERMISSIONS_CODE, UPDATE_QUOTAS_CODE, UPDATE_USER_CODE,
 };
 use crate::error::Error;
 use crate::models::user_info::{UserInfo, UserInfoDetails};
@@ -14,8 +15,13 @@ use crate::users::get_user::GetUser;
 use crate::users::get_users::GetUsers;
 use crate::users::login_user::LoginUser;
 use crate::users::logout_user::LogoutUser;
+use crate::users::scram_login_finish::ScramLoginFinish;
+use crate::users::scram_login_start::ScramLoginStart;
+use crate::users::unlock_user::UnlockUser;
 use crate::users::update_permissions::UpdatePermissions;
+use crate::users::update_quotas::UpdateQuotas;
 use crate::users::update_user::UpdateUser;
+use crate::utils::{scram, text};
 
 pub async fn get_user(
     client: &dyn BinaryClient,
@@ -51,6 +57,13 @@ pub async fn delete_user(client: &dyn BinaryClient, command: &DeleteUser) -> Res
     Ok(())
 }
 
+pub async fn unlock_user(client: &dyn BinaryClient, command: &UnlockUser) -> Result<(), Error> {
+    client
+        .send_with_response(UNLOCK_USER_CODE, &command.as_bytes())
+        .await?;
+    Ok(())
+}
+
 pub async fn update_user(client: &dyn BinaryClient, command: &UpdateUser) -> Result<(), Error> {
     client
         .send_with_response(UPDATE_USER_CODE, &command.as_bytes())
@@ -68,6 +81,13 @@ pub async fn update_permissions(
     Ok(())
 }
 
+pub async fn update_quotas(client: &dyn BinaryClient, command: &UpdateQuotas) -> Result<(), Error> {
+    client
+        .send_with_response(UPDATE_QUOTAS_CODE, &command.as_bytes())
+        .await?;
+    Ok(())
+}
+
 pub async fn change_password(
     client: &dyn BinaryClient,
     command: &ChangePassword,
@@ -78,10 +98,65 @@ pub async fn change_password(
     Ok(())
 }
 
-pub async fn login_user(client: &dyn BinaryClient, command: &LoginUser) -> Result<(), Error> {
-    client
-        .send_with_response(LOGIN_USER_CODE, &command.as_bytes())
+pub async fn login_user(
+    client: &dyn BinaryClient,
+    command: &LoginUser,
+    plaintext_login_fallback: bool,
+) -> Result<(), Error> {
+    match scram_login_user(client, command).await {
+        // The stored credentials of this user might not have been upgraded yet, so the password is sent as is.
+        // It's allowed only explicitly, as otherwise the server could downgrade the login to get the password.
+        Err(Error::InvalidResponse(status))
+            if plaintext_login_fallback && status == Error::InvalidCredentials.as_code() =>
+        {
+            client
+                .send_with_response(LOGIN_USER_CODE, &command.as_bytes())
+                .await?;
+            Ok(())
+        }
+        result => result,
+    }
+}
+
+async fn scram_login_user(client: &dyn BinaryClient, command: &LoginUser) -> Result<(), Error> {
+    let client_nonce = scram::generate_nonce();
+    let start = ScramLoginStart {
+        username: command.username.clone(),
+        client_nonce: client_nonce.clone(),
+    };
+    let response = client
+        .send_with_response(SCRAM_LOGIN_START_CODE, &start.as_bytes())
         .await?;
+    let challenge = mapper::map_scram_challenge(&response)?;
+    if !challenge.nonce.starts_with(&client_nonce) {
+        return Err(Error::InvalidScramHandshake);
+    }
+
+    // The iterations are chosen by the server, so they're limited to not let it exhaust the client.
+    if challenge.iterations == 0 || challenge.iterations > scram::MAX_SCRAM_ITERATIONS {
+        return Err(Error::InvalidScramHandshake);
+    }
+
+    let salted_password =
+        scram::salt_password(&command.password, &challenge.salt, challenge.iterations);
+    let auth_message = scram::auth_message(
+        &command.username,
+        &client_nonce,
+        &challenge.nonce,
+        &challenge.salt,
+        challenge.iterations,
+    );
+    let finish = ScramLoginFinish {
+        client_proof: text::to_base64(&scram::client_proof(&salted_password, &auth_message)),
+        nonce: challenge.nonce,
+    };
+    let server_signature = client
+        .send_with_response(SCRAM_LOGIN_FINISH_CODE, &finish.as_bytes())
+        .await?;
+    if !scram::verify_server_signature(&salted_password, &auth_message, &server_signature) {
+        return Err(Error::InvalidServerSignature);
+    }
+
     Ok(())
 }
 
@@ -1,24 +1,50 @@
 use crate::consumer_groups::create_consumer_group::CreateConsumerGroup;
 use crate::consumer_groups::delete_consumer_group::DeleteConsumerGroup;
 use crate::consumer_groups::get_consumer_group::GetConsumerGroup;
+use crate::consumer_groups::get_consumer_group_assignment::GetConsumerGroupAssignment;
 use crate::consumer_groups::get_consumer_groups::GetConsumerGroups;
+use crate::consumer_groups::heartbeat_consumer_group::HeartbeatConsumerGroup;
 use crate::consumer_groups::join_consumer_group::JoinConsumerGroup;
 use crate::consumer_groups::leave_consumer_group::LeaveConsumerGroup;
+use crate::consumer_offsets::get_consumer_lag::GetConsumerLag;
 use crate::consumer_offsets::get_consumer_offset::GetConsumerOffset;
+use crate::consumer_offsets::reset_consumer_offsets::ResetConsumerOffsets;
 use crate::consumer_offsets::store_consumer_offset::StoreConsumerOffset;
 use crate::error::Error;
+use crate::messages::grant_credits::GrantCredits;
+use crate::messages::init_producer::InitProducer;
+use crate::messages::poison_message::PoisonMessage;
 use crate::messages::poll_messages::PollMessages;
 use crate::messages::send_messages::SendMessages;
+use crate::messages::subscribe::Subscribe;
+use crate::messages::unsubscribe::Unsubscribe;
 use crate::models::client_info::{ClientInfo, ClientInfoDetails};
-use crate::models::consumer_group::{ConsumerGroup, ConsumerGroupDetails};
+use crate::models::consumer_group::{ConsumerGroup, ConsumerGroupAssignment, ConsumerGroupDetails};
+use crate::models::consumer_lag::ConsumerLag;
 use crate::models::consumer_offset_info::ConsumerOffsetInfo;
+use crate::models::consumer_offset_reset::ConsumerOffsetReset;
 use crate::models::messages::PolledMessages;
+use crate::models::personal_access_token::{PersonalAccessTokenInfo, RawPersonalAccessToken};
+use crate::models::producer_info::ProducerInfo;
+use crate::models::role_info::{RoleInfo, RoleInfoDetails};
 use crate::models::stats::Stats;
 use crate::models::stream::{Stream, StreamDetails};
 use crate::models::topic::{Topic, TopicDetails};
+use crate::models::transaction_info::TransactionInfo;
 use crate::models::user_info::{UserInfo, UserInfoDetails};
 use crate::partitions::create_partitions::CreatePartitions;
 use crate::partitions::delete_partitions::DeletePartitions;
+use crate::personal_access_tokens::create_personal_access_token::CreatePersonalAccessToken;
+use crate::personal_access_tokens::delete_personal_access_token::DeletePersonalAccessToken;
+use crate::personal_access_tokens::get_personal_access_tokens::GetPersonalAccessTokens;
+use crate::personal_access_tokens::login_with_token::LoginWithToken;
+use crate::roles::add_role_member::AddRoleMember;
+use crate::roles::create_role::CreateRole;
+use crate::roles::delete_role::DeleteRole;
+use crate::roles::get_role::GetRole;
+use crate::roles::get_roles::GetRoles;
+use crate::roles::remove_role_member::RemoveRoleMember;
+use crate::roles::update_role::UpdateRole;
 use crate::streams::create_stream::CreateStream;
 use crate::streams::delete_stream::DeleteStream;
 use crate::streams::get_stream::GetStream;
@@ -27,13 +53,18 @@ use crate::streams::update_stream::UpdateStream;
 use crate::system::get_client::GetClient;
 use crate::system::get_clients::GetClients;
 use crate::system::get_me::GetMe;
+use crate::system::get_sessions::GetSessions;
 use crate::system::get_stats::GetStats;
 use crate::system::ping::Ping;
+use crate::system::revoke_session::RevokeSession;
 use crate::topics::create_topic::CreateTopic;
 use crate::topics::delete_topic::DeleteTopic;
 use crate::topics::get_topic::GetTopic;
 use crate::topics::get_topics::GetTopics;
 use crate::topics::update_topic::UpdateTopic;
+use crate::transactions::abort_transaction::AbortTransaction;
+use crate::transactions::begin_transaction::BeginTransaction;
+use crate::transactions::commit_transaction::CommitTransaction;
 use crate::users::change_password::ChangePassword;
 use crate::users::create_user::CreateUser;
 use crate::users::delete_user::DeleteUser;
@@ -41,21 +72,27 @@ use crate::users::get_user::GetUser;
 use crate::users::get_users::GetUsers;
 use crate::users::login_user::LoginUser;
 use crate::users::logout_user::LogoutUser;
+use crate::users::unlock_user::UnlockUser;
 use crate::users::update_permissions::UpdatePermissions;
+use crate::users::update_quotas::UpdateQuotas;
 use crate::users::update_user::UpdateUser;
 use async_trait::async_trait;
+use flume::Receiver;
 use std::fmt::Debug;
 
 #[async_trait]
 pub trait Client:
     SystemClient
     + UserClient
+    + PersonalAccessTokenClient
+    + RoleClient
     + StreamClient
     + TopicClient
     + PartitionClient
     + MessageClient
     + ConsumerOffsetClient
     + ConsumerGroupClient
+    + TransactionClient
     + Sync
     + Send
     + Debug
@@ -70,6 +107,8 @@ pub trait SystemClient {
     async fn get_me(&self, command: &GetMe) -> Result<ClientInfoDetails, Error>;
     async fn get_client(&self, command: &GetClient) -> Result<ClientInfoDetails, Error>;
     async fn get_clients(&self, command: &GetClients) -> Result<Vec<ClientInfo>, Error>;
+    async fn get_sessions(&self, command: &GetSessions) -> Result<Vec<ClientInfo>, Error>;
+    async fn revoke_session(&self, command: &RevokeSession) -> Result<(), Error>;
     async fn ping(&self, command: &Ping) -> Result<(), Error>;
 }
 
@@ -79,13 +118,43 @@ pub trait UserClient {
     async fn get_users(&self, command: &GetUsers) -> Result<Vec<UserInfo>, Error>;
     async fn create_user(&self, command: &CreateUser) -> Result<(), Error>;
     async fn delete_user(&self, command: &DeleteUser) -> Result<(), Error>;
+    async fn unlock_user(&self, command: &UnlockUser) -> Result<(), Error>;
     async fn update_user(&self, command: &UpdateUser) -> Result<(), Error>;
     async fn update_permissions(&self, command: &UpdatePermissions) -> Result<(), Error>;
+    async fn update_quotas(&self, command: &UpdateQuotas) -> Result<(), Error>;
     async fn change_password(&self, command: &ChangePassword) -> Result<(), Error>;
     async fn login_user(&self, command: &LoginUser) -> Result<(), Error>;
     async fn logout_user(&self, command: &LogoutUser) -> Result<(), Error>;
 }
 
+#[async_trait]
+pub trait PersonalAccessTokenClient {
+    async fn get_personal_access_tokens(
+        &self,
+        command: &GetPersonalAccessTokens,
+    ) -> Result<Vec<PersonalAccessTokenInfo>, Error>;
+    async fn create_personal_access_token(
+        &self,
+        command: &CreatePersonalAccessToken,
+    ) -> Result<RawPersonalAccessToken, Error>;
+    async fn delete_personal_access_token(
+        &self,
+        command: &DeletePersonalAccessToken,
+    ) -> Result<(), Error>;
+    async fn login_with_token(&self, command: &LoginWithToken) -> Result<(), Error>;
+}
+
+#[async_trait]
+pub trait RoleClient {
+    async fn get_role(&self, command: &GetRole) -> Result<RoleInfoDetails, Error>;
+    async fn get_roles(&self, command: &GetRoles) -> Result<Vec<RoleInfo>, Error>;
+    async fn create_role(&self, command: &CreateRole) -> Result<(), Error>;
+    async fn delete_role(&self, command: &DeleteRole) -> Result<(), Error>;
+    async fn update_role(&self, command: &UpdateRole) -> Result<(), Error>;
+    async fn add_role_member(&self, command: &AddRoleMember) -> Result<(), Error>;
+    async fn remove_role_member(&self, command: &RemoveRoleMember) -> Result<(), Error>;
+}
+
 #[async_trait]
 pub trait StreamClient {
     async fn get_stream(&self, command: &GetStream) -> Result<StreamDetails, Error>;
@@ -114,6 +183,11 @@ pub trait PartitionClient {
 pub trait MessageClient {
     async fn poll_messages(&self, command: &PollMessages) -> Result<PolledMessages, Error>;
     async fn send_messages(&self, command: &mut SendMessages) -> Result<(), Error>;
+    async fn poison_message(&self, command: &PoisonMessage) -> Result<(), Error>;
+    async fn subscribe(&self, command: &Subscribe) -> Result<Receiver<PolledMessages>, Error>;
+    async fn grant_credits(&self, command: &GrantCredits) -> Result<(), Error>;
+    async fn unsubscribe(&self, command: &Unsubscribe) -> Result<(), Error>;
+    async fn init_producer(&self, command: &InitProducer) -> Result<ProducerInfo, Error>;
 }
 
 #[async_trait]
@@ -123,6 +197,11 @@ pub trait ConsumerOffsetClient {
         &self,
         command: &GetConsumerOffset,
     ) -> Result<ConsumerOffsetInfo, Error>;
+    async fn get_consumer_lag(&self, command: &GetConsumerLag) -> Result<Vec<ConsumerLag>, Error>;
+    async fn reset_consumer_offsets(
+        &self,
+        command: &ResetConsumerOffsets,
+    ) -> Result<Vec<ConsumerOffsetReset>, Error>;
 }
 
 #[async_trait]
@@ -139,4 +218,18 @@ pub trait ConsumerGroupClient {
     async fn delete_consumer_group(&self, command: &DeleteConsumerGroup) -> Result<(), Error>;
     async fn join_consumer_group(&self, command: &JoinConsumerGroup) -> Result<(), Error>;
     async fn leave_consumer_group(&self, command: &LeaveConsumerGroup) -> Result<(), Error>;
+    async fn heartbeat_consumer_group(&self, command: &HeartbeatConsumerGroup)
+        -> Result<(), Error>;
+    async fn get_consumer_group_assignment(
+        &self,
+        command: &GetConsumerGroupAssignment,
+    ) -> Result<ConsumerGroupAssignment, Error>;
+}
+
+#[async_trait]
+pub trait TransactionClient {
+    async fn begin_transaction(&self, command: &BeginTransaction)
+        -> Result<TransactionInfo, Error>;
+    async fn commit_transaction(&self, command: &CommitTransaction) -> Result<(), Error>;
+    async fn abort_transaction(&self, command: &AbortTransaction) -> Result<(), Error>;
 }
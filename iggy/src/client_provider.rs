@@ -58,6 +58,9 @@ impl ClientProviderConfig {
                     keep_alive_interval: args.quic_keep_alive_interval,
                     max_idle_timeout: args.quic_max_idle_timeout,
                     validate_certificate: args.quic_validate_certificate,
+                    client_certificate_file: args.quic_client_certificate_file,
+                    client_key_file: args.quic_client_key_file,
+                    plaintext_login_fallback: args.quic_plaintext_login_fallback,
                 }));
             }
             HTTP_TRANSPORT => {
@@ -73,6 +76,10 @@ impl ClientProviderConfig {
                     reconnection_interval: args.tcp_reconnection_interval,
                     tls_enabled: args.tcp_tls_enabled,
                     tls_domain: args.tcp_tls_domain,
+                    tls_certificate_file: args.tcp_tls_certificate_file,
+                    tls_key_file: args.tcp_tls_key_file,
+                    tls_ca_file: args.tcp_tls_ca_file,
+                    plaintext_login_fallback: args.tcp_plaintext_login_fallback,
                 }));
             }
             _ => return Err(ClientError::InvalidTransport(config.transport.clone())),
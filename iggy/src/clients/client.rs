@@ -1,32 +1,59 @@
 use crate::client::{
     Client, ConsumerGroupClient, ConsumerOffsetClient, MessageClient, PartitionClient,
-    StreamClient, SystemClient, TopicClient, UserClient,
+    PersonalAccessTokenClient, RoleClient, StreamClient, SystemClient, TopicClient,
+    TransactionClient, UserClient,
 };
 use crate::consumer::Consumer;
 use crate::consumer_groups::create_consumer_group::CreateConsumerGroup;
 use crate::consumer_groups::delete_consumer_group::DeleteConsumerGroup;
 use crate::consumer_groups::get_consumer_group::GetConsumerGroup;
+use crate::consumer_groups::get_consumer_group_assignment::GetConsumerGroupAssignment;
 use crate::consumer_groups::get_consumer_groups::GetConsumerGroups;
+use crate::consumer_groups::heartbeat_consumer_group::HeartbeatConsumerGroup;
 use crate::consumer_groups::join_consumer_group::JoinConsumerGroup;
 use crate::consumer_groups::leave_consumer_group::LeaveConsumerGroup;
+use crate::consumer_offsets::get_consumer_lag::GetConsumerLag;
 use crate::consumer_offsets::get_consumer_offset::GetConsumerOffset;
+use crate::consumer_offsets::reset_consumer_offsets::ResetConsumerOffsets;
 use crate::consumer_offsets::store_consumer_offset::StoreConsumerOffset;
 use crate::error::Error;
 use crate::identifier::Identifier;
 use crate::message_handler::MessageHandler;
+use crate::messages::grant_credits::GrantCredits;
+use crate::messages::init_producer::InitProducer;
+use crate::messages::poison_message::PoisonMessage;
 use crate::messages::poll_messages::{PollMessages, PollingKind};
 use crate::messages::send_messages::{Partitioning, PartitioningKind, SendMessages};
+use crate::messages::subscribe::Subscribe;
+use crate::messages::unsubscribe::Unsubscribe;
 use crate::models::client_info::{ClientInfo, ClientInfoDetails};
-use crate::models::consumer_group::{ConsumerGroup, ConsumerGroupDetails};
+use crate::models::consumer_group::{ConsumerGroup, ConsumerGroupAssignment, ConsumerGroupDetails};
+use crate::models::consumer_lag::ConsumerLag;
 use crate::models::consumer_offset_info::ConsumerOffsetInfo;
+use crate::models::consumer_offset_reset::ConsumerOffsetReset;
 use crate::models::messages::{Message, PolledMessages};
+use crate::models::personal_access_token::{PersonalAccessTokenInfo, RawPersonalAccessToken};
+use crate::models::producer_info::ProducerInfo;
+use crate::models::role_info::{RoleInfo, RoleInfoDetails};
 use crate::models::stats::Stats;
 use crate::models::stream::{Stream, StreamDetails};
 use crate::models::topic::{Topic, TopicDetails};
+use crate::models::transaction_info::TransactionInfo;
 use crate::models::user_info::{UserInfo, UserInfoDetails};
 use crate::partitioner::Partitioner;
 use crate::partitions::create_partitions::CreatePartitions;
 use crate::partitions::delete_partitions::DeletePartitions;
+use crate::personal_access_tokens::create_personal_access_token::CreatePersonalAccessToken;
+use crate::personal_access_tokens::delete_personal_access_token::DeletePersonalAccessToken;
+use crate::personal_access_tokens::get_personal_access_tokens::GetPersonalAccessTokens;
+use crate::personal_access_tokens::login_with_token::LoginWithToken;
+use crate::roles::add_role_member::AddRoleMember;
+use crate::roles::create_role::CreateRole;
+use crate::roles::delete_role::DeleteRole;
+use crate::roles::get_role::GetRole;
+use crate::roles::get_roles::GetRoles;
+use crate::roles::remove_role_member::RemoveRoleMember;
+use crate::roles::update_role::UpdateRole;
 use crate::streams::create_stream::CreateStream;
 use crate::streams::delete_stream::DeleteStream;
 use crate::streams::get_stream::GetStream;
@@ -35,14 +62,19 @@ use crate::streams::update_stream::UpdateStream;
 use crate::system::get_client::GetClient;
 use crate::system::get_clients::GetClients;
 use crate::system::get_me::GetMe;
+use crate::system::get_sessions::GetSessions;
 use crate::system::get_stats::GetStats;
 use crate::system::ping::Ping;
+use crate::system::revoke_session::RevokeSession;
 use crate::tcp::client::TcpClient;
 use crate::topics::create_topic::CreateTopic;
 use crate::topics::delete_topic::DeleteTopic;
 use crate::topics::get_topic::GetTopic;
 use crate::topics::get_topics::GetTopics;
 use crate::topics::update_topic::UpdateTopic;
+use crate::transactions::abort_transaction::AbortTransaction;
+use crate::transactions::begin_transaction::BeginTransaction;
+use crate::transactions::commit_transaction::CommitTransaction;
 use crate::users::change_password::ChangePassword;
 use crate::users::create_user::CreateUser;
 use crate::users::delete_user::DeleteUser;
@@ -50,17 +82,23 @@ use crate::users::get_user::GetUser;
 use crate::users::get_users::GetUsers;
 use crate::users::login_user::LoginUser;
 use crate::users::logout_user::LogoutUser;
+use crate::users::unlock_user::UnlockUser;
 use crate::users::update_permissions::UpdatePermissions;
+use crate::users::update_quotas::UpdateQuotas;
 use crate::users::update_user::UpdateUser;
 use crate::utils::crypto::Encryptor;
 use async_trait::async_trait;
 use bytes::Bytes;
+use flume::r#async::RecvStream;
 use flume::{Receiver, Sender};
+use futures::StreamExt;
 use std::collections::VecDeque;
 use std::fmt::Debug;
+use std::pin::Pin;
 use std::sync::Arc;
+use std::task::{Context, Poll};
 use std::time::Duration;
-use tokio::sync::{Mutex, RwLock};
+use tokio::sync::{oneshot, Mutex, RwLock};
 use tokio::task::JoinHandle;
 use tokio::time::sleep;
 use tracing::{error, info, warn};
@@ -71,7 +109,7 @@ pub struct IggyClient {
     config: Option<IggyClientConfig>,
     send_messages_batch: Option<Arc<Mutex<SendMessagesBatch>>>,
     partitioner: Option<Box<dyn Partitioner>>,
-    encryptor: Option<Box<dyn Encryptor>>,
+    encryptor: Option<Arc<Box<dyn Encryptor>>>,
     message_handler: Option<Arc<Box<dyn MessageHandler>>>,
     message_channel_sender: Option<Arc<Sender<Message>>>,
 }
@@ -100,7 +138,7 @@ impl IggyClientBuilder {
     }
 
     pub fn with_encryptor(mut self, encryptor: Box<dyn Encryptor>) -> Self {
-        self.client.encryptor = Some(encryptor);
+        self.client.encryptor = Some(Arc::new(encryptor));
         self
     }
 
@@ -119,6 +157,20 @@ struct SendMessagesBatch {
     pub commands: VecDeque<SendMessages>,
 }
 
+pub struct MessageSubscription {
+    pub subscription_id: u32,
+    messages: RecvStream<'static, Message>,
+    _cancellation: oneshot::Sender<()>,
+}
+
+impl futures::Stream for MessageSubscription {
+    type Item = Message;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.messages.poll_next_unpin(cx)
+    }
+}
+
 #[derive(Debug, Default)]
 pub struct IggyClientConfig {
     pub send_messages: SendMessagesConfig,
@@ -223,7 +275,7 @@ impl IggyClient {
             message_handler: message_handler.map(Arc::new),
             message_channel_sender: None,
             partitioner,
-            encryptor,
+            encryptor: encryptor.map(Arc::new),
         }
     }
 
@@ -233,6 +285,89 @@ impl IggyClient {
         receiver
     }
 
+    // The messages are pushed by the server as long as there are the credits available,
+    // which are granted back once the half of them has been consumed from the returned stream.
+    // Dropping the stream cancels the subscription.
+    pub async fn subscribe_to_messages(
+        &self,
+        command: Subscribe,
+    ) -> Result<MessageSubscription, Error> {
+        if command.credits == 0 {
+            return Err(Error::InvalidSubscriptionCredits);
+        }
+
+        let subscription_id = command.subscription_id;
+        let credits = command.credits;
+        let pushed_messages = self.client.read().await.subscribe(&command).await?;
+        let (sender, receiver) = flume::bounded(credits as usize);
+        let (cancellation_sender, mut cancellation_receiver) = oneshot::channel();
+        let client = self.client.clone();
+        let encryptor = self.encryptor.clone();
+        let credits_threshold = (credits / 2).max(1);
+        tokio::spawn(async move {
+            let mut consumed_credits = 0;
+            'subscription: loop {
+                let polled_messages = tokio::select! {
+                    _ = &mut cancellation_receiver => break,
+                    polled_messages = pushed_messages.recv_async() => polled_messages,
+                };
+                if polled_messages.is_err() {
+                    return;
+                }
+
+                for mut message in polled_messages.unwrap().messages {
+                    if let Some(encryptor) = &encryptor {
+                        match encryptor.decrypt(&message.payload) {
+                            Ok(payload) => message.payload = Bytes::from(payload),
+                            Err(error) => {
+                                error!("There was an error while decrypting the pushed message with offset: {}. {:?}", message.offset, error);
+                            }
+                        }
+                    }
+                    if sender.send_async(message).await.is_err() {
+                        break 'subscription;
+                    }
+
+                    consumed_credits += 1;
+                    if consumed_credits < credits_threshold {
+                        continue;
+                    }
+
+                    let result = client
+                        .read()
+                        .await
+                        .grant_credits(&GrantCredits {
+                            subscription_id,
+                            credits: consumed_credits,
+                        })
+                        .await;
+                    if let Err(error) = result {
+                        error!(
+                            "There was an error while granting the subscription credits: {:?}",
+                            error
+                        );
+                    }
+                    consumed_credits = 0;
+                }
+            }
+
+            let result = client
+                .read()
+                .await
+                .unsubscribe(&Unsubscribe { subscription_id })
+                .await;
+            if let Err(error) = result {
+                error!("There was an error while unsubscribing: {:?}", error);
+            }
+        });
+
+        Ok(MessageSubscription {
+            subscription_id,
+            messages: receiver.into_stream(),
+            _cancellation: cancellation_sender,
+        })
+    }
+
     pub fn start_polling_messages<F>(
         &self,
         mut poll_messages: PollMessages,
@@ -275,18 +410,43 @@ impl IggyClient {
             }
         }
 
+        // With the long polling, the server waits for the new messages, so there's no need to sleep between the polls.
+        let long_polling = poll_messages.max_wait_ms.is_some();
         tokio::spawn(async move {
             loop {
-                sleep(interval).await;
+                if !long_polling {
+                    sleep(interval).await;
+                }
                 let client = client.read().await;
                 let polled_messages = client.poll_messages(&poll_messages).await;
                 if let Err(error) = polled_messages {
                     error!("There was an error while polling messages: {:?}", error);
+                    if long_polling {
+                        sleep(interval).await;
+                    }
                     continue;
                 }
 
-                let messages = polled_messages.unwrap().messages;
+                let polled_messages = polled_messages.unwrap();
+                // The messages not matching the header filters are skipped, so the polling continues from the scanned offset.
+                let scanned_offset = polled_messages.scanned_offset;
+                let generation_id = polled_messages.generation_id;
+                let messages = polled_messages.messages;
                 if messages.is_empty() {
+                    if let Some(scanned_offset) = scanned_offset {
+                        if store_offset_when_messages_are_processed {
+                            Self::store_offset(
+                                client.as_ref(),
+                                &poll_messages,
+                                scanned_offset,
+                                generation_id,
+                            )
+                            .await;
+                        }
+                        if poll_messages.strategy.kind == PollingKind::Offset {
+                            poll_messages.strategy.value = scanned_offset + 1;
+                        }
+                    }
                     continue;
                 }
 
@@ -306,12 +466,25 @@ impl IggyClient {
                         warn!("Received a message with ID: {} at offset: {} which won't be processed. Consider providing the custom `MessageHandler` trait implementation or `on_message` closure.", message.id, message.offset);
                     }
                     if store_offset_after_processing_each_message {
-                        Self::store_offset(client.as_ref(), &poll_messages, current_offset).await;
+                        Self::store_offset(
+                            client.as_ref(),
+                            &poll_messages,
+                            current_offset,
+                            generation_id,
+                        )
+                        .await;
                     }
                 }
 
+                let current_offset = scanned_offset.unwrap_or(current_offset);
                 if store_offset_when_messages_are_processed {
-                    Self::store_offset(client.as_ref(), &poll_messages, current_offset).await;
+                    Self::store_offset(
+                        client.as_ref(),
+                        &poll_messages,
+                        current_offset,
+                        generation_id,
+                    )
+                    .await;
                 }
 
                 if poll_messages.strategy.kind == PollingKind::Offset {
@@ -336,7 +509,12 @@ impl IggyClient {
         self.send_messages(command).await
     }
 
-    async fn store_offset(client: &dyn Client, poll_messages: &PollMessages, offset: u64) {
+    async fn store_offset(
+        client: &dyn Client,
+        poll_messages: &PollMessages,
+        offset: u64,
+        generation_id: Option<u32>,
+    ) {
         let result = client
             .store_consumer_offset(&StoreConsumerOffset {
                 consumer: Consumer::from_consumer(&poll_messages.consumer),
@@ -344,6 +522,8 @@ impl IggyClient {
                 topic_id: Identifier::from_identifier(&poll_messages.topic_id),
                 partition_id: poll_messages.partition_id,
                 offset,
+                transaction_id: None,
+                generation_id,
             })
             .await;
         if let Err(error) = result {
@@ -430,6 +610,8 @@ impl IggyClient {
                             length: 4,
                             value: key.value.clone(),
                         },
+                        producer: None,
+                        transaction_id: None,
                         messages,
                     };
 
@@ -470,6 +652,10 @@ impl UserClient for IggyClient {
         self.client.read().await.delete_user(command).await
     }
 
+    async fn unlock_user(&self, command: &UnlockUser) -> Result<(), Error> {
+        self.client.read().await.unlock_user(command).await
+    }
+
     async fn update_user(&self, command: &UpdateUser) -> Result<(), Error> {
         self.client.read().await.update_user(command).await
     }
@@ -478,6 +664,10 @@ impl UserClient for IggyClient {
         self.client.read().await.update_permissions(command).await
     }
 
+    async fn update_quotas(&self, command: &UpdateQuotas) -> Result<(), Error> {
+        self.client.read().await.update_quotas(command).await
+    }
+
     async fn change_password(&self, command: &ChangePassword) -> Result<(), Error> {
         self.client.read().await.change_password(command).await
     }
@@ -491,6 +681,46 @@ impl UserClient for IggyClient {
     }
 }
 
+#[async_trait]
+impl PersonalAccessTokenClient for IggyClient {
+    async fn get_personal_access_tokens(
+        &self,
+        command: &GetPersonalAccessTokens,
+    ) -> Result<Vec<PersonalAccessTokenInfo>, Error> {
+        self.client
+            .read()
+            .await
+            .get_personal_access_tokens(command)
+            .await
+    }
+
+    async fn create_personal_access_token(
+        &self,
+        command: &CreatePersonalAccessToken,
+    ) -> Result<RawPersonalAccessToken, Error> {
+        self.client
+            .read()
+            .await
+            .create_personal_access_token(command)
+            .await
+    }
+
+    async fn delete_personal_access_token(
+        &self,
+        command: &DeletePersonalAccessToken,
+    ) -> Result<(), Error> {
+        self.client
+            .read()
+            .await
+            .delete_personal_access_token(command)
+            .await
+    }
+
+    async fn login_with_token(&self, command: &LoginWithToken) -> Result<(), Error> {
+        self.client.read().await.login_with_token(command).await
+    }
+}
+
 #[async_trait]
 impl Client for IggyClient {
     async fn connect(&mut self) -> Result<(), Error> {
@@ -520,11 +750,50 @@ impl SystemClient for IggyClient {
         self.client.read().await.get_clients(command).await
     }
 
+    async fn get_sessions(&self, command: &GetSessions) -> Result<Vec<ClientInfo>, Error> {
+        self.client.read().await.get_sessions(command).await
+    }
+
+    async fn revoke_session(&self, command: &RevokeSession) -> Result<(), Error> {
+        self.client.read().await.revoke_session(command).await
+    }
+
     async fn ping(&self, command: &Ping) -> Result<(), Error> {
         self.client.read().await.ping(command).await
     }
 }
 
+#[async_trait]
+impl RoleClient for IggyClient {
+    async fn get_role(&self, command: &GetRole) -> Result<RoleInfoDetails, Error> {
+        self.client.read().await.get_role(command).await
+    }
+
+    async fn get_roles(&self, command: &GetRoles) -> Result<Vec<RoleInfo>, Error> {
+        self.client.read().await.get_roles(command).await
+    }
+
+    async fn create_role(&self, command: &CreateRole) -> Result<(), Error> {
+        self.client.read().await.create_role(command).await
+    }
+
+    async fn delete_role(&self, command: &DeleteRole) -> Result<(), Error> {
+        self.client.read().await.delete_role(command).await
+    }
+
+    async fn update_role(&self, command: &UpdateRole) -> Result<(), Error> {
+        self.client.read().await.update_role(command).await
+    }
+
+    async fn add_role_member(&self, command: &AddRoleMember) -> Result<(), Error> {
+        self.client.read().await.add_role_member(command).await
+    }
+
+    async fn remove_role_member(&self, command: &RemoveRoleMember) -> Result<(), Error> {
+        self.client.read().await.remove_role_member(command).await
+    }
+}
+
 #[async_trait]
 impl StreamClient for IggyClient {
     async fn get_stream(&self, command: &GetStream) -> Result<StreamDetails, Error> {
@@ -617,7 +886,10 @@ impl MessageClient for IggyClient {
             }
         }
 
+        // The producer sequence and the transaction apply to the whole batch, so it cannot be merged with the other ones.
         let send_messages_now = self.send_messages_batch.is_none()
+            || command.producer.is_some()
+            || command.transaction_id.is_some()
             || match &self.config {
                 Some(config) => !config.send_messages.enabled || config.send_messages.interval == 0,
                 None => true,
@@ -641,6 +913,8 @@ impl MessageClient for IggyClient {
             stream_id: Identifier::from_identifier(&command.stream_id),
             topic_id: Identifier::from_identifier(&command.topic_id),
             partitioning: Partitioning::from_partitioning(&command.partitioning),
+            producer: None,
+            transaction_id: None,
             messages,
         };
 
@@ -648,6 +922,26 @@ impl MessageClient for IggyClient {
         batch.commands.push_back(send_messages);
         Ok(())
     }
+
+    async fn poison_message(&self, command: &PoisonMessage) -> Result<(), Error> {
+        self.client.read().await.poison_message(command).await
+    }
+
+    async fn subscribe(&self, command: &Subscribe) -> Result<Receiver<PolledMessages>, Error> {
+        self.client.read().await.subscribe(command).await
+    }
+
+    async fn grant_credits(&self, command: &GrantCredits) -> Result<(), Error> {
+        self.client.read().await.grant_credits(command).await
+    }
+
+    async fn unsubscribe(&self, command: &Unsubscribe) -> Result<(), Error> {
+        self.client.read().await.unsubscribe(command).await
+    }
+
+    async fn init_producer(&self, command: &InitProducer) -> Result<ProducerInfo, Error> {
+        self.client.read().await.init_producer(command).await
+    }
 }
 
 #[async_trait]
@@ -666,6 +960,39 @@ impl ConsumerOffsetClient for IggyClient {
     ) -> Result<ConsumerOffsetInfo, Error> {
         self.client.read().await.get_consumer_offset(command).await
     }
+
+    async fn get_consumer_lag(&self, command: &GetConsumerLag) -> Result<Vec<ConsumerLag>, Error> {
+        self.client.read().await.get_consumer_lag(command).await
+    }
+
+    async fn reset_consumer_offsets(
+        &self,
+        command: &ResetConsumerOffsets,
+    ) -> Result<Vec<ConsumerOffsetReset>, Error> {
+        self.client
+            .read()
+            .await
+            .reset_consumer_offsets(command)
+            .await
+    }
+}
+
+#[async_trait]
+impl TransactionClient for IggyClient {
+    async fn begin_transaction(
+        &self,
+        command: &BeginTransaction,
+    ) -> Result<TransactionInfo, Error> {
+        self.client.read().await.begin_transaction(command).await
+    }
+
+    async fn commit_transaction(&self, command: &CommitTransaction) -> Result<(), Error> {
+        self.client.read().await.commit_transaction(command).await
+    }
+
+    async fn abort_transaction(&self, command: &AbortTransaction) -> Result<(), Error> {
+        self.client.read().await.abort_transaction(command).await
+    }
 }
 
 #[async_trait]
@@ -707,4 +1034,26 @@ impl ConsumerGroupClient for IggyClient {
     async fn leave_consumer_group(&self, command: &LeaveConsumerGroup) -> Result<(), Error> {
         self.client.read().await.leave_consumer_group(command).await
     }
+
+    async fn heartbeat_consumer_group(
+        &self,
+        command: &HeartbeatConsumerGroup,
+    ) -> Result<(), Error> {
+        self.client
+            .read()
+            .await
+            .heartbeat_consumer_group(command)
+            .await
+    }
+
+    async fn get_consumer_group_assignment(
+        &self,
+        command: &GetConsumerGroupAssignment,
+    ) -> Result<ConsumerGroupAssignment, Error> {
+        self.client
+            .read()
+            .await
+            .get_consumer_group_assignment(command)
+            .await
+    }
 }
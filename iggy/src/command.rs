@@ -2,16 +2,36 @@ use crate::bytes_serializable::BytesSerializable;
 use crate::consumer_groups::create_consumer_group::CreateConsumerGroup;
 use crate::consumer_groups::delete_consumer_group::DeleteConsumerGroup;
 use crate::consumer_groups::get_consumer_group::GetConsumerGroup;
+use crate::consumer_groups::get_consumer_group_assignment::GetConsumerGroupAssignment;
 use crate::consumer_groups::get_consumer_groups::GetConsumerGroups;
+use crate::consumer_groups::heartbeat_consumer_group::HeartbeatConsumerGroup;
 use crate::consumer_groups::join_consumer_group::JoinConsumerGroup;
 use crate::consumer_groups::leave_consumer_group::LeaveConsumerGroup;
+use crate::consumer_offsets::get_consumer_lag::GetConsumerLag;
 use crate::consumer_offsets::get_consumer_offset::GetConsumerOffset;
+use crate::consumer_offsets::reset_consumer_offsets::ResetConsumerOffsets;
 use crate::consumer_offsets::store_consumer_offset::StoreConsumerOffset;
 use crate::error::Error;
+use crate::messages::grant_credits::GrantCredits;
+use crate::messages::init_producer::InitProducer;
+use crate::messages::poison_message::PoisonMessage;
 use crate::messages::poll_messages::PollMessages;
 use crate::messages::send_messages::SendMessages;
+use crate::messages::subscribe::Subscribe;
+use crate::messages::unsubscribe::Unsubscribe;
 use crate::partitions::create_partitions::CreatePartitions;
 use crate::partitions::delete_partitions::DeletePartitions;
+use crate::personal_access_tokens::create_personal_access_token::CreatePersonalAccessToken;
+use crate::personal_access_tokens::delete_personal_access_token::DeletePersonalAccessToken;
+use crate::personal_access_tokens::get_personal_access_tokens::GetPersonalAccessTokens;
+use crate::personal_access_tokens::login_with_token::LoginWithToken;
+use crate::roles::add_role_member::AddRoleMember;
+use crate::roles::create_role::CreateRole;
+use crate::roles::delete_role::DeleteRole;
+use crate::roles::get_role::GetRole;
+use crate::roles::get_roles::GetRoles;
+use crate::roles::remove_role_member::RemoveRoleMember;
+use crate::roles::update_role::UpdateRole;
 use crate::streams::create_stream::CreateStream;
 use crate::streams::delete_stream::DeleteStream;
 use crate::streams::get_stream::GetStream;
@@ -20,13 +40,18 @@ use crate::streams::update_stream::UpdateStream;
 use crate::system::get_client::GetClient;
 use crate::system::get_clients::GetClients;
 use crate::system::get_me::GetMe;
+use crate::system::get_sessions::GetSessions;
 use crate::system::get_stats::GetStats;
 use crate::system::ping::Ping;
+use crate::system::revoke_session::RevokeSession;
 use crate::topics::create_topic::CreateTopic;
 use crate::topics::delete_topic::DeleteTopic;
 use crate::topics::get_topic::GetTopic;
 use crate::topics::get_topics::GetTopics;
 use crate::topics::update_topic::UpdateTopic;
+use crate::transactions::abort_transaction::AbortTransaction;
+use crate::transactions::begin_transaction::BeginTransaction;
+use crate::transactions::commit_transaction::CommitTransaction;
 use crate::users::change_password::ChangePassword;
 use crate::users::create_user::CreateUser;
 use crate::users::delete_user::DeleteUser;
@@ -34,7 +59,11 @@ use crate::users::get_user::GetUser;
 use crate::users::get_users::GetUsers;
 use crate::users::login_user::LoginUser;
 use crate::users::logout_user::LogoutUser;
+use crate::users::scram_login_finish::ScramLoginFinish;
+use crate::users::scram_login_start::ScramLoginStart;
+use crate::users::unlock_user::UnlockUser;
 use crate::users::update_permissions::UpdatePermissions;
+use crate::users::update_quotas::UpdateQuotas;
 use crate::users::update_user::UpdateUser;
 use bytes::BufMut;
 use std::fmt::{Display, Formatter};
@@ -50,6 +79,10 @@ pub const GET_CLIENT: &str = "client.get";
 pub const GET_CLIENT_CODE: u32 = 21;
 pub const GET_CLIENTS: &str = "client.list";
 pub const GET_CLIENTS_CODE: u32 = 22;
+pub const GET_SESSIONS: &str = "session.list";
+pub const GET_SESSIONS_CODE: u32 = 23;
+pub const REVOKE_SESSION: &str = "session.revoke";
+pub const REVOKE_SESSION_CODE: u32 = 24;
 pub const GET_USER: &str = "user.get";
 pub const GET_USER_CODE: u32 = 31;
 pub const GET_USERS: &str = "user.list";
@@ -62,20 +95,64 @@ pub const UPDATE_USER: &str = "user.update";
 pub const UPDATE_USER_CODE: u32 = 35;
 pub const UPDATE_PERMISSIONS: &str = "user.permissions";
 pub const UPDATE_PERMISSIONS_CODE: u32 = 36;
+pub const UPDATE_QUOTAS: &str = "user.quotas";
+pub const UPDATE_QUOTAS_CODE: u32 = 40;
 pub const CHANGE_PASSWORD: &str = "user.password";
 pub const CHANGE_PASSWORD_CODE: u32 = 37;
 pub const LOGIN_USER: &str = "user.login";
 pub const LOGIN_USER_CODE: u32 = 38;
 pub const LOGOUT_USER: &str = "user.logout";
 pub const LOGOUT_USER_CODE: u32 = 39;
+pub const SCRAM_LOGIN_START: &str = "user.scram_login_start";
+pub const SCRAM_LOGIN_START_CODE: u32 = 45;
+pub const SCRAM_LOGIN_FINISH: &str = "user.scram_login_finish";
+pub const SCRAM_LOGIN_FINISH_CODE: u32 = 46;
+pub const UNLOCK_USER: &str = "user.unlock";
+pub const UNLOCK_USER_CODE: u32 = 47;
+pub const GET_PERSONAL_ACCESS_TOKENS: &str = "personal_access_token.list";
+pub const GET_PERSONAL_ACCESS_TOKENS_CODE: u32 = 41;
+pub const CREATE_PERSONAL_ACCESS_TOKEN: &str = "personal_access_token.create";
+pub const CREATE_PERSONAL_ACCESS_TOKEN_CODE: u32 = 42;
+pub const DELETE_PERSONAL_ACCESS_TOKEN: &str = "personal_access_token.delete";
+pub const DELETE_PERSONAL_ACCESS_TOKEN_CODE: u32 = 43;
+pub const LOGIN_WITH_TOKEN: &str = "personal_access_token.login";
+pub const LOGIN_WITH_TOKEN_CODE: u32 = 44;
+pub const GET_ROLE: &str = "role.get";
+pub const GET_ROLE_CODE: u32 = 51;
+pub const GET_ROLES: &str = "role.list";
+pub const GET_ROLES_CODE: u32 = 52;
+pub const CREATE_ROLE: &str = "role.create";
+pub const CREATE_ROLE_CODE: u32 = 53;
+pub const DELETE_ROLE: &str = "role.delete";
+pub const DELETE_ROLE_CODE: u32 = 54;
+pub const UPDATE_ROLE: &str = "role.update";
+pub const UPDATE_ROLE_CODE: u32 = 55;
+pub const ADD_ROLE_MEMBER: &str = "role.add_member";
+pub const ADD_ROLE_MEMBER_CODE: u32 = 56;
+pub const REMOVE_ROLE_MEMBER: &str = "role.remove_member";
+pub const REMOVE_ROLE_MEMBER_CODE: u32 = 57;
 pub const POLL_MESSAGES: &str = "message.poll";
 pub const POLL_MESSAGES_CODE: u32 = 100;
 pub const SEND_MESSAGES: &str = "message.send";
 pub const SEND_MESSAGES_CODE: u32 = 101;
+pub const POISON_MESSAGE: &str = "message.poison";
+pub const POISON_MESSAGE_CODE: u32 = 102;
+pub const SUBSCRIBE: &str = "message.subscribe";
+pub const SUBSCRIBE_CODE: u32 = 103;
+pub const UNSUBSCRIBE: &str = "message.unsubscribe";
+pub const UNSUBSCRIBE_CODE: u32 = 104;
+pub const GRANT_CREDITS: &str = "message.grant_credits";
+pub const GRANT_CREDITS_CODE: u32 = 105;
+pub const INIT_PRODUCER: &str = "message.init_producer";
+pub const INIT_PRODUCER_CODE: u32 = 106;
 pub const GET_CONSUMER_OFFSET: &str = "consumer_offset.get";
 pub const GET_CONSUMER_OFFSET_CODE: u32 = 120;
 pub const STORE_CONSUMER_OFFSET: &str = "consumer_offset.store";
 pub const STORE_CONSUMER_OFFSET_CODE: u32 = 121;
+pub const GET_CONSUMER_LAG: &str = "consumer_offset.lag";
+pub const GET_CONSUMER_LAG_CODE: u32 = 122;
+pub const RESET_CONSUMER_OFFSETS: &str = "consumer_offset.reset";
+pub const RESET_CONSUMER_OFFSETS_CODE: u32 = 123;
 pub const GET_STREAM: &str = "stream.get";
 pub const GET_STREAM_CODE: u32 = 200;
 pub const GET_STREAMS: &str = "stream.list";
@@ -112,6 +189,16 @@ pub const JOIN_CONSUMER_GROUP: &str = "consumer_group.join";
 pub const JOIN_CONSUMER_GROUP_CODE: u32 = 604;
 pub const LEAVE_CONSUMER_GROUP: &str = "consumer_group.leave";
 pub const LEAVE_CONSUMER_GROUP_CODE: u32 = 605;
+pub const HEARTBEAT_CONSUMER_GROUP: &str = "consumer_group.heartbeat";
+pub const HEARTBEAT_CONSUMER_GROUP_CODE: u32 = 606;
+pub const GET_CONSUMER_GROUP_ASSIGNMENT: &str = "consumer_group.assignment";
+pub const GET_CONSUMER_GROUP_ASSIGNMENT_CODE: u32 = 607;
+pub const BEGIN_TRANSACTION: &str = "transaction.begin";
+pub const BEGIN_TRANSACTION_CODE: u32 = 700;
+pub const COMMIT_TRANSACTION: &str = "transaction.commit";
+pub const COMMIT_TRANSACTION_CODE: u32 = 701;
+pub const ABORT_TRANSACTION: &str = "transaction.abort";
+pub const ABORT_TRANSACTION_CODE: u32 = 702;
 
 #[derive(Debug, PartialEq)]
 pub enum Command {
@@ -120,19 +207,43 @@ pub enum Command {
     GetMe(GetMe),
     GetClient(GetClient),
     GetClients(GetClients),
+    GetSessions(GetSessions),
+    RevokeSession(RevokeSession),
     GetUser(GetUser),
     GetUsers(GetUsers),
     CreateUser(CreateUser),
     DeleteUser(DeleteUser),
     UpdateUser(UpdateUser),
     UpdatePermissions(UpdatePermissions),
+    UpdateQuotas(UpdateQuotas),
     ChangePassword(ChangePassword),
     LoginUser(LoginUser),
     LogoutUser(LogoutUser),
+    ScramLoginStart(ScramLoginStart),
+    ScramLoginFinish(ScramLoginFinish),
+    UnlockUser(UnlockUser),
+    GetPersonalAccessTokens(GetPersonalAccessTokens),
+    CreatePersonalAccessToken(CreatePersonalAccessToken),
+    DeletePersonalAccessToken(DeletePersonalAccessToken),
+    LoginWithToken(LoginWithToken),
+    GetRole(GetRole),
+    GetRoles(GetRoles),
+    CreateRole(CreateRole),
+    DeleteRole(DeleteRole),
+    UpdateRole(UpdateRole),
+    AddRoleMember(AddRoleMember),
+    RemoveRoleMember(RemoveRoleMember),
     SendMessages(SendMessages),
     PollMessages(PollMessages),
+    PoisonMessage(PoisonMessage),
+    Subscribe(Subscribe),
+    Unsubscribe(Unsubscribe),
+    GrantCredits(GrantCredits),
+    InitProducer(InitProducer),
     GetConsumerOffset(GetConsumerOffset),
     StoreConsumerOffset(StoreConsumerOffset),
+    GetConsumerLag(GetConsumerLag),
+    ResetConsumerOffsets(ResetConsumerOffsets),
     GetStream(GetStream),
     GetStreams(GetStreams),
     CreateStream(CreateStream),
@@ -151,6 +262,11 @@ pub enum Command {
     DeleteConsumerGroup(DeleteConsumerGroup),
     JoinConsumerGroup(JoinConsumerGroup),
     LeaveConsumerGroup(LeaveConsumerGroup),
+    HeartbeatConsumerGroup(HeartbeatConsumerGroup),
+    GetConsumerGroupAssignment(GetConsumerGroupAssignment),
+    BeginTransaction(BeginTransaction),
+    CommitTransaction(CommitTransaction),
+    AbortTransaction(AbortTransaction),
 }
 
 pub trait CommandPayload: BytesSerializable + Display {}
@@ -163,6 +279,8 @@ impl BytesSerializable for Command {
             Command::GetMe(payload) => as_bytes(GET_ME_CODE, &payload.as_bytes()),
             Command::GetClient(payload) => as_bytes(GET_CLIENT_CODE, &payload.as_bytes()),
             Command::GetClients(payload) => as_bytes(GET_CLIENTS_CODE, &payload.as_bytes()),
+            Command::GetSessions(payload) => as_bytes(GET_SESSIONS_CODE, &payload.as_bytes()),
+            Command::RevokeSession(payload) => as_bytes(REVOKE_SESSION_CODE, &payload.as_bytes()),
             Command::GetUser(payload) => as_bytes(GET_USER_CODE, &payload.as_bytes()),
             Command::GetUsers(payload) => as_bytes(GET_USERS_CODE, &payload.as_bytes()),
             Command::CreateUser(payload) => as_bytes(CREATE_USER_CODE, &payload.as_bytes()),
@@ -171,17 +289,57 @@ impl BytesSerializable for Command {
             Command::UpdatePermissions(payload) => {
                 as_bytes(UPDATE_PERMISSIONS_CODE, &payload.as_bytes())
             }
+            Command::UpdateQuotas(payload) => as_bytes(UPDATE_QUOTAS_CODE, &payload.as_bytes()),
             Command::ChangePassword(payload) => as_bytes(CHANGE_PASSWORD_CODE, &payload.as_bytes()),
             Command::LoginUser(payload) => as_bytes(LOGIN_USER_CODE, &payload.as_bytes()),
             Command::LogoutUser(payload) => as_bytes(LOGOUT_USER_CODE, &payload.as_bytes()),
+            Command::ScramLoginStart(payload) => {
+                as_bytes(SCRAM_LOGIN_START_CODE, &payload.as_bytes())
+            }
+            Command::ScramLoginFinish(payload) => {
+                as_bytes(SCRAM_LOGIN_FINISH_CODE, &payload.as_bytes())
+            }
+            Command::UnlockUser(payload) => as_bytes(UNLOCK_USER_CODE, &payload.as_bytes()),
+            Command::GetPersonalAccessTokens(payload) => {
+                as_bytes(GET_PERSONAL_ACCESS_TOKENS_CODE, &payload.as_bytes())
+            }
+            Command::CreatePersonalAccessToken(payload) => {
+                as_bytes(CREATE_PERSONAL_ACCESS_TOKEN_CODE, &payload.as_bytes())
+            }
+            Command::DeletePersonalAccessToken(payload) => {
+                as_bytes(DELETE_PERSONAL_ACCESS_TOKEN_CODE, &payload.as_bytes())
+            }
+            Command::LoginWithToken(payload) => {
+                as_bytes(LOGIN_WITH_TOKEN_CODE, &payload.as_bytes())
+            }
+            Command::GetRole(payload) => as_bytes(GET_ROLE_CODE, &payload.as_bytes()),
+            Command::GetRoles(payload) => as_bytes(GET_ROLES_CODE, &payload.as_bytes()),
+            Command::CreateRole(payload) => as_bytes(CREATE_ROLE_CODE, &payload.as_bytes()),
+            Command::DeleteRole(payload) => as_bytes(DELETE_ROLE_CODE, &payload.as_bytes()),
+            Command::UpdateRole(payload) => as_bytes(UPDATE_ROLE_CODE, &payload.as_bytes()),
+            Command::AddRoleMember(payload) => as_bytes(ADD_ROLE_MEMBER_CODE, &payload.as_bytes()),
+            Command::RemoveRoleMember(payload) => {
+                as_bytes(REMOVE_ROLE_MEMBER_CODE, &payload.as_bytes())
+            }
             Command::SendMessages(payload) => as_bytes(SEND_MESSAGES_CODE, &payload.as_bytes()),
             Command::PollMessages(payload) => as_bytes(POLL_MESSAGES_CODE, &payload.as_bytes()),
+            Command::PoisonMessage(payload) => as_bytes(POISON_MESSAGE_CODE, &payload.as_bytes()),
+            Command::Subscribe(payload) => as_bytes(SUBSCRIBE_CODE, &payload.as_bytes()),
+            Command::Unsubscribe(payload) => as_bytes(UNSUBSCRIBE_CODE, &payload.as_bytes()),
+            Command::GrantCredits(payload) => as_bytes(GRANT_CREDITS_CODE, &payload.as_bytes()),
+            Command::InitProducer(payload) => as_bytes(INIT_PRODUCER_CODE, &payload.as_bytes()),
             Command::StoreConsumerOffset(payload) => {
                 as_bytes(STORE_CONSUMER_OFFSET_CODE, &payload.as_bytes())
             }
             Command::GetConsumerOffset(payload) => {
                 as_bytes(GET_CONSUMER_OFFSET_CODE, &payload.as_bytes())
             }
+            Command::GetConsumerLag(payload) => {
+                as_bytes(GET_CONSUMER_LAG_CODE, &payload.as_bytes())
+            }
+            Command::ResetConsumerOffsets(payload) => {
+                as_bytes(RESET_CONSUMER_OFFSETS_CODE, &payload.as_bytes())
+            }
             Command::GetStream(payload) => as_bytes(GET_STREAM_CODE, &payload.as_bytes()),
             Command::GetStreams(payload) => as_bytes(GET_STREAMS_CODE, &payload.as_bytes()),
             Command::CreateStream(payload) => as_bytes(CREATE_STREAM_CODE, &payload.as_bytes()),
@@ -216,6 +374,21 @@ impl BytesSerializable for Command {
             Command::LeaveConsumerGroup(payload) => {
                 as_bytes(LEAVE_CONSUMER_GROUP_CODE, &payload.as_bytes())
             }
+            Command::HeartbeatConsumerGroup(payload) => {
+                as_bytes(HEARTBEAT_CONSUMER_GROUP_CODE, &payload.as_bytes())
+            }
+            Command::GetConsumerGroupAssignment(payload) => {
+                as_bytes(GET_CONSUMER_GROUP_ASSIGNMENT_CODE, &payload.as_bytes())
+            }
+            Command::BeginTransaction(payload) => {
+                as_bytes(BEGIN_TRANSACTION_CODE, &payload.as_bytes())
+            }
+            Command::CommitTransaction(payload) => {
+                as_bytes(COMMIT_TRANSACTION_CODE, &payload.as_bytes())
+            }
+            Command::AbortTransaction(payload) => {
+                as_bytes(ABORT_TRANSACTION_CODE, &payload.as_bytes())
+            }
         }
     }
 
@@ -228,6 +401,8 @@ impl BytesSerializable for Command {
             GET_ME_CODE => Ok(Command::GetMe(GetMe::from_bytes(payload)?)),
             GET_CLIENT_CODE => Ok(Command::GetClient(GetClient::from_bytes(payload)?)),
             GET_CLIENTS_CODE => Ok(Command::GetClients(GetClients::from_bytes(payload)?)),
+            GET_SESSIONS_CODE => Ok(Command::GetSessions(GetSessions::from_bytes(payload)?)),
+            REVOKE_SESSION_CODE => Ok(Command::RevokeSession(RevokeSession::from_bytes(payload)?)),
             GET_USER_CODE => Ok(Command::GetUser(GetUser::from_bytes(payload)?)),
             GET_USERS_CODE => Ok(Command::GetUsers(GetUsers::from_bytes(payload)?)),
             CREATE_USER_CODE => Ok(Command::CreateUser(CreateUser::from_bytes(payload)?)),
@@ -236,19 +411,59 @@ impl BytesSerializable for Command {
             UPDATE_PERMISSIONS_CODE => Ok(Command::UpdatePermissions(
                 UpdatePermissions::from_bytes(payload)?,
             )),
+            UPDATE_QUOTAS_CODE => Ok(Command::UpdateQuotas(UpdateQuotas::from_bytes(payload)?)),
             CHANGE_PASSWORD_CODE => Ok(Command::ChangePassword(ChangePassword::from_bytes(
                 payload,
             )?)),
             LOGIN_USER_CODE => Ok(Command::LoginUser(LoginUser::from_bytes(payload)?)),
             LOGOUT_USER_CODE => Ok(Command::LogoutUser(LogoutUser::from_bytes(payload)?)),
+            SCRAM_LOGIN_START_CODE => Ok(Command::ScramLoginStart(ScramLoginStart::from_bytes(
+                payload,
+            )?)),
+            SCRAM_LOGIN_FINISH_CODE => Ok(Command::ScramLoginFinish(ScramLoginFinish::from_bytes(
+                payload,
+            )?)),
+            UNLOCK_USER_CODE => Ok(Command::UnlockUser(UnlockUser::from_bytes(payload)?)),
+            GET_PERSONAL_ACCESS_TOKENS_CODE => Ok(Command::GetPersonalAccessTokens(
+                GetPersonalAccessTokens::from_bytes(payload)?,
+            )),
+            CREATE_PERSONAL_ACCESS_TOKEN_CODE => Ok(Command::CreatePersonalAccessToken(
+                CreatePersonalAccessToken::from_bytes(payload)?,
+            )),
+            DELETE_PERSONAL_ACCESS_TOKEN_CODE => Ok(Command::DeletePersonalAccessToken(
+                DeletePersonalAccessToken::from_bytes(payload)?,
+            )),
+            LOGIN_WITH_TOKEN_CODE => Ok(Command::LoginWithToken(LoginWithToken::from_bytes(
+                payload,
+            )?)),
+            GET_ROLE_CODE => Ok(Command::GetRole(GetRole::from_bytes(payload)?)),
+            GET_ROLES_CODE => Ok(Command::GetRoles(GetRoles::from_bytes(payload)?)),
+            CREATE_ROLE_CODE => Ok(Command::CreateRole(CreateRole::from_bytes(payload)?)),
+            DELETE_ROLE_CODE => Ok(Command::DeleteRole(DeleteRole::from_bytes(payload)?)),
+            UPDATE_ROLE_CODE => Ok(Command::UpdateRole(UpdateRole::from_bytes(payload)?)),
+            ADD_ROLE_MEMBER_CODE => Ok(Command::AddRoleMember(AddRoleMember::from_bytes(payload)?)),
+            REMOVE_ROLE_MEMBER_CODE => Ok(Command::RemoveRoleMember(RemoveRoleMember::from_bytes(
+                payload,
+            )?)),
             SEND_MESSAGES_CODE => Ok(Command::SendMessages(SendMessages::from_bytes(payload)?)),
             POLL_MESSAGES_CODE => Ok(Command::PollMessages(PollMessages::from_bytes(payload)?)),
+            POISON_MESSAGE_CODE => Ok(Command::PoisonMessage(PoisonMessage::from_bytes(payload)?)),
+            SUBSCRIBE_CODE => Ok(Command::Subscribe(Subscribe::from_bytes(payload)?)),
+            UNSUBSCRIBE_CODE => Ok(Command::Unsubscribe(Unsubscribe::from_bytes(payload)?)),
+            GRANT_CREDITS_CODE => Ok(Command::GrantCredits(GrantCredits::from_bytes(payload)?)),
+            INIT_PRODUCER_CODE => Ok(Command::InitProducer(InitProducer::from_bytes(payload)?)),
             STORE_CONSUMER_OFFSET_CODE => Ok(Command::StoreConsumerOffset(
                 StoreConsumerOffset::from_bytes(payload)?,
             )),
             GET_CONSUMER_OFFSET_CODE => Ok(Command::GetConsumerOffset(
                 GetConsumerOffset::from_bytes(payload)?,
             )),
+            GET_CONSUMER_LAG_CODE => Ok(Command::GetConsumerLag(GetConsumerLag::from_bytes(
+                payload,
+            )?)),
+            RESET_CONSUMER_OFFSETS_CODE => Ok(Command::ResetConsumerOffsets(
+                ResetConsumerOffsets::from_bytes(payload)?,
+            )),
             GET_STREAM_CODE => Ok(Command::GetStream(GetStream::from_bytes(payload)?)),
             GET_STREAMS_CODE => Ok(Command::GetStreams(GetStreams::from_bytes(payload)?)),
             CREATE_STREAM_CODE => Ok(Command::CreateStream(CreateStream::from_bytes(payload)?)),
@@ -283,6 +498,21 @@ impl BytesSerializable for Command {
             LEAVE_CONSUMER_GROUP_CODE => Ok(Command::LeaveConsumerGroup(
                 LeaveConsumerGroup::from_bytes(payload)?,
             )),
+            HEARTBEAT_CONSUMER_GROUP_CODE => Ok(Command::HeartbeatConsumerGroup(
+                HeartbeatConsumerGroup::from_bytes(payload)?,
+            )),
+            GET_CONSUMER_GROUP_ASSIGNMENT_CODE => Ok(Command::GetConsumerGroupAssignment(
+                GetConsumerGroupAssignment::from_bytes(payload)?,
+            )),
+            BEGIN_TRANSACTION_CODE => Ok(Command::BeginTransaction(BeginTransaction::from_bytes(
+                payload,
+            )?)),
+            COMMIT_TRANSACTION_CODE => Ok(Command::CommitTransaction(
+                CommitTransaction::from_bytes(payload)?,
+            )),
+            ABORT_TRANSACTION_CODE => Ok(Command::AbortTransaction(AbortTransaction::from_bytes(
+                payload,
+            )?)),
             _ => Err(Error::InvalidCommand),
         }
     }
@@ -305,6 +535,8 @@ impl FromStr for Command {
             GET_ME => Ok(Command::GetMe(GetMe::from_str(payload)?)),
             GET_CLIENT => Ok(Command::GetClient(GetClient::from_str(payload)?)),
             GET_CLIENTS => Ok(Command::GetClients(GetClients::from_str(payload)?)),
+            GET_SESSIONS => Ok(Command::GetSessions(GetSessions::from_str(payload)?)),
+            REVOKE_SESSION => Ok(Command::RevokeSession(RevokeSession::from_str(payload)?)),
             GET_USER => Ok(Command::GetUser(GetUser::from_str(payload)?)),
             GET_USERS => Ok(Command::GetUsers(GetUsers::from_str(payload)?)),
             CREATE_USER => Ok(Command::CreateUser(CreateUser::from_str(payload)?)),
@@ -313,17 +545,53 @@ impl FromStr for Command {
             UPDATE_PERMISSIONS => Ok(Command::UpdatePermissions(UpdatePermissions::from_str(
                 payload,
             )?)),
+            UPDATE_QUOTAS => Ok(Command::UpdateQuotas(UpdateQuotas::from_str(payload)?)),
             CHANGE_PASSWORD => Ok(Command::ChangePassword(ChangePassword::from_str(payload)?)),
             LOGIN_USER => Ok(Command::LoginUser(LoginUser::from_str(payload)?)),
             LOGOUT_USER => Ok(Command::LogoutUser(LogoutUser::from_str(payload)?)),
+            SCRAM_LOGIN_START => Ok(Command::ScramLoginStart(ScramLoginStart::from_str(
+                payload,
+            )?)),
+            SCRAM_LOGIN_FINISH => Ok(Command::ScramLoginFinish(ScramLoginFinish::from_str(
+                payload,
+            )?)),
+            UNLOCK_USER => Ok(Command::UnlockUser(UnlockUser::from_str(payload)?)),
+            GET_PERSONAL_ACCESS_TOKENS => Ok(Command::GetPersonalAccessTokens(
+                GetPersonalAccessTokens::from_str(payload)?,
+            )),
+            CREATE_PERSONAL_ACCESS_TOKEN => Ok(Command::CreatePersonalAccessToken(
+                CreatePersonalAccessToken::from_str(payload)?,
+            )),
+            DELETE_PERSONAL_ACCESS_TOKEN => Ok(Command::DeletePersonalAccessToken(
+                DeletePersonalAccessToken::from_str(payload)?,
+            )),
+            LOGIN_WITH_TOKEN => Ok(Command::LoginWithToken(LoginWithToken::from_str(payload)?)),
+            GET_ROLE => Ok(Command::GetRole(GetRole::from_str(payload)?)),
+            GET_ROLES => Ok(Command::GetRoles(GetRoles::from_str(payload)?)),
+            CREATE_ROLE => Ok(Command::CreateRole(CreateRole::from_str(payload)?)),
+            DELETE_ROLE => Ok(Command::DeleteRole(DeleteRole::from_str(payload)?)),
+            UPDATE_ROLE => Ok(Command::UpdateRole(UpdateRole::from_str(payload)?)),
+            ADD_ROLE_MEMBER => Ok(Command::AddRoleMember(AddRoleMember::from_str(payload)?)),
+            REMOVE_ROLE_MEMBER => Ok(Command::RemoveRoleMember(RemoveRoleMember::from_str(
+                payload,
+            )?)),
             SEND_MESSAGES => Ok(Command::SendMessages(SendMessages::from_str(payload)?)),
             POLL_MESSAGES => Ok(Command::PollMessages(PollMessages::from_str(payload)?)),
+            POISON_MESSAGE => Ok(Command::PoisonMessage(PoisonMessage::from_str(payload)?)),
+            SUBSCRIBE => Ok(Command::Subscribe(Subscribe::from_str(payload)?)),
+            UNSUBSCRIBE => Ok(Command::Unsubscribe(Unsubscribe::from_str(payload)?)),
+            GRANT_CREDITS => Ok(Command::GrantCredits(GrantCredits::from_str(payload)?)),
+            INIT_PRODUCER => Ok(Command::InitProducer(InitProducer::from_str(payload)?)),
             STORE_CONSUMER_OFFSET => Ok(Command::StoreConsumerOffset(
                 StoreConsumerOffset::from_str(payload)?,
             )),
             GET_CONSUMER_OFFSET => Ok(Command::GetConsumerOffset(GetConsumerOffset::from_str(
                 payload,
             )?)),
+            GET_CONSUMER_LAG => Ok(Command::GetConsumerLag(GetConsumerLag::from_str(payload)?)),
+            RESET_CONSUMER_OFFSETS => Ok(Command::ResetConsumerOffsets(
+                ResetConsumerOffsets::from_str(payload)?,
+            )),
             GET_STREAM => Ok(Command::GetStream(GetStream::from_str(payload)?)),
             GET_STREAMS => Ok(Command::GetStreams(GetStreams::from_str(payload)?)),
             CREATE_STREAM => Ok(Command::CreateStream(CreateStream::from_str(payload)?)),
@@ -358,6 +626,21 @@ impl FromStr for Command {
             LEAVE_CONSUMER_GROUP => Ok(Command::LeaveConsumerGroup(LeaveConsumerGroup::from_str(
                 payload,
             )?)),
+            HEARTBEAT_CONSUMER_GROUP => Ok(Command::HeartbeatConsumerGroup(
+                HeartbeatConsumerGroup::from_str(payload)?,
+            )),
+            GET_CONSUMER_GROUP_ASSIGNMENT => Ok(Command::GetConsumerGroupAssignment(
+                GetConsumerGroupAssignment::from_str(payload)?,
+            )),
+            BEGIN_TRANSACTION => Ok(Command::BeginTransaction(BeginTransaction::from_str(
+                payload,
+            )?)),
+            COMMIT_TRANSACTION => Ok(Command::CommitTransaction(CommitTransaction::from_str(
+                payload,
+            )?)),
+            ABORT_TRANSACTION => Ok(Command::AbortTransaction(AbortTransaction::from_str(
+                payload,
+            )?)),
             _ => Err(Error::InvalidCommand),
         }
     }
@@ -371,6 +654,11 @@ impl Display for Command {
             Command::GetMe(_) => write!(formatter, "{GET_ME}"),
             Command::GetClient(payload) => write!(formatter, "{GET_CLIENT}|{payload}"),
             Command::GetClients(_) => write!(formatter, "{GET_CLIENTS}"),
+            Command::GetSessions(payload) => match payload.user_id {
+                Some(_) => write!(formatter, "{GET_SESSIONS}|{payload}"),
+                None => write!(formatter, "{GET_SESSIONS}"),
+            },
+            Command::RevokeSession(payload) => write!(formatter, "{REVOKE_SESSION}|{payload}"),
             Command::GetUser(payload) => write!(formatter, "{GET_USER}|{payload}"),
             Command::GetUsers(_) => write!(formatter, "{GET_USERS}"),
             Command::CreateUser(payload) => write!(formatter, "{CREATE_USER}|{payload}"),
@@ -379,11 +667,38 @@ impl Display for Command {
             Command::UpdatePermissions(payload) => {
                 write!(formatter, "{UPDATE_PERMISSIONS}|{payload}")
             }
+            Command::UpdateQuotas(payload) => write!(formatter, "{UPDATE_QUOTAS}|{payload}"),
             Command::ChangePassword(payload) => {
                 write!(formatter, "{CHANGE_PASSWORD}|{payload}")
             }
             Command::LoginUser(payload) => write!(formatter, "{LOGIN_USER}|{payload}"),
             Command::LogoutUser(_) => write!(formatter, "{LOGOUT_USER}"),
+            Command::ScramLoginStart(payload) => {
+                write!(formatter, "{SCRAM_LOGIN_START}|{payload}")
+            }
+            Command::ScramLoginFinish(payload) => {
+                write!(formatter, "{SCRAM_LOGIN_FINISH}|{payload}")
+            }
+            Command::UnlockUser(payload) => write!(formatter, "{UNLOCK_USER}|{payload}"),
+            Command::GetPersonalAccessTokens(_) => {
+                write!(formatter, "{GET_PERSONAL_ACCESS_TOKENS}")
+            }
+            Command::CreatePersonalAccessToken(payload) => {
+                write!(formatter, "{CREATE_PERSONAL_ACCESS_TOKEN}|{payload}")
+            }
+            Command::DeletePersonalAccessToken(payload) => {
+                write!(formatter, "{DELETE_PERSONAL_ACCESS_TOKEN}|{payload}")
+            }
+            Command::LoginWithToken(payload) => write!(formatter, "{LOGIN_WITH_TOKEN}|{payload}"),
+            Command::GetRole(payload) => write!(formatter, "{GET_ROLE}|{payload}"),
+            Command::GetRoles(_) => write!(formatter, "{GET_ROLES}"),
+            Command::CreateRole(payload) => write!(formatter, "{CREATE_ROLE}|{payload}"),
+            Command::DeleteRole(payload) => write!(formatter, "{DELETE_ROLE}|{payload}"),
+            Command::UpdateRole(payload) => write!(formatter, "{UPDATE_ROLE}|{payload}"),
+            Command::AddRoleMember(payload) => write!(formatter, "{ADD_ROLE_MEMBER}|{payload}"),
+            Command::RemoveRoleMember(payload) => {
+                write!(formatter, "{REMOVE_ROLE_MEMBER}|{payload}")
+            }
             Command::GetStream(payload) => write!(formatter, "{GET_STREAM}|{payload}"),
             Command::GetStreams(_) => write!(formatter, "{GET_STREAMS}"),
             Command::CreateStream(payload) => write!(formatter, "{CREATE_STREAM}|{payload}"),
@@ -402,12 +717,23 @@ impl Display for Command {
             }
             Command::PollMessages(payload) => write!(formatter, "{POLL_MESSAGES}|{payload}"),
             Command::SendMessages(payload) => write!(formatter, "{SEND_MESSAGES}|{payload}"),
+            Command::PoisonMessage(payload) => write!(formatter, "{POISON_MESSAGE}|{payload}"),
+            Command::Subscribe(payload) => write!(formatter, "{SUBSCRIBE}|{payload}"),
+            Command::Unsubscribe(payload) => write!(formatter, "{UNSUBSCRIBE}|{payload}"),
+            Command::GrantCredits(payload) => write!(formatter, "{GRANT_CREDITS}|{payload}"),
+            Command::InitProducer(_) => write!(formatter, "{INIT_PRODUCER}"),
             Command::StoreConsumerOffset(payload) => {
                 write!(formatter, "{STORE_CONSUMER_OFFSET}|{payload}")
             }
             Command::GetConsumerOffset(payload) => {
                 write!(formatter, "{GET_CONSUMER_OFFSET}|{payload}")
             }
+            Command::GetConsumerLag(payload) => {
+                write!(formatter, "{GET_CONSUMER_LAG}|{payload}")
+            }
+            Command::ResetConsumerOffsets(payload) => {
+                write!(formatter, "{RESET_CONSUMER_OFFSETS}|{payload}")
+            }
             Command::GetConsumerGroup(payload) => {
                 write!(formatter, "{GET_CONSUMER_GROUP}|{payload}")
             }
@@ -426,6 +752,19 @@ impl Display for Command {
             Command::LeaveConsumerGroup(payload) => {
                 write!(formatter, "{LEAVE_CONSUMER_GROUP}|{payload}")
             }
+            Command::HeartbeatConsumerGroup(payload) => {
+                write!(formatter, "{HEARTBEAT_CONSUMER_GROUP}|{payload}")
+            }
+            Command::GetConsumerGroupAssignment(payload) => {
+                write!(formatter, "{GET_CONSUMER_GROUP_ASSIGNMENT}|{payload}")
+            }
+            Command::BeginTransaction(_) => write!(formatter, "{BEGIN_TRANSACTION}"),
+            Command::CommitTransaction(payload) => {
+                write!(formatter, "{COMMIT_TRANSACTION}|{payload}")
+            }
+            Command::AbortTransaction(payload) => {
+                write!(formatter, "{ABORT_TRANSACTION}|{payload}")
+            }
         }
     }
 }
@@ -461,6 +800,16 @@ mod tests {
             GET_CLIENTS_CODE,
             &GetClients::default(),
         );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::GetSessions(GetSessions::default()),
+            GET_SESSIONS_CODE,
+            &GetSessions::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::RevokeSession(RevokeSession::default()),
+            REVOKE_SESSION_CODE,
+            &RevokeSession::default(),
+        );
         assert_serialized_as_bytes_and_deserialized_from_bytes(
             &Command::GetUser(GetUser::default()),
             GET_USER_CODE,
@@ -491,6 +840,11 @@ mod tests {
             UPDATE_PERMISSIONS_CODE,
             &UpdatePermissions::default(),
         );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::UpdateQuotas(UpdateQuotas::default()),
+            UPDATE_QUOTAS_CODE,
+            &UpdateQuotas::default(),
+        );
         assert_serialized_as_bytes_and_deserialized_from_bytes(
             &Command::ChangePassword(ChangePassword::default()),
             CHANGE_PASSWORD_CODE,
@@ -506,6 +860,76 @@ mod tests {
             LOGOUT_USER_CODE,
             &LogoutUser::default(),
         );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::ScramLoginStart(ScramLoginStart::default()),
+            SCRAM_LOGIN_START_CODE,
+            &ScramLoginStart::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::ScramLoginFinish(ScramLoginFinish::default()),
+            SCRAM_LOGIN_FINISH_CODE,
+            &ScramLoginFinish::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::UnlockUser(UnlockUser::default()),
+            UNLOCK_USER_CODE,
+            &UnlockUser::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::GetPersonalAccessTokens(GetPersonalAccessTokens::default()),
+            GET_PERSONAL_ACCESS_TOKENS_CODE,
+            &GetPersonalAccessTokens::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::CreatePersonalAccessToken(CreatePersonalAccessToken::default()),
+            CREATE_PERSONAL_ACCESS_TOKEN_CODE,
+            &CreatePersonalAccessToken::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::DeletePersonalAccessToken(DeletePersonalAccessToken::default()),
+            DELETE_PERSONAL_ACCESS_TOKEN_CODE,
+            &DeletePersonalAccessToken::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::LoginWithToken(LoginWithToken::default()),
+            LOGIN_WITH_TOKEN_CODE,
+            &LoginWithToken::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::GetRole(GetRole::default()),
+            GET_ROLE_CODE,
+            &GetRole::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::GetRoles(GetRoles::default()),
+            GET_ROLES_CODE,
+            &GetRoles::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::CreateRole(CreateRole::default()),
+            CREATE_ROLE_CODE,
+            &CreateRole::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::DeleteRole(DeleteRole::default()),
+            DELETE_ROLE_CODE,
+            &DeleteRole::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::UpdateRole(UpdateRole::default()),
+            UPDATE_ROLE_CODE,
+            &UpdateRole::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::AddRoleMember(AddRoleMember::default()),
+            ADD_ROLE_MEMBER_CODE,
+            &AddRoleMember::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::RemoveRoleMember(RemoveRoleMember::default()),
+            REMOVE_ROLE_MEMBER_CODE,
+            &RemoveRoleMember::default(),
+        );
         assert_serialized_as_bytes_and_deserialized_from_bytes(
             &Command::SendMessages(SendMessages::default()),
             SEND_MESSAGES_CODE,
@@ -516,6 +940,31 @@ mod tests {
             POLL_MESSAGES_CODE,
             &PollMessages::default(),
         );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::PoisonMessage(PoisonMessage::default()),
+            POISON_MESSAGE_CODE,
+            &PoisonMessage::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::Subscribe(Subscribe::default()),
+            SUBSCRIBE_CODE,
+            &Subscribe::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::Unsubscribe(Unsubscribe::default()),
+            UNSUBSCRIBE_CODE,
+            &Unsubscribe::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::GrantCredits(GrantCredits::default()),
+            GRANT_CREDITS_CODE,
+            &GrantCredits::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::InitProducer(InitProducer::default()),
+            INIT_PRODUCER_CODE,
+            &InitProducer::default(),
+        );
         assert_serialized_as_bytes_and_deserialized_from_bytes(
             &Command::StoreConsumerOffset(StoreConsumerOffset::default()),
             STORE_CONSUMER_OFFSET_CODE,
@@ -526,6 +975,16 @@ mod tests {
             GET_CONSUMER_OFFSET_CODE,
             &GetConsumerOffset::default(),
         );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::GetConsumerLag(GetConsumerLag::default()),
+            GET_CONSUMER_LAG_CODE,
+            &GetConsumerLag::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::ResetConsumerOffsets(ResetConsumerOffsets::default()),
+            RESET_CONSUMER_OFFSETS_CODE,
+            &ResetConsumerOffsets::default(),
+        );
         assert_serialized_as_bytes_and_deserialized_from_bytes(
             &Command::GetStream(GetStream::default()),
             GET_STREAM_CODE,
@@ -616,6 +1075,31 @@ mod tests {
             LEAVE_CONSUMER_GROUP_CODE,
             &LeaveConsumerGroup::default(),
         );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::HeartbeatConsumerGroup(HeartbeatConsumerGroup::default()),
+            HEARTBEAT_CONSUMER_GROUP_CODE,
+            &HeartbeatConsumerGroup::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::GetConsumerGroupAssignment(GetConsumerGroupAssignment::default()),
+            GET_CONSUMER_GROUP_ASSIGNMENT_CODE,
+            &GetConsumerGroupAssignment::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::BeginTransaction(BeginTransaction::default()),
+            BEGIN_TRANSACTION_CODE,
+            &BeginTransaction::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::CommitTransaction(CommitTransaction::default()),
+            COMMIT_TRANSACTION_CODE,
+            &CommitTransaction::default(),
+        );
+        assert_serialized_as_bytes_and_deserialized_from_bytes(
+            &Command::AbortTransaction(AbortTransaction::default()),
+            ABORT_TRANSACTION_CODE,
+            &AbortTransaction::default(),
+        );
     }
 
     #[test]
@@ -637,6 +1121,16 @@ mod tests {
             GET_CLIENTS,
             &GetClients::default(),
         );
+        assert_read_from_string(
+            &Command::GetSessions(GetSessions::default()),
+            GET_SESSIONS,
+            &GetSessions::default(),
+        );
+        assert_read_from_string(
+            &Command::RevokeSession(RevokeSession::default()),
+            REVOKE_SESSION,
+            &RevokeSession::default(),
+        );
         assert_read_from_string(
             &Command::GetUser(GetUser::default()),
             GET_USER,
@@ -667,6 +1161,11 @@ mod tests {
             UPDATE_PERMISSIONS,
             &UpdatePermissions::default(),
         );
+        assert_read_from_string(
+            &Command::UpdateQuotas(UpdateQuotas::default()),
+            UPDATE_QUOTAS,
+            &UpdateQuotas::default(),
+        );
         assert_read_from_string(
             &Command::ChangePassword(ChangePassword::default()),
             CHANGE_PASSWORD,
@@ -682,6 +1181,76 @@ mod tests {
             LOGOUT_USER,
             &LogoutUser::default(),
         );
+        assert_read_from_string(
+            &Command::ScramLoginStart(ScramLoginStart::default()),
+            SCRAM_LOGIN_START,
+            &ScramLoginStart::default(),
+        );
+        assert_read_from_string(
+            &Command::ScramLoginFinish(ScramLoginFinish::default()),
+            SCRAM_LOGIN_FINISH,
+            &ScramLoginFinish::default(),
+        );
+        assert_read_from_string(
+            &Command::UnlockUser(UnlockUser::default()),
+            UNLOCK_USER,
+            &UnlockUser::default(),
+        );
+        assert_read_from_string(
+            &Command::GetPersonalAccessTokens(GetPersonalAccessTokens::default()),
+            GET_PERSONAL_ACCESS_TOKENS,
+            &GetPersonalAccessTokens::default(),
+        );
+        assert_read_from_string(
+            &Command::CreatePersonalAccessToken(CreatePersonalAccessToken::default()),
+            CREATE_PERSONAL_ACCESS_TOKEN,
+            &CreatePersonalAccessToken::default(),
+        );
+        assert_read_from_string(
+            &Command::DeletePersonalAccessToken(DeletePersonalAccessToken::default()),
+            DELETE_PERSONAL_ACCESS_TOKEN,
+            &DeletePersonalAccessToken::default(),
+        );
+        assert_read_from_string(
+            &Command::LoginWithToken(LoginWithToken::default()),
+            LOGIN_WITH_TOKEN,
+            &LoginWithToken::default(),
+        );
+        assert_read_from_string(
+            &Command::GetRole(GetRole::default()),
+            GET_ROLE,
+            &GetRole::default(),
+        );
+        assert_read_from_string(
+            &Command::GetRoles(GetRoles::default()),
+            GET_ROLES,
+            &GetRoles::default(),
+        );
+        assert_read_from_string(
+            &Command::CreateRole(CreateRole::default()),
+            CREATE_ROLE,
+            &CreateRole::default(),
+        );
+        assert_read_from_string(
+            &Command::DeleteRole(DeleteRole::default()),
+            DELETE_ROLE,
+            &DeleteRole::default(),
+        );
+        assert_read_from_string(
+            &Command::UpdateRole(UpdateRole::default()),
+            UPDATE_ROLE,
+            &UpdateRole::default(),
+        );
+        assert_read_from_string(
+            &Command::AddRoleMember(AddRoleMember::default()),
+            ADD_ROLE_MEMBER,
+            &AddRoleMember::default(),
+        );
+        assert_read_from_string(
+            &Command::RemoveRoleMember(RemoveRoleMember::default()),
+            REMOVE_ROLE_MEMBER,
+            &RemoveRoleMember::default(),
+        );
         assert_read_from_string(
             &Command::SendMessages(SendMessages::default()),
             SEND_MESSAGES,
@@ -692,6 +1261,31 @@ mod tests {
             POLL_MESSAGES,
             &PollMessages::default(),
         );
+        assert_read_from_string(
+            &Command::PoisonMessage(PoisonMessage::default()),
+            POISON_MESSAGE,
+            &PoisonMessage::default(),
+        );
+        assert_read_from_string(
+            &Command::Subscribe(Subscribe::default()),
+            SUBSCRIBE,
+            &Subscribe::default(),
+        );
+        assert_read_from_string(
+            &Command::Unsubscribe(Unsubscribe::default()),
+            UNSUBSCRIBE,
+            &Unsubscribe::default(),
+        );
+        assert_read_from_string(
+            &Command::GrantCredits(GrantCredits::default()),
+            GRANT_CREDITS,
+            &GrantCredits::default(),
+        );
+        assert_read_from_string(
+            &Command::InitProducer(InitProducer::default()),
+            INIT_PRODUCER,
+            &InitProducer::default(),
+        );
         assert_read_from_string(
             &Command::StoreConsumerOffset(StoreConsumerOffset::default()),
             STORE_CONSUMER_OFFSET,
@@ -702,6 +1296,16 @@ mod tests {
             GET_CONSUMER_OFFSET,
             &GetConsumerOffset::default(),
         );
+        assert_read_from_string(
+            &Command::GetConsumerLag(GetConsumerLag::default()),
+            GET_CONSUMER_LAG,
+            &GetConsumerLag::default(),
+        );
+        assert_read_from_string(
+            &Command::ResetConsumerOffsets(ResetConsumerOffsets::default()),
+            RESET_CONSUMER_OFFSETS,
+            &ResetConsumerOffsets::default(),
+        );
         assert_read_from_string(
             &Command::GetStream(GetStream::default()),
             GET_STREAM,
@@ -792,6 +1396,31 @@ mod tests {
             LEAVE_CONSUMER_GROUP,
             &LeaveConsumerGroup::default(),
         );
+        assert_read_from_string(
+            &Command::HeartbeatConsumerGroup(HeartbeatConsumerGroup::default()),
+            HEARTBEAT_CONSUMER_GROUP,
+            &HeartbeatConsumerGroup::default(),
+        );
+        assert_read_from_string(
+            &Command::GetConsumerGroupAssignment(GetConsumerGroupAssignment::default()),
+            GET_CONSUMER_GROUP_ASSIGNMENT,
+            &GetConsumerGroupAssignment::default(),
+        );
+        assert_read_from_string(
+            &Command::BeginTransaction(BeginTransaction::default()),
+            BEGIN_TRANSACTION,
+            &BeginTransaction::default(),
+        );
+        assert_read_from_string(
+            &Command::CommitTransaction(CommitTransaction::default()),
+            COMMIT_TRANSACTION,
+            &CommitTransaction::default(),
+        );
+        assert_read_from_string(
+            &Command::AbortTransaction(AbortTransaction::default()),
+            ABORT_TRANSACTION,
+            &AbortTransaction::default(),
+        );
     }
 
     fn assert_serialized_as_bytes_and_deserialized_from_bytes(
@@ -2,6 +2,7 @@ use crate::bytes_serializable::BytesSerializable;
 use crate::command::CommandPayload;
 use crate::error::Error;
 use crate::identifier::Identifier;
+use crate::models::assignment_strategy::AssignmentStrategy;
 use crate::validatable::Validatable;
 use bytes::BufMut;
 use serde::{Deserialize, Serialize};
@@ -15,6 +16,11 @@ pub struct CreateConsumerGroup {
     #[serde(skip)]
     pub topic_id: Identifier,
     pub consumer_group_id: u32,
+    #[serde(default)]
+    pub assignment_strategy: AssignmentStrategy,
+    // The members that didn't send a heartbeat within the session timeout (in seconds) are evicted, 0 disables it.
+    #[serde(default)]
+    pub session_timeout: u32,
 }
 
 impl CommandPayload for CreateConsumerGroup {}
@@ -25,6 +31,8 @@ impl Default for CreateConsumerGroup {
             stream_id: Identifier::default(),
             topic_id: Identifier::default(),
             consumer_group_id: 1,
+            assignment_strategy: AssignmentStrategy::default(),
+            session_timeout: 0,
         }
     }
 }
@@ -43,17 +51,27 @@ impl FromStr for CreateConsumerGroup {
     type Err = Error;
     fn from_str(input: &str) -> Result<Self, Self::Err> {
         let parts = input.split('|').collect::<Vec<&str>>();
-        if parts.len() != 3 {
+        if parts.len() < 3 || parts.len() > 5 {
             return Err(Error::InvalidCommand);
         }
 
         let stream_id = parts[0].parse::<Identifier>()?;
         let topic_id = parts[1].parse::<Identifier>()?;
         let consumer_group_id = parts[2].parse::<u32>()?;
+        let assignment_strategy = match parts.get(3) {
+            Some(assignment_strategy) => assignment_strategy.parse::<AssignmentStrategy>()?,
+            None => AssignmentStrategy::default(),
+        };
+        let session_timeout = match parts.get(4) {
+            Some(session_timeout) => session_timeout.parse::<u32>()?,
+            None => 0,
+        };
         let command = CreateConsumerGroup {
             stream_id,
             topic_id,
             consumer_group_id,
+            assignment_strategy,
+            session_timeout,
         };
         command.validate()?;
         Ok(command)
@@ -64,10 +82,12 @@ impl BytesSerializable for CreateConsumerGroup {
     fn as_bytes(&self) -> Vec<u8> {
         let stream_id_bytes = self.stream_id.as_bytes();
         let topic_id_bytes = self.topic_id.as_bytes();
-        let mut bytes = Vec::with_capacity(4 + stream_id_bytes.len() + topic_id_bytes.len());
+        let mut bytes = Vec::with_capacity(9 + stream_id_bytes.len() + topic_id_bytes.len());
         bytes.extend(stream_id_bytes);
         bytes.extend(topic_id_bytes);
         bytes.put_u32_le(self.consumer_group_id);
+        bytes.put_u8(self.assignment_strategy.as_code());
+        bytes.put_u32_le(self.session_timeout);
         bytes
     }
 
@@ -82,10 +102,20 @@ impl BytesSerializable for CreateConsumerGroup {
         let topic_id = Identifier::from_bytes(&bytes[position..])?;
         position += topic_id.get_size_bytes() as usize;
         let consumer_group_id = u32::from_le_bytes(bytes[position..position + 4].try_into()?);
+        let assignment_strategy = match bytes.get(position + 4) {
+            Some(code) => AssignmentStrategy::from_code(*code)?,
+            None => AssignmentStrategy::default(),
+        };
+        let session_timeout = match bytes.get(position + 5..position + 9) {
+            Some(session_timeout) => u32::from_le_bytes(session_timeout.try_into()?),
+            None => 0,
+        };
         let command = CreateConsumerGroup {
             stream_id,
             topic_id,
             consumer_group_id,
+            assignment_strategy,
+            session_timeout,
         };
         command.validate()?;
         Ok(command)
@@ -96,8 +126,12 @@ impl Display for CreateConsumerGroup {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "{}|{}|{}",
-            self.stream_id, self.topic_id, self.consumer_group_id
+            "{}|{}|{}|{}|{}",
+            self.stream_id,
+            self.topic_id,
+            self.consumer_group_id,
+            self.assignment_strategy,
+            self.session_timeout
         )
     }
 }
@@ -112,6 +146,8 @@ mod tests {
             stream_id: Identifier::numeric(1).unwrap(),
             topic_id: Identifier::numeric(2).unwrap(),
             consumer_group_id: 3,
+            assignment_strategy: AssignmentStrategy::Sticky,
+            session_timeout: 30,
         };
 
         let bytes = command.as_bytes();
@@ -122,11 +158,16 @@ mod tests {
         position += topic_id.get_size_bytes() as usize;
         let consumer_group_id =
             u32::from_le_bytes(bytes[position..position + 4].try_into().unwrap());
+        let assignment_strategy = AssignmentStrategy::from_code(bytes[position + 4]).unwrap();
+        let session_timeout =
+            u32::from_le_bytes(bytes[position + 5..position + 9].try_into().unwrap());
 
         assert!(!bytes.is_empty());
         assert_eq!(stream_id, command.stream_id);
         assert_eq!(topic_id, command.topic_id);
         assert_eq!(consumer_group_id, command.consumer_group_id);
+        assert_eq!(assignment_strategy, command.assignment_strategy);
+        assert_eq!(session_timeout, command.session_timeout);
     }
 
     #[test]
@@ -147,6 +188,8 @@ mod tests {
         assert_eq!(command.stream_id, stream_id);
         assert_eq!(command.topic_id, topic_id);
         assert_eq!(command.consumer_group_id, consumer_group_id);
+        assert_eq!(command.assignment_strategy, AssignmentStrategy::default());
+        assert_eq!(command.session_timeout, 0);
     }
 
     #[test]
@@ -154,7 +197,11 @@ mod tests {
         let stream_id = Identifier::numeric(1).unwrap();
         let topic_id = Identifier::numeric(2).unwrap();
         let consumer_group_id = 3u32;
-        let input = format!("{stream_id}|{topic_id}|{consumer_group_id}");
+        let assignment_strategy = AssignmentStrategy::CooperativeSticky;
+        let session_timeout = 30u32;
+        let input = format!(
+            "{stream_id}|{topic_id}|{consumer_group_id}|{assignment_strategy}|{session_timeout}"
+        );
         let command = CreateConsumerGroup::from_str(&input);
         assert!(command.is_ok());
 
@@ -162,5 +209,7 @@ mod tests {
         assert_eq!(command.stream_id, stream_id);
         assert_eq!(command.topic_id, topic_id);
         assert_eq!(command.consumer_group_id, consumer_group_id);
+        assert_eq!(command.assignment_strategy, assignment_strategy);
+        assert_eq!(command.session_timeout, session_timeout);
     }
 }
@@ -0,0 +1,179 @@
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::CommandPayload;
+use crate::consumer::{Consumer, ConsumerKind};
+use crate::error::Error;
+use crate::identifier::Identifier;
+use crate::validatable::Validatable;
+use bytes::BufMut;
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, DisplayFromStr};
+use std::fmt::Display;
+use std::str::FromStr;
+
+// The lag of all the consumer groups of the topic is returned, if the consumer is not specified.
+#[serde_as]
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default)]
+pub struct GetConsumerLag {
+    #[serde(skip)]
+    pub stream_id: Identifier,
+    #[serde(skip)]
+    pub topic_id: Identifier,
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(default)]
+    pub consumer: Option<Consumer>,
+}
+
+impl CommandPayload for GetConsumerLag {}
+
+impl Validatable<Error> for GetConsumerLag {
+    fn validate(&self) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+impl FromStr for GetConsumerLag {
+    type Err = Error;
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let parts = input.split('|').collect::<Vec<&str>>();
+        if parts.len() != 2 && parts.len() != 4 {
+            return Err(Error::InvalidCommand);
+        }
+
+        let stream_id = parts[0].parse::<Identifier>()?;
+        let topic_id = parts[1].parse::<Identifier>()?;
+        let consumer = match parts.len() {
+            4 => Some(Consumer {
+                kind: ConsumerKind::from_str(parts[2])?,
+                id: parts[3].parse::<u32>()?,
+            }),
+            _ => None,
+        };
+        let command = GetConsumerLag {
+            stream_id,
+            topic_id,
+            consumer,
+        };
+        command.validate()?;
+        Ok(command)
+    }
+}
+
+impl BytesSerializable for GetConsumerLag {
+    fn as_bytes(&self) -> Vec<u8> {
+        let stream_id_bytes = self.stream_id.as_bytes();
+        let topic_id_bytes = self.topic_id.as_bytes();
+        let mut bytes = Vec::with_capacity(6 + stream_id_bytes.len() + topic_id_bytes.len());
+        bytes.extend(stream_id_bytes);
+        bytes.extend(topic_id_bytes);
+        if let Some(consumer) = &self.consumer {
+            bytes.put_u8(1);
+            bytes.extend(consumer.as_bytes());
+        } else {
+            bytes.put_u8(0);
+        }
+        bytes
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<GetConsumerLag, Error> {
+        if bytes.len() < 7 {
+            return Err(Error::InvalidCommand);
+        }
+
+        let mut position = 0;
+        let stream_id = Identifier::from_bytes(bytes)?;
+        position += stream_id.get_size_bytes() as usize;
+        let topic_id = Identifier::from_bytes(&bytes[position..])?;
+        position += topic_id.get_size_bytes() as usize;
+        let consumer = match bytes.get(position) {
+            Some(1) => {
+                let consumer_bytes = bytes
+                    .get(position + 1..position + 6)
+                    .ok_or(Error::InvalidCommand)?;
+                Some(Consumer::from_bytes(consumer_bytes)?)
+            }
+            Some(0) => None,
+            _ => return Err(Error::InvalidCommand),
+        };
+        let command = GetConsumerLag {
+            stream_id,
+            topic_id,
+            consumer,
+        };
+        command.validate()?;
+        Ok(command)
+    }
+}
+
+impl Display for GetConsumerLag {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.consumer {
+            Some(consumer) => write!(f, "{}|{}|{}", self.stream_id, self.topic_id, consumer),
+            None => write!(f, "{}|{}", self.stream_id, self.topic_id),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_bytes() {
+        let command = GetConsumerLag {
+            stream_id: Identifier::numeric(1).unwrap(),
+            topic_id: Identifier::numeric(2).unwrap(),
+            consumer: Some(Consumer::group(3)),
+        };
+
+        let bytes = command.as_bytes();
+        let mut position = 0;
+        let stream_id = Identifier::from_bytes(&bytes).unwrap();
+        position += stream_id.get_size_bytes() as usize;
+        let topic_id = Identifier::from_bytes(&bytes[position..]).unwrap();
+        position += topic_id.get_size_bytes() as usize;
+        let has_consumer = bytes[position];
+        let consumer = Consumer::from_bytes(&bytes[position + 1..position + 6]).unwrap();
+
+        assert!(!bytes.is_empty());
+        assert_eq!(stream_id, command.stream_id);
+        assert_eq!(topic_id, command.topic_id);
+        assert_eq!(has_consumer, 1);
+        assert_eq!(Some(consumer), command.consumer);
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes() {
+        let stream_id = Identifier::numeric(1).unwrap();
+        let topic_id = Identifier::numeric(2).unwrap();
+
+        let stream_id_bytes = stream_id.as_bytes();
+        let topic_id_bytes = topic_id.as_bytes();
+        let mut bytes = Vec::with_capacity(1 + stream_id_bytes.len() + topic_id_bytes.len());
+        bytes.extend(stream_id_bytes);
+        bytes.extend(topic_id_bytes);
+        bytes.put_u8(0);
+
+        let command = GetConsumerLag::from_bytes(&bytes);
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.stream_id, stream_id);
+        assert_eq!(command.topic_id, topic_id);
+        assert!(command.consumer.is_none());
+    }
+
+    #[test]
+    fn should_be_read_from_string() {
+        let stream_id = Identifier::numeric(1).unwrap();
+        let topic_id = Identifier::numeric(2).unwrap();
+        let consumer = Consumer::new(3);
+        let input = format!("{stream_id}|{topic_id}|{consumer}");
+        let command = GetConsumerLag::from_str(&input);
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.stream_id, stream_id);
+        assert_eq!(command.topic_id, topic_id);
+        assert_eq!(command.consumer, Some(consumer));
+    }
+}
@@ -1,2 +1,4 @@
+pub mod get_consumer_lag;
 pub mod get_consumer_offset;
+pub mod reset_consumer_offsets;
 pub mod store_consumer_offset;
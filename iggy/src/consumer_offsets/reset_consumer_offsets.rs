@@ -0,0 +1,325 @@
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::CommandPayload;
+use crate::consumer::{Consumer, ConsumerKind};
+use crate::error::Error;
+use crate::identifier::Identifier;
+use crate::validatable::Validatable;
+use bytes::BufMut;
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, DisplayFromStr};
+use std::fmt::Display;
+use std::str::FromStr;
+
+// The offsets of all the partitions are reset, if the partition is not specified.
+#[serde_as]
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default)]
+pub struct ResetConsumerOffsets {
+    #[serde_as(as = "DisplayFromStr")]
+    pub consumer: Consumer,
+    #[serde(skip)]
+    pub stream_id: Identifier,
+    #[serde(skip)]
+    pub topic_id: Identifier,
+    #[serde(default)]
+    pub partition_id: Option<u32>,
+    pub strategy: ResetStrategy,
+    #[serde(default)]
+    pub dry_run: bool,
+}
+
+// Each strategy points to the offset of the next message to be consumed.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default, Copy, Clone)]
+#[serde(tag = "kind", content = "value", rename_all = "snake_case")]
+pub enum ResetStrategy {
+    #[default]
+    Earliest,
+    Latest,
+    Timestamp(u64),
+    Offset(u64),
+    Shift(i64),
+}
+
+impl CommandPayload for ResetConsumerOffsets {}
+
+impl Validatable<Error> for ResetConsumerOffsets {
+    fn validate(&self) -> Result<(), Error> {
+        if let Some(partition_id) = self.partition_id {
+            if partition_id == 0 {
+                return Err(Error::InvalidCommand);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl ResetStrategy {
+    pub fn as_code(&self) -> u8 {
+        match self {
+            ResetStrategy::Earliest => 1,
+            ResetStrategy::Latest => 2,
+            ResetStrategy::Timestamp(_) => 3,
+            ResetStrategy::Offset(_) => 4,
+            ResetStrategy::Shift(_) => 5,
+        }
+    }
+
+    pub fn from_code(code: u8, value: u64) -> Result<Self, Error> {
+        match code {
+            1 => Ok(ResetStrategy::Earliest),
+            2 => Ok(ResetStrategy::Latest),
+            3 => Ok(ResetStrategy::Timestamp(value)),
+            4 => Ok(ResetStrategy::Offset(value)),
+            5 => Ok(ResetStrategy::Shift(value as i64)),
+            _ => Err(Error::InvalidCommand),
+        }
+    }
+
+    fn value(&self) -> u64 {
+        match self {
+            ResetStrategy::Earliest | ResetStrategy::Latest => 0,
+            ResetStrategy::Timestamp(value) | ResetStrategy::Offset(value) => *value,
+            ResetStrategy::Shift(value) => *value as u64,
+        }
+    }
+}
+
+impl FromStr for ResetStrategy {
+    type Err = Error;
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let (kind, value) = match input.split_once(':') {
+            Some((kind, value)) => (kind, Some(value)),
+            None => (input, None),
+        };
+        match (kind, value) {
+            ("earliest", None) => Ok(ResetStrategy::Earliest),
+            ("latest", None) => Ok(ResetStrategy::Latest),
+            ("timestamp", Some(value)) => Ok(ResetStrategy::Timestamp(value.parse::<u64>()?)),
+            ("offset", Some(value)) => Ok(ResetStrategy::Offset(value.parse::<u64>()?)),
+            ("shift", Some(value)) => Ok(ResetStrategy::Shift(value.parse::<i64>()?)),
+            _ => Err(Error::InvalidCommand),
+        }
+    }
+}
+
+impl Display for ResetStrategy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ResetStrategy::Earliest => write!(f, "earliest"),
+            ResetStrategy::Latest => write!(f, "latest"),
+            ResetStrategy::Timestamp(value) => write!(f, "timestamp:{value}"),
+            ResetStrategy::Offset(value) => write!(f, "offset:{value}"),
+            ResetStrategy::Shift(value) => write!(f, "shift:{value}"),
+        }
+    }
+}
+
+impl FromStr for ResetConsumerOffsets {
+    type Err = Error;
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let parts = input.split('|').collect::<Vec<&str>>();
+        if parts.len() != 6 && parts.len() != 7 {
+            return Err(Error::InvalidCommand);
+        }
+
+        let consumer_kind = ConsumerKind::from_str(parts[0])?;
+        let consumer_id = parts[1].parse::<u32>()?;
+        let consumer = Consumer {
+            kind: consumer_kind,
+            id: consumer_id,
+        };
+        let stream_id = parts[2].parse::<Identifier>()?;
+        let topic_id = parts[3].parse::<Identifier>()?;
+        let partition_id = match parts[4].parse::<u32>()? {
+            0 => None,
+            partition_id => Some(partition_id),
+        };
+        let strategy = parts[5].parse::<ResetStrategy>()?;
+        let dry_run = match parts.get(6) {
+            Some(dry_run) => dry_run.parse::<bool>().map_err(|_| Error::InvalidCommand)?,
+            None => false,
+        };
+        let command = ResetConsumerOffsets {
+            consumer,
+            stream_id,
+            topic_id,
+            partition_id,
+            strategy,
+            dry_run,
+        };
+        command.validate()?;
+        Ok(command)
+    }
+}
+
+impl BytesSerializable for ResetConsumerOffsets {
+    fn as_bytes(&self) -> Vec<u8> {
+        let consumer_bytes = self.consumer.as_bytes();
+        let stream_id_bytes = self.stream_id.as_bytes();
+        let topic_id_bytes = self.topic_id.as_bytes();
+        let mut bytes = Vec::with_capacity(
+            14 + consumer_bytes.len() + stream_id_bytes.len() + topic_id_bytes.len(),
+        );
+        bytes.extend(consumer_bytes);
+        bytes.extend(stream_id_bytes);
+        bytes.extend(topic_id_bytes);
+        if let Some(partition_id) = self.partition_id {
+            bytes.put_u32_le(partition_id);
+        } else {
+            bytes.put_u32_le(0);
+        }
+        bytes.put_u8(self.strategy.as_code());
+        bytes.put_u64_le(self.strategy.value());
+        bytes.put_u8(if self.dry_run { 1 } else { 0 });
+        bytes
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<ResetConsumerOffsets, Error> {
+        if bytes.len() < 25 {
+            return Err(Error::InvalidCommand);
+        }
+
+        let mut position = 0;
+        let consumer_kind = ConsumerKind::from_code(bytes[0])?;
+        let consumer_id = u32::from_le_bytes(bytes[1..5].try_into()?);
+        let consumer = Consumer {
+            kind: consumer_kind,
+            id: consumer_id,
+        };
+        position += 5;
+        let stream_id = Identifier::from_bytes(&bytes[position..])?;
+        position += stream_id.get_size_bytes() as usize;
+        let topic_id = Identifier::from_bytes(&bytes[position..])?;
+        position += topic_id.get_size_bytes() as usize;
+        if bytes.len() != position + 14 {
+            return Err(Error::InvalidCommand);
+        }
+
+        let partition_id = u32::from_le_bytes(bytes[position..position + 4].try_into()?);
+        let partition_id = if partition_id == 0 {
+            None
+        } else {
+            Some(partition_id)
+        };
+        let strategy_value = u64::from_le_bytes(bytes[position + 5..position + 13].try_into()?);
+        let strategy = ResetStrategy::from_code(bytes[position + 4], strategy_value)?;
+        let dry_run = bytes[position + 13] == 1;
+        let command = ResetConsumerOffsets {
+            consumer,
+            stream_id,
+            topic_id,
+            partition_id,
+            strategy,
+            dry_run,
+        };
+        command.validate()?;
+        Ok(command)
+    }
+}
+
+impl Display for ResetConsumerOffsets {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}|{}|{}|{}|{}|{}",
+            self.consumer,
+            self.stream_id,
+            self.topic_id,
+            self.partition_id.unwrap_or(0),
+            self.strategy,
+            self.dry_run
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_bytes() {
+        let command = ResetConsumerOffsets {
+            consumer: Consumer::group(1),
+            stream_id: Identifier::numeric(2).unwrap(),
+            topic_id: Identifier::numeric(3).unwrap(),
+            partition_id: Some(4),
+            strategy: ResetStrategy::Shift(-5),
+            dry_run: true,
+        };
+
+        let bytes = command.as_bytes();
+        let mut position = 0;
+        let consumer = Consumer::from_bytes(&bytes[..5]).unwrap();
+        position += 5;
+        let stream_id = Identifier::from_bytes(&bytes[position..]).unwrap();
+        position += stream_id.get_size_bytes() as usize;
+        let topic_id = Identifier::from_bytes(&bytes[position..]).unwrap();
+        position += topic_id.get_size_bytes() as usize;
+        let partition_id = u32::from_le_bytes(bytes[position..position + 4].try_into().unwrap());
+        let strategy_code = bytes[position + 4];
+        let strategy_value =
+            i64::from_le_bytes(bytes[position + 5..position + 13].try_into().unwrap());
+        let dry_run = bytes[position + 13];
+
+        assert!(!bytes.is_empty());
+        assert_eq!(consumer, command.consumer);
+        assert_eq!(stream_id, command.stream_id);
+        assert_eq!(topic_id, command.topic_id);
+        assert_eq!(Some(partition_id), command.partition_id);
+        assert_eq!(strategy_code, command.strategy.as_code());
+        assert_eq!(strategy_value, -5);
+        assert_eq!(dry_run, 1);
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes() {
+        let consumer = Consumer::new(1);
+        let stream_id = Identifier::numeric(2).unwrap();
+        let topic_id = Identifier::numeric(3).unwrap();
+        let strategy = ResetStrategy::Timestamp(1000);
+
+        let consumer_bytes = consumer.as_bytes();
+        let stream_id_bytes = stream_id.as_bytes();
+        let topic_id_bytes = topic_id.as_bytes();
+        let mut bytes = Vec::with_capacity(
+            14 + consumer_bytes.len() + stream_id_bytes.len() + topic_id_bytes.len(),
+        );
+        bytes.extend(consumer_bytes);
+        bytes.extend(stream_id_bytes);
+        bytes.extend(topic_id_bytes);
+        bytes.put_u32_le(0);
+        bytes.put_u8(strategy.as_code());
+        bytes.put_u64_le(1000);
+        bytes.put_u8(0);
+
+        let command = ResetConsumerOffsets::from_bytes(&bytes);
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.consumer, consumer);
+        assert_eq!(command.stream_id, stream_id);
+        assert_eq!(command.topic_id, topic_id);
+        assert!(command.partition_id.is_none());
+        assert_eq!(command.strategy, strategy);
+        assert!(!command.dry_run);
+    }
+
+    #[test]
+    fn should_be_read_from_string() {
+        let consumer = Consumer::new(1);
+        let stream_id = Identifier::numeric(2).unwrap();
+        let topic_id = Identifier::numeric(3).unwrap();
+        let partition_id = 4u32;
+        let input = format!("{consumer}|{stream_id}|{topic_id}|{partition_id}|offset:10|true");
+        let command = ResetConsumerOffsets::from_str(&input);
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.consumer, consumer);
+        assert_eq!(command.stream_id, stream_id);
+        assert_eq!(command.topic_id, topic_id);
+        assert_eq!(command.partition_id, Some(partition_id));
+        assert_eq!(command.strategy, ResetStrategy::Offset(10));
+        assert!(command.dry_run);
+    }
+}
@@ -19,6 +19,11 @@ pub struct StoreConsumerOffset {
     pub topic_id: Identifier,
     pub partition_id: Option<u32>,
     pub offset: u64,
+    #[serde(default)]
+    pub transaction_id: Option<u64>,
+    // The offset stored by the consumer group member is rejected if it comes from the previous generation.
+    #[serde(default)]
+    pub generation_id: Option<u32>,
 }
 
 impl Default for StoreConsumerOffset {
@@ -29,6 +34,8 @@ impl Default for StoreConsumerOffset {
             topic_id: Identifier::default(),
             partition_id: Some(1),
             offset: 0,
+            transaction_id: None,
+            generation_id: None,
         }
     }
 }
@@ -37,6 +44,10 @@ impl CommandPayload for StoreConsumerOffset {}
 
 impl Validatable<Error> for StoreConsumerOffset {
     fn validate(&self) -> Result<(), Error> {
+        if self.transaction_id == Some(0) {
+            return Err(Error::InvalidTransactionId);
+        }
+
         Ok(())
     }
 }
@@ -45,7 +56,7 @@ impl FromStr for StoreConsumerOffset {
     type Err = Error;
     fn from_str(input: &str) -> Result<Self, Self::Err> {
         let parts = input.split('|').collect::<Vec<&str>>();
-        if parts.len() != 6 {
+        if parts.len() < 6 || parts.len() > 8 {
             return Err(Error::InvalidCommand);
         }
 
@@ -59,12 +70,28 @@ impl FromStr for StoreConsumerOffset {
         let topic_id = parts[3].parse::<Identifier>()?;
         let partition_id = parts[4].parse::<u32>()?;
         let offset = parts[5].parse::<u64>()?;
+        let transaction_id = match parts.get(6) {
+            Some(transaction_id) => match transaction_id.parse::<u64>()? {
+                0 => None,
+                transaction_id => Some(transaction_id),
+            },
+            None => None,
+        };
+        let generation_id = match parts.get(7) {
+            Some(generation_id) => match generation_id.parse::<u32>()? {
+                0 => None,
+                generation_id => Some(generation_id),
+            },
+            None => None,
+        };
         let command = StoreConsumerOffset {
             consumer,
             stream_id,
             topic_id,
             partition_id: Some(partition_id),
             offset,
+            transaction_id,
+            generation_id,
         };
         command.validate()?;
         Ok(command)
@@ -77,7 +104,7 @@ impl BytesSerializable for StoreConsumerOffset {
         let stream_id_bytes = self.stream_id.as_bytes();
         let topic_id_bytes = self.topic_id.as_bytes();
         let mut bytes = Vec::with_capacity(
-            12 + consumer_bytes.len() + stream_id_bytes.len() + topic_id_bytes.len(),
+            24 + consumer_bytes.len() + stream_id_bytes.len() + topic_id_bytes.len(),
         );
         bytes.extend(consumer_bytes);
         bytes.extend(stream_id_bytes);
@@ -88,11 +115,13 @@ impl BytesSerializable for StoreConsumerOffset {
             bytes.put_u32_le(0);
         }
         bytes.put_u64_le(self.offset);
+        bytes.put_u64_le(self.transaction_id.unwrap_or(0));
+        bytes.put_u32_le(self.generation_id.unwrap_or(0));
         bytes
     }
 
     fn from_bytes(bytes: &[u8]) -> Result<StoreConsumerOffset, Error> {
-        if bytes.len() < 23 {
+        if bytes.len() < 31 {
             return Err(Error::InvalidCommand);
         }
 
@@ -115,12 +144,26 @@ impl BytesSerializable for StoreConsumerOffset {
             Some(partition_id)
         };
         let offset = u64::from_le_bytes(bytes[position + 4..position + 12].try_into()?);
+        let transaction_id = u64::from_le_bytes(bytes[position + 12..position + 20].try_into()?);
+        let transaction_id = match transaction_id {
+            0 => None,
+            transaction_id => Some(transaction_id),
+        };
+        let generation_id = match bytes.get(position + 20..position + 24) {
+            Some(generation_id) => match u32::from_le_bytes(generation_id.try_into()?) {
+                0 => None,
+                generation_id => Some(generation_id),
+            },
+            None => None,
+        };
         let command = StoreConsumerOffset {
             consumer,
             stream_id,
             topic_id,
             partition_id,
             offset,
+            transaction_id,
+            generation_id,
         };
         command.validate()?;
         Ok(command)
@@ -131,12 +174,14 @@ impl Display for StoreConsumerOffset {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "{}|{}|{}|{}|{}",
+            "{}|{}|{}|{}|{}|{}|{}",
             self.consumer,
             self.stream_id,
             self.topic_id,
             self.partition_id.unwrap_or(0),
-            self.offset
+            self.offset,
+            self.transaction_id.unwrap_or(0),
+            self.generation_id.unwrap_or(0)
         )
     }
 }
@@ -153,6 +198,8 @@ mod tests {
             topic_id: Identifier::numeric(3).unwrap(),
             partition_id: Some(4),
             offset: 5,
+            transaction_id: Some(6),
+            generation_id: Some(7),
         };
 
         let bytes = command.as_bytes();
@@ -170,6 +217,10 @@ mod tests {
         position += topic_id.get_size_bytes() as usize;
         let partition_id = u32::from_le_bytes(bytes[position..position + 4].try_into().unwrap());
         let offset = u64::from_le_bytes(bytes[position + 4..position + 12].try_into().unwrap());
+        let transaction_id =
+            u64::from_le_bytes(bytes[position + 12..position + 20].try_into().unwrap());
+        let generation_id =
+            u32::from_le_bytes(bytes[position + 20..position + 24].try_into().unwrap());
 
         assert!(!bytes.is_empty());
         assert_eq!(consumer, command.consumer);
@@ -177,6 +228,8 @@ mod tests {
         assert_eq!(topic_id, command.topic_id);
         assert_eq!(Some(partition_id), command.partition_id);
         assert_eq!(offset, command.offset);
+        assert_eq!(Some(transaction_id), command.transaction_id);
+        assert_eq!(Some(generation_id), command.generation_id);
     }
 
     #[test]
@@ -191,13 +244,15 @@ mod tests {
         let stream_id_bytes = stream_id.as_bytes();
         let topic_id_bytes = topic_id.as_bytes();
         let mut bytes = Vec::with_capacity(
-            12 + consumer_bytes.len() + stream_id_bytes.len() + topic_id_bytes.len(),
+            20 + consumer_bytes.len() + stream_id_bytes.len() + topic_id_bytes.len(),
         );
         bytes.extend(consumer_bytes);
         bytes.extend(stream_id_bytes);
         bytes.extend(topic_id_bytes);
         bytes.put_u32_le(partition_id);
         bytes.put_u64_le(offset);
+        bytes.put_u64_le(6);
+        bytes.put_u32_le(7);
 
         let command = StoreConsumerOffset::from_bytes(&bytes);
         assert!(command.is_ok());
@@ -208,6 +263,8 @@ mod tests {
         assert_eq!(command.topic_id, topic_id);
         assert_eq!(command.partition_id, Some(partition_id));
         assert_eq!(command.offset, offset);
+        assert_eq!(command.transaction_id, Some(6));
+        assert_eq!(command.generation_id, Some(7));
     }
 
     #[test]
@@ -217,7 +274,9 @@ mod tests {
         let topic_id = Identifier::numeric(3).unwrap();
         let partition_id = 4u32;
         let offset = 5u64;
-        let input = format!("{consumer}|{stream_id}|{topic_id}|{partition_id}|{offset}");
+        let generation_id = 7u32;
+        let input =
+            format!("{consumer}|{stream_id}|{topic_id}|{partition_id}|{offset}|0|{generation_id}");
         let command = StoreConsumerOffset::from_str(&input);
         assert!(command.is_ok());
 
@@ -227,5 +286,7 @@ mod tests {
         assert_eq!(command.topic_id, topic_id);
         assert_eq!(command.partition_id, Some(partition_id));
         assert_eq!(command.offset, offset);
+        assert!(command.transaction_id.is_none());
+        assert_eq!(command.generation_id, Some(generation_id));
     }
 }
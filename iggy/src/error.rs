@@ -68,6 +68,52 @@ pub enum Error {
     CannotEncryptData,
     #[error("Cannot decrypt data")]
     CannotDecryptData,
+    #[error("Encryption key with ID: {0} was not found")]
+    EncryptionKeyNotFound(u32),
+    #[error("Server-side encryption is disabled")]
+    EncryptionDisabled,
+    #[error("Invalid JWT algorithm: {0}")]
+    InvalidJwtAlgorithm(String),
+    #[error("Invalid JWT secret")]
+    InvalidJwtSecret,
+    #[error("Cannot generate JWT")]
+    CannotGenerateJwt,
+    #[error("Access token is missing")]
+    AccessTokenMissing,
+    #[error("Invalid access token")]
+    InvalidAccessToken,
+    #[error("Invalid refresh token")]
+    InvalidRefreshToken,
+    #[error("JWT is missing")]
+    JwtMissing,
+    #[error("Invalid personal access token name")]
+    InvalidPersonalAccessTokenName,
+    #[error("Personal access token: {0} for user with ID: {1} already exists")]
+    PersonalAccessTokenAlreadyExists(String, u32),
+    #[error("Personal access token: {0} for user with ID: {1} was not found")]
+    PersonalAccessTokenNotFound(String, u32),
+    #[error("Invalid personal access token")]
+    InvalidPersonalAccessToken,
+    #[error("Personal access token: {0} for user with ID: {1} has expired")]
+    PersonalAccessTokenExpired(String, u32),
+    #[error("Invalid role name")]
+    InvalidRoleName,
+    #[error("Role: {0} already exists")]
+    RoleAlreadyExists(String),
+    #[error("User with ID: {1} is not a member of role with ID: {0}")]
+    RoleMemberNotFound(u32, u32),
+    #[error("Quota exceeded, retry after: {0} ms")]
+    QuotaExceeded(u64),
+    #[error("SCRAM credentials not found for user: {0}")]
+    ScramCredentialsNotFound(String),
+    #[error("Invalid SCRAM handshake")]
+    InvalidScramHandshake,
+    #[error("Invalid SCRAM server signature")]
+    InvalidServerSignature,
+    #[error("Password policy violation: {0}")]
+    PasswordPolicyViolation(String),
+    #[error("User with ID: {0} is locked")]
+    UserLocked(u32),
     #[error("Invalid command")]
     InvalidCommand,
     #[error("Invalid format")]
@@ -158,6 +204,14 @@ pub enum Error {
     InvalidTopicName,
     #[error("Too many partitions")]
     TooManyPartitions,
+    #[error("Invalid topic cleanup policy")]
+    InvalidTopicCleanupPolicy,
+    #[error("Invalid compaction key")]
+    InvalidCompactionKey,
+    #[error("Invalid topic retention limits")]
+    InvalidTopicRetentionLimits,
+    #[error("Invalid dead letter topic with ID: {0}")]
+    InvalidDeadLetterTopic(u32),
     #[error("Cannot append message")]
     CannotAppendMessage,
     #[error("Cannot create partition with ID: {0} for stream with ID: {1} and topic with ID: {2}")]
@@ -246,6 +300,46 @@ pub enum Error {
     InvalidHeaderValue,
     #[error("Too big headers payload")]
     TooBigHeadersPayload,
+    #[error("Invalid header filter")]
+    InvalidHeaderFilter,
+    #[error("Invalid max wait: {0} ms, the maximum is: {1} ms")]
+    InvalidMaxWait(u64, u64),
+    #[error("Invalid delivery time: {0}, the latest allowed is: {1}")]
+    InvalidDeliveryTime(u64, u64),
+    #[error("Cannot filter by encrypted header: {0}")]
+    CannotFilterEncryptedHeader(String),
+    #[error("Header key: {0} is reserved by the server")]
+    ReservedHeaderKey(String),
+    #[error("Invalid headers")]
+    InvalidHeaders,
+    #[error("Cannot compact segment with start offset: {0} for partition with ID: {1}")]
+    CannotCompactSegment(u64, u32),
+    #[error("Cannot re-encrypt segment with start offset: {0} for partition with ID: {1}")]
+    CannotReencryptSegment(u64, u32),
+    #[error("Message with offset: {0} was not found in partition with ID: {1}")]
+    MessageNotFound(u64, u32),
+    #[error("Cannot save state for message with offset: {0} in partition with ID: {1}")]
+    CannotSaveMessageState(u64, u32),
+    #[error("Invalid subscription")]
+    InvalidSubscription,
+    #[error("Invalid subscription credits")]
+    InvalidSubscriptionCredits,
+    #[error("Subscription with ID: {0} already exists.")]
+    SubscriptionAlreadyExists(u32),
+    #[error("Subscription with ID: {0} was not found.")]
+    SubscriptionNotFound(u32),
+    #[error("Invalid producer ID")]
+    InvalidProducerId,
+    #[error("Producer sequence requires the partition ID partitioning")]
+    InvalidProducerPartitioning,
+    #[error("Duplicate sequence: {1} for producer with ID: {0} in partition with ID: {2}")]
+    DuplicateProducerSequence(u64, u64, u32),
+    #[error(
+        "Invalid sequence: {1} for producer with ID: {0}, expected: {2} in partition with ID: {3}"
+    )]
+    InvalidProducerSequence(u64, u64, u64, u32),
+    #[error("Cannot generate producer ID")]
+    CannotGenerateProducerId,
     #[error("Invalid offset: {0}")]
     InvalidOffset(u64),
     #[error("Failed to read consumers offsets  for partition with ID: {0}")]
@@ -264,6 +358,16 @@ pub enum Error {
     ConsumerGroupMemberNotFound(u32, u32, u32),
     #[error("Invalid consumer group ID")]
     InvalidConsumerGroupId,
+    #[error("Invalid consumer group assignment strategy")]
+    InvalidAssignmentStrategy,
+    #[error("Generation ID: {0} is stale, the current generation ID for consumer group with ID: {2} is: {1}.")]
+    StaleGenerationId(u32, u32, u32),
+    #[error("Transaction with ID: {0} was not found.")]
+    TransactionNotFound(u64),
+    #[error("Invalid transaction ID")]
+    InvalidTransactionId,
+    #[error("Cannot generate transaction ID")]
+    CannotGenerateTransactionId,
     #[error("Feature is unavailable")]
     FeatureUnavailable,
     #[error("Client with ID: {0} was not found.")]
@@ -302,6 +406,29 @@ impl Error {
             Error::InvalidEncryptionKey => 60,
             Error::CannotEncryptData => 61,
             Error::CannotDecryptData => 62,
+            Error::EncryptionKeyNotFound(_) => 63,
+            Error::EncryptionDisabled => 64,
+            Error::InvalidJwtAlgorithm(_) => 70,
+            Error::InvalidJwtSecret => 71,
+            Error::CannotGenerateJwt => 72,
+            Error::AccessTokenMissing => 73,
+            Error::InvalidAccessToken => 74,
+            Error::InvalidRefreshToken => 75,
+            Error::JwtMissing => 76,
+            Error::InvalidPersonalAccessTokenName => 77,
+            Error::PersonalAccessTokenAlreadyExists(_, _) => 78,
+            Error::PersonalAccessTokenNotFound(_, _) => 79,
+            Error::InvalidPersonalAccessToken => 80,
+            Error::PersonalAccessTokenExpired(_, _) => 81,
+            Error::InvalidRoleName => 82,
+            Error::RoleAlreadyExists(_) => 83,
+            Error::RoleMemberNotFound(_, _) => 84,
+            Error::QuotaExceeded(_) => 85,
+            Error::ScramCredentialsNotFound(_) => 86,
+            Error::InvalidScramHandshake => 87,
+            Error::InvalidServerSignature => 88,
+            Error::PasswordPolicyViolation(_) => 89,
+            Error::UserLocked(_) => 90,
             Error::ClientNotFound(_) => 100,
             Error::InvalidClientId => 101,
             Error::IoError(_) => 200,
@@ -353,6 +480,10 @@ impl Error {
             Error::TooManyPartitions => 2015,
             Error::InvalidTopicId => 2016,
             Error::CannotReadTopics(_) => 2017,
+            Error::InvalidTopicCleanupPolicy => 2018,
+            Error::InvalidCompactionKey => 2019,
+            Error::InvalidTopicRetentionLimits => 2020,
+            Error::InvalidDeadLetterTopic(_) => 2021,
             Error::CannotCreatePartition(_, _, _) => 3000,
             Error::CannotCreatePartitionsDirectory(_, _) => 3001,
             Error::CannotCreatePartitionDirectory(_, _, _) => 3002,
@@ -391,6 +522,25 @@ impl Error {
             Error::CannotReadMessageChecksum => 4026,
             Error::InvalidMessageChecksum(_, _, _) => 4027,
             Error::InvalidKeyValueLength => 4028,
+            Error::CannotCompactSegment(_, _) => 4029,
+            Error::MessageNotFound(_, _) => 4030,
+            Error::CannotSaveMessageState(_, _) => 4031,
+            Error::InvalidSubscription => 4032,
+            Error::InvalidSubscriptionCredits => 4033,
+            Error::SubscriptionAlreadyExists(_) => 4034,
+            Error::SubscriptionNotFound(_) => 4035,
+            Error::InvalidProducerId => 4036,
+            Error::InvalidProducerPartitioning => 4037,
+            Error::DuplicateProducerSequence(_, _, _) => 4038,
+            Error::InvalidProducerSequence(_, _, _, _) => 4039,
+            Error::CannotGenerateProducerId => 4040,
+            Error::InvalidHeaderFilter => 4041,
+            Error::CannotReencryptSegment(_, _) => 4042,
+            Error::InvalidMaxWait(_, _) => 4043,
+            Error::InvalidDeliveryTime(_, _) => 4044,
+            Error::CannotFilterEncryptedHeader(_) => 4045,
+            Error::ReservedHeaderKey(_) => 4046,
+            Error::InvalidHeaders => 4047,
             Error::InvalidOffset(_) => 4100,
             Error::CannotReadConsumerOffsets(_) => 4101,
             Error::ConsumerGroupNotFound(_, _) => 5000,
@@ -401,6 +551,11 @@ impl Error {
             Error::CannotReadConsumerGroups(_, _) => 5005,
             Error::CannotCreateConsumerGroupInfo(_, _, _) => 5006,
             Error::CannotDeleteConsumerGroupInfo(_, _, _) => 5007,
+            Error::InvalidAssignmentStrategy => 5008,
+            Error::StaleGenerationId(_, _, _) => 5009,
+            Error::TransactionNotFound(_) => 6000,
+            Error::InvalidTransactionId => 6001,
+            Error::CannotGenerateTransactionId => 6002,
         }
     }
 
@@ -433,6 +588,29 @@ impl Error {
             60 => "invalid_encryption_key",
             61 => "cannot_encrypt_data",
             62 => "cannot_decrypt_data",
+            63 => "encryption_key_not_found",
+            64 => "encryption_disabled",
+            70 => "invalid_jwt_algorithm",
+            71 => "invalid_jwt_secret",
+            72 => "cannot_generate_jwt",
+            73 => "access_token_missing",
+            74 => "invalid_access_token",
+            75 => "invalid_refresh_token",
+            76 => "jwt_missing",
+            77 => "invalid_personal_access_token_name",
+            78 => "personal_access_token_already_exists",
+            79 => "personal_access_token_not_found",
+            80 => "invalid_personal_access_token",
+            81 => "personal_access_token_expired",
+            82 => "invalid_role_name",
+            83 => "role_already_exists",
+            84 => "role_member_not_found",
+            85 => "quota_exceeded",
+            86 => "scram_credentials_not_found",
+            87 => "invalid_scram_handshake",
+            88 => "invalid_server_signature",
+            89 => "password_policy_violation",
+            90 => "user_locked",
             100 => "client_not_found",
             101 => "invalid_client_id",
             200 => "io_error",
@@ -484,6 +662,10 @@ impl Error {
             2015 => "too_many_partitions",
             2016 => "invalid_topic_id",
             2017 => "cannot_read_topics",
+            2018 => "invalid_topic_cleanup_policy",
+            2019 => "invalid_compaction_key",
+            2020 => "invalid_topic_retention_limits",
+            2021 => "invalid_dead_letter_topic",
             3000 => "cannot_create_partition",
             3001 => "cannot_create_partitions_directory",
             3002 => "cannot_create_partition_directory",
@@ -522,6 +704,25 @@ impl Error {
             4026 => "cannot_read_message_checksum",
             4027 => "invalid_message_checksum",
             4028 => "invalid_key_value_length",
+            4029 => "cannot_compact_segment",
+            4030 => "message_not_found",
+            4031 => "cannot_save_message_state",
+            4032 => "invalid_subscription",
+            4033 => "invalid_subscription_credits",
+            4034 => "subscription_already_exists",
+            4035 => "subscription_not_found",
+            4036 => "invalid_producer_id",
+            4037 => "invalid_producer_partitioning",
+            4038 => "duplicate_producer_sequence",
+            4039 => "invalid_producer_sequence",
+            4040 => "cannot_generate_producer_id",
+            4041 => "invalid_header_filter",
+            4042 => "cannot_reencrypt_segment",
+            4043 => "invalid_max_wait",
+            4044 => "invalid_delivery_time",
+            4045 => "cannot_filter_encrypted_header",
+            4046 => "reserved_header_key",
+            4047 => "invalid_headers",
             4100 => "invalid_offset",
             4101 => "cannot_read_consumer_offsets",
             5000 => "consumer_group_not_found",
@@ -532,6 +733,11 @@ impl Error {
             5005 => "cannot_read_consumer_groups",
             5006 => "cannot_create_consumer_group_info",
             5007 => "cannot_delete_consumer_group_info",
+            5008 => "invalid_assignment_strategy",
+            5009 => "stale_generation_id",
+            6000 => "transaction_not_found",
+            6001 => "invalid_transaction_id",
+            6002 => "cannot_generate_transaction_id",
             _ => "error",
         }
     }
@@ -561,6 +767,29 @@ impl Error {
             Error::InvalidEncryptionKey => "invalid_encryption_key",
             Error::CannotEncryptData => "cannot_encrypt_data",
             Error::CannotDecryptData => "cannot_decrypt_data",
+            Error::EncryptionKeyNotFound(_) => "encryption_key_not_found",
+            Error::EncryptionDisabled => "encryption_disabled",
+            Error::InvalidJwtAlgorithm(_) => "invalid_jwt_algorithm",
+            Error::InvalidJwtSecret => "invalid_jwt_secret",
+            Error::CannotGenerateJwt => "cannot_generate_jwt",
+            Error::AccessTokenMissing => "access_token_missing",
+            Error::InvalidAccessToken => "invalid_access_token",
+            Error::InvalidRefreshToken => "invalid_refresh_token",
+            Error::JwtMissing => "jwt_missing",
+            Error::InvalidPersonalAccessTokenName => "invalid_personal_access_token_name",
+            Error::PersonalAccessTokenAlreadyExists(_, _) => "personal_access_token_already_exists",
+            Error::PersonalAccessTokenNotFound(_, _) => "personal_access_token_not_found",
+            Error::InvalidPersonalAccessToken => "invalid_personal_access_token",
+            Error::PersonalAccessTokenExpired(_, _) => "personal_access_token_expired",
+            Error::InvalidRoleName => "invalid_role_name",
+            Error::RoleAlreadyExists(_) => "role_already_exists",
+            Error::RoleMemberNotFound(_, _) => "role_member_not_found",
+            Error::QuotaExceeded(_) => "quota_exceeded",
+            Error::ScramCredentialsNotFound(_) => "scram_credentials_not_found",
+            Error::InvalidScramHandshake => "invalid_scram_handshake",
+            Error::InvalidServerSignature => "invalid_server_signature",
+            Error::PasswordPolicyViolation(_) => "password_policy_violation",
+            Error::UserLocked(_) => "user_locked",
             Error::CannotCreateBaseDirectory => "cannot_create_base_directory",
             Error::CannotCreateStreamsDirectory => "cannot_create_streams_directory",
             Error::CannotCreateStreamDirectory(_) => "cannot_create_stream_directory",
@@ -625,6 +854,12 @@ impl Error {
             Error::TooBigHeadersPayload => "too_big_headers_payload",
             Error::InvalidHeaderKey => "invalid_header_key",
             Error::InvalidHeaderValue => "invalid_header_value",
+            Error::InvalidHeaderFilter => "invalid_header_filter",
+            Error::InvalidMaxWait(_, _) => "invalid_max_wait",
+            Error::InvalidDeliveryTime(_, _) => "invalid_delivery_time",
+            Error::CannotFilterEncryptedHeader(_) => "cannot_filter_encrypted_header",
+            Error::ReservedHeaderKey(_) => "reserved_header_key",
+            Error::InvalidHeaders => "invalid_headers",
             Error::TooManyMessages => "too_many_messages",
             Error::WriteError(_) => "write_error",
             Error::InvalidOffset(_) => "invalid_offset",
@@ -636,6 +871,23 @@ impl Error {
             Error::EmptyMessagePayload => "empty_message_payload",
             Error::CannotReadStreams => "cannot_read_streams",
             Error::CannotReadTopics(_) => "cannot_read_topics",
+            Error::InvalidTopicCleanupPolicy => "invalid_topic_cleanup_policy",
+            Error::InvalidCompactionKey => "invalid_compaction_key",
+            Error::InvalidTopicRetentionLimits => "invalid_topic_retention_limits",
+            Error::InvalidDeadLetterTopic(_) => "invalid_dead_letter_topic",
+            Error::CannotCompactSegment(_, _) => "cannot_compact_segment",
+            Error::CannotReencryptSegment(_, _) => "cannot_reencrypt_segment",
+            Error::MessageNotFound(_, _) => "message_not_found",
+            Error::CannotSaveMessageState(_, _) => "cannot_save_message_state",
+            Error::InvalidSubscription => "invalid_subscription",
+            Error::InvalidSubscriptionCredits => "invalid_subscription_credits",
+            Error::SubscriptionAlreadyExists(_) => "subscription_already_exists",
+            Error::SubscriptionNotFound(_) => "subscription_not_found",
+            Error::InvalidProducerId => "invalid_producer_id",
+            Error::InvalidProducerPartitioning => "invalid_producer_partitioning",
+            Error::DuplicateProducerSequence(_, _, _) => "duplicate_producer_sequence",
+            Error::InvalidProducerSequence(_, _, _, _) => "invalid_producer_sequence",
+            Error::CannotGenerateProducerId => "cannot_generate_producer_id",
             Error::CannotReadMessageChecksum => "cannot_read_message_checksum",
             Error::InvalidMessageChecksum(_, _, _) => "invalid_message_checksum",
             Error::InvalidKeyValueLength => "invalid_key_value_length",
@@ -650,6 +902,8 @@ impl Error {
             Error::CannotReadConsumerGroups(_, _) => "cannot_read_consumer_groups",
             Error::CannotCreateConsumerGroupInfo(_, _, _) => "cannot_create_consumer_group_info",
             Error::CannotDeleteConsumerGroupInfo(_, _, _) => "cannot_delete_consumer_group_info",
+            Error::InvalidAssignmentStrategy => "invalid_assignment_strategy",
+            Error::StaleGenerationId(_, _, _) => "stale_generation_id",
             Error::ClientNotFound(_) => "client_not_found",
             Error::InvalidClientId => "invalid_client_id",
             Error::EmptyResponse => "empty_response",
@@ -666,6 +920,9 @@ impl Error {
             Error::ConnectionError(_) => "connection_error",
             Error::ReadToEndError(_) => "read_to_end_error",
             Error::InvalidConsumerGroupId => "invalid_consumer_group_id",
+            Error::TransactionNotFound(_) => "transaction_not_found",
+            Error::InvalidTransactionId => "invalid_transaction_id",
+            Error::CannotGenerateTransactionId => "cannot_generate_transaction_id",
         }
     }
 }
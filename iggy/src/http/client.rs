@@ -1,17 +1,24 @@
 use crate::client::Client;
 use crate::error::Error;
 use crate::http::config::HttpClientConfig;
+use crate::models::identity_info::IdentityInfo;
+use crate::users::refresh_token::RefreshToken;
 use async_trait::async_trait;
 use reqwest::{Response, Url};
-use reqwest_middleware::{ClientBuilder, ClientWithMiddleware};
+use reqwest_middleware::{ClientBuilder, ClientWithMiddleware, RequestBuilder};
 use reqwest_retry::{policies::ExponentialBackoff, RetryTransientMiddleware};
 use serde::Serialize;
 use std::sync::Arc;
+use tokio::sync::RwLock;
+
+const REFRESH_TOKEN_PATH: &str = "/users/refresh-token";
 
 #[derive(Debug)]
 pub struct HttpClient {
     pub api_url: Url,
     client: ClientWithMiddleware,
+    access_token: RwLock<String>,
+    refresh_token: RwLock<String>,
 }
 
 #[async_trait]
@@ -52,12 +59,17 @@ impl HttpClient {
             .with(RetryTransientMiddleware::new_with_policy(retry_policy))
             .build();
 
-        Ok(Self { api_url, client })
+        Ok(Self {
+            api_url,
+            client,
+            access_token: RwLock::new(String::new()),
+            refresh_token: RwLock::new(String::new()),
+        })
     }
 
     pub async fn get(&self, path: &str) -> Result<Response, Error> {
         let url = self.get_url(path)?;
-        let response = self.client.get(url).send().await?;
+        let response = self.with_token(self.client.get(url)).await.send().await?;
         Self::handle_response(response).await
     }
 
@@ -67,7 +79,12 @@ impl HttpClient {
         query: &T,
     ) -> Result<Response, Error> {
         let url = self.get_url(path)?;
-        let response = self.client.get(url).query(query).send().await?;
+        let response = self
+            .with_token(self.client.get(url))
+            .await
+            .query(query)
+            .send()
+            .await?;
         Self::handle_response(response).await
     }
 
@@ -77,7 +94,12 @@ impl HttpClient {
         payload: &T,
     ) -> Result<Response, Error> {
         let url = self.get_url(path)?;
-        let response = self.client.post(url).json(payload).send().await?;
+        let response = self
+            .with_token(self.client.post(url))
+            .await
+            .json(payload)
+            .send()
+            .await?;
         Self::handle_response(response).await
     }
 
@@ -87,13 +109,22 @@ impl HttpClient {
         payload: &T,
     ) -> Result<Response, Error> {
         let url = self.get_url(path)?;
-        let response = self.client.put(url).json(payload).send().await?;
+        let response = self
+            .with_token(self.client.put(url))
+            .await
+            .json(payload)
+            .send()
+            .await?;
         Self::handle_response(response).await
     }
 
     pub async fn delete(&self, path: &str) -> Result<Response, Error> {
         let url = self.get_url(path)?;
-        let response = self.client.delete(url).send().await?;
+        let response = self
+            .with_token(self.client.delete(url))
+            .await
+            .send()
+            .await?;
         Self::handle_response(response).await
     }
 
@@ -103,14 +134,60 @@ impl HttpClient {
         query: &T,
     ) -> Result<Response, Error> {
         let url = self.get_url(path)?;
-        let response = self.client.delete(url).query(query).send().await?;
+        let response = self
+            .with_token(self.client.delete(url))
+            .await
+            .query(query)
+            .send()
+            .await?;
         Self::handle_response(response).await
     }
 
+    pub async fn set_tokens_from_identity(&self, identity: &IdentityInfo) -> Result<(), Error> {
+        if identity.tokens.is_none() {
+            return Err(Error::JwtMissing);
+        }
+
+        let tokens = identity.tokens.as_ref().unwrap();
+        self.set_access_token(Some(tokens.access_token.token.clone()))
+            .await;
+        self.set_refresh_token(Some(tokens.refresh_token.token.clone()))
+            .await;
+        Ok(())
+    }
+
+    pub async fn set_access_token(&self, token: Option<String>) {
+        *self.access_token.write().await = token.unwrap_or_default();
+    }
+
+    pub async fn set_refresh_token(&self, token: Option<String>) {
+        *self.refresh_token.write().await = token.unwrap_or_default();
+    }
+
+    pub async fn refresh_access_token(&self) -> Result<(), Error> {
+        let refresh_token = self.refresh_token.read().await.clone();
+        if refresh_token.is_empty() {
+            return Err(Error::InvalidRefreshToken);
+        }
+
+        let command = RefreshToken { refresh_token };
+        let response = self.post(REFRESH_TOKEN_PATH, &command).await?;
+        let identity: IdentityInfo = response.json().await?;
+        self.set_tokens_from_identity(&identity).await
+    }
+
     pub fn get_url(&self, path: &str) -> Result<Url, Error> {
         self.api_url.join(path).map_err(|_| Error::CannotParseUrl)
     }
 
+    async fn with_token(&self, request: RequestBuilder) -> RequestBuilder {
+        let access_token = self.access_token.read().await;
+        match access_token.is_empty() {
+            true => request,
+            false => request.bearer_auth(access_token.as_str()),
+        }
+    }
+
     async fn handle_response(response: Response) -> Result<Response, Error> {
         match response.status().is_success() {
             true => Ok(response),
@@ -2,12 +2,14 @@ use crate::client::ConsumerGroupClient;
 use crate::consumer_groups::create_consumer_group::CreateConsumerGroup;
 use crate::consumer_groups::delete_consumer_group::DeleteConsumerGroup;
 use crate::consumer_groups::get_consumer_group::GetConsumerGroup;
+use crate::consumer_groups::get_consumer_group_assignment::GetConsumerGroupAssignment;
 use crate::consumer_groups::get_consumer_groups::GetConsumerGroups;
+use crate::consumer_groups::heartbeat_consumer_group::HeartbeatConsumerGroup;
 use crate::consumer_groups::join_consumer_group::JoinConsumerGroup;
 use crate::consumer_groups::leave_consumer_group::LeaveConsumerGroup;
 use crate::error::Error;
 use crate::http::client::HttpClient;
-use crate::models::consumer_group::{ConsumerGroup, ConsumerGroupDetails};
+use crate::models::consumer_group::{ConsumerGroup, ConsumerGroupAssignment, ConsumerGroupDetails};
 use async_trait::async_trait;
 
 #[async_trait]
@@ -76,6 +78,20 @@ impl ConsumerGroupClient for HttpClient {
     async fn leave_consumer_group(&self, _command: &LeaveConsumerGroup) -> Result<(), Error> {
         Err(Error::FeatureUnavailable)
     }
+
+    async fn heartbeat_consumer_group(
+        &self,
+        _command: &HeartbeatConsumerGroup,
+    ) -> Result<(), Error> {
+        Err(Error::FeatureUnavailable)
+    }
+
+    async fn get_consumer_group_assignment(
+        &self,
+        _command: &GetConsumerGroupAssignment,
+    ) -> Result<ConsumerGroupAssignment, Error> {
+        Err(Error::FeatureUnavailable)
+    }
 }
 
 fn get_path(stream_id: &str, topic_id: &str) -> String {
@@ -1,9 +1,13 @@
 use crate::client::ConsumerOffsetClient;
+use crate::consumer_offsets::get_consumer_lag::GetConsumerLag;
 use crate::consumer_offsets::get_consumer_offset::GetConsumerOffset;
+use crate::consumer_offsets::reset_consumer_offsets::ResetConsumerOffsets;
 use crate::consumer_offsets::store_consumer_offset::StoreConsumerOffset;
 use crate::error::Error;
 use crate::http::client::HttpClient;
+use crate::models::consumer_lag::ConsumerLag;
 use crate::models::consumer_offset_info::ConsumerOffsetInfo;
+use crate::models::consumer_offset_reset::ConsumerOffsetReset;
 use async_trait::async_trait;
 
 #[async_trait]
@@ -36,6 +40,43 @@ impl ConsumerOffsetClient for HttpClient {
         let offset = response.json().await?;
         Ok(offset)
     }
+
+    async fn get_consumer_lag(&self, command: &GetConsumerLag) -> Result<Vec<ConsumerLag>, Error> {
+        let response = self
+            .get_with_query(
+                &format!(
+                    "{}/lag",
+                    get_path(
+                        &command.stream_id.as_string(),
+                        &command.topic_id.as_string()
+                    )
+                ),
+                &command,
+            )
+            .await?;
+        let lags = response.json().await?;
+        Ok(lags)
+    }
+
+    async fn reset_consumer_offsets(
+        &self,
+        command: &ResetConsumerOffsets,
+    ) -> Result<Vec<ConsumerOffsetReset>, Error> {
+        let response = self
+            .post(
+                &format!(
+                    "{}/reset",
+                    get_path(
+                        &command.stream_id.as_string(),
+                        &command.topic_id.as_string()
+                    )
+                ),
+                &command,
+            )
+            .await?;
+        let resets = response.json().await?;
+        Ok(resets)
+    }
 }
 
 fn get_path(stream_id: &str, topic_id: &str) -> String {
@@ -1,10 +1,19 @@
 use crate::client::MessageClient;
 use crate::error::Error;
 use crate::http::client::HttpClient;
+use crate::messages::grant_credits::GrantCredits;
+use crate::messages::init_producer::InitProducer;
+use crate::messages::poison_message::PoisonMessage;
 use crate::messages::poll_messages::PollMessages;
 use crate::messages::send_messages::SendMessages;
+use crate::messages::subscribe::Subscribe;
+use crate::messages::unsubscribe::Unsubscribe;
 use crate::models::messages::PolledMessages;
+use crate::models::producer_info::ProducerInfo;
 use async_trait::async_trait;
+use flume::Receiver;
+
+const PRODUCERS: &str = "/producers";
 
 #[async_trait]
 impl MessageClient for HttpClient {
@@ -33,6 +42,39 @@ impl MessageClient for HttpClient {
         .await?;
         Ok(())
     }
+
+    async fn poison_message(&self, command: &PoisonMessage) -> Result<(), Error> {
+        self.put(
+            &format!(
+                "{}/poison",
+                get_path(
+                    &command.stream_id.as_string(),
+                    &command.topic_id.as_string()
+                )
+            ),
+            &command,
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn subscribe(&self, _command: &Subscribe) -> Result<Receiver<PolledMessages>, Error> {
+        Err(Error::FeatureUnavailable)
+    }
+
+    async fn grant_credits(&self, _command: &GrantCredits) -> Result<(), Error> {
+        Err(Error::FeatureUnavailable)
+    }
+
+    async fn unsubscribe(&self, _command: &Unsubscribe) -> Result<(), Error> {
+        Err(Error::FeatureUnavailable)
+    }
+
+    async fn init_producer(&self, command: &InitProducer) -> Result<ProducerInfo, Error> {
+        let response = self.post(PRODUCERS, &command).await?;
+        let producer = response.json().await?;
+        Ok(producer)
+    }
 }
 
 fn get_path(stream_id: &str, topic_id: &str) -> String {
@@ -0,0 +1,63 @@
+use crate::client::RoleClient;
+use crate::error::Error;
+use crate::http::client::HttpClient;
+use crate::models::role_info::{RoleInfo, RoleInfoDetails};
+use crate::roles::add_role_member::AddRoleMember;
+use crate::roles::create_role::CreateRole;
+use crate::roles::delete_role::DeleteRole;
+use crate::roles::get_role::GetRole;
+use crate::roles::get_roles::GetRoles;
+use crate::roles::remove_role_member::RemoveRoleMember;
+use crate::roles::update_role::UpdateRole;
+use async_trait::async_trait;
+
+const PATH: &str = "/roles";
+
+#[async_trait]
+impl RoleClient for HttpClient {
+    async fn get_role(&self, command: &GetRole) -> Result<RoleInfoDetails, Error> {
+        let response = self.get(&format!("{PATH}/{}", command.role_id)).await?;
+        let role = response.json().await?;
+        Ok(role)
+    }
+
+    async fn get_roles(&self, _command: &GetRoles) -> Result<Vec<RoleInfo>, Error> {
+        let response = self.get(PATH).await?;
+        let roles = response.json().await?;
+        Ok(roles)
+    }
+
+    async fn create_role(&self, command: &CreateRole) -> Result<(), Error> {
+        self.post(PATH, &command).await?;
+        Ok(())
+    }
+
+    async fn delete_role(&self, command: &DeleteRole) -> Result<(), Error> {
+        self.delete(&format!("{PATH}/{}", command.role_id)).await?;
+        Ok(())
+    }
+
+    async fn update_role(&self, command: &UpdateRole) -> Result<(), Error> {
+        self.put(&format!("{PATH}/{}", command.role_id), &command)
+            .await?;
+        Ok(())
+    }
+
+    async fn add_role_member(&self, command: &AddRoleMember) -> Result<(), Error> {
+        self.post(
+            &format!("{PATH}/{}/members/{}", command.role_id, command.user_id),
+            &command,
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn remove_role_member(&self, command: &RemoveRoleMember) -> Result<(), Error> {
+        self.delete(&format!(
+            "{PATH}/{}/members/{}",
+            command.role_id, command.user_id
+        ))
+        .await?;
+        Ok(())
+    }
+}
@@ -6,12 +6,15 @@ use crate::models::stats::Stats;
 use crate::system::get_client::GetClient;
 use crate::system::get_clients::GetClients;
 use crate::system::get_me::GetMe;
+use crate::system::get_sessions::GetSessions;
 use crate::system::get_stats::GetStats;
 use crate::system::ping::Ping;
+use crate::system::revoke_session::RevokeSession;
 use async_trait::async_trait;
 
 const PING: &str = "/ping";
 const CLIENTS: &str = "/clients";
+const SESSIONS: &str = "/sessions";
 const STATS: &str = "/stats";
 
 #[async_trait]
@@ -39,6 +42,18 @@ impl SystemClient for HttpClient {
         Ok(clients)
     }
 
+    async fn get_sessions(&self, command: &GetSessions) -> Result<Vec<ClientInfo>, Error> {
+        let response = self.get_with_query(SESSIONS, command).await?;
+        let sessions = response.json().await?;
+        Ok(sessions)
+    }
+
+    async fn revoke_session(&self, command: &RevokeSession) -> Result<(), Error> {
+        let path = format!("{}/{}", SESSIONS, command.client_id);
+        self.delete(&path).await?;
+        Ok(())
+    }
+
     async fn ping(&self, _command: &Ping) -> Result<(), Error> {
         self.get(PING).await?;
         Ok(())
@@ -1,6 +1,7 @@
 use crate::client::UserClient;
 use crate::error::Error;
 use crate::http::client::HttpClient;
+use crate::models::identity_info::IdentityInfo;
 use crate::models::user_info::{UserInfo, UserInfoDetails};
 use crate::users::change_password::ChangePassword;
 use crate::users::create_user::CreateUser;
@@ -9,7 +10,9 @@ use crate::users::get_user::GetUser;
 use crate::users::get_users::GetUsers;
 use crate::users::login_user::LoginUser;
 use crate::users::logout_user::LogoutUser;
+use crate::users::unlock_user::UnlockUser;
 use crate::users::update_permissions::UpdatePermissions;
+use crate::users::update_quotas::UpdateQuotas;
 use crate::users::update_user::UpdateUser;
 use async_trait::async_trait;
 
@@ -39,6 +42,12 @@ impl UserClient for HttpClient {
         Ok(())
     }
 
+    async fn unlock_user(&self, command: &UnlockUser) -> Result<(), Error> {
+        self.put(&format!("{PATH}/{}/unlock", command.user_id), &command)
+            .await?;
+        Ok(())
+    }
+
     async fn update_user(&self, command: &UpdateUser) -> Result<(), Error> {
         self.put(&format!("{PATH}/{}", command.user_id), &command)
             .await?;
@@ -51,6 +60,12 @@ impl UserClient for HttpClient {
         Ok(())
     }
 
+    async fn update_quotas(&self, command: &UpdateQuotas) -> Result<(), Error> {
+        self.put(&format!("{PATH}/{}/quotas", command.user_id), &command)
+            .await?;
+        Ok(())
+    }
+
     async fn change_password(&self, command: &ChangePassword) -> Result<(), Error> {
         self.put(&format!("{PATH}/{}/password", command.user_id), &command)
             .await?;
@@ -58,12 +73,16 @@ impl UserClient for HttpClient {
     }
 
     async fn login_user(&self, command: &LoginUser) -> Result<(), Error> {
-        self.post(&format!("{PATH}/login"), &command).await?;
+        let response = self.post(&format!("{PATH}/login"), &command).await?;
+        let identity: IdentityInfo = response.json().await?;
+        self.set_tokens_from_identity(&identity).await?;
         Ok(())
     }
 
     async fn logout_user(&self, command: &LogoutUser) -> Result<(), Error> {
         self.post(&format!("{PATH}/logout"), &command).await?;
+        self.set_access_token(None).await;
+        self.set_refresh_token(None).await;
         Ok(())
     }
 }
@@ -157,6 +157,9 @@ impl BytesSerializable for Identifier {
 
         let kind = IdKind::from_code(bytes[0])?;
         let length = bytes[1];
+        if bytes.len() < 2 + length as usize {
+            return Err(Error::InvalidCommand);
+        }
         let value = bytes[2..2 + length as usize].to_vec();
         if value.len() != length as usize {
             return Err(Error::InvalidCommand);
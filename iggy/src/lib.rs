@@ -17,11 +17,14 @@ pub mod messages;
 pub mod models;
 pub mod partitioner;
 pub mod partitions;
+pub mod personal_access_tokens;
 pub mod quic;
+pub mod roles;
 pub mod streams;
 pub mod system;
 pub mod tcp;
 pub mod topics;
+pub mod transactions;
 pub mod users;
 pub mod utils;
 pub mod validatable;
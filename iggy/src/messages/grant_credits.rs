@@ -0,0 +1,134 @@
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::CommandPayload;
+use crate::error::Error;
+use crate::validatable::Validatable;
+use bytes::BufMut;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::str::FromStr;
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct GrantCredits {
+    pub subscription_id: u32,
+    pub credits: u32,
+}
+
+impl Default for GrantCredits {
+    fn default() -> Self {
+        GrantCredits {
+            subscription_id: 1,
+            credits: 100,
+        }
+    }
+}
+
+impl CommandPayload for GrantCredits {}
+
+impl Validatable<Error> for GrantCredits {
+    fn validate(&self) -> Result<(), Error> {
+        if self.credits == 0 {
+            return Err(Error::InvalidSubscriptionCredits);
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for GrantCredits {
+    type Err = Error;
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let parts = input.split('|').collect::<Vec<&str>>();
+        if parts.len() != 2 {
+            return Err(Error::InvalidCommand);
+        }
+
+        let subscription_id = parts[0].parse::<u32>()?;
+        let credits = parts[1].parse::<u32>()?;
+        let command = GrantCredits {
+            subscription_id,
+            credits,
+        };
+        command.validate()?;
+        Ok(command)
+    }
+}
+
+impl BytesSerializable for GrantCredits {
+    fn as_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(8);
+        bytes.put_u32_le(self.subscription_id);
+        bytes.put_u32_le(self.credits);
+        bytes
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<GrantCredits, Error> {
+        if bytes.len() != 8 {
+            return Err(Error::InvalidCommand);
+        }
+
+        let subscription_id = u32::from_le_bytes(bytes[0..4].try_into()?);
+        let credits = u32::from_le_bytes(bytes[4..8].try_into()?);
+        let command = GrantCredits {
+            subscription_id,
+            credits,
+        };
+        command.validate()?;
+        Ok(command)
+    }
+}
+
+impl Display for GrantCredits {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}|{}", self.subscription_id, self.credits)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_bytes() {
+        let command = GrantCredits {
+            subscription_id: 1,
+            credits: 2,
+        };
+
+        let bytes = command.as_bytes();
+        let subscription_id = u32::from_le_bytes(bytes[0..4].try_into().unwrap());
+        let credits = u32::from_le_bytes(bytes[4..8].try_into().unwrap());
+
+        assert!(!bytes.is_empty());
+        assert_eq!(subscription_id, command.subscription_id);
+        assert_eq!(credits, command.credits);
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes() {
+        let subscription_id = 1u32;
+        let credits = 2u32;
+        let mut bytes = Vec::with_capacity(8);
+        bytes.put_u32_le(subscription_id);
+        bytes.put_u32_le(credits);
+
+        let command = GrantCredits::from_bytes(&bytes);
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.subscription_id, subscription_id);
+        assert_eq!(command.credits, credits);
+    }
+
+    #[test]
+    fn should_be_read_from_string() {
+        let subscription_id = 1u32;
+        let credits = 2u32;
+        let input = format!("{subscription_id}|{credits}");
+        let command = GrantCredits::from_str(&input);
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.subscription_id, subscription_id);
+        assert_eq!(command.credits, credits);
+    }
+}
@@ -1,2 +1,7 @@
+pub mod grant_credits;
+pub mod init_producer;
+pub mod poison_message;
 pub mod poll_messages;
 pub mod send_messages;
+pub mod subscribe;
+pub mod unsubscribe;
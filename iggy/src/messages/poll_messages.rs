@@ -3,13 +3,16 @@ use crate::command::CommandPayload;
 use crate::consumer::{Consumer, ConsumerKind};
 use crate::error::Error;
 use crate::identifier::Identifier;
+use crate::models::header_filter::HeaderFilter;
 use crate::validatable::Validatable;
 use bytes::BufMut;
 use serde::{Deserialize, Serialize};
-use serde_with::{serde_as, DisplayFromStr};
+use serde_with::formats::CommaSeparator;
+use serde_with::{serde_as, DisplayFromStr, StringWithSeparator};
 use std::fmt::Display;
 use std::str::FromStr;
 
+#[serde_as]
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
 pub struct PollMessages {
     #[serde(flatten)]
@@ -26,6 +29,15 @@ pub struct PollMessages {
     pub count: u32,
     #[serde(default)]
     pub auto_commit: bool,
+    #[serde(default)]
+    pub include_all_states: bool,
+    #[serde(default)]
+    pub max_wait_ms: Option<u64>,
+    #[serde(default)]
+    pub isolation_level: IsolationLevel,
+    #[serde_as(as = "StringWithSeparator::<CommaSeparator, HeaderFilter>")]
+    #[serde(default)]
+    pub filters: Vec<HeaderFilter>,
 }
 
 #[serde_as]
@@ -50,6 +62,14 @@ pub enum PollingKind {
     Next,
 }
 
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default, Copy, Clone)]
+#[serde(rename_all = "snake_case")]
+pub enum IsolationLevel {
+    #[default]
+    ReadUncommitted,
+    ReadCommitted,
+}
+
 impl Default for PollMessages {
     fn default() -> Self {
         Self {
@@ -60,6 +80,10 @@ impl Default for PollMessages {
             strategy: default_strategy(),
             count: default_count(),
             auto_commit: false,
+            include_all_states: false,
+            max_wait_ms: None,
+            isolation_level: IsolationLevel::default(),
+            filters: vec![],
         }
     }
 }
@@ -75,6 +99,9 @@ impl Default for PollingStrategy {
 
 impl CommandPayload for PollMessages {}
 
+const MAX_HEADER_FILTERS: usize = 255;
+pub const MAX_WAIT_MS: u64 = 60_000;
+
 fn default_partition_id() -> Option<u32> {
     Some(1)
 }
@@ -97,6 +124,20 @@ fn default_count() -> u32 {
 
 impl Validatable<Error> for PollMessages {
     fn validate(&self) -> Result<(), Error> {
+        if self.filters.len() > MAX_HEADER_FILTERS {
+            return Err(Error::InvalidHeaderFilter);
+        }
+
+        for filter in &self.filters {
+            filter.validate()?;
+        }
+
+        if let Some(max_wait_ms) = self.max_wait_ms {
+            if max_wait_ms > MAX_WAIT_MS {
+                return Err(Error::InvalidMaxWait(max_wait_ms, MAX_WAIT_MS));
+            }
+        }
+
         Ok(())
     }
 }
@@ -187,6 +228,43 @@ impl Display for PollingKind {
     }
 }
 
+impl IsolationLevel {
+    pub fn as_code(&self) -> u8 {
+        match self {
+            IsolationLevel::ReadUncommitted => 1,
+            IsolationLevel::ReadCommitted => 2,
+        }
+    }
+
+    pub fn from_code(code: u8) -> Result<Self, Error> {
+        match code {
+            1 => Ok(IsolationLevel::ReadUncommitted),
+            2 => Ok(IsolationLevel::ReadCommitted),
+            _ => Err(Error::InvalidCommand),
+        }
+    }
+}
+
+impl FromStr for IsolationLevel {
+    type Err = Error;
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "u" | "read_uncommitted" => Ok(IsolationLevel::ReadUncommitted),
+            "c" | "read_committed" => Ok(IsolationLevel::ReadCommitted),
+            _ => Err(Error::InvalidCommand),
+        }
+    }
+}
+
+impl Display for IsolationLevel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            IsolationLevel::ReadUncommitted => write!(f, "read_uncommitted"),
+            IsolationLevel::ReadCommitted => write!(f, "read_committed"),
+        }
+    }
+}
+
 impl FromStr for PollMessages {
     type Err = Error;
     fn from_str(input: &str) -> Result<Self, Self::Err> {
@@ -219,6 +297,23 @@ impl FromStr for PollMessages {
             },
             None => false,
         };
+        let include_all_states = matches!(parts.get(9), Some(&"i" | &"include_all_states"));
+        let max_wait_ms = match parts.get(10).map(|max_wait_ms| max_wait_ms.parse::<u64>()) {
+            Some(Ok(0)) | Some(Err(_)) | None => None,
+            Some(Ok(max_wait_ms)) => Some(max_wait_ms),
+        };
+        let isolation_level = match parts.get(11) {
+            Some(isolation_level) => IsolationLevel::from_str(isolation_level)?,
+            None => IsolationLevel::default(),
+        };
+        let filters = match parts.get(12) {
+            Some(filters) => filters
+                .split(',')
+                .filter(|filter| !filter.is_empty())
+                .map(HeaderFilter::from_str)
+                .collect::<Result<Vec<HeaderFilter>, Error>>()?,
+            None => vec![],
+        };
 
         let command = PollMessages {
             consumer,
@@ -228,6 +323,10 @@ impl FromStr for PollMessages {
             strategy,
             count,
             auto_commit,
+            include_all_states,
+            max_wait_ms,
+            isolation_level,
+            filters,
         };
         command.validate()?;
         Ok(command)
@@ -241,7 +340,7 @@ impl BytesSerializable for PollMessages {
         let topic_id_bytes = self.topic_id.as_bytes();
         let strategy_bytes = self.strategy.as_bytes();
         let mut bytes = Vec::with_capacity(
-            9 + consumer_bytes.len()
+            19 + consumer_bytes.len()
                 + stream_id_bytes.len()
                 + topic_id_bytes.len()
                 + strategy_bytes.len(),
@@ -261,12 +360,24 @@ impl BytesSerializable for PollMessages {
         } else {
             bytes.put_u8(0);
         }
+        if self.include_all_states {
+            bytes.put_u8(1);
+        } else {
+            bytes.put_u8(0);
+        }
+        bytes.put_u64_le(self.max_wait_ms.unwrap_or(0));
+        bytes.put_u8(self.isolation_level.as_code());
+        #[allow(clippy::cast_possible_truncation)]
+        bytes.put_u8(self.filters.len() as u8);
+        for filter in &self.filters {
+            bytes.extend(filter.as_bytes());
+        }
 
         bytes
     }
 
     fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
-        if bytes.len() < 29 {
+        if bytes.len() < 39 {
             return Err(Error::InvalidCommand);
         }
 
@@ -297,6 +408,24 @@ impl BytesSerializable for PollMessages {
         let count = u32::from_le_bytes(bytes[position + 8..position + 12].try_into()?);
         let auto_commit = bytes[position + 12];
         let auto_commit = matches!(auto_commit, 1);
+        let include_all_states = matches!(bytes[position + 13], 1);
+        let max_wait_ms = u64::from_le_bytes(bytes[position + 14..position + 22].try_into()?);
+        let max_wait_ms = match max_wait_ms {
+            0 => None,
+            max_wait_ms => Some(max_wait_ms),
+        };
+        let isolation_level = IsolationLevel::from_code(bytes[position + 22])?;
+        position += 23;
+        let mut filters = vec![];
+        if let Some(filters_count) = bytes.get(position) {
+            position += 1;
+            for _ in 0..*filters_count {
+                let filter = HeaderFilter::from_bytes(&bytes[position..])?;
+                position += filter.get_size_bytes() as usize;
+                filters.push(filter);
+            }
+        }
+
         let command = PollMessages {
             consumer,
             stream_id,
@@ -305,6 +434,10 @@ impl BytesSerializable for PollMessages {
             strategy,
             count,
             auto_commit,
+            include_all_states,
+            max_wait_ms,
+            isolation_level,
+            filters,
         };
         command.validate()?;
         Ok(command)
@@ -315,14 +448,22 @@ impl Display for PollMessages {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "{}|{}|{}|{}|{}|{}|{}",
+            "{}|{}|{}|{}|{}|{}|{}|{}|{}|{}|{}",
             self.consumer,
             self.stream_id,
             self.topic_id,
             self.partition_id.unwrap_or(0),
             self.strategy,
             self.count,
-            auto_commit_to_string(self.auto_commit)
+            auto_commit_to_string(self.auto_commit),
+            include_all_states_to_string(self.include_all_states),
+            self.max_wait_ms.unwrap_or(0),
+            self.isolation_level,
+            self.filters
+                .iter()
+                .map(|filter| filter.to_string())
+                .collect::<Vec<String>>()
+                .join(",")
         )
     }
 }
@@ -341,6 +482,14 @@ fn auto_commit_to_string(auto_commit: bool) -> &'static str {
     }
 }
 
+fn include_all_states_to_string(include_all_states: bool) -> &'static str {
+    if include_all_states {
+        "i"
+    } else {
+        "o"
+    }
+}
+
 impl BytesSerializable for PollingStrategy {
     fn as_bytes(&self) -> Vec<u8> {
         let mut bytes = Vec::with_capacity(9);
@@ -364,6 +513,7 @@ impl BytesSerializable for PollingStrategy {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::models::header::HeaderValue;
 
     #[test]
     fn should_be_serialized_as_bytes() {
@@ -375,6 +525,10 @@ mod tests {
             strategy: PollingStrategy::offset(2),
             count: 3,
             auto_commit: true,
+            include_all_states: true,
+            max_wait_ms: Some(1000),
+            isolation_level: IsolationLevel::ReadCommitted,
+            filters: vec![HeaderFilter::exists("key").unwrap()],
         };
 
         let bytes = command.as_bytes();
@@ -401,6 +555,12 @@ mod tests {
         let count = u32::from_le_bytes(bytes[position + 8..position + 12].try_into().unwrap());
         let auto_commit = bytes[position + 12];
         let auto_commit = matches!(auto_commit, 1);
+        let include_all_states = matches!(bytes[position + 13], 1);
+        let max_wait_ms =
+            u64::from_le_bytes(bytes[position + 14..position + 22].try_into().unwrap());
+        let isolation_level = IsolationLevel::from_code(bytes[position + 22]).unwrap();
+        let filters_count = bytes[position + 23];
+        let filter = HeaderFilter::from_bytes(&bytes[position + 24..]).unwrap();
 
         assert!(!bytes.is_empty());
         assert_eq!(consumer, command.consumer);
@@ -410,6 +570,11 @@ mod tests {
         assert_eq!(strategy, command.strategy);
         assert_eq!(count, command.count);
         assert_eq!(auto_commit, command.auto_commit);
+        assert_eq!(include_all_states, command.include_all_states);
+        assert_eq!(Some(max_wait_ms), command.max_wait_ms);
+        assert_eq!(isolation_level, command.isolation_level);
+        assert_eq!(filters_count, 1);
+        assert_eq!(vec![filter], command.filters);
     }
 
     #[test]
@@ -421,13 +586,15 @@ mod tests {
         let strategy = PollingStrategy::offset(2);
         let count = 3u32;
         let auto_commit = 1u8;
+        let max_wait_ms = 1000u64;
+        let filter = HeaderFilter::prefix("key", "value").unwrap();
 
         let consumer_bytes = consumer.as_bytes();
         let stream_id_bytes = stream_id.as_bytes();
         let topic_id_bytes = topic_id.as_bytes();
         let strategy_bytes = strategy.as_bytes();
         let mut bytes = Vec::with_capacity(
-            9 + consumer_bytes.len()
+            19 + consumer_bytes.len()
                 + stream_id_bytes.len()
                 + topic_id_bytes.len()
                 + strategy_bytes.len(),
@@ -439,6 +606,11 @@ mod tests {
         bytes.extend(strategy_bytes);
         bytes.put_u32_le(count);
         bytes.put_u8(auto_commit);
+        bytes.put_u8(1);
+        bytes.put_u64_le(max_wait_ms);
+        bytes.put_u8(IsolationLevel::ReadCommitted.as_code());
+        bytes.put_u8(1);
+        bytes.extend(filter.as_bytes());
 
         let command = PollMessages::from_bytes(&bytes);
         assert!(command.is_ok());
@@ -453,6 +625,10 @@ mod tests {
         assert_eq!(command.strategy, strategy);
         assert_eq!(command.count, count);
         assert_eq!(command.auto_commit, auto_commit);
+        assert!(command.include_all_states);
+        assert_eq!(command.max_wait_ms, Some(max_wait_ms));
+        assert_eq!(command.isolation_level, IsolationLevel::ReadCommitted);
+        assert_eq!(command.filters, vec![filter]);
     }
 
     #[test]
@@ -482,5 +658,118 @@ mod tests {
         assert_eq!(command.strategy, strategy);
         assert_eq!(command.count, count);
         assert_eq!(command.auto_commit, auto_commit);
+        assert!(!command.include_all_states);
+        assert!(command.max_wait_ms.is_none());
+    }
+
+    #[test]
+    fn should_be_read_from_string_with_all_states_included() {
+        let consumer = Consumer::new(1);
+        let stream_id = Identifier::numeric(2).unwrap();
+        let topic_id = Identifier::numeric(3).unwrap();
+        let partition_id = 4u32;
+        let strategy = PollingStrategy::offset(2);
+        let count = 3u32;
+
+        let input = format!(
+            "{consumer}|{stream_id}|{topic_id}|{partition_id}|{strategy}|{count}|n|include_all_states",
+        );
+        let command = PollMessages::from_str(&input);
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert!(!command.auto_commit);
+        assert!(command.include_all_states);
+    }
+
+    #[test]
+    fn should_be_read_from_string_with_max_wait() {
+        let consumer = Consumer::new(1);
+        let stream_id = Identifier::numeric(2).unwrap();
+        let topic_id = Identifier::numeric(3).unwrap();
+        let partition_id = 4u32;
+        let strategy = PollingStrategy::next();
+        let count = 3u32;
+        let max_wait_ms = 1000u64;
+
+        let input = format!(
+            "{consumer}|{stream_id}|{topic_id}|{partition_id}|{strategy}|{count}|a|o|{max_wait_ms}",
+        );
+        let command = PollMessages::from_str(&input);
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert!(command.auto_commit);
+        assert!(!command.include_all_states);
+        assert_eq!(command.max_wait_ms, Some(max_wait_ms));
+    }
+
+    #[test]
+    fn should_not_be_read_from_string_with_max_wait_above_the_limit() {
+        let consumer = Consumer::new(1);
+        let stream_id = Identifier::numeric(2).unwrap();
+        let topic_id = Identifier::numeric(3).unwrap();
+        let partition_id = 4u32;
+        let strategy = PollingStrategy::next();
+        let count = 3u32;
+        let max_wait_ms = MAX_WAIT_MS + 1;
+
+        let input = format!(
+            "{consumer}|{stream_id}|{topic_id}|{partition_id}|{strategy}|{count}|a|o|{max_wait_ms}",
+        );
+        let command = PollMessages::from_str(&input);
+        assert!(matches!(
+            command,
+            Err(Error::InvalidMaxWait(_, MAX_WAIT_MS))
+        ));
+    }
+
+    #[test]
+    fn should_be_read_from_string_with_isolation_level() {
+        let consumer = Consumer::new(1);
+        let stream_id = Identifier::numeric(2).unwrap();
+        let topic_id = Identifier::numeric(3).unwrap();
+        let partition_id = 4u32;
+        let strategy = PollingStrategy::next();
+        let count = 3u32;
+
+        let input = format!(
+            "{consumer}|{stream_id}|{topic_id}|{partition_id}|{strategy}|{count}|a|o|0|read_committed",
+        );
+        let command = PollMessages::from_str(&input);
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert!(command.max_wait_ms.is_none());
+        assert_eq!(command.isolation_level, IsolationLevel::ReadCommitted);
+    }
+
+    #[test]
+    fn should_be_read_from_string_with_filters() {
+        let consumer = Consumer::new(1);
+        let stream_id = Identifier::numeric(2).unwrap();
+        let topic_id = Identifier::numeric(3).unwrap();
+        let partition_id = 4u32;
+        let strategy = PollingStrategy::next();
+        let count = 3u32;
+
+        let input = format!(
+            "{consumer}|{stream_id}|{topic_id}|{partition_id}|{strategy}|{count}|a|o|0|read_uncommitted|exists:key-1,eq:key-2:uint32:5",
+        );
+        let command = PollMessages::from_str(&input);
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(
+            command.filters,
+            vec![
+                HeaderFilter::exists("key-1").unwrap(),
+                HeaderFilter::equals("key-2", HeaderValue::from_uint32(5).unwrap()).unwrap()
+            ]
+        );
+        assert_eq!(
+            PollMessages::from_str(&command.to_string()).unwrap(),
+            command
+        );
     }
 }
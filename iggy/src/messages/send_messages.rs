@@ -3,7 +3,7 @@ use crate::command::CommandPayload;
 use crate::error::Error;
 use crate::identifier::Identifier;
 use crate::models::header;
-use crate::models::header::{HeaderKey, HeaderValue};
+use crate::models::header::{HeaderKey, HeaderKind, HeaderValue};
 use crate::validatable::Validatable;
 use bytes::{BufMut, Bytes};
 use serde::{Deserialize, Serialize};
@@ -18,6 +18,10 @@ const MAX_PAYLOAD_SIZE: u32 = 10 * 1024 * 1024;
 
 const EMPTY_KEY_VALUE: Vec<u8> = vec![];
 
+pub const DELIVER_AT_HEADER: &str = "iggy-deliver-at";
+// The headers with this prefix are set by the server, only the delivery time can be set by the client.
+const RESERVED_HEADER_PREFIX: &str = "iggy-";
+
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
 pub struct SendMessages {
     #[serde(skip)]
@@ -25,9 +29,19 @@ pub struct SendMessages {
     #[serde(skip)]
     pub topic_id: Identifier,
     pub partitioning: Partitioning,
+    #[serde(default)]
+    pub producer: Option<ProducerSequence>,
+    #[serde(default)]
+    pub transaction_id: Option<u64>,
     pub messages: Vec<Message>,
 }
 
+#[derive(Debug, Serialize, Deserialize, PartialEq, Copy, Clone)]
+pub struct ProducerSequence {
+    pub producer_id: u64,
+    pub sequence: u64,
+}
+
 #[serde_as]
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
 pub struct Partitioning {
@@ -69,6 +83,8 @@ impl Default for SendMessages {
             stream_id: Identifier::default(),
             topic_id: Identifier::default(),
             partitioning: Partitioning::default(),
+            producer: None,
+            transaction_id: None,
             messages: vec![Message::default()],
         }
     }
@@ -167,10 +183,34 @@ impl Validatable<Error> for SendMessages {
             return Err(Error::InvalidKeyValueLength);
         }
 
+        if self.transaction_id == Some(0) {
+            return Err(Error::InvalidTransactionId);
+        }
+
+        // The sequence numbers are tracked per partition, so it has to be known upfront.
+        if let Some(producer) = &self.producer {
+            if producer.producer_id == 0 {
+                return Err(Error::InvalidProducerId);
+            }
+
+            if self.partitioning.kind != PartitioningKind::PartitionId {
+                return Err(Error::InvalidProducerPartitioning);
+            }
+        }
+
+        Self::validate_reserved_headers(&self.messages)?;
+        let deliver_at_key = HeaderKey::new(DELIVER_AT_HEADER)?;
         let mut headers_size = 0;
         let mut payload_size = 0;
         for message in &self.messages {
             if let Some(headers) = &message.headers {
+                // The delivery time is a timestamp in microseconds.
+                if let Some(deliver_at) = headers.get(&deliver_at_key) {
+                    if deliver_at.kind != HeaderKind::Uint64 {
+                        return Err(Error::InvalidHeaderValue);
+                    }
+                }
+
                 for value in headers.values() {
                     headers_size += value.value.len() as u32;
                     if headers_size > MAX_HEADERS_SIZE {
@@ -192,6 +232,49 @@ impl Validatable<Error> for SendMessages {
     }
 }
 
+impl SendMessages {
+    // The server relies on its own headers (e.g. the producer or transaction ones), so they can't be sent by the client.
+    pub fn validate_reserved_headers(messages: &[Message]) -> Result<(), Error> {
+        for headers in messages
+            .iter()
+            .filter_map(|message| message.headers.as_ref())
+        {
+            if let Some(key) = headers.keys().find(|key| {
+                key.as_str().starts_with(RESERVED_HEADER_PREFIX)
+                    && key.as_str() != DELIVER_AT_HEADER
+            }) {
+                return Err(Error::ReservedHeaderKey(key.as_str().to_string()));
+            }
+        }
+
+        Ok(())
+    }
+
+    // The maximum delay is configured by the server, so it's validated separately.
+    pub fn validate_delivery_time(
+        messages: &[Message],
+        now: u64,
+        max_delay: u64,
+    ) -> Result<(), Error> {
+        let deliver_at_key = HeaderKey::new(DELIVER_AT_HEADER)?;
+        let latest_deliver_at = now.saturating_add(max_delay);
+        for message in messages {
+            if let Some(deliver_at) = message
+                .headers
+                .as_ref()
+                .and_then(|headers| headers.get(&deliver_at_key))
+            {
+                let deliver_at = deliver_at.as_uint64()?;
+                if deliver_at > latest_deliver_at {
+                    return Err(Error::InvalidDeliveryTime(deliver_at, latest_deliver_at));
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
 impl PartitioningKind {
     pub fn as_code(&self) -> u8 {
         match self {
@@ -417,6 +500,8 @@ impl FromStr for SendMessages {
                 length: key_length,
                 value: key_value,
             },
+            producer: None,
+            transaction_id: None,
             messages: vec![message],
         };
         command.validate()?;
@@ -436,11 +521,25 @@ impl BytesSerializable for SendMessages {
         let stream_id_bytes = self.stream_id.as_bytes();
         let topic_id_bytes = self.topic_id.as_bytes();
         let mut bytes = Vec::with_capacity(
-            stream_id_bytes.len() + topic_id_bytes.len() + key_bytes.len() + messages_size as usize,
+            24 + stream_id_bytes.len()
+                + topic_id_bytes.len()
+                + key_bytes.len()
+                + messages_size as usize,
         );
         bytes.extend(stream_id_bytes);
         bytes.extend(topic_id_bytes);
         bytes.extend(key_bytes);
+        match &self.producer {
+            Some(producer) => {
+                bytes.put_u64_le(producer.producer_id);
+                bytes.put_u64_le(producer.sequence);
+            }
+            None => {
+                bytes.put_u64_le(0);
+                bytes.put_u64_le(0);
+            }
+        }
+        bytes.put_u64_le(self.transaction_id.unwrap_or(0));
         for message in &self.messages {
             bytes.extend(message.as_bytes());
         }
@@ -449,7 +548,7 @@ impl BytesSerializable for SendMessages {
     }
 
     fn from_bytes(bytes: &[u8]) -> Result<SendMessages, Error> {
-        if bytes.len() < 11 {
+        if bytes.len() < 35 {
             return Err(Error::InvalidCommand);
         }
 
@@ -460,6 +559,25 @@ impl BytesSerializable for SendMessages {
         position += topic_id.get_size_bytes() as usize;
         let key = Partitioning::from_bytes(&bytes[position..])?;
         position += key.get_size_bytes() as usize;
+        if bytes.len() < position + 24 {
+            return Err(Error::InvalidCommand);
+        }
+
+        let producer_id = u64::from_le_bytes(bytes[position..position + 8].try_into()?);
+        let sequence = u64::from_le_bytes(bytes[position + 8..position + 16].try_into()?);
+        let producer = match producer_id {
+            0 => None,
+            producer_id => Some(ProducerSequence {
+                producer_id,
+                sequence,
+            }),
+        };
+        let transaction_id = u64::from_le_bytes(bytes[position + 16..position + 24].try_into()?);
+        let transaction_id = match transaction_id {
+            0 => None,
+            transaction_id => Some(transaction_id),
+        };
+        position += 24;
         let messages_payloads = &bytes[position..];
         position = 0;
         let mut messages = Vec::new();
@@ -473,6 +591,8 @@ impl BytesSerializable for SendMessages {
             stream_id,
             topic_id,
             partitioning: key,
+            producer,
+            transaction_id,
             messages,
         };
         command.validate()?;
@@ -538,6 +658,11 @@ mod tests {
             stream_id: Identifier::numeric(1).unwrap(),
             topic_id: Identifier::numeric(2).unwrap(),
             partitioning: Partitioning::partition_id(4),
+            producer: Some(ProducerSequence {
+                producer_id: 5,
+                sequence: 6,
+            }),
+            transaction_id: Some(7),
             messages,
         };
 
@@ -550,6 +675,11 @@ mod tests {
         position += topic_id.get_size_bytes() as usize;
         let key = Partitioning::from_bytes(&bytes[position..]).unwrap();
         position += key.get_size_bytes() as usize;
+        let producer_id = u64::from_le_bytes(bytes[position..position + 8].try_into().unwrap());
+        let sequence = u64::from_le_bytes(bytes[position + 8..position + 16].try_into().unwrap());
+        let transaction_id =
+            u64::from_le_bytes(bytes[position + 16..position + 24].try_into().unwrap());
+        position += 24;
         let messages = &bytes[position..];
         let command_messages = &command
             .messages
@@ -562,6 +692,9 @@ mod tests {
         assert_eq!(stream_id, command.stream_id);
         assert_eq!(topic_id, command.topic_id);
         assert_eq!(key, command.partitioning);
+        assert_eq!(producer_id, 5);
+        assert_eq!(sequence, 6);
+        assert_eq!(transaction_id, 7);
         assert_eq!(messages, command_messages);
     }
 
@@ -584,11 +717,14 @@ mod tests {
         let key_bytes = key.as_bytes();
         let stream_id_bytes = stream_id.as_bytes();
         let topic_id_bytes = topic_id.as_bytes();
-        let current_position = stream_id_bytes.len() + topic_id_bytes.len() + key_bytes.len();
+        let current_position = 24 + stream_id_bytes.len() + topic_id_bytes.len() + key_bytes.len();
         let mut bytes = Vec::with_capacity(current_position);
         bytes.extend(stream_id_bytes);
         bytes.extend(topic_id_bytes);
         bytes.extend(key_bytes);
+        bytes.put_u64_le(5);
+        bytes.put_u64_le(6);
+        bytes.put_u64_le(7);
         bytes.extend(messages);
 
         let command = SendMessages::from_bytes(&bytes);
@@ -607,6 +743,14 @@ mod tests {
         assert_eq!(command.stream_id, stream_id);
         assert_eq!(command.topic_id, topic_id);
         assert_eq!(command.partitioning, key);
+        assert_eq!(
+            command.producer,
+            Some(ProducerSequence {
+                producer_id: 5,
+                sequence: 6,
+            })
+        );
+        assert_eq!(command.transaction_id, Some(7));
         for (index, message) in command.messages.iter().enumerate() {
             let command_message = &command.messages[index];
             assert_eq!(command_message.id, message.id);
@@ -689,4 +833,95 @@ mod tests {
         let key = Partitioning::messages_key_str(&messages_key);
         assert!(key.is_err());
     }
+
+    #[test]
+    fn producer_sequence_should_require_partition_id_partitioning() {
+        let producer = Some(ProducerSequence {
+            producer_id: 1,
+            sequence: 1,
+        });
+        let command = SendMessages {
+            producer,
+            ..SendMessages::default()
+        };
+        assert!(command.validate().is_err());
+
+        let command = SendMessages {
+            partitioning: Partitioning::partition_id(1),
+            producer,
+            ..SendMessages::default()
+        };
+        assert!(command.validate().is_ok());
+    }
+
+    #[test]
+    fn deliver_at_header_should_be_a_timestamp() {
+        let deliver_at_key = HeaderKey::new(DELIVER_AT_HEADER).unwrap();
+        let message = Message::new(
+            None,
+            Bytes::from("hello"),
+            Some(HashMap::from([(
+                deliver_at_key.clone(),
+                HeaderValue::from_str("tomorrow").unwrap(),
+            )])),
+        );
+        let command = SendMessages {
+            messages: vec![message],
+            ..SendMessages::default()
+        };
+        assert!(command.validate().is_err());
+
+        let message = Message::new(
+            None,
+            Bytes::from("hello"),
+            Some(HashMap::from([(
+                deliver_at_key,
+                HeaderValue::from_uint64(1).unwrap(),
+            )])),
+        );
+        let command = SendMessages {
+            messages: vec![message],
+            ..SendMessages::default()
+        };
+        assert!(command.validate().is_ok());
+    }
+
+    #[test]
+    fn deliver_at_header_should_not_exceed_the_max_delay() {
+        let message = Message::new(
+            None,
+            Bytes::from("hello"),
+            Some(HashMap::from([(
+                HeaderKey::new(DELIVER_AT_HEADER).unwrap(),
+                HeaderValue::from_uint64(u64::MAX).unwrap(),
+            )])),
+        );
+        let messages = vec![message];
+        let result = SendMessages::validate_delivery_time(&messages, 1000, 100);
+        assert!(matches!(
+            result,
+            Err(Error::InvalidDeliveryTime(u64::MAX, 1100))
+        ));
+        assert!(SendMessages::validate_delivery_time(&messages, 1000, u64::MAX).is_ok());
+    }
+
+    #[test]
+    fn reserved_headers_should_not_be_allowed() {
+        let message = Message::new(
+            None,
+            Bytes::from("hello"),
+            Some(HashMap::from([(
+                HeaderKey::new("iggy-transaction-id").unwrap(),
+                HeaderValue::from_uint64(1).unwrap(),
+            )])),
+        );
+        let command = SendMessages {
+            messages: vec![message],
+            ..SendMessages::default()
+        };
+        assert!(matches!(
+            command.validate(),
+            Err(Error::ReservedHeaderKey(key)) if key == "iggy-transaction-id"
+        ));
+    }
 }
@@ -0,0 +1,351 @@
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::CommandPayload;
+use crate::consumer::{Consumer, ConsumerKind};
+use crate::error::Error;
+use crate::identifier::Identifier;
+use crate::messages::poll_messages::{IsolationLevel, PollingKind, PollingStrategy};
+use crate::validatable::Validatable;
+use bytes::BufMut;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::str::FromStr;
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct Subscribe {
+    pub subscription_id: u32,
+    #[serde(flatten)]
+    pub consumer: Consumer,
+    #[serde(skip)]
+    pub stream_id: Identifier,
+    #[serde(skip)]
+    pub topic_id: Identifier,
+    pub partition_id: Option<u32>,
+    #[serde(flatten)]
+    pub strategy: PollingStrategy,
+    pub auto_commit: bool,
+    pub credits: u32,
+    #[serde(default)]
+    pub isolation_level: IsolationLevel,
+}
+
+impl Default for Subscribe {
+    fn default() -> Self {
+        Subscribe {
+            subscription_id: 1,
+            consumer: Consumer::default(),
+            stream_id: Identifier::default(),
+            topic_id: Identifier::default(),
+            partition_id: Some(1),
+            strategy: PollingStrategy::next(),
+            auto_commit: true,
+            credits: 100,
+            isolation_level: IsolationLevel::default(),
+        }
+    }
+}
+
+impl CommandPayload for Subscribe {}
+
+impl Validatable<Error> for Subscribe {
+    fn validate(&self) -> Result<(), Error> {
+        if self.subscription_id == 0 {
+            return Err(Error::InvalidSubscription);
+        }
+
+        // Consumer group members share the stored offsets, so they can only follow them.
+        if self.consumer.kind == ConsumerKind::ConsumerGroup
+            && (self.strategy.kind != PollingKind::Next || !self.auto_commit)
+        {
+            return Err(Error::InvalidSubscription);
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for Subscribe {
+    type Err = Error;
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let parts = input.split('|').collect::<Vec<&str>>();
+        if parts.len() < 9 {
+            return Err(Error::InvalidCommand);
+        }
+
+        let subscription_id = parts[0].parse::<u32>()?;
+        let consumer_kind = ConsumerKind::from_str(parts[1])?;
+        let consumer_id = parts[2].parse::<u32>()?;
+        let consumer = Consumer {
+            kind: consumer_kind,
+            id: consumer_id,
+        };
+        let stream_id = parts[3].parse::<Identifier>()?;
+        let topic_id = parts[4].parse::<Identifier>()?;
+        let partition_id = parts[5].parse::<u32>()?;
+        let partition_id = match partition_id {
+            0 => None,
+            partition_id => Some(partition_id),
+        };
+        let polling_kind = PollingKind::from_str(parts[6])?;
+        let value = parts[7].parse::<u64>()?;
+        let strategy = PollingStrategy {
+            kind: polling_kind,
+            value,
+        };
+        let credits = parts[8].parse::<u32>()?;
+        let auto_commit = match parts.get(9) {
+            Some(auto_commit) => match *auto_commit {
+                "a" | "auto_commit" => true,
+                "n" | "no_commit" => false,
+                _ => return Err(Error::InvalidCommand),
+            },
+            None => false,
+        };
+        let isolation_level = match parts.get(10) {
+            Some(isolation_level) => IsolationLevel::from_str(isolation_level)?,
+            None => IsolationLevel::default(),
+        };
+        let command = Subscribe {
+            subscription_id,
+            consumer,
+            stream_id,
+            topic_id,
+            partition_id,
+            strategy,
+            auto_commit,
+            credits,
+            isolation_level,
+        };
+        command.validate()?;
+        Ok(command)
+    }
+}
+
+impl BytesSerializable for Subscribe {
+    fn as_bytes(&self) -> Vec<u8> {
+        let consumer_bytes = self.consumer.as_bytes();
+        let stream_id_bytes = self.stream_id.as_bytes();
+        let topic_id_bytes = self.topic_id.as_bytes();
+        let strategy_bytes = self.strategy.as_bytes();
+        let mut bytes = Vec::with_capacity(
+            14 + consumer_bytes.len()
+                + stream_id_bytes.len()
+                + topic_id_bytes.len()
+                + strategy_bytes.len(),
+        );
+        bytes.put_u32_le(self.subscription_id);
+        bytes.extend(consumer_bytes);
+        bytes.extend(stream_id_bytes);
+        bytes.extend(topic_id_bytes);
+        bytes.put_u32_le(self.partition_id.unwrap_or(0));
+        bytes.extend(strategy_bytes);
+        if self.auto_commit {
+            bytes.put_u8(1);
+        } else {
+            bytes.put_u8(0);
+        }
+        bytes.put_u32_le(self.credits);
+        bytes.put_u8(self.isolation_level.as_code());
+        bytes
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        if bytes.len() < 34 {
+            return Err(Error::InvalidCommand);
+        }
+
+        let subscription_id = u32::from_le_bytes(bytes[0..4].try_into()?);
+        let consumer_kind = ConsumerKind::from_code(bytes[4])?;
+        let consumer_id = u32::from_le_bytes(bytes[5..9].try_into()?);
+        let consumer = Consumer {
+            kind: consumer_kind,
+            id: consumer_id,
+        };
+        let mut position = 9;
+        let stream_id = Identifier::from_bytes(&bytes[position..])?;
+        position += stream_id.get_size_bytes() as usize;
+        let topic_id = Identifier::from_bytes(&bytes[position..])?;
+        position += topic_id.get_size_bytes() as usize;
+        if bytes.len() < position + 19 {
+            return Err(Error::InvalidCommand);
+        }
+
+        let partition_id = u32::from_le_bytes(bytes[position..position + 4].try_into()?);
+        let partition_id = match partition_id {
+            0 => None,
+            partition_id => Some(partition_id),
+        };
+        let strategy = PollingStrategy::from_bytes(&bytes[position + 4..position + 13])?;
+        let auto_commit = matches!(bytes[position + 13], 1);
+        let credits = u32::from_le_bytes(bytes[position + 14..position + 18].try_into()?);
+        let isolation_level = IsolationLevel::from_code(bytes[position + 18])?;
+        let command = Subscribe {
+            subscription_id,
+            consumer,
+            stream_id,
+            topic_id,
+            partition_id,
+            strategy,
+            auto_commit,
+            credits,
+            isolation_level,
+        };
+        command.validate()?;
+        Ok(command)
+    }
+}
+
+impl Display for Subscribe {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}|{}|{}|{}|{}|{}|{}|{}|{}",
+            self.subscription_id,
+            self.consumer,
+            self.stream_id,
+            self.topic_id,
+            self.partition_id.unwrap_or(0),
+            self.strategy,
+            self.credits,
+            if self.auto_commit {
+                "auto_commit"
+            } else {
+                "no_commit"
+            },
+            self.isolation_level
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_bytes() {
+        let command = Subscribe {
+            subscription_id: 1,
+            consumer: Consumer::new(2),
+            stream_id: Identifier::numeric(3).unwrap(),
+            topic_id: Identifier::numeric(4).unwrap(),
+            partition_id: Some(5),
+            strategy: PollingStrategy::offset(6),
+            auto_commit: true,
+            credits: 7,
+            isolation_level: IsolationLevel::ReadCommitted,
+        };
+
+        let bytes = command.as_bytes();
+        let subscription_id = u32::from_le_bytes(bytes[0..4].try_into().unwrap());
+        let consumer = Consumer::from_bytes(&bytes[4..9]).unwrap();
+        let mut position = 9;
+        let stream_id = Identifier::from_bytes(&bytes[position..]).unwrap();
+        position += stream_id.get_size_bytes() as usize;
+        let topic_id = Identifier::from_bytes(&bytes[position..]).unwrap();
+        position += topic_id.get_size_bytes() as usize;
+        let partition_id = u32::from_le_bytes(bytes[position..position + 4].try_into().unwrap());
+        let strategy = PollingStrategy::from_bytes(&bytes[position + 4..position + 13]).unwrap();
+        let auto_commit = bytes[position + 13];
+        let credits = u32::from_le_bytes(bytes[position + 14..position + 18].try_into().unwrap());
+        let isolation_level = IsolationLevel::from_code(bytes[position + 18]).unwrap();
+
+        assert!(!bytes.is_empty());
+        assert_eq!(subscription_id, command.subscription_id);
+        assert_eq!(consumer, command.consumer);
+        assert_eq!(stream_id, command.stream_id);
+        assert_eq!(topic_id, command.topic_id);
+        assert_eq!(Some(partition_id), command.partition_id);
+        assert_eq!(strategy, command.strategy);
+        assert_eq!(auto_commit, 1);
+        assert_eq!(credits, command.credits);
+        assert_eq!(isolation_level, command.isolation_level);
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes() {
+        let subscription_id = 1u32;
+        let consumer = Consumer::new(2);
+        let stream_id = Identifier::numeric(3).unwrap();
+        let topic_id = Identifier::numeric(4).unwrap();
+        let partition_id = 5u32;
+        let strategy = PollingStrategy::offset(6);
+        let credits = 7u32;
+
+        let consumer_bytes = consumer.as_bytes();
+        let stream_id_bytes = stream_id.as_bytes();
+        let topic_id_bytes = topic_id.as_bytes();
+        let strategy_bytes = strategy.as_bytes();
+        let mut bytes = Vec::with_capacity(
+            13 + consumer_bytes.len()
+                + stream_id_bytes.len()
+                + topic_id_bytes.len()
+                + strategy_bytes.len(),
+        );
+        bytes.put_u32_le(subscription_id);
+        bytes.extend(consumer_bytes);
+        bytes.extend(stream_id_bytes);
+        bytes.extend(topic_id_bytes);
+        bytes.put_u32_le(partition_id);
+        bytes.extend(strategy_bytes);
+        bytes.put_u8(1);
+        bytes.put_u32_le(credits);
+        bytes.put_u8(IsolationLevel::ReadCommitted.as_code());
+
+        let command = Subscribe::from_bytes(&bytes);
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.subscription_id, subscription_id);
+        assert_eq!(command.consumer, consumer);
+        assert_eq!(command.stream_id, stream_id);
+        assert_eq!(command.topic_id, topic_id);
+        assert_eq!(command.partition_id, Some(partition_id));
+        assert_eq!(command.strategy, strategy);
+        assert!(command.auto_commit);
+        assert_eq!(command.credits, credits);
+        assert_eq!(command.isolation_level, IsolationLevel::ReadCommitted);
+    }
+
+    #[test]
+    fn should_be_read_from_string() {
+        let subscription_id = 1u32;
+        let consumer = Consumer::new(2);
+        let stream_id = Identifier::numeric(3).unwrap();
+        let topic_id = Identifier::numeric(4).unwrap();
+        let partition_id = 5u32;
+        let strategy = PollingStrategy::offset(6);
+        let credits = 7u32;
+        let input = format!(
+            "{subscription_id}|{consumer}|{stream_id}|{topic_id}|{partition_id}|{strategy}|{credits}|a|read_committed"
+        );
+        let command = Subscribe::from_str(&input);
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.subscription_id, subscription_id);
+        assert_eq!(command.consumer, consumer);
+        assert_eq!(command.stream_id, stream_id);
+        assert_eq!(command.topic_id, topic_id);
+        assert_eq!(command.partition_id, Some(partition_id));
+        assert_eq!(command.strategy, strategy);
+        assert!(command.auto_commit);
+        assert_eq!(command.credits, credits);
+        assert_eq!(command.isolation_level, IsolationLevel::ReadCommitted);
+    }
+
+    #[test]
+    fn consumer_group_subscription_should_require_next_strategy_with_auto_commit() {
+        let command = Subscribe {
+            consumer: Consumer::group(1),
+            strategy: PollingStrategy::offset(0),
+            ..Subscribe::default()
+        };
+        assert!(command.validate().is_err());
+
+        let command = Subscribe {
+            consumer: Consumer::group(1),
+            partition_id: None,
+            ..Subscribe::default()
+        };
+        assert!(command.validate().is_ok());
+    }
+}
@@ -0,0 +1,107 @@
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::CommandPayload;
+use crate::error::Error;
+use crate::validatable::Validatable;
+use bytes::BufMut;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::str::FromStr;
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct Unsubscribe {
+    pub subscription_id: u32,
+}
+
+impl Default for Unsubscribe {
+    fn default() -> Self {
+        Unsubscribe { subscription_id: 1 }
+    }
+}
+
+impl CommandPayload for Unsubscribe {}
+
+impl Validatable<Error> for Unsubscribe {
+    fn validate(&self) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+impl FromStr for Unsubscribe {
+    type Err = Error;
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let parts = input.split('|').collect::<Vec<&str>>();
+        if parts.len() != 1 {
+            return Err(Error::InvalidCommand);
+        }
+
+        let subscription_id = parts[0].parse::<u32>()?;
+        let command = Unsubscribe { subscription_id };
+        command.validate()?;
+        Ok(command)
+    }
+}
+
+impl BytesSerializable for Unsubscribe {
+    fn as_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(4);
+        bytes.put_u32_le(self.subscription_id);
+        bytes
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<Unsubscribe, Error> {
+        if bytes.len() != 4 {
+            return Err(Error::InvalidCommand);
+        }
+
+        let subscription_id = u32::from_le_bytes(bytes.try_into()?);
+        let command = Unsubscribe { subscription_id };
+        command.validate()?;
+        Ok(command)
+    }
+}
+
+impl Display for Unsubscribe {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.subscription_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_bytes() {
+        let command = Unsubscribe { subscription_id: 1 };
+
+        let bytes = command.as_bytes();
+        let subscription_id = u32::from_le_bytes(bytes[0..4].try_into().unwrap());
+
+        assert!(!bytes.is_empty());
+        assert_eq!(subscription_id, command.subscription_id);
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes() {
+        let subscription_id = 1u32;
+        let mut bytes = Vec::with_capacity(4);
+        bytes.put_u32_le(subscription_id);
+
+        let command = Unsubscribe::from_bytes(&bytes);
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.subscription_id, subscription_id);
+    }
+
+    #[test]
+    fn should_be_read_from_string() {
+        let subscription_id = 1u32;
+        let input = format!("{subscription_id}");
+        let command = Unsubscribe::from_str(&input);
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.subscription_id, subscription_id);
+    }
+}
@@ -0,0 +1,59 @@
+use crate::error::Error;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::str::FromStr;
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum AssignmentStrategy {
+    Range,
+    #[default]
+    RoundRobin,
+    Sticky,
+    CooperativeSticky,
+}
+
+impl FromStr for AssignmentStrategy {
+    type Err = Error;
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "range" => Ok(AssignmentStrategy::Range),
+            "round_robin" => Ok(AssignmentStrategy::RoundRobin),
+            "sticky" => Ok(AssignmentStrategy::Sticky),
+            "cooperative_sticky" => Ok(AssignmentStrategy::CooperativeSticky),
+            _ => Err(Error::InvalidAssignmentStrategy),
+        }
+    }
+}
+
+impl Display for AssignmentStrategy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AssignmentStrategy::Range => write!(f, "range"),
+            AssignmentStrategy::RoundRobin => write!(f, "round_robin"),
+            AssignmentStrategy::Sticky => write!(f, "sticky"),
+            AssignmentStrategy::CooperativeSticky => write!(f, "cooperative_sticky"),
+        }
+    }
+}
+
+impl AssignmentStrategy {
+    pub fn as_code(&self) -> u8 {
+        match self {
+            AssignmentStrategy::Range => 1,
+            AssignmentStrategy::RoundRobin => 2,
+            AssignmentStrategy::Sticky => 3,
+            AssignmentStrategy::CooperativeSticky => 4,
+        }
+    }
+
+    pub fn from_code(code: u8) -> Result<Self, Error> {
+        match code {
+            1 => Ok(AssignmentStrategy::Range),
+            2 => Ok(AssignmentStrategy::RoundRobin),
+            3 => Ok(AssignmentStrategy::Sticky),
+            4 => Ok(AssignmentStrategy::CooperativeSticky),
+            _ => Err(Error::InvalidAssignmentStrategy),
+        }
+    }
+}
@@ -1,3 +1,4 @@
+use crate::models::quotas::Quotas;
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -17,6 +18,10 @@ pub struct ClientInfoDetails {
     pub transport: String,
     pub consumer_groups_count: u32,
     pub consumer_groups: Vec<ConsumerGroupInfo>,
+    #[serde(default)]
+    pub client_quotas: Quotas,
+    #[serde(default)]
+    pub user_quotas: Quotas,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
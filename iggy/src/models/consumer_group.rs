@@ -1,3 +1,4 @@
+use crate::models::assignment_strategy::AssignmentStrategy;
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -5,6 +6,9 @@ pub struct ConsumerGroup {
     pub id: u32,
     pub partitions_count: u32,
     pub members_count: u32,
+    pub assignment_strategy: AssignmentStrategy,
+    pub session_timeout: u32,
+    pub generation_id: u32,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -12,12 +16,25 @@ pub struct ConsumerGroupDetails {
     pub id: u32,
     pub partitions_count: u32,
     pub members_count: u32,
+    pub assignment_strategy: AssignmentStrategy,
+    pub session_timeout: u32,
+    pub generation_id: u32,
     pub members: Vec<ConsumerGroupMember>,
 }
 
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ConsumerGroupAssignment {
+    pub consumer_group_id: u32,
+    pub member_id: u32,
+    pub generation_id: u32,
+    pub partitions_count: u32,
+    pub partitions: Vec<u32>,
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct ConsumerGroupMember {
     pub id: u32,
+    pub last_heartbeat_at: u64,
     pub partitions_count: u32,
     pub partitions: Vec<u32>,
 }
@@ -0,0 +1,20 @@
+use crate::consumer::ConsumerKind;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ConsumerLag {
+    pub consumer_kind: ConsumerKind,
+    pub consumer_id: u32,
+    pub partitions: Vec<PartitionLag>,
+}
+
+// The time lag is the difference in microseconds between the timestamps of the last message
+// in the partition and the last consumed one (or the first available one if none was consumed).
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PartitionLag {
+    pub partition_id: u32,
+    pub current_offset: u64,
+    pub stored_offset: Option<u64>,
+    pub messages_lag: u64,
+    pub time_lag: u64,
+}
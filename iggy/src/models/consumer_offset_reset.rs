@@ -0,0 +1,10 @@
+use serde::{Deserialize, Serialize};
+
+// The stored offset is the last consumed one, none means that the next message to be consumed is the first one.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ConsumerOffsetReset {
+    pub partition_id: u32,
+    pub current_offset: u64,
+    pub previous_offset: Option<u64>,
+    pub stored_offset: Option<u64>,
+}
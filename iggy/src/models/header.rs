@@ -522,26 +522,25 @@ impl BytesSerializable for HashMap<HeaderKey, HeaderValue> {
         let mut headers = Self::new();
         let mut position = 0;
         while position < bytes.len() {
-            let key_length = u32::from_le_bytes(bytes[position..position + 4].try_into()?) as usize;
+            let key_length = read_u32(bytes, position)? as usize;
             if key_length == 0 || key_length > 255 {
                 return Err(Error::InvalidHeaderKey);
             }
             position += 4;
-            let key = String::from_utf8(bytes[position..position + key_length].to_vec());
+            let key = String::from_utf8(read_bytes(bytes, position, key_length)?.to_vec());
             if key.is_err() {
                 return Err(Error::InvalidHeaderKey);
             }
             let key = key.unwrap();
             position += key_length;
-            let kind = HeaderKind::from_code(bytes[position])?;
+            let kind = HeaderKind::from_code(read_bytes(bytes, position, 1)?[0])?;
             position += 1;
-            let value_length =
-                u32::from_le_bytes(bytes[position..position + 4].try_into()?) as usize;
+            let value_length = read_u32(bytes, position)? as usize;
             if value_length == 0 || value_length > 255 {
                 return Err(Error::InvalidHeaderValue);
             }
             position += 4;
-            let value = bytes[position..position + value_length].to_vec();
+            let value = read_bytes(bytes, position, value_length)?.to_vec();
             position += value_length;
             headers.insert(HeaderKey(key), HeaderValue { kind, value });
         }
@@ -550,6 +549,20 @@ impl BytesSerializable for HashMap<HeaderKey, HeaderValue> {
     }
 }
 
+fn read_bytes(bytes: &[u8], position: usize, length: usize) -> Result<&[u8], Error> {
+    bytes
+        .get(position..position + length)
+        .ok_or(Error::InvalidHeaders)
+}
+
+fn read_u32(bytes: &[u8], position: usize) -> Result<u32, Error> {
+    Ok(u32::from_le_bytes(
+        read_bytes(bytes, position, 4)?
+            .try_into()
+            .map_err(|_| Error::InvalidHeaders)?,
+    ))
+}
+
 pub fn get_headers_size_bytes(headers: &Option<HashMap<HeaderKey, HeaderValue>>) -> u32 {
     // Headers length field
     let mut size = 4;
@@ -854,4 +867,22 @@ mod tests {
             assert_eq!(deserialized_value.value, value.value);
         }
     }
+
+    #[test]
+    fn truncated_bytes_should_not_be_deserialized() {
+        let headers = HashMap::from([(
+            HeaderKey::new("key-1").unwrap(),
+            HeaderValue::from_str("Value 1").unwrap(),
+        )]);
+        let bytes = headers.as_bytes();
+
+        for length in 1..bytes.len() {
+            let result = HashMap::<HeaderKey, HeaderValue>::from_bytes(&bytes[..length]);
+            assert!(result.is_err());
+            assert_eq!(
+                result.unwrap_err().as_code(),
+                Error::InvalidHeaders.as_code()
+            );
+        }
+    }
 }
@@ -0,0 +1,463 @@
+use crate::bytes_serializable::BytesSerializable;
+use crate::error::Error;
+use crate::models::header::{HeaderKey, HeaderKind, HeaderValue};
+use crate::validatable::Validatable;
+use bytes::BufMut;
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct HeaderFilter {
+    pub key: HeaderKey,
+    pub condition: HeaderCondition,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum HeaderCondition {
+    Exists,
+    Equals(HeaderValue),
+    Range {
+        min: Option<HeaderValue>,
+        max: Option<HeaderValue>,
+    },
+    Prefix(String),
+}
+
+impl HeaderFilter {
+    pub fn exists(key: &str) -> Result<Self, Error> {
+        Self::new(key, HeaderCondition::Exists)
+    }
+
+    pub fn equals(key: &str, value: HeaderValue) -> Result<Self, Error> {
+        Self::new(key, HeaderCondition::Equals(value))
+    }
+
+    pub fn range(
+        key: &str,
+        min: Option<HeaderValue>,
+        max: Option<HeaderValue>,
+    ) -> Result<Self, Error> {
+        Self::new(key, HeaderCondition::Range { min, max })
+    }
+
+    pub fn prefix(key: &str, prefix: &str) -> Result<Self, Error> {
+        Self::new(key, HeaderCondition::Prefix(prefix.to_string()))
+    }
+
+    fn new(key: &str, condition: HeaderCondition) -> Result<Self, Error> {
+        let filter = Self {
+            key: HeaderKey::new(key)?,
+            condition,
+        };
+        filter.validate()?;
+        Ok(filter)
+    }
+
+    pub fn matches(&self, headers: &Option<HashMap<HeaderKey, HeaderValue>>) -> bool {
+        let value = match headers.as_ref().and_then(|headers| headers.get(&self.key)) {
+            Some(value) => value,
+            None => return false,
+        };
+
+        match &self.condition {
+            HeaderCondition::Exists => true,
+            HeaderCondition::Equals(expected) => value == expected,
+            HeaderCondition::Range { min, max } => {
+                let above_min = min.as_ref().is_none_or(|min| {
+                    matches!(
+                        compare(value, min),
+                        Some(Ordering::Greater | Ordering::Equal)
+                    )
+                });
+                let below_max = max.as_ref().is_none_or(|max| {
+                    matches!(compare(value, max), Some(Ordering::Less | Ordering::Equal))
+                });
+                above_min && below_max
+            }
+            HeaderCondition::Prefix(prefix) => value
+                .as_str()
+                .is_ok_and(|value| value.starts_with(prefix.as_str())),
+        }
+    }
+
+    pub fn get_size_bytes(&self) -> u32 {
+        self.as_bytes().len() as u32
+    }
+}
+
+impl HeaderCondition {
+    pub fn as_code(&self) -> u8 {
+        match self {
+            HeaderCondition::Exists => 1,
+            HeaderCondition::Equals(_) => 2,
+            HeaderCondition::Range { .. } => 3,
+            HeaderCondition::Prefix(_) => 4,
+        }
+    }
+}
+
+impl Validatable<Error> for HeaderFilter {
+    fn validate(&self) -> Result<(), Error> {
+        match &self.condition {
+            HeaderCondition::Exists | HeaderCondition::Equals(_) => Ok(()),
+            HeaderCondition::Range { min, max } => {
+                let kinds = [min, max]
+                    .into_iter()
+                    .flatten()
+                    .map(|value| value.kind)
+                    .collect::<Vec<HeaderKind>>();
+                if kinds.is_empty() || kinds.iter().any(|kind| !is_numeric(*kind)) {
+                    return Err(Error::InvalidHeaderFilter);
+                }
+
+                if kinds.len() == 2 && kinds[0] != kinds[1] {
+                    return Err(Error::InvalidHeaderFilter);
+                }
+
+                Ok(())
+            }
+            HeaderCondition::Prefix(prefix) => {
+                if prefix.is_empty() || prefix.len() > 255 {
+                    return Err(Error::InvalidHeaderFilter);
+                }
+
+                Ok(())
+            }
+        }
+    }
+}
+
+fn is_numeric(kind: HeaderKind) -> bool {
+    !matches!(
+        kind,
+        HeaderKind::Raw | HeaderKind::String | HeaderKind::Bool
+    )
+}
+
+// The values of the different kinds are not comparable, thus such a header never matches the range.
+fn compare(value: &HeaderValue, other: &HeaderValue) -> Option<Ordering> {
+    if value.kind != other.kind {
+        return None;
+    }
+
+    match value.kind {
+        HeaderKind::Int8 => Some(value.as_int8().ok()?.cmp(&other.as_int8().ok()?)),
+        HeaderKind::Int16 => Some(value.as_int16().ok()?.cmp(&other.as_int16().ok()?)),
+        HeaderKind::Int32 => Some(value.as_int32().ok()?.cmp(&other.as_int32().ok()?)),
+        HeaderKind::Int64 => Some(value.as_int64().ok()?.cmp(&other.as_int64().ok()?)),
+        HeaderKind::Int128 => Some(value.as_int128().ok()?.cmp(&other.as_int128().ok()?)),
+        HeaderKind::Uint8 => Some(value.as_uint8().ok()?.cmp(&other.as_uint8().ok()?)),
+        HeaderKind::Uint16 => Some(value.as_uint16().ok()?.cmp(&other.as_uint16().ok()?)),
+        HeaderKind::Uint32 => Some(value.as_uint32().ok()?.cmp(&other.as_uint32().ok()?)),
+        HeaderKind::Uint64 => Some(value.as_uint64().ok()?.cmp(&other.as_uint64().ok()?)),
+        HeaderKind::Uint128 => Some(value.as_uint128().ok()?.cmp(&other.as_uint128().ok()?)),
+        HeaderKind::Float32 => value
+            .as_float32()
+            .ok()?
+            .partial_cmp(&other.as_float32().ok()?),
+        HeaderKind::Float64 => value
+            .as_float64()
+            .ok()?
+            .partial_cmp(&other.as_float64().ok()?),
+        HeaderKind::Raw | HeaderKind::String | HeaderKind::Bool => None,
+    }
+}
+
+fn parse_value(kind: HeaderKind, input: &str) -> Result<HeaderValue, Error> {
+    let value = match kind {
+        HeaderKind::Raw => HeaderValue::from_raw(input.as_bytes()),
+        HeaderKind::String => HeaderValue::from_str(input),
+        HeaderKind::Bool => HeaderValue::from_bool(parse::<bool>(input)?),
+        HeaderKind::Int8 => HeaderValue::from_int8(parse::<i8>(input)?),
+        HeaderKind::Int16 => HeaderValue::from_int16(parse::<i16>(input)?),
+        HeaderKind::Int32 => HeaderValue::from_int32(parse::<i32>(input)?),
+        HeaderKind::Int64 => HeaderValue::from_int64(parse::<i64>(input)?),
+        HeaderKind::Int128 => HeaderValue::from_int128(parse::<i128>(input)?),
+        HeaderKind::Uint8 => HeaderValue::from_uint8(parse::<u8>(input)?),
+        HeaderKind::Uint16 => HeaderValue::from_uint16(parse::<u16>(input)?),
+        HeaderKind::Uint32 => HeaderValue::from_uint32(parse::<u32>(input)?),
+        HeaderKind::Uint64 => HeaderValue::from_uint64(parse::<u64>(input)?),
+        HeaderKind::Uint128 => HeaderValue::from_uint128(parse::<u128>(input)?),
+        HeaderKind::Float32 => HeaderValue::from_float32(parse::<f32>(input)?),
+        HeaderKind::Float64 => HeaderValue::from_float64(parse::<f64>(input)?),
+    };
+    value.map_err(|_| Error::InvalidHeaderFilter)
+}
+
+fn parse<T: FromStr>(input: &str) -> Result<T, Error> {
+    input.parse::<T>().map_err(|_| Error::InvalidHeaderFilter)
+}
+
+fn value_to_string(value: &HeaderValue) -> String {
+    match value.kind {
+        HeaderKind::Raw | HeaderKind::String => String::from_utf8_lossy(&value.value).to_string(),
+        // The remaining kinds are displayed as "kind: value".
+        _ => value
+            .to_string()
+            .split_once(": ")
+            .map(|(_, value)| value.to_string())
+            .unwrap_or_default(),
+    }
+}
+
+impl FromStr for HeaderFilter {
+    type Err = Error;
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let (condition, input) = input.split_once(':').ok_or(Error::InvalidHeaderFilter)?;
+        let parts = match condition {
+            "exists" => vec![input],
+            "prefix" => input.splitn(2, ':').collect::<Vec<&str>>(),
+            "eq" | "range" => input.splitn(3, ':').collect::<Vec<&str>>(),
+            _ => return Err(Error::InvalidHeaderFilter),
+        };
+
+        let key = parts[0];
+        match (condition, parts.as_slice()) {
+            ("exists", [_]) => Self::exists(key),
+            ("prefix", [_, prefix]) => Self::prefix(key, prefix),
+            ("eq", [_, kind, value]) => {
+                let kind = HeaderKind::from_str(kind)?;
+                Self::equals(key, parse_value(kind, value)?)
+            }
+            ("range", [_, kind, bounds]) => {
+                let kind = HeaderKind::from_str(kind)?;
+                let (min, max) = bounds.split_once("..").ok_or(Error::InvalidHeaderFilter)?;
+                let parse_bound = |bound: &str| match bound {
+                    "" => Ok(None),
+                    bound => parse_value(kind, bound).map(Some),
+                };
+                Self::range(key, parse_bound(min)?, parse_bound(max)?)
+            }
+            _ => Err(Error::InvalidHeaderFilter),
+        }
+    }
+}
+
+impl Display for HeaderFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let key = self.key.as_str();
+        match &self.condition {
+            HeaderCondition::Exists => write!(f, "exists:{key}"),
+            HeaderCondition::Equals(value) => {
+                write!(f, "eq:{key}:{}:{}", value.kind, value_to_string(value))
+            }
+            HeaderCondition::Range { min, max } => {
+                let kind = min.as_ref().or(max.as_ref()).map(|value| value.kind);
+                let min = min.as_ref().map(value_to_string).unwrap_or_default();
+                let max = max.as_ref().map(value_to_string).unwrap_or_default();
+                match kind {
+                    Some(kind) => write!(f, "range:{key}:{kind}:{min}..{max}"),
+                    None => write!(f, "range:{key}:raw:.."),
+                }
+            }
+            HeaderCondition::Prefix(prefix) => write!(f, "prefix:{key}:{prefix}"),
+        }
+    }
+}
+
+impl BytesSerializable for HeaderFilter {
+    fn as_bytes(&self) -> Vec<u8> {
+        let key = self.key.as_str().as_bytes();
+        let mut bytes = Vec::with_capacity(2 + key.len());
+        bytes.put_u8(self.condition.as_code());
+        #[allow(clippy::cast_possible_truncation)]
+        bytes.put_u8(key.len() as u8);
+        bytes.extend(key);
+        match &self.condition {
+            HeaderCondition::Exists => {}
+            HeaderCondition::Equals(value) => {
+                bytes.put_u8(value.kind.as_code());
+                extend_value(&mut bytes, Some(value));
+            }
+            HeaderCondition::Range { min, max } => {
+                let kind = min.as_ref().or(max.as_ref()).map(|value| value.kind);
+                bytes.put_u8(kind.unwrap_or(HeaderKind::Raw).as_code());
+                extend_value(&mut bytes, min.as_ref());
+                extend_value(&mut bytes, max.as_ref());
+            }
+            HeaderCondition::Prefix(prefix) => {
+                #[allow(clippy::cast_possible_truncation)]
+                bytes.put_u8(prefix.len() as u8);
+                bytes.extend(prefix.as_bytes());
+            }
+        }
+
+        bytes
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        let mut position = 0;
+        let condition_code = read_u8(bytes, &mut position)?;
+        let key_length = read_u8(bytes, &mut position)? as usize;
+        let key = read_bytes(bytes, &mut position, key_length)?;
+        let key = std::str::from_utf8(key).map_err(|_| Error::InvalidHeaderKey)?;
+        let condition = match condition_code {
+            1 => HeaderCondition::Exists,
+            2 => {
+                let kind = HeaderKind::from_code(read_u8(bytes, &mut position)?)?;
+                let value = read_value(bytes, &mut position, kind)?;
+                HeaderCondition::Equals(value.ok_or(Error::InvalidHeaderFilter)?)
+            }
+            3 => {
+                let kind = HeaderKind::from_code(read_u8(bytes, &mut position)?)?;
+                let min = read_value(bytes, &mut position, kind)?;
+                let max = read_value(bytes, &mut position, kind)?;
+                HeaderCondition::Range { min, max }
+            }
+            4 => {
+                let prefix_length = read_u8(bytes, &mut position)? as usize;
+                let prefix = read_bytes(bytes, &mut position, prefix_length)?;
+                let prefix =
+                    String::from_utf8(prefix.to_vec()).map_err(|_| Error::InvalidHeaderFilter)?;
+                HeaderCondition::Prefix(prefix)
+            }
+            _ => return Err(Error::InvalidHeaderFilter),
+        };
+
+        Self::new(key, condition)
+    }
+}
+
+fn extend_value(bytes: &mut Vec<u8>, value: Option<&HeaderValue>) {
+    match value {
+        Some(value) => {
+            #[allow(clippy::cast_possible_truncation)]
+            bytes.put_u8(value.value.len() as u8);
+            bytes.extend(&value.value);
+        }
+        None => bytes.put_u8(0),
+    }
+}
+
+fn read_u8(bytes: &[u8], position: &mut usize) -> Result<u8, Error> {
+    let value = *bytes.get(*position).ok_or(Error::InvalidHeaderFilter)?;
+    *position += 1;
+    Ok(value)
+}
+
+fn read_bytes<'a>(bytes: &'a [u8], position: &mut usize, length: usize) -> Result<&'a [u8], Error> {
+    let value = bytes
+        .get(*position..*position + length)
+        .ok_or(Error::InvalidHeaderFilter)?;
+    *position += length;
+    Ok(value)
+}
+
+fn read_value(
+    bytes: &[u8],
+    position: &mut usize,
+    kind: HeaderKind,
+) -> Result<Option<HeaderValue>, Error> {
+    let length = read_u8(bytes, position)? as usize;
+    if length == 0 {
+        return Ok(None);
+    }
+
+    let value = read_bytes(bytes, position, length)?;
+    Ok(Some(HeaderValue {
+        kind,
+        value: value.to_vec(),
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn filters_should_match_headers() {
+        let headers = Some(HashMap::from([
+            (
+                HeaderKey::new("priority").unwrap(),
+                HeaderValue::from_uint32(5).unwrap(),
+            ),
+            (
+                HeaderKey::new("type").unwrap(),
+                HeaderValue::from_str("order_created").unwrap(),
+            ),
+        ]));
+
+        let uint32 = |value| Some(HeaderValue::from_uint32(value).unwrap());
+        let int32 = |value| Some(HeaderValue::from_int32(value).unwrap());
+        assert!(HeaderFilter::exists("type").unwrap().matches(&headers));
+        assert!(!HeaderFilter::exists("missing").unwrap().matches(&headers));
+        assert!(!HeaderFilter::exists("type").unwrap().matches(&None));
+        assert!(
+            HeaderFilter::equals("priority", HeaderValue::from_uint32(5).unwrap())
+                .unwrap()
+                .matches(&headers)
+        );
+        assert!(
+            !HeaderFilter::equals("priority", HeaderValue::from_uint64(5).unwrap())
+                .unwrap()
+                .matches(&headers)
+        );
+        assert!(HeaderFilter::range("priority", uint32(5), uint32(10))
+            .unwrap()
+            .matches(&headers));
+        assert!(HeaderFilter::range("priority", None, uint32(5))
+            .unwrap()
+            .matches(&headers));
+        assert!(!HeaderFilter::range("priority", uint32(6), None)
+            .unwrap()
+            .matches(&headers));
+        assert!(!HeaderFilter::range("priority", int32(0), None)
+            .unwrap()
+            .matches(&headers));
+        assert!(HeaderFilter::prefix("type", "order_")
+            .unwrap()
+            .matches(&headers));
+        assert!(!HeaderFilter::prefix("type", "payment_")
+            .unwrap()
+            .matches(&headers));
+    }
+
+    #[test]
+    fn invalid_range_should_not_be_created() {
+        let string = |value| Some(HeaderValue::from_str(value).unwrap());
+        assert!(HeaderFilter::range("key", None, None).is_err());
+        assert!(HeaderFilter::range("key", string("a"), string("b")).is_err());
+        assert!(HeaderFilter::range(
+            "key",
+            Some(HeaderValue::from_int8(1).unwrap()),
+            Some(HeaderValue::from_int16(2).unwrap())
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn filters_should_be_serialized_and_deserialized() {
+        let filters = vec![
+            HeaderFilter::exists("key-1").unwrap(),
+            HeaderFilter::equals("key-2", HeaderValue::from_str("a:b").unwrap()).unwrap(),
+            HeaderFilter::range("key-3", None, Some(HeaderValue::from_float64(1.5).unwrap()))
+                .unwrap(),
+            HeaderFilter::prefix("key-4", "value").unwrap(),
+        ];
+
+        for filter in filters {
+            assert_eq!(
+                HeaderFilter::from_bytes(&filter.as_bytes()).unwrap(),
+                filter
+            );
+            assert_eq!(HeaderFilter::from_str(&filter.to_string()).unwrap(), filter);
+        }
+    }
+
+    #[test]
+    fn filter_should_be_read_from_string() {
+        let filter = HeaderFilter::from_str("range:priority:int64:-5..10").unwrap();
+        assert_eq!(filter.key.as_str(), "priority");
+        assert_eq!(
+            filter.condition,
+            HeaderCondition::Range {
+                min: Some(HeaderValue::from_int64(-5).unwrap()),
+                max: Some(HeaderValue::from_int64(10).unwrap()),
+            }
+        );
+        assert!(HeaderFilter::from_str("unknown:priority").is_err());
+        assert!(HeaderFilter::from_str("eq:priority:uint8:300").is_err());
+    }
+}
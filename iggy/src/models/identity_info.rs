@@ -0,0 +1,19 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct IdentityInfo {
+    pub user_id: u32,
+    pub tokens: Option<IdentityTokens>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct IdentityTokens {
+    pub access_token: TokenInfo,
+    pub refresh_token: TokenInfo,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TokenInfo {
+    pub token: String,
+    pub expiry: u64,
+}
@@ -16,6 +16,12 @@ use std::str::FromStr;
 pub struct PolledMessages {
     pub partition_id: u32,
     pub current_offset: u64,
+    #[serde(default)]
+    pub scanned_offset: Option<u64>,
+    #[serde(default)]
+    pub generation_id: Option<u32>,
+    #[serde(default)]
+    pub assignment_changed: bool,
     pub messages: Vec<Message>,
 }
 
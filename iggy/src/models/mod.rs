@@ -1,12 +1,25 @@
+pub mod assignment_strategy;
+pub mod cleanup_policy;
 pub mod client_info;
 pub mod consumer_group;
+pub mod consumer_lag;
 pub mod consumer_offset_info;
+pub mod consumer_offset_reset;
 pub mod header;
+pub mod header_filter;
+pub mod identity_info;
 pub mod messages;
 pub mod partition;
 pub mod permissions;
+pub mod personal_access_token;
+pub mod producer_info;
+pub mod quotas;
+pub mod role_info;
+pub mod scram_challenge;
 pub mod stats;
 pub mod stream;
+pub mod stream_encryption;
 pub mod topic;
+pub mod transaction_info;
 pub mod user_info;
 pub mod user_status;
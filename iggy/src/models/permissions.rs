@@ -5,13 +5,13 @@ use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fmt::Display;
 
-#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Default)]
 pub struct Permissions {
     pub global: GlobalPermissions,
     pub streams: Option<HashMap<u32, StreamPermissions>>,
 }
 
-#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Default)]
 pub struct GlobalPermissions {
     pub manage_servers: bool,
     pub read_servers: bool,
@@ -62,6 +62,88 @@ impl Permissions {
             streams: None,
         }
     }
+
+    // The effective permissions granted by both documents, e.g. the user's own permissions and the assigned roles.
+    pub fn union(&self, other: &Permissions) -> Permissions {
+        let streams = match (&self.streams, &other.streams) {
+            (Some(streams), Some(other_streams)) => {
+                let mut streams = streams.clone();
+                for (stream_id, other_stream) in other_streams {
+                    let stream = match streams.get(stream_id) {
+                        Some(stream) => stream.union(other_stream),
+                        None => other_stream.clone(),
+                    };
+                    streams.insert(*stream_id, stream);
+                }
+                Some(streams)
+            }
+            (Some(streams), None) | (None, Some(streams)) => Some(streams.clone()),
+            (None, None) => None,
+        };
+
+        Permissions {
+            global: self.global.union(&other.global),
+            streams,
+        }
+    }
+}
+
+impl GlobalPermissions {
+    pub fn union(&self, other: &GlobalPermissions) -> GlobalPermissions {
+        GlobalPermissions {
+            manage_servers: self.manage_servers || other.manage_servers,
+            read_servers: self.read_servers || other.read_servers,
+            manage_users: self.manage_users || other.manage_users,
+            read_users: self.read_users || other.read_users,
+            manage_streams: self.manage_streams || other.manage_streams,
+            read_streams: self.read_streams || other.read_streams,
+            manage_topics: self.manage_topics || other.manage_topics,
+            read_topics: self.read_topics || other.read_topics,
+            poll_messages: self.poll_messages || other.poll_messages,
+            send_messages: self.send_messages || other.send_messages,
+        }
+    }
+}
+
+impl StreamPermissions {
+    pub fn union(&self, other: &StreamPermissions) -> StreamPermissions {
+        let topics = match (&self.topics, &other.topics) {
+            (Some(topics), Some(other_topics)) => {
+                let mut topics = topics.clone();
+                for (topic_id, other_topic) in other_topics {
+                    let topic = match topics.get(topic_id) {
+                        Some(topic) => topic.union(other_topic),
+                        None => other_topic.clone(),
+                    };
+                    topics.insert(*topic_id, topic);
+                }
+                Some(topics)
+            }
+            (Some(topics), None) | (None, Some(topics)) => Some(topics.clone()),
+            (None, None) => None,
+        };
+
+        StreamPermissions {
+            manage_stream: self.manage_stream || other.manage_stream,
+            read_stream: self.read_stream || other.read_stream,
+            manage_topics: self.manage_topics || other.manage_topics,
+            read_topics: self.read_topics || other.read_topics,
+            poll_messages: self.poll_messages || other.poll_messages,
+            send_messages: self.send_messages || other.send_messages,
+            topics,
+        }
+    }
+}
+
+impl TopicPermissions {
+    pub fn union(&self, other: &TopicPermissions) -> TopicPermissions {
+        TopicPermissions {
+            manage_topic: self.manage_topic || other.manage_topic,
+            read_topic: self.read_topic || other.read_topic,
+            poll_messages: self.poll_messages || other.poll_messages,
+            send_messages: self.send_messages || other.send_messages,
+        }
+    }
 }
 
 impl Display for Permissions {
@@ -317,4 +399,108 @@ mod tests {
 
         assert_eq!(permissions, deserialized_permissions);
     }
+
+    #[test]
+    fn union_should_grant_permissions_from_both_documents() {
+        let permissions = Permissions {
+            global: GlobalPermissions {
+                manage_servers: false,
+                read_servers: true,
+                manage_users: false,
+                read_users: false,
+                manage_streams: false,
+                read_streams: true,
+                manage_topics: false,
+                read_topics: false,
+                poll_messages: false,
+                send_messages: false,
+            },
+            streams: Some(HashMap::from([(
+                1,
+                StreamPermissions {
+                    manage_stream: false,
+                    read_stream: true,
+                    manage_topics: false,
+                    read_topics: true,
+                    poll_messages: true,
+                    send_messages: false,
+                    topics: None,
+                },
+            )])),
+        };
+        let other_permissions = Permissions {
+            global: GlobalPermissions {
+                manage_servers: false,
+                read_servers: false,
+                manage_users: false,
+                read_users: true,
+                manage_streams: false,
+                read_streams: false,
+                manage_topics: false,
+                read_topics: false,
+                poll_messages: false,
+                send_messages: true,
+            },
+            streams: Some(HashMap::from([
+                (
+                    1,
+                    StreamPermissions {
+                        manage_stream: false,
+                        read_stream: false,
+                        manage_topics: false,
+                        read_topics: false,
+                        poll_messages: false,
+                        send_messages: true,
+                        topics: Some(HashMap::from([(
+                            1,
+                            TopicPermissions {
+                                manage_topic: false,
+                                read_topic: true,
+                                poll_messages: true,
+                                send_messages: false,
+                            },
+                        )])),
+                    },
+                ),
+                (
+                    2,
+                    StreamPermissions {
+                        manage_stream: true,
+                        read_stream: true,
+                        manage_topics: true,
+                        read_topics: true,
+                        poll_messages: false,
+                        send_messages: false,
+                        topics: None,
+                    },
+                ),
+            ])),
+        };
+
+        let union = permissions.union(&other_permissions);
+
+        assert!(union.global.read_servers);
+        assert!(union.global.read_users);
+        assert!(union.global.read_streams);
+        assert!(union.global.send_messages);
+        assert!(!union.global.manage_servers);
+        assert!(!union.global.poll_messages);
+        let streams = union.streams.unwrap();
+        assert_eq!(streams.len(), 2);
+        let stream = streams.get(&1).unwrap();
+        assert!(stream.read_stream);
+        assert!(stream.poll_messages);
+        assert!(stream.send_messages);
+        assert!(!stream.manage_stream);
+        assert!(
+            stream
+                .topics
+                .as_ref()
+                .unwrap()
+                .get(&1)
+                .unwrap()
+                .poll_messages
+        );
+        assert!(streams.get(&2).unwrap().manage_stream);
+    }
 }
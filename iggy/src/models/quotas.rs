@@ -0,0 +1,102 @@
+use crate::bytes_serializable::BytesSerializable;
+use crate::error::Error;
+use bytes::BufMut;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::str::FromStr;
+
+pub const QUOTAS_SIZE: usize = 32;
+
+// The value of 0 means that there is no limit.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Copy, Default)]
+pub struct Quotas {
+    #[serde(default)]
+    pub produce_bytes_per_second: u64,
+    #[serde(default)]
+    pub produce_messages_per_second: u64,
+    #[serde(default)]
+    pub consume_bytes_per_second: u64,
+    #[serde(default)]
+    pub consume_messages_per_second: u64,
+}
+
+impl Quotas {
+    pub fn is_unlimited(&self) -> bool {
+        self.produce_bytes_per_second == 0
+            && self.produce_messages_per_second == 0
+            && self.consume_bytes_per_second == 0
+            && self.consume_messages_per_second == 0
+    }
+}
+
+impl FromStr for Quotas {
+    type Err = Error;
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let parts = input.split('|').collect::<Vec<&str>>();
+        if parts.len() != 4 {
+            return Err(Error::InvalidCommand);
+        }
+
+        Ok(Quotas {
+            produce_bytes_per_second: parts[0].parse::<u64>()?,
+            produce_messages_per_second: parts[1].parse::<u64>()?,
+            consume_bytes_per_second: parts[2].parse::<u64>()?,
+            consume_messages_per_second: parts[3].parse::<u64>()?,
+        })
+    }
+}
+
+impl BytesSerializable for Quotas {
+    fn as_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(QUOTAS_SIZE);
+        bytes.put_u64_le(self.produce_bytes_per_second);
+        bytes.put_u64_le(self.produce_messages_per_second);
+        bytes.put_u64_le(self.consume_bytes_per_second);
+        bytes.put_u64_le(self.consume_messages_per_second);
+        bytes
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<Quotas, Error> {
+        if bytes.len() < QUOTAS_SIZE {
+            return Err(Error::InvalidCommand);
+        }
+
+        Ok(Quotas {
+            produce_bytes_per_second: u64::from_le_bytes(bytes[0..8].try_into()?),
+            produce_messages_per_second: u64::from_le_bytes(bytes[8..16].try_into()?),
+            consume_bytes_per_second: u64::from_le_bytes(bytes[16..24].try_into()?),
+            consume_messages_per_second: u64::from_le_bytes(bytes[24..32].try_into()?),
+        })
+    }
+}
+
+impl Display for Quotas {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}|{}|{}|{}",
+            self.produce_bytes_per_second,
+            self.produce_messages_per_second,
+            self.consume_bytes_per_second,
+            self.consume_messages_per_second
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn quotas_should_be_serialized_and_deserialized_from_bytes_and_string() {
+        let quotas = Quotas {
+            produce_bytes_per_second: 1_000_000,
+            produce_messages_per_second: 1000,
+            consume_bytes_per_second: 0,
+            consume_messages_per_second: 500,
+        };
+
+        assert_eq!(Quotas::from_bytes(&quotas.as_bytes()).unwrap(), quotas);
+        assert_eq!(Quotas::from_str(&quotas.to_string()).unwrap(), quotas);
+    }
+}
@@ -0,0 +1,20 @@
+use crate::models::permissions::Permissions;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RoleInfo {
+    pub id: u32,
+    pub created_at: u64,
+    pub name: String,
+    pub members_count: u32,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RoleInfoDetails {
+    pub id: u32,
+    pub created_at: u64,
+    pub name: String,
+    pub members_count: u32,
+    pub permissions: Permissions,
+    pub members: Vec<u32>,
+}
@@ -0,0 +1,8 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ScramChallenge {
+    pub nonce: String,
+    pub salt: Vec<u8>,
+    pub iterations: u32,
+}
@@ -0,0 +1,103 @@
+use crate::bytes_serializable::BytesSerializable;
+use crate::error::Error;
+use bytes::BufMut;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::str::FromStr;
+
+pub const STREAM_ENCRYPTION_SIZE: usize = 6;
+
+// When the key ID is not set, the currently active key of the server-side key ring is used.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Copy, Default)]
+pub struct StreamEncryption {
+    pub enabled: bool,
+    #[serde(default)]
+    pub key_id: Option<u32>,
+    #[serde(default)]
+    pub encrypt_headers: bool,
+}
+
+impl FromStr for StreamEncryption {
+    type Err = Error;
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let parts = input.split('|').collect::<Vec<&str>>();
+        if parts.len() != 3 {
+            return Err(Error::InvalidCommand);
+        }
+
+        let key_id = match parts[1].parse::<u32>()? {
+            0 => None,
+            key_id => Some(key_id),
+        };
+        Ok(StreamEncryption {
+            enabled: parts[0]
+                .parse::<bool>()
+                .map_err(|_| Error::InvalidCommand)?,
+            key_id,
+            encrypt_headers: parts[2]
+                .parse::<bool>()
+                .map_err(|_| Error::InvalidCommand)?,
+        })
+    }
+}
+
+impl BytesSerializable for StreamEncryption {
+    fn as_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(STREAM_ENCRYPTION_SIZE);
+        bytes.put_u8(u8::from(self.enabled));
+        bytes.put_u32_le(self.key_id.unwrap_or(0));
+        bytes.put_u8(u8::from(self.encrypt_headers));
+        bytes
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<StreamEncryption, Error> {
+        if bytes.len() < STREAM_ENCRYPTION_SIZE {
+            return Err(Error::InvalidCommand);
+        }
+
+        let key_id = match u32::from_le_bytes(bytes[1..5].try_into()?) {
+            0 => None,
+            key_id => Some(key_id),
+        };
+        Ok(StreamEncryption {
+            enabled: bytes[0] == 1,
+            key_id,
+            encrypt_headers: bytes[5] == 1,
+        })
+    }
+}
+
+impl Display for StreamEncryption {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}|{}|{}",
+            self.enabled,
+            self.key_id.unwrap_or(0),
+            self.encrypt_headers
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stream_encryption_should_be_serialized_and_deserialized_from_bytes_and_string() {
+        let encryption = StreamEncryption {
+            enabled: true,
+            key_id: Some(2),
+            encrypt_headers: true,
+        };
+
+        assert_eq!(
+            StreamEncryption::from_bytes(&encryption.as_bytes()).unwrap(),
+            encryption
+        );
+        assert_eq!(
+            StreamEncryption::from_str(&encryption.to_string()).unwrap(),
+            encryption
+        );
+    }
+}
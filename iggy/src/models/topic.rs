@@ -1,3 +1,4 @@
+use crate::models::cleanup_policy::CleanupPolicy;
 use crate::models::partition::Partition;
 use serde::{Deserialize, Serialize};
 
@@ -8,6 +9,11 @@ pub struct Topic {
     pub name: String,
     pub size_bytes: u64,
     pub message_expiry: Option<u32>,
+    pub cleanup_policy: CleanupPolicy,
+    pub compaction_key: Option<String>,
+    pub max_topic_size_bytes: Option<u64>,
+    pub max_messages: Option<u64>,
+    pub dead_letter_topic_id: Option<u32>,
     pub messages_count: u64,
     pub partitions_count: u32,
 }
@@ -19,6 +25,11 @@ pub struct TopicDetails {
     pub name: String,
     pub size_bytes: u64,
     pub message_expiry: Option<u32>,
+    pub cleanup_policy: CleanupPolicy,
+    pub compaction_key: Option<String>,
+    pub max_topic_size_bytes: Option<u64>,
+    pub max_messages: Option<u64>,
+    pub dead_letter_topic_id: Option<u32>,
     pub messages_count: u64,
     pub partitions_count: u32,
     pub partitions: Vec<Partition>,
@@ -1,4 +1,5 @@
 use crate::models::permissions::Permissions;
+use crate::models::quotas::Quotas;
 use crate::models::user_status::UserStatus;
 use serde::{Deserialize, Serialize};
 
@@ -17,4 +18,10 @@ pub struct UserInfoDetails {
     pub status: UserStatus,
     pub username: String,
     pub permissions: Option<Permissions>,
+    #[serde(default)]
+    pub quotas: Option<Quotas>,
+    #[serde(default)]
+    pub failed_login_attempts: u32,
+    #[serde(default)]
+    pub locked_at: Option<u64>,
 }
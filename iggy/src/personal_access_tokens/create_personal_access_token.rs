@@ -0,0 +1,160 @@
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::CommandPayload;
+use crate::error::Error;
+use crate::personal_access_tokens::{
+    MAX_PERSONAL_ACCESS_TOKEN_NAME_LENGTH, MIN_PERSONAL_ACCESS_TOKEN_NAME_LENGTH,
+};
+use crate::utils::text;
+use crate::validatable::Validatable;
+use bytes::BufMut;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::str::{from_utf8, FromStr};
+
+// The expiry is the number of seconds after which the token expires, if not specified, it never expires.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct CreatePersonalAccessToken {
+    pub name: String,
+    #[serde(default)]
+    pub expiry: Option<u32>,
+}
+
+impl CommandPayload for CreatePersonalAccessToken {}
+
+impl Default for CreatePersonalAccessToken {
+    fn default() -> Self {
+        CreatePersonalAccessToken {
+            name: "token".to_string(),
+            expiry: None,
+        }
+    }
+}
+
+impl Validatable<Error> for CreatePersonalAccessToken {
+    fn validate(&self) -> Result<(), Error> {
+        if self.name.is_empty()
+            || self.name.len() > MAX_PERSONAL_ACCESS_TOKEN_NAME_LENGTH
+            || self.name.len() < MIN_PERSONAL_ACCESS_TOKEN_NAME_LENGTH
+        {
+            return Err(Error::InvalidPersonalAccessTokenName);
+        }
+
+        if !text::is_resource_name_valid(&self.name) {
+            return Err(Error::InvalidPersonalAccessTokenName);
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for CreatePersonalAccessToken {
+    type Err = Error;
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let parts = input.split('|').collect::<Vec<&str>>();
+        if parts.is_empty() || parts.len() > 2 {
+            return Err(Error::InvalidCommand);
+        }
+
+        let name = parts[0].to_string();
+        let expiry = match parts.get(1) {
+            Some(expiry) => match expiry.parse::<u32>()? {
+                0 => None,
+                expiry => Some(expiry),
+            },
+            None => None,
+        };
+        let command = CreatePersonalAccessToken { name, expiry };
+        command.validate()?;
+        Ok(command)
+    }
+}
+
+impl BytesSerializable for CreatePersonalAccessToken {
+    fn as_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(5 + self.name.len());
+        #[allow(clippy::cast_possible_truncation)]
+        bytes.put_u8(self.name.len() as u8);
+        bytes.extend(self.name.as_bytes());
+        bytes.put_u32_le(self.expiry.unwrap_or(0));
+        bytes
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<CreatePersonalAccessToken, Error> {
+        if bytes.len() < 8 {
+            return Err(Error::InvalidCommand);
+        }
+
+        let name_length = bytes[0];
+        if bytes.len() != 5 + name_length as usize {
+            return Err(Error::InvalidCommand);
+        }
+
+        let name = from_utf8(&bytes[1..=(name_length as usize)])?.to_string();
+        let position = 1 + name_length as usize;
+        let expiry = match u32::from_le_bytes(bytes[position..position + 4].try_into()?) {
+            0 => None,
+            expiry => Some(expiry),
+        };
+        let command = CreatePersonalAccessToken { name, expiry };
+        command.validate()?;
+        Ok(command)
+    }
+}
+
+impl Display for CreatePersonalAccessToken {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}|{}", self.name, self.expiry.unwrap_or(0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_bytes() {
+        let command = CreatePersonalAccessToken {
+            name: "test".to_string(),
+            expiry: Some(100),
+        };
+
+        let bytes = command.as_bytes();
+        let name_length = bytes[0];
+        let name = from_utf8(&bytes[1..=(name_length as usize)]).unwrap();
+        let position = 1 + name_length as usize;
+        let expiry = u32::from_le_bytes(bytes[position..position + 4].try_into().unwrap());
+
+        assert!(!bytes.is_empty());
+        assert_eq!(name, command.name);
+        assert_eq!(Some(expiry), command.expiry);
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes() {
+        let name = "test";
+        let mut bytes = Vec::new();
+        #[allow(clippy::cast_possible_truncation)]
+        bytes.put_u8(name.len() as u8);
+        bytes.extend(name.as_bytes());
+        bytes.put_u32_le(0);
+        let command = CreatePersonalAccessToken::from_bytes(&bytes);
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.name, name);
+        assert!(command.expiry.is_none());
+    }
+
+    #[test]
+    fn should_be_read_from_string() {
+        let name = "test";
+        let expiry = 100;
+        let input = format!("{name}|{expiry}");
+        let command = CreatePersonalAccessToken::from_str(&input);
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.name, name);
+        assert_eq!(command.expiry, Some(expiry));
+    }
+}
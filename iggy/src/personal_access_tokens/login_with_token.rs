@@ -0,0 +1,124 @@
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::CommandPayload;
+use crate::error::Error;
+use crate::personal_access_tokens::MAX_PERSONAL_ACCESS_TOKEN_LENGTH;
+use crate::validatable::Validatable;
+use bytes::BufMut;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::str::{from_utf8, FromStr};
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct LoginWithToken {
+    pub token: String,
+}
+
+impl CommandPayload for LoginWithToken {}
+
+impl Default for LoginWithToken {
+    fn default() -> Self {
+        LoginWithToken {
+            token: "token".to_string(),
+        }
+    }
+}
+
+impl Validatable<Error> for LoginWithToken {
+    fn validate(&self) -> Result<(), Error> {
+        if self.token.is_empty() || self.token.len() > MAX_PERSONAL_ACCESS_TOKEN_LENGTH {
+            return Err(Error::InvalidPersonalAccessToken);
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for LoginWithToken {
+    type Err = Error;
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let parts = input.split('|').collect::<Vec<&str>>();
+        if parts.len() != 1 {
+            return Err(Error::InvalidCommand);
+        }
+
+        let token = parts[0].to_string();
+        let command = LoginWithToken { token };
+        command.validate()?;
+        Ok(command)
+    }
+}
+
+impl BytesSerializable for LoginWithToken {
+    fn as_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(1 + self.token.len());
+        #[allow(clippy::cast_possible_truncation)]
+        bytes.put_u8(self.token.len() as u8);
+        bytes.extend(self.token.as_bytes());
+        bytes
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<LoginWithToken, Error> {
+        if bytes.len() < 2 {
+            return Err(Error::InvalidCommand);
+        }
+
+        let token_length = bytes[0];
+        if bytes.len() != 1 + token_length as usize {
+            return Err(Error::InvalidCommand);
+        }
+
+        let token = from_utf8(&bytes[1..=(token_length as usize)])?.to_string();
+        let command = LoginWithToken { token };
+        command.validate()?;
+        Ok(command)
+    }
+}
+
+impl Display for LoginWithToken {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.token)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_bytes() {
+        let command = LoginWithToken {
+            token: "secret".to_string(),
+        };
+
+        let bytes = command.as_bytes();
+        let token_length = bytes[0];
+        let token = from_utf8(&bytes[1..=(token_length as usize)]).unwrap();
+
+        assert!(!bytes.is_empty());
+        assert_eq!(token, command.token);
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes() {
+        let token = "secret";
+        let mut bytes = Vec::new();
+        #[allow(clippy::cast_possible_truncation)]
+        bytes.put_u8(token.len() as u8);
+        bytes.extend(token.as_bytes());
+        let command = LoginWithToken::from_bytes(&bytes);
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.token, token);
+    }
+
+    #[test]
+    fn should_be_read_from_string() {
+        let token = "secret";
+        let command = LoginWithToken::from_str(token);
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.token, token);
+    }
+}
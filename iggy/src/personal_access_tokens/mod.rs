@@ -0,0 +1,8 @@
+pub mod create_personal_access_token;
+pub mod delete_personal_access_token;
+pub mod get_personal_access_tokens;
+pub mod login_with_token;
+
+const MAX_PERSONAL_ACCESS_TOKEN_NAME_LENGTH: usize = 30;
+const MIN_PERSONAL_ACCESS_TOKEN_NAME_LENGTH: usize = 3;
+const MAX_PERSONAL_ACCESS_TOKEN_LENGTH: usize = 100;
@@ -1,13 +1,17 @@
 use crate::binary::binary_client::BinaryClient;
+use crate::binary::subscriptions::{MessageSubscriptions, PUSH_STATUS};
 use crate::client::Client;
 use crate::error::Error;
 use crate::quic::config::QuicClientConfig;
 use async_trait::async_trait;
 use bytes::BufMut;
 use quinn::{ClientConfig, Connection, Endpoint, IdleTimeout, RecvStream, VarInt};
+use std::fs::File;
+use std::io::BufReader;
 use std::net::SocketAddr;
 use std::sync::Arc;
 use std::time::Duration;
+use tokio::task::JoinHandle;
 use tokio::time::sleep;
 use tracing::{error, info, trace};
 
@@ -22,6 +26,8 @@ pub struct QuicClient {
     pub(crate) connection: Option<Connection>,
     pub(crate) config: Arc<QuicClientConfig>,
     pub(crate) server_address: SocketAddr,
+    pub(crate) subscriptions: MessageSubscriptions,
+    pub(crate) push_reader: Option<JoinHandle<()>>,
 }
 
 unsafe impl Send for QuicClient {}
@@ -74,6 +80,10 @@ impl Client for QuicClient {
             break;
         }
 
+        self.push_reader = Some(start_push_reader(
+            connection.clone(),
+            self.subscriptions.clone(),
+        ));
         self.connection = Some(connection);
 
         Ok(())
@@ -81,6 +91,10 @@ impl Client for QuicClient {
 
     async fn disconnect(&mut self) -> Result<(), Error> {
         info!("{} client is disconnecting from server...", NAME);
+        if let Some(push_reader) = self.push_reader.take() {
+            push_reader.abort();
+        }
+        self.subscriptions.clear();
         self.connection = None;
         self.endpoint.wait_idle().await;
         info!("{} client has disconnected from server.", NAME);
@@ -108,6 +122,10 @@ impl BinaryClient for QuicClient {
         error!("Cannot send data. Client is not connected.");
         Err(Error::NotConnected)
     }
+
+    fn get_subscriptions(&self) -> &MessageSubscriptions {
+        &self.subscriptions
+    }
 }
 
 impl QuicClient {
@@ -144,6 +162,8 @@ impl QuicClient {
             endpoint,
             server_address,
             connection: None,
+            subscriptions: MessageSubscriptions::default(),
+            push_reader: None,
         })
     }
 
@@ -179,6 +199,42 @@ impl QuicClient {
     }
 }
 
+// The server opens a dedicated unidirectional stream for each subscription to push the messages.
+fn start_push_reader(
+    connection: Connection,
+    subscriptions: MessageSubscriptions,
+) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        while let Ok(mut recv) = connection.accept_uni().await {
+            let subscriptions = subscriptions.clone();
+            tokio::spawn(async move {
+                loop {
+                    let mut header = [0u8; RESPONSE_INITIAL_BYTES_LENGTH];
+                    if recv.read_exact(&mut header).await.is_err() {
+                        return;
+                    }
+
+                    let status = u32::from_le_bytes(header[..4].try_into().unwrap());
+                    let length = u32::from_le_bytes(header[4..].try_into().unwrap());
+                    let mut payload = vec![0u8; length as usize];
+                    if recv.read_exact(&mut payload).await.is_err() {
+                        return;
+                    }
+
+                    if status != PUSH_STATUS {
+                        error!("Received an invalid pushed frame with status: {}.", status);
+                        continue;
+                    }
+
+                    if let Err(error) = subscriptions.dispatch(&payload) {
+                        error!("Received invalid pushed messages: {}", error);
+                    }
+                }
+            });
+        }
+    })
+}
+
 fn configure(config: &QuicClientConfig) -> Result<ClientConfig, Error> {
     let max_concurrent_bidi_streams = VarInt::try_from(config.max_concurrent_bidi_streams);
     if max_concurrent_bidi_streams.is_err() {
@@ -214,19 +270,72 @@ fn configure(config: &QuicClientConfig) -> Result<ClientConfig, Error> {
         transport.max_idle_timeout(Some(max_idle_timeout.unwrap()));
     }
 
-    let mut client_config = match config.validate_certificate {
-        true => ClientConfig::with_native_roots(),
-        false => ClientConfig::new(Arc::new(
+    let client_certificate = match (&config.client_certificate_file, &config.client_key_file) {
+        (Some(certificate_file), Some(key_file)) => {
+            Some(load_client_certificate(certificate_file, key_file)?)
+        }
+        _ => None,
+    };
+
+    let mut client_config = match (config.validate_certificate, client_certificate) {
+        (true, None) => ClientConfig::with_native_roots(),
+        (false, None) => ClientConfig::new(Arc::new(
             rustls::ClientConfig::builder()
                 .with_safe_defaults()
                 .with_custom_certificate_verifier(SkipServerVerification::new())
                 .with_no_client_auth(),
         )),
+        (validate_certificate, Some((certificates, key))) => {
+            let builder = rustls::ClientConfig::builder().with_safe_defaults();
+            let crypto = match validate_certificate {
+                true => builder
+                    .with_root_certificates(load_native_roots()?)
+                    .with_client_auth_cert(certificates, key),
+                false => builder
+                    .with_custom_certificate_verifier(SkipServerVerification::new())
+                    .with_client_auth_cert(certificates, key),
+            };
+            if crypto.is_err() {
+                error!("Invalid client certificate: {:?}", crypto.err());
+                return Err(Error::InvalidConfiguration);
+            }
+            ClientConfig::new(Arc::new(crypto.unwrap()))
+        }
     };
     client_config.transport_config(Arc::new(transport));
     Ok(client_config)
 }
 
+fn load_client_certificate(
+    certificate_file: &str,
+    key_file: &str,
+) -> Result<(Vec<rustls::Certificate>, rustls::PrivateKey), Error> {
+    let mut certificate_reader = BufReader::new(File::open(certificate_file)?);
+    let certificates = rustls_pemfile::certs(&mut certificate_reader)?
+        .into_iter()
+        .map(rustls::Certificate)
+        .collect();
+    let mut key_reader = BufReader::new(File::open(key_file)?);
+    let mut keys = rustls_pemfile::pkcs8_private_keys(&mut key_reader)?;
+    if keys.is_empty() {
+        error!("No private key found in: {key_file}");
+        return Err(Error::InvalidConfiguration);
+    }
+
+    Ok((certificates, rustls::PrivateKey(keys.remove(0))))
+}
+
+fn load_native_roots() -> Result<rustls::RootCertStore, Error> {
+    let mut roots = rustls::RootCertStore::empty();
+    for certificate in rustls_native_certs::load_native_certs()? {
+        if roots.add(&rustls::Certificate(certificate.0)).is_err() {
+            trace!("Skipping invalid native root certificate.");
+        }
+    }
+
+    Ok(roots)
+}
+
 struct SkipServerVerification;
 
 impl SkipServerVerification {
@@ -14,6 +14,9 @@ pub struct QuicClientConfig {
     pub keep_alive_interval: u64,
     pub max_idle_timeout: u64,
     pub validate_certificate: bool,
+    pub client_certificate_file: Option<String>,
+    pub client_key_file: Option<String>,
+    pub plaintext_login_fallback: bool,
 }
 
 impl Default for QuicClientConfig {
@@ -33,6 +36,9 @@ impl Default for QuicClientConfig {
             keep_alive_interval: 5000,
             max_idle_timeout: 10000,
             validate_certificate: false,
+            client_certificate_file: None,
+            client_key_file: None,
+            plaintext_login_fallback: false,
         }
     }
 }
@@ -1,11 +1,18 @@
 use crate::binary;
 use crate::client::MessageClient;
 use crate::error::Error;
+use crate::messages::grant_credits::GrantCredits;
+use crate::messages::init_producer::InitProducer;
+use crate::messages::poison_message::PoisonMessage;
 use crate::messages::poll_messages::PollMessages;
 use crate::messages::send_messages::SendMessages;
+use crate::messages::subscribe::Subscribe;
+use crate::messages::unsubscribe::Unsubscribe;
 use crate::models::messages::PolledMessages;
+use crate::models::producer_info::ProducerInfo;
 use crate::quic::client::QuicClient;
 use async_trait::async_trait;
+use flume::Receiver;
 
 #[async_trait]
 impl MessageClient for QuicClient {
@@ -16,4 +23,24 @@ impl MessageClient for QuicClient {
     async fn send_messages(&self, command: &mut SendMessages) -> Result<(), Error> {
         binary::messages::send_messages(self, command).await
     }
+
+    async fn poison_message(&self, command: &PoisonMessage) -> Result<(), Error> {
+        binary::messages::poison_message(self, command).await
+    }
+
+    async fn subscribe(&self, command: &Subscribe) -> Result<Receiver<PolledMessages>, Error> {
+        binary::messages::subscribe(self, command).await
+    }
+
+    async fn grant_credits(&self, command: &GrantCredits) -> Result<(), Error> {
+        binary::messages::grant_credits(self, command).await
+    }
+
+    async fn unsubscribe(&self, command: &Unsubscribe) -> Result<(), Error> {
+        binary::messages::unsubscribe(self, command).await
+    }
+
+    async fn init_producer(&self, command: &InitProducer) -> Result<ProducerInfo, Error> {
+        binary::messages::init_producer(self, command).await
+    }
 }
@@ -0,0 +1,44 @@
+use crate::binary;
+use crate::client::RoleClient;
+use crate::error::Error;
+use crate::models::role_info::{RoleInfo, RoleInfoDetails};
+use crate::quic::client::QuicClient;
+use crate::roles::add_role_member::AddRoleMember;
+use crate::roles::create_role::CreateRole;
+use crate::roles::delete_role::DeleteRole;
+use crate::roles::get_role::GetRole;
+use crate::roles::get_roles::GetRoles;
+use crate::roles::remove_role_member::RemoveRoleMember;
+use crate::roles::update_role::UpdateRole;
+use async_trait::async_trait;
+
+#[async_trait]
+impl RoleClient for QuicClient {
+    async fn get_role(&self, command: &GetRole) -> Result<RoleInfoDetails, Error> {
+        binary::roles::get_role(self, command).await
+    }
+
+    async fn get_roles(&self, command: &GetRoles) -> Result<Vec<RoleInfo>, Error> {
+        binary::roles::get_roles(self, command).await
+    }
+
+    async fn create_role(&self, command: &CreateRole) -> Result<(), Error> {
+        binary::roles::create_role(self, command).await
+    }
+
+    async fn delete_role(&self, command: &DeleteRole) -> Result<(), Error> {
+        binary::roles::delete_role(self, command).await
+    }
+
+    async fn update_role(&self, command: &UpdateRole) -> Result<(), Error> {
+        binary::roles::update_role(self, command).await
+    }
+
+    async fn add_role_member(&self, command: &AddRoleMember) -> Result<(), Error> {
+        binary::roles::add_role_member(self, command).await
+    }
+
+    async fn remove_role_member(&self, command: &RemoveRoleMember) -> Result<(), Error> {
+        binary::roles::remove_role_member(self, command).await
+    }
+}
@@ -10,7 +10,9 @@ use crate::users::get_user::GetUser;
 use crate::users::get_users::GetUsers;
 use crate::users::login_user::LoginUser;
 use crate::users::logout_user::LogoutUser;
+use crate::users::unlock_user::UnlockUser;
 use crate::users::update_permissions::UpdatePermissions;
+use crate::users::update_quotas::UpdateQuotas;
 use crate::users::update_user::UpdateUser;
 use async_trait::async_trait;
 
@@ -32,6 +34,10 @@ impl UserClient for QuicClient {
         binary::users::delete_user(self, command).await
     }
 
+    async fn unlock_user(&self, command: &UnlockUser) -> Result<(), Error> {
+        binary::users::unlock_user(self, command).await
+    }
+
     async fn update_user(&self, command: &UpdateUser) -> Result<(), Error> {
         binary::users::update_user(self, command).await
     }
@@ -40,12 +46,16 @@ impl UserClient for QuicClient {
         binary::users::update_permissions(self, command).await
     }
 
+    async fn update_quotas(&self, command: &UpdateQuotas) -> Result<(), Error> {
+        binary::users::update_quotas(self, command).await
+    }
+
     async fn change_password(&self, command: &ChangePassword) -> Result<(), Error> {
         binary::users::change_password(self, command).await
     }
 
     async fn login_user(&self, command: &LoginUser) -> Result<(), Error> {
-        binary::users::login_user(self, command).await
+        binary::users::login_user(self, command, self.config.plaintext_login_fallback).await
     }
 
     async fn logout_user(&self, command: &LogoutUser) -> Result<(), Error> {
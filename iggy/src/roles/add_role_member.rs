@@ -0,0 +1,124 @@
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::CommandPayload;
+use crate::error::Error;
+use crate::identifier::Identifier;
+use crate::validatable::Validatable;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::str::FromStr;
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default)]
+pub struct AddRoleMember {
+    #[serde(skip)]
+    pub role_id: Identifier,
+    #[serde(skip)]
+    pub user_id: Identifier,
+}
+
+impl CommandPayload for AddRoleMember {}
+
+impl Validatable<Error> for AddRoleMember {
+    fn validate(&self) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+impl FromStr for AddRoleMember {
+    type Err = Error;
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let parts = input.split('|').collect::<Vec<&str>>();
+        if parts.len() != 2 {
+            return Err(Error::InvalidCommand);
+        }
+
+        let role_id = parts[0].parse::<Identifier>()?;
+        let user_id = parts[1].parse::<Identifier>()?;
+        let command = AddRoleMember { role_id, user_id };
+        command.validate()?;
+        Ok(command)
+    }
+}
+
+impl BytesSerializable for AddRoleMember {
+    fn as_bytes(&self) -> Vec<u8> {
+        let role_id_bytes = self.role_id.as_bytes();
+        let user_id_bytes = self.user_id.as_bytes();
+        let mut bytes = Vec::with_capacity(role_id_bytes.len() + user_id_bytes.len());
+        bytes.extend(role_id_bytes);
+        bytes.extend(user_id_bytes);
+        bytes
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<AddRoleMember, Error> {
+        if bytes.len() < 6 {
+            return Err(Error::InvalidCommand);
+        }
+
+        let mut position = 0;
+        let role_id = Identifier::from_bytes(bytes)?;
+        position += role_id.get_size_bytes() as usize;
+        let user_id = Identifier::from_bytes(&bytes[position..])?;
+        let command = AddRoleMember { role_id, user_id };
+        command.validate()?;
+        Ok(command)
+    }
+}
+
+impl Display for AddRoleMember {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}|{}", self.role_id, self.user_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_bytes() {
+        let command = AddRoleMember {
+            role_id: Identifier::numeric(1).unwrap(),
+            user_id: Identifier::numeric(2).unwrap(),
+        };
+
+        let bytes = command.as_bytes();
+        let mut position = 0;
+        let role_id = Identifier::from_bytes(&bytes).unwrap();
+        position += role_id.get_size_bytes() as usize;
+        let user_id = Identifier::from_bytes(&bytes[position..]).unwrap();
+
+        assert!(!bytes.is_empty());
+        assert_eq!(role_id, command.role_id);
+        assert_eq!(user_id, command.user_id);
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes() {
+        let role_id = Identifier::numeric(1).unwrap();
+        let user_id = Identifier::numeric(2).unwrap();
+        let role_id_bytes = role_id.as_bytes();
+        let user_id_bytes = user_id.as_bytes();
+        let mut bytes = Vec::with_capacity(role_id_bytes.len() + user_id_bytes.len());
+        bytes.extend(role_id_bytes);
+        bytes.extend(user_id_bytes);
+        let command = AddRoleMember::from_bytes(&bytes);
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.role_id, role_id);
+        assert_eq!(command.user_id, user_id);
+    }
+
+    #[test]
+    fn should_be_read_from_string() {
+        let role_id = Identifier::numeric(1).unwrap();
+        let user_id = Identifier::numeric(2).unwrap();
+        let input = format!("{}|{}", role_id, user_id);
+        let command = AddRoleMember::from_str(&input);
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.role_id, role_id);
+        assert_eq!(command.user_id, user_id);
+    }
+}
@@ -0,0 +1,180 @@
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::CommandPayload;
+use crate::error::Error;
+use crate::models::permissions::Permissions;
+use crate::roles::{MAX_NAME_LENGTH, MIN_NAME_LENGTH};
+use crate::utils::text;
+use crate::validatable::Validatable;
+use bytes::BufMut;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::str::{from_utf8, FromStr};
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct CreateRole {
+    pub name: String,
+    pub permissions: Permissions,
+}
+
+impl CommandPayload for CreateRole {}
+
+impl Default for CreateRole {
+    fn default() -> Self {
+        CreateRole {
+            name: "role".to_string(),
+            permissions: Permissions::default(),
+        }
+    }
+}
+
+impl Validatable<Error> for CreateRole {
+    fn validate(&self) -> Result<(), Error> {
+        if self.name.is_empty()
+            || self.name.len() > MAX_NAME_LENGTH
+            || self.name.len() < MIN_NAME_LENGTH
+        {
+            return Err(Error::InvalidRoleName);
+        }
+
+        if !text::is_resource_name_valid(&self.name) {
+            return Err(Error::InvalidRoleName);
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for CreateRole {
+    type Err = Error;
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        // No support for permissions yet
+        let parts = input.split('|').collect::<Vec<&str>>();
+        if parts.len() != 1 {
+            return Err(Error::InvalidCommand);
+        }
+
+        let name = parts[0].to_string();
+        let command = CreateRole {
+            name,
+            permissions: Permissions::default(),
+        };
+        command.validate()?;
+        Ok(command)
+    }
+}
+
+impl BytesSerializable for CreateRole {
+    fn as_bytes(&self) -> Vec<u8> {
+        let permissions = self.permissions.as_bytes();
+        let mut bytes = Vec::with_capacity(5 + self.name.len() + permissions.len());
+        #[allow(clippy::cast_possible_truncation)]
+        bytes.put_u8(self.name.len() as u8);
+        bytes.extend(self.name.as_bytes());
+        #[allow(clippy::cast_possible_truncation)]
+        bytes.put_u32_le(permissions.len() as u32);
+        bytes.extend(permissions);
+        bytes
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<CreateRole, Error> {
+        if bytes.len() < 8 {
+            return Err(Error::InvalidCommand);
+        }
+
+        let name_length = bytes[0] as usize;
+        if bytes.len() < 5 + name_length {
+            return Err(Error::InvalidCommand);
+        }
+
+        let name = from_utf8(&bytes[1..1 + name_length])?.to_string();
+        let mut position = 1 + name_length;
+        let permissions_length = u32::from_le_bytes(bytes[position..position + 4].try_into()?);
+        position += 4;
+        if bytes.len() != position + permissions_length as usize {
+            return Err(Error::InvalidCommand);
+        }
+
+        let permissions = Permissions::from_bytes(&bytes[position..])?;
+        let command = CreateRole { name, permissions };
+        command.validate()?;
+        Ok(command)
+    }
+}
+
+impl Display for CreateRole {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::permissions::GlobalPermissions;
+
+    #[test]
+    fn should_be_serialized_as_bytes() {
+        let command = CreateRole {
+            name: "readers".to_string(),
+            permissions: get_permissions(),
+        };
+
+        let bytes = command.as_bytes();
+        let name_length = bytes[0] as usize;
+        let name = from_utf8(&bytes[1..1 + name_length]).unwrap();
+        let mut position = 1 + name_length;
+        let permissions_length =
+            u32::from_le_bytes(bytes[position..position + 4].try_into().unwrap());
+        position += 4;
+        let permissions =
+            Permissions::from_bytes(&bytes[position..position + permissions_length as usize])
+                .unwrap();
+
+        assert!(!bytes.is_empty());
+        assert_eq!(name, command.name);
+        assert_eq!(permissions, command.permissions);
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes() {
+        let name = "readers";
+        let permissions = get_permissions();
+        let permissions_bytes = permissions.as_bytes();
+        let mut bytes = Vec::new();
+        #[allow(clippy::cast_possible_truncation)]
+        bytes.put_u8(name.len() as u8);
+        bytes.extend(name.as_bytes());
+        bytes.put_u32_le(permissions_bytes.len() as u32);
+        bytes.extend(permissions_bytes);
+
+        let command = CreateRole::from_bytes(&bytes);
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.name, name);
+        assert_eq!(command.permissions, permissions);
+    }
+
+    #[test]
+    fn should_be_read_from_string() {
+        let name = "readers";
+        let command = CreateRole::from_str(name);
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.name, name);
+        assert_eq!(command.permissions, Permissions::default());
+    }
+
+    fn get_permissions() -> Permissions {
+        Permissions {
+            global: GlobalPermissions {
+                read_streams: true,
+                read_topics: true,
+                poll_messages: true,
+                ..Default::default()
+            },
+            streams: None,
+        }
+    }
+}
@@ -0,0 +1,100 @@
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::CommandPayload;
+use crate::error::Error;
+use crate::identifier::Identifier;
+use crate::validatable::Validatable;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::str::FromStr;
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default)]
+pub struct DeleteRole {
+    #[serde(skip)]
+    pub role_id: Identifier,
+}
+
+impl CommandPayload for DeleteRole {}
+
+impl Validatable<Error> for DeleteRole {
+    fn validate(&self) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+impl FromStr for DeleteRole {
+    type Err = Error;
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let parts = input.split('|').collect::<Vec<&str>>();
+        if parts.len() != 1 {
+            return Err(Error::InvalidCommand);
+        }
+
+        let role_id = parts[0].parse::<Identifier>()?;
+        let command = DeleteRole { role_id };
+        command.validate()?;
+        Ok(command)
+    }
+}
+
+impl BytesSerializable for DeleteRole {
+    fn as_bytes(&self) -> Vec<u8> {
+        self.role_id.as_bytes()
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<DeleteRole, Error> {
+        if bytes.len() < 3 {
+            return Err(Error::InvalidCommand);
+        }
+
+        let role_id = Identifier::from_bytes(bytes)?;
+        let command = DeleteRole { role_id };
+        command.validate()?;
+        Ok(command)
+    }
+}
+
+impl Display for DeleteRole {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.role_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_bytes() {
+        let command = DeleteRole {
+            role_id: Identifier::numeric(1).unwrap(),
+        };
+
+        let bytes = command.as_bytes();
+        let role_id = Identifier::from_bytes(&bytes).unwrap();
+
+        assert!(!bytes.is_empty());
+        assert_eq!(role_id, command.role_id);
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes() {
+        let role_id = Identifier::numeric(1).unwrap();
+        let bytes = role_id.as_bytes();
+        let command = DeleteRole::from_bytes(&bytes);
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.role_id, role_id);
+    }
+
+    #[test]
+    fn should_be_read_from_string() {
+        let role_id = Identifier::numeric(1).unwrap();
+        let input = role_id.to_string();
+        let command = DeleteRole::from_str(&input);
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.role_id, role_id);
+    }
+}
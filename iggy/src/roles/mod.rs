@@ -0,0 +1,10 @@
+pub mod add_role_member;
+pub mod create_role;
+pub mod delete_role;
+pub mod get_role;
+pub mod get_roles;
+pub mod remove_role_member;
+pub mod update_role;
+
+const MAX_NAME_LENGTH: usize = 50;
+const MIN_NAME_LENGTH: usize = 3;
@@ -0,0 +1,158 @@
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::CommandPayload;
+use crate::error::Error;
+use crate::identifier::Identifier;
+use crate::models::permissions::Permissions;
+use crate::validatable::Validatable;
+use bytes::BufMut;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::str::FromStr;
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default)]
+pub struct UpdateRole {
+    #[serde(skip)]
+    pub role_id: Identifier,
+    pub permissions: Permissions,
+}
+
+impl CommandPayload for UpdateRole {}
+
+impl Validatable<Error> for UpdateRole {
+    fn validate(&self) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+impl FromStr for UpdateRole {
+    type Err = Error;
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        // No support for permissions yet
+        let parts = input.split('|').collect::<Vec<&str>>();
+        if parts.len() != 1 {
+            return Err(Error::InvalidCommand);
+        }
+
+        let role_id = parts[0].parse::<Identifier>()?;
+        let command = UpdateRole {
+            role_id,
+            permissions: Permissions::default(),
+        };
+        command.validate()?;
+        Ok(command)
+    }
+}
+
+impl BytesSerializable for UpdateRole {
+    fn as_bytes(&self) -> Vec<u8> {
+        let role_id_bytes = self.role_id.as_bytes();
+        let permissions = self.permissions.as_bytes();
+        let mut bytes = Vec::with_capacity(role_id_bytes.len() + 4 + permissions.len());
+        bytes.extend(role_id_bytes);
+        #[allow(clippy::cast_possible_truncation)]
+        bytes.put_u32_le(permissions.len() as u32);
+        bytes.extend(permissions);
+        bytes
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<UpdateRole, Error> {
+        if bytes.len() < 8 {
+            return Err(Error::InvalidCommand);
+        }
+
+        let role_id = Identifier::from_bytes(bytes)?;
+        let mut position = role_id.get_size_bytes() as usize;
+        if bytes.len() < position + 4 {
+            return Err(Error::InvalidCommand);
+        }
+
+        let permissions_length = u32::from_le_bytes(bytes[position..position + 4].try_into()?);
+        position += 4;
+        if bytes.len() != position + permissions_length as usize {
+            return Err(Error::InvalidCommand);
+        }
+
+        let permissions = Permissions::from_bytes(&bytes[position..])?;
+        let command = UpdateRole {
+            role_id,
+            permissions,
+        };
+        command.validate()?;
+        Ok(command)
+    }
+}
+
+impl Display for UpdateRole {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.role_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::permissions::GlobalPermissions;
+
+    #[test]
+    fn should_be_serialized_as_bytes() {
+        let command = UpdateRole {
+            role_id: Identifier::numeric(1).unwrap(),
+            permissions: get_permissions(),
+        };
+
+        let bytes = command.as_bytes();
+        let role_id = Identifier::from_bytes(&bytes).unwrap();
+        let mut position = role_id.get_size_bytes() as usize;
+        let permissions_length =
+            u32::from_le_bytes(bytes[position..position + 4].try_into().unwrap());
+        position += 4;
+        let permissions =
+            Permissions::from_bytes(&bytes[position..position + permissions_length as usize])
+                .unwrap();
+
+        assert!(!bytes.is_empty());
+        assert_eq!(role_id, command.role_id);
+        assert_eq!(permissions, command.permissions);
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes() {
+        let role_id = Identifier::numeric(1).unwrap();
+        let permissions = get_permissions();
+        let permissions_bytes = permissions.as_bytes();
+        let mut bytes = Vec::new();
+        bytes.extend(role_id.as_bytes());
+        bytes.put_u32_le(permissions_bytes.len() as u32);
+        bytes.extend(permissions_bytes);
+
+        let command = UpdateRole::from_bytes(&bytes);
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.role_id, role_id);
+        assert_eq!(command.permissions, permissions);
+    }
+
+    #[test]
+    fn should_be_read_from_string() {
+        let role_id = Identifier::numeric(1).unwrap();
+        let input = role_id.to_string();
+        let command = UpdateRole::from_str(&input);
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.role_id, role_id);
+    }
+
+    fn get_permissions() -> Permissions {
+        Permissions {
+            global: GlobalPermissions {
+                manage_streams: true,
+                read_streams: true,
+                send_messages: true,
+                ..Default::default()
+            },
+            streams: None,
+        }
+    }
+}
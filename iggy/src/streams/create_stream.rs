@@ -1,6 +1,7 @@
 use crate::bytes_serializable::BytesSerializable;
 use crate::command::CommandPayload;
 use crate::error::Error;
+use crate::models::stream_encryption::{StreamEncryption, STREAM_ENCRYPTION_SIZE};
 use crate::utils::text;
 use crate::validatable::Validatable;
 use bytes::BufMut;
@@ -14,6 +15,8 @@ const MAX_NAME_LENGTH: usize = 255;
 pub struct CreateStream {
     pub stream_id: u32,
     pub name: String,
+    #[serde(default)]
+    pub encryption: Option<StreamEncryption>,
 }
 
 impl CommandPayload for CreateStream {}
@@ -23,6 +26,7 @@ impl Default for CreateStream {
         CreateStream {
             stream_id: 1,
             name: "stream".to_string(),
+            encryption: None,
         }
     }
 }
@@ -49,13 +53,21 @@ impl FromStr for CreateStream {
     type Err = Error;
     fn from_str(input: &str) -> Result<Self, Self::Err> {
         let parts = input.split('|').collect::<Vec<&str>>();
-        if parts.len() != 2 {
+        if parts.len() != 2 && parts.len() != 5 {
             return Err(Error::InvalidCommand);
         }
 
         let stream_id = parts[0].parse::<u32>()?;
         let name = parts[1].to_string();
-        let command = CreateStream { stream_id, name };
+        let encryption = match parts.len() {
+            5 => Some(StreamEncryption::from_str(&parts[2..].join("|"))?),
+            _ => None,
+        };
+        let command = CreateStream {
+            stream_id,
+            name,
+            encryption,
+        };
         command.validate()?;
         Ok(command)
     }
@@ -63,11 +75,18 @@ impl FromStr for CreateStream {
 
 impl BytesSerializable for CreateStream {
     fn as_bytes(&self) -> Vec<u8> {
-        let mut bytes = Vec::with_capacity(5 + self.name.len());
+        let mut bytes = Vec::with_capacity(6 + self.name.len() + STREAM_ENCRYPTION_SIZE);
         bytes.put_u32_le(self.stream_id);
         #[allow(clippy::cast_possible_truncation)]
         bytes.put_u8(self.name.len() as u8);
         bytes.extend(self.name.as_bytes());
+        match &self.encryption {
+            Some(encryption) => {
+                bytes.put_u8(1);
+                bytes.extend(encryption.as_bytes());
+            }
+            None => bytes.put_u8(0),
+        }
         bytes
     }
 
@@ -83,7 +102,16 @@ impl BytesSerializable for CreateStream {
             return Err(Error::InvalidCommand);
         }
 
-        let command = CreateStream { stream_id, name };
+        let position = 5 + name_length as usize;
+        let encryption = match bytes.get(position) {
+            Some(1) => Some(StreamEncryption::from_bytes(&bytes[position + 1..])?),
+            _ => None,
+        };
+        let command = CreateStream {
+            stream_id,
+            name,
+            encryption,
+        };
         command.validate()?;
         Ok(command)
     }
@@ -91,7 +119,10 @@ impl BytesSerializable for CreateStream {
 
 impl Display for CreateStream {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}|{}", self.stream_id, self.name)
+        match &self.encryption {
+            Some(encryption) => write!(f, "{}|{}|{}", self.stream_id, self.name, encryption),
+            None => write!(f, "{}|{}", self.stream_id, self.name),
+        }
     }
 }
 
@@ -104,33 +135,51 @@ mod tests {
         let command = CreateStream {
             stream_id: 1,
             name: "test".to_string(),
+            encryption: Some(StreamEncryption {
+                enabled: true,
+                key_id: Some(2),
+                encrypt_headers: true,
+            }),
         };
 
         let bytes = command.as_bytes();
         let stream_id = u32::from_le_bytes(bytes[..4].try_into().unwrap());
         let name_length = bytes[4];
         let name = from_utf8(&bytes[5..5 + name_length as usize]).unwrap();
+        let position = 5 + name_length as usize;
+        let has_encryption = bytes[position];
+        let encryption = StreamEncryption::from_bytes(&bytes[position + 1..]).unwrap();
 
         assert!(!bytes.is_empty());
         assert_eq!(stream_id, command.stream_id);
         assert_eq!(name, command.name);
+        assert_eq!(has_encryption, 1);
+        assert_eq!(Some(encryption), command.encryption);
     }
 
     #[test]
     fn should_be_deserialized_from_bytes() {
         let stream_id = 1u32;
         let name = "test".to_string();
+        let encryption = StreamEncryption {
+            enabled: true,
+            key_id: None,
+            encrypt_headers: false,
+        };
         let mut bytes = Vec::new();
         bytes.put_u32_le(stream_id);
         #[allow(clippy::cast_possible_truncation)]
         bytes.put_u8(name.len() as u8);
         bytes.extend(name.as_bytes());
+        bytes.put_u8(1);
+        bytes.extend(encryption.as_bytes());
         let command = CreateStream::from_bytes(&bytes);
         assert!(command.is_ok());
 
         let command = command.unwrap();
         assert_eq!(command.stream_id, stream_id);
         assert_eq!(command.name, name);
+        assert_eq!(command.encryption, Some(encryption));
     }
 
     #[test]
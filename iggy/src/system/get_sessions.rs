@@ -0,0 +1,111 @@
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::CommandPayload;
+use crate::error::Error;
+use crate::validatable::Validatable;
+use bytes::BufMut;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::str::FromStr;
+
+// When the user ID is not set, the sessions of all the users are returned.
+#[derive(Debug, Default, Serialize, Deserialize, PartialEq)]
+pub struct GetSessions {
+    #[serde(default)]
+    pub user_id: Option<u32>,
+}
+
+impl CommandPayload for GetSessions {}
+
+impl Validatable<Error> for GetSessions {
+    fn validate(&self) -> Result<(), Error> {
+        if let Some(user_id) = self.user_id {
+            if user_id == 0 {
+                return Err(Error::InvalidCommand);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for GetSessions {
+    type Err = Error;
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let user_id = match input {
+            "" => None,
+            user_id => Some(user_id.parse::<u32>()?),
+        };
+        let command = GetSessions { user_id };
+        command.validate()?;
+        Ok(command)
+    }
+}
+
+impl BytesSerializable for GetSessions {
+    fn as_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(4);
+        bytes.put_u32_le(self.user_id.unwrap_or(0));
+        bytes
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<GetSessions, Error> {
+        if bytes.len() != 4 {
+            return Err(Error::InvalidCommand);
+        }
+
+        let user_id = match u32::from_le_bytes(bytes.try_into()?) {
+            0 => None,
+            user_id => Some(user_id),
+        };
+        let command = GetSessions { user_id };
+        command.validate()?;
+        Ok(command)
+    }
+}
+
+impl Display for GetSessions {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.user_id {
+            Some(user_id) => write!(f, "{user_id}"),
+            None => write!(f, ""),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_bytes() {
+        let command = GetSessions { user_id: Some(1) };
+
+        let bytes = command.as_bytes();
+        let user_id = u32::from_le_bytes(bytes[..4].try_into().unwrap());
+
+        assert!(!bytes.is_empty());
+        assert_eq!(Some(user_id), command.user_id);
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes() {
+        let user_id = 1u32;
+        let bytes = user_id.to_le_bytes();
+        let command = GetSessions::from_bytes(&bytes);
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.user_id, Some(user_id));
+    }
+
+    #[test]
+    fn should_be_read_from_string() {
+        let user_id = 1u32;
+        let input = user_id.to_string();
+        let command = GetSessions::from_str(&input);
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.user_id, Some(user_id));
+    }
+}
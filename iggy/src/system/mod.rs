@@ -1,5 +1,7 @@
 pub mod get_client;
 pub mod get_clients;
 pub mod get_me;
+pub mod get_sessions;
 pub mod get_stats;
 pub mod ping;
+pub mod revoke_session;
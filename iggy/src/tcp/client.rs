@@ -1,18 +1,22 @@
 use crate::binary::binary_client::BinaryClient;
+use crate::binary::subscriptions::{MessageSubscriptions, PUSH_STATUS};
 use crate::client::Client;
 use crate::error::Error;
 use crate::tcp::config::TcpClientConfig;
 use async_trait::async_trait;
 use bytes::BufMut;
+use flume::{Receiver, Sender};
 use std::fmt::Debug;
 use std::net::SocketAddr;
 use std::sync::Arc;
 use std::time::Duration;
-use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWriteExt, WriteHalf};
+use tokio::net::tcp::OwnedWriteHalf;
 use tokio::net::TcpStream;
 use tokio::sync::Mutex;
+use tokio::task::JoinHandle;
 use tokio::time::sleep;
-use tokio_native_tls::native_tls::TlsConnector;
+use tokio_native_tls::native_tls::{Certificate, Identity, TlsConnector};
 use tokio_native_tls::TlsStream;
 use tracing::log::trace;
 use tracing::{error, info};
@@ -25,27 +29,48 @@ const NAME: &str = "Iggy";
 #[derive(Debug)]
 pub struct TcpClient {
     pub(crate) server_address: SocketAddr,
-    pub(crate) stream: Option<Mutex<Box<dyn ConnectionStream>>>,
+    pub(crate) connection: Option<Mutex<TcpConnection>>,
     pub(crate) config: Arc<TcpClientConfig>,
+    pub(crate) subscriptions: MessageSubscriptions,
 }
 
 unsafe impl Send for TcpClient {}
 unsafe impl Sync for TcpClient {}
 
+// The responses and the messages pushed for the subscriptions share the same stream,
+// thus it's consumed by the background reader which routes the received frames.
+#[derive(Debug)]
+pub(crate) struct TcpConnection {
+    stream: Box<dyn ConnectionStream>,
+    responses: Receiver<Result<Response, Error>>,
+    reader: JoinHandle<()>,
+}
+
+impl Drop for TcpConnection {
+    fn drop(&mut self) {
+        self.reader.abort();
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct Response {
+    status: u32,
+    payload: Vec<u8>,
+}
+
 #[async_trait]
 pub(crate) trait ConnectionStream: Debug + Sync + Send {
-    async fn read(&mut self, buf: &mut [u8]) -> Result<usize, Error>;
     async fn write(&mut self, buf: &[u8]) -> Result<(), Error>;
 }
 
 #[derive(Debug)]
 struct TcpConnectionStream {
-    stream: TcpStream,
+    stream: OwnedWriteHalf,
 }
 
 #[derive(Debug)]
 struct TcpTlsConnectionStream {
-    stream: TlsStream<TcpStream>,
+    stream: WriteHalf<TlsStream<TcpStream>>,
 }
 
 unsafe impl Send for TcpConnectionStream {}
@@ -56,15 +81,6 @@ unsafe impl Sync for TcpTlsConnectionStream {}
 
 #[async_trait]
 impl ConnectionStream for TcpConnectionStream {
-    async fn read(&mut self, buf: &mut [u8]) -> Result<usize, Error> {
-        let result = self.stream.read_exact(buf).await;
-        if let Err(error) = result {
-            return Err(Error::from(error));
-        }
-
-        Ok(result.unwrap())
-    }
-
     async fn write(&mut self, buf: &[u8]) -> Result<(), Error> {
         let result = self.stream.write_all(buf).await;
         if let Err(error) = result {
@@ -77,15 +93,6 @@ impl ConnectionStream for TcpConnectionStream {
 
 #[async_trait]
 impl ConnectionStream for TcpTlsConnectionStream {
-    async fn read(&mut self, buf: &mut [u8]) -> Result<usize, Error> {
-        let result = self.stream.read_exact(buf).await;
-        if let Err(error) = result {
-            return Err(Error::from(error));
-        }
-
-        Ok(result.unwrap())
-    }
-
     async fn write(&mut self, buf: &[u8]) -> Result<(), Error> {
         let result = self.stream.write_all(buf).await;
         if let Err(error) = result {
@@ -107,7 +114,9 @@ impl Client for TcpClient {
     async fn connect(&mut self) -> Result<(), Error> {
         let tls_enabled = self.config.tls_enabled;
         let mut retry_count = 0;
+        let (responses_sender, responses) = flume::unbounded();
         let connection_stream: Box<dyn ConnectionStream>;
+        let reader;
         let remote_address;
         loop {
             info!(
@@ -141,24 +150,48 @@ impl Client for TcpClient {
             remote_address = stream.peer_addr()?;
 
             if !tls_enabled {
-                connection_stream = Box::new(TcpConnectionStream { stream });
+                let (read_half, write_half) = stream.into_split();
+                reader = start_reader(read_half, responses_sender, self.subscriptions.clone());
+                connection_stream = Box::new(TcpConnectionStream { stream: write_half });
                 break;
             }
 
-            let connector =
-                tokio_native_tls::TlsConnector::from(TlsConnector::builder().build().unwrap());
+            let mut connector = TlsConnector::builder();
+            if let (Some(certificate_file), Some(key_file)) =
+                (&self.config.tls_certificate_file, &self.config.tls_key_file)
+            {
+                connector.identity(load_identity(certificate_file, key_file)?);
+            }
+            if let Some(ca_file) = &self.config.tls_ca_file {
+                connector.add_root_certificate(load_certificate(ca_file)?);
+            }
+            let connector = tokio_native_tls::TlsConnector::from(connector.build().unwrap());
             let stream = tokio_native_tls::TlsConnector::connect(
                 &connector,
                 &self.config.tls_domain,
                 stream,
             )
-            .await
-            .unwrap();
-            connection_stream = Box::new(TcpTlsConnectionStream { stream });
+            .await;
+            if let Err(error) = stream {
+                error!(
+                    "Failed to complete TLS handshake with server: {}, error: {}",
+                    self.config.server_address, error
+                );
+                return Err(Error::NotConnected);
+            }
+
+            let stream = stream.unwrap();
+            let (read_half, write_half) = tokio::io::split(stream);
+            reader = start_reader(read_half, responses_sender, self.subscriptions.clone());
+            connection_stream = Box::new(TcpTlsConnectionStream { stream: write_half });
             break;
         }
 
-        self.stream = Some(Mutex::new(connection_stream));
+        self.connection = Some(Mutex::new(TcpConnection {
+            stream: connection_stream,
+            responses,
+            reader,
+        }));
 
         info!(
             "{} client has connected to server: {}",
@@ -170,7 +203,8 @@ impl Client for TcpClient {
 
     async fn disconnect(&mut self) -> Result<(), Error> {
         info!("{} client is disconnecting from server...", NAME);
-        self.stream = None;
+        self.connection = None;
+        self.subscriptions.clear();
         info!("{} client has disconnected from server.", NAME);
         Ok(())
     }
@@ -179,7 +213,7 @@ impl Client for TcpClient {
 #[async_trait]
 impl BinaryClient for TcpClient {
     async fn send_with_response(&self, command: u32, payload: &[u8]) -> Result<Vec<u8>, Error> {
-        if let Some(stream) = &self.stream {
+        if let Some(connection) = &self.connection {
             let payload_length = payload.len() + 4;
             let mut buffer = Vec::with_capacity(REQUEST_INITIAL_BYTES_LENGTH + payload_length);
             #[allow(clippy::cast_possible_truncation)]
@@ -187,26 +221,27 @@ impl BinaryClient for TcpClient {
             buffer.put_u32_le(command);
             buffer.extend(payload);
 
-            let mut stream = stream.lock().await;
+            let mut connection = connection.lock().await;
             trace!("Sending a TCP request...");
-            stream.write(&buffer).await?;
+            connection.stream.write(&buffer).await?;
             trace!("Sent a TCP request, waiting for a response...");
 
-            let mut response_buffer = [0u8; RESPONSE_INITIAL_BYTES_LENGTH];
-            let read_bytes = stream.read(&mut response_buffer).await?;
-            if read_bytes != RESPONSE_INITIAL_BYTES_LENGTH {
-                error!("Received an invalid or empty response.");
-                return Err(Error::EmptyResponse);
+            let response = connection.responses.recv_async().await;
+            if response.is_err() {
+                error!("Connection has been closed, cannot receive a response.");
+                return Err(Error::NotConnected);
             }
 
-            let status = u32::from_le_bytes(response_buffer[..4].try_into().unwrap());
-            let length = u32::from_le_bytes(response_buffer[4..].try_into().unwrap());
-            return self.handle_response(status, length, stream.as_mut()).await;
+            return self.handle_response(response.unwrap()?);
         }
 
         error!("Cannot send data. Client is not connected.");
         Err(Error::NotConnected)
     }
+
+    fn get_subscriptions(&self) -> &MessageSubscriptions {
+        &self.subscriptions
+    }
 }
 
 impl TcpClient {
@@ -232,16 +267,13 @@ impl TcpClient {
         Ok(Self {
             config,
             server_address,
-            stream: None,
+            connection: None,
+            subscriptions: MessageSubscriptions::default(),
         })
     }
 
-    async fn handle_response(
-        &self,
-        status: u32,
-        length: u32,
-        stream: &mut dyn ConnectionStream,
-    ) -> Result<Vec<u8>, Error> {
+    fn handle_response(&self, response: Response) -> Result<Vec<u8>, Error> {
+        let status = response.status;
         if status != 0 {
             error!(
                 "Received an invalid response with status: {} ({}).",
@@ -251,13 +283,83 @@ impl TcpClient {
             return Err(Error::InvalidResponse(status));
         }
 
+        let length = response.payload.len();
         trace!("Status: OK. Response length: {}", length);
         if length <= 1 {
             return Ok(EMPTY_RESPONSE);
         }
 
-        let mut response_buffer = vec![0u8; length as usize];
-        stream.read(&mut response_buffer).await?;
-        Ok(response_buffer)
+        Ok(response.payload)
+    }
+}
+
+fn start_reader<R>(
+    mut reader: R,
+    responses: Sender<Result<Response, Error>>,
+    subscriptions: MessageSubscriptions,
+) -> JoinHandle<()>
+where
+    R: AsyncRead + Unpin + Send + 'static,
+{
+    tokio::spawn(async move {
+        loop {
+            let response = read_response(&mut reader).await;
+            if let Err(error) = response {
+                trace!("Stopped reading from the connection: {}", error);
+                responses.send(Err(error)).ok();
+                return;
+            }
+
+            let response = response.unwrap();
+            if response.status == PUSH_STATUS {
+                if let Err(error) = subscriptions.dispatch(&response.payload) {
+                    error!("Received invalid pushed messages: {}", error);
+                }
+                continue;
+            }
+
+            if responses.send(Ok(response)).is_err() {
+                return;
+            }
+        }
+    })
+}
+
+async fn read_response<R>(reader: &mut R) -> Result<Response, Error>
+where
+    R: AsyncRead + Unpin,
+{
+    let mut response_buffer = [0u8; RESPONSE_INITIAL_BYTES_LENGTH];
+    reader.read_exact(&mut response_buffer).await?;
+    let status = u32::from_le_bytes(response_buffer[..4].try_into()?);
+    let length = u32::from_le_bytes(response_buffer[4..].try_into()?);
+    let mut payload = vec![0u8; length as usize];
+    if length > 0 {
+        reader.read_exact(&mut payload).await?;
     }
+
+    Ok(Response { status, payload })
+}
+
+fn load_identity(certificate_file: &str, key_file: &str) -> Result<Identity, Error> {
+    let certificate = std::fs::read(certificate_file)?;
+    let key = std::fs::read(key_file)?;
+    let identity = Identity::from_pkcs8(&certificate, &key);
+    if identity.is_err() {
+        error!("Unable to create TLS identity from: {certificate_file} and {key_file}");
+        return Err(Error::InvalidConfiguration);
+    }
+
+    Ok(identity.unwrap())
+}
+
+fn load_certificate(certificate_file: &str) -> Result<Certificate, Error> {
+    let certificate = std::fs::read(certificate_file)?;
+    let certificate = Certificate::from_pem(&certificate);
+    if certificate.is_err() {
+        error!("Unable to load TLS certificate from: {certificate_file}");
+        return Err(Error::InvalidConfiguration);
+    }
+
+    Ok(certificate.unwrap())
 }
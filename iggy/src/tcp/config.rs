@@ -5,6 +5,10 @@ pub struct TcpClientConfig {
     pub reconnection_interval: u64,
     pub tls_enabled: bool,
     pub tls_domain: String,
+    pub tls_certificate_file: Option<String>,
+    pub tls_key_file: Option<String>,
+    pub tls_ca_file: Option<String>,
+    pub plaintext_login_fallback: bool,
 }
 
 impl Default for TcpClientConfig {
@@ -15,6 +19,10 @@ impl Default for TcpClientConfig {
             reconnection_interval: 1000,
             tls_enabled: false,
             tls_domain: "localhost".to_string(),
+            tls_certificate_file: None,
+            tls_key_file: None,
+            tls_ca_file: None,
+            plaintext_login_fallback: false,
         }
     }
 }
@@ -1,9 +1,13 @@
 use crate::binary;
 use crate::client::ConsumerOffsetClient;
+use crate::consumer_offsets::get_consumer_lag::GetConsumerLag;
 use crate::consumer_offsets::get_consumer_offset::GetConsumerOffset;
+use crate::consumer_offsets::reset_consumer_offsets::ResetConsumerOffsets;
 use crate::consumer_offsets::store_consumer_offset::StoreConsumerOffset;
 use crate::error::Error;
+use crate::models::consumer_lag::ConsumerLag;
 use crate::models::consumer_offset_info::ConsumerOffsetInfo;
+use crate::models::consumer_offset_reset::ConsumerOffsetReset;
 use crate::tcp::client::TcpClient;
 use async_trait::async_trait;
 
@@ -19,4 +23,15 @@ impl ConsumerOffsetClient for TcpClient {
     ) -> Result<ConsumerOffsetInfo, Error> {
         binary::consumer_offsets::get_consumer_offset(self, command).await
     }
+
+    async fn get_consumer_lag(&self, command: &GetConsumerLag) -> Result<Vec<ConsumerLag>, Error> {
+        binary::consumer_offsets::get_consumer_lag(self, command).await
+    }
+
+    async fn reset_consumer_offsets(
+        &self,
+        command: &ResetConsumerOffsets,
+    ) -> Result<Vec<ConsumerOffsetReset>, Error> {
+        binary::consumer_offsets::reset_consumer_offsets(self, command).await
+    }
 }
@@ -6,8 +6,10 @@ use crate::models::stats::Stats;
 use crate::system::get_client::GetClient;
 use crate::system::get_clients::GetClients;
 use crate::system::get_me::GetMe;
+use crate::system::get_sessions::GetSessions;
 use crate::system::get_stats::GetStats;
 use crate::system::ping::Ping;
+use crate::system::revoke_session::RevokeSession;
 use crate::tcp::client::TcpClient;
 use async_trait::async_trait;
 
@@ -29,6 +31,14 @@ impl SystemClient for TcpClient {
         binary::system::get_clients(self, command).await
     }
 
+    async fn get_sessions(&self, command: &GetSessions) -> Result<Vec<ClientInfo>, Error> {
+        binary::system::get_sessions(self, command).await
+    }
+
+    async fn revoke_session(&self, command: &RevokeSession) -> Result<(), Error> {
+        binary::system::revoke_session(self, command).await
+    }
+
     async fn ping(&self, command: &Ping) -> Result<(), Error> {
         binary::system::ping(self, command).await
     }
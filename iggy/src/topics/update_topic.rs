@@ -2,6 +2,8 @@ use crate::bytes_serializable::BytesSerializable;
 use crate::command::CommandPayload;
 use crate::error::Error;
 use crate::identifier::Identifier;
+use crate::models::cleanup_policy::CleanupPolicy;
+use crate::models::header::HeaderKey;
 use crate::utils::text;
 use crate::validatable::Validatable;
 use bytes::BufMut;
@@ -19,6 +21,16 @@ pub struct UpdateTopic {
     pub topic_id: Identifier,
     pub message_expiry: Option<u32>,
     pub name: String,
+    #[serde(default)]
+    pub cleanup_policy: CleanupPolicy,
+    #[serde(default)]
+    pub compaction_key: Option<String>,
+    #[serde(default)]
+    pub max_topic_size_bytes: Option<u64>,
+    #[serde(default)]
+    pub max_messages: Option<u64>,
+    #[serde(default)]
+    pub dead_letter_topic_id: Option<u32>,
 }
 
 impl CommandPayload for UpdateTopic {}
@@ -30,6 +42,11 @@ impl Default for UpdateTopic {
             topic_id: Identifier::default(),
             message_expiry: None,
             name: "topic".to_string(),
+            cleanup_policy: CleanupPolicy::default(),
+            compaction_key: None,
+            max_topic_size_bytes: None,
+            max_messages: None,
+            dead_letter_topic_id: None,
         }
     }
 }
@@ -44,6 +61,24 @@ impl Validatable<Error> for UpdateTopic {
             return Err(Error::InvalidTopicName);
         }
 
+        if let Some(compaction_key) = &self.compaction_key {
+            if self.cleanup_policy != CleanupPolicy::Compact {
+                return Err(Error::InvalidCompactionKey);
+            }
+
+            if HeaderKey::new(compaction_key).is_err() {
+                return Err(Error::InvalidCompactionKey);
+            }
+        }
+
+        if self.max_topic_size_bytes == Some(0) || self.max_messages == Some(0) {
+            return Err(Error::InvalidTopicRetentionLimits);
+        }
+
+        if self.dead_letter_topic_id == Some(0) {
+            return Err(Error::InvalidDeadLetterTopic(0));
+        }
+
         Ok(())
     }
 }
@@ -52,7 +87,7 @@ impl FromStr for UpdateTopic {
     type Err = Error;
     fn from_str(input: &str) -> Result<Self, Self::Err> {
         let parts = input.split('|').collect::<Vec<&str>>();
-        if parts.len() != 4 {
+        if parts.len() < 4 || parts.len() > 9 {
             return Err(Error::InvalidCommand);
         }
 
@@ -67,11 +102,45 @@ impl FromStr for UpdateTopic {
             Err(_) => None,
         };
         let name = parts[3].to_string();
+        let cleanup_policy = match parts.get(4) {
+            Some(cleanup_policy) => CleanupPolicy::from_str(cleanup_policy)?,
+            None => CleanupPolicy::default(),
+        };
+        let compaction_key = parts
+            .get(5)
+            .filter(|key| !key.is_empty())
+            .map(|key| key.to_string());
+        let max_topic_size_bytes = match parts.get(6) {
+            Some(max_topic_size_bytes) => match max_topic_size_bytes.parse::<u64>()? {
+                0 => None,
+                max_topic_size_bytes => Some(max_topic_size_bytes),
+            },
+            None => None,
+        };
+        let max_messages = match parts.get(7) {
+            Some(max_messages) => match max_messages.parse::<u64>()? {
+                0 => None,
+                max_messages => Some(max_messages),
+            },
+            None => None,
+        };
+        let dead_letter_topic_id = match parts.get(8) {
+            Some(dead_letter_topic_id) => match dead_letter_topic_id.parse::<u32>()? {
+                0 => None,
+                dead_letter_topic_id => Some(dead_letter_topic_id),
+            },
+            None => None,
+        };
         let command = UpdateTopic {
             stream_id,
             topic_id,
             message_expiry,
             name,
+            cleanup_policy,
+            compaction_key,
+            max_topic_size_bytes,
+            max_messages,
+            dead_letter_topic_id,
         };
         command.validate()?;
         Ok(command)
@@ -82,8 +151,13 @@ impl BytesSerializable for UpdateTopic {
     fn as_bytes(&self) -> Vec<u8> {
         let stream_id_bytes = self.stream_id.as_bytes();
         let topic_id_bytes = self.topic_id.as_bytes();
-        let mut bytes =
-            Vec::with_capacity(5 + stream_id_bytes.len() + topic_id_bytes.len() + self.name.len());
+        let compaction_key = self.compaction_key.as_deref().unwrap_or_default();
+        let mut bytes = Vec::with_capacity(
+            27 + stream_id_bytes.len()
+                + topic_id_bytes.len()
+                + self.name.len()
+                + compaction_key.len(),
+        );
         bytes.extend(stream_id_bytes);
         bytes.extend(topic_id_bytes);
         match self.message_expiry {
@@ -93,11 +167,18 @@ impl BytesSerializable for UpdateTopic {
         #[allow(clippy::cast_possible_truncation)]
         bytes.put_u8(self.name.len() as u8);
         bytes.extend(self.name.as_bytes());
+        bytes.put_u8(self.cleanup_policy.as_code());
+        #[allow(clippy::cast_possible_truncation)]
+        bytes.put_u8(compaction_key.len() as u8);
+        bytes.extend(compaction_key.as_bytes());
+        bytes.put_u64_le(self.max_topic_size_bytes.unwrap_or(0));
+        bytes.put_u64_le(self.max_messages.unwrap_or(0));
+        bytes.put_u32_le(self.dead_letter_topic_id.unwrap_or(0));
         bytes
     }
 
     fn from_bytes(bytes: &[u8]) -> Result<UpdateTopic, Error> {
-        if bytes.len() < 12 {
+        if bytes.len() < 34 {
             return Err(Error::InvalidCommand);
         }
 
@@ -117,11 +198,46 @@ impl BytesSerializable for UpdateTopic {
         if name.len() != name_length as usize {
             return Err(Error::InvalidCommand);
         }
+        position += 5 + name_length as usize;
+        let cleanup_policy = CleanupPolicy::from_code(bytes[position])?;
+        let compaction_key_length = bytes[position + 1];
+        let compaction_key = match compaction_key_length {
+            0 => None,
+            _ => Some(
+                from_utf8(&bytes[position + 2..position + 2 + compaction_key_length as usize])?
+                    .to_string(),
+            ),
+        };
+        position += 2 + compaction_key_length as usize;
+        if bytes.len() < position + 20 {
+            return Err(Error::InvalidCommand);
+        }
+        let max_topic_size_bytes = u64::from_le_bytes(bytes[position..position + 8].try_into()?);
+        let max_topic_size_bytes = match max_topic_size_bytes {
+            0 => None,
+            _ => Some(max_topic_size_bytes),
+        };
+        let max_messages = u64::from_le_bytes(bytes[position + 8..position + 16].try_into()?);
+        let max_messages = match max_messages {
+            0 => None,
+            _ => Some(max_messages),
+        };
+        let dead_letter_topic_id =
+            u32::from_le_bytes(bytes[position + 16..position + 20].try_into()?);
+        let dead_letter_topic_id = match dead_letter_topic_id {
+            0 => None,
+            _ => Some(dead_letter_topic_id),
+        };
         let command = UpdateTopic {
             stream_id,
             topic_id,
             message_expiry,
             name,
+            cleanup_policy,
+            compaction_key,
+            max_topic_size_bytes,
+            max_messages,
+            dead_letter_topic_id,
         };
         command.validate()?;
         Ok(command)
@@ -132,11 +248,20 @@ impl Display for UpdateTopic {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "{}|{}|{}|{}",
+            "{}|{}|{}|{}|{}",
             self.stream_id,
             self.topic_id,
             self.message_expiry.unwrap_or(0),
-            self.name
+            self.name,
+            self.cleanup_policy
+        )?;
+        write!(
+            f,
+            "|{}|{}|{}|{}",
+            self.compaction_key.as_deref().unwrap_or_default(),
+            self.max_topic_size_bytes.unwrap_or(0),
+            self.max_messages.unwrap_or(0),
+            self.dead_letter_topic_id.unwrap_or(0)
         )
     }
 }
@@ -153,6 +278,11 @@ mod tests {
             topic_id: Identifier::numeric(2).unwrap(),
             message_expiry: Some(10),
             name: "test".to_string(),
+            cleanup_policy: CleanupPolicy::Compact,
+            compaction_key: Some("key".to_string()),
+            max_topic_size_bytes: Some(1000),
+            max_messages: Some(100),
+            dead_letter_topic_id: Some(5),
         };
 
         let bytes = command.as_bytes();
@@ -170,6 +300,20 @@ mod tests {
         let name = from_utf8(&bytes[position + 5..position + 5 + name_length as usize])
             .unwrap()
             .to_string();
+        position += 5 + name_length as usize;
+        let cleanup_policy = CleanupPolicy::from_code(bytes[position]).unwrap();
+        let compaction_key_length = bytes[position + 1];
+        let compaction_key =
+            from_utf8(&bytes[position + 2..position + 2 + compaction_key_length as usize])
+                .unwrap()
+                .to_string();
+        position += 2 + compaction_key_length as usize;
+        let max_topic_size_bytes =
+            u64::from_le_bytes(bytes[position..position + 8].try_into().unwrap());
+        let max_messages =
+            u64::from_le_bytes(bytes[position + 8..position + 16].try_into().unwrap());
+        let dead_letter_topic_id =
+            u32::from_le_bytes(bytes[position + 16..position + 20].try_into().unwrap());
 
         assert!(!bytes.is_empty());
         assert_eq!(stream_id, command.stream_id);
@@ -177,6 +321,11 @@ mod tests {
         assert_eq!(message_expiry, command.message_expiry);
         assert_eq!(name.len() as u8, command.name.len() as u8);
         assert_eq!(name, command.name);
+        assert_eq!(cleanup_policy, command.cleanup_policy);
+        assert_eq!(Some(compaction_key), command.compaction_key);
+        assert_eq!(Some(max_topic_size_bytes), command.max_topic_size_bytes);
+        assert_eq!(Some(max_messages), command.max_messages);
+        assert_eq!(Some(dead_letter_topic_id), command.dead_letter_topic_id);
     }
 
     #[test]
@@ -185,6 +334,11 @@ mod tests {
         let topic_id = Identifier::numeric(2).unwrap();
         let name = "test".to_string();
         let message_expiry = 10;
+        let cleanup_policy = CleanupPolicy::Compact;
+        let compaction_key = "key".to_string();
+        let max_topic_size_bytes = 1000u64;
+        let max_messages = 100u64;
+        let dead_letter_topic_id = 5u32;
 
         let stream_id_bytes = stream_id.as_bytes();
         let topic_id_bytes = topic_id.as_bytes();
@@ -196,6 +350,13 @@ mod tests {
         #[allow(clippy::cast_possible_truncation)]
         bytes.put_u8(name.len() as u8);
         bytes.extend(name.as_bytes());
+        bytes.put_u8(cleanup_policy.as_code());
+        #[allow(clippy::cast_possible_truncation)]
+        bytes.put_u8(compaction_key.len() as u8);
+        bytes.extend(compaction_key.as_bytes());
+        bytes.put_u64_le(max_topic_size_bytes);
+        bytes.put_u64_le(max_messages);
+        bytes.put_u32_le(dead_letter_topic_id);
 
         let command = UpdateTopic::from_bytes(&bytes);
         assert!(command.is_ok());
@@ -205,6 +366,11 @@ mod tests {
         assert_eq!(command.topic_id, topic_id);
         assert_eq!(command.message_expiry, Some(message_expiry));
         assert_eq!(command.name, name);
+        assert_eq!(command.cleanup_policy, cleanup_policy);
+        assert_eq!(command.compaction_key, Some(compaction_key));
+        assert_eq!(command.max_topic_size_bytes, Some(max_topic_size_bytes));
+        assert_eq!(command.max_messages, Some(max_messages));
+        assert_eq!(command.dead_letter_topic_id, Some(dead_letter_topic_id));
     }
 
     #[test]
@@ -222,5 +388,31 @@ mod tests {
         assert_eq!(command.topic_id, topic_id);
         assert_eq!(command.message_expiry, Some(message_expiry));
         assert_eq!(command.name, name);
+        assert_eq!(command.cleanup_policy, CleanupPolicy::Delete);
+        assert_eq!(command.compaction_key, None);
+        assert_eq!(command.max_topic_size_bytes, None);
+        assert_eq!(command.max_messages, None);
+        assert_eq!(command.dead_letter_topic_id, None);
+    }
+
+    #[test]
+    fn should_be_read_from_string_with_retention_limits() {
+        let stream_id = Identifier::numeric(1).unwrap();
+        let topic_id = Identifier::numeric(2).unwrap();
+        let message_expiry = 10;
+        let name = "test".to_string();
+        let max_topic_size_bytes = 1000u64;
+        let max_messages = 100u64;
+        let input = format!(
+            "{stream_id}|{topic_id}|{message_expiry}|{name}|delete||{max_topic_size_bytes}|{max_messages}"
+        );
+        let command = UpdateTopic::from_str(&input);
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.cleanup_policy, CleanupPolicy::Delete);
+        assert_eq!(command.compaction_key, None);
+        assert_eq!(command.max_topic_size_bytes, Some(max_topic_size_bytes));
+        assert_eq!(command.max_messages, Some(max_messages));
     }
 }
@@ -0,0 +1,3 @@
+pub mod abort_transaction;
+pub mod begin_transaction;
+pub mod commit_transaction;
@@ -5,10 +5,16 @@ pub mod get_user;
 pub mod get_users;
 pub mod login_user;
 pub mod logout_user;
+pub mod refresh_token;
+pub mod scram_login_finish;
+pub mod scram_login_start;
+pub mod unlock_user;
 pub mod update_permissions;
+pub mod update_quotas;
 pub mod update_user;
 
 const MAX_USERNAME_LENGTH: usize = 50;
 const MIN_USERNAME_LENGTH: usize = 3;
 const MAX_PASSWORD_LENGTH: usize = 100;
 const MIN_PASSWORD_LENGTH: usize = 3;
+const MAX_NONCE_LENGTH: usize = 255;
@@ -0,0 +1,19 @@
+use crate::error::Error;
+use crate::validatable::Validatable;
+use serde::{Deserialize, Serialize};
+
+// Used only by the HTTP API to exchange the refresh token for a new pair of tokens.
+#[derive(Debug, Default, Serialize, Deserialize, PartialEq)]
+pub struct RefreshToken {
+    pub refresh_token: String,
+}
+
+impl Validatable<Error> for RefreshToken {
+    fn validate(&self) -> Result<(), Error> {
+        if self.refresh_token.is_empty() {
+            return Err(Error::InvalidRefreshToken);
+        }
+
+        Ok(())
+    }
+}
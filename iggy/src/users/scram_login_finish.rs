@@ -0,0 +1,162 @@
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::CommandPayload;
+use crate::error::Error;
+use crate::users::MAX_NONCE_LENGTH;
+use crate::validatable::Validatable;
+use bytes::BufMut;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::str::{from_utf8, FromStr};
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct ScramLoginFinish {
+    pub nonce: String,
+    pub client_proof: String,
+}
+
+impl CommandPayload for ScramLoginFinish {}
+
+impl Default for ScramLoginFinish {
+    fn default() -> Self {
+        ScramLoginFinish {
+            nonce: "nonce".to_string(),
+            client_proof: "proof".to_string(),
+        }
+    }
+}
+
+impl Validatable<Error> for ScramLoginFinish {
+    fn validate(&self) -> Result<(), Error> {
+        if self.nonce.is_empty() || self.nonce.len() > MAX_NONCE_LENGTH {
+            return Err(Error::InvalidScramHandshake);
+        }
+
+        if self.client_proof.is_empty() || self.client_proof.len() > MAX_NONCE_LENGTH {
+            return Err(Error::InvalidScramHandshake);
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for ScramLoginFinish {
+    type Err = Error;
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let parts = input.split('|').collect::<Vec<&str>>();
+        if parts.len() != 2 {
+            return Err(Error::InvalidCommand);
+        }
+
+        let nonce = parts[0].to_string();
+        let client_proof = parts[1].to_string();
+        let command = ScramLoginFinish {
+            nonce,
+            client_proof,
+        };
+        command.validate()?;
+        Ok(command)
+    }
+}
+
+impl BytesSerializable for ScramLoginFinish {
+    fn as_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(2 + self.nonce.len() + self.client_proof.len());
+        #[allow(clippy::cast_possible_truncation)]
+        bytes.put_u8(self.nonce.len() as u8);
+        bytes.extend(self.nonce.as_bytes());
+        #[allow(clippy::cast_possible_truncation)]
+        bytes.put_u8(self.client_proof.len() as u8);
+        bytes.extend(self.client_proof.as_bytes());
+        bytes
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<ScramLoginFinish, Error> {
+        if bytes.len() < 4 {
+            return Err(Error::InvalidCommand);
+        }
+
+        let nonce_length = bytes[0] as usize;
+        if bytes.len() < 2 + nonce_length {
+            return Err(Error::InvalidCommand);
+        }
+
+        let nonce = from_utf8(&bytes[1..=nonce_length])?.to_string();
+        let client_proof_length = bytes[1 + nonce_length] as usize;
+        if bytes.len() != 2 + nonce_length + client_proof_length {
+            return Err(Error::InvalidCommand);
+        }
+
+        let client_proof = from_utf8(&bytes[2 + nonce_length..])?.to_string();
+        let command = ScramLoginFinish {
+            nonce,
+            client_proof,
+        };
+        command.validate()?;
+        Ok(command)
+    }
+}
+
+impl Display for ScramLoginFinish {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}|{}", self.nonce, self.client_proof)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_bytes() {
+        let command = ScramLoginFinish {
+            nonce: "nonce".to_string(),
+            client_proof: "proof".to_string(),
+        };
+
+        let bytes = command.as_bytes();
+        let nonce_length = bytes[0];
+        let nonce = from_utf8(&bytes[1..=(nonce_length as usize)]).unwrap();
+        let client_proof_length = bytes[1 + nonce_length as usize];
+        let client_proof = from_utf8(
+            &bytes[2 + nonce_length as usize
+                ..2 + nonce_length as usize + client_proof_length as usize],
+        )
+        .unwrap();
+
+        assert!(!bytes.is_empty());
+        assert_eq!(nonce, command.nonce);
+        assert_eq!(client_proof, command.client_proof);
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes() {
+        let nonce = "nonce";
+        let client_proof = "proof";
+        let mut bytes = Vec::new();
+        #[allow(clippy::cast_possible_truncation)]
+        bytes.put_u8(nonce.len() as u8);
+        bytes.extend(nonce.as_bytes());
+        #[allow(clippy::cast_possible_truncation)]
+        bytes.put_u8(client_proof.len() as u8);
+        bytes.extend(client_proof.as_bytes());
+        let command = ScramLoginFinish::from_bytes(&bytes);
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.nonce, nonce);
+        assert_eq!(command.client_proof, client_proof);
+    }
+
+    #[test]
+    fn should_be_read_from_string() {
+        let nonce = "nonce";
+        let client_proof = "proof";
+        let input = format!("{}|{}", nonce, client_proof);
+        let command = ScramLoginFinish::from_str(&input);
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.nonce, nonce);
+        assert_eq!(command.client_proof, client_proof);
+    }
+}
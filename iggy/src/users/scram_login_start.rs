@@ -0,0 +1,170 @@
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::CommandPayload;
+use crate::error::Error;
+use crate::users::{MAX_NONCE_LENGTH, MAX_USERNAME_LENGTH, MIN_USERNAME_LENGTH};
+use crate::utils::text;
+use crate::validatable::Validatable;
+use bytes::BufMut;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::str::{from_utf8, FromStr};
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct ScramLoginStart {
+    pub username: String,
+    pub client_nonce: String,
+}
+
+impl CommandPayload for ScramLoginStart {}
+
+impl Default for ScramLoginStart {
+    fn default() -> Self {
+        ScramLoginStart {
+            username: "user".to_string(),
+            client_nonce: "nonce".to_string(),
+        }
+    }
+}
+
+impl Validatable<Error> for ScramLoginStart {
+    fn validate(&self) -> Result<(), Error> {
+        if self.username.is_empty()
+            || self.username.len() > MAX_USERNAME_LENGTH
+            || self.username.len() < MIN_USERNAME_LENGTH
+        {
+            return Err(Error::InvalidUsername);
+        }
+
+        if !text::is_resource_name_valid(&self.username) {
+            return Err(Error::InvalidUsername);
+        }
+
+        if self.client_nonce.is_empty() || self.client_nonce.len() > MAX_NONCE_LENGTH {
+            return Err(Error::InvalidScramHandshake);
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for ScramLoginStart {
+    type Err = Error;
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let parts = input.split('|').collect::<Vec<&str>>();
+        if parts.len() != 2 {
+            return Err(Error::InvalidCommand);
+        }
+
+        let username = parts[0].to_string();
+        let client_nonce = parts[1].to_string();
+        let command = ScramLoginStart {
+            username,
+            client_nonce,
+        };
+        command.validate()?;
+        Ok(command)
+    }
+}
+
+impl BytesSerializable for ScramLoginStart {
+    fn as_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(2 + self.username.len() + self.client_nonce.len());
+        #[allow(clippy::cast_possible_truncation)]
+        bytes.put_u8(self.username.len() as u8);
+        bytes.extend(self.username.as_bytes());
+        #[allow(clippy::cast_possible_truncation)]
+        bytes.put_u8(self.client_nonce.len() as u8);
+        bytes.extend(self.client_nonce.as_bytes());
+        bytes
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<ScramLoginStart, Error> {
+        if bytes.len() < 5 {
+            return Err(Error::InvalidCommand);
+        }
+
+        let username_length = bytes[0] as usize;
+        if bytes.len() < 2 + username_length {
+            return Err(Error::InvalidCommand);
+        }
+
+        let username = from_utf8(&bytes[1..=username_length])?.to_string();
+        let client_nonce_length = bytes[1 + username_length] as usize;
+        if bytes.len() != 2 + username_length + client_nonce_length {
+            return Err(Error::InvalidCommand);
+        }
+
+        let client_nonce = from_utf8(&bytes[2 + username_length..])?.to_string();
+        let command = ScramLoginStart {
+            username,
+            client_nonce,
+        };
+        command.validate()?;
+        Ok(command)
+    }
+}
+
+impl Display for ScramLoginStart {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}|{}", self.username, self.client_nonce)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_bytes() {
+        let command = ScramLoginStart {
+            username: "user".to_string(),
+            client_nonce: "nonce".to_string(),
+        };
+
+        let bytes = command.as_bytes();
+        let username_length = bytes[0];
+        let username = from_utf8(&bytes[1..=(username_length as usize)]).unwrap();
+        let client_nonce_length = bytes[1 + username_length as usize];
+        let client_nonce = from_utf8(
+            &bytes[2 + username_length as usize
+                ..2 + username_length as usize + client_nonce_length as usize],
+        )
+        .unwrap();
+
+        assert!(!bytes.is_empty());
+        assert_eq!(username, command.username);
+        assert_eq!(client_nonce, command.client_nonce);
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes() {
+        let username = "user";
+        let client_nonce = "nonce";
+        let mut bytes = Vec::new();
+        #[allow(clippy::cast_possible_truncation)]
+        bytes.put_u8(username.len() as u8);
+        bytes.extend(username.as_bytes());
+        #[allow(clippy::cast_possible_truncation)]
+        bytes.put_u8(client_nonce.len() as u8);
+        bytes.extend(client_nonce.as_bytes());
+        let command = ScramLoginStart::from_bytes(&bytes);
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.username, username);
+        assert_eq!(command.client_nonce, client_nonce);
+    }
+
+    #[test]
+    fn should_be_read_from_string() {
+        let username = "user";
+        let client_nonce = "nonce";
+        let input = format!("{}|{}", username, client_nonce);
+        let command = ScramLoginStart::from_str(&input);
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.username, username);
+        assert_eq!(command.client_nonce, client_nonce);
+    }
+}
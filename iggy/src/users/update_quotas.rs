@@ -0,0 +1,150 @@
+use crate::bytes_serializable::BytesSerializable;
+use crate::command::CommandPayload;
+use crate::error::Error;
+use crate::identifier::Identifier;
+use crate::models::quotas::{Quotas, QUOTAS_SIZE};
+use crate::validatable::Validatable;
+use bytes::BufMut;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::str::FromStr;
+
+// When the quotas are not provided, the default ones from the server configuration are used.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default)]
+pub struct UpdateQuotas {
+    #[serde(skip)]
+    pub user_id: Identifier,
+    pub quotas: Option<Quotas>,
+}
+
+impl CommandPayload for UpdateQuotas {}
+
+impl Validatable<Error> for UpdateQuotas {
+    fn validate(&self) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+impl FromStr for UpdateQuotas {
+    type Err = Error;
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let parts = input.splitn(2, '|').collect::<Vec<&str>>();
+        let user_id = parts[0].parse::<Identifier>()?;
+        let quotas = match parts.get(1) {
+            Some(quotas) => Some(quotas.parse::<Quotas>()?),
+            None => None,
+        };
+        let command = UpdateQuotas { user_id, quotas };
+        command.validate()?;
+        Ok(command)
+    }
+}
+
+impl BytesSerializable for UpdateQuotas {
+    fn as_bytes(&self) -> Vec<u8> {
+        let user_id_bytes = self.user_id.as_bytes();
+        let mut bytes = Vec::with_capacity(user_id_bytes.len() + 1 + QUOTAS_SIZE);
+        bytes.extend(user_id_bytes);
+        if let Some(quotas) = &self.quotas {
+            bytes.put_u8(1);
+            bytes.extend(quotas.as_bytes());
+        } else {
+            bytes.put_u8(0);
+        }
+
+        bytes
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<UpdateQuotas, Error> {
+        if bytes.len() < 4 {
+            return Err(Error::InvalidCommand);
+        }
+
+        let user_id = Identifier::from_bytes(bytes)?;
+        let position = user_id.get_size_bytes() as usize;
+        if bytes.len() <= position {
+            return Err(Error::InvalidCommand);
+        }
+
+        let quotas = match bytes[position] {
+            0 => None,
+            1 => Some(Quotas::from_bytes(&bytes[position + 1..])?),
+            _ => return Err(Error::InvalidCommand),
+        };
+
+        let command = UpdateQuotas { user_id, quotas };
+        command.validate()?;
+        Ok(command)
+    }
+}
+
+impl Display for UpdateQuotas {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.quotas {
+            Some(quotas) => write!(f, "{}|{}", self.user_id, quotas),
+            None => write!(f, "{}", self.user_id),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_be_serialized_as_bytes() {
+        let command = UpdateQuotas {
+            user_id: Identifier::numeric(1).unwrap(),
+            quotas: Some(get_quotas()),
+        };
+        let bytes = command.as_bytes();
+        let user_id = Identifier::from_bytes(&bytes).unwrap();
+        let position = user_id.get_size_bytes() as usize;
+        let has_quotas = bytes[position];
+        let quotas = Quotas::from_bytes(&bytes[position + 1..]).unwrap();
+
+        assert!(!bytes.is_empty());
+        assert_eq!(user_id, command.user_id);
+        assert_eq!(has_quotas, 1);
+        assert_eq!(quotas, command.quotas.unwrap());
+    }
+
+    #[test]
+    fn should_be_deserialized_from_bytes() {
+        let user_id = Identifier::numeric(1).unwrap();
+        let quotas = get_quotas();
+        let mut bytes = Vec::new();
+        bytes.extend(user_id.as_bytes());
+        bytes.put_u8(1);
+        bytes.extend(quotas.as_bytes());
+
+        let command = UpdateQuotas::from_bytes(&bytes);
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.user_id, user_id);
+        assert_eq!(command.quotas.unwrap(), quotas);
+    }
+
+    #[test]
+    fn should_be_read_from_string() {
+        let user_id = Identifier::numeric(1).unwrap();
+        let quotas = get_quotas();
+        let input = format!("{user_id}|{quotas}");
+        let command = UpdateQuotas::from_str(&input);
+        assert!(command.is_ok());
+
+        let command = command.unwrap();
+        assert_eq!(command.user_id, user_id);
+        assert_eq!(command.quotas.unwrap(), quotas);
+    }
+
+    fn get_quotas() -> Quotas {
+        Quotas {
+            produce_bytes_per_second: 1_000_000,
+            produce_messages_per_second: 1000,
+            consume_bytes_per_second: 2_000_000,
+            consume_messages_per_second: 0,
+        }
+    }
+}
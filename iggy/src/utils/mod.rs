@@ -1,4 +1,5 @@
 pub mod checksum;
 pub mod crypto;
+pub mod scram;
 pub mod text;
 pub mod timestamp;
@@ -0,0 +1,158 @@
+use crate::utils::text;
+use aes_gcm::aead::rand_core::RngCore;
+use aes_gcm::aead::OsRng;
+use hmac::{Hmac, Mac};
+use sha2::{Digest, Sha256};
+
+pub const SCRAM_ITERATIONS: u32 = 4096;
+pub const MAX_SCRAM_ITERATIONS: u32 = 100_000;
+const SALT_LENGTH: usize = 16;
+const NONCE_LENGTH: usize = 18;
+const CLIENT_KEY: &[u8] = b"Client Key";
+const SERVER_KEY: &[u8] = b"Server Key";
+
+type HmacSha256 = Hmac<Sha256>;
+
+pub fn generate_nonce() -> String {
+    text::to_base64(&generate_random_bytes(NONCE_LENGTH))
+}
+
+pub fn generate_salt() -> Vec<u8> {
+    generate_random_bytes(SALT_LENGTH)
+}
+
+// The salt returned for the unknown users, which is the same for every login of the given username.
+pub fn fake_salt(key: &[u8], username: &str) -> Vec<u8> {
+    hmac(key, username.as_bytes())[..SALT_LENGTH].to_vec()
+}
+
+pub fn salt_password(password: &str, salt: &[u8], iterations: u32) -> Vec<u8> {
+    let mut salted_password = [0u8; 32];
+    pbkdf2::pbkdf2_hmac::<Sha256>(password.as_bytes(), salt, iterations, &mut salted_password);
+    salted_password.to_vec()
+}
+
+pub fn client_key(salted_password: &[u8]) -> Vec<u8> {
+    hmac(salted_password, CLIENT_KEY)
+}
+
+pub fn server_key(salted_password: &[u8]) -> Vec<u8> {
+    hmac(salted_password, SERVER_KEY)
+}
+
+pub fn stored_key(client_key: &[u8]) -> Vec<u8> {
+    Sha256::digest(client_key).to_vec()
+}
+
+// The same message is built on both sides from the values exchanged during the handshake, as in RFC 5802.
+pub fn auth_message(
+    username: &str,
+    client_nonce: &str,
+    nonce: &str,
+    salt: &[u8],
+    iterations: u32,
+) -> String {
+    format!(
+        "n={username},r={client_nonce},r={nonce},s={},i={iterations},c=biws,r={nonce}",
+        text::to_base64(salt)
+    )
+}
+
+pub fn client_proof(salted_password: &[u8], auth_message: &str) -> Vec<u8> {
+    let client_key = client_key(salted_password);
+    let client_signature = hmac(&stored_key(&client_key), auth_message.as_bytes());
+    xor(&client_key, &client_signature)
+}
+
+pub fn verify_client_proof(stored_key: &[u8], auth_message: &str, client_proof: &[u8]) -> bool {
+    if client_proof.len() != stored_key.len() {
+        return false;
+    }
+
+    let client_signature = hmac(stored_key, auth_message.as_bytes());
+    let client_key = xor(client_proof, &client_signature);
+    constant_time_eq(&self::stored_key(&client_key), stored_key)
+}
+
+pub fn server_signature(server_key: &[u8], auth_message: &str) -> Vec<u8> {
+    hmac(server_key, auth_message.as_bytes())
+}
+
+pub fn verify_server_signature(
+    salted_password: &[u8],
+    auth_message: &str,
+    server_signature: &[u8],
+) -> bool {
+    let expected_signature = self::server_signature(&server_key(salted_password), auth_message);
+    constant_time_eq(&expected_signature, server_signature)
+}
+
+fn hmac(key: &[u8], data: &[u8]) -> Vec<u8> {
+    let mut mac = HmacSha256::new_from_slice(key).expect("HMAC accepts keys of any length");
+    mac.update(data);
+    mac.finalize().into_bytes().to_vec()
+}
+
+fn xor(left: &[u8], right: &[u8]) -> Vec<u8> {
+    left.iter().zip(right).map(|(l, r)| l ^ r).collect()
+}
+
+fn constant_time_eq(left: &[u8], right: &[u8]) -> bool {
+    if left.len() != right.len() {
+        return false;
+    }
+
+    left.iter()
+        .zip(right)
+        .fold(0u8, |result, (l, r)| result | (l ^ r))
+        == 0
+}
+
+fn generate_random_bytes(length: usize) -> Vec<u8> {
+    let mut bytes = vec![0u8; length];
+    OsRng.fill_bytes(&mut bytes);
+    bytes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn client_proof_and_server_signature_should_be_verified_with_stored_keys() {
+        let salt = generate_salt();
+        let salted_password = salt_password("secret", &salt, SCRAM_ITERATIONS);
+        let stored_key = stored_key(&client_key(&salted_password));
+        let server_key = server_key(&salted_password);
+        let client_nonce = generate_nonce();
+        let nonce = format!("{client_nonce}{}", generate_nonce());
+        let auth_message = auth_message("user", &client_nonce, &nonce, &salt, SCRAM_ITERATIONS);
+
+        let client_proof = client_proof(&salted_password, &auth_message);
+        assert!(verify_client_proof(
+            &stored_key,
+            &auth_message,
+            &client_proof
+        ));
+
+        let invalid_salted_password = salt_password("invalid", &salt, SCRAM_ITERATIONS);
+        let invalid_client_proof = super::client_proof(&invalid_salted_password, &auth_message);
+        assert!(!verify_client_proof(
+            &stored_key,
+            &auth_message,
+            &invalid_client_proof
+        ));
+
+        let server_signature = server_signature(&server_key, &auth_message);
+        assert!(verify_server_signature(
+            &salted_password,
+            &auth_message,
+            &server_signature
+        ));
+        assert!(!verify_server_signature(
+            &invalid_salted_password,
+            &auth_message,
+            &server_signature
+        ));
+    }
+}
@@ -19,6 +19,10 @@ impl TimeStamp {
         self.0.duration_since(UNIX_EPOCH).unwrap().as_micros() as u64
     }
 
+    pub fn to_secs(&self) -> u64 {
+        self.0.duration_since(UNIX_EPOCH).unwrap().as_secs()
+    }
+
     pub fn to_string(&self, format: &str) -> String {
         DateTime::<Utc>::from(self.0).format(format).to_string()
     }
@@ -46,6 +50,12 @@ mod tests {
         assert_eq!(timestamp.to_micros(), 1663472051111);
     }
 
+    #[test]
+    fn test_timestamp_to_secs() {
+        let timestamp = TimeStamp::from(1694968446131680);
+        assert_eq!(timestamp.to_secs(), 1694968446);
+    }
+
     #[test]
     fn test_timestamp_to_string() {
         let timestamp = TimeStamp::from(1694968446131680);
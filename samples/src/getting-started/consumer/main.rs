@@ -2,7 +2,7 @@ use iggy::client::Client;
 use iggy::clients::client::IggyClient;
 use iggy::consumer::Consumer;
 use iggy::identifier::Identifier;
-use iggy::messages::poll_messages::{PollMessages, PollingStrategy};
+use iggy::messages::poll_messages::{IsolationLevel, PollMessages, PollingStrategy};
 use iggy::models::messages::Message;
 use std::error::Error;
 use std::time::Duration;
@@ -43,6 +43,10 @@ async fn consume_messages(client: &dyn Client) -> Result<(), Box<dyn Error>> {
                 strategy: PollingStrategy::offset(offset),
                 count: messages_per_batch,
                 auto_commit: false,
+                include_all_states: false,
+                max_wait_ms: None,
+                isolation_level: IsolationLevel::default(),
+                filters: vec![],
             })
             .await?;
         if polled_messages.messages.is_empty() {
@@ -2,6 +2,7 @@ use iggy::client::{Client, StreamClient, TopicClient};
 use iggy::clients::client::IggyClient;
 use iggy::identifier::Identifier;
 use iggy::messages::send_messages::{Message, Partitioning, SendMessages};
+use iggy::models::cleanup_policy::CleanupPolicy;
 use iggy::streams::create_stream::CreateStream;
 use iggy::topics::create_topic::CreateTopic;
 use std::error::Error;
@@ -28,6 +29,7 @@ async fn init_system(client: &IggyClient) {
         .create_stream(&CreateStream {
             stream_id: STREAM_ID,
             name: "sample-stream".to_string(),
+            encryption: None,
         })
         .await
     {
@@ -42,6 +44,11 @@ async fn init_system(client: &IggyClient) {
             partitions_count: 1,
             name: "sample-topic".to_string(),
             message_expiry: None,
+            cleanup_policy: CleanupPolicy::Delete,
+            compaction_key: None,
+            max_topic_size_bytes: None,
+            max_messages: None,
+            dead_letter_topic_id: None,
         })
         .await
     {
@@ -75,6 +82,8 @@ async fn produce_messages(client: &dyn Client) -> Result<(), Box<dyn Error>> {
                 stream_id: Identifier::numeric(STREAM_ID)?,
                 topic_id: Identifier::numeric(TOPIC_ID)?,
                 partitioning: Partitioning::partition_id(PARTITION_ID),
+                producer: None,
+                transaction_id: None,
                 messages,
             })
             .await?;
@@ -5,7 +5,7 @@ use iggy::client_provider::ClientProviderConfig;
 use iggy::clients::client::{IggyClient, IggyClientConfig, PollMessagesConfig, StoreOffsetKind};
 use iggy::consumer::{Consumer, ConsumerKind};
 use iggy::identifier::Identifier;
-use iggy::messages::poll_messages::{PollMessages, PollingStrategy};
+use iggy::messages::poll_messages::{IsolationLevel, PollMessages, PollingStrategy};
 use iggy::models::header::HeaderKey;
 use iggy::models::messages::Message;
 use samples::shared::args::Args;
@@ -54,6 +54,10 @@ async fn consume_messages(args: &Args, client: &IggyClient) -> Result<(), Box<dy
                 strategy: PollingStrategy::next(),
                 count: args.messages_per_batch,
                 auto_commit: true,
+                include_all_states: false,
+                max_wait_ms: None,
+                isolation_level: IsolationLevel::default(),
+                filters: vec![],
             },
             Some(|message| {
                 let result = handle_message(&message);
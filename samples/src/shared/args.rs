@@ -54,6 +54,18 @@ pub struct Args {
     #[arg(long, default_value = "localhost")]
     pub tcp_tls_domain: String,
 
+    #[arg(long)]
+    pub tcp_tls_certificate_file: Option<String>,
+
+    #[arg(long)]
+    pub tcp_tls_key_file: Option<String>,
+
+    #[arg(long)]
+    pub tcp_tls_ca_file: Option<String>,
+
+    #[arg(long, default_value = "false")]
+    pub tcp_plaintext_login_fallback: bool,
+
     #[arg(long, default_value = "127.0.0.1:0")]
     pub quic_client_address: String,
 
@@ -95,6 +107,15 @@ pub struct Args {
 
     #[arg(long, default_value = "false")]
     pub quic_validate_certificate: bool,
+
+    #[arg(long)]
+    pub quic_client_certificate_file: Option<String>,
+
+    #[arg(long)]
+    pub quic_client_key_file: Option<String>,
+
+    #[arg(long, default_value = "false")]
+    pub quic_plaintext_login_fallback: bool,
 }
 
 impl Args {
@@ -109,6 +130,10 @@ impl Args {
             tcp_reconnection_interval: self.tcp_reconnection_interval,
             tcp_tls_enabled: self.tcp_tls_enabled,
             tcp_tls_domain: self.tcp_tls_domain.clone(),
+            tcp_tls_certificate_file: self.tcp_tls_certificate_file.clone(),
+            tcp_tls_key_file: self.tcp_tls_key_file.clone(),
+            tcp_tls_ca_file: self.tcp_tls_ca_file.clone(),
+            tcp_plaintext_login_fallback: self.tcp_plaintext_login_fallback,
             quic_client_address: self.quic_client_address.clone(),
             quic_server_address: self.quic_server_address.clone(),
             quic_server_name: self.quic_server_name.clone(),
@@ -123,6 +148,9 @@ impl Args {
             quic_keep_alive_interval: self.quic_keep_alive_interval,
             quic_max_idle_timeout: self.quic_max_idle_timeout,
             quic_validate_certificate: self.quic_validate_certificate,
+            quic_client_certificate_file: self.quic_client_certificate_file.clone(),
+            quic_client_key_file: self.quic_client_key_file.clone(),
+            quic_plaintext_login_fallback: self.quic_plaintext_login_fallback,
         }
     }
 }
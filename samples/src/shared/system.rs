@@ -2,6 +2,7 @@ use crate::shared::args::Args;
 use iggy::client::Client;
 use iggy::error::Error;
 use iggy::identifier::Identifier;
+use iggy::models::cleanup_policy::CleanupPolicy;
 use iggy::streams::create_stream::CreateStream;
 use iggy::streams::get_stream::GetStream;
 use iggy::topics::create_topic::CreateTopic;
@@ -65,6 +66,7 @@ pub async fn init_by_producer(args: &Args, client: &dyn Client) -> Result<(), Er
         .create_stream(&CreateStream {
             stream_id: args.stream_id,
             name: "sample".to_string(),
+            encryption: None,
         })
         .await?;
     client
@@ -74,6 +76,11 @@ pub async fn init_by_producer(args: &Args, client: &dyn Client) -> Result<(), Er
             partitions_count: args.partitions_count,
             name: "orders".to_string(),
             message_expiry: None,
+            cleanup_policy: CleanupPolicy::Delete,
+            compaction_key: None,
+            max_topic_size_bytes: None,
+            max_messages: None,
+            dead_letter_topic_id: None,
         })
         .await?;
     Ok(())
@@ -1,34 +1,53 @@
 use crate::binary::handlers::consumer_groups::{
-    create_consumer_group_handler, delete_consumer_group_handler, get_consumer_group_handler,
-    get_consumer_groups_handler, join_consumer_group_handler, leave_consumer_group_handler,
+    create_consumer_group_handler, delete_consumer_group_handler,
+    get_consumer_group_assignment_handler, get_consumer_group_handler, get_consumer_groups_handler,
+    heartbeat_consumer_group_handler, join_consumer_group_handler, leave_consumer_group_handler,
 };
 use crate::binary::handlers::consumer_offsets::*;
 use crate::binary::handlers::messages::*;
 use crate::binary::handlers::partitions::*;
+use crate::binary::handlers::personal_access_tokens::{
+    create_personal_access_token_handler, delete_personal_access_token_handler,
+    get_personal_access_tokens_handler, login_with_token_handler,
+};
+use crate::binary::handlers::roles::{
+    add_role_member_handler, create_role_handler, delete_role_handler, get_role_handler,
+    get_roles_handler, remove_role_member_handler, update_role_handler,
+};
 use crate::binary::handlers::streams::*;
 use crate::binary::handlers::system::*;
 use crate::binary::handlers::topics::*;
+use crate::binary::handlers::transactions::*;
 use crate::binary::handlers::users::{
     change_password_handler, create_user_handler, delete_user_handler, get_user_handler,
-    get_users_handler, login_user_handler, logout_user_handler, update_permissions_handler,
-    update_user_handler,
+    get_users_handler, login_user_handler, logout_user_handler, scram_login_finish_handler,
+    scram_login_start_handler, unlock_user_handler, update_permissions_handler,
+    update_quotas_handler, update_user_handler,
 };
 use crate::binary::sender::Sender;
+use crate::binary::subscriptions::Subscriptions;
+use crate::streaming::audit::audit_log::SUCCESS_RESULT;
 use crate::streaming::systems::system::System;
 use crate::streaming::users::user_context::UserContext;
-use iggy::command::Command;
+use iggy::command::{
+    Command, CHANGE_PASSWORD, CREATE_USER, LOGIN_USER, LOGIN_WITH_TOKEN, SCRAM_LOGIN_FINISH,
+    SCRAM_LOGIN_START, UPDATE_PERMISSIONS,
+};
 use iggy::error::Error;
 use std::sync::Arc;
 use tokio::sync::RwLock;
 use tracing::trace;
 
+const LOGIN_WITH_CERTIFICATE: &str = "user.login_with_certificate";
+
 pub async fn handle(
     command: &Command,
     sender: &mut dyn Sender,
     user_context: &mut UserContext,
+    subscriptions: &mut Subscriptions,
     system: Arc<RwLock<System>>,
 ) -> Result<(), Error> {
-    let result = try_handle(command, sender, user_context, system).await;
+    let result = try_handle(command, sender, user_context, subscriptions, system).await;
     if result.is_ok() {
         trace!(
             "Command was handled successfully, client: '{}'.",
@@ -51,6 +70,140 @@ async fn try_handle(
     command: &Command,
     sender: &mut dyn Sender,
     user_context: &mut UserContext,
+    subscriptions: &mut Subscriptions,
+    system: Arc<RwLock<System>>,
+) -> Result<(), Error> {
+    let previous_user_id = user_context.user_id;
+    let result = handle_command(command, sender, user_context, subscriptions, system.clone()).await;
+    if let Some(audited_command) = describe_audited_command(command) {
+        // Logout clears the user ID, so the record is assigned to the user who was logged in.
+        let user_id = match user_context.user_id {
+            0 => previous_user_id,
+            user_id => user_id,
+        };
+        let audit_result = match &result {
+            Ok(_) => SUCCESS_RESULT,
+            Err(error) => error.as_string(),
+        };
+        system
+            .read()
+            .await
+            .audit_client_command(
+                user_context.client_id,
+                user_id,
+                &audited_command,
+                audit_result,
+            )
+            .await;
+    }
+
+    result
+}
+
+pub(crate) async fn login_with_certificate(
+    username: &str,
+    user_context: &mut UserContext,
+    system: &System,
+) {
+    let result = system
+        .login_with_certificate(username, user_context.client_id)
+        .await;
+    let audit_result = match &result {
+        Ok(user) => {
+            user_context.set_user_id(user.id);
+            SUCCESS_RESULT
+        }
+        Err(error) => error.as_string(),
+    };
+    system
+        .audit_client_command(
+            user_context.client_id,
+            user_context.user_id,
+            &format!("{LOGIN_WITH_CERTIFICATE}|{username}"),
+            audit_result,
+        )
+        .await;
+}
+
+// Only the administrative and security related commands are audited, credentials are never stored.
+fn describe_audited_command(command: &Command) -> Option<String> {
+    match command {
+        Command::LoginUser(command) => Some(format!("{LOGIN_USER}|{}", command.username)),
+        Command::CreateUser(command) => Some(format!("{CREATE_USER}|{}", command.username)),
+        Command::ChangePassword(command) => Some(format!("{CHANGE_PASSWORD}|{}", command.user_id)),
+        Command::UpdatePermissions(command) => {
+            Some(format!("{UPDATE_PERMISSIONS}|{}", command.user_id))
+        }
+        Command::LoginWithToken(_) => Some(LOGIN_WITH_TOKEN.to_string()),
+        Command::ScramLoginStart(command) => {
+            Some(format!("{SCRAM_LOGIN_START}|{}", command.username))
+        }
+        Command::ScramLoginFinish(_) => Some(SCRAM_LOGIN_FINISH.to_string()),
+        Command::DeleteUser(_)
+        | Command::UnlockUser(_)
+        | Command::UpdateUser(_)
+        | Command::UpdateQuotas(_)
+        | Command::LogoutUser(_)
+        | Command::RevokeSession(_)
+        | Command::CreatePersonalAccessToken(_)
+        | Command::DeletePersonalAccessToken(_)
+        | Command::CreateRole(_)
+        | Command::DeleteRole(_)
+        | Command::UpdateRole(_)
+        | Command::AddRoleMember(_)
+        | Command::RemoveRoleMember(_)
+        | Command::PoisonMessage(_)
+        | Command::ResetConsumerOffsets(_)
+        | Command::CreateStream(_)
+        | Command::DeleteStream(_)
+        | Command::UpdateStream(_)
+        | Command::CreateTopic(_)
+        | Command::DeleteTopic(_)
+        | Command::UpdateTopic(_)
+        | Command::CreatePartitions(_)
+        | Command::DeletePartitions(_)
+        | Command::CreateConsumerGroup(_)
+        | Command::DeleteConsumerGroup(_)
+        | Command::JoinConsumerGroup(_)
+        | Command::LeaveConsumerGroup(_)
+        | Command::HeartbeatConsumerGroup(_)
+        | Command::StoreConsumerOffset(_)
+        | Command::InitProducer(_)
+        | Command::Subscribe(_)
+        | Command::Unsubscribe(_)
+        | Command::BeginTransaction(_)
+        | Command::CommitTransaction(_)
+        | Command::AbortTransaction(_) => Some(command.to_string()),
+        // Data plane commands are not audited, as they would flood the audit log.
+        Command::SendMessages(_) | Command::PollMessages(_) | Command::GrantCredits(_) => None,
+        Command::Ping(_)
+        | Command::GetStats(_)
+        | Command::GetMe(_)
+        | Command::GetClient(_)
+        | Command::GetClients(_)
+        | Command::GetSessions(_)
+        | Command::GetUser(_)
+        | Command::GetUsers(_)
+        | Command::GetPersonalAccessTokens(_)
+        | Command::GetRole(_)
+        | Command::GetRoles(_)
+        | Command::GetConsumerOffset(_)
+        | Command::GetConsumerLag(_)
+        | Command::GetStream(_)
+        | Command::GetStreams(_)
+        | Command::GetTopic(_)
+        | Command::GetTopics(_)
+        | Command::GetConsumerGroup(_)
+        | Command::GetConsumerGroups(_)
+        | Command::GetConsumerGroupAssignment(_) => None,
+    }
+}
+
+async fn handle_command(
+    command: &Command,
+    sender: &mut dyn Sender,
+    user_context: &mut UserContext,
+    subscriptions: &mut Subscriptions,
     system: Arc<RwLock<System>>,
 ) -> Result<(), Error> {
     trace!(
@@ -72,6 +225,12 @@ async fn try_handle(
         Command::GetClients(command) => {
             get_clients_handler::handle(command, sender, user_context, system).await
         }
+        Command::GetSessions(command) => {
+            get_sessions_handler::handle(command, sender, user_context, system).await
+        }
+        Command::RevokeSession(command) => {
+            revoke_session_handler::handle(command, sender, user_context, system).await
+        }
         Command::GetUser(command) => {
             get_user_handler::handle(command, sender, user_context, system).await
         }
@@ -84,12 +243,18 @@ async fn try_handle(
         Command::DeleteUser(command) => {
             delete_user_handler::handle(command, sender, user_context, system).await
         }
+        Command::UnlockUser(command) => {
+            unlock_user_handler::handle(command, sender, user_context, system).await
+        }
         Command::UpdateUser(command) => {
             update_user_handler::handle(command, sender, user_context, system).await
         }
         Command::UpdatePermissions(command) => {
             update_permissions_handler::handle(command, sender, user_context, system).await
         }
+        Command::UpdateQuotas(command) => {
+            update_quotas_handler::handle(command, sender, user_context, system).await
+        }
         Command::ChangePassword(command) => {
             change_password_handler::handle(command, sender, user_context, system).await
         }
@@ -99,18 +264,80 @@ async fn try_handle(
         Command::LogoutUser(command) => {
             logout_user_handler::handle(command, sender, user_context, system).await
         }
+        Command::ScramLoginStart(command) => {
+            scram_login_start_handler::handle(command, sender, user_context, system).await
+        }
+        Command::ScramLoginFinish(command) => {
+            scram_login_finish_handler::handle(command, sender, user_context, system).await
+        }
+        Command::GetPersonalAccessTokens(command) => {
+            get_personal_access_tokens_handler::handle(command, sender, user_context, system).await
+        }
+        Command::CreatePersonalAccessToken(command) => {
+            create_personal_access_token_handler::handle(command, sender, user_context, system)
+                .await
+        }
+        Command::DeletePersonalAccessToken(command) => {
+            delete_personal_access_token_handler::handle(command, sender, user_context, system)
+                .await
+        }
+        Command::LoginWithToken(command) => {
+            login_with_token_handler::handle(command, sender, user_context, system).await
+        }
+        Command::GetRole(command) => {
+            get_role_handler::handle(command, sender, user_context, system).await
+        }
+        Command::GetRoles(command) => {
+            get_roles_handler::handle(command, sender, user_context, system).await
+        }
+        Command::CreateRole(command) => {
+            create_role_handler::handle(command, sender, user_context, system).await
+        }
+        Command::DeleteRole(command) => {
+            delete_role_handler::handle(command, sender, user_context, system).await
+        }
+        Command::UpdateRole(command) => {
+            update_role_handler::handle(command, sender, user_context, system).await
+        }
+        Command::AddRoleMember(command) => {
+            add_role_member_handler::handle(command, sender, user_context, system).await
+        }
+        Command::RemoveRoleMember(command) => {
+            remove_role_member_handler::handle(command, sender, user_context, system).await
+        }
         Command::SendMessages(command) => {
             send_messages_handler::handle(command, sender, user_context, system).await
         }
         Command::PollMessages(command) => {
             poll_messages_handler::handle(command, sender, user_context, system).await
         }
+        Command::PoisonMessage(command) => {
+            poison_message_handler::handle(command, sender, user_context, system).await
+        }
+        Command::Subscribe(command) => {
+            subscribe_handler::handle(command, sender, user_context, subscriptions, system).await
+        }
+        Command::GrantCredits(command) => {
+            grant_credits_handler::handle(command, sender, user_context, subscriptions).await
+        }
+        Command::InitProducer(command) => {
+            init_producer_handler::handle(command, sender, user_context, system).await
+        }
+        Command::Unsubscribe(command) => {
+            unsubscribe_handler::handle(command, sender, user_context, subscriptions).await
+        }
         Command::GetConsumerOffset(command) => {
             get_consumer_offset_handler::handle(command, sender, user_context, system).await
         }
         Command::StoreConsumerOffset(command) => {
             store_consumer_offset_handler::handle(command, sender, user_context, system).await
         }
+        Command::GetConsumerLag(command) => {
+            get_consumer_lag_handler::handle(command, sender, user_context, system).await
+        }
+        Command::ResetConsumerOffsets(command) => {
+            reset_consumer_offsets_handler::handle(command, sender, user_context, system).await
+        }
         Command::GetStream(command) => {
             get_stream_handler::handle(command, sender, user_context, system).await
         }
@@ -165,5 +392,21 @@ async fn try_handle(
         Command::LeaveConsumerGroup(command) => {
             leave_consumer_group_handler::handle(command, sender, user_context, system).await
         }
+        Command::HeartbeatConsumerGroup(command) => {
+            heartbeat_consumer_group_handler::handle(command, sender, user_context, system).await
+        }
+        Command::GetConsumerGroupAssignment(command) => {
+            get_consumer_group_assignment_handler::handle(command, sender, user_context, system)
+                .await
+        }
+        Command::BeginTransaction(command) => {
+            begin_transaction_handler::handle(command, sender, user_context, system).await
+        }
+        Command::CommitTransaction(command) => {
+            commit_transaction_handler::handle(command, sender, user_context, system).await
+        }
+        Command::AbortTransaction(command) => {
+            abort_transaction_handler::handle(command, sender, user_context, system).await
+        }
     }
 }
@@ -36,6 +36,8 @@ pub async fn handle(
             &command.stream_id,
             &command.topic_id,
             command.consumer_group_id,
+            command.assignment_strategy,
+            command.session_timeout,
         )
         .await?;
     sender.send_empty_ok_response().await?;
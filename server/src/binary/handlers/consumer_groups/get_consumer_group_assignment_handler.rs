@@ -0,0 +1,39 @@
+use crate::binary::mapper;
+use crate::binary::sender::Sender;
+use crate::streaming::systems::system::System;
+use crate::streaming::users::user_context::UserContext;
+use anyhow::Result;
+use iggy::consumer_groups::get_consumer_group_assignment::GetConsumerGroupAssignment;
+use iggy::error::Error;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+use tracing::trace;
+
+pub async fn handle(
+    command: &GetConsumerGroupAssignment,
+    sender: &mut dyn Sender,
+    user_context: &UserContext,
+    system: Arc<RwLock<System>>,
+) -> Result<(), Error> {
+    trace!("{command}");
+    if !user_context.is_authenticated() {
+        return Err(Error::Unauthenticated);
+    }
+
+    let system = system.read().await;
+    let stream = system.get_stream(&command.stream_id)?;
+    let topic = stream.get_topic(&command.topic_id)?;
+    system.permissioner.get_consumer_group(
+        user_context.user_id,
+        stream.stream_id,
+        topic.topic_id,
+    )?;
+    let consumer_group = topic.get_consumer_group(command.consumer_group_id)?;
+    let consumer_group = consumer_group.read().await;
+    let assignment = consumer_group
+        .get_assignment(user_context.client_id)
+        .await?;
+    let assignment = mapper::map_consumer_group_assignment(&assignment);
+    sender.send_ok_response(assignment.as_slice()).await?;
+    Ok(())
+}
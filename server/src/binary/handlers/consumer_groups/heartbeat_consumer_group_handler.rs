@@ -0,0 +1,40 @@
+use crate::binary::sender::Sender;
+use crate::streaming::systems::system::System;
+use crate::streaming::users::user_context::UserContext;
+use anyhow::Result;
+use iggy::consumer_groups::heartbeat_consumer_group::HeartbeatConsumerGroup;
+use iggy::error::Error;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+use tracing::trace;
+
+pub async fn handle(
+    command: &HeartbeatConsumerGroup,
+    sender: &mut dyn Sender,
+    user_context: &UserContext,
+    system: Arc<RwLock<System>>,
+) -> Result<(), Error> {
+    trace!("{command}");
+    if !user_context.is_authenticated() {
+        return Err(Error::Unauthenticated);
+    }
+
+    let system = system.read().await;
+    let stream = system.get_stream(&command.stream_id)?;
+    let topic = stream.get_topic(&command.topic_id)?;
+    system.permissioner.join_consumer_group(
+        user_context.user_id,
+        stream.stream_id,
+        topic.topic_id,
+    )?;
+    system
+        .heartbeat_consumer_group(
+            user_context.client_id,
+            &command.stream_id,
+            &command.topic_id,
+            command.consumer_group_id,
+        )
+        .await?;
+    sender.send_empty_ok_response().await?;
+    Ok(())
+}
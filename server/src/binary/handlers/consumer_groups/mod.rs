@@ -1,6 +1,8 @@
 pub mod create_consumer_group_handler;
 pub mod delete_consumer_group_handler;
+pub mod get_consumer_group_assignment_handler;
 pub mod get_consumer_group_handler;
 pub mod get_consumer_groups_handler;
+pub mod heartbeat_consumer_group_handler;
 pub mod join_consumer_group_handler;
 pub mod leave_consumer_group_handler;
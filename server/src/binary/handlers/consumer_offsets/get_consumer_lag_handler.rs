@@ -0,0 +1,36 @@
+use crate::binary::mapper;
+use crate::binary::sender::Sender;
+use crate::streaming::systems::system::System;
+use crate::streaming::users::user_context::UserContext;
+use anyhow::Result;
+use iggy::consumer_offsets::get_consumer_lag::GetConsumerLag;
+use iggy::error::Error;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+use tracing::trace;
+
+pub async fn handle(
+    command: &GetConsumerLag,
+    sender: &mut dyn Sender,
+    user_context: &UserContext,
+    system: Arc<RwLock<System>>,
+) -> Result<(), Error> {
+    trace!("{command}");
+    if !user_context.is_authenticated() {
+        return Err(Error::Unauthenticated);
+    }
+
+    let system = system.read().await;
+    let stream = system.get_stream(&command.stream_id)?;
+    let topic = stream.get_topic(&command.topic_id)?;
+    system
+        .permissioner
+        .get_topic(user_context.user_id, stream.stream_id, topic.topic_id)?;
+    let lags = match &command.consumer {
+        Some(consumer) => vec![topic.get_consumer_lag(consumer).await?],
+        None => topic.get_consumer_groups_lag().await?,
+    };
+    let lags = mapper::map_consumer_lags(&lags);
+    sender.send_ok_response(&lags).await?;
+    Ok(())
+}
@@ -0,0 +1,40 @@
+use crate::binary::mapper;
+use crate::binary::sender::Sender;
+use crate::streaming::systems::system::System;
+use crate::streaming::users::user_context::UserContext;
+use anyhow::Result;
+use iggy::consumer_offsets::reset_consumer_offsets::ResetConsumerOffsets;
+use iggy::error::Error;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+use tracing::trace;
+
+pub async fn handle(
+    command: &ResetConsumerOffsets,
+    sender: &mut dyn Sender,
+    user_context: &UserContext,
+    system: Arc<RwLock<System>>,
+) -> Result<(), Error> {
+    trace!("{command}");
+    if !user_context.is_authenticated() {
+        return Err(Error::Unauthenticated);
+    }
+
+    let system = system.read().await;
+    let stream = system.get_stream(&command.stream_id)?;
+    let topic = stream.get_topic(&command.topic_id)?;
+    system
+        .permissioner
+        .poll_messages(user_context.user_id, stream.stream_id, topic.topic_id)?;
+    let resets = topic
+        .reset_consumer_offsets(
+            &command.consumer,
+            command.partition_id,
+            command.strategy,
+            command.dry_run,
+        )
+        .await?;
+    let resets = mapper::map_consumer_offset_resets(&resets);
+    sender.send_ok_response(&resets).await?;
+    Ok(())
+}
@@ -1,6 +1,7 @@
 use crate::binary::sender::Sender;
 use crate::streaming::polling_consumer::PollingConsumer;
 use crate::streaming::systems::system::System;
+use crate::streaming::topics::consumer_group::ConsumerGroupGeneration;
 use crate::streaming::users::user_context::UserContext;
 use anyhow::Result;
 use iggy::consumer_offsets::store_consumer_offset::StoreConsumerOffset;
@@ -25,12 +26,43 @@ pub async fn handle(
         user_context.client_id,
         command.partition_id,
     );
+    let generation = match (consumer, command.generation_id) {
+        (PollingConsumer::ConsumerGroup(consumer_group_id, _), Some(generation_id)) => {
+            Some(ConsumerGroupGeneration {
+                consumer_group_id,
+                generation_id,
+            })
+        }
+        _ => None,
+    };
     let system = system.read().await;
-    system
+    let topic = system
         .get_stream(&command.stream_id)?
-        .get_topic(&command.topic_id)?
-        .store_consumer_offset(consumer, command.offset)
-        .await?;
+        .get_topic(&command.topic_id)?;
+    match (command.transaction_id, generation) {
+        (Some(transaction_id), _) => {
+            system
+                .store_transactional_consumer_offset(
+                    user_context.user_id,
+                    transaction_id,
+                    topic,
+                    consumer,
+                    command.offset,
+                    generation,
+                )
+                .await?
+        }
+        (None, Some(generation)) => {
+            topic
+                .store_consumer_offset_in_generation(consumer, command.offset, generation)
+                .await?
+        }
+        (None, None) => {
+            topic
+                .store_consumer_offset(consumer, command.offset)
+                .await?
+        }
+    }
 
     sender.send_empty_ok_response().await?;
     Ok(())
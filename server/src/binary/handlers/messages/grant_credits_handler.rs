@@ -0,0 +1,23 @@
+use crate::binary::sender::Sender;
+use crate::binary::subscriptions::Subscriptions;
+use crate::streaming::users::user_context::UserContext;
+use anyhow::Result;
+use iggy::error::Error;
+use iggy::messages::grant_credits::GrantCredits;
+use tracing::trace;
+
+pub async fn handle(
+    command: &GrantCredits,
+    sender: &mut dyn Sender,
+    user_context: &UserContext,
+    subscriptions: &mut Subscriptions,
+) -> Result<(), Error> {
+    trace!("{command}");
+    if !user_context.is_authenticated() {
+        return Err(Error::Unauthenticated);
+    }
+
+    subscriptions.grant_credits(command.subscription_id, command.credits)?;
+    sender.send_empty_ok_response().await?;
+    Ok(())
+}
@@ -0,0 +1,29 @@
+use crate::binary::mapper;
+use crate::binary::sender::Sender;
+use crate::streaming::systems::system::System;
+use crate::streaming::users::user_context::UserContext;
+use anyhow::Result;
+use iggy::error::Error;
+use iggy::messages::init_producer::InitProducer;
+use iggy::models::producer_info::ProducerInfo;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+use tracing::trace;
+
+pub async fn handle(
+    command: &InitProducer,
+    sender: &mut dyn Sender,
+    user_context: &UserContext,
+    system: Arc<RwLock<System>>,
+) -> Result<(), Error> {
+    trace!("{command}");
+    if !user_context.is_authenticated() {
+        return Err(Error::Unauthenticated);
+    }
+
+    let system = system.read().await;
+    let producer_id = system.init_producer().await?;
+    let bytes = mapper::map_producer_info(&ProducerInfo { producer_id });
+    sender.send_ok_response(bytes.as_slice()).await?;
+    Ok(())
+}
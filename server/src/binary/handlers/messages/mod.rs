@@ -1,2 +1,7 @@
+pub mod grant_credits_handler;
+pub mod init_producer_handler;
+pub mod poison_message_handler;
 pub mod poll_messages_handler;
 pub mod send_messages_handler;
+pub mod subscribe_handler;
+pub mod unsubscribe_handler;
@@ -0,0 +1,46 @@
+use crate::binary::sender::Sender;
+use crate::streaming::systems::system::System;
+use crate::streaming::users::user_context::UserContext;
+use anyhow::Result;
+use iggy::error::Error;
+use iggy::messages::poison_message::PoisonMessage;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+use tracing::trace;
+
+pub async fn handle(
+    command: &PoisonMessage,
+    sender: &mut dyn Sender,
+    user_context: &UserContext,
+    system: Arc<RwLock<System>>,
+) -> Result<(), Error> {
+    trace!("{command}");
+    if !user_context.is_authenticated() {
+        return Err(Error::Unauthenticated);
+    }
+
+    let system = system.read().await;
+    let stream = system.get_stream(&command.stream_id)?;
+    let topic = stream.get_topic(&command.topic_id)?;
+    system
+        .permissioner
+        .poll_messages(user_context.user_id, stream.stream_id, topic.topic_id)?;
+    if let Some(dead_letter_topic_id) = topic.dead_letter_topic_id {
+        system.permissioner.append_messages(
+            user_context.user_id,
+            stream.stream_id,
+            dead_letter_topic_id,
+        )?;
+    }
+
+    system
+        .poison_message(
+            &command.stream_id,
+            &command.topic_id,
+            command.partition_id,
+            command.offset,
+        )
+        .await?;
+    sender.send_empty_ok_response().await?;
+    Ok(())
+}
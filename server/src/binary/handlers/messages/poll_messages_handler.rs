@@ -1,6 +1,8 @@
 use crate::binary::mapper;
 use crate::binary::sender::Sender;
+use crate::streaming::polling_args::PollingArgs;
 use crate::streaming::polling_consumer::PollingConsumer;
+use crate::streaming::quotas::quota_manager::Throughput;
 use crate::streaming::systems::system::System;
 use crate::streaming::users::user_context::UserContext;
 use anyhow::Result;
@@ -26,23 +28,53 @@ pub async fn handle(
         user_context.client_id,
         command.partition_id,
     );
-    let system = system.read().await;
-    let stream = system.get_stream(&command.stream_id)?;
-    let topic = stream.get_topic(&command.topic_id)?;
-    system
-        .permissioner
-        .poll_messages(user_context.user_id, stream.stream_id, topic.topic_id)?;
+    System::acquire_quota(
+        &system,
+        Throughput::Consume,
+        Some(user_context.client_id),
+        user_context.user_id,
+        0,
+        0,
+    )
+    .await?;
+    {
+        let system = system.read().await;
+        let stream = system.get_stream(&command.stream_id)?;
+        let topic = stream.get_topic(&command.topic_id)?;
+        system.permissioner.poll_messages(
+            user_context.user_id,
+            stream.stream_id,
+            topic.topic_id,
+        )?;
+    }
 
-    let messages = system
-        .poll_messages(
-            consumer,
-            &command.stream_id,
-            &command.topic_id,
+    let messages = System::poll_messages_with_wait(
+        &system,
+        consumer,
+        &command.stream_id,
+        &command.topic_id,
+        PollingArgs::new(
             command.strategy,
             command.count,
             command.auto_commit,
+            command.include_all_states,
+            command.max_wait_ms,
+            command.isolation_level,
+            command.filters.clone(),
+        ),
+    )
+    .await?;
+    system
+        .read()
+        .await
+        .record_quota_usage(
+            Throughput::Consume,
+            Some(user_context.client_id),
+            user_context.user_id,
+            messages.get_size_bytes(),
+            messages.messages.len() as u64,
         )
-        .await?;
+        .await;
     let messages = mapper::map_polled_messages(&messages);
     sender.send_ok_response(&messages).await?;
     Ok(())
@@ -1,4 +1,5 @@
 use crate::binary::sender::Sender;
+use crate::streaming::quotas::quota_manager::Throughput;
 use crate::streaming::systems::system::System;
 use crate::streaming::users::user_context::UserContext;
 use anyhow::Result;
@@ -19,18 +20,47 @@ pub async fn handle(
         return Err(Error::Unauthenticated);
     }
 
-    let system = system.read().await;
-    let stream = system.get_stream(&command.stream_id)?;
-    let topic = stream.get_topic(&command.topic_id)?;
-    system
-        .permissioner
-        .append_messages(user_context.user_id, stream.stream_id, topic.topic_id)?;
+    {
+        let system = system.read().await;
+        let stream = system.get_stream(&command.stream_id)?;
+        let topic = stream.get_topic(&command.topic_id)?;
+        system.permissioner.append_messages(
+            user_context.user_id,
+            stream.stream_id,
+            topic.topic_id,
+        )?;
+        if let Some(transaction_id) = command.transaction_id {
+            system
+                .verify_transaction(user_context.user_id, transaction_id)
+                .await?;
+        }
+        system.validate_delivery_time(&command.messages)?;
+    }
 
+    // The quota is charged only for the messages which are allowed to be sent.
+    let bytes = command
+        .messages
+        .iter()
+        .map(|message| message.get_size_bytes() as u64)
+        .sum();
+    System::acquire_quota(
+        &system,
+        Throughput::Produce,
+        Some(user_context.client_id),
+        user_context.user_id,
+        bytes,
+        command.messages.len() as u64,
+    )
+    .await?;
+
+    let system = system.read().await;
     system
         .append_messages(
             &command.stream_id,
             &command.topic_id,
             &command.partitioning,
+            command.producer.as_ref(),
+            command.transaction_id,
             &command.messages,
         )
         .await?;
@@ -0,0 +1,67 @@
+use crate::binary::sender::Sender;
+use crate::binary::subscriptions::{Subscription, Subscriptions};
+use crate::streaming::polling_consumer::PollingConsumer;
+use crate::streaming::systems::system::System;
+use crate::streaming::users::user_context::UserContext;
+use anyhow::Result;
+use iggy::error::Error;
+use iggy::messages::subscribe::Subscribe;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+use tracing::trace;
+
+pub async fn handle(
+    command: &Subscribe,
+    sender: &mut dyn Sender,
+    user_context: &UserContext,
+    subscriptions: &mut Subscriptions,
+    system: Arc<RwLock<System>>,
+) -> Result<(), Error> {
+    trace!("{command}");
+    if !user_context.is_authenticated() {
+        return Err(Error::Unauthenticated);
+    }
+
+    let consumer = PollingConsumer::from_consumer(
+        &command.consumer,
+        user_context.client_id,
+        command.partition_id,
+    );
+    let listeners;
+    let mut assignment_listener = None;
+    {
+        let system = system.read().await;
+        let stream = system.get_stream(&command.stream_id)?;
+        let topic = stream.get_topic(&command.topic_id)?;
+        system.permissioner.poll_messages(
+            user_context.user_id,
+            stream.stream_id,
+            topic.topic_id,
+        )?;
+
+        listeners = system
+            .subscribe_to_new_messages(consumer, &command.stream_id, &command.topic_id)
+            .await?;
+        if let PollingConsumer::ConsumerGroup(consumer_group_id, _) = consumer {
+            let consumer_group = topic.get_consumer_group(consumer_group_id)?.read().await;
+            assignment_listener = Some(consumer_group.subscribe_to_assignment_changes());
+        }
+    }
+
+    let mut subscription = Subscription::new(
+        consumer,
+        &command.stream_id,
+        &command.topic_id,
+        command.strategy,
+        command.auto_commit,
+        command.credits,
+        listeners,
+    )
+    .with_isolation_level(command.isolation_level);
+    if let Some(assignment_listener) = assignment_listener {
+        subscription = subscription.with_assignment_listener(assignment_listener);
+    }
+    subscriptions.add(command.subscription_id, subscription)?;
+    sender.send_empty_ok_response().await?;
+    Ok(())
+}
@@ -0,0 +1,29 @@
+use crate::binary::mapper;
+use crate::binary::sender::Sender;
+use crate::streaming::systems::system::System;
+use crate::streaming::users::user_context::UserContext;
+use iggy::error::Error;
+use iggy::personal_access_tokens::create_personal_access_token::CreatePersonalAccessToken;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+use tracing::trace;
+
+pub async fn handle(
+    command: &CreatePersonalAccessToken,
+    sender: &mut dyn Sender,
+    user_context: &UserContext,
+    system: Arc<RwLock<System>>,
+) -> Result<(), Error> {
+    trace!("{command}");
+    if !user_context.is_authenticated() {
+        return Err(Error::Unauthenticated);
+    }
+
+    let system = system.read().await;
+    let token = system
+        .create_personal_access_token(user_context.user_id, &command.name, command.expiry)
+        .await?;
+    let token = mapper::map_raw_personal_access_token(&token);
+    sender.send_ok_response(token.as_slice()).await?;
+    Ok(())
+}
@@ -0,0 +1,27 @@
+use crate::binary::sender::Sender;
+use crate::streaming::systems::system::System;
+use crate::streaming::users::user_context::UserContext;
+use iggy::error::Error;
+use iggy::personal_access_tokens::delete_personal_access_token::DeletePersonalAccessToken;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+use tracing::trace;
+
+pub async fn handle(
+    command: &DeletePersonalAccessToken,
+    sender: &mut dyn Sender,
+    user_context: &UserContext,
+    system: Arc<RwLock<System>>,
+) -> Result<(), Error> {
+    trace!("{command}");
+    if !user_context.is_authenticated() {
+        return Err(Error::Unauthenticated);
+    }
+
+    let system = system.read().await;
+    system
+        .delete_personal_access_token(user_context.user_id, &command.name)
+        .await?;
+    sender.send_empty_ok_response().await?;
+    Ok(())
+}
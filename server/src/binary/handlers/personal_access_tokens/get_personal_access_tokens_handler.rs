@@ -0,0 +1,31 @@
+use crate::binary::mapper;
+use crate::binary::sender::Sender;
+use crate::streaming::systems::system::System;
+use crate::streaming::users::user_context::UserContext;
+use iggy::error::Error;
+use iggy::personal_access_tokens::get_personal_access_tokens::GetPersonalAccessTokens;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+use tracing::trace;
+
+pub async fn handle(
+    command: &GetPersonalAccessTokens,
+    sender: &mut dyn Sender,
+    user_context: &UserContext,
+    system: Arc<RwLock<System>>,
+) -> Result<(), Error> {
+    trace!("{command}");
+    if !user_context.is_authenticated() {
+        return Err(Error::Unauthenticated);
+    }
+
+    let system = system.read().await;
+    let personal_access_tokens = system
+        .get_personal_access_tokens(user_context.user_id)
+        .await?;
+    let personal_access_tokens = mapper::map_personal_access_tokens(&personal_access_tokens);
+    sender
+        .send_ok_response(personal_access_tokens.as_slice())
+        .await?;
+    Ok(())
+}
@@ -0,0 +1,25 @@
+use crate::binary::sender::Sender;
+use crate::streaming::systems::system::System;
+use crate::streaming::users::user_context::UserContext;
+use iggy::error::Error;
+use iggy::personal_access_tokens::login_with_token::LoginWithToken;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+use tracing::{info, trace};
+
+pub async fn handle(
+    command: &LoginWithToken,
+    sender: &mut dyn Sender,
+    user_context: &mut UserContext,
+    system: Arc<RwLock<System>>,
+) -> Result<(), Error> {
+    trace!("{command}");
+    let system = system.read().await;
+    let user = system
+        .login_with_personal_access_token(&command.token, Some(user_context.client_id))
+        .await?;
+    user_context.set_user_id(user.id);
+    info!("Set user ID: {}", user.id);
+    sender.send_empty_ok_response().await?;
+    Ok(())
+}
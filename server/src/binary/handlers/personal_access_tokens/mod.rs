@@ -0,0 +1,4 @@
+pub mod create_personal_access_token_handler;
+pub mod delete_personal_access_token_handler;
+pub mod get_personal_access_tokens_handler;
+pub mod login_with_token_handler;
@@ -0,0 +1,28 @@
+use crate::binary::sender::Sender;
+use crate::streaming::systems::system::System;
+use crate::streaming::users::user_context::UserContext;
+use iggy::error::Error;
+use iggy::roles::add_role_member::AddRoleMember;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+use tracing::trace;
+
+pub async fn handle(
+    command: &AddRoleMember,
+    sender: &mut dyn Sender,
+    user_context: &UserContext,
+    system: Arc<RwLock<System>>,
+) -> Result<(), Error> {
+    trace!("{command}");
+    if !user_context.is_authenticated() {
+        return Err(Error::Unauthenticated);
+    }
+
+    let mut system = system.write().await;
+    system.permissioner.add_role_member(user_context.user_id)?;
+    system
+        .add_role_member(&command.role_id, &command.user_id)
+        .await?;
+    sender.send_empty_ok_response().await?;
+    Ok(())
+}
@@ -0,0 +1,28 @@
+use crate::binary::mapper;
+use crate::binary::sender::Sender;
+use crate::streaming::systems::system::System;
+use crate::streaming::users::user_context::UserContext;
+use iggy::error::Error;
+use iggy::roles::get_roles::GetRoles;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+use tracing::trace;
+
+pub async fn handle(
+    command: &GetRoles,
+    sender: &mut dyn Sender,
+    user_context: &UserContext,
+    system: Arc<RwLock<System>>,
+) -> Result<(), Error> {
+    trace!("{command}");
+    if !user_context.is_authenticated() {
+        return Err(Error::Unauthenticated);
+    }
+
+    let system = system.read().await;
+    system.permissioner.get_roles(user_context.user_id)?;
+    let roles = system.get_roles().await?;
+    let roles = mapper::map_roles(&roles);
+    sender.send_ok_response(roles.as_slice()).await?;
+    Ok(())
+}
@@ -21,7 +21,8 @@ pub async fn handle(
         let client = system.get_client(user_context.client_id).await?;
         {
             let client = client.read().await;
-            bytes = mapper::map_client(&client).await;
+            let user_quotas = system.get_user_quotas(client.user_id).await;
+            bytes = mapper::map_client(&client, &system.get_client_quotas(), &user_quotas).await;
         }
     }
     sender.send_ok_response(bytes.as_slice()).await?;
@@ -0,0 +1,28 @@
+use crate::binary::mapper;
+use crate::binary::sender::Sender;
+use crate::streaming::systems::system::System;
+use crate::streaming::users::user_context::UserContext;
+use iggy::error::Error;
+use iggy::system::get_sessions::GetSessions;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+use tracing::log::trace;
+
+pub async fn handle(
+    command: &GetSessions,
+    sender: &mut dyn Sender,
+    user_context: &UserContext,
+    system: Arc<RwLock<System>>,
+) -> Result<(), Error> {
+    trace!("{command}");
+    if !user_context.is_authenticated() {
+        return Err(Error::Unauthenticated);
+    }
+
+    let system = system.read().await;
+    system.permissioner.get_sessions(user_context.user_id)?;
+    let sessions = system.get_sessions(command.user_id).await;
+    let sessions = mapper::map_clients(&sessions).await;
+    sender.send_ok_response(sessions.as_slice()).await?;
+    Ok(())
+}
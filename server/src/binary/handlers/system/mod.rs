@@ -1,5 +1,7 @@
 pub mod get_client_handler;
 pub mod get_clients_handler;
 pub mod get_me_handler;
+pub mod get_sessions_handler;
 pub mod get_stats_handler;
 pub mod ping_handler;
+pub mod revoke_session_handler;
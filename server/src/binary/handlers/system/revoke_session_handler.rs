@@ -0,0 +1,26 @@
+use crate::binary::sender::Sender;
+use crate::streaming::systems::system::System;
+use crate::streaming::users::user_context::UserContext;
+use iggy::error::Error;
+use iggy::system::revoke_session::RevokeSession;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+use tracing::log::trace;
+
+pub async fn handle(
+    command: &RevokeSession,
+    sender: &mut dyn Sender,
+    user_context: &UserContext,
+    system: Arc<RwLock<System>>,
+) -> Result<(), Error> {
+    trace!("{command}");
+    if !user_context.is_authenticated() {
+        return Err(Error::Unauthenticated);
+    }
+
+    let system = system.read().await;
+    system.permissioner.revoke_session(user_context.user_id)?;
+    system.revoke_session(command.client_id).await?;
+    sender.send_empty_ok_response().await?;
+    Ok(())
+}
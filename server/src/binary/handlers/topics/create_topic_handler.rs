@@ -1,5 +1,6 @@
 use crate::binary::sender::Sender;
 use crate::streaming::systems::system::System;
+use crate::streaming::topics::topic::TopicSettings;
 use crate::streaming::users::user_context::UserContext;
 use anyhow::Result;
 use iggy::error::Error;
@@ -35,6 +36,13 @@ pub async fn handle(
             &command.name,
             command.partitions_count,
             command.message_expiry,
+            TopicSettings {
+                cleanup_policy: command.cleanup_policy,
+                compaction_key: command.compaction_key.clone(),
+                max_topic_size_bytes: command.max_topic_size_bytes,
+                max_messages: command.max_messages,
+                dead_letter_topic_id: command.dead_letter_topic_id,
+            },
         )
         .await?;
     sender.send_empty_ok_response().await?;
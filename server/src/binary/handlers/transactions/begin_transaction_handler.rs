@@ -0,0 +1,31 @@
+use crate::binary::mapper;
+use crate::binary::sender::Sender;
+use crate::streaming::systems::system::System;
+use crate::streaming::users::user_context::UserContext;
+use anyhow::Result;
+use iggy::error::Error;
+use iggy::models::transaction_info::TransactionInfo;
+use iggy::transactions::begin_transaction::BeginTransaction;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+use tracing::trace;
+
+pub async fn handle(
+    command: &BeginTransaction,
+    sender: &mut dyn Sender,
+    user_context: &UserContext,
+    system: Arc<RwLock<System>>,
+) -> Result<(), Error> {
+    trace!("{command}");
+    if !user_context.is_authenticated() {
+        return Err(Error::Unauthenticated);
+    }
+
+    let system = system.read().await;
+    let transaction_id = system
+        .begin_transaction(user_context.user_id, Some(user_context.client_id))
+        .await?;
+    let bytes = mapper::map_transaction_info(&TransactionInfo { transaction_id });
+    sender.send_ok_response(bytes.as_slice()).await?;
+    Ok(())
+}
@@ -5,5 +5,9 @@ pub mod get_user_handler;
 pub mod get_users_handler;
 pub mod login_user_handler;
 pub mod logout_user_handler;
+pub mod scram_login_finish_handler;
+pub mod scram_login_start_handler;
+pub mod unlock_user_handler;
 pub mod update_permissions_handler;
+pub mod update_quotas_handler;
 pub mod update_user_handler;
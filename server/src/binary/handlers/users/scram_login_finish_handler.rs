@@ -0,0 +1,34 @@
+use crate::binary::sender::Sender;
+use crate::streaming::systems::system::System;
+use crate::streaming::users::user_context::UserContext;
+use iggy::error::Error;
+use iggy::users::scram_login_finish::ScramLoginFinish;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+use tracing::{info, trace};
+
+pub async fn handle(
+    command: &ScramLoginFinish,
+    sender: &mut dyn Sender,
+    user_context: &mut UserContext,
+    system: Arc<RwLock<System>>,
+) -> Result<(), Error> {
+    trace!("{command}");
+    let handshake = match user_context.take_scram_handshake() {
+        Some(handshake) => handshake,
+        None => return Err(Error::InvalidScramHandshake),
+    };
+    let system = system.read().await;
+    let (user, server_signature) = system
+        .finish_scram_login(
+            &handshake,
+            &command.nonce,
+            &command.client_proof,
+            Some(user_context.client_id),
+        )
+        .await?;
+    user_context.set_user_id(user.id);
+    info!("Set user ID: {}", user.id);
+    sender.send_ok_response(server_signature.as_slice()).await?;
+    Ok(())
+}
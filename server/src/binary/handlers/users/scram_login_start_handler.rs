@@ -0,0 +1,26 @@
+use crate::binary::mapper;
+use crate::binary::sender::Sender;
+use crate::streaming::systems::system::System;
+use crate::streaming::users::user_context::UserContext;
+use iggy::error::Error;
+use iggy::users::scram_login_start::ScramLoginStart;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+use tracing::trace;
+
+pub async fn handle(
+    command: &ScramLoginStart,
+    sender: &mut dyn Sender,
+    user_context: &mut UserContext,
+    system: Arc<RwLock<System>>,
+) -> Result<(), Error> {
+    trace!("{command}");
+    let system = system.read().await;
+    let (handshake, challenge) = system
+        .start_scram_login(&command.username, &command.client_nonce)
+        .await?;
+    user_context.set_scram_handshake(handshake);
+    let challenge = mapper::map_scram_challenge(&challenge);
+    sender.send_ok_response(challenge.as_slice()).await?;
+    Ok(())
+}
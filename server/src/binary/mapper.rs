@@ -1,9 +1,18 @@
 use bytes::BufMut;
+use iggy::models::consumer_group::ConsumerGroupAssignment;
+use iggy::models::consumer_lag::ConsumerLag;
 use iggy::models::consumer_offset_info::ConsumerOffsetInfo;
+use iggy::models::consumer_offset_reset::ConsumerOffsetReset;
+use iggy::models::producer_info::ProducerInfo;
+use iggy::models::quotas::Quotas;
+use iggy::models::scram_challenge::ScramChallenge;
+use iggy::models::transaction_info::TransactionInfo;
 
 use crate::streaming::clients::client_manager::{Client, Transport};
 use crate::streaming::models::messages::PolledMessages;
 use crate::streaming::partitions::partition::Partition;
+use crate::streaming::personal_access_tokens::personal_access_token::PersonalAccessToken;
+use crate::streaming::roles::role::Role;
 use crate::streaming::streams::stream::Stream;
 use crate::streaming::topics::consumer_group::ConsumerGroup;
 use crate::streaming::topics::topic::Topic;
@@ -51,7 +60,60 @@ pub fn map_consumer_offset(offset: &ConsumerOffsetInfo) -> Vec<u8> {
     bytes
 }
 
-pub async fn map_client(client: &Client) -> Vec<u8> {
+pub fn map_consumer_lags(lags: &[ConsumerLag]) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    for lag in lags {
+        bytes.put_u8(lag.consumer_kind.as_code());
+        bytes.put_u32_le(lag.consumer_id);
+        bytes.put_u32_le(lag.partitions.len() as u32);
+        for partition in &lag.partitions {
+            bytes.put_u32_le(partition.partition_id);
+            bytes.put_u64_le(partition.current_offset);
+            extend_optional_offset(partition.stored_offset, &mut bytes);
+            bytes.put_u64_le(partition.messages_lag);
+            bytes.put_u64_le(partition.time_lag);
+        }
+    }
+    bytes
+}
+
+pub fn map_consumer_offset_resets(resets: &[ConsumerOffsetReset]) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(30 * resets.len());
+    for reset in resets {
+        bytes.put_u32_le(reset.partition_id);
+        bytes.put_u64_le(reset.current_offset);
+        extend_optional_offset(reset.previous_offset, &mut bytes);
+        extend_optional_offset(reset.stored_offset, &mut bytes);
+    }
+    bytes
+}
+
+fn extend_optional_offset(offset: Option<u64>, bytes: &mut Vec<u8>) {
+    match offset {
+        Some(offset) => {
+            bytes.put_u8(1);
+            bytes.put_u64_le(offset);
+        }
+        None => {
+            bytes.put_u8(0);
+            bytes.put_u64_le(0);
+        }
+    }
+}
+
+pub fn map_producer_info(producer: &ProducerInfo) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(8);
+    bytes.put_u64_le(producer.producer_id);
+    bytes
+}
+
+pub fn map_transaction_info(transaction: &TransactionInfo) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(8);
+    bytes.put_u64_le(transaction.transaction_id);
+    bytes
+}
+
+pub async fn map_client(client: &Client, client_quotas: &Quotas, user_quotas: &Quotas) -> Vec<u8> {
     let mut bytes = Vec::new();
     extend_client(client, &mut bytes);
     for consumer_group in &client.consumer_groups {
@@ -59,6 +121,8 @@ pub async fn map_client(client: &Client) -> Vec<u8> {
         bytes.put_u32_le(consumer_group.topic_id);
         bytes.put_u32_le(consumer_group.stream_id);
     }
+    bytes.extend(client_quotas.as_bytes());
+    bytes.extend(user_quotas.as_bytes());
     bytes
 }
 
@@ -71,6 +135,16 @@ pub async fn map_clients(clients: &[Arc<RwLock<Client>>]) -> Vec<u8> {
     bytes
 }
 
+pub fn map_scram_challenge(challenge: &ScramChallenge) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(6 + challenge.nonce.len() + challenge.salt.len());
+    bytes.put_u8(challenge.nonce.len() as u8);
+    bytes.extend(challenge.nonce.as_bytes());
+    bytes.put_u8(challenge.salt.len() as u8);
+    bytes.extend(&challenge.salt);
+    bytes.put_u32_le(challenge.iterations);
+    bytes
+}
+
 pub fn map_user(user: &User) -> Vec<u8> {
     let mut bytes = Vec::new();
     extend_user(user, &mut bytes);
@@ -83,6 +157,14 @@ pub fn map_user(user: &User) -> Vec<u8> {
     } else {
         bytes.put_u32_le(0);
     }
+    if let Some(quotas) = &user.quotas {
+        bytes.put_u8(1);
+        bytes.extend(quotas.as_bytes());
+    } else {
+        bytes.put_u8(0);
+    }
+    bytes.put_u32_le(user.failed_login_attempts);
+    bytes.put_u64_le(user.locked_at.unwrap_or(0));
     bytes
 }
 
@@ -94,6 +176,44 @@ pub fn map_users(users: &[User]) -> Vec<u8> {
     bytes
 }
 
+pub fn map_raw_personal_access_token(token: &str) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(1 + token.len());
+    bytes.put_u8(token.len() as u8);
+    bytes.extend(token.as_bytes());
+    bytes
+}
+
+pub fn map_personal_access_tokens(personal_access_tokens: &[PersonalAccessToken]) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    for personal_access_token in personal_access_tokens {
+        bytes.put_u8(personal_access_token.name.len() as u8);
+        bytes.extend(personal_access_token.name.as_bytes());
+        bytes.put_u64_le(personal_access_token.expiry.unwrap_or(0));
+    }
+    bytes
+}
+
+pub fn map_role(role: &Role) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    extend_role(role, &mut bytes);
+    let permissions = role.permissions.as_bytes();
+    #[allow(clippy::cast_possible_truncation)]
+    bytes.put_u32_le(permissions.len() as u32);
+    bytes.extend(permissions);
+    for member in &role.members {
+        bytes.put_u32_le(*member);
+    }
+    bytes
+}
+
+pub fn map_roles(roles: &[Role]) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    for role in roles {
+        extend_role(role, &mut bytes);
+    }
+    bytes
+}
+
 pub fn map_polled_messages(polled_messages: &PolledMessages) -> Vec<u8> {
     let messages_count = polled_messages.messages.len() as u32;
     let messages_size = polled_messages
@@ -102,9 +222,25 @@ pub fn map_polled_messages(polled_messages: &PolledMessages) -> Vec<u8> {
         .map(|message| message.get_size_bytes())
         .sum::<u32>();
 
-    let mut bytes = Vec::with_capacity(20 + messages_size as usize);
+    let mut bytes = Vec::with_capacity(34 + messages_size as usize);
     bytes.put_u32_le(polled_messages.partition_id);
     bytes.put_u64_le(polled_messages.current_offset);
+    match polled_messages.scanned_offset {
+        Some(scanned_offset) => {
+            bytes.put_u8(1);
+            bytes.put_u64_le(scanned_offset);
+        }
+        None => {
+            bytes.put_u8(0);
+            bytes.put_u64_le(0);
+        }
+    }
+    bytes.put_u32_le(polled_messages.generation_id.unwrap_or(0));
+    bytes.put_u8(if polled_messages.assignment_changed {
+        1
+    } else {
+        0
+    });
     bytes.put_u32_le(messages_count);
     for message in polled_messages.messages.iter() {
         message.extend(&mut bytes);
@@ -155,6 +291,7 @@ pub async fn map_consumer_group(consumer_group: &ConsumerGroup) -> Vec<u8> {
     for member in members {
         let member = member.read().await;
         bytes.put_u32_le(member.id);
+        bytes.put_u64_le(member.last_heartbeat_at);
         let partitions = member.get_partitions();
         bytes.put_u32_le(partitions.len() as u32);
         for partition in partitions {
@@ -164,6 +301,18 @@ pub async fn map_consumer_group(consumer_group: &ConsumerGroup) -> Vec<u8> {
     bytes
 }
 
+pub fn map_consumer_group_assignment(assignment: &ConsumerGroupAssignment) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(16 + assignment.partitions.len() * 4);
+    bytes.put_u32_le(assignment.consumer_group_id);
+    bytes.put_u32_le(assignment.member_id);
+    bytes.put_u32_le(assignment.generation_id);
+    bytes.put_u32_le(assignment.partitions_count);
+    for partition_id in &assignment.partitions {
+        bytes.put_u32_le(*partition_id);
+    }
+    bytes
+}
+
 pub async fn map_consumer_groups(consumer_groups: &[&RwLock<ConsumerGroup>]) -> Vec<u8> {
     let mut bytes = Vec::new();
     for consumer_group in consumer_groups {
@@ -191,10 +340,21 @@ async fn extend_topic(topic: &Topic, bytes: &mut Vec<u8>) {
         Some(message_expiry) => bytes.put_u32_le(message_expiry),
         None => bytes.put_u32_le(0),
     };
+    bytes.put_u8(topic.cleanup_policy.as_code());
+    bytes.put_u64_le(topic.max_topic_size_bytes.unwrap_or(0));
+    bytes.put_u64_le(topic.max_messages.unwrap_or(0));
+    bytes.put_u32_le(topic.dead_letter_topic_id.unwrap_or(0));
     bytes.put_u64_le(topic.get_size_bytes().await);
     bytes.put_u64_le(topic.get_messages_count().await);
     bytes.put_u8(topic.name.len() as u8);
     bytes.extend(topic.name.as_bytes());
+    match &topic.compaction_key {
+        Some(compaction_key) => {
+            bytes.put_u8(compaction_key.as_str().len() as u8);
+            bytes.extend(compaction_key.as_str().as_bytes());
+        }
+        None => bytes.put_u8(0),
+    }
 }
 
 fn extend_partition(partition: &Partition, bytes: &mut Vec<u8>) {
@@ -210,6 +370,9 @@ fn extend_consumer_group(consumer_group: &ConsumerGroup, bytes: &mut Vec<u8>) {
     bytes.put_u32_le(consumer_group.id);
     bytes.put_u32_le(consumer_group.partitions_count);
     bytes.put_u32_le(consumer_group.get_members().len() as u32);
+    bytes.put_u8(consumer_group.assignment_strategy.as_code());
+    bytes.put_u32_le(consumer_group.session_timeout);
+    bytes.put_u32_le(consumer_group.generation_id);
 }
 
 fn extend_client(client: &Client, bytes: &mut Vec<u8>) {
@@ -233,3 +396,11 @@ fn extend_user(user: &User, bytes: &mut Vec<u8>) {
     bytes.put_u8(user.username.len() as u8);
     bytes.extend(user.username.as_bytes());
 }
+
+fn extend_role(role: &Role, bytes: &mut Vec<u8>) {
+    bytes.put_u32_le(role.id);
+    bytes.put_u64_le(role.created_at);
+    bytes.put_u32_le(role.members.len() as u32);
+    bytes.put_u8(role.name.len() as u8);
+    bytes.extend(role.name.as_bytes());
+}
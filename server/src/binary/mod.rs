@@ -2,3 +2,4 @@ pub mod command;
 mod handlers;
 mod mapper;
 pub mod sender;
+pub mod subscriptions;
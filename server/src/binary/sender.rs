@@ -7,4 +7,5 @@ pub trait Sender: Sync + Send {
     async fn send_empty_ok_response(&mut self) -> Result<(), Error>;
     async fn send_ok_response(&mut self, payload: &[u8]) -> Result<(), Error>;
     async fn send_error_response(&mut self, error: Error) -> Result<(), Error>;
+    async fn send_push(&mut self, payload: &[u8]) -> Result<(), Error>;
 }
@@ -0,0 +1,320 @@
+use crate::binary::mapper;
+use crate::streaming::polling_args::PollingArgs;
+use crate::streaming::polling_consumer::PollingConsumer;
+use crate::streaming::quotas::quota_manager::Throughput;
+use crate::streaming::systems::system::System;
+use crate::streaming::users::user_context::UserContext;
+use bytes::BufMut;
+use futures::future::select_all;
+use iggy::error::Error;
+use iggy::identifier::Identifier;
+use iggy::messages::poll_messages::{IsolationLevel, PollingStrategy};
+use iggy::models::messages::MessageState;
+use std::collections::{HashMap, HashSet};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use tokio::sync::{watch, RwLock};
+use tracing::{error, trace};
+
+const MAX_PUSHED_MESSAGES: u32 = 1000;
+
+#[derive(Debug, Default)]
+pub struct Subscriptions {
+    subscriptions: HashMap<u32, Subscription>,
+    pending: HashSet<u32>,
+}
+
+#[derive(Debug)]
+pub struct Subscription {
+    consumer: PollingConsumer,
+    stream_id: Identifier,
+    topic_id: Identifier,
+    strategy: PollingStrategy,
+    auto_commit: bool,
+    credits: u32,
+    isolation_level: IsolationLevel,
+    listeners: Vec<watch::Receiver<u64>>,
+    assignment_listener: Option<watch::Receiver<u32>>,
+}
+
+impl Subscription {
+    pub fn new(
+        consumer: PollingConsumer,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        strategy: PollingStrategy,
+        auto_commit: bool,
+        credits: u32,
+        listeners: Vec<watch::Receiver<u64>>,
+    ) -> Self {
+        Self {
+            consumer,
+            stream_id: Identifier::from_identifier(stream_id),
+            topic_id: Identifier::from_identifier(topic_id),
+            strategy,
+            auto_commit,
+            credits,
+            isolation_level: IsolationLevel::default(),
+            listeners,
+            assignment_listener: None,
+        }
+    }
+
+    pub fn with_isolation_level(mut self, isolation_level: IsolationLevel) -> Self {
+        self.isolation_level = isolation_level;
+        self
+    }
+
+    // The consumer group member is notified when its partitions are reassigned.
+    pub fn with_assignment_listener(mut self, assignment_listener: watch::Receiver<u32>) -> Self {
+        self.assignment_listener = Some(assignment_listener);
+        self
+    }
+}
+
+impl Subscriptions {
+    pub fn add(&mut self, subscription_id: u32, subscription: Subscription) -> Result<(), Error> {
+        if self.subscriptions.contains_key(&subscription_id) {
+            return Err(Error::SubscriptionAlreadyExists(subscription_id));
+        }
+
+        self.subscriptions.insert(subscription_id, subscription);
+        // The already available messages should be pushed without waiting for the new ones.
+        self.pending.insert(subscription_id);
+        Ok(())
+    }
+
+    pub fn grant_credits(&mut self, subscription_id: u32, credits: u32) -> Result<(), Error> {
+        let subscription = self.subscriptions.get_mut(&subscription_id);
+        if subscription.is_none() {
+            return Err(Error::SubscriptionNotFound(subscription_id));
+        }
+
+        let subscription = subscription.unwrap();
+        subscription.credits = subscription.credits.saturating_add(credits);
+        self.pending.insert(subscription_id);
+        Ok(())
+    }
+
+    pub fn remove(&mut self, subscription_id: u32) -> Result<(), Error> {
+        if self.subscriptions.remove(&subscription_id).is_none() {
+            return Err(Error::SubscriptionNotFound(subscription_id));
+        }
+
+        self.pending.remove(&subscription_id);
+        Ok(())
+    }
+
+    pub fn contains(&self, subscription_id: u32) -> bool {
+        self.subscriptions.contains_key(&subscription_id)
+    }
+
+    // Completes once any of the subscriptions having the credits might have the new messages to push.
+    // It's cancel safe, so it can be raced against reading the next request from the connection.
+    pub async fn wait_for_new_messages(&mut self) {
+        if !self.pending.is_empty() {
+            return;
+        }
+
+        // The listener without an index is notified about the consumer group member's assignment changes.
+        let mut listeners = Vec::new();
+        for (subscription_id, subscription) in self.subscriptions.iter_mut() {
+            if subscription.credits == 0 {
+                continue;
+            }
+
+            let subscription_id = *subscription_id;
+            for (index, listener) in subscription.listeners.iter_mut().enumerate() {
+                listeners.push(Box::pin(async move {
+                    let closed = listener.changed().await.is_err();
+                    (subscription_id, Some(index), closed)
+                })
+                    as Pin<Box<dyn Future<Output = (u32, Option<usize>, bool)> + Send>>);
+            }
+            if let Some(listener) = subscription.assignment_listener.as_mut() {
+                listeners.push(Box::pin(async move {
+                    let closed = listener.changed().await.is_err();
+                    (subscription_id, None, closed)
+                }));
+            }
+        }
+        if listeners.is_empty() {
+            return std::future::pending().await;
+        }
+
+        let ((subscription_id, index, closed), _, _) = select_all(listeners).await;
+        if closed {
+            // The partition or the consumer group has been deleted, so there's nothing more to wait for.
+            if let Some(subscription) = self.subscriptions.get_mut(&subscription_id) {
+                match index {
+                    Some(index) => {
+                        subscription.listeners.remove(index);
+                    }
+                    None => subscription.assignment_listener = None,
+                }
+            }
+        }
+        self.pending.insert(subscription_id);
+    }
+
+    // Returns the payloads to be pushed, each one prefixed with the subscription ID.
+    pub async fn poll_pending_messages(
+        &mut self,
+        system: &Arc<RwLock<System>>,
+        user_context: &UserContext,
+    ) -> Vec<Vec<u8>> {
+        let mut payloads = Vec::new();
+        for subscription_id in std::mem::take(&mut self.pending) {
+            let subscription = self.subscriptions.get_mut(&subscription_id);
+            if subscription.is_none() {
+                continue;
+            }
+
+            let subscription = subscription.unwrap();
+            match subscription.poll_messages(system, user_context).await {
+                Ok((payload, has_more)) => {
+                    if let Some(payload) = payload {
+                        let mut bytes = Vec::with_capacity(4 + payload.len());
+                        bytes.put_u32_le(subscription_id);
+                        bytes.extend(payload);
+                        payloads.push(bytes);
+                    }
+                    if has_more {
+                        self.pending.insert(subscription_id);
+                    }
+                }
+                // The subscription is polled again once the new messages arrive or the credits are granted.
+                Err(Error::QuotaExceeded(_)) => {
+                    trace!(
+                        "Quota exceeded when polling messages for subscription with ID: {}.",
+                        subscription_id
+                    );
+                }
+                Err(error) => {
+                    error!(
+                        "Failed to poll messages for subscription with ID: {}, it will be removed. {}",
+                        subscription_id, error
+                    );
+                    self.subscriptions.remove(&subscription_id);
+                }
+            }
+        }
+
+        payloads
+    }
+}
+
+impl Subscription {
+    // Returns the payload to be pushed (if any) and whether the next messages might be already available.
+    async fn poll_messages(
+        &mut self,
+        system: &Arc<RwLock<System>>,
+        user_context: &UserContext,
+    ) -> Result<(Option<Vec<u8>>, bool), Error> {
+        if self.credits == 0 {
+            return Ok((None, false));
+        }
+
+        // The user's permissions (e.g. the assigned roles) might have changed since subscribing.
+        {
+            let system = system.read().await;
+            let stream = system.get_stream(&self.stream_id)?;
+            let topic = stream.get_topic(&self.topic_id)?;
+            system.permissioner.poll_messages(
+                user_context.user_id,
+                stream.stream_id,
+                topic.topic_id,
+            )?;
+        }
+
+        System::acquire_quota(
+            system,
+            Throughput::Consume,
+            Some(user_context.client_id),
+            user_context.user_id,
+            0,
+            0,
+        )
+        .await?;
+
+        // The assignment might have changed, so the member waits only for its current partitions.
+        if let PollingConsumer::ConsumerGroup(_, _) = self.consumer {
+            self.listeners = system
+                .read()
+                .await
+                .subscribe_to_new_messages(self.consumer, &self.stream_id, &self.topic_id)
+                .await?;
+        }
+
+        // The consumer group member is assigned the partitions in the round robin manner,
+        // thus each one of them should be checked before waiting for the new messages.
+        let attempts = match self.consumer {
+            PollingConsumer::Consumer(_, _) => 1,
+            PollingConsumer::ConsumerGroup(_, _) => self.listeners.len().max(1),
+        };
+        for _ in 0..attempts {
+            let count = self.credits.min(MAX_PUSHED_MESSAGES);
+            let mut polled_messages = system
+                .read()
+                .await
+                .poll_messages(
+                    self.consumer,
+                    &self.stream_id,
+                    &self.topic_id,
+                    &PollingArgs::new(
+                        self.strategy,
+                        count,
+                        self.auto_commit,
+                        true,
+                        None,
+                        self.isolation_level,
+                        vec![],
+                    ),
+                )
+                .await?;
+            // The plain consumer keeps track of its own position, no matter the initial strategy.
+            if let (PollingConsumer::Consumer(_, _), Some(offset)) =
+                (self.consumer, polled_messages.scanned_offset)
+            {
+                self.strategy = PollingStrategy::offset(offset + 1);
+            }
+
+            if polled_messages.messages.is_empty() {
+                continue;
+            }
+
+            let has_more = polled_messages.messages.len() as u32 == count;
+            // The poisoned messages are skipped and don't consume the credits.
+            polled_messages
+                .messages
+                .retain(|message| message.state == MessageState::Available);
+            if polled_messages.messages.is_empty() {
+                return Ok((None, true));
+            }
+
+            system
+                .read()
+                .await
+                .record_quota_usage(
+                    Throughput::Consume,
+                    Some(user_context.client_id),
+                    user_context.user_id,
+                    polled_messages.get_size_bytes(),
+                    polled_messages.messages.len() as u64,
+                )
+                .await;
+            self.credits -= polled_messages.messages.len() as u32;
+            trace!(
+                "Pushing {} messages for {}, remaining credits: {}",
+                polled_messages.messages.len(),
+                self.consumer,
+                self.credits
+            );
+            let payload = mapper::map_polled_messages(&polled_messages);
+            return Ok((Some(payload), has_more && self.credits > 0));
+        }
+
+        Ok((None, false))
+    }
+}
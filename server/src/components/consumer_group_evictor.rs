@@ -0,0 +1,41 @@
+use crate::configs::server::ConsumerGroupEvictorConfig;
+use crate::streaming::systems::system::System;
+use iggy::utils::timestamp::TimeStamp;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::RwLock;
+use tokio::{task, time};
+use tracing::info;
+
+pub fn start(config: ConsumerGroupEvictorConfig, system: Arc<RwLock<System>>) {
+    if !config.enabled {
+        info!("Consumer group evictor is disabled.");
+        return;
+    }
+
+    if config.interval == 0 {
+        panic!("Consumer group evictor interval must be greater than 0.")
+    }
+
+    let duration = Duration::from_secs(config.interval);
+    task::spawn(async move {
+        let mut interval = time::interval(duration);
+        info!(
+            "Consumer group evictor is enabled, members with expired sessions will be evicted every: {:?}.",
+            duration
+        );
+        interval.tick().await;
+        loop {
+            interval.tick().await;
+            let system = system.read().await;
+            let now = TimeStamp::now().to_micros();
+            let evicted_members = system.evict_expired_consumer_group_members(now).await;
+            if evicted_members > 0 {
+                info!(
+                    "Evicted {} consumer group members with expired sessions.",
+                    evicted_members
+                );
+            }
+        }
+    });
+}
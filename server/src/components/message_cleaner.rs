@@ -3,6 +3,7 @@ use crate::streaming::systems::system::System;
 use crate::streaming::topics::topic::Topic;
 use iggy::error::Error;
 use iggy::utils::timestamp::TimeStamp;
+use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::Duration;
 use tokio::sync::RwLock;
@@ -41,12 +42,34 @@ pub fn start(config: MessageCleanerConfig, system: Arc<RwLock<System>>) {
                             topic.stream_id, topic.topic_id
                         );
                     }
+
+                    if delete_oversized_segments(topic).await.is_err() {
+                        error!(
+                            "Failed to delete segments exceeding the retention limits for stream ID: {}, topic ID: {}",
+                            topic.stream_id, topic.topic_id
+                        );
+                    }
+
+                    delete_expired_producers(topic, now).await;
                 }
             }
         }
     });
 }
 
+async fn delete_expired_producers(topic: &Topic, now: u64) {
+    for partition in topic.get_partitions() {
+        let mut partition = partition.write().await;
+        let deleted_producers = partition.delete_expired_producers(now);
+        if deleted_producers > 0 {
+            info!(
+                "Deleted {} expired producers for stream ID: {}, topic ID: {}, partition ID: {}",
+                deleted_producers, topic.stream_id, topic.topic_id, partition.partition_id
+            );
+        }
+    }
+}
+
 async fn delete_expired_segments(topic: &Topic, now: u64) -> Result<(), Error> {
     let expired_segments = topic
         .get_expired_segments_start_offsets_per_partition(now)
@@ -66,7 +89,29 @@ async fn delete_expired_segments(topic: &Topic, now: u64) -> Result<(), Error> {
         topic.topic_id
     );
 
-    for (partition_id, start_offsets) in &expired_segments {
+    delete_segments(topic, &expired_segments).await
+}
+
+async fn delete_oversized_segments(topic: &Topic) -> Result<(), Error> {
+    let oversized_segments = topic
+        .get_oversized_segments_start_offsets_per_partition()
+        .await;
+    if oversized_segments.is_empty() {
+        return Ok(());
+    }
+
+    info!(
+        "Found segments exceeding the retention limits in {} partitions for stream ID: {}, topic ID: {}, deleting...",
+        oversized_segments.len(),
+        topic.stream_id,
+        topic.topic_id
+    );
+
+    delete_segments(topic, &oversized_segments).await
+}
+
+async fn delete_segments(topic: &Topic, segments: &HashMap<u32, Vec<u64>>) -> Result<(), Error> {
+    for (partition_id, start_offsets) in segments {
         let partition = topic.get_partition(*partition_id);
         if partition.is_err() {
             error!(
@@ -0,0 +1,58 @@
+use crate::configs::server::MessageCompactorConfig;
+use crate::streaming::systems::system::System;
+use iggy::models::cleanup_policy::CleanupPolicy;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::RwLock;
+use tokio::{task, time};
+use tracing::{error, info, trace};
+
+pub fn start(config: MessageCompactorConfig, system: Arc<RwLock<System>>) {
+    if !config.enabled {
+        info!("Message compactor is disabled.");
+        return;
+    }
+
+    if config.interval == 0 {
+        panic!("Message compactor interval must be greater than 0.")
+    }
+
+    let duration = Duration::from_secs(config.interval);
+    task::spawn(async move {
+        let mut interval = time::interval(duration);
+        info!(
+            "Message compactor is enabled, topics with compact cleanup policy will be compacted every: {:?}.",
+            duration
+        );
+        interval.tick().await;
+        loop {
+            interval.tick().await;
+            let system = system.read().await;
+            let streams = system.get_streams();
+            for stream in streams {
+                let topics = stream.get_topics();
+                for topic in topics {
+                    if topic.cleanup_policy != CleanupPolicy::Compact {
+                        continue;
+                    }
+
+                    match topic.compact().await {
+                        Ok(0) => trace!(
+                            "No messages to compact for stream ID: {}, topic ID: {}",
+                            topic.stream_id,
+                            topic.topic_id
+                        ),
+                        Ok(compacted_messages) => info!(
+                            "Compacted {} messages for stream ID: {}, topic ID: {}",
+                            compacted_messages, topic.stream_id, topic.topic_id
+                        ),
+                        Err(error) => error!(
+                            "Failed to compact segments for stream ID: {}, topic ID: {}. Error: {}",
+                            topic.stream_id, topic.topic_id, error
+                        ),
+                    }
+                }
+            }
+        }
+    });
+}
@@ -0,0 +1,56 @@
+use crate::configs::server::MessageDelivererConfig;
+use crate::streaming::systems::system::System;
+use iggy::utils::timestamp::TimeStamp;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::RwLock;
+use tokio::{task, time};
+use tracing::{error, info, trace};
+
+pub fn start(config: MessageDelivererConfig, system: Arc<RwLock<System>>) {
+    if !config.enabled {
+        info!("Message deliverer is disabled.");
+        return;
+    }
+
+    if config.interval == 0 {
+        panic!("Message deliverer interval must be greater than 0.")
+    }
+
+    let duration = Duration::from_secs(config.interval);
+    task::spawn(async move {
+        let mut interval = time::interval(duration);
+        info!(
+            "Message deliverer is enabled, delayed messages will be delivered every: {:?}.",
+            duration
+        );
+        interval.tick().await;
+        loop {
+            interval.tick().await;
+            let system = system.read().await;
+            let now = TimeStamp::now().to_micros();
+            for stream in system.get_streams() {
+                for topic in stream.get_topics() {
+                    for partition in topic.get_partitions() {
+                        if !partition.read().await.has_due_delayed_messages(now) {
+                            continue;
+                        }
+
+                        let mut partition = partition.write().await;
+                        match partition.deliver_delayed_messages(now).await {
+                            Ok(0) => {}
+                            Ok(delivered_messages) => trace!(
+                                "Delivered {} delayed messages for stream ID: {}, topic ID: {}, partition ID: {}",
+                                delivered_messages, topic.stream_id, topic.topic_id, partition.partition_id
+                            ),
+                            Err(error) => error!(
+                                "Failed to deliver delayed messages for stream ID: {}, topic ID: {}, partition ID: {}. Error: {}",
+                                topic.stream_id, topic.topic_id, partition.partition_id, error
+                            ),
+                        }
+                    }
+                }
+            }
+        }
+    });
+}
@@ -0,0 +1,60 @@
+use crate::configs::server::MessageReencryptorConfig;
+use crate::streaming::systems::system::System;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::RwLock;
+use tokio::{task, time};
+use tracing::{error, info};
+
+pub fn start(config: MessageReencryptorConfig, system: Arc<RwLock<System>>) {
+    if !config.enabled {
+        info!("Message re-encryptor is disabled.");
+        return;
+    }
+
+    if config.interval == 0 {
+        panic!("Message re-encryptor interval must be greater than 0.")
+    }
+
+    let duration = Duration::from_secs(config.interval);
+    task::spawn(async move {
+        let mut interval = time::interval(duration);
+        info!(
+            "Message re-encryptor is enabled, closed segments will be re-encrypted with the current stream keys every: {:?}.",
+            duration
+        );
+        interval.tick().await;
+        loop {
+            interval.tick().await;
+            let system = system.read().await;
+            let key_ring = match system.key_ring {
+                Some(ref key_ring) => key_ring,
+                None => continue,
+            };
+
+            let streams = system.get_streams();
+            for stream in streams {
+                // The streams with their own key are re-encrypted with it instead of the active one.
+                let key_id = match system.get_stream_encryption(stream) {
+                    Some((key_id, _)) => key_id,
+                    None => continue,
+                };
+
+                let topics = stream.get_topics();
+                for topic in topics {
+                    match topic.reencrypt(key_ring, key_id).await {
+                        Ok(0) => {}
+                        Ok(reencrypted_messages) => info!(
+                            "Re-encrypted {} messages for stream ID: {}, topic ID: {}",
+                            reencrypted_messages, topic.stream_id, topic.topic_id
+                        ),
+                        Err(error) => error!(
+                            "Failed to re-encrypt segments for stream ID: {}, topic ID: {}. Error: {}",
+                            topic.stream_id, topic.topic_id, error
+                        ),
+                    }
+                }
+            }
+        }
+    });
+}
@@ -1,5 +1,10 @@
 pub mod channel;
 pub mod config_provider;
+pub mod consumer_group_evictor;
 pub mod logging;
 pub mod message_cleaner;
+pub mod message_compactor;
+pub mod message_deliverer;
+pub mod message_reencryptor;
 pub mod message_saver;
+pub mod transaction_aborter;
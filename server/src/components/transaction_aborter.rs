@@ -0,0 +1,42 @@
+use crate::configs::server::TransactionAborterConfig;
+use crate::streaming::systems::system::System;
+use iggy::utils::timestamp::TimeStamp;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::RwLock;
+use tokio::{task, time};
+use tracing::info;
+
+pub fn start(config: TransactionAborterConfig, system: Arc<RwLock<System>>) {
+    if !config.enabled {
+        info!("Transaction aborter is disabled.");
+        return;
+    }
+
+    if config.interval == 0 {
+        panic!("Transaction aborter interval must be greater than 0.")
+    }
+
+    if config.timeout == 0 {
+        panic!("Transaction aborter timeout must be greater than 0.")
+    }
+
+    let duration = Duration::from_secs(config.interval);
+    task::spawn(async move {
+        let mut interval = time::interval(duration);
+        info!(
+            "Transaction aborter is enabled, transactions older than: {} seconds will be aborted every: {:?}.",
+            config.timeout, duration
+        );
+        interval.tick().await;
+        loop {
+            interval.tick().await;
+            let system = system.read().await;
+            let now = TimeStamp::now().to_micros();
+            let aborted_transactions = system.abort_expired_transactions(now, config.timeout).await;
+            if aborted_transactions > 0 {
+                info!("Aborted {} expired transactions.", aborted_transactions);
+            }
+        }
+    });
+}
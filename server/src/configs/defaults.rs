@@ -1,18 +1,29 @@
-use crate::configs::http::{HttpConfig, HttpCorsConfig, HttpTlsConfig};
-use crate::configs::quic::{QuicCertificateConfig, QuicConfig};
-use crate::configs::server::{MessageCleanerConfig, MessageSaverConfig, ServerConfig};
+use crate::configs::http::{HttpConfig, HttpCorsConfig, HttpJwtConfig, HttpTlsConfig};
+use crate::configs::quic::{QuicCertificateConfig, QuicClientAuthConfig, QuicConfig};
+use crate::configs::server::{
+    ConsumerGroupEvictorConfig, MessageCleanerConfig, MessageCompactorConfig,
+    MessageDelivererConfig, MessageReencryptorConfig, MessageSaverConfig, ServerConfig,
+    TransactionAborterConfig,
+};
 use crate::configs::system::{
-    CacheConfig, DatabaseConfig, EncryptionConfig, LoggingConfig, PartitionConfig, SegmentConfig,
-    StreamConfig, SystemConfig, TopicConfig, UserConfig,
+    AuditConfig, CacheConfig, DatabaseConfig, EncryptionConfig, LockoutConfig, LoggingConfig,
+    PartitionConfig, PasswordPolicyConfig, QuotaConfig, SegmentConfig, StreamConfig, SystemConfig,
+    TopicConfig, UserConfig,
 };
 use crate::configs::tcp::{TcpConfig, TcpTlsConfig};
+use iggy::models::quotas::Quotas;
 use std::sync::Arc;
 
 impl Default for ServerConfig {
     fn default() -> ServerConfig {
         ServerConfig {
             message_cleaner: MessageCleanerConfig::default(),
+            message_compactor: MessageCompactorConfig::default(),
+            message_reencryptor: MessageReencryptorConfig::default(),
             message_saver: MessageSaverConfig::default(),
+            consumer_group_evictor: ConsumerGroupEvictorConfig::default(),
+            transaction_aborter: TransactionAborterConfig::default(),
+            message_deliverer: MessageDelivererConfig::default(),
             system: Arc::new(SystemConfig::default()),
             quic: QuicConfig::default(),
             tcp: TcpConfig::default(),
@@ -34,6 +45,7 @@ impl Default for QuicConfig {
             keep_alive_interval: 5000,
             max_idle_timeout: 10000,
             certificate: QuicCertificateConfig::default(),
+            client_auth: QuicClientAuthConfig::default(),
         }
     }
 }
@@ -48,6 +60,15 @@ impl Default for QuicCertificateConfig {
     }
 }
 
+impl Default for QuicClientAuthConfig {
+    fn default() -> QuicClientAuthConfig {
+        QuicClientAuthConfig {
+            enabled: false,
+            ca_file: "certs/iggy_ca_cert.pem".to_string(),
+        }
+    }
+}
+
 impl Default for TcpConfig {
     fn default() -> TcpConfig {
         TcpConfig {
@@ -64,11 +85,28 @@ impl Default for HttpConfig {
             enabled: true,
             address: "127.0.0.1:3000".to_string(),
             cors: HttpCorsConfig::default(),
+            jwt: HttpJwtConfig::default(),
             tls: HttpTlsConfig::default(),
         }
     }
 }
 
+impl Default for HttpJwtConfig {
+    fn default() -> HttpJwtConfig {
+        HttpJwtConfig {
+            algorithm: "HS256".to_string(),
+            issuer: "iggy".to_string(),
+            audience: "iggy".to_string(),
+            access_token_expiry: 3600,
+            refresh_token_expiry: 86400,
+            clock_skew: 5,
+            encoding_secret: "".to_string(),
+            decoding_secret: "".to_string(),
+            use_base64_secret: false,
+        }
+    }
+}
+
 impl Default for MessageCleanerConfig {
     fn default() -> MessageCleanerConfig {
         MessageCleanerConfig {
@@ -78,6 +116,24 @@ impl Default for MessageCleanerConfig {
     }
 }
 
+impl Default for MessageCompactorConfig {
+    fn default() -> MessageCompactorConfig {
+        MessageCompactorConfig {
+            enabled: true,
+            interval: 300,
+        }
+    }
+}
+
+impl Default for MessageReencryptorConfig {
+    fn default() -> MessageReencryptorConfig {
+        MessageReencryptorConfig {
+            enabled: true,
+            interval: 600,
+        }
+    }
+}
+
 impl Default for MessageSaverConfig {
     fn default() -> MessageSaverConfig {
         MessageSaverConfig {
@@ -88,6 +144,34 @@ impl Default for MessageSaverConfig {
     }
 }
 
+impl Default for ConsumerGroupEvictorConfig {
+    fn default() -> ConsumerGroupEvictorConfig {
+        ConsumerGroupEvictorConfig {
+            enabled: true,
+            interval: 5,
+        }
+    }
+}
+
+impl Default for MessageDelivererConfig {
+    fn default() -> MessageDelivererConfig {
+        MessageDelivererConfig {
+            enabled: true,
+            interval: 1,
+        }
+    }
+}
+
+impl Default for TransactionAborterConfig {
+    fn default() -> TransactionAborterConfig {
+        TransactionAborterConfig {
+            enabled: true,
+            interval: 5,
+            timeout: 60,
+        }
+    }
+}
+
 impl Default for SystemConfig {
     fn default() -> SystemConfig {
         SystemConfig {
@@ -101,6 +185,68 @@ impl Default for SystemConfig {
             partition: PartitionConfig::default(),
             segment: SegmentConfig::default(),
             user: UserConfig::default(),
+            audit: AuditConfig::default(),
+            quota: QuotaConfig::default(),
+        }
+    }
+}
+
+impl Default for EncryptionConfig {
+    fn default() -> EncryptionConfig {
+        EncryptionConfig {
+            enabled: false,
+            key: "".to_string(),
+            active_key_id: 1,
+            encrypt_headers: false,
+            keys: vec![],
+        }
+    }
+}
+
+impl Default for AuditConfig {
+    fn default() -> AuditConfig {
+        AuditConfig {
+            enabled: true,
+            path: "audit.log".to_string(),
+            max_file_size_bytes: 100 * 1024 * 1024,
+            stream: "".to_string(),
+        }
+    }
+}
+
+impl Default for PasswordPolicyConfig {
+    fn default() -> PasswordPolicyConfig {
+        PasswordPolicyConfig {
+            min_length: 3,
+            max_length: 100,
+            require_uppercase: false,
+            require_lowercase: false,
+            require_digit: false,
+            require_special_character: false,
+            disallow_username: true,
+        }
+    }
+}
+
+impl Default for LockoutConfig {
+    fn default() -> LockoutConfig {
+        LockoutConfig {
+            enabled: true,
+            max_failed_attempts: 5,
+            window: 300,
+            duration: 900,
+        }
+    }
+}
+
+impl Default for QuotaConfig {
+    fn default() -> QuotaConfig {
+        QuotaConfig {
+            enabled: true,
+            throttle: true,
+            max_throttle_delay_ms: 1000,
+            client: Quotas::default(),
+            user: Quotas::default(),
         }
     }
 }
@@ -156,6 +302,8 @@ impl Default for PartitionConfig {
             deduplicate_messages: false,
             enforce_fsync: false,
             validate_checksum: false,
+            producer_expiry: 604_800,
+            max_delivery_delay: 604_800,
         }
     }
 }
@@ -5,6 +5,7 @@ pub struct HttpConfig {
     pub enabled: bool,
     pub address: String,
     pub cors: HttpCorsConfig,
+    pub jwt: HttpJwtConfig,
     pub tls: HttpTlsConfig,
 }
 
@@ -15,6 +16,19 @@ pub struct HttpTlsConfig {
     pub key_file: String,
 }
 
+#[derive(Debug, Deserialize, Serialize)]
+pub struct HttpJwtConfig {
+    pub algorithm: String,
+    pub issuer: String,
+    pub audience: String,
+    pub access_token_expiry: u64,
+    pub refresh_token_expiry: u64,
+    pub clock_skew: u64,
+    pub encoding_secret: String,
+    pub decoding_secret: String,
+    pub use_base64_secret: bool,
+}
+
 #[derive(Debug, Deserialize, Serialize, Default)]
 pub struct HttpCorsConfig {
     pub enabled: bool,
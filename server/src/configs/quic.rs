@@ -12,6 +12,7 @@ pub struct QuicConfig {
     pub keep_alive_interval: u64,
     pub max_idle_timeout: u64,
     pub certificate: QuicCertificateConfig,
+    pub client_auth: QuicClientAuthConfig,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -20,3 +21,9 @@ pub struct QuicCertificateConfig {
     pub cert_file: String,
     pub key_file: String,
 }
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct QuicClientAuthConfig {
+    pub enabled: bool,
+    pub ca_file: String,
+}
@@ -11,7 +11,12 @@ use std::sync::Arc;
 #[derive(Debug, Deserialize, Serialize)]
 pub struct ServerConfig {
     pub message_cleaner: MessageCleanerConfig,
+    pub message_compactor: MessageCompactorConfig,
+    pub message_reencryptor: MessageReencryptorConfig,
     pub message_saver: MessageSaverConfig,
+    pub consumer_group_evictor: ConsumerGroupEvictorConfig,
+    pub transaction_aborter: TransactionAborterConfig,
+    pub message_deliverer: MessageDelivererConfig,
     pub system: Arc<SystemConfig>,
     pub quic: QuicConfig,
     pub tcp: TcpConfig,
@@ -24,6 +29,18 @@ pub struct MessageCleanerConfig {
     pub interval: u64,
 }
 
+#[derive(Debug, Deserialize, Serialize)]
+pub struct MessageCompactorConfig {
+    pub enabled: bool,
+    pub interval: u64,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct MessageReencryptorConfig {
+    pub enabled: bool,
+    pub interval: u64,
+}
+
 #[derive(Debug, Deserialize, Serialize)]
 pub struct MessageSaverConfig {
     pub enabled: bool,
@@ -31,6 +48,25 @@ pub struct MessageSaverConfig {
     pub interval: u64,
 }
 
+#[derive(Debug, Deserialize, Serialize)]
+pub struct ConsumerGroupEvictorConfig {
+    pub enabled: bool,
+    pub interval: u64,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct MessageDelivererConfig {
+    pub enabled: bool,
+    pub interval: u64,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct TransactionAborterConfig {
+    pub enabled: bool,
+    pub interval: u64,
+    pub timeout: u64,
+}
+
 impl ServerConfig {
     pub async fn load(config_provider: &dyn ConfigProvider) -> Result<ServerConfig, ServerError> {
         let server_config = config_provider.load_config().await?;
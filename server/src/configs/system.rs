@@ -1,3 +1,4 @@
+use iggy::models::quotas::Quotas;
 use serde::{Deserialize, Serialize};
 use std::fmt::{Display, Formatter};
 
@@ -13,6 +14,8 @@ pub struct SystemConfig {
     pub segment: SegmentConfig,
     pub encryption: EncryptionConfig,
     pub user: UserConfig,
+    pub audit: AuditConfig,
+    pub quota: QuotaConfig,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -33,16 +36,67 @@ pub struct CacheConfig {
     pub messages_amount: u32,
 }
 
-#[derive(Debug, Deserialize, Serialize, Default)]
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(default)]
 pub struct EncryptionConfig {
     pub enabled: bool,
     pub key: String,
+    pub active_key_id: u32,
+    pub encrypt_headers: bool,
+    pub keys: Vec<EncryptionKeyConfig>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct EncryptionKeyConfig {
+    pub id: u32,
+    pub key: String,
 }
 
 #[derive(Debug, Deserialize, Serialize, Default)]
 pub struct UserConfig {
     pub authentication_enabled: bool,
     pub authorization_enabled: bool,
+    #[serde(default)]
+    pub password_policy: PasswordPolicyConfig,
+    #[serde(default)]
+    pub lockout: LockoutConfig,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct PasswordPolicyConfig {
+    pub min_length: u32,
+    pub max_length: u32,
+    pub require_uppercase: bool,
+    pub require_lowercase: bool,
+    pub require_digit: bool,
+    pub require_special_character: bool,
+    pub disallow_username: bool,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct LockoutConfig {
+    pub enabled: bool,
+    pub max_failed_attempts: u32,
+    pub window: u64,
+    pub duration: u64,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(default)]
+pub struct AuditConfig {
+    pub enabled: bool,
+    pub path: String,
+    pub max_file_size_bytes: u64,
+    pub stream: String,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct QuotaConfig {
+    pub enabled: bool,
+    pub throttle: bool,
+    pub max_throttle_delay_ms: u64,
+    pub client: Quotas,
+    pub user: Quotas,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -62,6 +116,8 @@ pub struct PartitionConfig {
     pub deduplicate_messages: bool,
     pub enforce_fsync: bool,
     pub validate_checksum: bool,
+    pub producer_expiry: u32,
+    pub max_delivery_delay: u32,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -81,6 +137,10 @@ impl SystemConfig {
         format!("{}/{}", self.get_system_path(), self.database.path)
     }
 
+    pub fn get_audit_log_path(&self) -> String {
+        format!("{}/{}", self.get_system_path(), self.audit.path)
+    }
+
     pub fn get_streams_path(&self) -> String {
         format!("{}/{}", self.get_system_path(), self.stream.path)
     }
@@ -12,4 +12,11 @@ pub struct TcpTlsConfig {
     pub enabled: bool,
     pub certificate: String,
     pub password: String,
+    pub client_auth: TcpClientAuthConfig,
+}
+
+#[derive(Debug, Deserialize, Serialize, Default)]
+pub struct TcpClientAuthConfig {
+    pub enabled: bool,
+    pub ca_file: String,
 }
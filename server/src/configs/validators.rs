@@ -1,5 +1,5 @@
 use crate::configs::server::ServerConfig;
-use crate::configs::system::{CacheConfig, SegmentConfig};
+use crate::configs::system::{CacheConfig, LockoutConfig, PasswordPolicyConfig, SegmentConfig};
 use crate::configs::utils::is_power_of_two;
 use crate::server_error::ServerError;
 use crate::streaming::segments::segment;
@@ -10,6 +10,8 @@ impl Validatable<ServerError> for ServerConfig {
     fn validate(&self) -> Result<(), ServerError> {
         self.system.segment.validate()?;
         self.system.cache.validate()?;
+        self.system.user.password_policy.validate()?;
+        self.system.user.lockout.validate()?;
 
         Ok(())
     }
@@ -26,6 +28,28 @@ impl Validatable<ServerError> for CacheConfig {
     }
 }
 
+impl Validatable<ServerError> for PasswordPolicyConfig {
+    fn validate(&self) -> Result<(), ServerError> {
+        if self.min_length == 0 || self.min_length > self.max_length {
+            error!("Password policy configuration -> min length must be between 1 and max length.");
+            return Err(ServerError::InvalidConfiguration);
+        }
+
+        Ok(())
+    }
+}
+
+impl Validatable<ServerError> for LockoutConfig {
+    fn validate(&self) -> Result<(), ServerError> {
+        if self.enabled && self.max_failed_attempts == 0 {
+            error!("Lockout configuration -> max failed attempts must be greater than 0.");
+            return Err(ServerError::InvalidConfiguration);
+        }
+
+        Ok(())
+    }
+}
+
 impl Validatable<ServerError> for SegmentConfig {
     fn validate(&self) -> Result<(), ServerError> {
         if self.size_bytes > segment::MAX_SIZE_BYTES {
@@ -0,0 +1,66 @@
+use crate::http::error::ErrorCode;
+use crate::http::jwt::json_web_token::Identity;
+use crate::http::state::AppState;
+use crate::streaming::audit::audit_log::SUCCESS_RESULT;
+use axum::extract::{ConnectInfo, State};
+use axum::http::{Method, Request};
+use axum::middleware::Next;
+use axum::response::Response;
+use std::net::SocketAddr;
+use std::sync::Arc;
+
+// Data plane requests are not audited, as they would flood the audit log.
+const UNAUDITED_PATHS: &[(Method, &str)] = &[(Method::POST, "/messages")];
+
+// Set by the login handlers, as there is no identity available yet when the request is received.
+#[derive(Debug, Clone, Copy)]
+pub struct AuditUserId(pub u32);
+
+pub async fn audit<T>(
+    State(state): State<Arc<AppState>>,
+    request: Request<T>,
+    next: Next<T>,
+) -> Response {
+    if !is_audited(request.method(), request.uri().path()) {
+        return next.run(request).await;
+    }
+
+    let command = format!("{} {}", request.method(), request.uri().path());
+    let address = request
+        .extensions()
+        .get::<ConnectInfo<SocketAddr>>()
+        .map(|connect_info| connect_info.0);
+    let identity_user_id = request
+        .extensions()
+        .get::<Identity>()
+        .map(|identity| identity.user_id);
+    let response = next.run(request).await;
+    let user_id = match response.extensions().get::<AuditUserId>() {
+        Some(audit_user_id) => audit_user_id.0,
+        None => identity_user_id.unwrap_or_default(),
+    };
+    let result = match response.extensions().get::<ErrorCode>() {
+        Some(error_code) => error_code.0.to_string(),
+        None if response.status().is_success() => SUCCESS_RESULT.to_string(),
+        None => response.status().to_string(),
+    };
+
+    state
+        .system
+        .read()
+        .await
+        .audit_http_request(address.as_ref(), user_id, &command, &result)
+        .await;
+    response
+}
+
+fn is_audited(method: &Method, path: &str) -> bool {
+    if method == Method::GET || method == Method::HEAD || method == Method::OPTIONS {
+        return false;
+    }
+
+    let path = path.trim_end_matches('/');
+    !UNAUDITED_PATHS
+        .iter()
+        .any(|(unaudited_method, suffix)| method == unaudited_method && path.ends_with(suffix))
+}
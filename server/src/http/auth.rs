@@ -1,4 +0,0 @@
-// TODO: Resolve user ID from JWT
-pub fn resolve_user_id() -> u32 {
-    1
-}
@@ -1,26 +1,33 @@
 use crate::http::error::CustomError;
+use crate::http::jwt::json_web_token::Identity;
+use crate::http::state::AppState;
 use crate::streaming::polling_consumer::PollingConsumer;
-use crate::streaming::systems::system::System;
+use crate::streaming::topics::consumer_group::ConsumerGroupGeneration;
 use axum::extract::{Path, Query, State};
 use axum::http::StatusCode;
-use axum::routing::get;
-use axum::{Json, Router};
+use axum::routing::{get, post};
+use axum::{Extension, Json, Router};
+use iggy::consumer_offsets::get_consumer_lag::GetConsumerLag;
 use iggy::consumer_offsets::get_consumer_offset::GetConsumerOffset;
+use iggy::consumer_offsets::reset_consumer_offsets::ResetConsumerOffsets;
 use iggy::consumer_offsets::store_consumer_offset::StoreConsumerOffset;
 use iggy::identifier::Identifier;
+use iggy::models::consumer_lag::ConsumerLag;
 use iggy::models::consumer_offset_info::ConsumerOffsetInfo;
+use iggy::models::consumer_offset_reset::ConsumerOffsetReset;
 use iggy::validatable::Validatable;
 use std::sync::Arc;
-use tokio::sync::RwLock;
 
-pub fn router(system: Arc<RwLock<System>>) -> Router {
+pub fn router(state: Arc<AppState>) -> Router {
     Router::new()
         .route("/", get(get_consumer_offset).put(store_consumer_offset))
-        .with_state(system)
+        .route("/lag", get(get_consumer_lag))
+        .route("/reset", post(reset_consumer_offsets))
+        .with_state(state)
 }
 
 async fn get_consumer_offset(
-    State(system): State<Arc<RwLock<System>>>,
+    State(state): State<Arc<AppState>>,
     Path((stream_id, topic_id)): Path<(String, String)>,
     mut query: Query<GetConsumerOffset>,
 ) -> Result<Json<ConsumerOffsetInfo>, CustomError> {
@@ -29,7 +36,7 @@ async fn get_consumer_offset(
     query.validate()?;
 
     let consumer = PollingConsumer::Consumer(query.consumer.id, query.partition_id.unwrap_or(0));
-    let system = system.read().await;
+    let system = state.system.read().await;
     let stream = system.get_stream(&query.stream_id)?;
     let topic = stream.get_topic(&query.topic_id)?;
     let offset = topic.get_consumer_offset(consumer).await?;
@@ -37,8 +44,32 @@ async fn get_consumer_offset(
     Ok(Json(offset))
 }
 
+async fn get_consumer_lag(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path((stream_id, topic_id)): Path<(String, String)>,
+    mut query: Query<GetConsumerLag>,
+) -> Result<Json<Vec<ConsumerLag>>, CustomError> {
+    query.stream_id = Identifier::from_str_value(&stream_id)?;
+    query.topic_id = Identifier::from_str_value(&topic_id)?;
+    query.validate()?;
+
+    let system = state.system.read().await;
+    let stream = system.get_stream(&query.stream_id)?;
+    let topic = stream.get_topic(&query.topic_id)?;
+    system
+        .permissioner
+        .get_topic(identity.user_id, stream.stream_id, topic.topic_id)?;
+    let lags = match &query.consumer {
+        Some(consumer) => vec![topic.get_consumer_lag(consumer).await?],
+        None => topic.get_consumer_groups_lag().await?,
+    };
+    Ok(Json(lags))
+}
+
 async fn store_consumer_offset(
-    State(system): State<Arc<RwLock<System>>>,
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
     Path((stream_id, topic_id)): Path<(String, String)>,
     mut command: Json<StoreConsumerOffset>,
 ) -> Result<StatusCode, CustomError> {
@@ -48,11 +79,68 @@ async fn store_consumer_offset(
 
     let consumer =
         PollingConsumer::Consumer(command.consumer.id, command.partition_id.unwrap_or(0));
-    let system = system.read().await;
+    // The consumer kind is not part of the request body, and only the consumer group has a generation.
+    let generation = command
+        .generation_id
+        .map(|generation_id| ConsumerGroupGeneration {
+            consumer_group_id: command.consumer.id,
+            generation_id,
+        });
+    let system = state.system.read().await;
     let stream = system.get_stream(&command.stream_id)?;
     let topic = stream.get_topic(&command.topic_id)?;
-    topic
-        .store_consumer_offset(consumer, command.offset)
-        .await?;
+    if let Some(transaction_id) = command.transaction_id {
+        system
+            .store_transactional_consumer_offset(
+                identity.user_id,
+                transaction_id,
+                topic,
+                consumer,
+                command.offset,
+                generation,
+            )
+            .await?;
+        return Ok(StatusCode::NO_CONTENT);
+    }
+
+    match generation {
+        Some(generation) => {
+            topic
+                .store_consumer_offset_in_generation(consumer, command.offset, generation)
+                .await?
+        }
+        None => {
+            topic
+                .store_consumer_offset(consumer, command.offset)
+                .await?
+        }
+    }
     Ok(StatusCode::NO_CONTENT)
 }
+
+async fn reset_consumer_offsets(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path((stream_id, topic_id)): Path<(String, String)>,
+    mut command: Json<ResetConsumerOffsets>,
+) -> Result<Json<Vec<ConsumerOffsetReset>>, CustomError> {
+    command.stream_id = Identifier::from_str_value(&stream_id)?;
+    command.topic_id = Identifier::from_str_value(&topic_id)?;
+    command.validate()?;
+
+    let system = state.system.read().await;
+    let stream = system.get_stream(&command.stream_id)?;
+    let topic = stream.get_topic(&command.topic_id)?;
+    system
+        .permissioner
+        .poll_messages(identity.user_id, stream.stream_id, topic.topic_id)?;
+    let resets = topic
+        .reset_consumer_offsets(
+            &command.consumer,
+            command.partition_id,
+            command.strategy,
+            command.dry_run,
+        )
+        .await?;
+    Ok(Json(resets))
+}
@@ -1,6 +1,6 @@
 use axum::http::StatusCode;
 use axum::response::{IntoResponse, Response};
-use axum::Json;
+use axum::{Extension, Json};
 use iggy::error::Error;
 use serde::Serialize;
 use thiserror::Error;
@@ -11,6 +11,9 @@ pub enum CustomError {
     Error(#[from] Error),
 }
 
+#[derive(Debug, Clone, Copy)]
+pub struct ErrorCode(pub &'static str);
+
 #[derive(Debug, Serialize)]
 pub struct ErrorResponse {
     pub id: u32,
@@ -28,20 +31,35 @@ impl IntoResponse for CustomError {
                     Error::TopicIdNotFound(_, _) => StatusCode::NOT_FOUND,
                     Error::PartitionNotFound(_, _, _) => StatusCode::NOT_FOUND,
                     Error::SegmentNotFound => StatusCode::NOT_FOUND,
+                    Error::MessageNotFound(_, _) => StatusCode::NOT_FOUND,
                     Error::ClientNotFound(_) => StatusCode::NOT_FOUND,
                     Error::ConsumerGroupNotFound(_, _) => StatusCode::NOT_FOUND,
                     Error::ConsumerGroupMemberNotFound(_, _, _) => StatusCode::NOT_FOUND,
                     Error::ResourceNotFound(_) => StatusCode::NOT_FOUND,
+                    Error::TransactionNotFound(_) => StatusCode::NOT_FOUND,
+                    Error::PersonalAccessTokenNotFound(_, _) => StatusCode::NOT_FOUND,
+                    Error::RoleMemberNotFound(_, _) => StatusCode::NOT_FOUND,
                     Error::IoError(_) => StatusCode::INTERNAL_SERVER_ERROR,
                     Error::WriteError(_) => StatusCode::INTERNAL_SERVER_ERROR,
                     Error::CannotParseInt(_) => StatusCode::INTERNAL_SERVER_ERROR,
                     Error::CannotParseSlice(_) => StatusCode::INTERNAL_SERVER_ERROR,
                     Error::CannotParseUtf8(_) => StatusCode::INTERNAL_SERVER_ERROR,
                     Error::Unauthenticated => StatusCode::UNAUTHORIZED,
+                    Error::AccessTokenMissing => StatusCode::UNAUTHORIZED,
+                    Error::InvalidAccessToken => StatusCode::UNAUTHORIZED,
+                    Error::InvalidRefreshToken => StatusCode::UNAUTHORIZED,
+                    Error::InvalidPersonalAccessToken => StatusCode::UNAUTHORIZED,
+                    Error::PersonalAccessTokenExpired(_, _) => StatusCode::UNAUTHORIZED,
                     Error::Unauthorized => StatusCode::FORBIDDEN,
+                    Error::UserLocked(_) => StatusCode::FORBIDDEN,
+                    Error::QuotaExceeded(_) => StatusCode::TOO_MANY_REQUESTS,
                     _ => StatusCode::BAD_REQUEST,
                 };
-                (status_code, Json(ErrorResponse::from_error(error)))
+                (
+                    status_code,
+                    Extension(ErrorCode(error.as_string())),
+                    Json(ErrorResponse::from_error(error)),
+                )
             }
         }
         .into_response()
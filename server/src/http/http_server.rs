@@ -1,17 +1,25 @@
 use crate::configs::http::{HttpConfig, HttpCorsConfig};
+use crate::http::audit::audit;
+use crate::http::jwt::jwt_manager::JwtManager;
+use crate::http::jwt::middleware::jwt_auth;
+use crate::http::state::AppState;
 use crate::http::{
-    consumer_groups, consumer_offsets, messages, partitions, streams, system, topics, users,
+    consumer_groups, consumer_offsets, messages, partitions, personal_access_tokens, roles,
+    streams, system, topics, transactions, users,
 };
 use crate::streaming::systems::system::System;
 use axum::http::Method;
-use axum::Router;
+use axum::{middleware, Router};
 use axum_server::tls_rustls::RustlsConfig;
+use std::net::SocketAddr;
 use std::path::PathBuf;
 use std::sync::Arc;
 use tokio::sync::RwLock;
 use tower_http::cors::{AllowOrigin, CorsLayer};
 use tracing::info;
 
+const JWT_SECRET_NAME: &str = "jwt";
+
 pub async fn start(config: HttpConfig, system: Arc<RwLock<System>>) {
     let api_name = if config.tls.enabled {
         "HTTP API (TLS)"
@@ -19,29 +27,41 @@ pub async fn start(config: HttpConfig, system: Arc<RwLock<System>>) {
         "HTTP API"
     };
 
+    let app_state = build_app_state(&config, system).await;
     let mut app = Router::new().nest(
         "/",
-        system::router(system.clone())
-            .nest("/users", users::router(system.clone()))
+        system::router(app_state.clone())
+            .nest("/users", users::router(app_state.clone()))
+            .nest(
+                "/personal-access-tokens",
+                personal_access_tokens::router(app_state.clone()),
+            )
+            .nest("/roles", roles::router(app_state.clone()))
+            .nest("/transactions", transactions::router(app_state.clone()))
             .nest(
                 "/streams",
-                streams::router(system.clone()).nest(
+                streams::router(app_state.clone()).nest(
                     "/:stream_id/topics",
-                    topics::router(system.clone())
+                    topics::router(app_state.clone())
                         .nest(
                             "/:topic_id/consumer-groups",
-                            consumer_groups::router(system.clone()),
+                            consumer_groups::router(app_state.clone()),
                         )
-                        .nest("/:topic_id/messages", messages::router(system.clone()))
+                        .nest("/:topic_id/messages", messages::router(app_state.clone()))
                         .nest(
                             "/:topic_id/consumer-offsets",
-                            consumer_offsets::router(system.clone()),
+                            consumer_offsets::router(app_state.clone()),
                         )
-                        .nest("/:topic_id/partitions", partitions::router(system.clone())),
+                        .nest(
+                            "/:topic_id/partitions",
+                            partitions::router(app_state.clone()),
+                        ),
                 ),
             ),
     );
 
+    app = app.layer(middleware::from_fn_with_state(app_state.clone(), audit));
+    app = app.layer(middleware::from_fn_with_state(app_state.clone(), jwt_auth));
     if config.cors.enabled {
         app = app.layer(configure_cors(config.cors));
     }
@@ -50,7 +70,7 @@ pub async fn start(config: HttpConfig, system: Arc<RwLock<System>>) {
 
     if !config.tls.enabled {
         axum::Server::bind(&config.address.parse().unwrap())
-            .serve(app.into_make_service())
+            .serve(app.into_make_service_with_connect_info::<SocketAddr>())
             .await
             .unwrap();
         return;
@@ -64,11 +84,36 @@ pub async fn start(config: HttpConfig, system: Arc<RwLock<System>>) {
     .unwrap();
 
     axum_server::bind_rustls(config.address.parse().unwrap(), tls_config)
-        .serve(app.into_make_service())
+        .serve(app.into_make_service_with_connect_info::<SocketAddr>())
         .await
         .unwrap();
 }
 
+async fn build_app_state(config: &HttpConfig, system: Arc<RwLock<System>>) -> Arc<AppState> {
+    let generated_secret = system
+        .read()
+        .await
+        .storage
+        .info
+        .load_or_create_secret(JWT_SECRET_NAME)
+        .await;
+    if let Err(error) = generated_secret {
+        panic!("Failed to load JWT secret: {}", error);
+    }
+
+    let jwt_manager = JwtManager::from_config(&config.jwt, &generated_secret.unwrap());
+    if let Err(error) = jwt_manager {
+        panic!("Failed to initialize JWT manager: {}", error);
+    }
+
+    let authentication_enabled = system.read().await.config.user.authentication_enabled;
+    Arc::new(AppState {
+        jwt_manager: jwt_manager.unwrap(),
+        system,
+        authentication_enabled,
+    })
+}
+
 fn configure_cors(config: HttpCorsConfig) -> CorsLayer {
     let allowed_origins = match config.allowed_origins {
         origins if origins.is_empty() => AllowOrigin::default(),
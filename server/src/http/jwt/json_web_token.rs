@@ -0,0 +1,54 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone)]
+pub struct Identity {
+    pub token_id: String,
+    pub token_expiry: u64,
+    pub user_id: u32,
+}
+
+impl Identity {
+    // Used when the authentication is disabled and the request doesn't carry any access token.
+    pub fn anonymous() -> Self {
+        Self {
+            token_id: String::new(),
+            token_expiry: 0,
+            user_id: 0,
+        }
+    }
+
+    pub fn is_anonymous(&self) -> bool {
+        self.token_id.is_empty()
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct JwtClaims {
+    pub jti: String,
+    pub iss: String,
+    pub aud: String,
+    pub sub: u32,
+    pub iat: u64,
+    pub exp: u64,
+    pub nbf: u64,
+    #[serde(default)]
+    pub ver: u32,
+}
+
+#[derive(Debug)]
+pub struct GeneratedTokens {
+    pub user_id: u32,
+    pub access_token: String,
+    pub access_token_expiry: u64,
+    pub refresh_token: String,
+    pub refresh_token_expiry: u64,
+}
+
+#[derive(Debug)]
+pub struct RefreshToken {
+    pub user_id: u32,
+    pub token_version: u32,
+    pub access_token_id: String,
+    pub access_token_expiry: u64,
+    pub expiry: u64,
+}
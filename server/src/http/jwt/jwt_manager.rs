@@ -0,0 +1,291 @@
+use crate::configs::http::HttpJwtConfig;
+use crate::http::jwt::json_web_token::{GeneratedTokens, JwtClaims, RefreshToken};
+use crate::streaming::utils::hash;
+use iggy::error::Error;
+use iggy::utils::timestamp::TimeStamp;
+use jsonwebtoken::{encode, Algorithm, DecodingKey, EncodingKey, Header, TokenData, Validation};
+use std::collections::HashMap;
+use std::str::FromStr;
+use tokio::sync::RwLock;
+use tracing::{debug, error};
+use uuid::Uuid;
+
+pub struct IssuerOptions {
+    pub issuer: String,
+    pub audience: String,
+    pub access_token_expiry: u64,
+    pub refresh_token_expiry: u64,
+    pub key: EncodingKey,
+    pub algorithm: Algorithm,
+}
+
+pub struct ValidatorOptions {
+    pub key: DecodingKey,
+    pub validation: Validation,
+}
+
+pub struct JwtManager {
+    issuer: IssuerOptions,
+    validator: ValidatorOptions,
+    revoked_tokens: RwLock<HashMap<String, u64>>,
+    refresh_tokens: RwLock<HashMap<u128, RefreshToken>>,
+}
+
+impl JwtManager {
+    pub fn new(issuer: IssuerOptions, validator: ValidatorOptions) -> Self {
+        Self {
+            issuer,
+            validator,
+            revoked_tokens: RwLock::new(HashMap::new()),
+            refresh_tokens: RwLock::new(HashMap::new()),
+        }
+    }
+
+    // Without the configured secrets, the generated one is used, so the tokens can't be forged with a well-known key.
+    pub fn from_config(config: &HttpJwtConfig, generated_secret: &[u8]) -> Result<Self, Error> {
+        let algorithm = Algorithm::from_str(&config.algorithm)
+            .map_err(|_| Error::InvalidJwtAlgorithm(config.algorithm.clone()))?;
+        if !matches!(
+            algorithm,
+            Algorithm::HS256 | Algorithm::HS384 | Algorithm::HS512
+        ) {
+            error!(
+                "JWT algorithm: {} is not supported, use one of: HS256, HS384, HS512.",
+                config.algorithm
+            );
+            return Err(Error::InvalidJwtAlgorithm(config.algorithm.clone()));
+        }
+
+        if config.encoding_secret.is_empty() != config.decoding_secret.is_empty() {
+            error!(
+                "JWT encoding and decoding secrets must be either both configured or both empty."
+            );
+            return Err(Error::InvalidJwtSecret);
+        }
+
+        if config.encoding_secret.is_empty() && generated_secret.is_empty() {
+            error!("JWT generated secret cannot be empty.");
+            return Err(Error::InvalidJwtSecret);
+        }
+
+        let (encoding_key, decoding_key) = match config.use_base64_secret {
+            _ if config.encoding_secret.is_empty() => (
+                EncodingKey::from_secret(generated_secret),
+                DecodingKey::from_secret(generated_secret),
+            ),
+            true => (
+                EncodingKey::from_base64_secret(&config.encoding_secret)
+                    .map_err(|_| Error::InvalidJwtSecret)?,
+                DecodingKey::from_base64_secret(&config.decoding_secret)
+                    .map_err(|_| Error::InvalidJwtSecret)?,
+            ),
+            false => (
+                EncodingKey::from_secret(config.encoding_secret.as_bytes()),
+                DecodingKey::from_secret(config.decoding_secret.as_bytes()),
+            ),
+        };
+
+        let mut validation = Validation::new(algorithm);
+        validation.set_issuer(&[&config.issuer]);
+        validation.set_audience(&[&config.audience]);
+        validation.leeway = config.clock_skew;
+        validation.validate_nbf = true;
+
+        Ok(Self::new(
+            IssuerOptions {
+                issuer: config.issuer.clone(),
+                audience: config.audience.clone(),
+                access_token_expiry: config.access_token_expiry,
+                refresh_token_expiry: config.refresh_token_expiry,
+                key: encoding_key,
+                algorithm,
+            },
+            ValidatorOptions {
+                key: decoding_key,
+                validation,
+            },
+        ))
+    }
+
+    pub async fn generate(
+        &self,
+        user_id: u32,
+        token_version: u32,
+    ) -> Result<GeneratedTokens, Error> {
+        let now = TimeStamp::now().to_secs();
+        let access_token_expiry = now + self.issuer.access_token_expiry;
+        let claims = JwtClaims {
+            jti: Uuid::new_v4().to_string(),
+            iss: self.issuer.issuer.clone(),
+            aud: self.issuer.audience.clone(),
+            sub: user_id,
+            iat: now,
+            exp: access_token_expiry,
+            nbf: now,
+            ver: token_version,
+        };
+
+        let header = Header::new(self.issuer.algorithm);
+        let access_token = encode(&header, &claims, &self.issuer.key);
+        if let Err(err) = access_token {
+            error!("Cannot generate JWT for user with ID: {user_id}. Error: {err}");
+            return Err(Error::CannotGenerateJwt);
+        }
+
+        let refresh_token = format!("{}{}", Uuid::new_v4().simple(), Uuid::new_v4().simple());
+        let refresh_token_expiry = now + self.issuer.refresh_token_expiry;
+        self.refresh_tokens.write().await.insert(
+            hash::calculate(refresh_token.as_bytes()),
+            RefreshToken {
+                user_id,
+                token_version,
+                access_token_id: claims.jti,
+                access_token_expiry,
+                expiry: refresh_token_expiry,
+            },
+        );
+
+        Ok(GeneratedTokens {
+            user_id,
+            access_token: access_token.unwrap(),
+            access_token_expiry,
+            refresh_token,
+            refresh_token_expiry,
+        })
+    }
+
+    pub async fn decode(&self, token: &str) -> Result<TokenData<JwtClaims>, Error> {
+        let token_data = jsonwebtoken::decode::<JwtClaims>(
+            token,
+            &self.validator.key,
+            &self.validator.validation,
+        )
+        .map_err(|err| {
+            debug!("Cannot decode JWT. Error: {err}");
+            Error::InvalidAccessToken
+        })?;
+
+        if self
+            .revoked_tokens
+            .read()
+            .await
+            .contains_key(&token_data.claims.jti)
+        {
+            debug!("JWT with ID: {} was revoked.", token_data.claims.jti);
+            return Err(Error::InvalidAccessToken);
+        }
+
+        Ok(token_data)
+    }
+
+    // The refresh token can be used only once, its paired access token is revoked at the same time.
+    pub async fn consume_refresh_token(&self, refresh_token: &str) -> Result<RefreshToken, Error> {
+        let token = self
+            .refresh_tokens
+            .write()
+            .await
+            .remove(&hash::calculate(refresh_token.as_bytes()));
+        let token = token.ok_or(Error::InvalidRefreshToken)?;
+        if token.expiry <= TimeStamp::now().to_secs() {
+            return Err(Error::InvalidRefreshToken);
+        }
+
+        self.revoke_token(&token.access_token_id, token.access_token_expiry)
+            .await;
+        Ok(token)
+    }
+
+    pub async fn revoke_token(&self, token_id: &str, expiry: u64) {
+        let now = TimeStamp::now().to_secs();
+        let mut revoked_tokens = self.revoked_tokens.write().await;
+        let leeway = self.validator.validation.leeway;
+        revoked_tokens.retain(|_, token_expiry| *token_expiry + leeway > now);
+        revoked_tokens.insert(token_id.to_string(), expiry);
+        let mut refresh_tokens = self.refresh_tokens.write().await;
+        refresh_tokens.retain(|_, token| token.access_token_id != token_id && token.expiry > now);
+        debug!("Revoked JWT with ID: {token_id}.");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn generated_access_token_should_be_decoded() {
+        let jwt_manager = JwtManager::from_config(&HttpJwtConfig::default(), &[1; 32]).unwrap();
+        let tokens = jwt_manager.generate(1, 0).await.unwrap();
+        let token_data = jwt_manager.decode(&tokens.access_token).await.unwrap();
+
+        assert_eq!(token_data.claims.sub, 1);
+        assert_eq!(token_data.claims.ver, 0);
+        assert_eq!(token_data.claims.exp, tokens.access_token_expiry);
+        assert!(tokens.refresh_token_expiry > tokens.access_token_expiry);
+    }
+
+    #[tokio::test]
+    async fn revoked_access_token_should_not_be_decoded() {
+        let jwt_manager = JwtManager::from_config(&HttpJwtConfig::default(), &[1; 32]).unwrap();
+        let tokens = jwt_manager.generate(1, 0).await.unwrap();
+        let token_data = jwt_manager.decode(&tokens.access_token).await.unwrap();
+        jwt_manager
+            .revoke_token(&token_data.claims.jti, token_data.claims.exp)
+            .await;
+
+        let result = jwt_manager.decode(&tokens.access_token).await;
+        assert!(matches!(result, Err(Error::InvalidAccessToken)));
+        let result = jwt_manager
+            .consume_refresh_token(&tokens.refresh_token)
+            .await;
+        assert!(matches!(result, Err(Error::InvalidRefreshToken)));
+    }
+
+    #[tokio::test]
+    async fn refresh_token_should_be_consumed_only_once() {
+        let jwt_manager = JwtManager::from_config(&HttpJwtConfig::default(), &[1; 32]).unwrap();
+        let tokens = jwt_manager.generate(1, 0).await.unwrap();
+        let refresh_token = jwt_manager
+            .consume_refresh_token(&tokens.refresh_token)
+            .await
+            .unwrap();
+
+        assert_eq!(refresh_token.user_id, 1);
+        let result = jwt_manager.decode(&tokens.access_token).await;
+        assert!(matches!(result, Err(Error::InvalidAccessToken)));
+        let result = jwt_manager
+            .consume_refresh_token(&tokens.refresh_token)
+            .await;
+        assert!(matches!(result, Err(Error::InvalidRefreshToken)));
+    }
+
+    #[tokio::test]
+    async fn access_token_signed_with_another_generated_secret_should_not_be_decoded() {
+        let jwt_manager = JwtManager::from_config(&HttpJwtConfig::default(), &[1; 32]).unwrap();
+        let tokens = jwt_manager.generate(1, 0).await.unwrap();
+
+        let other_jwt_manager =
+            JwtManager::from_config(&HttpJwtConfig::default(), &[2; 32]).unwrap();
+        let result = other_jwt_manager.decode(&tokens.access_token).await;
+        assert!(matches!(result, Err(Error::InvalidAccessToken)));
+    }
+
+    #[test]
+    fn only_one_configured_secret_should_be_rejected() {
+        let config = HttpJwtConfig {
+            encoding_secret: "secret".to_string(),
+            ..Default::default()
+        };
+        let result = JwtManager::from_config(&config, &[1; 32]);
+        assert!(matches!(result, Err(Error::InvalidJwtSecret)));
+    }
+
+    #[test]
+    fn unsupported_algorithm_should_be_rejected() {
+        let config = HttpJwtConfig {
+            algorithm: "RS256".to_string(),
+            ..Default::default()
+        };
+        let result = JwtManager::from_config(&config, &[1; 32]);
+        assert!(matches!(result, Err(Error::InvalidJwtAlgorithm(_))));
+    }
+}
@@ -0,0 +1,58 @@
+use crate::http::error::CustomError;
+use crate::http::jwt::json_web_token::Identity;
+use crate::http::state::AppState;
+use axum::extract::State;
+use axum::http::header::AUTHORIZATION;
+use axum::http::Request;
+use axum::middleware::Next;
+use axum::response::Response;
+use iggy::error::Error;
+use std::sync::Arc;
+
+const BEARER: &str = "Bearer ";
+const UNAUTHORIZED_PATHS: &[&str] = &[
+    "/",
+    "/ping",
+    "/users/login",
+    "/users/refresh-token",
+    "/personal-access-tokens/login",
+];
+
+pub async fn jwt_auth<T>(
+    State(state): State<Arc<AppState>>,
+    mut request: Request<T>,
+    next: Next<T>,
+) -> Result<Response, CustomError> {
+    if UNAUTHORIZED_PATHS.contains(&request.uri().path()) {
+        return Ok(next.run(request).await);
+    }
+
+    let bearer = request
+        .headers()
+        .get(AUTHORIZATION)
+        .and_then(|value| value.to_str().ok());
+    let identity = match bearer {
+        Some(bearer) => {
+            let token = bearer
+                .strip_prefix(BEARER)
+                .ok_or(Error::InvalidAccessToken)?;
+            let token_data = state.jwt_manager.decode(token).await?;
+            state
+                .system
+                .read()
+                .await
+                .ensure_token_version(token_data.claims.sub, token_data.claims.ver)
+                .await?;
+            Identity {
+                token_id: token_data.claims.jti,
+                token_expiry: token_data.claims.exp,
+                user_id: token_data.claims.sub,
+            }
+        }
+        None if !state.authentication_enabled => Identity::anonymous(),
+        None => return Err(CustomError::Error(Error::AccessTokenMissing)),
+    };
+
+    request.extensions_mut().insert(identity);
+    Ok(next.run(request).await)
+}
@@ -0,0 +1,3 @@
+pub mod json_web_token;
+pub mod jwt_manager;
+pub mod middleware;
@@ -1,10 +1,17 @@
+use crate::http::jwt::json_web_token::GeneratedTokens;
 use crate::streaming::clients::client_manager::Client;
+use crate::streaming::personal_access_tokens::personal_access_token::PersonalAccessToken;
+use crate::streaming::roles::role::Role;
 use crate::streaming::streams::stream::Stream;
 use crate::streaming::topics::consumer_group::ConsumerGroup;
 use crate::streaming::topics::topic::Topic;
 use crate::streaming::users::user::User;
 use iggy::models::client_info::ConsumerGroupInfo;
 use iggy::models::consumer_group::{ConsumerGroupDetails, ConsumerGroupMember};
+use iggy::models::identity_info::{IdentityInfo, IdentityTokens, TokenInfo};
+use iggy::models::personal_access_token::PersonalAccessTokenInfo;
+use iggy::models::quotas::Quotas;
+use iggy::models::role_info::{RoleInfo, RoleInfoDetails};
 use iggy::models::stream::StreamDetails;
 use iggy::models::topic::TopicDetails;
 use iggy::models::user_info::{UserInfo, UserInfoDetails};
@@ -55,6 +62,14 @@ pub async fn map_topics(topics: &[&Topic]) -> Vec<iggy::models::topic::Topic> {
             partitions_count: topic.get_partitions().len() as u32,
             messages_count: topic.get_messages_count().await,
             message_expiry: topic.message_expiry,
+            cleanup_policy: topic.cleanup_policy,
+            compaction_key: topic
+                .compaction_key
+                .as_ref()
+                .map(|key| key.as_str().to_string()),
+            max_topic_size_bytes: topic.max_topic_size_bytes,
+            max_messages: topic.max_messages,
+            dead_letter_topic_id: topic.dead_letter_topic_id,
         };
         topics_data.push(topic);
     }
@@ -72,6 +87,14 @@ pub async fn map_topic(topic: &Topic) -> TopicDetails {
         partitions_count: topic.get_partitions().len() as u32,
         partitions: Vec::new(),
         message_expiry: topic.message_expiry,
+        cleanup_policy: topic.cleanup_policy,
+        compaction_key: topic
+            .compaction_key
+            .as_ref()
+            .map(|key| key.as_str().to_string()),
+        max_topic_size_bytes: topic.max_topic_size_bytes,
+        max_messages: topic.max_messages,
+        dead_letter_topic_id: topic.dead_letter_topic_id,
     };
     for partition in topic.get_partitions() {
         let partition = partition.read().await;
@@ -97,6 +120,9 @@ pub fn map_user(user: &User) -> UserInfoDetails {
         created_at: user.created_at,
         status: user.status,
         permissions: user.permissions.clone(),
+        quotas: user.quotas,
+        failed_login_attempts: user.failed_login_attempts,
+        locked_at: user.locked_at,
     }
 }
 
@@ -115,7 +141,52 @@ pub fn map_users(users: &[User]) -> Vec<UserInfo> {
     users_data
 }
 
-pub async fn map_client(client: &Client) -> iggy::models::client_info::ClientInfoDetails {
+pub fn map_role(role: &Role) -> RoleInfoDetails {
+    RoleInfoDetails {
+        id: role.id,
+        name: role.name.clone(),
+        created_at: role.created_at,
+        members_count: role.members.len() as u32,
+        permissions: role.permissions.clone(),
+        members: role.members.clone(),
+    }
+}
+
+pub fn map_roles(roles: &[Role]) -> Vec<RoleInfo> {
+    let mut roles_data = Vec::with_capacity(roles.len());
+    for role in roles {
+        let role = RoleInfo {
+            id: role.id,
+            name: role.name.clone(),
+            created_at: role.created_at,
+            members_count: role.members.len() as u32,
+        };
+        roles_data.push(role);
+    }
+    roles_data.sort_by_key(|role| role.id);
+    roles_data
+}
+
+pub fn map_personal_access_tokens(
+    personal_access_tokens: &[PersonalAccessToken],
+) -> Vec<PersonalAccessTokenInfo> {
+    let mut personal_access_tokens_data = Vec::with_capacity(personal_access_tokens.len());
+    for personal_access_token in personal_access_tokens {
+        let personal_access_token = PersonalAccessTokenInfo {
+            name: personal_access_token.name.clone(),
+            expiry: personal_access_token.expiry,
+        };
+        personal_access_tokens_data.push(personal_access_token);
+    }
+    personal_access_tokens_data.sort_by(|a, b| a.name.cmp(&b.name));
+    personal_access_tokens_data
+}
+
+pub async fn map_client(
+    client: &Client,
+    client_quotas: &Quotas,
+    user_quotas: &Quotas,
+) -> iggy::models::client_info::ClientInfoDetails {
     let client = iggy::models::client_info::ClientInfoDetails {
         client_id: client.client_id,
         user_id: client.user_id,
@@ -131,6 +202,8 @@ pub async fn map_client(client: &Client) -> iggy::models::client_info::ClientInf
                 consumer_group_id: consumer_group.consumer_group_id,
             })
             .collect(),
+        client_quotas: *client_quotas,
+        user_quotas: *user_quotas,
     };
     client
 }
@@ -165,6 +238,9 @@ pub async fn map_consumer_groups(
             id: consumer_group.id,
             partitions_count: consumer_group.partitions_count,
             members_count: consumer_group.get_members().len() as u32,
+            assignment_strategy: consumer_group.assignment_strategy,
+            session_timeout: consumer_group.session_timeout,
+            generation_id: consumer_group.generation_id,
         };
         groups.push(consumer_group);
     }
@@ -177,6 +253,9 @@ pub async fn map_consumer_group(consumer_group: &ConsumerGroup) -> ConsumerGroup
         id: consumer_group.id,
         partitions_count: consumer_group.partitions_count,
         members_count: consumer_group.get_members().len() as u32,
+        assignment_strategy: consumer_group.assignment_strategy,
+        session_timeout: consumer_group.session_timeout,
+        generation_id: consumer_group.generation_id,
         members: Vec::new(),
     };
     let members = consumer_group.get_members();
@@ -185,9 +264,26 @@ pub async fn map_consumer_group(consumer_group: &ConsumerGroup) -> ConsumerGroup
         let partitions = member.get_partitions();
         consumer_group_details.members.push(ConsumerGroupMember {
             id: member.id,
+            last_heartbeat_at: member.last_heartbeat_at,
             partitions_count: partitions.len() as u32,
             partitions,
         });
     }
     consumer_group_details
 }
+
+pub fn map_generated_tokens_to_identity_info(tokens: GeneratedTokens) -> IdentityInfo {
+    IdentityInfo {
+        user_id: tokens.user_id,
+        tokens: Some(IdentityTokens {
+            access_token: TokenInfo {
+                token: tokens.access_token,
+                expiry: tokens.access_token_expiry,
+            },
+            refresh_token: TokenInfo {
+                token: tokens.refresh_token,
+                expiry: tokens.refresh_token_expiry,
+            },
+        }),
+    }
+}
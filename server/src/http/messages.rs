@@ -1,27 +1,32 @@
-use crate::http::auth;
 use crate::http::error::CustomError;
+use crate::http::jwt::json_web_token::Identity;
+use crate::http::state::AppState;
 use crate::streaming;
+use crate::streaming::polling_args::PollingArgs;
 use crate::streaming::polling_consumer::PollingConsumer;
+use crate::streaming::quotas::quota_manager::Throughput;
 use crate::streaming::systems::system::System;
 use axum::extract::{Path, Query, State};
 use axum::http::StatusCode;
-use axum::routing::get;
-use axum::{Json, Router};
+use axum::routing::{get, put};
+use axum::{Extension, Json, Router};
 use iggy::identifier::Identifier;
+use iggy::messages::poison_message::PoisonMessage;
 use iggy::messages::poll_messages::PollMessages;
 use iggy::messages::send_messages::SendMessages;
 use iggy::validatable::Validatable;
 use std::sync::Arc;
-use tokio::sync::RwLock;
 
-pub fn router(system: Arc<RwLock<System>>) -> Router {
+pub fn router(state: Arc<AppState>) -> Router {
     Router::new()
         .route("/", get(poll_messages).post(send_messages))
-        .with_state(system)
+        .route("/poison", put(poison_message))
+        .with_state(state)
 }
 
 async fn poll_messages(
-    State(system): State<Arc<RwLock<System>>>,
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
     Path((stream_id, topic_id)): Path<(String, String)>,
     mut query: Query<PollMessages>,
 ) -> Result<Json<streaming::models::messages::PolledMessages>, CustomError> {
@@ -29,31 +34,60 @@ async fn poll_messages(
     query.topic_id = Identifier::from_str_value(&topic_id)?;
     query.validate()?;
 
-    let user_id = auth::resolve_user_id();
     let partition_id = query.partition_id.unwrap_or(0);
     let consumer = PollingConsumer::Consumer(query.consumer.id, partition_id);
-    let system = system.read().await;
-    let stream = system.get_stream(&query.stream_id)?;
-    let topic = stream.get_topic(&query.topic_id)?;
-    system
-        .permissioner
-        .poll_messages(user_id, stream.stream_id, topic.topic_id)?;
+    System::acquire_quota(
+        &state.system,
+        Throughput::Consume,
+        None,
+        identity.user_id,
+        0,
+        0,
+    )
+    .await?;
+    {
+        let system = state.system.read().await;
+        let stream = system.get_stream(&query.stream_id)?;
+        let topic = stream.get_topic(&query.topic_id)?;
+        system
+            .permissioner
+            .poll_messages(identity.user_id, stream.stream_id, topic.topic_id)?;
+    }
 
-    let polled_messages = system
-        .poll_messages(
-            consumer,
-            &query.stream_id,
-            &query.topic_id,
+    let polled_messages = System::poll_messages_with_wait(
+        &state.system,
+        consumer,
+        &query.stream_id,
+        &query.topic_id,
+        PollingArgs::new(
             query.strategy,
             query.count,
             query.auto_commit,
+            query.include_all_states,
+            query.max_wait_ms,
+            query.isolation_level,
+            query.filters.clone(),
+        ),
+    )
+    .await?;
+    state
+        .system
+        .read()
+        .await
+        .record_quota_usage(
+            Throughput::Consume,
+            None,
+            identity.user_id,
+            polled_messages.get_size_bytes(),
+            polled_messages.messages.len() as u64,
         )
-        .await?;
+        .await;
     Ok(Json(polled_messages))
 }
 
 async fn send_messages(
-    State(system): State<Arc<RwLock<System>>>,
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
     Path((stream_id, topic_id)): Path<(String, String)>,
     Json(mut command): Json<SendMessages>,
 ) -> Result<StatusCode, CustomError> {
@@ -62,20 +96,82 @@ async fn send_messages(
     command.partitioning.length = command.partitioning.value.len() as u8;
     command.validate()?;
 
-    let user_id = auth::resolve_user_id();
-    let system = system.read().await;
-    let stream = system.get_stream(&command.stream_id)?;
-    let topic = stream.get_topic(&command.topic_id)?;
-    system
-        .permissioner
-        .append_messages(user_id, stream.stream_id, topic.topic_id)?;
+    {
+        let system = state.system.read().await;
+        let stream = system.get_stream(&command.stream_id)?;
+        let topic = stream.get_topic(&command.topic_id)?;
+        system
+            .permissioner
+            .append_messages(identity.user_id, stream.stream_id, topic.topic_id)?;
+        if let Some(transaction_id) = command.transaction_id {
+            system
+                .verify_transaction(identity.user_id, transaction_id)
+                .await?;
+        }
+        system.validate_delivery_time(&command.messages)?;
+    }
+
+    // The quota is charged only for the messages which are allowed to be sent.
+    let bytes = command
+        .messages
+        .iter()
+        .map(|message| message.get_size_bytes() as u64)
+        .sum();
+    System::acquire_quota(
+        &state.system,
+        Throughput::Produce,
+        None,
+        identity.user_id,
+        bytes,
+        command.messages.len() as u64,
+    )
+    .await?;
+
+    let system = state.system.read().await;
     system
         .append_messages(
             &command.stream_id,
             &command.topic_id,
             &command.partitioning,
+            command.producer.as_ref(),
+            command.transaction_id,
             &command.messages,
         )
         .await?;
     Ok(StatusCode::CREATED)
 }
+
+async fn poison_message(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path((stream_id, topic_id)): Path<(String, String)>,
+    Json(mut command): Json<PoisonMessage>,
+) -> Result<StatusCode, CustomError> {
+    command.stream_id = Identifier::from_str_value(&stream_id)?;
+    command.topic_id = Identifier::from_str_value(&topic_id)?;
+    command.validate()?;
+
+    let system = state.system.read().await;
+    let stream = system.get_stream(&command.stream_id)?;
+    let topic = stream.get_topic(&command.topic_id)?;
+    system
+        .permissioner
+        .poll_messages(identity.user_id, stream.stream_id, topic.topic_id)?;
+    if let Some(dead_letter_topic_id) = topic.dead_letter_topic_id {
+        system.permissioner.append_messages(
+            identity.user_id,
+            stream.stream_id,
+            dead_letter_topic_id,
+        )?;
+    }
+
+    system
+        .poison_message(
+            &command.stream_id,
+            &command.topic_id,
+            command.partition_id,
+            command.offset,
+        )
+        .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -1,12 +1,17 @@
-pub mod auth;
+pub mod audit;
 pub mod consumer_groups;
 pub mod consumer_offsets;
 pub mod error;
 pub mod http_server;
+pub mod jwt;
 mod mapper;
 pub mod messages;
 pub mod partitions;
+pub mod personal_access_tokens;
+pub mod roles;
+pub mod state;
 pub mod streams;
 pub mod system;
 pub mod topics;
+pub mod transactions;
 pub mod users;
@@ -0,0 +1,120 @@
+use crate::http::error::CustomError;
+use crate::http::jwt::json_web_token::Identity;
+use crate::http::mapper;
+use crate::http::state::AppState;
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::routing::{get, post};
+use axum::{Extension, Json, Router};
+use iggy::identifier::Identifier;
+use iggy::models::role_info::{RoleInfo, RoleInfoDetails};
+use iggy::roles::create_role::CreateRole;
+use iggy::roles::update_role::UpdateRole;
+use iggy::validatable::Validatable;
+use std::sync::Arc;
+
+pub fn router(state: Arc<AppState>) -> Router {
+    Router::new()
+        .route("/", get(get_roles).post(create_role))
+        .route(
+            "/:role_id",
+            get(get_role).put(update_role).delete(delete_role),
+        )
+        .route(
+            "/:role_id/members/:user_id",
+            post(add_role_member).delete(remove_role_member),
+        )
+        .with_state(state)
+}
+
+async fn get_role(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path(role_id): Path<String>,
+) -> Result<Json<RoleInfoDetails>, CustomError> {
+    let role_id = Identifier::from_str_value(&role_id)?;
+    let system = state.system.read().await;
+    system.permissioner.get_role(identity.user_id)?;
+    let role = system.get_role(&role_id).await?;
+    let role = mapper::map_role(&role);
+    Ok(Json(role))
+}
+
+async fn get_roles(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Json<Vec<RoleInfo>>, CustomError> {
+    let system = state.system.read().await;
+    system.permissioner.get_roles(identity.user_id)?;
+    let roles = system.get_roles().await?;
+    let roles = mapper::map_roles(&roles);
+    Ok(Json(roles))
+}
+
+async fn create_role(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Json(command): Json<CreateRole>,
+) -> Result<StatusCode, CustomError> {
+    command.validate()?;
+    let mut system = state.system.write().await;
+    system.permissioner.create_role(identity.user_id)?;
+    system
+        .create_role(&command.name, command.permissions)
+        .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+async fn update_role(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path(role_id): Path<String>,
+    Json(mut command): Json<UpdateRole>,
+) -> Result<StatusCode, CustomError> {
+    command.role_id = Identifier::from_str_value(&role_id)?;
+    command.validate()?;
+    let mut system = state.system.write().await;
+    system.permissioner.update_role(identity.user_id)?;
+    system
+        .update_role(&command.role_id, command.permissions)
+        .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+async fn delete_role(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path(role_id): Path<String>,
+) -> Result<StatusCode, CustomError> {
+    let role_id = Identifier::from_str_value(&role_id)?;
+    let mut system = state.system.write().await;
+    system.permissioner.delete_role(identity.user_id)?;
+    system.delete_role(&role_id).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+async fn add_role_member(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path((role_id, user_id)): Path<(String, String)>,
+) -> Result<StatusCode, CustomError> {
+    let role_id = Identifier::from_str_value(&role_id)?;
+    let user_id = Identifier::from_str_value(&user_id)?;
+    let mut system = state.system.write().await;
+    system.permissioner.add_role_member(identity.user_id)?;
+    system.add_role_member(&role_id, &user_id).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+async fn remove_role_member(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path((role_id, user_id)): Path<(String, String)>,
+) -> Result<StatusCode, CustomError> {
+    let role_id = Identifier::from_str_value(&role_id)?;
+    let user_id = Identifier::from_str_value(&user_id)?;
+    let mut system = state.system.write().await;
+    system.permissioner.remove_role_member(identity.user_id)?;
+    system.remove_role_member(&role_id, &user_id).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -1,57 +1,96 @@
 use crate::http::error::CustomError;
+use crate::http::jwt::json_web_token::Identity;
 use crate::http::mapper;
-use crate::streaming::systems::system::System;
-use axum::extract::{Path, State};
-use axum::routing::get;
-use axum::{Json, Router};
+use crate::http::state::AppState;
+use axum::extract::{Path, Query, State};
+use axum::http::StatusCode;
+use axum::routing::{delete, get, post};
+use axum::{Extension, Json, Router};
 use iggy::models::client_info::{ClientInfo, ClientInfoDetails};
+use iggy::models::producer_info::ProducerInfo;
 use iggy::models::stats::Stats;
+use iggy::system::get_sessions::GetSessions;
 use std::sync::Arc;
-use tokio::sync::RwLock;
-
-use super::auth;
 
 const NAME: &str = "Iggy HTTP";
 const PONG: &str = "pong";
 
-pub fn router(system: Arc<RwLock<System>>) -> Router {
+pub fn router(state: Arc<AppState>) -> Router {
     Router::new()
         .route("/", get(|| async { NAME }))
         .route("/ping", get(|| async { PONG }))
         .route("/stats", get(get_stats))
         .route("/clients", get(get_clients))
         .route("/clients/:client_id", get(get_client))
-        .with_state(system)
+        .route("/sessions", get(get_sessions))
+        .route("/sessions/:client_id", delete(revoke_session))
+        .route("/producers", post(init_producer))
+        .with_state(state)
 }
 
-async fn get_stats(State(system): State<Arc<RwLock<System>>>) -> Result<Json<Stats>, CustomError> {
-    let user_id = auth::resolve_user_id();
-    let system = system.read().await;
-    system.permissioner.get_stats(user_id)?;
+async fn get_stats(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Json<Stats>, CustomError> {
+    let system = state.system.read().await;
+    system.permissioner.get_stats(identity.user_id)?;
     let stats = system.get_stats().await;
     Ok(Json(stats))
 }
 
 async fn get_client(
-    State(system): State<Arc<RwLock<System>>>,
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
     Path(client_id): Path<u32>,
 ) -> Result<Json<ClientInfoDetails>, CustomError> {
-    let user_id = auth::resolve_user_id();
-    let system = system.read().await;
-    system.permissioner.get_client(user_id)?;
+    let system = state.system.read().await;
+    system.permissioner.get_client(identity.user_id)?;
     let client = system.get_client(client_id).await?;
     let client = client.read().await;
-    let client = mapper::map_client(&client).await;
+    let user_quotas = system.get_user_quotas(client.user_id).await;
+    let client = mapper::map_client(&client, &system.get_client_quotas(), &user_quotas).await;
     Ok(Json(client))
 }
 
 async fn get_clients(
-    State(system): State<Arc<RwLock<System>>>,
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
 ) -> Result<Json<Vec<ClientInfo>>, CustomError> {
-    let user_id = auth::resolve_user_id();
-    let system = system.read().await;
-    system.permissioner.get_clients(user_id)?;
+    let system = state.system.read().await;
+    system.permissioner.get_clients(identity.user_id)?;
     let clients = system.get_clients().await;
     let clients = mapper::map_clients(&clients).await;
     Ok(Json(clients))
 }
+
+async fn get_sessions(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    query: Query<GetSessions>,
+) -> Result<Json<Vec<ClientInfo>>, CustomError> {
+    let system = state.system.read().await;
+    system.permissioner.get_sessions(identity.user_id)?;
+    let sessions = system.get_sessions(query.user_id).await;
+    let sessions = mapper::map_clients(&sessions).await;
+    Ok(Json(sessions))
+}
+
+async fn revoke_session(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path(client_id): Path<u32>,
+) -> Result<StatusCode, CustomError> {
+    let system = state.system.read().await;
+    system.permissioner.revoke_session(identity.user_id)?;
+    system.revoke_session(client_id).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+async fn init_producer(
+    State(state): State<Arc<AppState>>,
+    Extension(_identity): Extension<Identity>,
+) -> Result<Json<ProducerInfo>, CustomError> {
+    let system = state.system.read().await;
+    let producer_id = system.init_producer().await?;
+    Ok(Json(ProducerInfo { producer_id }))
+}
@@ -1,76 +1,79 @@
 use crate::http::error::CustomError;
-use crate::http::{auth, mapper};
-use crate::streaming::systems::system::System;
+use crate::http::jwt::json_web_token::Identity;
+use crate::http::mapper;
+use crate::http::state::AppState;
+use crate::streaming::topics::topic::TopicSettings;
 use axum::extract::{Path, State};
 use axum::http::StatusCode;
 use axum::routing::get;
-use axum::{Json, Router};
+use axum::{Extension, Json, Router};
 use iggy::identifier::Identifier;
 use iggy::models::topic::{Topic, TopicDetails};
 use iggy::topics::create_topic::CreateTopic;
 use iggy::topics::update_topic::UpdateTopic;
 use iggy::validatable::Validatable;
 use std::sync::Arc;
-use tokio::sync::RwLock;
 
-pub fn router(system: Arc<RwLock<System>>) -> Router {
+pub fn router(state: Arc<AppState>) -> Router {
     Router::new()
         .route("/", get(get_topics).post(create_topic))
         .route(
             "/:topic_id",
             get(get_topic).put(update_topic).delete(delete_topic),
         )
-        .with_state(system)
+        .with_state(state)
 }
 
 async fn get_topic(
-    State(system): State<Arc<RwLock<System>>>,
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
     Path((stream_id, topic_id)): Path<(String, String)>,
 ) -> Result<Json<TopicDetails>, CustomError> {
-    let user_id = auth::resolve_user_id();
-    let system = system.read().await;
+    let system = state.system.read().await;
     let stream_id = Identifier::from_str_value(&stream_id)?;
     let topic_id = Identifier::from_str_value(&topic_id)?;
     let stream = system.get_stream(&stream_id)?;
     let topic = stream.get_topic(&topic_id)?;
     system
         .permissioner
-        .get_topic(user_id, stream.stream_id, topic.topic_id)?;
+        .get_topic(identity.user_id, stream.stream_id, topic.topic_id)?;
     let topic = mapper::map_topic(topic).await;
     Ok(Json(topic))
 }
 
 async fn get_topics(
-    State(system): State<Arc<RwLock<System>>>,
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
     Path(stream_id): Path<String>,
 ) -> Result<Json<Vec<Topic>>, CustomError> {
-    let user_id = auth::resolve_user_id();
-    let system = system.read().await;
+    let system = state.system.read().await;
     let stream_id = Identifier::from_str_value(&stream_id)?;
     let stream = system.get_stream(&stream_id)?;
-    system.permissioner.get_topics(user_id, stream.stream_id)?;
+    system
+        .permissioner
+        .get_topics(identity.user_id, stream.stream_id)?;
     let topics = stream.get_topics();
     let topics = mapper::map_topics(&topics).await;
     Ok(Json(topics))
 }
 
 async fn create_topic(
-    State(system): State<Arc<RwLock<System>>>,
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
     Path(stream_id): Path<String>,
     Json(mut command): Json<CreateTopic>,
 ) -> Result<StatusCode, CustomError> {
     command.stream_id = Identifier::from_str_value(&stream_id)?;
     command.validate()?;
-    let user_id = auth::resolve_user_id();
     {
-        let system = system.read().await;
+        let system = state.system.read().await;
         let stream = system.get_stream(&command.stream_id)?;
         system
             .permissioner
-            .create_topic(user_id, stream.stream_id)?;
+            .create_topic(identity.user_id, stream.stream_id)?;
     }
 
-    let mut system = system.write().await;
+    let mut system = state.system.write().await;
     system
         .get_stream_mut(&command.stream_id)?
         .create_topic(
@@ -78,54 +81,72 @@ async fn create_topic(
             &command.name,
             command.partitions_count,
             command.message_expiry,
+            TopicSettings {
+                cleanup_policy: command.cleanup_policy,
+                compaction_key: command.compaction_key.clone(),
+                max_topic_size_bytes: command.max_topic_size_bytes,
+                max_messages: command.max_messages,
+                dead_letter_topic_id: command.dead_letter_topic_id,
+            },
         )
         .await?;
     Ok(StatusCode::CREATED)
 }
 
 async fn update_topic(
-    State(system): State<Arc<RwLock<System>>>,
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
     Path((stream_id, topic_id)): Path<(String, String)>,
     Json(mut command): Json<UpdateTopic>,
 ) -> Result<StatusCode, CustomError> {
     command.stream_id = Identifier::from_str_value(&stream_id)?;
     command.topic_id = Identifier::from_str_value(&topic_id)?;
     command.validate()?;
-    let user_id = auth::resolve_user_id();
     {
-        let system = system.read().await;
+        let system = state.system.read().await;
         let stream = system.get_stream(&command.stream_id)?;
         let topic = stream.get_topic(&command.topic_id)?;
         system
             .permissioner
-            .update_topic(user_id, stream.stream_id, topic.topic_id)?;
+            .update_topic(identity.user_id, stream.stream_id, topic.topic_id)?;
     }
 
-    let mut system = system.write().await;
+    let mut system = state.system.write().await;
     system
         .get_stream_mut(&command.stream_id)?
-        .update_topic(&command.topic_id, &command.name, command.message_expiry)
+        .update_topic(
+            &command.topic_id,
+            &command.name,
+            command.message_expiry,
+            TopicSettings {
+                cleanup_policy: command.cleanup_policy,
+                compaction_key: command.compaction_key.clone(),
+                max_topic_size_bytes: command.max_topic_size_bytes,
+                max_messages: command.max_messages,
+                dead_letter_topic_id: command.dead_letter_topic_id,
+            },
+        )
         .await?;
     Ok(StatusCode::NO_CONTENT)
 }
 
 async fn delete_topic(
-    State(system): State<Arc<RwLock<System>>>,
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
     Path((stream_id, topic_id)): Path<(String, String)>,
 ) -> Result<StatusCode, CustomError> {
     let stream_id = Identifier::from_str_value(&stream_id)?;
     let topic_id = Identifier::from_str_value(&topic_id)?;
-    let user_id = auth::resolve_user_id();
     {
-        let system = system.read().await;
+        let system = state.system.read().await;
         let stream = system.get_stream(&stream_id)?;
         let topic = stream.get_topic(&topic_id)?;
         system
             .permissioner
-            .delete_topic(user_id, stream.stream_id, topic.topic_id)?;
+            .delete_topic(identity.user_id, stream.stream_id, topic.topic_id)?;
     }
 
-    let mut system = system.write().await;
+    let mut system = state.system.write().await;
     system.delete_topic(&stream_id, &topic_id).await?;
     Ok(StatusCode::NO_CONTENT)
 }
@@ -0,0 +1,50 @@
+use crate::http::error::CustomError;
+use crate::http::jwt::json_web_token::Identity;
+use crate::http::state::AppState;
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::routing::post;
+use axum::{Extension, Json, Router};
+use iggy::models::transaction_info::TransactionInfo;
+use std::sync::Arc;
+
+pub fn router(state: Arc<AppState>) -> Router {
+    Router::new()
+        .route("/", post(begin_transaction))
+        .route("/:transaction_id/commit", post(commit_transaction))
+        .route("/:transaction_id/abort", post(abort_transaction))
+        .with_state(state)
+}
+
+async fn begin_transaction(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+) -> Result<Json<TransactionInfo>, CustomError> {
+    let system = state.system.read().await;
+    let transaction_id = system.begin_transaction(identity.user_id, None).await?;
+    Ok(Json(TransactionInfo { transaction_id }))
+}
+
+async fn commit_transaction(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path(transaction_id): Path<u64>,
+) -> Result<StatusCode, CustomError> {
+    let system = state.system.read().await;
+    system
+        .commit_transaction(identity.user_id, transaction_id)
+        .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+async fn abort_transaction(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path(transaction_id): Path<u64>,
+) -> Result<StatusCode, CustomError> {
+    let system = state.system.read().await;
+    system
+        .abort_transaction(identity.user_id, transaction_id)
+        .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
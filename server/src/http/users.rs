@@ -1,23 +1,28 @@
+use crate::http::audit::AuditUserId;
 use crate::http::error::CustomError;
-use crate::http::{auth, mapper};
-use crate::streaming::systems::system::System;
+use crate::http::jwt::json_web_token::Identity;
+use crate::http::mapper;
+use crate::http::state::AppState;
 use axum::extract::{Path, State};
 use axum::http::StatusCode;
 use axum::routing::{get, post, put};
-use axum::{Json, Router};
+use axum::{Extension, Json, Router};
+use iggy::error::Error;
 use iggy::identifier::Identifier;
+use iggy::models::identity_info::IdentityInfo;
 use iggy::models::user_info::{UserInfo, UserInfoDetails};
 use iggy::users::change_password::ChangePassword;
 use iggy::users::create_user::CreateUser;
 use iggy::users::login_user::LoginUser;
 use iggy::users::logout_user::LogoutUser;
+use iggy::users::refresh_token::RefreshToken;
 use iggy::users::update_permissions::UpdatePermissions;
+use iggy::users::update_quotas::UpdateQuotas;
 use iggy::users::update_user::UpdateUser;
 use iggy::validatable::Validatable;
 use std::sync::Arc;
-use tokio::sync::RwLock;
 
-pub fn router(system: Arc<RwLock<System>>) -> Router {
+pub fn router(state: Arc<AppState>) -> Router {
     Router::new()
         .route("/", get(get_users).post(create_user))
         .route(
@@ -25,46 +30,49 @@ pub fn router(system: Arc<RwLock<System>>) -> Router {
             get(get_user).put(update_user).delete(delete_user),
         )
         .route("/:user_id/permissions", put(update_permissions))
+        .route("/:user_id/quotas", put(update_quotas))
         .route("/:user_id/password", put(change_password))
+        .route("/:user_id/unlock", put(unlock_user))
         .route("/login", post(login_user))
         .route("/logout", post(logout_user))
-        .with_state(system)
+        .route("/refresh-token", post(refresh_token))
+        .with_state(state)
 }
 
 async fn get_user(
-    State(system): State<Arc<RwLock<System>>>,
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
     Path(user_id): Path<String>,
 ) -> Result<Json<UserInfoDetails>, CustomError> {
     let user_id = Identifier::from_str_value(&user_id)?;
-    let authenticated_user_id = auth::resolve_user_id();
-    let system = system.read().await;
+    let system = state.system.read().await;
     let user = system.get_user(&user_id).await?;
-    if user.id != authenticated_user_id {
-        system.permissioner.get_user(authenticated_user_id)?;
+    if user.id != identity.user_id {
+        system.permissioner.get_user(identity.user_id)?;
     }
     let user = mapper::map_user(&user);
     Ok(Json(user))
 }
 
 async fn get_users(
-    State(system): State<Arc<RwLock<System>>>,
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
 ) -> Result<Json<Vec<UserInfo>>, CustomError> {
-    let user_id = auth::resolve_user_id();
-    let system = system.read().await;
-    system.permissioner.get_users(user_id)?;
+    let system = state.system.read().await;
+    system.permissioner.get_users(identity.user_id)?;
     let users = system.get_users().await?;
     let users = mapper::map_users(&users);
     Ok(Json(users))
 }
 
 async fn create_user(
-    State(system): State<Arc<RwLock<System>>>,
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
     Json(command): Json<CreateUser>,
 ) -> Result<StatusCode, CustomError> {
     command.validate()?;
-    let user_id = auth::resolve_user_id();
-    let system = system.read().await;
-    system.permissioner.create_user(user_id)?;
+    let system = state.system.read().await;
+    system.permissioner.create_user(identity.user_id)?;
     system
         .create_user(
             &command.username,
@@ -76,15 +84,15 @@ async fn create_user(
 }
 
 async fn update_user(
-    State(system): State<Arc<RwLock<System>>>,
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
     Path(user_id): Path<String>,
     Json(mut command): Json<UpdateUser>,
 ) -> Result<StatusCode, CustomError> {
     command.user_id = Identifier::from_str_value(&user_id)?;
     command.validate()?;
-    let authenticated_user_id = auth::resolve_user_id();
-    let system = system.read().await;
-    system.permissioner.update_user(authenticated_user_id)?;
+    let system = state.system.read().await;
+    system.permissioner.update_user(identity.user_id)?;
     system
         .update_user(&command.user_id, command.username, command.status)
         .await?;
@@ -92,33 +100,47 @@ async fn update_user(
 }
 
 async fn update_permissions(
-    State(system): State<Arc<RwLock<System>>>,
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
     Path(user_id): Path<String>,
     Json(mut command): Json<UpdatePermissions>,
 ) -> Result<StatusCode, CustomError> {
     command.user_id = Identifier::from_str_value(&user_id)?;
     command.validate()?;
-    let authenticated_user_id = auth::resolve_user_id();
-    let mut system = system.write().await;
-    system
-        .permissioner
-        .update_permissions(authenticated_user_id)?;
+    let mut system = state.system.write().await;
+    system.permissioner.update_permissions(identity.user_id)?;
     system
         .update_permissions(&command.user_id, command.permissions)
         .await?;
     Ok(StatusCode::NO_CONTENT)
 }
 
+async fn update_quotas(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path(user_id): Path<String>,
+    Json(mut command): Json<UpdateQuotas>,
+) -> Result<StatusCode, CustomError> {
+    command.user_id = Identifier::from_str_value(&user_id)?;
+    command.validate()?;
+    let system = state.system.read().await;
+    system.permissioner.update_quotas(identity.user_id)?;
+    system
+        .update_quotas(&command.user_id, command.quotas)
+        .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
 async fn change_password(
-    State(system): State<Arc<RwLock<System>>>,
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
     Path(user_id): Path<String>,
     Json(mut command): Json<ChangePassword>,
 ) -> Result<StatusCode, CustomError> {
     command.user_id = Identifier::from_str_value(&user_id)?;
     command.validate()?;
-    let authenticated_user_id = auth::resolve_user_id();
-    let system = system.read().await;
-    system.permissioner.change_password(authenticated_user_id)?;
+    let system = state.system.read().await;
+    system.permissioner.change_password(identity.user_id)?;
     system
         .change_password(
             &command.user_id,
@@ -130,38 +152,92 @@ async fn change_password(
 }
 
 async fn delete_user(
-    State(system): State<Arc<RwLock<System>>>,
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
     Path(user_id): Path<String>,
 ) -> Result<StatusCode, CustomError> {
     let user_id = Identifier::from_str_value(&user_id)?;
-    let authenticated_user_id = auth::resolve_user_id();
-    let mut system = system.write().await;
-    system.permissioner.delete_user(authenticated_user_id)?;
+    let mut system = state.system.write().await;
+    system.permissioner.delete_user(identity.user_id)?;
     system.delete_user(&user_id).await?;
     Ok(StatusCode::NO_CONTENT)
 }
 
+async fn unlock_user(
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
+    Path(user_id): Path<String>,
+) -> Result<StatusCode, CustomError> {
+    let user_id = Identifier::from_str_value(&user_id)?;
+    let system = state.system.read().await;
+    system.permissioner.unlock_user(identity.user_id)?;
+    system.unlock_user(&user_id).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
 async fn login_user(
-    State(system): State<Arc<RwLock<System>>>,
+    State(state): State<Arc<AppState>>,
     Json(command): Json<LoginUser>,
-) -> Result<StatusCode, CustomError> {
+) -> Result<(Extension<AuditUserId>, Json<IdentityInfo>), CustomError> {
     command.validate()?;
-    let system = system.read().await;
-    system
+    let system = state.system.read().await;
+    let user = system
         .login_user(&command.username, &command.password, None)
         .await?;
-    // TODO: Return JWT
-    Ok(StatusCode::OK)
+    let tokens = state
+        .jwt_manager
+        .generate(user.id, user.token_version)
+        .await?;
+    Ok((
+        Extension(AuditUserId(user.id)),
+        Json(mapper::map_generated_tokens_to_identity_info(tokens)),
+    ))
 }
 
 async fn logout_user(
-    State(system): State<Arc<RwLock<System>>>,
+    State(state): State<Arc<AppState>>,
+    Extension(identity): Extension<Identity>,
     Json(command): Json<LogoutUser>,
 ) -> Result<StatusCode, CustomError> {
     command.validate()?;
-    let user_id = auth::resolve_user_id();
-    let system = system.read().await;
-    system.logout_user(user_id, None).await?;
-    // TODO: Clear JWT
+    let system = state.system.read().await;
+    system.logout_user(identity.user_id, None).await?;
+    if !identity.is_anonymous() {
+        state
+            .jwt_manager
+            .revoke_token(&identity.token_id, identity.token_expiry)
+            .await;
+    }
     Ok(StatusCode::NO_CONTENT)
 }
+
+async fn refresh_token(
+    State(state): State<Arc<AppState>>,
+    Json(command): Json<RefreshToken>,
+) -> Result<(Extension<AuditUserId>, Json<IdentityInfo>), CustomError> {
+    command.validate()?;
+    let refresh_token = state
+        .jwt_manager
+        .consume_refresh_token(&command.refresh_token)
+        .await?;
+    let system = state.system.read().await;
+    let user = system
+        .get_user(&Identifier::numeric(refresh_token.user_id)?)
+        .await?;
+    if !user.is_active() {
+        return Err(CustomError::Error(Error::UserInactive));
+    }
+
+    if user.token_version != refresh_token.token_version {
+        return Err(CustomError::Error(Error::InvalidRefreshToken));
+    }
+
+    let tokens = state
+        .jwt_manager
+        .generate(user.id, user.token_version)
+        .await?;
+    Ok((
+        Extension(AuditUserId(user.id)),
+        Json(mapper::map_generated_tokens_to_identity_info(tokens)),
+    ))
+}
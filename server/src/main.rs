@@ -11,7 +11,10 @@ mod tcp;
 
 use crate::args::Args;
 use crate::components::logging::Logging;
-use crate::components::{channel, config_provider, message_cleaner, message_saver};
+use crate::components::{
+    channel, config_provider, consumer_group_evictor, message_cleaner, message_compactor,
+    message_deliverer, message_reencryptor, message_saver, transaction_aborter,
+};
 use crate::configs::server::ServerConfig;
 use crate::http::http_server;
 use crate::quic::quic_server;
@@ -53,7 +56,12 @@ async fn main() -> Result<(), ServerError> {
     let system = Arc::new(RwLock::new(system));
     let (sender, receiver) = flume::unbounded::<ServerCommand>();
     message_cleaner::start(config.message_cleaner, system.clone());
+    message_compactor::start(config.message_compactor, system.clone());
+    message_deliverer::start(config.message_deliverer, system.clone());
+    message_reencryptor::start(config.message_reencryptor, system.clone());
     message_saver::start(config.message_saver, sender.clone());
+    consumer_group_evictor::start(config.consumer_group_evictor, system.clone());
+    transaction_aborter::start(config.transaction_aborter, system.clone());
     channel::start(system.clone(), receiver);
 
     #[cfg(unix)]
@@ -1,12 +1,17 @@
 use crate::binary::command;
+use crate::binary::subscriptions::Subscriptions;
 use crate::quic::quic_sender::QuicSender;
 use crate::server_error::ServerError;
 use crate::streaming::clients::client_manager::Transport;
 use crate::streaming::systems::system::System;
 use crate::streaming::users::user_context::UserContext;
+use crate::streaming::utils::certificates;
+use iggy::binary::subscriptions::PUSH_STATUS;
 use iggy::bytes_serializable::BytesSerializable;
 use iggy::command::Command;
-use quinn::Endpoint;
+use quinn::{Connection, Endpoint, SendStream};
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
 use std::sync::Arc;
 use tokio::sync::RwLock;
 use tracing::log::trace;
@@ -44,7 +49,7 @@ async fn handle_connection(
     let address = connection.remote_address();
     async {
         info!("Client has connected: {}", address);
-        let client_id = system
+        let (client_id, revocation) = system
             .read()
             .await
             .add_client(&address, Transport::Quic)
@@ -55,11 +60,46 @@ async fn handle_connection(
             if !system.config.user.authentication_enabled {
                 user_context.disable_authentication();
             }
+            let username = connection
+                .peer_identity()
+                .and_then(|identity| identity.downcast::<Vec<rustls::Certificate>>().ok())
+                .and_then(|certificates| {
+                    certificates.first().and_then(certificates::get_common_name)
+                });
+            if let Some(username) = username {
+                command::login_with_certificate(&username, &mut user_context, &system).await;
+            }
         }
 
+        let mut subscriptions = Subscriptions::default();
+        let mut push_streams = HashMap::new();
         loop {
-            let stream = connection.accept_bi().await;
-            let mut stream = match stream {
+            let stream = tokio::select! {
+                stream = connection.accept_bi() => Some(stream),
+                _ = subscriptions.wait_for_new_messages() => None,
+                _ = revocation.notified() => {
+                    info!("Session for client with ID: {client_id} has been revoked, closing the QUIC connection.");
+                    connection.close(0u32.into(), b"session revoked");
+                    system.read().await.delete_client(&address).await;
+                    return Ok(());
+                }
+            };
+            if stream.is_none() {
+                let result = push_messages(
+                    &connection,
+                    &mut subscriptions,
+                    &mut push_streams,
+                    &system,
+                    &user_context,
+                )
+                .await;
+                if result.is_err() {
+                    error!("Error when pushing the QUIC messages: {:?}", result.err());
+                }
+                continue;
+            }
+
+            let mut stream = match stream.unwrap() {
                 Err(quinn::ConnectionError::ApplicationClosed { .. }) => {
                     info!("Connection closed");
                     system.read().await.delete_client(&address).await;
@@ -114,9 +154,11 @@ async fn handle_connection(
                     recv: stream.1,
                 },
                 &mut user_context,
+                &mut subscriptions,
                 system.clone(),
             )
             .await;
+            push_streams.retain(|subscription_id, _| subscriptions.contains(*subscription_id));
             if result.is_err() {
                 error!("Error when handling the QUIC request: {:?}", result.err());
                 continue;
@@ -126,3 +168,29 @@ async fn handle_connection(
     .await?;
     Ok(())
 }
+
+// Each subscription has its own unidirectional stream, so the pushed messages don't interfere with the requests.
+async fn push_messages(
+    connection: &Connection,
+    subscriptions: &mut Subscriptions,
+    push_streams: &mut HashMap<u32, SendStream>,
+    system: &Arc<RwLock<System>>,
+    user_context: &UserContext,
+) -> Result<(), ServerError> {
+    for payload in subscriptions
+        .poll_pending_messages(system, user_context)
+        .await
+    {
+        let subscription_id = u32::from_le_bytes(payload[..4].try_into().unwrap());
+        let push_stream = match push_streams.entry(subscription_id) {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(connection.open_uni().await?),
+        };
+        let length = (payload.len() as u32).to_le_bytes();
+        push_stream
+            .write_all(&[&PUSH_STATUS.to_le_bytes(), &length, payload.as_slice()].concat())
+            .await?;
+    }
+    trace!("Pushed the messages for QUIC subscriptions.");
+    Ok(())
+}
@@ -38,6 +38,11 @@ impl Sender for QuicSender {
         self.send_response(&error.as_code().to_le_bytes(), &[])
             .await
     }
+
+    // The messages are pushed over the dedicated streams, see the QUIC listener.
+    async fn send_push(&mut self, _payload: &[u8]) -> Result<(), Error> {
+        Err(Error::FeatureUnavailable)
+    }
 }
 
 impl QuicSender {
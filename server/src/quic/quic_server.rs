@@ -1,11 +1,10 @@
 use crate::configs::quic::QuicConfig;
 use crate::quic::listener;
 use crate::streaming::systems::system::System;
+use crate::streaming::utils::certificates;
 use anyhow::Result;
 use quinn::{Endpoint, IdleTimeout, VarInt};
 use std::error::Error;
-use std::fs::File;
-use std::io::BufReader;
 use std::sync::Arc;
 use std::time::Duration;
 use tokio::sync::RwLock;
@@ -26,10 +25,25 @@ pub fn start(config: QuicConfig, system: Arc<RwLock<System>>) {
 fn configure_quic(config: &QuicConfig) -> Result<quinn::ServerConfig, Box<dyn Error>> {
     let (certificate, key) = match config.certificate.self_signed {
         true => generate_self_signed_cert()?,
-        false => load_certificates(&config.certificate.cert_file, &config.certificate.key_file)?,
+        false => (
+            certificates::load_certificates(&config.certificate.cert_file)?,
+            certificates::load_private_key(&config.certificate.key_file)?,
+        ),
     };
 
-    let mut server_config = quinn::ServerConfig::with_single_cert(certificate, key)?;
+    let mut server_config = match config.client_auth.enabled {
+        true => {
+            info!("QUIC client certificate authentication is enabled.");
+            let crypto = rustls::ServerConfig::builder()
+                .with_safe_defaults()
+                .with_client_cert_verifier(certificates::create_client_verifier(
+                    &config.client_auth.ca_file,
+                )?)
+                .with_single_cert(certificate, key)?;
+            quinn::ServerConfig::with_crypto(Arc::new(crypto))
+        }
+        false => quinn::ServerConfig::with_single_cert(certificate, key)?,
+    };
     let mut transport = quinn::TransportConfig::default();
     transport.initial_mtu(config.initial_mtu);
     transport.send_window(config.send_window);
@@ -58,18 +72,3 @@ fn generate_self_signed_cert(
     let cert_chain = vec![rustls::Certificate(certificate_der)];
     Ok((cert_chain, private_key))
 }
-
-fn load_certificates(
-    cert_file: &str,
-    key_file: &str,
-) -> Result<(Vec<rustls::Certificate>, rustls::PrivateKey), Box<dyn Error>> {
-    let mut cert_chain_reader = BufReader::new(File::open(cert_file)?);
-    let certs = rustls_pemfile::certs(&mut cert_chain_reader)?
-        .into_iter()
-        .map(rustls::Certificate)
-        .collect();
-    let mut key_reader = BufReader::new(File::open(key_file)?);
-    let mut keys = rustls_pemfile::rsa_private_keys(&mut key_reader)?;
-    let key = rustls::PrivateKey(keys.remove(0));
-    Ok((certs, key))
-}
@@ -0,0 +1,227 @@
+use iggy::utils::timestamp::TimeStamp;
+use serde::{Deserialize, Serialize};
+use tokio::fs::{rename, File, OpenOptions};
+use tokio::io::AsyncWriteExt;
+use tokio::sync::Mutex;
+use tracing::{error, info};
+
+pub const SUCCESS_RESULT: &str = "ok";
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct AuditRecord {
+    pub timestamp: u64,
+    pub user_id: u32,
+    pub client_address: String,
+    pub transport: String,
+    pub command: String,
+    pub result: String,
+}
+
+impl AuditRecord {
+    pub fn new(
+        user_id: u32,
+        client_address: &str,
+        transport: &str,
+        command: &str,
+        result: &str,
+    ) -> Self {
+        Self {
+            timestamp: TimeStamp::now().to_micros(),
+            user_id,
+            client_address: client_address.to_string(),
+            transport: transport.to_string(),
+            command: command.to_string(),
+            result: result.to_string(),
+        }
+    }
+}
+
+// The records are appended as JSON lines and synced to disk, the file is opened on the first record and never truncated.
+// Once it exceeds the max size, it's renamed with the timestamp suffix and the new one is started.
+#[derive(Debug)]
+pub struct AuditLog {
+    enabled: bool,
+    path: String,
+    max_file_size_bytes: u64,
+    file: Mutex<Option<AuditFile>>,
+}
+
+#[derive(Debug)]
+struct AuditFile {
+    file: File,
+    size_bytes: u64,
+}
+
+impl AuditLog {
+    pub fn new(enabled: bool, path: &str, max_file_size_bytes: u64) -> Self {
+        Self {
+            enabled,
+            path: path.to_string(),
+            max_file_size_bytes,
+            file: Mutex::new(None),
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    pub async fn append(&self, record: &AuditRecord) {
+        if !self.enabled {
+            return;
+        }
+
+        let mut line = match serde_json::to_vec(record) {
+            Ok(line) => line,
+            Err(err) => {
+                error!(
+                    "Cannot serialize audit record: {:?}. Error: {}",
+                    record, err
+                );
+                return;
+            }
+        };
+        line.push(b'\n');
+
+        let mut file = self.file.lock().await;
+        if let Some(opened_file) = file.as_ref() {
+            if self.max_file_size_bytes > 0
+                && opened_file.size_bytes + line.len() as u64 > self.max_file_size_bytes
+            {
+                file.take();
+                self.rotate().await;
+            }
+        }
+        if file.is_none() {
+            match self.open().await {
+                Some(opened_file) => {
+                    file.replace(opened_file);
+                }
+                None => return,
+            }
+        }
+        let file = file.as_mut().unwrap();
+
+        if let Err(err) = Self::write(&mut file.file, &line).await {
+            error!("Cannot store audit record: {:?}. Error: {}", record, err);
+            return;
+        }
+        file.size_bytes += line.len() as u64;
+    }
+
+    async fn open(&self) -> Option<AuditFile> {
+        let file = match OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .await
+        {
+            Ok(file) => file,
+            Err(err) => {
+                error!("Cannot open audit log at: {}. Error: {}", self.path, err);
+                return None;
+            }
+        };
+        let size_bytes = match file.metadata().await {
+            Ok(metadata) => metadata.len(),
+            Err(err) => {
+                error!(
+                    "Cannot read audit log metadata at: {}. Error: {}",
+                    self.path, err
+                );
+                return None;
+            }
+        };
+        info!("Opened audit log at: {}", self.path);
+        Some(AuditFile { file, size_bytes })
+    }
+
+    async fn rotate(&self) {
+        let rotated_path = format!("{}.{}", self.path, TimeStamp::now().to_micros());
+        match rename(&self.path, &rotated_path).await {
+            Ok(_) => info!("Rotated audit log at: {} to: {}", self.path, rotated_path),
+            Err(err) => error!(
+                "Cannot rotate audit log at: {} to: {}. Error: {}",
+                self.path, rotated_path, err
+            ),
+        }
+    }
+
+    async fn write(file: &mut File, line: &[u8]) -> Result<(), std::io::Error> {
+        file.write_all(line).await?;
+        file.flush().await?;
+        file.sync_data().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn records_should_be_appended_to_the_audit_log_as_json_lines() {
+        let path =
+            std::env::temp_dir().join(format!("iggy_audit_{}.log", TimeStamp::now().to_micros()));
+        let path = path.to_str().unwrap();
+        let records = vec![
+            AuditRecord::new(
+                0,
+                "127.0.0.1:1234",
+                "TCP",
+                "user.login|iggy",
+                "invalid_credentials",
+            ),
+            AuditRecord::new(
+                1,
+                "127.0.0.1:1234",
+                "TCP",
+                "stream.delete|1",
+                SUCCESS_RESULT,
+            ),
+        ];
+        let audit_log = AuditLog::new(true, path, 0);
+        audit_log.append(&records[0]).await;
+
+        let audit_log = AuditLog::new(true, path, 0);
+        audit_log.append(&records[1]).await;
+
+        let content = tokio::fs::read_to_string(path).await.unwrap();
+        tokio::fs::remove_file(path).await.unwrap();
+        let stored_records = content
+            .lines()
+            .map(|line| serde_json::from_str::<AuditRecord>(line).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(stored_records, records);
+    }
+
+    #[tokio::test]
+    async fn audit_log_should_be_rotated_once_it_exceeds_the_max_size() {
+        let directory =
+            std::env::temp_dir().join(format!("iggy_audit_{}", TimeStamp::now().to_micros()));
+        tokio::fs::create_dir(&directory).await.unwrap();
+        let path = directory.join("audit.log");
+        let path = path.to_str().unwrap();
+        let record = AuditRecord::new(
+            1,
+            "127.0.0.1:1234",
+            "TCP",
+            "stream.delete|1",
+            SUCCESS_RESULT,
+        );
+        let record_size = serde_json::to_vec(&record).unwrap().len() as u64 + 1;
+        let audit_log = AuditLog::new(true, path, 2 * record_size);
+        for _ in 0..3 {
+            audit_log.append(&record).await;
+        }
+
+        let mut entries = tokio::fs::read_dir(&directory).await.unwrap();
+        let mut files = Vec::new();
+        while let Some(entry) = entries.next_entry().await.unwrap() {
+            files.push(entry.path());
+        }
+        let content = tokio::fs::read_to_string(path).await.unwrap();
+        tokio::fs::remove_dir_all(&directory).await.unwrap();
+        assert_eq!(files.len(), 2);
+        assert_eq!(content.lines().count(), 1);
+    }
+}
@@ -4,7 +4,7 @@ use std::collections::HashMap;
 use std::fmt::{Display, Formatter};
 use std::net::SocketAddr;
 use std::sync::Arc;
-use tokio::sync::RwLock;
+use tokio::sync::{Notify, RwLock};
 
 #[derive(Debug, Default)]
 pub struct ClientManager {
@@ -18,6 +18,7 @@ pub struct Client {
     pub address: SocketAddr,
     pub transport: Transport,
     pub consumer_groups: Vec<ConsumerGroup>,
+    pub revocation: Arc<Notify>,
 }
 
 #[derive(Debug)]
@@ -43,18 +44,21 @@ impl Display for Transport {
 }
 
 impl ClientManager {
-    pub fn add_client(&mut self, address: &SocketAddr, transport: Transport) -> u32 {
+    // The returned notification is used by the connection handler to close the connection when the session is revoked.
+    pub fn add_client(&mut self, address: &SocketAddr, transport: Transport) -> (u32, Arc<Notify>) {
         let id = checksum::calculate(address.to_string().as_bytes());
+        let revocation = Arc::new(Notify::new());
         let client = Client {
             client_id: id,
             user_id: None,
             address: *address,
             transport,
             consumer_groups: Vec::new(),
+            revocation: revocation.clone(),
         };
         self.clients
             .insert(client.client_id, Arc::new(RwLock::new(client)));
-        id
+        (id, revocation)
     }
 
     pub async fn set_user_id(&mut self, client_id: u32, user_id: u32) -> Result<(), Error> {
@@ -100,6 +104,39 @@ impl ClientManager {
         self.clients.values().cloned().collect()
     }
 
+    pub async fn get_sessions(&self, user_id: Option<u32>) -> Vec<Arc<RwLock<Client>>> {
+        let mut sessions = Vec::new();
+        for client in self.clients.values() {
+            let session_user_id = client.read().await.user_id;
+            if session_user_id.is_some() && (user_id.is_none() || session_user_id == user_id) {
+                sessions.push(client.clone());
+            }
+        }
+        sessions
+    }
+
+    // The permit is stored by the notification, so the connection is closed even if it's not awaiting at the moment.
+    pub async fn revoke_session(&self, client_id: u32) -> Result<(), Error> {
+        let client = self.get_client_by_id(client_id)?;
+        let mut client = client.write().await;
+        client.user_id = None;
+        client.revocation.notify_one();
+        Ok(())
+    }
+
+    pub async fn revoke_user_sessions(&self, user_id: u32) -> Vec<u32> {
+        let mut client_ids = Vec::new();
+        for client in self.clients.values() {
+            let mut client = client.write().await;
+            if client.user_id == Some(user_id) {
+                client.user_id = None;
+                client.revocation.notify_one();
+                client_ids.push(client.client_id);
+            }
+        }
+        client_ids
+    }
+
     pub fn delete_client(&mut self, address: &SocketAddr) -> Option<Arc<RwLock<Client>>> {
         let id = checksum::calculate(address.to_string().as_bytes());
         self.clients.remove(&id)
@@ -201,3 +238,34 @@ impl ClientManager {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+    use tokio::time::timeout;
+
+    #[tokio::test]
+    async fn revoked_user_sessions_should_be_logged_out_and_notified() {
+        let mut client_manager = ClientManager::default();
+        let (client1, revocation1) =
+            client_manager.add_client(&"127.0.0.1:8001".parse().unwrap(), Transport::Tcp);
+        let (client2, _) =
+            client_manager.add_client(&"127.0.0.1:8002".parse().unwrap(), Transport::Quic);
+        client_manager.add_client(&"127.0.0.1:8003".parse().unwrap(), Transport::Tcp);
+        client_manager.set_user_id(client1, 1).await.unwrap();
+        client_manager.set_user_id(client2, 2).await.unwrap();
+
+        assert_eq!(client_manager.get_sessions(None).await.len(), 2);
+        assert_eq!(client_manager.get_sessions(Some(1)).await.len(), 1);
+
+        let revoked_clients = client_manager.revoke_user_sessions(1).await;
+
+        assert_eq!(revoked_clients, vec![client1]);
+        assert!(client_manager.get_sessions(Some(1)).await.is_empty());
+        assert_eq!(client_manager.get_sessions(None).await.len(), 1);
+        assert!(timeout(Duration::from_millis(100), revocation1.notified())
+            .await
+            .is_ok());
+    }
+}
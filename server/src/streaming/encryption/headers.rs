@@ -0,0 +1,163 @@
+use bytes::BufMut;
+use iggy::bytes_serializable::BytesSerializable;
+use iggy::error::Error;
+use iggy::models::header::{HeaderKey, HeaderValue};
+use std::collections::HashMap;
+
+// Marks the messages which have their headers encrypted together with the payload.
+pub const ENCRYPTED_HEADERS_HEADER: &str = "iggy-encrypted-headers";
+const RESERVED_HEADER_PREFIX: &str = "iggy-";
+
+type Headers = HashMap<HeaderKey, HeaderValue>;
+
+// The reserved headers are read by the server, e.g. to delay the delivery, so they're never encrypted.
+pub fn is_reserved_header(key: &HeaderKey) -> bool {
+    key.as_str().starts_with(RESERVED_HEADER_PREFIX)
+}
+
+// The headers are stored in front of the payload, so they are not limited by the size of a single header value.
+pub fn pack_headers(payload: &[u8], headers: &Headers) -> Result<(Vec<u8>, Headers), Error> {
+    let (mut reserved_headers, user_headers): (Headers, Headers) = headers
+        .iter()
+        .map(|(key, value)| (key.clone(), value.clone()))
+        .partition(|(key, _)| is_reserved_header(key));
+    // Only the server may mark the headers as encrypted, otherwise the payload would be misread on the poll.
+    let marker = HeaderKey::new(ENCRYPTED_HEADERS_HEADER)?;
+    reserved_headers.remove(&marker);
+    if user_headers.is_empty() {
+        return Ok((payload.to_vec(), reserved_headers));
+    }
+
+    let headers_bytes = user_headers.as_bytes();
+    let mut bytes = Vec::with_capacity(4 + headers_bytes.len() + payload.len());
+    #[allow(clippy::cast_possible_truncation)]
+    bytes.put_u32_le(headers_bytes.len() as u32);
+    bytes.extend(headers_bytes);
+    bytes.extend(payload);
+    reserved_headers.insert(marker, HeaderValue::from_bool(true)?);
+    Ok((bytes, reserved_headers))
+}
+
+pub fn unpack_headers(
+    payload: Vec<u8>,
+    headers: &Option<Headers>,
+) -> Result<(Vec<u8>, Option<Headers>), Error> {
+    let marker = HeaderKey::new(ENCRYPTED_HEADERS_HEADER)?;
+    let mut headers = match headers {
+        Some(headers) if headers.contains_key(&marker) => headers.clone(),
+        _ => return Ok((payload, headers.clone())),
+    };
+
+    if payload.len() < 4 {
+        return Err(Error::CannotDecryptData);
+    }
+
+    let headers_length = u32::from_le_bytes(payload[..4].try_into()?) as usize;
+    if payload.len() < 4 + headers_length {
+        return Err(Error::CannotDecryptData);
+    }
+
+    // The headers added by the server after the encryption, e.g. the dead letter ones, are kept.
+    headers.remove(&marker);
+    headers.extend(HashMap::from_bytes(&payload[4..4 + headers_length])?);
+    Ok((payload[4 + headers_length..].to_vec(), Some(headers)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn packed_headers_should_be_unpacked_together_with_server_headers() {
+        let payload = b"Hello World!";
+        let headers = HashMap::from([(
+            HeaderKey::new("tenant").unwrap(),
+            HeaderValue::from_str("tenant-1").unwrap(),
+        )]);
+
+        let (packed_payload, mut packed_headers) = pack_headers(payload, &headers).unwrap();
+        assert_eq!(packed_headers.len(), 1);
+        assert!(packed_headers.contains_key(&HeaderKey::new(ENCRYPTED_HEADERS_HEADER).unwrap()));
+
+        let server_header_key = HeaderKey::new("iggy-producer-id").unwrap();
+        packed_headers.insert(
+            server_header_key.clone(),
+            HeaderValue::from_uint64(1).unwrap(),
+        );
+        let (unpacked_payload, unpacked_headers) =
+            unpack_headers(packed_payload, &Some(packed_headers)).unwrap();
+        let unpacked_headers = unpacked_headers.unwrap();
+
+        assert_eq!(unpacked_payload, payload);
+        assert_eq!(unpacked_headers.len(), 2);
+        assert!(unpacked_headers.contains_key(&server_header_key));
+        assert_eq!(
+            unpacked_headers.get(&HeaderKey::new("tenant").unwrap()),
+            headers.get(&HeaderKey::new("tenant").unwrap())
+        );
+    }
+
+    #[test]
+    fn reserved_headers_should_not_be_packed() {
+        let payload = b"Hello World!";
+        let deliver_at_key = HeaderKey::new("iggy-deliver-at").unwrap();
+        let tenant_key = HeaderKey::new("tenant").unwrap();
+        let headers = HashMap::from([
+            (deliver_at_key.clone(), HeaderValue::from_uint64(1).unwrap()),
+            (
+                tenant_key.clone(),
+                HeaderValue::from_str("tenant-1").unwrap(),
+            ),
+        ]);
+
+        let (packed_payload, packed_headers) = pack_headers(payload, &headers).unwrap();
+        assert_eq!(packed_headers.len(), 2);
+        assert!(packed_headers.contains_key(&deliver_at_key));
+        assert!(!packed_headers.contains_key(&tenant_key));
+
+        let (unpacked_payload, unpacked_headers) =
+            unpack_headers(packed_payload, &Some(packed_headers)).unwrap();
+        assert_eq!(unpacked_payload, payload);
+        assert_eq!(unpacked_headers.unwrap(), headers);
+
+        let reserved_headers =
+            HashMap::from([(deliver_at_key.clone(), HeaderValue::from_uint64(1).unwrap())]);
+        let (packed_payload, packed_headers) = pack_headers(payload, &reserved_headers).unwrap();
+        assert_eq!(packed_payload, payload);
+        assert_eq!(packed_headers, reserved_headers);
+    }
+
+    #[test]
+    fn client_encrypted_headers_marker_should_not_be_kept() {
+        let payload = b"Hello World!";
+        let marker = HeaderKey::new(ENCRYPTED_HEADERS_HEADER).unwrap();
+        let headers = HashMap::from([(marker.clone(), HeaderValue::from_bool(true).unwrap())]);
+
+        let (packed_payload, packed_headers) = pack_headers(payload, &headers).unwrap();
+        assert_eq!(packed_payload, payload);
+        assert!(packed_headers.is_empty());
+
+        let tenant_key = HeaderKey::new("tenant").unwrap();
+        let headers = HashMap::from([
+            (marker.clone(), HeaderValue::from_bool(false).unwrap()),
+            (
+                tenant_key.clone(),
+                HeaderValue::from_str("tenant-1").unwrap(),
+            ),
+        ]);
+        let (packed_payload, packed_headers) = pack_headers(payload, &headers).unwrap();
+        assert_eq!(packed_headers.len(), 1);
+        assert_eq!(
+            packed_headers.get(&marker),
+            Some(&HeaderValue::from_bool(true).unwrap())
+        );
+
+        let (unpacked_payload, unpacked_headers) =
+            unpack_headers(packed_payload, &Some(packed_headers)).unwrap();
+        assert_eq!(unpacked_payload, payload);
+        let unpacked_headers = unpacked_headers.unwrap();
+        assert_eq!(unpacked_headers.len(), 1);
+        assert!(unpacked_headers.contains_key(&tenant_key));
+    }
+}
@@ -0,0 +1,186 @@
+use crate::configs::system::EncryptionConfig;
+use iggy::error::Error;
+use iggy::utils::crypto::{Aes256GcmEncryptor, Encryptor};
+use std::collections::HashMap;
+
+const KEY_ID_SIZE: usize = 4;
+
+// Every encrypted payload starts with the ID of the key used to encrypt it, so the keys can be rotated.
+// The payloads encrypted before the key IDs were introduced have no prefix, and are decrypted with the legacy key.
+#[derive(Debug)]
+pub struct KeyRing {
+    active_key_id: u32,
+    encryptors: HashMap<u32, Box<dyn Encryptor>>,
+    legacy_encryptor: Option<Box<dyn Encryptor>>,
+}
+
+impl KeyRing {
+    pub fn new(
+        active_key_id: u32,
+        encryptors: HashMap<u32, Box<dyn Encryptor>>,
+    ) -> Result<Self, Error> {
+        if !encryptors.contains_key(&active_key_id) {
+            return Err(Error::EncryptionKeyNotFound(active_key_id));
+        }
+
+        Ok(Self {
+            active_key_id,
+            encryptors,
+            legacy_encryptor: None,
+        })
+    }
+
+    pub fn with_legacy_encryptor(mut self, legacy_encryptor: Box<dyn Encryptor>) -> Self {
+        self.legacy_encryptor = Some(legacy_encryptor);
+        self
+    }
+
+    pub fn from_config(config: &EncryptionConfig) -> Result<Self, Error> {
+        let mut encryptors: HashMap<u32, Box<dyn Encryptor>> = HashMap::new();
+        for key in &config.keys {
+            encryptors.insert(
+                key.id,
+                Box::new(Aes256GcmEncryptor::from_base64_key(&key.key)?),
+            );
+        }
+
+        if config.key.is_empty() {
+            return Self::new(config.active_key_id, encryptors);
+        }
+
+        // Without any keys configured, the legacy key is also used to encrypt the new payloads.
+        if config.keys.is_empty() {
+            encryptors.insert(
+                config.active_key_id,
+                Box::new(Aes256GcmEncryptor::from_base64_key(&config.key)?),
+            );
+        }
+
+        Ok(Self::new(config.active_key_id, encryptors)?
+            .with_legacy_encryptor(Box::new(Aes256GcmEncryptor::from_base64_key(&config.key)?)))
+    }
+
+    pub fn get_active_key_id(&self) -> u32 {
+        self.active_key_id
+    }
+
+    pub fn contains_key(&self, key_id: u32) -> bool {
+        self.encryptors.contains_key(&key_id)
+    }
+
+    pub fn get_key_id(&self, data: &[u8]) -> Result<u32, Error> {
+        if data.len() < KEY_ID_SIZE {
+            return Err(Error::CannotDecryptData);
+        }
+
+        Ok(u32::from_le_bytes(data[..KEY_ID_SIZE].try_into()?))
+    }
+
+    pub fn is_encrypted_with_key(&self, data: &[u8], key_id: u32) -> Result<bool, Error> {
+        Ok(self.get_key_id(data)? == key_id)
+    }
+
+    pub fn encrypt_with_key(&self, data: &[u8], key_id: u32) -> Result<Vec<u8>, Error> {
+        let encryptor = self.encryptors.get(&key_id);
+        if encryptor.is_none() {
+            return Err(Error::EncryptionKeyNotFound(key_id));
+        }
+
+        let encrypted_data = encryptor.unwrap().encrypt(data)?;
+        Ok([&key_id.to_le_bytes(), encrypted_data.as_slice()].concat())
+    }
+
+    pub fn reencrypt(&self, data: &[u8], key_id: u32) -> Result<Vec<u8>, Error> {
+        self.encrypt_with_key(&self.decrypt(data)?, key_id)
+    }
+}
+
+impl Encryptor for KeyRing {
+    fn encrypt(&self, data: &[u8]) -> Result<Vec<u8>, Error> {
+        self.encrypt_with_key(data, self.active_key_id)
+    }
+
+    fn decrypt(&self, data: &[u8]) -> Result<Vec<u8>, Error> {
+        let result = match self.get_key_id(data) {
+            Ok(key_id) => match self.encryptors.get(&key_id) {
+                Some(encryptor) => encryptor.decrypt(&data[KEY_ID_SIZE..]),
+                None => Err(Error::EncryptionKeyNotFound(key_id)),
+            },
+            Err(error) => Err(error),
+        };
+
+        // The legacy payload starts with a random nonce, which may look like any key ID,
+        // so it's recognized by failing to decrypt it with the prefixed key.
+        match (result, &self.legacy_encryptor) {
+            (Err(_), Some(legacy_encryptor)) => legacy_encryptor.decrypt(data),
+            (result, _) => result,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_key_ring(active_key_id: u32, key_ids: &[u32]) -> KeyRing {
+        let mut encryptors: HashMap<u32, Box<dyn Encryptor>> = HashMap::new();
+        for key_id in key_ids {
+            let key = [*key_id as u8; 32];
+            encryptors.insert(*key_id, Box::new(Aes256GcmEncryptor::new(&key).unwrap()));
+        }
+        KeyRing::new(active_key_id, encryptors).unwrap()
+    }
+
+    #[test]
+    fn data_encrypted_with_previous_key_should_be_decrypted_after_rotation() {
+        let old_key_ring = create_key_ring(1, &[1]);
+        let data = b"Hello World!";
+        let encrypted_data = old_key_ring.encrypt(data).unwrap();
+
+        let key_ring = create_key_ring(2, &[1, 2]);
+        assert_eq!(key_ring.get_key_id(&encrypted_data).unwrap(), 1);
+        assert!(!key_ring.is_encrypted_with_key(&encrypted_data, 2).unwrap());
+        let decrypted_data = key_ring.decrypt(&encrypted_data).unwrap();
+        assert_eq!(data, decrypted_data.as_slice());
+
+        let reencrypted_data = key_ring.reencrypt(&encrypted_data, 2).unwrap();
+        assert_eq!(reencrypted_data.len(), encrypted_data.len());
+        assert!(key_ring
+            .is_encrypted_with_key(&reencrypted_data, 2)
+            .unwrap());
+        let decrypted_data = key_ring.decrypt(&reencrypted_data).unwrap();
+        assert_eq!(data, decrypted_data.as_slice());
+    }
+
+    #[test]
+    fn legacy_data_without_key_id_should_be_decrypted_and_reencrypted_with_legacy_key() {
+        let legacy_key = [9; 32];
+        let legacy_encryptor = Aes256GcmEncryptor::new(&legacy_key).unwrap();
+        let data = b"Hello World!";
+        let legacy_data = legacy_encryptor.encrypt(data).unwrap();
+
+        let key_ring = create_key_ring(1, &[1])
+            .with_legacy_encryptor(Box::new(Aes256GcmEncryptor::new(&legacy_key).unwrap()));
+        let decrypted_data = key_ring.decrypt(&legacy_data).unwrap();
+        assert_eq!(data, decrypted_data.as_slice());
+
+        let reencrypted_data = key_ring.reencrypt(&legacy_data, 1).unwrap();
+        assert!(key_ring
+            .is_encrypted_with_key(&reencrypted_data, 1)
+            .unwrap());
+        let decrypted_data = key_ring.decrypt(&reencrypted_data).unwrap();
+        assert_eq!(data, decrypted_data.as_slice());
+    }
+
+    #[test]
+    fn data_encrypted_with_unknown_key_should_not_be_decrypted() {
+        let old_key_ring = create_key_ring(1, &[1]);
+        let encrypted_data = old_key_ring.encrypt(b"Hello World!").unwrap();
+
+        let key_ring = create_key_ring(2, &[2]);
+        assert!(matches!(
+            key_ring.decrypt(&encrypted_data),
+            Err(Error::EncryptionKeyNotFound(1))
+        ));
+    }
+}
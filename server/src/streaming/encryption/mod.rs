@@ -0,0 +1,2 @@
+pub mod headers;
+pub mod key_ring;
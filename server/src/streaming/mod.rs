@@ -1,12 +1,19 @@
+pub mod audit;
 pub mod clients;
+pub mod encryption;
 pub mod models;
 pub mod partitions;
 pub mod persistence;
+pub mod personal_access_tokens;
+pub mod polling_args;
 pub mod polling_consumer;
+pub mod quotas;
+pub mod roles;
 pub mod segments;
 pub mod storage;
 pub mod streams;
 pub mod systems;
 pub mod topics;
+pub mod transactions;
 pub mod users;
 pub mod utils;
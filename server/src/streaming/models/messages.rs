@@ -7,5 +7,17 @@ use std::sync::Arc;
 pub struct PolledMessages {
     pub partition_id: u32,
     pub current_offset: u64,
+    pub scanned_offset: Option<u64>,
+    pub generation_id: Option<u32>,
+    pub assignment_changed: bool,
     pub messages: Vec<Arc<Message>>,
 }
+
+impl PolledMessages {
+    pub fn get_size_bytes(&self) -> u64 {
+        self.messages
+            .iter()
+            .map(|message| message.get_size_bytes() as u64)
+            .sum()
+    }
+}
@@ -0,0 +1,72 @@
+use crate::streaming::partitions::partition::Partition;
+use iggy::error::Error;
+use iggy::models::header::HeaderKey;
+use ringbuffer::RingBuffer;
+use std::collections::{HashMap, HashSet};
+use tracing::{info, trace};
+
+impl Partition {
+    pub async fn compact_segments(&mut self, compaction_key: &HeaderKey) -> Result<u64, Error> {
+        // The last segment is still being written to, so only the previous ones can be compacted.
+        if self.segments.len() < 2 {
+            return Ok(0);
+        }
+
+        let mut latest_offsets = HashMap::new();
+        let mut segments_keys = Vec::with_capacity(self.segments.len());
+        for segment in &self.segments {
+            let keys = segment.load_message_keys(compaction_key).await?;
+            for (offset, key) in &keys {
+                latest_offsets.insert(*key, *offset);
+            }
+            segments_keys.push(keys);
+        }
+
+        let compacted_segments_count = self.segments.len() - 1;
+        let mut compacted_messages = 0;
+        let mut compacted_end_offset = None;
+        for (segment, keys) in self
+            .segments
+            .iter_mut()
+            .zip(segments_keys)
+            .take(compacted_segments_count)
+        {
+            let removed_offsets = keys
+                .into_iter()
+                .filter(|(offset, key)| latest_offsets.get(key) != Some(offset))
+                .map(|(offset, _)| offset)
+                .collect::<HashSet<u64>>();
+            if removed_offsets.is_empty() {
+                trace!(
+                    "Nothing to compact in segment with start offset: {} for partition with ID: {}.",
+                    segment.start_offset,
+                    self.partition_id
+                );
+                continue;
+            }
+
+            segment.compact(&removed_offsets).await?;
+            compacted_messages += removed_offsets.len() as u64;
+            compacted_end_offset = Some(segment.current_offset);
+            info!(
+                "Compacted {} messages in segment with start offset: {} for partition with ID: {} for topic with ID: {} and stream with ID: {}.",
+                removed_offsets.len(),
+                segment.start_offset,
+                self.partition_id,
+                self.topic_id,
+                self.stream_id
+            );
+        }
+
+        // Cached messages must stay contiguous, thus the cache is dropped once it contains the compacted offsets.
+        if let Some(end_offset) = compacted_end_offset {
+            if let Some(messages) = self.messages.as_mut() {
+                if !messages.is_empty() && messages[0].offset <= end_offset {
+                    messages.clear();
+                }
+            }
+        }
+
+        Ok(compacted_messages)
+    }
+}
@@ -2,7 +2,10 @@ use crate::streaming::partitions::partition::{ConsumerOffset, Partition};
 use crate::streaming::polling_consumer::PollingConsumer;
 use crate::streaming::utils::file;
 use iggy::consumer::{Consumer, ConsumerKind};
+use iggy::consumer_offsets::reset_consumer_offsets::ResetStrategy;
 use iggy::error::Error;
+use iggy::models::consumer_lag::PartitionLag;
+use iggy::models::consumer_offset_reset::ConsumerOffsetReset;
 use tokio::fs;
 use tokio::io::AsyncReadExt;
 use tokio::sync::RwLock;
@@ -35,6 +38,182 @@ impl Partition {
         Ok(0)
     }
 
+    pub async fn get_consumer_lag(
+        &self,
+        consumer_kind: ConsumerKind,
+        consumer_id: u32,
+    ) -> Result<PartitionLag, Error> {
+        let consumer_offsets = match consumer_kind {
+            ConsumerKind::Consumer => self.consumer_offsets.read().await,
+            ConsumerKind::ConsumerGroup => self.consumer_group_offsets.read().await,
+        };
+        let stored_offset = match consumer_offsets.offsets.get(&consumer_id) {
+            Some(consumer_offset) => Some(consumer_offset.read().await.offset),
+            None => None,
+        };
+
+        let first_offset = match self.segments.first() {
+            Some(segment) => segment.start_offset,
+            None => 0,
+        };
+        let messages_lag = match (stored_offset, self.should_increment_offset) {
+            (_, false) => 0,
+            (Some(stored_offset), true) if stored_offset >= first_offset => {
+                self.current_offset - stored_offset
+            }
+            (_, true) => self.current_offset + 1 - first_offset,
+        };
+
+        let mut time_lag = 0;
+        if messages_lag > 0 {
+            // The lag in time is measured from the last consumed message, or the first available one.
+            let consumed_offset = stored_offset
+                .filter(|stored_offset| *stored_offset >= first_offset)
+                .unwrap_or(first_offset);
+            let consumed_timestamp = self.get_message_timestamp(consumed_offset).await?;
+            let last_timestamp = self.get_message_timestamp(self.current_offset).await?;
+            if let (Some(consumed_timestamp), Some(last_timestamp)) =
+                (consumed_timestamp, last_timestamp)
+            {
+                time_lag = last_timestamp.saturating_sub(consumed_timestamp);
+            }
+        }
+
+        Ok(PartitionLag {
+            partition_id: self.partition_id,
+            current_offset: self.current_offset,
+            stored_offset,
+            messages_lag,
+            time_lag,
+        })
+    }
+
+    pub async fn reset_consumer_offset(
+        &self,
+        consumer_kind: ConsumerKind,
+        consumer_id: u32,
+        strategy: ResetStrategy,
+        dry_run: bool,
+    ) -> Result<ConsumerOffsetReset, Error> {
+        let previous_offset = {
+            let consumer_offsets = match consumer_kind {
+                ConsumerKind::Consumer => self.consumer_offsets.read().await,
+                ConsumerKind::ConsumerGroup => self.consumer_group_offsets.read().await,
+            };
+            match consumer_offsets.offsets.get(&consumer_id) {
+                Some(consumer_offset) => Some(consumer_offset.read().await.offset),
+                None => None,
+            }
+        };
+
+        let first_offset = match self.segments.first() {
+            Some(segment) => segment.start_offset,
+            None => 0,
+        };
+        let last_offset = match self.should_increment_offset {
+            true => self.current_offset + 1,
+            false => 0,
+        };
+        let next_offset = match strategy {
+            ResetStrategy::Earliest => first_offset,
+            ResetStrategy::Latest => last_offset,
+            ResetStrategy::Timestamp(timestamp) => self
+                .get_offset_by_timestamp(timestamp)
+                .await?
+                .unwrap_or(last_offset),
+            ResetStrategy::Offset(offset) => {
+                if offset > last_offset {
+                    return Err(Error::InvalidOffset(offset));
+                }
+                offset.max(first_offset)
+            }
+            ResetStrategy::Shift(shift) => {
+                let next_offset = match previous_offset {
+                    Some(previous_offset) => previous_offset + 1,
+                    None => first_offset,
+                };
+                next_offset
+                    .saturating_add_signed(shift)
+                    .clamp(first_offset, last_offset.max(first_offset))
+            }
+        };
+
+        let stored_offset = next_offset.checked_sub(1);
+        if !dry_run {
+            trace!(
+                "Resetting offset for {} ID: {} in partition with ID: {} from: {:?} to: {:?}...",
+                consumer_kind,
+                consumer_id,
+                self.partition_id,
+                previous_offset,
+                stored_offset
+            );
+            match stored_offset {
+                Some(stored_offset) => {
+                    let consumer = match consumer_kind {
+                        ConsumerKind::Consumer => {
+                            PollingConsumer::Consumer(consumer_id, self.partition_id)
+                        }
+                        ConsumerKind::ConsumerGroup => {
+                            PollingConsumer::ConsumerGroup(consumer_id, 0)
+                        }
+                    };
+                    self.store_consumer_offset(consumer, stored_offset).await?;
+                }
+                None => {
+                    self.delete_consumer_offset(consumer_kind, consumer_id)
+                        .await?
+                }
+            }
+        }
+
+        Ok(ConsumerOffsetReset {
+            partition_id: self.partition_id,
+            current_offset: self.current_offset,
+            previous_offset,
+            stored_offset,
+        })
+    }
+
+    async fn delete_consumer_offset(
+        &self,
+        consumer_kind: ConsumerKind,
+        consumer_id: u32,
+    ) -> Result<(), Error> {
+        let mut consumer_offsets = match consumer_kind {
+            ConsumerKind::Consumer => self.consumer_offsets.write().await,
+            ConsumerKind::ConsumerGroup => self.consumer_group_offsets.write().await,
+        };
+        if let Some(consumer_offset) = consumer_offsets.offsets.remove(&consumer_id) {
+            let consumer_offset = consumer_offset.into_inner();
+            self.storage
+                .partition
+                .delete_offset(&consumer_offset)
+                .await?;
+        }
+        Ok(())
+    }
+
+    async fn get_offset_by_timestamp(&self, timestamp: u64) -> Result<Option<u64>, Error> {
+        for segment in self.segments.iter() {
+            if let Some(offset) = segment.get_offset_by_timestamp(timestamp).await? {
+                return Ok(Some(offset));
+            }
+        }
+        Ok(None)
+    }
+
+    async fn get_message_timestamp(&self, offset: u64) -> Result<Option<u64>, Error> {
+        let segment = self
+            .segments
+            .iter()
+            .find(|segment| segment.start_offset <= offset && segment.current_offset >= offset);
+        match segment {
+            Some(segment) => segment.get_message_timestamp(offset).await,
+            None => Ok(None),
+        }
+    }
+
     pub async fn store_consumer_offset(
         &self,
         consumer: PollingConsumer,
@@ -0,0 +1,118 @@
+use crate::streaming::partitions::partition::Partition;
+use crate::streaming::partitions::producers::{PRODUCER_ID_HEADER, PRODUCER_SEQUENCE_HEADER};
+use crate::streaming::partitions::transactions::TRANSACTION_ID_HEADER;
+use iggy::error::Error;
+use iggy::messages::send_messages::DELIVER_AT_HEADER;
+use iggy::models::header::{HeaderKey, HeaderValue};
+use iggy::models::messages::{Message, MessageState};
+use std::collections::HashSet;
+use tracing::warn;
+
+pub const DELIVERED_FROM_HEADER: &str = "iggy-delivered-from";
+
+#[derive(Debug, Default)]
+pub struct DelayedMessages {
+    pub pending: Vec<(u64, u64)>,
+    pub delivered: Vec<u64>,
+}
+
+impl Partition {
+    pub fn has_due_delayed_messages(&self, now: u64) -> bool {
+        self.delayed_messages
+            .first()
+            .is_some_and(|(deliver_at, _)| *deliver_at <= now)
+    }
+
+    // The delayed message stays hidden at its offset, and its copy is appended once the delivery time comes.
+    // This way, the consumers which have already moved past it will receive it, and the time index
+    // points to the copy at the delivery time.
+    pub async fn deliver_delayed_messages(&mut self, now: u64) -> Result<u32, Error> {
+        let due_messages = self
+            .delayed_messages
+            .iter()
+            .take_while(|(deliver_at, _)| *deliver_at <= now)
+            .copied()
+            .collect::<Vec<_>>();
+        if due_messages.is_empty() {
+            return Ok(0);
+        }
+
+        // The messages of the open transactions are delivered once they're committed.
+        let last_stable_offset = self.get_last_stable_offset();
+        let transaction_id_key = HeaderKey::new(TRANSACTION_ID_HEADER)?;
+        let excluded_keys = [
+            HeaderKey::new(DELIVER_AT_HEADER)?,
+            HeaderKey::new(PRODUCER_ID_HEADER)?,
+            HeaderKey::new(PRODUCER_SEQUENCE_HEADER)?,
+            transaction_id_key.clone(),
+        ];
+        let delivered_from_key = HeaderKey::new(DELIVERED_FROM_HEADER)?;
+        let mut copies = Vec::new();
+        // The delayed messages are removed only once they're handled, so the failed append is retried.
+        let mut handled_messages = Vec::new();
+        for (deliver_at, offset) in due_messages {
+            if last_stable_offset.is_some_and(|last_stable_offset| offset >= last_stable_offset) {
+                continue;
+            }
+
+            let message = match self.get_message(offset).await {
+                Ok(message) => message,
+                Err(Error::MessageNotFound(_, _)) => {
+                    warn!(
+                        "Delayed message with offset: {} was not found in partition with ID: {}.",
+                        offset, self.partition_id
+                    );
+                    handled_messages.push((deliver_at, offset));
+                    continue;
+                }
+                Err(error) => return Err(error),
+            };
+
+            handled_messages.push((deliver_at, offset));
+            let mut headers = message.headers.clone().unwrap_or_default();
+            if let Some(Ok(transaction_id)) = headers
+                .get(&transaction_id_key)
+                .map(|transaction_id| transaction_id.as_uint64())
+            {
+                if self.aborted_transactions.contains(&transaction_id) {
+                    continue;
+                }
+            }
+
+            headers.retain(|key, _| !excluded_keys.contains(key));
+            headers.insert(
+                delivered_from_key.clone(),
+                HeaderValue::from_uint64(offset)?,
+            );
+            copies.push(Message::empty(
+                now,
+                MessageState::Available,
+                0,
+                message.payload.clone(),
+                message.checksum,
+                Some(headers),
+            ));
+        }
+
+        let delivered_messages = copies.len() as u32;
+        if delivered_messages > 0 {
+            self.append_messages(copies).await?;
+        }
+        for handled_message in handled_messages {
+            self.delayed_messages.remove(&handled_message);
+        }
+        Ok(delivered_messages)
+    }
+
+    pub fn apply_delayed_messages(&mut self, delayed_messages: DelayedMessages) {
+        let delivered = delayed_messages
+            .delivered
+            .into_iter()
+            .collect::<HashSet<_>>();
+        for (deliver_at, offset) in delayed_messages.pending {
+            if !delivered.contains(&offset) {
+                self.delayed_messages.insert((deliver_at, offset));
+            }
+        }
+    }
+}
@@ -0,0 +1,53 @@
+use crate::streaming::encryption::key_ring::KeyRing;
+use crate::streaming::partitions::partition::Partition;
+use iggy::error::Error;
+use tracing::{info, trace};
+
+impl Partition {
+    // Only the closed segments are rewritten, the cached messages can still be decrypted with the previous keys.
+    pub fn get_closed_segments_start_offsets(&self) -> Vec<u64> {
+        self.segments
+            .iter()
+            .filter(|segment| segment.is_closed)
+            .map(|segment| segment.start_offset)
+            .collect()
+    }
+
+    pub async fn reencrypt_segment(
+        &self,
+        start_offset: u64,
+        key_ring: &KeyRing,
+        key_id: u32,
+    ) -> Result<u64, Error> {
+        // The segment could have been deleted in the meantime, e.g. by the message cleaner.
+        let segment = match self
+            .segments
+            .iter()
+            .find(|segment| segment.start_offset == start_offset)
+        {
+            Some(segment) => segment,
+            None => return Ok(0),
+        };
+
+        let reencrypted_messages = segment.reencrypt(key_ring, key_id).await?;
+        if reencrypted_messages == 0 {
+            trace!(
+                "Nothing to re-encrypt in segment with start offset: {} for partition with ID: {}.",
+                segment.start_offset,
+                self.partition_id
+            );
+            return Ok(0);
+        }
+
+        info!(
+            "Re-encrypted {} messages with key ID: {} in segment with start offset: {} for partition with ID: {} for topic with ID: {} and stream with ID: {}.",
+            reencrypted_messages,
+            key_id,
+            segment.start_offset,
+            self.partition_id,
+            self.topic_id,
+            self.stream_id
+        );
+        Ok(reencrypted_messages)
+    }
+}
@@ -1,14 +1,36 @@
 use crate::streaming::partitions::partition::Partition;
+use crate::streaming::partitions::transactions::{
+    TRANSACTION_ID_HEADER, TRANSACTION_MARKER_HEADER,
+};
 use crate::streaming::polling_consumer::PollingConsumer;
 use crate::streaming::segments::segment::Segment;
 use crate::streaming::utils::random_id;
 use iggy::error::Error;
-use iggy::models::messages::Message;
+use iggy::messages::poll_messages::IsolationLevel;
+use iggy::messages::send_messages::DELIVER_AT_HEADER;
+use iggy::models::header::HeaderKey;
+use iggy::models::header_filter::HeaderFilter;
+use iggy::models::messages::{Message, MessageState};
 use ringbuffer::RingBuffer;
 use std::sync::Arc;
 use tracing::{error, trace, warn};
 
 const EMPTY_MESSAGES: Vec<Arc<Message>> = vec![];
+const MAX_SCANNED_MESSAGES: usize = 10_000;
+
+struct VisibilityContext {
+    isolation_level: IsolationLevel,
+    end_offset: Option<u64>,
+    transaction_id_key: HeaderKey,
+    transaction_marker_key: HeaderKey,
+    deliver_at_key: HeaderKey,
+}
+
+enum Visibility {
+    Visible,
+    Hidden,
+    Blocked,
+}
 
 impl Partition {
     pub async fn get_messages_by_timestamp(
@@ -86,6 +108,34 @@ impl Partition {
             return Ok(EMPTY_MESSAGES);
         }
 
+        // Compacted segments may have gaps wider than the requested window, so keep scanning
+        // (with a growing window) until some messages are found or the end is reached.
+        let mut start_offset = start_offset;
+        let mut window = count;
+        loop {
+            let mut messages = self.read_messages_by_offset(start_offset, window).await?;
+            if !messages.is_empty() {
+                messages.truncate(count as usize);
+                return Ok(messages);
+            }
+
+            let end_offset = self.get_end_offset(start_offset, window);
+            if end_offset >= self.current_offset {
+                return Ok(messages);
+            }
+
+            start_offset = end_offset + 1;
+            window = window
+                .saturating_mul(2)
+                .min(count.max(MAX_SCANNED_MESSAGES as u32));
+        }
+    }
+
+    async fn read_messages_by_offset(
+        &self,
+        start_offset: u64,
+        count: u32,
+    ) -> Result<Vec<Arc<Message>>, Error> {
         let end_offset = self.get_end_offset(start_offset, count);
         let messages = self.try_get_messages_from_cache(start_offset, end_offset);
         if let Some(messages) = messages {
@@ -161,6 +211,113 @@ impl Partition {
         self.get_messages_by_offset(offset, count).await
     }
 
+    // The hidden messages, the unavailable ones (unless all states are included) and the ones not matching
+    // the filters are skipped, so the next messages are read until there's enough of them.
+    // The scanned offset allows the consumer to continue from the same place.
+    pub async fn get_visible_messages(
+        &self,
+        mut messages: Vec<Arc<Message>>,
+        count: u32,
+        isolation_level: IsolationLevel,
+        include_all_states: bool,
+        filters: &[HeaderFilter],
+    ) -> Result<(Vec<Arc<Message>>, Option<u64>), Error> {
+        let end_offset = match isolation_level {
+            IsolationLevel::ReadUncommitted => None,
+            IsolationLevel::ReadCommitted => self.get_last_stable_offset(),
+        };
+        let visibility = VisibilityContext {
+            isolation_level,
+            end_offset,
+            transaction_id_key: HeaderKey::new(TRANSACTION_ID_HEADER)?,
+            transaction_marker_key: HeaderKey::new(TRANSACTION_MARKER_HEADER)?,
+            deliver_at_key: HeaderKey::new(DELIVER_AT_HEADER)?,
+        };
+        let mut visible_messages = Vec::new();
+        let mut scanned_offset = None;
+        let mut scanned_messages = 0;
+        loop {
+            let last_offset = match messages.last() {
+                Some(message) => message.offset,
+                None => return Ok((visible_messages, scanned_offset)),
+            };
+
+            scanned_messages += messages.len();
+            for message in messages {
+                if visible_messages.len() >= count as usize {
+                    return Ok((visible_messages, scanned_offset));
+                }
+
+                let offset = message.offset;
+                match self.get_visibility(&message, &visibility)? {
+                    // The messages are returned in order, so the ones after the open transaction have to wait as well.
+                    Visibility::Blocked => return Ok((visible_messages, scanned_offset)),
+                    Visibility::Hidden => {}
+                    Visibility::Visible => {
+                        if (include_all_states || message.state == MessageState::Available)
+                            && filters
+                                .iter()
+                                .all(|filter| filter.matches(&message.headers))
+                        {
+                            visible_messages.push(message);
+                        }
+                    }
+                }
+                scanned_offset = Some(offset);
+            }
+
+            if visible_messages.len() >= count as usize
+                || last_offset >= self.current_offset
+                || scanned_messages >= MAX_SCANNED_MESSAGES
+            {
+                return Ok((visible_messages, scanned_offset));
+            }
+
+            messages = self.get_messages_by_offset(last_offset + 1, count).await?;
+        }
+    }
+
+    fn get_visibility(
+        &self,
+        message: &Message,
+        context: &VisibilityContext,
+    ) -> Result<Visibility, Error> {
+        if context
+            .end_offset
+            .is_some_and(|end_offset| message.offset >= end_offset)
+        {
+            return Ok(Visibility::Blocked);
+        }
+
+        let headers = match &message.headers {
+            Some(headers) => headers,
+            None => return Ok(Visibility::Visible),
+        };
+
+        if headers.contains_key(&context.transaction_marker_key) {
+            return Ok(Visibility::Hidden);
+        }
+
+        if context.isolation_level == IsolationLevel::ReadCommitted {
+            // The transaction headers are set only by the server, so the unparsable value is not transactional.
+            if let Some(Ok(transaction_id)) = headers
+                .get(&context.transaction_id_key)
+                .map(|transaction_id| transaction_id.as_uint64())
+            {
+                if self.aborted_transactions.contains(&transaction_id) {
+                    return Ok(Visibility::Hidden);
+                }
+            }
+        }
+
+        // The delayed message is delivered as a copy appended at its delivery time.
+        if headers.contains_key(&context.deliver_at_key) {
+            return Ok(Visibility::Hidden);
+        }
+
+        Ok(Visibility::Visible)
+    }
+
     fn get_end_offset(&self, offset: u64, count: u32) -> u64 {
         let mut end_offset = offset + (count - 1) as u64;
         let segment = self.segments.last().unwrap();
@@ -267,6 +424,66 @@ impl Partition {
         messages
     }
 
+    pub async fn get_message(&self, offset: u64) -> Result<Arc<Message>, Error> {
+        let messages = self.get_messages_by_offset(offset, 1).await?;
+        match messages.first() {
+            Some(message) if message.offset == offset => Ok(message.clone()),
+            _ => Err(Error::MessageNotFound(offset, self.partition_id)),
+        }
+    }
+
+    pub async fn update_message_state(
+        &mut self,
+        offset: u64,
+        state: MessageState,
+    ) -> Result<Arc<Message>, Error> {
+        let message = self.get_message(offset).await?;
+        if message.state == state {
+            return Ok(message);
+        }
+
+        let segment = self
+            .segments
+            .iter_mut()
+            .find(|segment| segment.start_offset <= offset && segment.current_offset >= offset);
+        if segment.is_none() {
+            return Err(Error::MessageNotFound(offset, self.partition_id));
+        }
+
+        let updated_message = Arc::new(Message::create(
+            message.offset,
+            state,
+            message.timestamp,
+            message.id,
+            message.payload.clone(),
+            message.checksum,
+            message.headers.clone(),
+        ));
+        segment
+            .unwrap()
+            .update_message_state(updated_message.clone())
+            .await?;
+        if let Some(messages) = self.messages.as_mut() {
+            if !messages.is_empty() && messages[0].offset <= offset {
+                if let Some(cached_message) =
+                    messages.get_mut((offset - messages[0].offset) as isize)
+                {
+                    if cached_message.offset == offset {
+                        *cached_message = updated_message;
+                    }
+                }
+            }
+        }
+
+        trace!(
+            "Updated state of the message with offset: {} to: {} for partition with ID: {}.",
+            offset,
+            state,
+            self.partition_id
+        );
+        Ok(message)
+    }
+
     pub async fn append_messages(&mut self, messages: Vec<Message>) -> Result<(), Error> {
         let segment = self.segments.last_mut();
         if segment.is_none() {
@@ -293,6 +510,7 @@ impl Partition {
         );
 
         let deduplicate_messages = self.message_ids.is_some();
+        let deliver_at_key = HeaderKey::new(DELIVER_AT_HEADER)?;
         for mut message in messages {
             if message.id == 0 {
                 message.id = random_id::get();
@@ -323,6 +541,15 @@ impl Partition {
             );
 
             message.offset = self.current_offset;
+            if let Some(deliver_at) = message
+                .headers
+                .as_ref()
+                .and_then(|headers| headers.get(&deliver_at_key))
+            {
+                self.delayed_messages
+                    .insert((deliver_at.as_uint64()?, message.offset));
+            }
+
             let message = Arc::new(message);
             segment.append_message(message.clone()).await?;
             if self.messages.is_some() {
@@ -343,6 +570,7 @@ impl Partition {
             segment.start_offset,
             self.partition_id
         );
+        self.new_messages_notifier.send_replace(self.current_offset);
 
         self.unsaved_messages_count += messages_count;
         if self.unsaved_messages_count >= self.config.partition.messages_required_to_save
@@ -1,6 +1,11 @@
+pub mod compaction;
 pub mod consumer_offsets;
+pub mod delivery;
+pub mod encryption;
 pub mod messages;
 pub mod partition;
 pub mod persistence;
+pub mod producers;
 pub mod segments;
 pub mod storage;
+pub mod transactions;
@@ -1,12 +1,14 @@
 use crate::configs::system::SystemConfig;
+use crate::streaming::partitions::delivery::DelayedMessages;
+use crate::streaming::partitions::transactions::TransactionMarker;
 use crate::streaming::segments::segment::Segment;
 use crate::streaming::storage::SystemStorage;
 use iggy::models::messages::Message;
 use iggy::utils::timestamp::TimeStamp;
 use ringbuffer::AllocRingBuffer;
-use std::collections::HashMap;
+use std::collections::{BTreeSet, HashMap, HashSet};
 use std::sync::Arc;
-use tokio::sync::RwLock;
+use tokio::sync::{watch, RwLock};
 
 #[derive(Debug)]
 pub struct Partition {
@@ -20,6 +22,9 @@ pub struct Partition {
     pub current_offset: u64,
     pub messages: Option<AllocRingBuffer<Arc<Message>>>,
     pub message_ids: Option<HashMap<u128, bool>>,
+    pub producer_sequences: HashMap<u64, ProducerState>,
+    pub open_transactions: HashMap<u64, u64>,
+    pub aborted_transactions: HashSet<u64>,
     pub unsaved_messages_count: u32,
     pub should_increment_offset: bool,
     pub created_at: u64,
@@ -29,6 +34,23 @@ pub struct Partition {
     pub(crate) segments: Vec<Segment>,
     pub(crate) config: Arc<SystemConfig>,
     pub(crate) storage: Arc<SystemStorage>,
+    pub(crate) new_messages_notifier: Arc<watch::Sender<u64>>,
+    pub(crate) delayed_messages: BTreeSet<(u64, u64)>,
+}
+
+#[derive(Debug)]
+pub struct ProducerState {
+    pub sequence: u64,
+    pub last_appended_at: u64,
+}
+
+// The partition state stored in the messages headers, which is recovered by scanning the segments.
+#[derive(Debug, Default)]
+pub struct RecoveredState {
+    pub producer_sequences: HashMap<u64, u64>,
+    // The offset of each marker is kept, as the segments are not loaded in order.
+    pub transaction_markers: HashMap<u64, Option<(u64, TransactionMarker)>>,
+    pub delayed_messages: DelayedMessages,
 }
 
 #[derive(Debug)]
@@ -74,6 +96,9 @@ impl Partition {
                 true => Some(HashMap::new()),
                 false => None,
             },
+            producer_sequences: HashMap::new(),
+            open_transactions: HashMap::new(),
+            aborted_transactions: HashSet::new(),
             segments: vec![],
             current_offset: 0,
             unsaved_messages_count: 0,
@@ -87,6 +112,8 @@ impl Partition {
             config,
             storage,
             created_at: TimeStamp::now().to_micros(),
+            new_messages_notifier: Arc::new(watch::channel(0).0),
+            delayed_messages: BTreeSet::new(),
         };
 
         if with_segment {
@@ -113,13 +140,14 @@ impl Partition {
     }
 
     pub fn get_messages_count(&self) -> u64 {
-        let last_segment = self.segments.last();
-        if let Some(last_segment) = last_segment {
-            if last_segment.current_size_bytes > 0 {
-                return last_segment.current_offset + 1;
-            }
-        }
-        0
+        self.segments
+            .iter()
+            .map(|segment| segment.get_messages_count())
+            .sum()
+    }
+
+    pub fn subscribe_to_new_messages(&self) -> watch::Receiver<u64> {
+        self.new_messages_notifier.subscribe()
     }
 
     pub fn get_segments(&self) -> &Vec<Segment> {
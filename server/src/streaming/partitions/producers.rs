@@ -0,0 +1,97 @@
+use crate::streaming::partitions::partition::{Partition, ProducerState};
+use iggy::error::Error;
+use iggy::messages::send_messages::ProducerSequence;
+use iggy::models::header::{HeaderKey, HeaderValue};
+use iggy::models::messages::Message;
+use iggy::utils::timestamp::TimeStamp;
+use std::collections::HashMap;
+use tracing::warn;
+
+pub const PRODUCER_ID_HEADER: &str = "iggy-producer-id";
+pub const PRODUCER_SEQUENCE_HEADER: &str = "iggy-producer-sequence";
+
+impl Partition {
+    pub async fn append_producer_messages(
+        &mut self,
+        producer: &ProducerSequence,
+        mut messages: Vec<Message>,
+    ) -> Result<(), Error> {
+        if messages.is_empty() {
+            return Ok(());
+        }
+
+        // The unknown producer (e.g. the one whose messages have already expired) starts with any sequence.
+        if let Some(state) = self.producer_sequences.get(&producer.producer_id) {
+            let last_sequence = state.sequence;
+            // The retried batch has already been appended, so it's acknowledged without appending it again.
+            let batch_last_sequence = producer.sequence.saturating_add(messages.len() as u64 - 1);
+            if batch_last_sequence <= last_sequence {
+                warn!(
+                    "Ignored the already appended sequences: {}..={} for producer with ID: {} in partition with ID: {}.",
+                    producer.sequence, batch_last_sequence, producer.producer_id, self.partition_id
+                );
+                return Ok(());
+            }
+
+            if producer.sequence <= last_sequence {
+                warn!(
+                    "Rejected the duplicated sequence: {} for producer with ID: {} in partition with ID: {}.",
+                    producer.sequence, producer.producer_id, self.partition_id
+                );
+                return Err(Error::DuplicateProducerSequence(
+                    producer.producer_id,
+                    producer.sequence,
+                    self.partition_id,
+                ));
+            }
+
+            if producer.sequence > last_sequence + 1 {
+                return Err(Error::InvalidProducerSequence(
+                    producer.producer_id,
+                    producer.sequence,
+                    last_sequence + 1,
+                    self.partition_id,
+                ));
+            }
+        }
+
+        // The producer state is stored along with the messages, so it can be recovered from the segments.
+        let producer_id_key = HeaderKey::new(PRODUCER_ID_HEADER)?;
+        let producer_sequence_key = HeaderKey::new(PRODUCER_SEQUENCE_HEADER)?;
+        let producer_id = HeaderValue::from_uint64(producer.producer_id)?;
+        let mut sequence = producer.sequence;
+        for message in messages.iter_mut() {
+            let headers = message.headers.get_or_insert_with(HashMap::new);
+            headers.insert(producer_id_key.clone(), producer_id.clone());
+            headers.insert(
+                producer_sequence_key.clone(),
+                HeaderValue::from_uint64(sequence)?,
+            );
+            sequence += 1;
+        }
+
+        self.append_messages(messages).await?;
+        self.producer_sequences.insert(
+            producer.producer_id,
+            ProducerState {
+                sequence: sequence - 1,
+                last_appended_at: TimeStamp::now().to_micros(),
+            },
+        );
+        Ok(())
+    }
+
+    pub fn delete_expired_producers(&mut self, now: u64) -> usize {
+        let producer_expiry = self.config.partition.producer_expiry;
+        if producer_expiry == 0 {
+            return 0;
+        }
+
+        // Once the producer is forgotten, its next sequence is accepted as the new starting point.
+        let producer_expiry = producer_expiry as u64 * 1_000_000;
+        let producers_count = self.producer_sequences.len();
+        self.producer_sequences
+            .retain(|_, state| state.last_appended_at.saturating_add(producer_expiry) > now);
+        producers_count - self.producer_sequences.len()
+    }
+}
@@ -23,17 +23,17 @@ impl Partition {
         Ok(())
     }
 
-    pub async fn delete_segment(&mut self, start_offset: u64) -> Result<&Segment, Error> {
-        let segment = self
+    pub async fn delete_segment(&mut self, start_offset: u64) -> Result<Segment, Error> {
+        let index = self
             .segments
             .iter()
-            .find(|s| s.start_offset == start_offset);
-        if segment.is_none() {
+            .position(|s| s.start_offset == start_offset);
+        if index.is_none() {
             return Err(Error::SegmentNotFound);
         }
 
-        let segment = segment.unwrap();
-        self.storage.segment.delete(segment).await?;
+        let segment = self.segments.remove(index.unwrap());
+        self.storage.segment.delete(&segment).await?;
         Ok(segment)
     }
 }
@@ -1,10 +1,13 @@
-use crate::streaming::partitions::partition::{ConsumerOffset, Partition};
+use crate::streaming::partitions::delivery::DelayedMessages;
+use crate::streaming::partitions::partition::{ConsumerOffset, Partition, ProducerState};
 use crate::streaming::persistence::persister::Persister;
 use async_trait::async_trait;
 use iggy::consumer::ConsumerKind;
 use iggy::error::Error;
+use iggy::utils::timestamp::TimeStamp;
 use serde::{Deserialize, Serialize};
 use sled::Db;
+use std::collections::HashMap;
 use std::path::Path;
 use std::sync::Arc;
 use tokio::fs;
@@ -42,6 +45,12 @@ impl PartitionStorage for FilePartitionStorage {
         );
         Ok(())
     }
+
+    async fn delete_offset(&self, offset: &ConsumerOffset) -> Result<(), Error> {
+        self.persister.delete(&offset.path).await?;
+        trace!("Deleted offset for {}", offset.consumer_id);
+        Ok(())
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -89,6 +98,8 @@ impl Storage<Partition> for FilePartitionStorage {
         partition.created_at = partition_data.created_at;
 
         let mut dir_entries = dir_entries.unwrap();
+        let mut transaction_markers = HashMap::new();
+        let mut delayed_messages = DelayedMessages::default();
         while let Some(dir_entry) = dir_entries.next_entry().await.unwrap_or(None) {
             let metadata = dir_entry.metadata().await.unwrap();
             if metadata.is_dir() {
@@ -144,9 +155,54 @@ impl Storage<Partition> for FilePartitionStorage {
                 info!("Loaded: {} unique message IDs for partition with ID: {} and segment with start offset: {}...", partition_message_ids.len(), partition.partition_id, segment.start_offset);
             }
 
+            // The producer sequences, transaction markers and delayed messages are recovered with a single scan.
+            let recovered_state = segment
+                .storage
+                .segment
+                .load_recovered_state(&segment)
+                .await?;
+
+            // The segments are not sorted yet, so the highest sequence of each producer is kept.
+            // The producers are treated as active since the load, so they don't expire right away.
+            let loaded_at = TimeStamp::now().to_micros();
+            for (producer_id, sequence) in recovered_state.producer_sequences {
+                let state =
+                    partition
+                        .producer_sequences
+                        .entry(producer_id)
+                        .or_insert(ProducerState {
+                            sequence,
+                            last_appended_at: loaded_at,
+                        });
+                if sequence > state.sequence {
+                    state.sequence = sequence;
+                }
+            }
+
+            // The marker may be stored in a different segment than the transaction's messages.
+            // The segments are not sorted yet, so the marker with the lowest offset is kept.
+            for (transaction_id, marker) in recovered_state.transaction_markers {
+                let transaction_marker = transaction_markers.entry(transaction_id).or_insert(None);
+                if let Some((offset, _)) = marker {
+                    if transaction_marker.is_none_or(|(lowest_offset, _)| offset < lowest_offset) {
+                        *transaction_marker = marker;
+                    }
+                }
+            }
+
+            // The delayed message and its delivered copy may be stored in different segments.
+            delayed_messages
+                .pending
+                .extend(recovered_state.delayed_messages.pending);
+            delayed_messages
+                .delivered
+                .extend(recovered_state.delayed_messages.delivered);
+
             partition.segments.push(segment);
         }
 
+        partition.apply_transaction_markers(transaction_markers);
+        partition.apply_delayed_messages(delayed_messages);
         partition
             .segments
             .sort_by(|a, b| a.start_offset.cmp(&b.start_offset));
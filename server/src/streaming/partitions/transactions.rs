@@ -0,0 +1,135 @@
+use crate::streaming::partitions::partition::Partition;
+use bytes::Bytes;
+use iggy::error::Error;
+use iggy::messages::send_messages::ProducerSequence;
+use iggy::models::header::{HeaderKey, HeaderValue};
+use iggy::models::messages::{Message, MessageState};
+use iggy::utils::checksum;
+use iggy::utils::timestamp::TimeStamp;
+use std::collections::HashMap;
+use std::str::FromStr;
+use tracing::trace;
+
+pub const TRANSACTION_ID_HEADER: &str = "iggy-transaction-id";
+pub const TRANSACTION_MARKER_HEADER: &str = "iggy-transaction-marker";
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TransactionMarker {
+    Commit,
+    Abort,
+}
+
+impl TransactionMarker {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TransactionMarker::Commit => "commit",
+            TransactionMarker::Abort => "abort",
+        }
+    }
+}
+
+impl FromStr for TransactionMarker {
+    type Err = Error;
+    fn from_str(marker: &str) -> Result<Self, Self::Err> {
+        match marker {
+            "commit" => Ok(TransactionMarker::Commit),
+            "abort" => Ok(TransactionMarker::Abort),
+            _ => Err(Error::InvalidHeaderValue),
+        }
+    }
+}
+
+impl Partition {
+    pub async fn append_transactional_messages(
+        &mut self,
+        transaction_id: u64,
+        producer: Option<&ProducerSequence>,
+        mut messages: Vec<Message>,
+    ) -> Result<(), Error> {
+        if messages.is_empty() {
+            return Ok(());
+        }
+
+        let transaction_id_key = HeaderKey::new(TRANSACTION_ID_HEADER)?;
+        let transaction_id_value = HeaderValue::from_uint64(transaction_id)?;
+        for message in messages.iter_mut() {
+            message
+                .headers
+                .get_or_insert_with(HashMap::new)
+                .insert(transaction_id_key.clone(), transaction_id_value.clone());
+        }
+
+        let first_offset = match self.should_increment_offset {
+            true => self.current_offset + 1,
+            false => self.current_offset,
+        };
+        match producer {
+            Some(producer) => self.append_producer_messages(producer, messages).await?,
+            None => self.append_messages(messages).await?,
+        }
+
+        self.open_transactions
+            .entry(transaction_id)
+            .or_insert(first_offset);
+        Ok(())
+    }
+
+    pub async fn append_transaction_marker(
+        &mut self,
+        transaction_id: u64,
+        marker: TransactionMarker,
+    ) -> Result<(), Error> {
+        let mut headers = HashMap::new();
+        headers.insert(
+            HeaderKey::new(TRANSACTION_ID_HEADER)?,
+            HeaderValue::from_uint64(transaction_id)?,
+        );
+        headers.insert(
+            HeaderKey::new(TRANSACTION_MARKER_HEADER)?,
+            HeaderValue::from_str(marker.as_str())?,
+        );
+        let payload = Bytes::new();
+        let marker_message = Message::empty(
+            TimeStamp::now().to_micros(),
+            MessageState::Available,
+            0,
+            payload.clone(),
+            checksum::calculate(&payload),
+            Some(headers),
+        );
+        // The transaction is completed even if the marker can't be written, so it doesn't block the consumers.
+        let result = self.append_messages(vec![marker_message]).await;
+        self.complete_transaction(transaction_id, marker);
+        result?;
+        trace!(
+            "Appended {} marker for transaction with ID: {} to partition with ID: {}.",
+            marker.as_str(),
+            transaction_id,
+            self.partition_id
+        );
+        Ok(())
+    }
+
+    pub fn apply_transaction_markers(
+        &mut self,
+        markers: HashMap<u64, Option<(u64, TransactionMarker)>>,
+    ) {
+        for (transaction_id, marker) in markers {
+            // The transactions left open before the restart can't be completed anymore.
+            let marker = marker.map_or(TransactionMarker::Abort, |(_, marker)| marker);
+            self.complete_transaction(transaction_id, marker);
+        }
+    }
+
+    // The first offset of the oldest open transaction, the read committed consumers can't go beyond it.
+    pub fn get_last_stable_offset(&self) -> Option<u64> {
+        self.open_transactions.values().min().copied()
+    }
+
+    fn complete_transaction(&mut self, transaction_id: u64, marker: TransactionMarker) {
+        self.open_transactions.remove(&transaction_id);
+        if marker == TransactionMarker::Abort {
+            self.aborted_transactions.insert(transaction_id);
+        }
+    }
+}
@@ -2,13 +2,15 @@ use crate::streaming::utils::file;
 use async_trait::async_trait;
 use iggy::error::Error;
 use std::fmt::Debug;
+use std::io::SeekFrom;
 use tokio::fs;
-use tokio::io::AsyncWriteExt;
+use tokio::io::{AsyncSeekExt, AsyncWriteExt};
 
 #[async_trait]
 pub trait Persister: Sync + Send {
     async fn append(&self, path: &str, bytes: &[u8]) -> Result<(), Error>;
     async fn overwrite(&self, path: &str, bytes: &[u8]) -> Result<(), Error>;
+    async fn write_at(&self, path: &str, position: u64, bytes: &[u8]) -> Result<(), Error>;
     async fn delete(&self, path: &str) -> Result<(), Error>;
 }
 
@@ -46,6 +48,13 @@ impl Persister for FilePersister {
         Ok(())
     }
 
+    async fn write_at(&self, path: &str, position: u64, bytes: &[u8]) -> Result<(), Error> {
+        let mut file = file::update(path).await?;
+        file.seek(SeekFrom::Start(position)).await?;
+        file.write_all(bytes).await?;
+        Ok(())
+    }
+
     async fn delete(&self, path: &str) -> Result<(), Error> {
         fs::remove_file(path).await?;
         Ok(())
@@ -68,6 +77,14 @@ impl Persister for FileWithSyncPersister {
         Ok(())
     }
 
+    async fn write_at(&self, path: &str, position: u64, bytes: &[u8]) -> Result<(), Error> {
+        let mut file = file::update(path).await?;
+        file.seek(SeekFrom::Start(position)).await?;
+        file.write_all(bytes).await?;
+        file.sync_all().await?;
+        Ok(())
+    }
+
     async fn delete(&self, path: &str) -> Result<(), Error> {
         fs::remove_file(path).await?;
         Ok(())
@@ -0,0 +1,2 @@
+pub mod personal_access_token;
+pub mod storage;
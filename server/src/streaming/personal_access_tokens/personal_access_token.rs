@@ -0,0 +1,93 @@
+use base64::engine::general_purpose;
+use base64::Engine;
+use ring::digest;
+use ring::rand::{SecureRandom, SystemRandom};
+use serde::{Deserialize, Serialize};
+
+const TOKEN_SIZE_BYTES: usize = 32;
+
+// Only the hash of the token is stored, the raw token is returned to the user once, when it's created.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct PersonalAccessToken {
+    pub user_id: u32,
+    pub name: String,
+    pub token: String,
+    pub expiry: Option<u64>,
+}
+
+impl PersonalAccessToken {
+    pub fn new(user_id: u32, name: &str, now: u64, expiry: Option<u32>) -> (Self, String) {
+        let mut bytes = [0u8; TOKEN_SIZE_BYTES];
+        SystemRandom::new()
+            .fill(&mut bytes)
+            .expect("Failed to generate the personal access token.");
+        let token = general_purpose::URL_SAFE_NO_PAD.encode(bytes);
+        let token_hash = Self::hash_token(&token);
+        (
+            Self {
+                user_id,
+                name: name.to_string(),
+                token: token_hash,
+                expiry: expiry.map(|expiry| now + expiry as u64 * 1_000_000),
+            },
+            token,
+        )
+    }
+
+    pub fn empty(token_hash: &str) -> Self {
+        Self {
+            token: token_hash.to_string(),
+            ..Default::default()
+        }
+    }
+
+    pub fn is_expired(&self, now: u64) -> bool {
+        match self.expiry {
+            Some(expiry) => expiry <= now,
+            None => false,
+        }
+    }
+
+    pub fn hash_token(token: &str) -> String {
+        let hash = digest::digest(&digest::SHA256, token.as_bytes());
+        general_purpose::URL_SAFE_NO_PAD.encode(hash.as_ref())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iggy::utils::timestamp::TimeStamp;
+
+    #[test]
+    fn personal_access_token_should_be_created_with_hashed_token() {
+        let user_id = 1;
+        let name = "test_token";
+        let now = TimeStamp::now().to_micros();
+        let (personal_access_token, raw_token) = PersonalAccessToken::new(user_id, name, now, None);
+        assert_eq!(personal_access_token.user_id, user_id);
+        assert_eq!(personal_access_token.name, name);
+        assert!(!raw_token.is_empty());
+        assert_ne!(personal_access_token.token, raw_token);
+        assert_eq!(
+            personal_access_token.token,
+            PersonalAccessToken::hash_token(&raw_token)
+        );
+        assert!(personal_access_token.expiry.is_none());
+        assert!(!personal_access_token.is_expired(now));
+    }
+
+    #[test]
+    fn personal_access_token_should_expire_after_given_number_of_seconds() {
+        let now = TimeStamp::now().to_micros();
+        let expiry = 10;
+        let (personal_access_token, _) =
+            PersonalAccessToken::new(1, "test_token", now, Some(expiry));
+        assert_eq!(
+            personal_access_token.expiry,
+            Some(now + expiry as u64 * 1_000_000)
+        );
+        assert!(!personal_access_token.is_expired(now));
+        assert!(personal_access_token.is_expired(now + expiry as u64 * 1_000_000));
+    }
+}
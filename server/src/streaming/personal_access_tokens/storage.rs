@@ -0,0 +1,152 @@
+use crate::streaming::personal_access_tokens::personal_access_token::PersonalAccessToken;
+use crate::streaming::storage::{PersonalAccessTokenStorage, Storage};
+use async_trait::async_trait;
+use iggy::error::Error;
+use sled::Db;
+use std::sync::Arc;
+use tracing::{error, info};
+
+const KEY_PREFIX: &str = "personal_access_tokens";
+
+#[derive(Debug)]
+pub struct FilePersonalAccessTokenStorage {
+    db: Arc<Db>,
+}
+
+impl FilePersonalAccessTokenStorage {
+    pub fn new(db: Arc<Db>) -> Self {
+        Self { db }
+    }
+}
+
+unsafe impl Send for FilePersonalAccessTokenStorage {}
+unsafe impl Sync for FilePersonalAccessTokenStorage {}
+
+#[async_trait]
+impl PersonalAccessTokenStorage for FilePersonalAccessTokenStorage {
+    async fn load_all(&self) -> Result<Vec<PersonalAccessToken>, Error> {
+        let mut personal_access_tokens = Vec::new();
+        for data in self.db.scan_prefix(format!("{}:", KEY_PREFIX)) {
+            let personal_access_token = match data {
+                Ok((_, value)) => match rmp_serde::from_slice::<PersonalAccessToken>(&value) {
+                    Ok(personal_access_token) => personal_access_token,
+                    Err(err) => {
+                        error!("Cannot deserialize personal access token. Error: {}", err);
+                        return Err(Error::CannotDeserializeResource(KEY_PREFIX.to_string()));
+                    }
+                },
+                Err(err) => {
+                    error!("Cannot load personal access token. Error: {}", err);
+                    return Err(Error::CannotLoadResource(KEY_PREFIX.to_string()));
+                }
+            };
+            personal_access_tokens.push(personal_access_token);
+        }
+
+        Ok(personal_access_tokens)
+    }
+
+    async fn load_for_user(&self, user_id: u32) -> Result<Vec<PersonalAccessToken>, Error> {
+        let personal_access_tokens = self.load_all().await?;
+        Ok(personal_access_tokens
+            .into_iter()
+            .filter(|personal_access_token| personal_access_token.user_id == user_id)
+            .collect())
+    }
+
+    async fn load_by_token(&self, token_hash: &str) -> Result<PersonalAccessToken, Error> {
+        let mut personal_access_token = PersonalAccessToken::empty(token_hash);
+        self.load(&mut personal_access_token).await?;
+        Ok(personal_access_token)
+    }
+
+    async fn load_by_name(&self, user_id: u32, name: &str) -> Result<PersonalAccessToken, Error> {
+        let personal_access_tokens = self.load_for_user(user_id).await?;
+        match personal_access_tokens
+            .into_iter()
+            .find(|personal_access_token| personal_access_token.name == name)
+        {
+            Some(personal_access_token) => Ok(personal_access_token),
+            None => Err(Error::PersonalAccessTokenNotFound(
+                name.to_string(),
+                user_id,
+            )),
+        }
+    }
+}
+
+#[async_trait]
+impl Storage<PersonalAccessToken> for FilePersonalAccessTokenStorage {
+    async fn load(&self, personal_access_token: &mut PersonalAccessToken) -> Result<(), Error> {
+        let key = get_key(&personal_access_token.token);
+        let data = self.db.get(&key);
+        if data.is_err() {
+            return Err(Error::CannotLoadResource(key));
+        }
+
+        let data = data.unwrap();
+        if data.is_none() {
+            return Err(Error::ResourceNotFound(key));
+        }
+
+        let data = data.unwrap();
+        let data = rmp_serde::from_slice::<PersonalAccessToken>(&data);
+        if data.is_err() {
+            return Err(Error::CannotDeserializeResource(key));
+        }
+
+        let data = data.unwrap();
+        personal_access_token.user_id = data.user_id;
+        personal_access_token.name = data.name;
+        personal_access_token.expiry = data.expiry;
+        Ok(())
+    }
+
+    async fn save(&self, personal_access_token: &PersonalAccessToken) -> Result<(), Error> {
+        let key = get_key(&personal_access_token.token);
+        match rmp_serde::to_vec(&personal_access_token) {
+            Ok(data) => {
+                if let Err(err) = self.db.insert(&key, data) {
+                    error!(
+                        "Cannot save personal access token: {} for user with ID: {}. Error: {}",
+                        personal_access_token.name, personal_access_token.user_id, err
+                    );
+                    return Err(Error::CannotSaveResource(key.to_string()));
+                }
+            }
+            Err(err) => {
+                error!(
+                    "Cannot serialize personal access token: {} for user with ID: {}. Error: {}",
+                    personal_access_token.name, personal_access_token.user_id, err
+                );
+                return Err(Error::CannotSerializeResource(key));
+            }
+        }
+
+        info!(
+            "Saved personal access token: {} for user with ID: {}.",
+            personal_access_token.name, personal_access_token.user_id
+        );
+        Ok(())
+    }
+
+    async fn delete(&self, personal_access_token: &PersonalAccessToken) -> Result<(), Error> {
+        info!(
+            "Deleting personal access token: {} for user with ID: {}...",
+            personal_access_token.name, personal_access_token.user_id
+        );
+        let key = get_key(&personal_access_token.token);
+        if self.db.remove(&key).is_err() {
+            return Err(Error::CannotDeleteResource(key));
+        }
+        info!(
+            "Deleted personal access token: {} for user with ID: {}.",
+            personal_access_token.name, personal_access_token.user_id
+        );
+        Ok(())
+    }
+}
+
+fn get_key(token_hash: &str) -> String {
+    format!("{}:{}", KEY_PREFIX, token_hash)
+}
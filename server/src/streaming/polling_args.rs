@@ -0,0 +1,35 @@
+use iggy::messages::poll_messages::{IsolationLevel, PollingStrategy};
+use iggy::models::header_filter::HeaderFilter;
+
+#[derive(Debug, Clone)]
+pub struct PollingArgs {
+    pub strategy: PollingStrategy,
+    pub count: u32,
+    pub auto_commit: bool,
+    pub include_all_states: bool,
+    pub max_wait_ms: Option<u64>,
+    pub isolation_level: IsolationLevel,
+    pub filters: Vec<HeaderFilter>,
+}
+
+impl PollingArgs {
+    pub fn new(
+        strategy: PollingStrategy,
+        count: u32,
+        auto_commit: bool,
+        include_all_states: bool,
+        max_wait_ms: Option<u64>,
+        isolation_level: IsolationLevel,
+        filters: Vec<HeaderFilter>,
+    ) -> Self {
+        Self {
+            strategy,
+            count,
+            auto_commit,
+            include_all_states,
+            max_wait_ms,
+            isolation_level,
+            filters,
+        }
+    }
+}
@@ -0,0 +1,362 @@
+use iggy::models::quotas::Quotas;
+use std::collections::HashMap;
+
+const MICROS_PER_SECOND: u128 = 1_000_000;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Throughput {
+    Produce,
+    Consume,
+}
+
+#[derive(Debug, Default)]
+pub struct QuotaManager {
+    users_quotas: HashMap<u32, Quotas>,
+    users_usage: HashMap<u32, ThroughputUsage>,
+    clients_usage: HashMap<u32, ThroughputUsage>,
+}
+
+#[derive(Debug, Default)]
+struct ThroughputUsage {
+    produce: Usage,
+    consume: Usage,
+}
+
+#[derive(Debug, Default)]
+struct Usage {
+    bytes: Bucket,
+    messages: Bucket,
+}
+
+// The usage is tracked with the token bucket refilled with the limit per second, holding at most one second of the limit.
+// The usage above the limit is carried over as the debt, which has to be paid off before the next request is allowed.
+#[derive(Debug, Default)]
+struct Bucket {
+    used: u64,
+    updated_at: u64,
+}
+
+impl QuotaManager {
+    pub fn get_user_quotas(&self, user_id: u32) -> Option<&Quotas> {
+        self.users_quotas.get(&user_id)
+    }
+
+    pub fn set_user_quotas(&mut self, user_id: u32, quotas: Option<Quotas>) {
+        match quotas {
+            Some(quotas) => self.users_quotas.insert(user_id, quotas),
+            None => self.users_quotas.remove(&user_id),
+        };
+    }
+
+    pub fn delete_user(&mut self, user_id: u32) {
+        self.users_quotas.remove(&user_id);
+        self.users_usage.remove(&user_id);
+    }
+
+    pub fn delete_client(&mut self, client_id: u32) {
+        self.clients_usage.remove(&client_id);
+    }
+
+    // Returns the number of microseconds to wait before the next attempt, if any of the quotas is exceeded.
+    // Otherwise, the usage is recorded at once, so the concurrent requests cannot exceed the quotas together.
+    pub fn try_acquire(
+        &mut self,
+        throughput: Throughput,
+        client: Option<(u32, &Quotas)>,
+        user: (u32, &Quotas),
+        now: u64,
+        bytes: u64,
+        messages: u64,
+    ) -> Option<u64> {
+        let (user_id, user_quotas) = user;
+        let user_usage = self
+            .users_usage
+            .entry(user_id)
+            .or_default()
+            .get_mut(throughput);
+        user_usage.refill(now, user_quotas, throughput);
+        let mut backoff = user_usage.get_backoff(bytes, messages, user_quotas, throughput);
+        if let Some((client_id, client_quotas)) = client {
+            let client_usage = self
+                .clients_usage
+                .entry(client_id)
+                .or_default()
+                .get_mut(throughput);
+            client_usage.refill(now, client_quotas, throughput);
+            backoff =
+                backoff.max(client_usage.get_backoff(bytes, messages, client_quotas, throughput));
+        }
+
+        if backoff.is_none() {
+            self.record(
+                throughput,
+                client.map(|(client_id, _)| client_id),
+                user_id,
+                bytes,
+                messages,
+            );
+        }
+        backoff
+    }
+
+    // Used when the size is known only after the request is handled, e.g. for the polled messages.
+    pub fn record(
+        &mut self,
+        throughput: Throughput,
+        client_id: Option<u32>,
+        user_id: u32,
+        bytes: u64,
+        messages: u64,
+    ) {
+        self.users_usage
+            .entry(user_id)
+            .or_default()
+            .get_mut(throughput)
+            .record(bytes, messages);
+        if let Some(client_id) = client_id {
+            self.clients_usage
+                .entry(client_id)
+                .or_default()
+                .get_mut(throughput)
+                .record(bytes, messages);
+        }
+    }
+}
+
+impl ThroughputUsage {
+    fn get_mut(&mut self, throughput: Throughput) -> &mut Usage {
+        match throughput {
+            Throughput::Produce => &mut self.produce,
+            Throughput::Consume => &mut self.consume,
+        }
+    }
+}
+
+impl Usage {
+    fn refill(&mut self, now: u64, quotas: &Quotas, throughput: Throughput) {
+        let (bytes_limit, messages_limit) = get_limits(quotas, throughput);
+        self.bytes.refill(now, bytes_limit);
+        self.messages.refill(now, messages_limit);
+    }
+
+    fn get_backoff(
+        &self,
+        bytes: u64,
+        messages: u64,
+        quotas: &Quotas,
+        throughput: Throughput,
+    ) -> Option<u64> {
+        let (bytes_limit, messages_limit) = get_limits(quotas, throughput);
+        self.bytes
+            .get_backoff(bytes, bytes_limit)
+            .max(self.messages.get_backoff(messages, messages_limit))
+    }
+
+    fn record(&mut self, bytes: u64, messages: u64) {
+        self.bytes.used += bytes;
+        self.messages.used += messages;
+    }
+}
+
+impl Bucket {
+    fn refill(&mut self, now: u64, limit: u64) {
+        if limit == 0 {
+            self.used = 0;
+            self.updated_at = now;
+            return;
+        }
+
+        let elapsed = now.saturating_sub(self.updated_at) as u128;
+        let refilled = elapsed * limit as u128 / MICROS_PER_SECOND;
+        if refilled >= self.used as u128 {
+            self.used = 0;
+            self.updated_at = now;
+            return;
+        }
+
+        // Only the time matching the refilled amount is consumed, so the remainder is not lost.
+        self.used -= refilled as u64;
+        self.updated_at += (refilled * MICROS_PER_SECOND / limit as u128) as u64;
+    }
+
+    // The request is always allowed when there is no usage left, so the batches larger than the limit are not blocked forever.
+    fn get_backoff(&self, requested: u64, limit: u64) -> Option<u64> {
+        let requested = requested.max(1);
+        if limit == 0 || self.used == 0 || self.used + requested <= limit {
+            return None;
+        }
+
+        let excess = (self.used - limit.saturating_sub(requested)) as u128;
+        Some((excess * MICROS_PER_SECOND).div_ceil(limit as u128) as u64)
+    }
+}
+
+fn get_limits(quotas: &Quotas, throughput: Throughput) -> (u64, u64) {
+    match throughput {
+        Throughput::Produce => (
+            quotas.produce_bytes_per_second,
+            quotas.produce_messages_per_second,
+        ),
+        Throughput::Consume => (
+            quotas.consume_bytes_per_second,
+            quotas.consume_messages_per_second,
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SECOND_MICROS: u64 = 1_000_000;
+
+    #[test]
+    fn produce_should_be_limited_per_user_and_client_until_the_usage_is_refilled() {
+        let mut quota_manager = QuotaManager::default();
+        let user_quotas = Quotas {
+            produce_messages_per_second: 10,
+            ..Default::default()
+        };
+        let client_quotas = Quotas {
+            produce_bytes_per_second: 1000,
+            ..Default::default()
+        };
+        let user_id = 1;
+        let client_id = 2;
+        let now = 5 * SECOND_MICROS;
+
+        let backoff = quota_manager.try_acquire(
+            Throughput::Produce,
+            Some((client_id, &client_quotas)),
+            (user_id, &user_quotas),
+            now,
+            600,
+            5,
+        );
+        assert!(backoff.is_none());
+
+        let backoff = quota_manager.try_acquire(
+            Throughput::Produce,
+            Some((client_id, &client_quotas)),
+            (user_id, &user_quotas),
+            now + 100,
+            600,
+            5,
+        );
+        assert_eq!(backoff, Some(200_000));
+
+        let backoff = quota_manager.try_acquire(
+            Throughput::Produce,
+            None,
+            (user_id, &user_quotas),
+            now + 200,
+            600,
+            5,
+        );
+        assert!(backoff.is_none());
+
+        let backoff = quota_manager.try_acquire(
+            Throughput::Consume,
+            None,
+            (user_id, &user_quotas),
+            now + 300,
+            0,
+            0,
+        );
+        assert!(backoff.is_none());
+
+        let backoff = quota_manager.try_acquire(
+            Throughput::Produce,
+            Some((client_id, &client_quotas)),
+            (user_id, &user_quotas),
+            now + 200 + SECOND_MICROS,
+            600,
+            5,
+        );
+        assert!(backoff.is_none());
+    }
+
+    #[test]
+    fn batch_larger_than_the_limit_should_be_allowed_once_and_carried_over_as_debt() {
+        let mut quota_manager = QuotaManager::default();
+        let user_quotas = Quotas {
+            produce_messages_per_second: 10,
+            ..Default::default()
+        };
+        let user_id = 1;
+        let now = 5 * SECOND_MICROS;
+
+        let backoff = quota_manager.try_acquire(
+            Throughput::Produce,
+            None,
+            (user_id, &user_quotas),
+            now,
+            0,
+            30,
+        );
+        assert!(backoff.is_none());
+
+        let backoff = quota_manager.try_acquire(
+            Throughput::Produce,
+            None,
+            (user_id, &user_quotas),
+            now + SECOND_MICROS,
+            0,
+            1,
+        );
+        assert_eq!(backoff, Some(1_100_000));
+
+        let backoff = quota_manager.try_acquire(
+            Throughput::Produce,
+            None,
+            (user_id, &user_quotas),
+            now + 3 * SECOND_MICROS,
+            0,
+            30,
+        );
+        assert!(backoff.is_none());
+    }
+
+    #[test]
+    fn consume_should_be_limited_by_the_recorded_usage() {
+        let mut quota_manager = QuotaManager::default();
+        let user_quotas = Quotas {
+            consume_bytes_per_second: 1000,
+            ..Default::default()
+        };
+        let user_id = 1;
+        let client_id = 2;
+        let now = 5 * SECOND_MICROS;
+
+        let backoff = quota_manager.try_acquire(
+            Throughput::Consume,
+            None,
+            (user_id, &user_quotas),
+            now,
+            0,
+            0,
+        );
+        assert!(backoff.is_none());
+        quota_manager.record(Throughput::Consume, Some(client_id), user_id, 3000, 3);
+
+        let backoff = quota_manager.try_acquire(
+            Throughput::Consume,
+            None,
+            (user_id, &user_quotas),
+            now,
+            0,
+            0,
+        );
+        assert_eq!(backoff, Some(2_001_000));
+
+        let backoff = quota_manager.try_acquire(
+            Throughput::Consume,
+            None,
+            (user_id, &user_quotas),
+            now + 2_001_000,
+            0,
+            0,
+        );
+        assert!(backoff.is_none());
+    }
+}
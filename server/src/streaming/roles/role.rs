@@ -0,0 +1,80 @@
+use iggy::models::permissions::Permissions;
+use iggy::utils::timestamp::TimeStamp;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Role {
+    pub id: u32,
+    pub name: String,
+    pub created_at: u64,
+    pub permissions: Permissions,
+    pub members: Vec<u32>,
+}
+
+impl Default for Role {
+    fn default() -> Self {
+        Self {
+            id: 1,
+            name: "role".to_string(),
+            created_at: TimeStamp::now().to_micros(),
+            permissions: Permissions::default(),
+            members: Vec::new(),
+        }
+    }
+}
+
+impl Role {
+    pub fn empty(id: u32) -> Self {
+        Self {
+            id,
+            ..Default::default()
+        }
+    }
+
+    pub fn new(id: u32, name: &str, permissions: Permissions) -> Self {
+        Self {
+            id,
+            name: name.to_string(),
+            created_at: TimeStamp::now().to_micros(),
+            permissions,
+            members: Vec::new(),
+        }
+    }
+
+    pub fn has_member(&self, user_id: u32) -> bool {
+        self.members.contains(&user_id)
+    }
+
+    pub fn add_member(&mut self, user_id: u32) -> bool {
+        if self.has_member(user_id) {
+            return false;
+        }
+
+        self.members.push(user_id);
+        self.members.sort();
+        true
+    }
+
+    pub fn remove_member(&mut self, user_id: u32) -> bool {
+        let members_count = self.members.len();
+        self.members.retain(|member| *member != user_id);
+        self.members.len() != members_count
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn member_should_be_added_to_the_role_only_once_and_removed() {
+        let mut role = Role::new(1, "test", Permissions::default());
+        assert!(role.add_member(2));
+        assert!(!role.add_member(2));
+        assert!(role.has_member(2));
+        assert_eq!(role.members.len(), 1);
+        assert!(role.remove_member(2));
+        assert!(!role.remove_member(2));
+        assert!(role.members.is_empty());
+    }
+}
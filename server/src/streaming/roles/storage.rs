@@ -0,0 +1,153 @@
+use crate::streaming::roles::role::Role;
+use crate::streaming::storage::{RoleStorage, Storage};
+use async_trait::async_trait;
+use iggy::error::Error;
+use sled::Db;
+use std::sync::Arc;
+use tracing::{error, info};
+
+const KEY_PREFIX: &str = "roles";
+
+#[derive(Debug)]
+pub struct FileRoleStorage {
+    db: Arc<Db>,
+}
+
+impl FileRoleStorage {
+    pub fn new(db: Arc<Db>) -> Self {
+        Self { db }
+    }
+}
+
+unsafe impl Send for FileRoleStorage {}
+unsafe impl Sync for FileRoleStorage {}
+
+#[async_trait]
+impl RoleStorage for FileRoleStorage {
+    async fn load_by_id(&self, id: u32) -> Result<Role, Error> {
+        let mut role = Role::empty(id);
+        self.load(&mut role).await?;
+        Ok(role)
+    }
+
+    async fn load_by_name(&self, name: &str) -> Result<Role, Error> {
+        let role_id_key = get_role_id_key(name);
+        let role_id = self.db.get(&role_id_key);
+        if role_id.is_err() {
+            return Err(Error::CannotLoadResource(role_id_key));
+        }
+
+        let role_id = role_id.unwrap();
+        if role_id.is_none() {
+            return Err(Error::ResourceNotFound(role_id_key));
+        }
+
+        let role_id = role_id.unwrap();
+        let role_id = u32::from_le_bytes(role_id.as_ref().try_into()?);
+        let mut role = Role::empty(role_id);
+        self.load(&mut role).await?;
+        Ok(role)
+    }
+
+    async fn load_all(&self) -> Result<Vec<Role>, Error> {
+        let mut roles = Vec::new();
+        for data in self.db.scan_prefix(format!("{}:", KEY_PREFIX)) {
+            let role = match data {
+                Ok((_, value)) => match rmp_serde::from_slice::<Role>(&value) {
+                    Ok(role) => role,
+                    Err(err) => {
+                        error!("Cannot deserialize role. Error: {}", err);
+                        return Err(Error::CannotDeserializeResource(KEY_PREFIX.to_string()));
+                    }
+                },
+                Err(err) => {
+                    error!("Cannot load role. Error: {}", err);
+                    return Err(Error::CannotLoadResource(KEY_PREFIX.to_string()));
+                }
+            };
+            roles.push(role);
+        }
+
+        Ok(roles)
+    }
+}
+
+#[async_trait]
+impl Storage<Role> for FileRoleStorage {
+    async fn load(&self, role: &mut Role) -> Result<(), Error> {
+        let key = get_key(role.id);
+        let role_data = self.db.get(&key);
+        if role_data.is_err() {
+            return Err(Error::CannotLoadResource(key));
+        }
+
+        let role_data = role_data.unwrap();
+        if role_data.is_none() {
+            return Err(Error::ResourceNotFound(key));
+        }
+
+        let role_data = role_data.unwrap();
+        let role_data = rmp_serde::from_slice::<Role>(&role_data);
+        if role_data.is_err() {
+            return Err(Error::CannotDeserializeResource(key));
+        }
+
+        let role_data = role_data.unwrap();
+        role.name = role_data.name;
+        role.created_at = role_data.created_at;
+        role.permissions = role_data.permissions;
+        role.members = role_data.members;
+        Ok(())
+    }
+
+    async fn save(&self, role: &Role) -> Result<(), Error> {
+        let key = get_key(role.id);
+        match rmp_serde::to_vec(&role) {
+            Ok(data) => {
+                if let Err(err) = self.db.insert(&key, data) {
+                    error!("Cannot save role with ID: {}. Error: {}", role.id, err);
+                    return Err(Error::CannotSaveResource(key.to_string()));
+                }
+                if let Err(err) = self
+                    .db
+                    .insert(get_role_id_key(&role.name), &role.id.to_le_bytes())
+                {
+                    error!(
+                        "Cannot save name for role with ID: {}. Error: {}",
+                        role.id, err
+                    );
+                    return Err(Error::CannotSaveResource(key.to_string()));
+                }
+            }
+            Err(err) => {
+                error!("Cannot serialize role with ID: {}. Error: {}", role.id, err);
+                return Err(Error::CannotSerializeResource(key));
+            }
+        }
+
+        info!("Saved role with ID: {}.", role.id);
+        Ok(())
+    }
+
+    async fn delete(&self, role: &Role) -> Result<(), Error> {
+        info!("Deleting role with ID: {}...", role.id);
+        let key = get_key(role.id);
+        if self.db.remove(&key).is_err() {
+            return Err(Error::CannotDeleteResource(key));
+        }
+        let key = get_role_id_key(&role.name);
+        if self.db.remove(&key).is_err() {
+            return Err(Error::CannotDeleteResource(key));
+        }
+        info!("Deleted role with ID: {}.", role.id);
+        Ok(())
+    }
+}
+
+fn get_key(role_id: u32) -> String {
+    format!("{}:{}", KEY_PREFIX, role_id)
+}
+
+fn get_role_id_key(name: &str) -> String {
+    format!("{}_id:{}", KEY_PREFIX, name)
+}
@@ -0,0 +1,47 @@
+use crate::streaming::segments::segment::Segment;
+use crate::streaming::utils::hash;
+use iggy::error::Error;
+use iggy::models::header::HeaderKey;
+use iggy::models::messages::Message;
+use std::collections::HashSet;
+
+impl Segment {
+    pub async fn load_message_keys(
+        &self,
+        compaction_key: &HeaderKey,
+    ) -> Result<Vec<(u64, u128)>, Error> {
+        let mut keys = self
+            .storage
+            .segment
+            .load_message_keys(self, compaction_key)
+            .await?;
+        if let Some(unsaved_messages) = &self.unsaved_messages {
+            for message in unsaved_messages {
+                if let Some(key) = calculate_message_key(message, compaction_key) {
+                    keys.push((message.offset, key));
+                }
+            }
+        }
+
+        Ok(keys)
+    }
+
+    pub async fn compact(&mut self, removed_offsets: &HashSet<u64>) -> Result<(), Error> {
+        let size_bytes = self
+            .storage
+            .segment
+            .save_compacted_messages(self, removed_offsets)
+            .await?;
+        self.current_size_bytes = size_bytes;
+        if self.indexes.is_some() {
+            self.indexes = Some(self.storage.segment.load_all_indexes(self).await?);
+        }
+
+        Ok(())
+    }
+}
+
+pub fn calculate_message_key(message: &Message, compaction_key: &HeaderKey) -> Option<u128> {
+    let value = message.headers.as_ref()?.get(compaction_key)?;
+    Some(hash::calculate(&value.value))
+}
@@ -0,0 +1,16 @@
+use crate::streaming::encryption::key_ring::KeyRing;
+use crate::streaming::segments::segment::Segment;
+use iggy::error::Error;
+
+impl Segment {
+    pub async fn reencrypt(&self, key_ring: &KeyRing, key_id: u32) -> Result<u64, Error> {
+        if !self.is_closed {
+            return Ok(0);
+        }
+
+        self.storage
+            .segment
+            .save_reencrypted_messages(self, key_ring, key_id)
+            .await
+    }
+}
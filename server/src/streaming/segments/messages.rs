@@ -51,6 +51,62 @@ impl Segment {
         Ok(messages)
     }
 
+    pub async fn get_message_timestamp(&self, offset: u64) -> Result<Option<u64>, Error> {
+        if offset < self.start_offset || offset > self.current_offset {
+            return Ok(None);
+        }
+
+        let relative_offset = (offset - self.start_offset) as u32;
+        if let Some(time_indexes) = self.time_indexes.as_ref() {
+            return Ok(Self::find_timestamp(time_indexes, relative_offset));
+        }
+
+        if let Some(unsaved_messages) = self.unsaved_messages.as_ref() {
+            let message = unsaved_messages
+                .iter()
+                .find(|message| message.offset == offset);
+            if let Some(message) = message {
+                return Ok(Some(message.timestamp));
+            }
+        }
+
+        let time_indexes = self.storage.segment.load_all_time_indexes(self).await?;
+        Ok(Self::find_timestamp(&time_indexes, relative_offset))
+    }
+
+    pub async fn get_offset_by_timestamp(&self, timestamp: u64) -> Result<Option<u64>, Error> {
+        if let Some(time_indexes) = self.time_indexes.as_ref() {
+            return Ok(self.find_offset(time_indexes, timestamp));
+        }
+
+        let time_indexes = self.storage.segment.load_all_time_indexes(self).await?;
+        if let Some(offset) = self.find_offset(&time_indexes, timestamp) {
+            return Ok(Some(offset));
+        }
+
+        let offset = self.unsaved_messages.as_ref().and_then(|unsaved_messages| {
+            unsaved_messages
+                .iter()
+                .find(|message| message.timestamp >= timestamp)
+                .map(|message| message.offset)
+        });
+        Ok(offset)
+    }
+
+    fn find_offset(&self, time_indexes: &[TimeIndex], timestamp: u64) -> Option<u64> {
+        time_indexes
+            .iter()
+            .find(|time_index| time_index.timestamp >= timestamp)
+            .map(|time_index| self.start_offset + time_index.relative_offset as u64)
+    }
+
+    fn find_timestamp(time_indexes: &[TimeIndex], relative_offset: u32) -> Option<u64> {
+        time_indexes
+            .binary_search_by_key(&relative_offset, |time_index| time_index.relative_offset)
+            .ok()
+            .map(|position| time_indexes[position].timestamp)
+    }
+
     fn load_messages_from_unsaved_buffer(&self, offset: u64, end_offset: u64) -> Vec<Arc<Message>> {
         self.unsaved_messages
             .as_ref()
@@ -223,4 +279,21 @@ impl Segment {
 
         Ok(())
     }
+
+    pub async fn update_message_state(&mut self, message: Arc<Message>) -> Result<(), Error> {
+        if let Some(unsaved_messages) = self.unsaved_messages.as_mut() {
+            if let Some(unsaved_message) = unsaved_messages
+                .iter_mut()
+                .find(|unsaved_message| unsaved_message.offset == message.offset)
+            {
+                *unsaved_message = message;
+                return Ok(());
+            }
+        }
+
+        self.storage
+            .segment
+            .save_message_state(self, message.offset, message.state)
+            .await
+    }
 }
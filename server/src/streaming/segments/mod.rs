@@ -1,3 +1,5 @@
+pub mod compaction;
+pub mod encryption;
 pub mod index;
 pub mod messages;
 pub mod persistence;
@@ -99,6 +99,14 @@ impl Segment {
         (last_message.timestamp + message_expiry) <= now
     }
 
+    pub fn get_messages_count(&self) -> u64 {
+        if self.current_size_bytes == 0 {
+            return 0;
+        }
+
+        self.current_offset - self.start_offset + 1
+    }
+
     fn get_log_path(path: &str) -> String {
         format!("{}.{}", path, LOG_EXTENSION)
     }
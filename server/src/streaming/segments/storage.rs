@@ -1,18 +1,29 @@
+use crate::streaming::encryption::key_ring::KeyRing;
 use crate::streaming::persistence::persister::Persister;
 use async_trait::async_trait;
 use bytes::{BufMut, Bytes};
 use iggy::bytes_serializable::BytesSerializable;
 use iggy::error::Error;
+use iggy::messages::send_messages::DELIVER_AT_HEADER;
+use iggy::models::header::HeaderKey;
 use iggy::models::messages::{Message, MessageState};
 use iggy::utils::checksum;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::io::SeekFrom;
 use std::path::Path;
 use std::sync::Arc;
+use tokio::fs;
 use tokio::io::{AsyncReadExt, AsyncSeekExt, BufReader};
 use tracing::log::{trace, warn};
 use tracing::{error, info};
 
+use crate::streaming::partitions::delivery::DELIVERED_FROM_HEADER;
+use crate::streaming::partitions::partition::RecoveredState;
+use crate::streaming::partitions::producers::{PRODUCER_ID_HEADER, PRODUCER_SEQUENCE_HEADER};
+use crate::streaming::partitions::transactions::{
+    TransactionMarker, TRANSACTION_ID_HEADER, TRANSACTION_MARKER_HEADER,
+};
+use crate::streaming::segments::compaction;
 use crate::streaming::segments::index::{Index, IndexRange};
 use crate::streaming::segments::segment::Segment;
 use crate::streaming::segments::time_index::TimeIndex;
@@ -22,6 +33,9 @@ use crate::streaming::utils::file;
 const EMPTY_INDEXES: Vec<Index> = vec![];
 const EMPTY_TIME_INDEXES: Vec<TimeIndex> = vec![];
 const INDEX_SIZE: u32 = 4;
+const COMPACTED_EXTENSION: &str = "compacted";
+const REENCRYPTED_EXTENSION: &str = "reencrypted";
+const REENCRYPTION_BATCH_SIZE: u32 = 1000;
 
 #[derive(Debug)]
 pub struct FileSegmentStorage {
@@ -215,6 +229,81 @@ impl SegmentStorage for FileSegmentStorage {
         Ok(message_ids)
     }
 
+    async fn load_recovered_state(&self, segment: &Segment) -> Result<RecoveredState, Error> {
+        let producer_id_key = HeaderKey::new(PRODUCER_ID_HEADER)?;
+        let producer_sequence_key = HeaderKey::new(PRODUCER_SEQUENCE_HEADER)?;
+        let transaction_id_key = HeaderKey::new(TRANSACTION_ID_HEADER)?;
+        let transaction_marker_key = HeaderKey::new(TRANSACTION_MARKER_HEADER)?;
+        let deliver_at_key = HeaderKey::new(DELIVER_AT_HEADER)?;
+        let delivered_from_key = HeaderKey::new(DELIVERED_FROM_HEADER)?;
+        let mut state = RecoveredState::default();
+        // The malformed values are skipped, so a single message can't prevent the partition from being loaded.
+        load_messages_by_range(segment, &IndexRange::max_range(), |message: Message| {
+            let headers = match &message.headers {
+                Some(headers) => headers,
+                None => return Ok(()),
+            };
+
+            if let (Some(Ok(producer_id)), Some(Ok(sequence))) = (
+                headers.get(&producer_id_key).map(|value| value.as_uint64()),
+                headers
+                    .get(&producer_sequence_key)
+                    .map(|value| value.as_uint64()),
+            ) {
+                let last_sequence = state
+                    .producer_sequences
+                    .entry(producer_id)
+                    .or_insert(sequence);
+                if sequence > *last_sequence {
+                    *last_sequence = sequence;
+                }
+            }
+
+            if let Some(Ok(transaction_id)) = headers
+                .get(&transaction_id_key)
+                .map(|value| value.as_uint64())
+            {
+                let marker = state
+                    .transaction_markers
+                    .entry(transaction_id)
+                    .or_insert(None);
+                if let Some(Ok(transaction_marker)) = headers
+                    .get(&transaction_marker_key)
+                    .map(|value| value.as_str()?.parse::<TransactionMarker>())
+                {
+                    // Only the first marker completes the transaction.
+                    if marker.is_none_or(|(offset, _)| message.offset < offset) {
+                        *marker = Some((message.offset, transaction_marker));
+                    }
+                }
+            }
+
+            if let Some(Ok(deliver_at)) =
+                headers.get(&deliver_at_key).map(|value| value.as_uint64())
+            {
+                state
+                    .delayed_messages
+                    .pending
+                    .push((deliver_at, message.offset));
+            }
+            if let Some(Ok(delivered_from)) = headers
+                .get(&delivered_from_key)
+                .map(|value| value.as_uint64())
+            {
+                state.delayed_messages.delivered.push(delivered_from);
+            }
+            Ok(())
+        })
+        .await?;
+        trace!(
+            "Loaded {} producer sequences, {} transaction markers and {} delayed messages from disk.",
+            state.producer_sequences.len(),
+            state.transaction_markers.len(),
+            state.delayed_messages.pending.len()
+        );
+        Ok(state)
+    }
+
     async fn load_checksums(&self, segment: &Segment) -> Result<(), Error> {
         load_messages_by_range(segment, &IndexRange::max_range(), |message: Message| {
             let calculated_checksum = checksum::calculate(&message.payload);
@@ -475,6 +564,281 @@ impl SegmentStorage for FileSegmentStorage {
 
         Ok(())
     }
+
+    async fn load_message_keys(
+        &self,
+        segment: &Segment,
+        compaction_key: &HeaderKey,
+    ) -> Result<Vec<(u64, u128)>, Error> {
+        let mut keys = Vec::new();
+        load_messages_by_range(segment, &IndexRange::max_range(), |message: Message| {
+            if let Some(key) = compaction::calculate_message_key(&message, compaction_key) {
+                keys.push((message.offset, key));
+            }
+            Ok(())
+        })
+        .await?;
+        trace!("Loaded {} message keys from disk.", keys.len());
+        Ok(keys)
+    }
+
+    async fn save_compacted_messages(
+        &self,
+        segment: &Segment,
+        removed_offsets: &HashSet<u64>,
+    ) -> Result<u32, Error> {
+        // The time index has an entry for every offset of the segment, including the already compacted ones.
+        let time_indexes = self.load_all_time_indexes(segment).await?;
+        let mut messages_bytes = Vec::new();
+        let mut positions = Vec::new();
+        load_messages_by_range(segment, &IndexRange::max_range(), |message: Message| {
+            if removed_offsets.contains(&message.offset) {
+                return Ok(());
+            }
+
+            positions.push((message.offset, messages_bytes.len() as u32));
+            message.extend(&mut messages_bytes);
+            Ok(())
+        })
+        .await?;
+
+        // Offsets stay stable: the index of a removed message points to the next retained one.
+        let size_bytes = messages_bytes.len() as u32;
+        let mut index_bytes = Vec::with_capacity(time_indexes.len() * 4);
+        let mut time_index_bytes = Vec::with_capacity(time_indexes.len() * 8);
+        let mut positions = positions.into_iter().peekable();
+        for time_index in &time_indexes {
+            let offset = segment.start_offset + time_index.relative_offset as u64;
+            while let Some((retained_offset, _)) = positions.peek() {
+                if *retained_offset >= offset {
+                    break;
+                }
+                positions.next();
+            }
+            let position = match positions.peek() {
+                Some((_, position)) => *position,
+                None => size_bytes,
+            };
+            index_bytes.put_u32_le(position);
+            time_index_bytes.put_u64_le(time_index.timestamp);
+        }
+
+        for (path, bytes) in [
+            (&segment.log_path, &messages_bytes),
+            (&segment.index_path, &index_bytes),
+            (&segment.time_index_path, &time_index_bytes),
+        ] {
+            let compacted_path = format!("{}.{}", path, COMPACTED_EXTENSION);
+            if let Err(error) = self.persister.overwrite(&compacted_path, bytes).await {
+                error!(
+                    "Cannot save compacted segment file: {}. Error: {}",
+                    compacted_path, error
+                );
+                return Err(Error::CannotCompactSegment(
+                    segment.start_offset,
+                    segment.partition_id,
+                ));
+            }
+        }
+
+        for path in [
+            &segment.log_path,
+            &segment.index_path,
+            &segment.time_index_path,
+        ] {
+            let compacted_path = format!("{}.{}", path, COMPACTED_EXTENSION);
+            if let Err(error) = fs::rename(&compacted_path, path).await {
+                error!(
+                    "Cannot replace segment file: {} with compacted one. Error: {}",
+                    path, error
+                );
+                return Err(Error::CannotCompactSegment(
+                    segment.start_offset,
+                    segment.partition_id,
+                ));
+            }
+        }
+
+        Ok(size_bytes)
+    }
+
+    async fn save_reencrypted_messages(
+        &self,
+        segment: &Segment,
+        key_ring: &KeyRing,
+        key_id: u32,
+    ) -> Result<u64, Error> {
+        let size_bytes = fs::metadata(&segment.log_path).await?.len();
+        let reencrypted_path = format!("{}.{}", segment.log_path, REENCRYPTED_EXTENSION);
+        if let Err(error) = self.persister.overwrite(&reencrypted_path, &[]).await {
+            error!(
+                "Cannot create re-encrypted segment file: {}. Error: {}",
+                reencrypted_path, error
+            );
+            return Err(Error::CannotReencryptSegment(
+                segment.start_offset,
+                segment.partition_id,
+            ));
+        }
+
+        // The segment is re-encrypted in batches, so it never has to be loaded into memory at once.
+        // The encrypted payload keeps its length, so the indexes still point to the same positions.
+        let mut reencrypted_messages = 0;
+        let mut written_bytes = 0;
+        while written_bytes < size_bytes {
+            // The batch starts at the message under the current position, so the compaction gaps are skipped.
+            let relative_offset = match read_offset(&segment.log_path, written_bytes).await {
+                Some(offset) if offset >= segment.start_offset => {
+                    (offset - segment.start_offset) as u32
+                }
+                _ => break,
+            };
+            let index_range = IndexRange {
+                start: Index {
+                    relative_offset,
+                    position: written_bytes as u32,
+                },
+                end: Index {
+                    relative_offset: relative_offset.saturating_add(REENCRYPTION_BATCH_SIZE - 1),
+                    position: u32::MAX,
+                },
+            };
+            let mut messages_bytes = Vec::new();
+            load_messages_by_range(segment, &index_range, |message: Message| {
+                if key_ring.is_encrypted_with_key(&message.payload, key_id)? {
+                    message.extend(&mut messages_bytes);
+                    return Ok(());
+                }
+
+                // The legacy payloads have no key ID prefix, so re-encrypting them would change their length.
+                // They're kept as they are and decrypted with the legacy key.
+                let payload = key_ring.reencrypt(&message.payload, key_id)?;
+                if payload.len() != message.payload.len() {
+                    message.extend(&mut messages_bytes);
+                    return Ok(());
+                }
+
+                let checksum = checksum::calculate(&payload);
+                let message = Message::create(
+                    message.offset,
+                    message.state,
+                    message.timestamp,
+                    message.id,
+                    Bytes::from(payload),
+                    checksum,
+                    message.headers,
+                );
+                message.extend(&mut messages_bytes);
+                reencrypted_messages += 1;
+                Ok(())
+            })
+            .await?;
+
+            // The position doesn't move past the unparsable batch, so the rest of the segment is left as it is.
+            if messages_bytes.is_empty() {
+                break;
+            }
+
+            written_bytes += messages_bytes.len() as u64;
+            if let Err(error) = self
+                .persister
+                .append(&reencrypted_path, &messages_bytes)
+                .await
+            {
+                error!(
+                    "Cannot save re-encrypted segment file: {}. Error: {}",
+                    reencrypted_path, error
+                );
+                return Err(Error::CannotReencryptSegment(
+                    segment.start_offset,
+                    segment.partition_id,
+                ));
+            }
+        }
+
+        if reencrypted_messages == 0 {
+            self.persister.delete(&reencrypted_path).await?;
+            return Ok(0);
+        }
+
+        if written_bytes != size_bytes {
+            error!(
+                "Re-encrypted segment with start offset: {} has size: {} bytes, expected: {} bytes.",
+                segment.start_offset, written_bytes, size_bytes
+            );
+            self.persister.delete(&reencrypted_path).await?;
+            return Err(Error::CannotReencryptSegment(
+                segment.start_offset,
+                segment.partition_id,
+            ));
+        }
+
+        if let Err(error) = fs::rename(&reencrypted_path, &segment.log_path).await {
+            error!(
+                "Cannot replace segment file: {} with re-encrypted one. Error: {}",
+                segment.log_path, error
+            );
+            return Err(Error::CannotReencryptSegment(
+                segment.start_offset,
+                segment.partition_id,
+            ));
+        }
+
+        Ok(reencrypted_messages)
+    }
+
+    async fn save_message_state(
+        &self,
+        segment: &Segment,
+        offset: u64,
+        state: MessageState,
+    ) -> Result<(), Error> {
+        if offset < segment.start_offset || offset > segment.current_offset {
+            return Err(Error::MessageNotFound(offset, segment.partition_id));
+        }
+
+        let relative_offset = (offset - segment.start_offset) as u32;
+        let mut index_file = file::open(&segment.index_path).await?;
+        if index_file.metadata().await?.len() < ((relative_offset + 1) * INDEX_SIZE) as u64 {
+            return Err(Error::MessageNotFound(offset, segment.partition_id));
+        }
+
+        index_file
+            .seek(SeekFrom::Start((relative_offset * INDEX_SIZE) as u64))
+            .await?;
+        let position = index_file.read_u32_le().await? as u64;
+
+        // After the compaction, the index of a removed message points to the next retained one.
+        let mut log_file = file::open(&segment.log_path).await?;
+        if log_file.metadata().await?.len() < position + 9 {
+            return Err(Error::MessageNotFound(offset, segment.partition_id));
+        }
+
+        log_file.seek(SeekFrom::Start(position)).await?;
+        if log_file.read_u64_le().await? != offset {
+            return Err(Error::MessageNotFound(offset, segment.partition_id));
+        }
+
+        if let Err(error) = self
+            .persister
+            .write_at(&segment.log_path, position + 8, &[state.as_code()])
+            .await
+        {
+            error!(
+                "Cannot save state: {} for message with offset: {} in segment with start offset: {}. Error: {}",
+                state, offset, segment.start_offset, error
+            );
+            return Err(Error::CannotSaveMessageState(offset, segment.partition_id));
+        }
+
+        Ok(())
+    }
+}
+
+async fn read_offset(path: &str, position: u64) -> Option<u64> {
+    let mut file = file::open(path).await.ok()?;
+    file.seek(SeekFrom::Start(position)).await.ok()?;
+    file.read_u64_le().await.ok()
 }
 
 async fn load_messages_by_range(
@@ -500,6 +864,7 @@ async fn load_messages_by_range(
     let mut read_messages = 0;
     let messages_count =
         (1 + index_range.end.relative_offset - index_range.start.relative_offset) as usize;
+    let end_offset = segment.start_offset + index_range.end.relative_offset as u64;
 
     while read_messages < messages_count {
         let offset = reader.read_u64_le().await;
@@ -507,6 +872,12 @@ async fn load_messages_by_range(
             break;
         }
 
+        // Compacted segments may have gaps, so the range can end before reading all the messages.
+        let offset = offset.unwrap();
+        if offset > end_offset {
+            break;
+        }
+
         let state = reader.read_u8().await;
         if state.is_err() {
             return Err(Error::CannotReadMessageState);
@@ -557,7 +928,6 @@ async fn load_messages_by_range(
             return Err(Error::CannotReadMessagePayload);
         }
 
-        let offset = offset.unwrap();
         let timestamp = timestamp.unwrap();
         let id = id.unwrap();
         let checksum = checksum.unwrap();
@@ -1,6 +1,11 @@
-use crate::streaming::partitions::partition::{ConsumerOffset, Partition};
+use crate::streaming::encryption::key_ring::KeyRing;
+use crate::streaming::partitions::partition::{ConsumerOffset, Partition, RecoveredState};
 use crate::streaming::partitions::storage::FilePartitionStorage;
 use crate::streaming::persistence::persister::Persister;
+use crate::streaming::personal_access_tokens::personal_access_token::PersonalAccessToken;
+use crate::streaming::personal_access_tokens::storage::FilePersonalAccessTokenStorage;
+use crate::streaming::roles::role::Role;
+use crate::streaming::roles::storage::FileRoleStorage;
 use crate::streaming::segments::index::{Index, IndexRange};
 use crate::streaming::segments::segment::Segment;
 use crate::streaming::segments::storage::FileSegmentStorage;
@@ -16,8 +21,10 @@ use crate::streaming::users::storage::FileUserStorage;
 use crate::streaming::users::user::User;
 use async_trait::async_trait;
 use iggy::error::Error;
-use iggy::models::messages::Message;
+use iggy::models::header::HeaderKey;
+use iggy::models::messages::{Message, MessageState};
 use sled::Db;
+use std::collections::HashSet;
 use std::fmt::{Debug, Formatter};
 use std::sync::Arc;
 
@@ -29,7 +36,12 @@ pub trait Storage<T>: Sync + Send {
 }
 
 #[async_trait]
-pub trait SystemInfoStorage: Storage<SystemInfo> {}
+pub trait SystemInfoStorage: Storage<SystemInfo> {
+    async fn generate_producer_id(&self) -> Result<u64, Error>;
+    async fn is_producer_id_issued(&self, producer_id: u64) -> Result<bool, Error>;
+    async fn generate_transaction_id(&self) -> Result<u64, Error>;
+    async fn load_or_create_secret(&self, name: &str) -> Result<Vec<u8>, Error>;
+}
 
 #[async_trait]
 pub trait UserStorage: Storage<User> {
@@ -38,6 +50,21 @@ pub trait UserStorage: Storage<User> {
     async fn load_all(&self) -> Result<Vec<User>, Error>;
 }
 
+#[async_trait]
+pub trait PersonalAccessTokenStorage: Storage<PersonalAccessToken> {
+    async fn load_all(&self) -> Result<Vec<PersonalAccessToken>, Error>;
+    async fn load_for_user(&self, user_id: u32) -> Result<Vec<PersonalAccessToken>, Error>;
+    async fn load_by_token(&self, token_hash: &str) -> Result<PersonalAccessToken, Error>;
+    async fn load_by_name(&self, user_id: u32, name: &str) -> Result<PersonalAccessToken, Error>;
+}
+
+#[async_trait]
+pub trait RoleStorage: Storage<Role> {
+    async fn load_by_id(&self, id: u32) -> Result<Role, Error>;
+    async fn load_by_name(&self, name: &str) -> Result<Role, Error>;
+    async fn load_all(&self) -> Result<Vec<Role>, Error>;
+}
+
 #[async_trait]
 pub trait StreamStorage: Storage<Stream> {}
 
@@ -65,6 +92,7 @@ pub trait TopicStorage: Storage<Topic> {
 #[async_trait]
 pub trait PartitionStorage: Storage<Partition> {
     async fn save_offset(&self, offset: &ConsumerOffset) -> Result<(), Error>;
+    async fn delete_offset(&self, offset: &ConsumerOffset) -> Result<(), Error>;
 }
 
 #[async_trait]
@@ -80,6 +108,7 @@ pub trait SegmentStorage: Storage<Segment> {
         messages: &[Arc<Message>],
     ) -> Result<u32, Error>;
     async fn load_message_ids(&self, segment: &Segment) -> Result<Vec<u128>, Error>;
+    async fn load_recovered_state(&self, segment: &Segment) -> Result<RecoveredState, Error>;
     async fn load_checksums(&self, segment: &Segment) -> Result<(), Error>;
     async fn load_all_indexes(&self, segment: &Segment) -> Result<Vec<Index>, Error>;
     async fn load_index_range(
@@ -102,12 +131,36 @@ pub trait SegmentStorage: Storage<Segment> {
         segment: &Segment,
         messages: &[Arc<Message>],
     ) -> Result<(), Error>;
+    async fn load_message_keys(
+        &self,
+        segment: &Segment,
+        compaction_key: &HeaderKey,
+    ) -> Result<Vec<(u64, u128)>, Error>;
+    async fn save_compacted_messages(
+        &self,
+        segment: &Segment,
+        removed_offsets: &HashSet<u64>,
+    ) -> Result<u32, Error>;
+    async fn save_reencrypted_messages(
+        &self,
+        segment: &Segment,
+        key_ring: &KeyRing,
+        key_id: u32,
+    ) -> Result<u64, Error>;
+    async fn save_message_state(
+        &self,
+        segment: &Segment,
+        offset: u64,
+        state: MessageState,
+    ) -> Result<(), Error>;
 }
 
 #[derive(Debug)]
 pub struct SystemStorage {
     pub info: Arc<dyn SystemInfoStorage>,
     pub user: Arc<dyn UserStorage>,
+    pub personal_access_token: Arc<dyn PersonalAccessTokenStorage>,
+    pub role: Arc<dyn RoleStorage>,
     pub stream: Arc<dyn StreamStorage>,
     pub topic: Arc<dyn TopicStorage>,
     pub partition: Arc<dyn PartitionStorage>,
@@ -119,6 +172,8 @@ impl SystemStorage {
         Self {
             info: Arc::new(FileSystemInfoStorage::new(db.clone())),
             user: Arc::new(FileUserStorage::new(db.clone())),
+            personal_access_token: Arc::new(FilePersonalAccessTokenStorage::new(db.clone())),
+            role: Arc::new(FileRoleStorage::new(db.clone())),
             stream: Arc::new(FileStreamStorage::new(db.clone())),
             topic: Arc::new(FileTopicStorage::new(db.clone(), persister.clone())),
             partition: Arc::new(FilePartitionStorage::new(db.clone(), persister.clone())),
@@ -139,6 +194,18 @@ impl Debug for dyn UserStorage {
     }
 }
 
+impl Debug for dyn PersonalAccessTokenStorage {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "PersonalAccessTokenStorage")
+    }
+}
+
+impl Debug for dyn RoleStorage {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "RoleStorage")
+    }
+}
+
 impl Debug for dyn StreamStorage {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "StreamStorage")
@@ -165,6 +232,7 @@ impl Debug for dyn SegmentStorage {
 
 #[cfg(test)]
 pub(crate) mod tests {
+    use crate::streaming::encryption::key_ring::KeyRing;
     use crate::streaming::partitions::partition::Partition;
     use crate::streaming::segments::index::{Index, IndexRange};
     use crate::streaming::segments::segment::Segment;
@@ -178,6 +246,8 @@ pub(crate) mod tests {
 
     struct TestSystemInfoStorage {}
     struct TestUserStorage {}
+    struct TestPersonalAccessTokenStorage {}
+    struct TestRoleStorage {}
     struct TestStreamStorage {}
     struct TestTopicStorage {}
     struct TestPartitionStorage {}
@@ -199,7 +269,23 @@ pub(crate) mod tests {
     }
 
     #[async_trait]
-    impl SystemInfoStorage for TestSystemInfoStorage {}
+    impl SystemInfoStorage for TestSystemInfoStorage {
+        async fn generate_producer_id(&self) -> Result<u64, Error> {
+            Ok(1)
+        }
+
+        async fn is_producer_id_issued(&self, _producer_id: u64) -> Result<bool, Error> {
+            Ok(true)
+        }
+
+        async fn generate_transaction_id(&self) -> Result<u64, Error> {
+            Ok(1)
+        }
+
+        async fn load_or_create_secret(&self, _name: &str) -> Result<Vec<u8>, Error> {
+            Ok(vec![1; 32])
+        }
+    }
 
     #[async_trait]
     impl Storage<User> for TestUserStorage {
@@ -231,6 +317,77 @@ pub(crate) mod tests {
         }
     }
 
+    #[async_trait]
+    impl Storage<PersonalAccessToken> for TestPersonalAccessTokenStorage {
+        async fn load(
+            &self,
+            _personal_access_token: &mut PersonalAccessToken,
+        ) -> Result<(), Error> {
+            Ok(())
+        }
+
+        async fn save(&self, _personal_access_token: &PersonalAccessToken) -> Result<(), Error> {
+            Ok(())
+        }
+
+        async fn delete(&self, _personal_access_token: &PersonalAccessToken) -> Result<(), Error> {
+            Ok(())
+        }
+    }
+
+    #[async_trait]
+    impl PersonalAccessTokenStorage for TestPersonalAccessTokenStorage {
+        async fn load_all(&self) -> Result<Vec<PersonalAccessToken>, Error> {
+            Ok(vec![])
+        }
+
+        async fn load_for_user(&self, _user_id: u32) -> Result<Vec<PersonalAccessToken>, Error> {
+            Ok(vec![])
+        }
+
+        async fn load_by_token(&self, _token_hash: &str) -> Result<PersonalAccessToken, Error> {
+            Ok(PersonalAccessToken::default())
+        }
+
+        async fn load_by_name(
+            &self,
+            _user_id: u32,
+            _name: &str,
+        ) -> Result<PersonalAccessToken, Error> {
+            Ok(PersonalAccessToken::default())
+        }
+    }
+
+    #[async_trait]
+    impl Storage<Role> for TestRoleStorage {
+        async fn load(&self, _role: &mut Role) -> Result<(), Error> {
+            Ok(())
+        }
+
+        async fn save(&self, _role: &Role) -> Result<(), Error> {
+            Ok(())
+        }
+
+        async fn delete(&self, _role: &Role) -> Result<(), Error> {
+            Ok(())
+        }
+    }
+
+    #[async_trait]
+    impl RoleStorage for TestRoleStorage {
+        async fn load_by_id(&self, _id: u32) -> Result<Role, Error> {
+            Ok(Role::default())
+        }
+
+        async fn load_by_name(&self, _name: &str) -> Result<Role, Error> {
+            Ok(Role::default())
+        }
+
+        async fn load_all(&self) -> Result<Vec<Role>, Error> {
+            Ok(vec![])
+        }
+    }
+
     #[async_trait]
     impl Storage<Stream> for TestStreamStorage {
         async fn load(&self, _stream: &mut Stream) -> Result<(), Error> {
@@ -322,6 +479,10 @@ pub(crate) mod tests {
         async fn save_offset(&self, _offset: &ConsumerOffset) -> Result<(), Error> {
             Ok(())
         }
+
+        async fn delete_offset(&self, _offset: &ConsumerOffset) -> Result<(), Error> {
+            Ok(())
+        }
     }
 
     #[async_trait]
@@ -361,6 +522,10 @@ pub(crate) mod tests {
             Ok(vec![])
         }
 
+        async fn load_recovered_state(&self, _segment: &Segment) -> Result<RecoveredState, Error> {
+            Ok(RecoveredState::default())
+        }
+
         async fn load_checksums(&self, _segment: &Segment) -> Result<(), Error> {
             Ok(())
         }
@@ -406,12 +571,48 @@ pub(crate) mod tests {
         ) -> Result<(), Error> {
             Ok(())
         }
+
+        async fn load_message_keys(
+            &self,
+            _segment: &Segment,
+            _compaction_key: &HeaderKey,
+        ) -> Result<Vec<(u64, u128)>, Error> {
+            Ok(vec![])
+        }
+
+        async fn save_compacted_messages(
+            &self,
+            _segment: &Segment,
+            _removed_offsets: &HashSet<u64>,
+        ) -> Result<u32, Error> {
+            Ok(0)
+        }
+
+        async fn save_reencrypted_messages(
+            &self,
+            _segment: &Segment,
+            _key_ring: &KeyRing,
+            _key_id: u32,
+        ) -> Result<u64, Error> {
+            Ok(0)
+        }
+
+        async fn save_message_state(
+            &self,
+            _segment: &Segment,
+            _offset: u64,
+            _state: MessageState,
+        ) -> Result<(), Error> {
+            Ok(())
+        }
     }
 
     pub fn get_test_system_storage() -> SystemStorage {
         SystemStorage {
             info: Arc::new(TestSystemInfoStorage {}),
             user: Arc::new(TestUserStorage {}),
+            personal_access_token: Arc::new(TestPersonalAccessTokenStorage {}),
+            role: Arc::new(TestRoleStorage {}),
             stream: Arc::new(TestStreamStorage {}),
             topic: Arc::new(TestTopicStorage {}),
             partition: Arc::new(TestPartitionStorage {}),
@@ -4,6 +4,7 @@ use crate::streaming::topics::topic::Topic;
 use async_trait::async_trait;
 use futures::future::join_all;
 use iggy::error::Error;
+use iggy::models::stream_encryption::StreamEncryption;
 use serde::{Deserialize, Serialize};
 use sled::Db;
 use std::path::Path;
@@ -33,6 +34,8 @@ impl StreamStorage for FileStreamStorage {}
 struct StreamData {
     name: String,
     created_at: u64,
+    #[serde(default)]
+    encryption: Option<StreamEncryption>,
 }
 
 #[async_trait]
@@ -63,6 +66,7 @@ impl Storage<Stream> for FileStreamStorage {
         let stream_data = stream_data.unwrap();
         stream.name = stream_data.name;
         stream.created_at = stream_data.created_at;
+        stream.encryption = stream_data.encryption;
         let mut unloaded_topics = Vec::new();
         let dir_entries = fs::read_dir(&stream.topics_path).await;
         if dir_entries.is_err() {
@@ -151,6 +155,7 @@ impl Storage<Stream> for FileStreamStorage {
         match rmp_serde::to_vec(&StreamData {
             name: stream.name.clone(),
             created_at: stream.created_at,
+            encryption: stream.encryption,
         }) {
             Ok(data) => {
                 if let Err(err) = self.db.insert(&key, data) {
@@ -1,6 +1,7 @@
 use crate::configs::system::SystemConfig;
 use crate::streaming::storage::SystemStorage;
 use crate::streaming::topics::topic::Topic;
+use iggy::models::stream_encryption::StreamEncryption;
 use iggy::utils::timestamp::TimeStamp;
 use std::collections::HashMap;
 use std::sync::Arc;
@@ -12,6 +13,7 @@ pub struct Stream {
     pub path: String,
     pub topics_path: String,
     pub created_at: u64,
+    pub encryption: Option<StreamEncryption>,
     pub(crate) topics: HashMap<u32, Topic>,
     pub(crate) topics_ids: HashMap<String, u32>,
     pub(crate) config: Arc<SystemConfig>,
@@ -42,6 +44,7 @@ impl Stream {
             topics_ids: HashMap::new(),
             storage,
             created_at: TimeStamp::now().to_micros(),
+            encryption: None,
         }
     }
 
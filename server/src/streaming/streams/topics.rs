@@ -1,7 +1,8 @@
 use crate::streaming::streams::stream::Stream;
-use crate::streaming::topics::topic::Topic;
+use crate::streaming::topics::topic::{Topic, TopicSettings};
 use iggy::error::Error;
 use iggy::identifier::{IdKind, Identifier};
+use iggy::models::header::HeaderKey;
 use iggy::utils::text;
 use tracing::info;
 
@@ -12,6 +13,7 @@ impl Stream {
         name: &str,
         partitions_count: u32,
         message_expiry: Option<u32>,
+        settings: TopicSettings,
     ) -> Result<(), Error> {
         if self.topics.contains_key(&id) {
             return Err(Error::TopicIdAlreadyExists(id, self.stream_id));
@@ -22,7 +24,9 @@ impl Stream {
             return Err(Error::TopicNameAlreadyExists(name, self.stream_id));
         }
 
-        let topic = Topic::create(
+        self.validate_dead_letter_topic(id, settings.dead_letter_topic_id)?;
+        let compaction_key = map_compaction_key(settings.compaction_key.as_deref())?;
+        let mut topic = Topic::create(
             self.stream_id,
             id,
             &name,
@@ -31,10 +35,15 @@ impl Stream {
             self.storage.clone(),
             message_expiry,
         )?;
+        topic.cleanup_policy = settings.cleanup_policy;
+        topic.compaction_key = compaction_key;
+        topic.max_topic_size_bytes = settings.max_topic_size_bytes;
+        topic.max_messages = settings.max_messages;
+        topic.dead_letter_topic_id = settings.dead_letter_topic_id;
         topic.persist().await?;
         info!(
-            "Created topic: {} with ID: {}, partitions: {}",
-            name, id, partitions_count
+            "Created topic: {} with ID: {}, partitions: {}, cleanup policy: {}",
+            name, id, partitions_count, topic.cleanup_policy
         );
         self.topics_ids.insert(name, id);
         self.topics.insert(id, topic);
@@ -47,13 +56,17 @@ impl Stream {
         id: &Identifier,
         name: &str,
         message_expiry: Option<u32>,
+        settings: TopicSettings,
     ) -> Result<(), Error> {
+        let compaction_key = map_compaction_key(settings.compaction_key.as_deref())?;
         let topic_id;
         {
             let topic = self.get_topic(id)?;
             topic_id = topic.topic_id;
         }
 
+        self.validate_dead_letter_topic(topic_id, settings.dead_letter_topic_id)?;
+
         let updated_name = text::to_lowercase_non_whitespace(name);
 
         {
@@ -73,6 +86,11 @@ impl Stream {
             let topic = self.get_topic_mut(id)?;
             topic.name = updated_name;
             topic.message_expiry = message_expiry;
+            topic.cleanup_policy = settings.cleanup_policy;
+            topic.compaction_key = compaction_key;
+            topic.max_topic_size_bytes = settings.max_topic_size_bytes;
+            topic.max_messages = settings.max_messages;
+            topic.dead_letter_topic_id = settings.dead_letter_topic_id;
             for partition in topic.partitions.values_mut() {
                 let mut partition = partition.write().await;
                 partition.message_expiry = message_expiry;
@@ -88,6 +106,24 @@ impl Stream {
         Ok(())
     }
 
+    fn validate_dead_letter_topic(
+        &self,
+        topic_id: u32,
+        dead_letter_topic_id: Option<u32>,
+    ) -> Result<(), Error> {
+        if let Some(dead_letter_topic_id) = dead_letter_topic_id {
+            if dead_letter_topic_id == topic_id {
+                return Err(Error::InvalidDeadLetterTopic(dead_letter_topic_id));
+            }
+
+            if !self.topics.contains_key(&dead_letter_topic_id) {
+                return Err(Error::TopicIdNotFound(dead_letter_topic_id, self.stream_id));
+            }
+        }
+
+        Ok(())
+    }
+
     pub fn get_topic(&self, identifier: &Identifier) -> Result<&Topic, Error> {
         match identifier.kind {
             IdKind::Numeric => self.get_topic_by_id(identifier.get_u32_value().unwrap()),
@@ -156,6 +192,16 @@ impl Stream {
     }
 }
 
+fn map_compaction_key(compaction_key: Option<&str>) -> Result<Option<HeaderKey>, Error> {
+    match compaction_key {
+        Some(compaction_key) => match HeaderKey::new(compaction_key) {
+            Ok(compaction_key) => Ok(Some(compaction_key)),
+            Err(_) => Err(Error::InvalidCompactionKey),
+        },
+        None => Ok(None),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -174,7 +220,13 @@ mod tests {
         let storage = Arc::new(get_test_system_storage());
         let mut stream = Stream::create(stream_id, stream_name, config, storage);
         stream
-            .create_topic(topic_id, topic_name, 1, message_expiry)
+            .create_topic(
+                topic_id,
+                topic_name,
+                1,
+                message_expiry,
+                TopicSettings::default(),
+            )
             .await
             .unwrap();
 
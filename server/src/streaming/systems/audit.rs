@@ -0,0 +1,147 @@
+use crate::streaming::audit::audit_log::AuditRecord;
+use crate::streaming::streams::stream::Stream;
+use crate::streaming::systems::system::System;
+use crate::streaming::topics::topic::TopicSettings;
+use bytes::Bytes;
+use iggy::error::Error;
+use iggy::identifier::Identifier;
+use iggy::messages::send_messages::{Message, Partitioning};
+use iggy::utils::text;
+use std::net::SocketAddr;
+use tracing::{error, info};
+
+const UNKNOWN: &str = "unknown";
+const HTTP_TRANSPORT: &str = "HTTP";
+const AUDIT_TOPIC_ID: u32 = 1;
+const AUDIT_TOPIC_NAME: &str = "records";
+
+impl System {
+    // The internal stream is created on the first start, so the records can be read with the poll API.
+    pub(crate) async fn init_audit_stream(&mut self) -> Result<(), Error> {
+        if !self.config.audit.enabled || self.config.audit.stream.is_empty() {
+            return Ok(());
+        }
+
+        let name = text::to_lowercase_non_whitespace(&self.config.audit.stream);
+        if self.streams_ids.contains_key(&name) {
+            info!("Audit records will be published to stream: '{}'.", name);
+            return Ok(());
+        }
+
+        let stream_id = self
+            .streams
+            .keys()
+            .max()
+            .map_or(1, |stream_id| stream_id + 1);
+        let mut stream =
+            Stream::create(stream_id, &name, self.config.clone(), self.storage.clone());
+        stream.persist().await?;
+        stream
+            .create_topic(
+                AUDIT_TOPIC_ID,
+                AUDIT_TOPIC_NAME,
+                1,
+                None,
+                TopicSettings::default(),
+            )
+            .await?;
+        info!(
+            "Created audit stream with ID: {}, name: '{}'.",
+            stream_id, name
+        );
+        self.streams_ids.insert(name, stream_id);
+        self.streams.insert(stream_id, stream);
+        Ok(())
+    }
+
+    pub async fn audit_client_command(
+        &self,
+        client_id: u32,
+        user_id: u32,
+        command: &str,
+        result: &str,
+    ) {
+        if !self.audit_log.is_enabled() {
+            return;
+        }
+
+        let (client_address, transport) = {
+            let client_manager = self.client_manager.read().await;
+            match client_manager.get_client_by_id(client_id) {
+                Ok(client) => {
+                    let client = client.read().await;
+                    (client.address.to_string(), client.transport.to_string())
+                }
+                Err(_) => (UNKNOWN.to_string(), UNKNOWN.to_string()),
+            }
+        };
+
+        let record = AuditRecord::new(user_id, &client_address, &transport, command, result);
+        self.audit_log.append(&record).await;
+        self.publish_audit_record(&record).await;
+    }
+
+    pub async fn audit_http_request(
+        &self,
+        address: Option<&SocketAddr>,
+        user_id: u32,
+        command: &str,
+        result: &str,
+    ) {
+        if !self.audit_log.is_enabled() {
+            return;
+        }
+
+        let client_address = match address {
+            Some(address) => address.to_string(),
+            None => UNKNOWN.to_string(),
+        };
+        let record = AuditRecord::new(user_id, &client_address, HTTP_TRANSPORT, command, result);
+        self.audit_log.append(&record).await;
+        self.publish_audit_record(&record).await;
+    }
+
+    async fn publish_audit_record(&self, record: &AuditRecord) {
+        if self.config.audit.stream.is_empty() {
+            return;
+        }
+
+        let payload = match serde_json::to_vec(record) {
+            Ok(payload) => Bytes::from(payload),
+            Err(err) => {
+                error!(
+                    "Cannot serialize audit record: {:?}. Error: {}",
+                    record, err
+                );
+                return;
+            }
+        };
+        let message = Message {
+            id: 0,
+            length: payload.len() as u32,
+            payload,
+            headers: None,
+        };
+        let stream_name = text::to_lowercase_non_whitespace(&self.config.audit.stream);
+        let result = match Identifier::named(&stream_name) {
+            Ok(stream_id) => {
+                self.append_messages(
+                    &stream_id,
+                    &Identifier::numeric(AUDIT_TOPIC_ID).unwrap(),
+                    &Partitioning::balanced(),
+                    None,
+                    None,
+                    &vec![message],
+                )
+                .await
+            }
+            Err(error) => Err(error),
+        };
+        if let Err(error) = result {
+            error!(
+                "Cannot publish audit record: {:?} to stream: {}. Error: {}",
+                record, self.config.audit.stream, error
+            );
+        }
+    }
+}
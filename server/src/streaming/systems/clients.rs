@@ -4,15 +4,19 @@ use iggy::error::Error;
 use iggy::identifier::Identifier;
 use std::net::SocketAddr;
 use std::sync::Arc;
-use tokio::sync::RwLock;
+use tokio::sync::{Notify, RwLock};
 use tracing::{error, info};
 
 impl System {
-    pub async fn add_client(&self, address: &SocketAddr, transport: Transport) -> u32 {
+    pub async fn add_client(
+        &self,
+        address: &SocketAddr,
+        transport: Transport,
+    ) -> (u32, Arc<Notify>) {
         let mut client_manager = self.client_manager.write().await;
-        let client_id = client_manager.add_client(address, transport);
+        let (client_id, revocation) = client_manager.add_client(address, transport);
         info!("Added {transport} client with ID: {client_id} for address: {address}");
-        client_id
+        (client_id, revocation)
     }
 
     pub async fn delete_client(&self, address: &SocketAddr) {
@@ -54,6 +58,15 @@ impl System {
             }
         }
 
+        // The abandoned transaction would otherwise hold the last stable offset of its partitions.
+        let aborted_transactions = self.abort_client_transactions(client_id).await;
+        if aborted_transactions > 0 {
+            info!(
+                "Aborted {} transactions of client with ID: {}",
+                aborted_transactions, client_id
+            );
+        }
+
         {
             let mut client_manager = self.client_manager.write().await;
             let client = client_manager.delete_client(address);
@@ -63,6 +76,10 @@ impl System {
 
             let client = client.unwrap();
             let client = client.read().await;
+            self.quota_manager
+                .lock()
+                .await
+                .delete_client(client.client_id);
 
             info!(
                 "Deleted {} client with ID: {} for address: {}",
@@ -80,4 +97,24 @@ impl System {
         let client_manager = self.client_manager.read().await;
         client_manager.get_clients()
     }
+
+    pub async fn get_sessions(&self, user_id: Option<u32>) -> Vec<Arc<RwLock<Client>>> {
+        let client_manager = self.client_manager.read().await;
+        client_manager.get_sessions(user_id).await
+    }
+
+    pub async fn revoke_session(&self, client_id: u32) -> Result<(), Error> {
+        let client_manager = self.client_manager.read().await;
+        client_manager.revoke_session(client_id).await?;
+        info!("Revoked session for client with ID: {client_id}.");
+        Ok(())
+    }
+
+    pub async fn revoke_user_sessions(&self, user_id: u32) {
+        let client_manager = self.client_manager.read().await;
+        let client_ids = client_manager.revoke_user_sessions(user_id).await;
+        if !client_ids.is_empty() {
+            info!("Revoked sessions for user with ID: {user_id}, clients: {client_ids:?}.");
+        }
+    }
 }
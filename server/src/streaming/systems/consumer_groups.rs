@@ -1,6 +1,8 @@
 use crate::streaming::systems::system::System;
 use iggy::error::Error;
 use iggy::identifier::Identifier;
+use iggy::models::assignment_strategy::AssignmentStrategy;
+use tracing::{error, info};
 
 impl System {
     pub async fn create_consumer_group(
@@ -8,10 +10,12 @@ impl System {
         stream_id: &Identifier,
         topic_id: &Identifier,
         consumer_group_id: u32,
+        assignment_strategy: AssignmentStrategy,
+        session_timeout: u32,
     ) -> Result<(), Error> {
         self.get_stream_mut(stream_id)?
             .get_topic_mut(topic_id)?
-            .create_consumer_group(consumer_group_id)
+            .create_consumer_group(consumer_group_id, assignment_strategy, session_timeout)
             .await?;
         Ok(())
     }
@@ -81,6 +85,64 @@ impl System {
         Ok(())
     }
 
+    pub async fn heartbeat_consumer_group(
+        &self,
+        client_id: u32,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        consumer_group_id: u32,
+    ) -> Result<(), Error> {
+        self.get_stream(stream_id)?
+            .get_topic(topic_id)?
+            .heartbeat_consumer_group(consumer_group_id, client_id)
+            .await
+    }
+
+    pub async fn evict_expired_consumer_group_members(&self, now: u64) -> u32 {
+        let mut expired_members = Vec::new();
+        for stream in self.get_streams() {
+            for topic in stream.get_topics() {
+                for consumer_group in topic.get_consumer_groups() {
+                    let consumer_group = consumer_group.read().await;
+                    for member_id in consumer_group.get_expired_members(now).await {
+                        expired_members.push((
+                            stream.stream_id,
+                            topic.topic_id,
+                            consumer_group.id,
+                            member_id,
+                        ));
+                    }
+                }
+            }
+        }
+
+        let mut evicted_members = 0;
+        for (stream_id, topic_id, consumer_group_id, member_id) in expired_members {
+            if let Err(error) = self
+                .leave_consumer_group(
+                    member_id,
+                    &Identifier::numeric(stream_id).unwrap(),
+                    &Identifier::numeric(topic_id).unwrap(),
+                    consumer_group_id,
+                )
+                .await
+            {
+                error!(
+                    "Failed to evict member with ID: {} from consumer group with ID: {}. Error: {}",
+                    member_id, consumer_group_id, error
+                );
+                continue;
+            }
+
+            info!(
+                "Evicted member with ID: {} from consumer group with ID: {} for topic with ID: {} and stream with ID: {}, session timeout has expired.",
+                member_id, consumer_group_id, topic_id, stream_id
+            );
+            evicted_members += 1;
+        }
+        evicted_members
+    }
+
     pub async fn leave_consumer_group(
         &self,
         client_id: u32,
@@ -0,0 +1,45 @@
+use crate::streaming::streams::stream::Stream;
+use crate::streaming::systems::system::System;
+use iggy::error::Error;
+use iggy::models::stream_encryption::StreamEncryption;
+
+impl System {
+    // Returns the ID of the key used to encrypt the stream messages and whether their headers are encrypted too.
+    pub(crate) fn get_stream_encryption(&self, stream: &Stream) -> Option<(u32, bool)> {
+        let key_ring = self.key_ring.as_ref()?;
+        match stream.encryption {
+            Some(encryption) if !encryption.enabled => None,
+            Some(encryption) => Some((
+                encryption.key_id.unwrap_or(key_ring.get_active_key_id()),
+                encryption.encrypt_headers,
+            )),
+            None => Some((
+                key_ring.get_active_key_id(),
+                self.config.encryption.encrypt_headers,
+            )),
+        }
+    }
+
+    pub(crate) fn validate_stream_encryption(
+        &self,
+        encryption: &Option<StreamEncryption>,
+    ) -> Result<(), Error> {
+        let encryption = match encryption {
+            Some(encryption) if encryption.enabled => encryption,
+            _ => return Ok(()),
+        };
+
+        let key_ring = match &self.key_ring {
+            Some(key_ring) => key_ring,
+            None => return Err(Error::EncryptionDisabled),
+        };
+
+        if let Some(key_id) = encryption.key_id {
+            if !key_ring.contains_key(key_id) {
+                return Err(Error::EncryptionKeyNotFound(key_id));
+            }
+        }
+
+        Ok(())
+    }
+}
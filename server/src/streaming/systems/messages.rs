@@ -1,15 +1,30 @@
+use crate::streaming::encryption::headers;
 use crate::streaming::models::messages::PolledMessages;
+use crate::streaming::polling_args::PollingArgs;
 use crate::streaming::polling_consumer::PollingConsumer;
 use crate::streaming::systems::system::System;
+use crate::streaming::transactions::transaction_manager::TransactionPartition;
 use bytes::Bytes;
+use futures::future::select_all;
 use iggy::error::Error;
 use iggy::identifier::Identifier;
-use iggy::messages::poll_messages::PollingStrategy;
+use iggy::messages::poll_messages::PollingKind;
 use iggy::messages::send_messages;
-use iggy::messages::send_messages::Partitioning;
-use iggy::models::messages::Message;
+use iggy::messages::send_messages::{Partitioning, ProducerSequence, SendMessages};
+use iggy::models::header::{HeaderKey, HeaderValue};
+use iggy::models::messages::{Message, MessageState};
+use iggy::utils::crypto::Encryptor;
+use iggy::utils::timestamp::TimeStamp;
+use std::collections::HashMap;
 use std::sync::Arc;
-use tracing::{error, trace};
+use std::time::Duration;
+use tokio::sync::{watch, RwLock};
+use tokio::time::{timeout_at, Instant};
+use tracing::{error, info, trace};
+
+const DEAD_LETTER_SOURCE_TOPIC_HEADER: &str = "iggy-dead-letter-source-topic";
+const DEAD_LETTER_SOURCE_PARTITION_HEADER: &str = "iggy-dead-letter-source-partition";
+const DEAD_LETTER_SOURCE_OFFSET_HEADER: &str = "iggy-dead-letter-source-offset";
 
 impl System {
     pub async fn poll_messages(
@@ -17,11 +32,9 @@ impl System {
         consumer: PollingConsumer,
         stream_id: &Identifier,
         topic_id: &Identifier,
-        strategy: PollingStrategy,
-        count: u32,
-        auto_commit: bool,
+        args: &PollingArgs,
     ) -> Result<PolledMessages, Error> {
-        if count == 0 {
+        if args.count == 0 {
             return Err(Error::InvalidMessagesCount);
         }
 
@@ -31,42 +44,83 @@ impl System {
             return Err(Error::NoPartitions(topic.topic_id, topic.stream_id));
         }
 
+        // Only the reserved headers are stored in plaintext, when the headers are encrypted.
+        if let Some((_, true)) = self.get_stream_encryption(stream) {
+            if let Some(filter) = args
+                .filters
+                .iter()
+                .find(|filter| !headers::is_reserved_header(&filter.key))
+            {
+                return Err(Error::CannotFilterEncryptedHeader(
+                    filter.key.as_str().to_string(),
+                ));
+            }
+        }
+
+        let mut generation_id = None;
+        let mut assignment_changed = false;
         let partition_id = match consumer {
             PollingConsumer::Consumer(_, partition_id) => partition_id,
             PollingConsumer::ConsumerGroup(consumer_group_id, member_id) => {
-                let consumer_group = topic.get_consumer_group(consumer_group_id)?.read().await;
-                consumer_group.calculate_partition_id(member_id).await?
+                let mut consumer_group = topic.get_consumer_group(consumer_group_id)?.write().await;
+                let partition_id = consumer_group.calculate_partition_id(member_id).await?;
+                generation_id = Some(consumer_group.generation_id);
+                assignment_changed = consumer_group.acknowledge_assignment(member_id).await?;
+                match partition_id {
+                    Some(partition_id) => partition_id,
+                    // The member has no partitions assigned at the moment, e.g. they are still being revoked.
+                    None => {
+                        return Ok(PolledMessages {
+                            partition_id: 0,
+                            current_offset: 0,
+                            scanned_offset: None,
+                            generation_id,
+                            assignment_changed,
+                            messages: vec![],
+                        })
+                    }
+                }
             }
         };
 
-        let mut polled_messages = topic
-            .get_messages(consumer, partition_id, strategy, count)
-            .await?;
+        let mut polled_messages = topic.get_messages(consumer, partition_id, args).await?;
+        polled_messages.generation_id = generation_id;
+        polled_messages.assignment_changed = assignment_changed;
 
-        if polled_messages.messages.is_empty() {
-            return Ok(polled_messages);
+        // The offset is stored even if none of the scanned messages matched the filters.
+        let offset = match polled_messages.scanned_offset {
+            Some(offset) => offset,
+            None => return Ok(polled_messages),
+        };
+
+        // The messages are decrypted before the offset is stored, so they are not skipped if the decryption fails.
+        if self.get_stream_encryption(stream).is_some() {
+            polled_messages.messages = self.decrypt_messages(&polled_messages.messages)?;
         }
 
-        let offset = polled_messages.messages.last().unwrap().offset;
-        if auto_commit {
+        if args.auto_commit {
             trace!("Last offset: {} will be automatically stored for {}, stream: {}, topic: {}, partition: {}", offset, consumer, stream_id, topic_id, partition_id);
             topic.store_consumer_offset(consumer, offset).await?;
         }
 
-        if self.encryptor.is_none() {
-            return Ok(polled_messages);
-        }
+        Ok(polled_messages)
+    }
 
-        let encryptor = self.encryptor.as_ref().unwrap();
-        let mut decrypted_messages = Vec::with_capacity(polled_messages.messages.len());
-        for message in polled_messages.messages.iter() {
-            let payload = encryptor.decrypt(&message.payload);
+    fn decrypt_messages(&self, messages: &[Arc<Message>]) -> Result<Vec<Arc<Message>>, Error> {
+        let key_ring = self.key_ring.as_ref().unwrap();
+        let mut decrypted_messages = Vec::with_capacity(messages.len());
+        for message in messages.iter() {
+            let payload = key_ring.decrypt(&message.payload);
             if payload.is_err() {
-                error!("Cannot decrypt the message.");
+                error!(
+                    "Cannot decrypt the message with offset: {}. Error: {:?}",
+                    message.offset,
+                    payload.err()
+                );
                 return Err(Error::CannotDecryptData);
             }
 
-            let payload = payload.unwrap();
+            let (payload, headers) = headers::unpack_headers(payload.unwrap(), &message.headers)?;
             decrypted_messages.push(Arc::new(Message {
                 id: message.id,
                 state: message.state,
@@ -75,34 +129,243 @@ impl System {
                 checksum: message.checksum,
                 length: payload.len() as u32,
                 payload: Bytes::from(payload),
-                headers: message.headers.clone(),
+                headers,
             }));
         }
 
-        polled_messages.messages = decrypted_messages;
+        Ok(decrypted_messages)
+    }
+
+    // The system lock is released while waiting, so the appends and the other requests are not blocked.
+    pub async fn poll_messages_with_wait(
+        system: &RwLock<System>,
+        consumer: PollingConsumer,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        mut args: PollingArgs,
+    ) -> Result<PolledMessages, Error> {
+        let deadline = args
+            .max_wait_ms
+            .map(|max_wait_ms| Instant::now() + Duration::from_millis(max_wait_ms));
+        let mut scanned_offset = None;
+        loop {
+            let system = system.read().await;
+            // Subscribing before polling ensures that the messages appended in the meantime are not missed.
+            let mut listeners = match deadline {
+                Some(deadline) if Instant::now() < deadline => {
+                    system
+                        .subscribe_to_new_messages(consumer, stream_id, topic_id)
+                        .await?
+                }
+                _ => Vec::new(),
+            };
+            let polled_messages = system
+                .poll_messages_from_scanned_offset(
+                    consumer,
+                    stream_id,
+                    topic_id,
+                    &mut args,
+                    &mut scanned_offset,
+                )
+                .await?;
+            if !polled_messages.messages.is_empty() || listeners.is_empty() {
+                return Ok(polled_messages);
+            }
+
+            drop(system);
+            trace!(
+                "Waiting for the new messages for {}, stream: {}, topic: {}, partitions: {}",
+                consumer,
+                stream_id,
+                topic_id,
+                listeners.len()
+            );
+            let changes = listeners
+                .iter_mut()
+                .map(|listener| Box::pin(listener.changed()));
+            if timeout_at(deadline.unwrap(), select_all(changes))
+                .await
+                .is_err()
+            {
+                return Ok(polled_messages);
+            }
+        }
+    }
+
+    // The messages skipped by the filters are not scanned again by the next polls.
+    async fn poll_messages_from_scanned_offset(
+        &self,
+        consumer: PollingConsumer,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        args: &mut PollingArgs,
+        scanned_offset: &mut Option<u64>,
+    ) -> Result<PolledMessages, Error> {
+        let mut polled_messages = self
+            .poll_messages(consumer, stream_id, topic_id, args)
+            .await?;
+        match polled_messages.scanned_offset {
+            Some(offset) => {
+                *scanned_offset = Some(offset);
+                if args.strategy.kind == PollingKind::Offset {
+                    args.strategy.value = offset + 1;
+                }
+            }
+            None => polled_messages.scanned_offset = *scanned_offset,
+        }
+
         Ok(polled_messages)
     }
 
+    // The consumer group member is subscribed only to the partitions assigned to it.
+    pub async fn subscribe_to_new_messages(
+        &self,
+        consumer: PollingConsumer,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+    ) -> Result<Vec<watch::Receiver<u64>>, Error> {
+        let stream = self.get_stream(stream_id)?;
+        let topic = stream.get_topic(topic_id)?;
+        let partitions = match consumer {
+            PollingConsumer::Consumer(_, partition_id) => vec![partition_id],
+            PollingConsumer::ConsumerGroup(consumer_group_id, member_id) => {
+                let consumer_group = topic.get_consumer_group(consumer_group_id)?.read().await;
+                consumer_group.get_member_partitions(member_id).await?
+            }
+        };
+
+        let mut listeners = Vec::with_capacity(partitions.len());
+        for partition_id in partitions {
+            listeners.push(topic.subscribe_to_new_messages(partition_id).await?);
+        }
+        Ok(listeners)
+    }
+
+    pub async fn poison_message(
+        &self,
+        stream_id: &Identifier,
+        topic_id: &Identifier,
+        partition_id: u32,
+        offset: u64,
+    ) -> Result<(), Error> {
+        let stream = self.get_stream(stream_id)?;
+        let topic = stream.get_topic(topic_id)?;
+        // The partition stays locked until the message is poisoned, so it's moved to the dead letter topic only once.
+        let mut partition = topic.get_partition(partition_id)?.write().await;
+        let message = partition.get_message(offset).await?;
+        if message.state == MessageState::Poisoned {
+            trace!("Message with offset: {} for stream: {}, topic: {}, partition: {} is already poisoned.", offset, stream_id, topic_id, partition_id);
+            return Ok(());
+        }
+
+        // The message is poisoned only once its copy is appended, so a failed append can be retried.
+        if let Some(dead_letter_topic_id) = topic.dead_letter_topic_id {
+            let dead_letter_topic =
+                stream.get_topic(&Identifier::numeric(dead_letter_topic_id)?)?;
+            let dead_letter_message =
+                Self::create_dead_letter_message(&message, topic.topic_id, partition_id)?;
+            dead_letter_topic
+                .append_messages(
+                    &Partitioning::balanced(),
+                    None,
+                    None,
+                    vec![dead_letter_message],
+                )
+                .await?;
+            trace!("Moved poisoned message with offset: {} for stream: {}, topic: {}, partition: {} to dead letter topic: {}.", offset, stream_id, topic_id, partition_id, dead_letter_topic_id);
+        }
+
+        partition
+            .update_message_state(offset, MessageState::Poisoned)
+            .await?;
+        Ok(())
+    }
+
+    fn create_dead_letter_message(
+        message: &Message,
+        topic_id: u32,
+        partition_id: u32,
+    ) -> Result<Message, Error> {
+        // The headers reserved by the server, e.g. the producer or transaction ones, are not valid in the dead letter topic.
+        // Only the encrypted headers marker is kept, as the payload still contains the encrypted headers.
+        let encrypted_headers_key = HeaderKey::new(headers::ENCRYPTED_HEADERS_HEADER)?;
+        let mut dead_letter_headers = message
+            .headers
+            .iter()
+            .flatten()
+            .filter(|(key, _)| !headers::is_reserved_header(key) || **key == encrypted_headers_key)
+            .map(|(key, value)| (key.clone(), value.clone()))
+            .collect::<HashMap<_, _>>();
+        dead_letter_headers.insert(
+            HeaderKey::new(DEAD_LETTER_SOURCE_TOPIC_HEADER)?,
+            HeaderValue::from_uint32(topic_id)?,
+        );
+        dead_letter_headers.insert(
+            HeaderKey::new(DEAD_LETTER_SOURCE_PARTITION_HEADER)?,
+            HeaderValue::from_uint32(partition_id)?,
+        );
+        dead_letter_headers.insert(
+            HeaderKey::new(DEAD_LETTER_SOURCE_OFFSET_HEADER)?,
+            HeaderValue::from_uint64(message.offset)?,
+        );
+
+        // The payload is moved as is, so it stays encrypted if the encryption is enabled.
+        Ok(Message::empty(
+            TimeStamp::now().to_micros(),
+            MessageState::Available,
+            message.id,
+            message.payload.clone(),
+            message.checksum,
+            Some(dead_letter_headers),
+        ))
+    }
+
     pub async fn append_messages(
         &self,
         stream_id: &Identifier,
         topic_id: &Identifier,
         partitioning: &Partitioning,
+        producer: Option<&ProducerSequence>,
+        transaction_id: Option<u64>,
         messages: &Vec<send_messages::Message>,
     ) -> Result<(), Error> {
+        SendMessages::validate_reserved_headers(messages)?;
+        if let Some(producer) = producer {
+            if !self
+                .storage
+                .info
+                .is_producer_id_issued(producer.producer_id)
+                .await?
+            {
+                return Err(Error::InvalidProducerId);
+            }
+        }
+
         let stream = self.get_stream(stream_id)?;
         let topic = stream.get_topic(topic_id)?;
+        let encryption = self.get_stream_encryption(stream);
         let mut received_messages = Vec::with_capacity(messages.len());
         for message in messages {
             let encrypted_message;
-            let message = match self.encryptor {
-                Some(ref encryptor) => {
-                    let payload = encryptor.encrypt(message.payload.as_ref())?;
+            let message = match encryption {
+                Some((key_id, encrypt_headers)) => {
+                    let key_ring = self.key_ring.as_ref().unwrap();
+                    let (payload, headers) = match &message.headers {
+                        Some(headers) if encrypt_headers && !headers.is_empty() => {
+                            let (payload, headers) =
+                                headers::pack_headers(&message.payload, headers)?;
+                            (key_ring.encrypt_with_key(&payload, key_id)?, Some(headers))
+                        }
+                        _ => (
+                            key_ring.encrypt_with_key(&message.payload, key_id)?,
+                            message.headers.clone(),
+                        ),
+                    };
                     encrypted_message = send_messages::Message {
                         id: message.id,
                         length: payload.len() as u32,
                         payload: Bytes::from(payload),
-                        headers: message.headers.clone(),
+                        headers,
                     };
                     &encrypted_message
                 }
@@ -111,6 +374,48 @@ impl System {
             received_messages.push(Message::from_message(message));
         }
 
-        topic.append_messages(partitioning, received_messages).await
+        let transaction_id = match transaction_id {
+            Some(transaction_id) => transaction_id,
+            None => {
+                topic
+                    .append_messages(partitioning, producer, None, received_messages)
+                    .await?;
+                return Ok(());
+            }
+        };
+
+        // The lock is held during the append, so the transaction cannot be completed in the meantime.
+        let mut transaction_manager = self.transaction_manager.write().await;
+        let transaction = transaction_manager.get_transaction_mut(transaction_id)?;
+        let partition_id = topic
+            .append_messages(
+                partitioning,
+                producer,
+                Some(transaction_id),
+                received_messages,
+            )
+            .await?;
+        if let Some(partition_id) = partition_id {
+            transaction.partitions.insert(TransactionPartition {
+                stream_id: stream.stream_id,
+                topic_id: topic.topic_id,
+                partition_id,
+            });
+        }
+        Ok(())
+    }
+
+    pub fn validate_delivery_time(&self, messages: &[send_messages::Message]) -> Result<(), Error> {
+        SendMessages::validate_delivery_time(
+            messages,
+            TimeStamp::now().to_micros(),
+            self.config.partition.max_delivery_delay as u64 * 1_000_000,
+        )
+    }
+
+    pub async fn init_producer(&self) -> Result<u64, Error> {
+        let producer_id = self.storage.info.generate_producer_id().await?;
+        info!("Initialized producer with ID: {}.", producer_id);
+        Ok(producer_id)
     }
 }
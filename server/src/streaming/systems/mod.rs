@@ -1,10 +1,16 @@
+pub mod audit;
 pub mod clients;
 pub mod consumer_groups;
+pub mod encryption;
 pub mod info;
 pub mod messages;
+pub mod personal_access_tokens;
+pub mod quotas;
+pub mod roles;
 pub mod stats;
 pub mod storage;
 pub mod streams;
 pub mod system;
 pub mod topics;
+pub mod transactions;
 pub mod users;
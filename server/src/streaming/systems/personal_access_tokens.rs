@@ -0,0 +1,136 @@
+use crate::streaming::personal_access_tokens::personal_access_token::PersonalAccessToken;
+use crate::streaming::systems::system::System;
+use crate::streaming::users::user::User;
+use iggy::error::Error;
+use iggy::identifier::Identifier;
+use iggy::utils::text;
+use iggy::utils::timestamp::TimeStamp;
+use tracing::{error, info, warn};
+
+impl System {
+    pub async fn get_personal_access_tokens(
+        &self,
+        user_id: u32,
+    ) -> Result<Vec<PersonalAccessToken>, Error> {
+        self.storage
+            .personal_access_token
+            .load_for_user(user_id)
+            .await
+    }
+
+    pub async fn create_personal_access_token(
+        &self,
+        user_id: u32,
+        name: &str,
+        expiry: Option<u32>,
+    ) -> Result<String, Error> {
+        let name = text::to_lowercase_non_whitespace(name);
+        if self
+            .storage
+            .personal_access_token
+            .load_by_name(user_id, &name)
+            .await
+            .is_ok()
+        {
+            error!("Personal access token: {name} for user with ID: {user_id} already exists.");
+            return Err(Error::PersonalAccessTokenAlreadyExists(name, user_id));
+        }
+
+        info!("Creating personal access token: {name} for user with ID: {user_id}...");
+        let (personal_access_token, token) =
+            PersonalAccessToken::new(user_id, &name, TimeStamp::now().to_micros(), expiry);
+        self.storage
+            .personal_access_token
+            .save(&personal_access_token)
+            .await?;
+        info!("Created personal access token: {name} for user with ID: {user_id}.");
+        Ok(token)
+    }
+
+    pub async fn delete_personal_access_token(
+        &self,
+        user_id: u32,
+        name: &str,
+    ) -> Result<(), Error> {
+        let name = text::to_lowercase_non_whitespace(name);
+        let personal_access_token = self
+            .storage
+            .personal_access_token
+            .load_by_name(user_id, &name)
+            .await?;
+        info!("Deleting personal access token: {name} for user with ID: {user_id}...");
+        self.storage
+            .personal_access_token
+            .delete(&personal_access_token)
+            .await?;
+        info!("Deleted personal access token: {name} for user with ID: {user_id}.");
+        Ok(())
+    }
+
+    pub async fn login_with_personal_access_token(
+        &self,
+        token: &str,
+        client_id: Option<u32>,
+    ) -> Result<User, Error> {
+        let token_hash = PersonalAccessToken::hash_token(token);
+        let personal_access_token = match self
+            .storage
+            .personal_access_token
+            .load_by_token(&token_hash)
+            .await
+        {
+            Ok(personal_access_token) => personal_access_token,
+            Err(_) => {
+                error!("Cannot login with invalid personal access token.");
+                return Err(Error::InvalidPersonalAccessToken);
+            }
+        };
+        if personal_access_token.is_expired(TimeStamp::now().to_micros()) {
+            warn!(
+                "Personal access token: {} for user with ID: {} has expired.",
+                personal_access_token.name, personal_access_token.user_id
+            );
+            return Err(Error::PersonalAccessTokenExpired(
+                personal_access_token.name,
+                personal_access_token.user_id,
+            ));
+        }
+
+        let user = self
+            .get_user(&Identifier::numeric(personal_access_token.user_id)?)
+            .await?;
+        info!(
+            "Logging in user: {} with personal access token: {}...",
+            user.username, personal_access_token.name
+        );
+        if !user.is_active() {
+            warn!("User: {} is inactive.", user.username);
+            return Err(Error::UserInactive);
+        }
+        self.ensure_user_not_locked(&user, TimeStamp::now().to_micros())?;
+        if let Some(client_id) = client_id {
+            let mut client_manager = self.client_manager.write().await;
+            client_manager.set_user_id(client_id, user.id).await?;
+        }
+
+        info!(
+            "Logged in user: {} with personal access token: {}.",
+            user.username, personal_access_token.name
+        );
+        Ok(user)
+    }
+
+    pub(crate) async fn delete_personal_access_tokens_for_user(
+        &self,
+        user_id: u32,
+    ) -> Result<(), Error> {
+        let personal_access_tokens = self.get_personal_access_tokens(user_id).await?;
+        for personal_access_token in personal_access_tokens {
+            self.storage
+                .personal_access_token
+                .delete(&personal_access_token)
+                .await?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,142 @@
+use crate::streaming::quotas::quota_manager::Throughput;
+use crate::streaming::systems::system::System;
+use crate::streaming::users::user::User;
+use iggy::error::Error;
+use iggy::identifier::Identifier;
+use iggy::models::quotas::Quotas;
+use iggy::utils::timestamp::TimeStamp;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::RwLock;
+use tokio::time::sleep;
+use tracing::{info, trace};
+
+impl System {
+    pub(crate) fn load_quotas(&mut self, users: &[User]) {
+        let quota_manager = self.quota_manager.get_mut();
+        for user in users {
+            quota_manager.set_user_quotas(user.id, user.quotas);
+        }
+    }
+
+    pub async fn update_quotas(
+        &self,
+        user_id: &Identifier,
+        quotas: Option<Quotas>,
+    ) -> Result<(), Error> {
+        let mut user = self.get_user(user_id).await?;
+        user.quotas = quotas;
+        info!(
+            "Updating quotas for user: {} with ID: {user_id}...",
+            user.username
+        );
+        self.storage.user.save(&user).await?;
+        self.quota_manager
+            .lock()
+            .await
+            .set_user_quotas(user.id, quotas);
+        info!(
+            "Updated quotas for user: {} with ID: {user_id}.",
+            user.username
+        );
+        Ok(())
+    }
+
+    pub fn get_client_quotas(&self) -> Quotas {
+        self.config.quota.client
+    }
+
+    pub async fn get_user_quotas(&self, user_id: Option<u32>) -> Quotas {
+        let quota_manager = self.quota_manager.lock().await;
+        match user_id.and_then(|user_id| quota_manager.get_user_quotas(user_id)) {
+            Some(quotas) => *quotas,
+            None => self.config.quota.user,
+        }
+    }
+
+    // The request is delayed until the quota is available again, or rejected if it would take too long.
+    pub async fn acquire_quota(
+        system: &Arc<RwLock<System>>,
+        throughput: Throughput,
+        client_id: Option<u32>,
+        user_id: u32,
+        bytes: u64,
+        messages: u64,
+    ) -> Result<(), Error> {
+        let mut throttled_micros = 0;
+        loop {
+            let (backoff, throttle, max_throttle_delay_micros) = {
+                let system = system.read().await;
+                if !system.config.quota.enabled {
+                    return Ok(());
+                }
+
+                let backoff = system
+                    .try_acquire_quota(throughput, client_id, user_id, bytes, messages)
+                    .await;
+                if backoff.is_none() {
+                    return Ok(());
+                }
+
+                (
+                    backoff.unwrap(),
+                    system.config.quota.throttle,
+                    system.config.quota.max_throttle_delay_ms * 1000,
+                )
+            };
+
+            if !throttle || throttled_micros + backoff > max_throttle_delay_micros {
+                trace!("Quota exceeded for user with ID: {user_id}, client ID: {client_id:?}.");
+                return Err(Error::QuotaExceeded(backoff.div_ceil(1000)));
+            }
+
+            trace!(
+                "Throttling user with ID: {user_id}, client ID: {client_id:?} for {backoff} us."
+            );
+            throttled_micros += backoff;
+            sleep(Duration::from_micros(backoff)).await;
+        }
+    }
+
+    pub async fn record_quota_usage(
+        &self,
+        throughput: Throughput,
+        client_id: Option<u32>,
+        user_id: u32,
+        bytes: u64,
+        messages: u64,
+    ) {
+        if !self.config.quota.enabled {
+            return;
+        }
+
+        self.quota_manager
+            .lock()
+            .await
+            .record(throughput, client_id, user_id, bytes, messages);
+    }
+
+    async fn try_acquire_quota(
+        &self,
+        throughput: Throughput,
+        client_id: Option<u32>,
+        user_id: u32,
+        bytes: u64,
+        messages: u64,
+    ) -> Option<u64> {
+        let client_quotas = self.get_client_quotas();
+        let user_quotas = self.get_user_quotas(Some(user_id)).await;
+        if client_quotas.is_unlimited() && user_quotas.is_unlimited() {
+            return None;
+        }
+
+        self.quota_manager.lock().await.try_acquire(
+            throughput,
+            client_id.map(|client_id| (client_id, &client_quotas)),
+            (user_id, &user_quotas),
+            TimeStamp::now().to_micros(),
+            bytes,
+            messages,
+        )
+    }
+}
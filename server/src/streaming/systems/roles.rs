@@ -0,0 +1,147 @@
+use crate::streaming::roles::role::Role;
+use crate::streaming::systems::system::System;
+use iggy::error::Error;
+use iggy::identifier::{IdKind, Identifier};
+use iggy::models::permissions::Permissions;
+use iggy::utils::text;
+use std::sync::atomic::{AtomicU32, Ordering};
+use tracing::{error, info};
+
+static ROLE_ID: AtomicU32 = AtomicU32::new(1);
+
+impl System {
+    pub(crate) async fn load_roles(&self) -> Result<Vec<Role>, Error> {
+        info!("Loading roles...");
+        let roles = self.storage.role.load_all().await?;
+        let current_role_id = roles.iter().map(|role| role.id).max().unwrap_or(0);
+        ROLE_ID.store(current_role_id + 1, Ordering::SeqCst);
+        info!("Loaded {} role(s).", roles.len());
+        Ok(roles)
+    }
+
+    pub async fn get_role(&self, role_id: &Identifier) -> Result<Role, Error> {
+        Ok(match role_id.kind {
+            IdKind::Numeric => {
+                self.storage
+                    .role
+                    .load_by_id(role_id.get_u32_value()?)
+                    .await?
+            }
+            IdKind::String => {
+                self.storage
+                    .role
+                    .load_by_name(&role_id.get_string_value()?)
+                    .await?
+            }
+        })
+    }
+
+    pub async fn get_roles(&self) -> Result<Vec<Role>, Error> {
+        self.storage.role.load_all().await
+    }
+
+    pub async fn create_role(
+        &mut self,
+        name: &str,
+        permissions: Permissions,
+    ) -> Result<Role, Error> {
+        let name = text::to_lowercase_non_whitespace(name);
+        if self.storage.role.load_by_name(&name).await.is_ok() {
+            error!("Role: {name} already exists.");
+            return Err(Error::RoleAlreadyExists(name));
+        }
+
+        let role_id = ROLE_ID.fetch_add(1, Ordering::SeqCst);
+        info!("Creating role: {name} with ID: {role_id}...");
+        let role = Role::new(role_id, &name, permissions);
+        self.storage.role.save(&role).await?;
+        self.permissioner.update_permissions_for_role(&role);
+        info!("Created role: {name} with ID: {role_id}.");
+        Ok(role)
+    }
+
+    pub async fn update_role(
+        &mut self,
+        role_id: &Identifier,
+        permissions: Permissions,
+    ) -> Result<Role, Error> {
+        let mut role = self.get_role(role_id).await?;
+        info!("Updating role: {} with ID: {}...", role.name, role.id);
+        role.permissions = permissions;
+        self.storage.role.save(&role).await?;
+        self.permissioner.update_permissions_for_role(&role);
+        info!("Updated role: {} with ID: {}.", role.name, role.id);
+        Ok(role)
+    }
+
+    pub async fn delete_role(&mut self, role_id: &Identifier) -> Result<Role, Error> {
+        let role = self.get_role(role_id).await?;
+        info!("Deleting role: {} with ID: {}...", role.name, role.id);
+        self.storage.role.delete(&role).await?;
+        self.permissioner.delete_permissions_for_role(role.id);
+        info!("Deleted role: {} with ID: {}.", role.name, role.id);
+        Ok(role)
+    }
+
+    pub async fn add_role_member(
+        &mut self,
+        role_id: &Identifier,
+        user_id: &Identifier,
+    ) -> Result<(), Error> {
+        let mut role = self.get_role(role_id).await?;
+        let user = self.get_user(user_id).await?;
+        if !role.add_member(user.id) {
+            info!(
+                "User: {} with ID: {} is already a member of role: {} with ID: {}.",
+                user.username, user.id, role.name, role.id
+            );
+            return Ok(());
+        }
+
+        self.storage.role.save(&role).await?;
+        self.permissioner.update_permissions_for_role(&role);
+        info!(
+            "Added user: {} with ID: {} to role: {} with ID: {}.",
+            user.username, user.id, role.name, role.id
+        );
+        Ok(())
+    }
+
+    pub async fn remove_role_member(
+        &mut self,
+        role_id: &Identifier,
+        user_id: &Identifier,
+    ) -> Result<(), Error> {
+        let mut role = self.get_role(role_id).await?;
+        let user = self.get_user(user_id).await?;
+        if !role.remove_member(user.id) {
+            error!(
+                "User: {} with ID: {} is not a member of role: {} with ID: {}.",
+                user.username, user.id, role.name, role.id
+            );
+            return Err(Error::RoleMemberNotFound(role.id, user.id));
+        }
+
+        self.storage.role.save(&role).await?;
+        self.permissioner.update_permissions_for_role(&role);
+        info!(
+            "Removed user: {} with ID: {} from role: {} with ID: {}.",
+            user.username, user.id, role.name, role.id
+        );
+        Ok(())
+    }
+
+    pub(crate) async fn remove_user_from_roles(&self, user_id: u32) -> Result<(), Error> {
+        let roles = self.storage.role.load_all().await?;
+        for mut role in roles {
+            if role.remove_member(user_id) {
+                self.storage.role.save(&role).await?;
+                info!(
+                    "Removed user with ID: {user_id} from role: {} with ID: {}.",
+                    role.name, role.id
+                );
+            }
+        }
+        Ok(())
+    }
+}
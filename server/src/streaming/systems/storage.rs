@@ -1,5 +1,6 @@
 use crate::streaming::storage::{Storage, SystemInfoStorage};
 use crate::streaming::systems::info::SystemInfo;
+use crate::streaming::utils::crypto;
 use async_trait::async_trait;
 use iggy::error::Error;
 use sled::Db;
@@ -7,6 +8,8 @@ use std::sync::Arc;
 use tracing::{error, info};
 
 const KEY: &str = "system";
+const SECRETS_KEY: &str = "secrets";
+const PRODUCERS_KEY: &str = "producers";
 
 #[derive(Debug)]
 pub struct FileSystemInfoStorage {
@@ -22,7 +25,74 @@ impl FileSystemInfoStorage {
 unsafe impl Send for FileSystemInfoStorage {}
 unsafe impl Sync for FileSystemInfoStorage {}
 
-impl SystemInfoStorage for FileSystemInfoStorage {}
+#[async_trait]
+impl SystemInfoStorage for FileSystemInfoStorage {
+    async fn generate_producer_id(&self) -> Result<u64, Error> {
+        // The IDs generated by the database are monotonic across the restarts, 0 is reserved for no producer.
+        let producer_id = match self.db.generate_id() {
+            Ok(id) => id + 1,
+            Err(err) => {
+                error!("Cannot generate producer ID. Error: {}", err);
+                return Err(Error::CannotGenerateProducerId);
+            }
+        };
+
+        // The issued IDs are stored, so the producers can't make up their own ones.
+        if let Err(err) = self
+            .db
+            .insert(format!("{PRODUCERS_KEY}:{producer_id}"), &[])
+        {
+            error!("Cannot save producer ID: {producer_id}. Error: {}", err);
+            return Err(Error::CannotGenerateProducerId);
+        }
+
+        Ok(producer_id)
+    }
+
+    async fn is_producer_id_issued(&self, producer_id: u64) -> Result<bool, Error> {
+        let key = format!("{PRODUCERS_KEY}:{producer_id}");
+        match self.db.contains_key(&key) {
+            Ok(issued) => Ok(issued),
+            Err(err) => {
+                error!("Cannot load producer ID: {producer_id}. Error: {}", err);
+                Err(Error::CannotLoadResource(key))
+            }
+        }
+    }
+
+    async fn generate_transaction_id(&self) -> Result<u64, Error> {
+        // 0 is reserved for no transaction.
+        match self.db.generate_id() {
+            Ok(id) => Ok(id + 1),
+            Err(err) => {
+                error!("Cannot generate transaction ID. Error: {}", err);
+                Err(Error::CannotGenerateTransactionId)
+            }
+        }
+    }
+
+    // The secrets are generated once and kept in the database, so they stay the same across the restarts.
+    async fn load_or_create_secret(&self, name: &str) -> Result<Vec<u8>, Error> {
+        let key = format!("{SECRETS_KEY}:{name}");
+        match self.db.get(&key) {
+            Ok(Some(secret)) => return Ok(secret.to_vec()),
+            Ok(None) => {}
+            Err(err) => {
+                error!("Cannot load secret: {name}. Error: {}", err);
+                return Err(Error::CannotLoadResource(key));
+            }
+        }
+
+        let secret = crypto::generate_secret();
+        if let Err(err) = self.db.insert(&key, secret.as_slice()) {
+            error!("Cannot save secret: {name}. Error: {}", err);
+            return Err(Error::CannotSaveResource(key));
+        }
+
+        info!("Generated secret: {name}.");
+        Ok(secret)
+    }
+}
 
 #[async_trait]
 impl Storage<SystemInfo> for FileSystemInfoStorage {
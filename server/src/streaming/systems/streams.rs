@@ -3,6 +3,7 @@ use crate::streaming::systems::system::System;
 use futures::future::join_all;
 use iggy::error::Error;
 use iggy::identifier::{IdKind, Identifier};
+use iggy::models::stream_encryption::StreamEncryption;
 use iggy::utils::text;
 use std::sync::Arc;
 use tokio::fs::read_dir;
@@ -132,6 +133,7 @@ impl System {
         user_id: u32,
         stream_id: u32,
         name: &str,
+        encryption: Option<StreamEncryption>,
     ) -> Result<(), Error> {
         self.permissioner.create_stream(user_id)?;
         if self.streams.contains_key(&stream_id) {
@@ -143,7 +145,10 @@ impl System {
             return Err(Error::StreamNameAlreadyExists(name.to_string()));
         }
 
-        let stream = Stream::create(stream_id, &name, self.config.clone(), self.storage.clone());
+        self.validate_stream_encryption(&encryption)?;
+        let mut stream =
+            Stream::create(stream_id, &name, self.config.clone(), self.storage.clone());
+        stream.encryption = encryption;
         stream.persist().await?;
         info!("Created stream with ID: {}, name: '{}'.", stream_id, name);
         self.streams_ids.insert(name, stream.stream_id);
@@ -212,7 +217,7 @@ mod tests {
         let storage = get_test_system_storage();
         let mut system = System::create(config, storage);
         system
-            .create_stream(user_id, stream_id, stream_name)
+            .create_stream(user_id, stream_id, stream_name, None)
             .await
             .unwrap();
 
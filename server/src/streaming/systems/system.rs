@@ -1,20 +1,25 @@
 use crate::configs::system::SystemConfig;
+use crate::streaming::audit::audit_log::AuditLog;
 use crate::streaming::clients::client_manager::ClientManager;
+use crate::streaming::encryption::key_ring::KeyRing;
 use crate::streaming::persistence::persister::*;
+use crate::streaming::quotas::quota_manager::QuotaManager;
 use crate::streaming::storage::{SegmentStorage, SystemStorage};
 use crate::streaming::streams::stream::Stream;
+use crate::streaming::transactions::transaction_manager::TransactionManager;
 use crate::streaming::users::permissioner::Permissioner;
 use iggy::error::Error;
-use iggy::utils::crypto::{Aes256GcmEncryptor, Encryptor};
 use sled::Db;
 use std::collections::HashMap;
 use std::path::Path;
 use std::sync::Arc;
 use tokio::fs::create_dir;
-use tokio::sync::RwLock;
+use tokio::sync::{Mutex, RwLock};
 use tokio::time::Instant;
 use tracing::{info, trace};
 
+const SCRAM_SALT_KEY_NAME: &str = "scram_salt_key";
+
 #[derive(Debug)]
 pub struct System {
     pub permissioner: Permissioner,
@@ -23,7 +28,12 @@ pub struct System {
     pub(crate) streams_ids: HashMap<String, u32>,
     pub(crate) config: Arc<SystemConfig>,
     pub(crate) client_manager: Arc<RwLock<ClientManager>>,
-    pub(crate) encryptor: Option<Box<dyn Encryptor>>,
+    pub(crate) transaction_manager: RwLock<TransactionManager>,
+    pub(crate) key_ring: Option<KeyRing>,
+    pub(crate) audit_log: AuditLog,
+    pub(crate) quota_manager: Mutex<QuotaManager>,
+    pub(crate) scram_salt_key: Vec<u8>,
+    pub(crate) failed_logins_lock: Mutex<()>,
 }
 
 impl System {
@@ -62,10 +72,13 @@ impl System {
         );
 
         System {
-            encryptor: match config.encryption.enabled {
-                true => Some(Box::new(
-                    Aes256GcmEncryptor::from_base64_key(&config.encryption.key).unwrap(),
-                )),
+            audit_log: AuditLog::new(
+                config.audit.enabled,
+                &config.get_audit_log_path(),
+                config.audit.max_file_size_bytes,
+            ),
+            key_ring: match config.encryption.enabled {
+                true => Some(KeyRing::from_config(&config.encryption).unwrap()),
                 false => None,
             },
             config,
@@ -73,7 +86,11 @@ impl System {
             streams_ids: HashMap::new(),
             storage: Arc::new(storage),
             client_manager: Arc::new(RwLock::new(ClientManager::default())),
+            transaction_manager: RwLock::new(TransactionManager::default()),
             permissioner: Permissioner::default(),
+            quota_manager: Mutex::new(QuotaManager::default()),
+            scram_salt_key: Vec::new(),
+            failed_logins_lock: Mutex::new(()),
         }
     }
 
@@ -95,8 +112,15 @@ impl System {
         );
         let now = Instant::now();
         self.load_version().await?;
+        // The salts of the unknown users must not change on restart, otherwise they could be told apart.
+        self.scram_salt_key = self
+            .storage
+            .info
+            .load_or_create_secret(SCRAM_SALT_KEY_NAME)
+            .await?;
         self.load_users().await?;
         self.load_streams().await?;
+        self.init_audit_stream().await?;
         info!("Initialized system in {} ms.", now.elapsed().as_millis());
         Ok(())
     }
@@ -0,0 +1,219 @@
+use crate::streaming::partitions::transactions::TransactionMarker;
+use crate::streaming::polling_consumer::PollingConsumer;
+use crate::streaming::systems::system::System;
+use crate::streaming::topics::consumer_group::ConsumerGroupGeneration;
+use crate::streaming::topics::topic::Topic;
+use crate::streaming::transactions::transaction_manager::{Transaction, TransactionConsumerOffset};
+use iggy::error::Error;
+use iggy::identifier::Identifier;
+use iggy::utils::timestamp::TimeStamp;
+use tracing::{error, info, warn};
+
+impl System {
+    pub async fn begin_transaction(
+        &self,
+        user_id: u32,
+        client_id: Option<u32>,
+    ) -> Result<u64, Error> {
+        let transaction_id = self.storage.info.generate_transaction_id().await?;
+        self.transaction_manager.write().await.begin_transaction(
+            transaction_id,
+            user_id,
+            client_id,
+            TimeStamp::now().to_micros(),
+        );
+        info!(
+            "Began transaction with ID: {} for user with ID: {}.",
+            transaction_id, user_id
+        );
+        Ok(transaction_id)
+    }
+
+    pub async fn verify_transaction(&self, user_id: u32, transaction_id: u64) -> Result<(), Error> {
+        self.transaction_manager
+            .read()
+            .await
+            .verify_transaction(transaction_id, user_id)
+    }
+
+    pub async fn store_transactional_consumer_offset(
+        &self,
+        user_id: u32,
+        transaction_id: u64,
+        topic: &Topic,
+        consumer: PollingConsumer,
+        offset: u64,
+        generation: Option<ConsumerGroupGeneration>,
+    ) -> Result<(), Error> {
+        // The generation is validated again when the transaction is committed.
+        if let Some(generation) = generation {
+            topic
+                .get_consumer_group(generation.consumer_group_id)?
+                .read()
+                .await
+                .validate_generation(generation.generation_id)?;
+        }
+
+        self.transaction_manager.write().await.add_consumer_offset(
+            transaction_id,
+            user_id,
+            TransactionConsumerOffset {
+                stream_id: topic.stream_id,
+                topic_id: topic.topic_id,
+                consumer,
+                offset,
+                generation,
+            },
+        )
+    }
+
+    pub async fn commit_transaction(&self, user_id: u32, transaction_id: u64) -> Result<(), Error> {
+        // The lock is held until the markers are written, so no messages can be added to the transaction in the meantime.
+        let mut transaction_manager = self.transaction_manager.write().await;
+        let transaction = transaction_manager.remove_transaction(transaction_id, user_id)?;
+        let mut result = self
+            .append_transaction_markers(&transaction, TransactionMarker::Commit)
+            .await;
+        for consumer_offset in &transaction.consumer_offsets {
+            if let Err(error) = self.store_committed_consumer_offset(consumer_offset).await {
+                error!(
+                    "Failed to store consumer offset for transaction with ID: {}. Error: {}",
+                    transaction_id, error
+                );
+                result = result.and(Err(error));
+            }
+        }
+        info!(
+            "Committed transaction with ID: {} for user with ID: {}.",
+            transaction_id, user_id
+        );
+        result
+    }
+
+    pub async fn abort_transaction(&self, user_id: u32, transaction_id: u64) -> Result<(), Error> {
+        let mut transaction_manager = self.transaction_manager.write().await;
+        let transaction = transaction_manager.remove_transaction(transaction_id, user_id)?;
+        let result = self
+            .append_transaction_markers(&transaction, TransactionMarker::Abort)
+            .await;
+        info!(
+            "Aborted transaction with ID: {} for user with ID: {}.",
+            transaction_id, user_id
+        );
+        result
+    }
+
+    pub async fn abort_expired_transactions(&self, now: u64, timeout: u64) -> u32 {
+        let expired_transactions = self
+            .transaction_manager
+            .read()
+            .await
+            .get_expired_transactions(now, timeout);
+        self.abort_transactions(expired_transactions).await
+    }
+
+    pub(crate) async fn abort_client_transactions(&self, client_id: u32) -> u32 {
+        let client_transactions = self
+            .transaction_manager
+            .read()
+            .await
+            .get_client_transactions(client_id);
+        self.abort_transactions(client_transactions).await
+    }
+
+    async fn abort_transactions(&self, transactions: Vec<(u64, u32)>) -> u32 {
+        let mut aborted_transactions = 0;
+        for (transaction_id, user_id) in transactions {
+            if let Err(error) = self.abort_transaction(user_id, transaction_id).await {
+                error!(
+                    "Failed to abort transaction with ID: {} for user with ID: {}. Error: {}",
+                    transaction_id, user_id, error
+                );
+                continue;
+            }
+            aborted_transactions += 1;
+        }
+        aborted_transactions
+    }
+
+    async fn store_committed_consumer_offset(
+        &self,
+        consumer_offset: &TransactionConsumerOffset,
+    ) -> Result<(), Error> {
+        let stream = self.get_stream(&Identifier::numeric(consumer_offset.stream_id)?)?;
+        let topic = stream.get_topic(&Identifier::numeric(consumer_offset.topic_id)?)?;
+        match consumer_offset.generation {
+            Some(generation) => {
+                topic
+                    .store_consumer_offset_in_generation(
+                        consumer_offset.consumer,
+                        consumer_offset.offset,
+                        generation,
+                    )
+                    .await
+            }
+            None => {
+                topic
+                    .store_consumer_offset(consumer_offset.consumer, consumer_offset.offset)
+                    .await
+            }
+        }
+    }
+
+    // The transaction is completed in each partition even if the other ones fail, so it doesn't stay partially open.
+    async fn append_transaction_markers(
+        &self,
+        transaction: &Transaction,
+        marker: TransactionMarker,
+    ) -> Result<(), Error> {
+        let mut result = Ok(());
+        for partition in &transaction.partitions {
+            let topic = match self
+                .get_stream(&Identifier::numeric(partition.stream_id)?)
+                .and_then(|stream| stream.get_topic(&Identifier::numeric(partition.topic_id)?))
+            {
+                Ok(topic) => topic,
+                Err(error) => {
+                    warn!(
+                        "Skipped {} marker for transaction with ID: {} in deleted partition with ID: {}. {}",
+                        marker.as_str(),
+                        transaction.transaction_id,
+                        partition.partition_id,
+                        error
+                    );
+                    continue;
+                }
+            };
+
+            match topic
+                .append_transaction_marker(
+                    partition.partition_id,
+                    transaction.transaction_id,
+                    marker,
+                )
+                .await
+            {
+                Ok(()) => {}
+                Err(Error::PartitionNotFound(_, _, _)) => {
+                    warn!(
+                        "Skipped {} marker for transaction with ID: {} in deleted partition with ID: {}.",
+                        marker.as_str(),
+                        transaction.transaction_id,
+                        partition.partition_id
+                    );
+                }
+                Err(error) => {
+                    error!(
+                        "Failed to append {} marker for transaction with ID: {} to partition with ID: {}. Error: {}",
+                        marker.as_str(),
+                        transaction.transaction_id,
+                        partition.partition_id,
+                        error
+                    );
+                    result = result.and(Err(error));
+                }
+            }
+        }
+        result
+    }
+}
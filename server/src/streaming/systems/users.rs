@@ -1,11 +1,15 @@
 use crate::streaming::systems::system::System;
+use crate::streaming::users::password_policy;
+use crate::streaming::users::scram::{ScramCredentials, ScramHandshake};
 use crate::streaming::users::user::User;
 use crate::streaming::utils::crypto;
 use iggy::error::Error;
 use iggy::identifier::{IdKind, Identifier};
 use iggy::models::permissions::Permissions;
+use iggy::models::scram_challenge::ScramChallenge;
 use iggy::models::user_status::UserStatus;
-use iggy::utils::text;
+use iggy::utils::timestamp::TimeStamp;
+use iggy::utils::{scram, text};
 use std::sync::atomic::{AtomicU32, Ordering};
 use tracing::log::error;
 use tracing::{info, warn};
@@ -27,7 +31,9 @@ impl System {
         let users_count = users.len();
         let current_user_id = users.iter().map(|user| user.id).max().unwrap_or(1);
         USER_ID.store(current_user_id + 1, Ordering::SeqCst);
-        self.permissioner.init(users);
+        let roles = self.load_roles().await?;
+        self.load_quotas(&users);
+        self.permissioner.init(users, roles);
         if self.config.user.authorization_enabled {
             self.permissioner.enable()
         }
@@ -67,6 +73,7 @@ impl System {
             error!("User: {username} already exists.");
             return Err(Error::UserAlreadyExists);
         }
+        password_policy::validate(&self.config.user.password_policy, &username, password)?;
         let user_id = USER_ID.fetch_add(1, Ordering::SeqCst);
         info!("Creating user: {username} with ID: {user_id}...");
         let user = User::new(user_id, &username, password, permissions);
@@ -84,7 +91,10 @@ impl System {
 
         info!("Deleting user: {} with ID: {user_id}...", user.username);
         self.storage.user.delete(&user).await?;
+        self.delete_personal_access_tokens_for_user(user.id).await?;
+        self.remove_user_from_roles(user.id).await?;
         self.permissioner.delete_permissions_for_user(user.id);
+        self.quota_manager.lock().await.delete_user(user.id);
         info!("Deleted user: {} with ID: {user_id}.", user.username);
         Ok(user)
     }
@@ -109,11 +119,17 @@ impl System {
 
         if let Some(status) = status {
             user.status = status;
+            if !user.is_active() {
+                user.revoke_tokens();
+            }
         }
 
         info!("Updating user: {} with ID: {user_id}...", user.username);
         self.storage.user.save(&user).await?;
         info!("Updated user: {} with ID: {user_id}.", user.username);
+        if !user.is_active() {
+            self.revoke_user_sessions(user.id).await;
+        }
         Ok(user)
     }
 
@@ -158,16 +174,24 @@ impl System {
             return Err(Error::InvalidCredentials);
         }
 
+        password_policy::validate(
+            &self.config.user.password_policy,
+            &user.username,
+            new_password,
+        )?;
         info!(
             "Changing password for user: {} with ID: {user_id}...",
             user.username
         );
         user.password = crypto::hash_password(new_password);
+        user.scram_credentials = Some(ScramCredentials::new(new_password));
+        user.revoke_tokens();
         self.storage.user.save(&user).await?;
         info!(
             "Changed password for user: {} with ID: {user_id}.",
             user.username
         );
+        self.revoke_user_sessions(user.id).await;
         Ok(())
     }
 
@@ -178,7 +202,7 @@ impl System {
         client_id: Option<u32>,
     ) -> Result<User, Error> {
         info!("Logging in user: {username}...");
-        let user = match self.storage.user.load_by_username(username).await {
+        let mut user = match self.storage.user.load_by_username(username).await {
             Ok(user) => user,
             Err(_) => {
                 error!("Cannot login user: {username}.");
@@ -189,9 +213,18 @@ impl System {
             warn!("User: {username} is inactive.");
             return Err(Error::UserInactive);
         }
+        let now = TimeStamp::now().to_micros();
+        self.ensure_user_not_locked(&user, now)?;
         if !crypto::verify_password(password, &user.password) {
+            self.register_failed_login(&mut user, now).await?;
             return Err(Error::InvalidCredentials);
         }
+        self.register_successful_login(&mut user, now).await?;
+        if user.scram_credentials.is_none() {
+            info!("Creating SCRAM credentials for user: {username}...");
+            user.scram_credentials = Some(ScramCredentials::new(password));
+            self.storage.user.save(&user).await?;
+        }
         if let Some(client_id) = client_id {
             let mut client_manager = self.client_manager.write().await;
             client_manager.set_user_id(client_id, user.id).await?;
@@ -201,6 +234,200 @@ impl System {
         Ok(user)
     }
 
+    pub async fn start_scram_login(
+        &self,
+        username: &str,
+        client_nonce: &str,
+    ) -> Result<(ScramHandshake, ScramChallenge), Error> {
+        info!("Starting SCRAM login for user: {username}...");
+        // The unknown users, and the ones without the credentials, get the same challenge as the others,
+        // with a salt which is stable for the username, so they can't be told apart before the login finishes.
+        let (salt, iterations) = match self.load_scram_user(username).await {
+            Some(user) => {
+                let credentials = user.scram_credentials.unwrap();
+                (credentials.salt, credentials.iterations)
+            }
+            None => (
+                scram::fake_salt(&self.scram_salt_key, username),
+                scram::SCRAM_ITERATIONS,
+            ),
+        };
+        let nonce = format!("{client_nonce}{}", scram::generate_nonce());
+        let handshake = ScramHandshake {
+            username: username.to_string(),
+            client_nonce: client_nonce.to_string(),
+            nonce: nonce.clone(),
+        };
+        let challenge = ScramChallenge {
+            nonce,
+            salt,
+            iterations,
+        };
+        Ok((handshake, challenge))
+    }
+
+    pub async fn finish_scram_login(
+        &self,
+        handshake: &ScramHandshake,
+        nonce: &str,
+        client_proof: &str,
+        client_id: Option<u32>,
+    ) -> Result<(User, Vec<u8>), Error> {
+        let username = &handshake.username;
+        if nonce != handshake.nonce {
+            error!("Invalid SCRAM nonce for user: {username}.");
+            return Err(Error::InvalidScramHandshake);
+        }
+
+        let client_proof =
+            text::from_base64_as_bytes(client_proof).map_err(|_| Error::InvalidScramHandshake)?;
+        let mut user = match self.load_scram_user(username).await {
+            Some(user) => user,
+            None => {
+                error!("Cannot login user: {username} with SCRAM.");
+                return Err(Error::InvalidCredentials);
+            }
+        };
+        let now = TimeStamp::now().to_micros();
+        self.ensure_user_not_locked(&user, now)?;
+        let credentials = user.scram_credentials.clone().unwrap();
+        let auth_message = scram::auth_message(
+            username,
+            &handshake.client_nonce,
+            &handshake.nonce,
+            &credentials.salt,
+            credentials.iterations,
+        );
+        if !scram::verify_client_proof(&credentials.stored_key, &auth_message, &client_proof) {
+            error!("Invalid SCRAM client proof for user: {username}.");
+            self.register_failed_login(&mut user, now).await?;
+            return Err(Error::InvalidCredentials);
+        }
+
+        if !user.is_active() {
+            warn!("User: {username} is inactive.");
+            return Err(Error::UserInactive);
+        }
+
+        let server_signature = scram::server_signature(&credentials.server_key, &auth_message);
+        self.register_successful_login(&mut user, now).await?;
+        if let Some(client_id) = client_id {
+            let mut client_manager = self.client_manager.write().await;
+            client_manager.set_user_id(client_id, user.id).await?;
+        }
+
+        info!("Logged in user: {username} with SCRAM.");
+        Ok((user, server_signature))
+    }
+
+    // The credentials are created for the existing users on the next password change or login with the password.
+    async fn load_scram_user(&self, username: &str) -> Option<User> {
+        let user = self.storage.user.load_by_username(username).await.ok()?;
+        if user.scram_credentials.is_none() {
+            warn!("User: {username} has no SCRAM credentials.");
+            return None;
+        }
+
+        Some(user)
+    }
+
+    // The client certificate has been already verified by the TLS layer, so there is no password to check.
+    pub async fn login_with_certificate(
+        &self,
+        username: &str,
+        client_id: u32,
+    ) -> Result<User, Error> {
+        let username = text::to_lowercase_non_whitespace(username);
+        info!("Logging in user: {username} with client certificate...");
+        let user = match self.storage.user.load_by_username(&username).await {
+            Ok(user) => user,
+            Err(_) => {
+                error!("Cannot login user: {username} with client certificate.");
+                return Err(Error::InvalidCredentials);
+            }
+        };
+        if !user.is_active() {
+            warn!("User: {username} is inactive.");
+            return Err(Error::UserInactive);
+        }
+        self.ensure_user_not_locked(&user, TimeStamp::now().to_micros())?;
+
+        let mut client_manager = self.client_manager.write().await;
+        client_manager.set_user_id(client_id, user.id).await?;
+        info!("Logged in user: {username} with client certificate.");
+        Ok(user)
+    }
+
+    // The HTTP access tokens are stateless, so the ones issued before the revocation are rejected by their version.
+    pub async fn ensure_token_version(
+        &self,
+        user_id: u32,
+        token_version: u32,
+    ) -> Result<(), Error> {
+        let user = self
+            .get_user(&Identifier::numeric(user_id)?)
+            .await
+            .map_err(|_| Error::InvalidAccessToken)?;
+        if user.token_version != token_version {
+            warn!("Access token version: {token_version} for user with ID: {user_id} was revoked.");
+            return Err(Error::InvalidAccessToken);
+        }
+
+        Ok(())
+    }
+
+    pub async fn unlock_user(&self, user_id: &Identifier) -> Result<(), Error> {
+        let mut user = self.get_user(user_id).await?;
+        info!("Unlocking user: {} with ID: {user_id}...", user.username);
+        self.reset_failed_logins(&mut user).await?;
+        info!("Unlocked user: {} with ID: {user_id}.", user.username);
+        Ok(())
+    }
+
+    pub(crate) fn ensure_user_not_locked(&self, user: &User, now: u64) -> Result<(), Error> {
+        if user.is_locked(&self.config.user.lockout, now) {
+            warn!("User: {} is locked.", user.username);
+            return Err(Error::UserLocked(user.id));
+        }
+
+        Ok(())
+    }
+
+    // The user is reloaded under the lock, so none of the concurrent failed logins is lost.
+    async fn register_failed_login(&self, user: &mut User, now: u64) -> Result<(), Error> {
+        let _failed_logins_lock = self.failed_logins_lock.lock().await;
+        *user = self.storage.user.load_by_id(user.id).await?;
+        user.register_failed_login(&self.config.user.lockout, now);
+        self.storage.user.save(user).await?;
+        if user.locked_at == Some(now) {
+            warn!(
+                "User: {} has been locked after {} failed login attempts.",
+                user.username, user.failed_login_attempts
+            );
+        }
+        Ok(())
+    }
+
+    // The lock is re-checked after the reload, so a login which raced with the locking one is rejected.
+    async fn register_successful_login(&self, user: &mut User, now: u64) -> Result<(), Error> {
+        let _failed_logins_lock = self.failed_logins_lock.lock().await;
+        *user = self.storage.user.load_by_id(user.id).await?;
+        self.ensure_user_not_locked(user, now)?;
+        if user.reset_failed_logins() {
+            self.storage.user.save(user).await?;
+        }
+        Ok(())
+    }
+
+    async fn reset_failed_logins(&self, user: &mut User) -> Result<(), Error> {
+        let _failed_logins_lock = self.failed_logins_lock.lock().await;
+        *user = self.storage.user.load_by_id(user.id).await?;
+        if user.reset_failed_logins() {
+            self.storage.user.save(user).await?;
+        }
+        Ok(())
+    }
+
     pub async fn logout_user(&self, user_id: u32, client_id: Option<u32>) -> Result<(), Error> {
         if user_id == 0 {
             return Err(Error::InvalidCredentials);
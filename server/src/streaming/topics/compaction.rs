@@ -0,0 +1,35 @@
+use crate::streaming::topics::topic::Topic;
+use iggy::error::Error;
+use iggy::models::cleanup_policy::CleanupPolicy;
+use iggy::models::header::HeaderKey;
+
+// Used to keep the messages key as a header, when the topic is compacted without a custom compaction key.
+pub const MESSAGES_KEY_HEADER: &str = "iggy-messages-key";
+
+impl Topic {
+    pub fn get_compaction_key(&self) -> Result<HeaderKey, Error> {
+        match &self.compaction_key {
+            Some(compaction_key) => Ok(compaction_key.clone()),
+            None => HeaderKey::new(MESSAGES_KEY_HEADER),
+        }
+    }
+
+    pub fn should_store_messages_key(&self) -> bool {
+        self.cleanup_policy == CleanupPolicy::Compact && self.compaction_key.is_none()
+    }
+
+    pub async fn compact(&self) -> Result<u64, Error> {
+        if self.cleanup_policy != CleanupPolicy::Compact {
+            return Ok(0);
+        }
+
+        let compaction_key = self.get_compaction_key()?;
+        let mut compacted_messages = 0;
+        for partition in self.get_partitions() {
+            let mut partition = partition.write().await;
+            compacted_messages += partition.compact_segments(&compaction_key).await?;
+        }
+
+        Ok(compacted_messages)
+    }
+}
@@ -1,6 +1,9 @@
 use iggy::error::Error;
+use iggy::models::assignment_strategy::AssignmentStrategy;
+use iggy::models::consumer_group::ConsumerGroupAssignment;
+use iggy::utils::timestamp::TimeStamp;
 use std::collections::HashMap;
-use tokio::sync::RwLock;
+use tokio::sync::{watch, RwLock};
 use tracing::trace;
 
 #[derive(Debug)]
@@ -8,24 +11,57 @@ pub struct ConsumerGroup {
     pub topic_id: u32,
     pub id: u32,
     pub partitions_count: u32,
+    pub assignment_strategy: AssignmentStrategy,
+    pub session_timeout: u32,
+    pub generation_id: u32,
     members: HashMap<u32, RwLock<ConsumerGroupMember>>,
+    pending_partitions: RwLock<Vec<PendingPartition>>,
+    assignment_notifier: watch::Sender<u32>,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct ConsumerGroupGeneration {
+    pub consumer_group_id: u32,
+    pub generation_id: u32,
 }
 
 #[derive(Debug)]
 pub struct ConsumerGroupMember {
     pub id: u32,
+    pub last_heartbeat_at: u64,
+    assignment_changed: bool,
     partitions: HashMap<u32, u32>,
     current_partition_index: u32,
     current_partition_id: u32,
 }
 
+// The partition revoked from one member stays with it until that member polls again (or leaves the group),
+// only then it's assigned to another one, so the partition is never left without an owner.
+#[derive(Debug, Copy, Clone)]
+struct PendingPartition {
+    partition_id: u32,
+    revoked_member_id: u32,
+    assigned_member_id: u32,
+}
+
 impl ConsumerGroup {
-    pub fn new(topic_id: u32, id: u32, partitions_count: u32) -> ConsumerGroup {
+    pub fn new(
+        topic_id: u32,
+        id: u32,
+        partitions_count: u32,
+        assignment_strategy: AssignmentStrategy,
+        session_timeout: u32,
+    ) -> ConsumerGroup {
         ConsumerGroup {
             topic_id,
             id,
             partitions_count,
+            assignment_strategy,
+            session_timeout,
+            generation_id: 0,
             members: HashMap::new(),
+            pending_partitions: RwLock::new(Vec::new()),
+            assignment_notifier: watch::channel(0).0,
         }
     }
 
@@ -38,10 +74,26 @@ impl ConsumerGroup {
         self.assign_partitions().await;
     }
 
-    pub async fn calculate_partition_id(&self, member_id: u32) -> Result<u32, Error> {
+    pub async fn calculate_partition_id(&mut self, member_id: u32) -> Result<Option<u32>, Error> {
+        if !self.members.contains_key(&member_id) {
+            return Err(Error::ConsumerGroupMemberNotFound(
+                member_id,
+                self.id,
+                self.topic_id,
+            ));
+        }
+
+        self.release_revoked_partitions(member_id).await;
+        // Polling the messages is treated as a heartbeat as well.
+        let mut member = self.members.get(&member_id).unwrap().write().await;
+        member.last_heartbeat_at = TimeStamp::now().to_micros();
+        Ok(member.calculate_partition_id())
+    }
+
+    pub async fn get_current_partition_id(&self, member_id: u32) -> Result<u32, Error> {
         let member = self.members.get(&member_id);
         if let Some(member) = member {
-            return Ok(member.write().await.calculate_partition_id());
+            return Ok(member.read().await.current_partition_id);
         }
         Err(Error::ConsumerGroupMemberNotFound(
             member_id,
@@ -50,10 +102,68 @@ impl ConsumerGroup {
         ))
     }
 
-    pub async fn get_current_partition_id(&self, member_id: u32) -> Result<u32, Error> {
+    // Returns whether the member's assignment has changed since it was acknowledged for the last time.
+    pub async fn acknowledge_assignment(&self, member_id: u32) -> Result<bool, Error> {
         let member = self.members.get(&member_id);
         if let Some(member) = member {
-            return Ok(member.read().await.current_partition_id);
+            let mut member = member.write().await;
+            let assignment_changed = member.assignment_changed;
+            member.assignment_changed = false;
+            return Ok(assignment_changed);
+        }
+        Err(Error::ConsumerGroupMemberNotFound(
+            member_id,
+            self.id,
+            self.topic_id,
+        ))
+    }
+
+    pub async fn get_assignment(&self, member_id: u32) -> Result<ConsumerGroupAssignment, Error> {
+        self.acknowledge_assignment(member_id).await?;
+        let member = self.members.get(&member_id).unwrap().read().await;
+        let partitions = member.get_partitions();
+        Ok(ConsumerGroupAssignment {
+            consumer_group_id: self.id,
+            member_id,
+            generation_id: self.generation_id,
+            partitions_count: partitions.len() as u32,
+            partitions,
+        })
+    }
+
+    pub fn subscribe_to_assignment_changes(&self) -> watch::Receiver<u32> {
+        self.assignment_notifier.subscribe()
+    }
+
+    pub async fn get_member_partitions(&self, member_id: u32) -> Result<Vec<u32>, Error> {
+        let member = self.members.get(&member_id);
+        if let Some(member) = member {
+            return Ok(member.read().await.get_partitions());
+        }
+        Err(Error::ConsumerGroupMemberNotFound(
+            member_id,
+            self.id,
+            self.topic_id,
+        ))
+    }
+
+    pub fn validate_generation(&self, generation_id: u32) -> Result<(), Error> {
+        if generation_id != self.generation_id {
+            return Err(Error::StaleGenerationId(
+                generation_id,
+                self.generation_id,
+                self.id,
+            ));
+        }
+
+        Ok(())
+    }
+
+    pub async fn heartbeat(&self, member_id: u32) -> Result<(), Error> {
+        let member = self.members.get(&member_id);
+        if let Some(member) = member {
+            member.write().await.last_heartbeat_at = TimeStamp::now().to_micros();
+            return Ok(());
         }
         Err(Error::ConsumerGroupMemberNotFound(
             member_id,
@@ -62,11 +172,29 @@ impl ConsumerGroup {
         ))
     }
 
+    pub async fn get_expired_members(&self, now: u64) -> Vec<u32> {
+        if self.session_timeout == 0 {
+            return Vec::new();
+        }
+
+        let session_timeout = self.session_timeout as u64 * 1_000_000;
+        let mut expired_members = Vec::new();
+        for member in self.members.values() {
+            let member = member.read().await;
+            if member.last_heartbeat_at + session_timeout < now {
+                expired_members.push(member.id);
+            }
+        }
+        expired_members
+    }
+
     pub async fn add_member(&mut self, member_id: u32) {
         self.members.insert(
             member_id,
             RwLock::new(ConsumerGroupMember {
                 id: member_id,
+                last_heartbeat_at: TimeStamp::now().to_micros(),
+                assignment_changed: false,
                 partitions: HashMap::new(),
                 current_partition_index: 0,
                 current_partition_id: 0,
@@ -93,43 +221,257 @@ impl ConsumerGroup {
         }
     }
 
+    async fn release_revoked_partitions(&mut self, member_id: u32) {
+        let pending_partitions = self.pending_partitions.get_mut();
+        if pending_partitions.is_empty() {
+            return;
+        }
+
+        let mut released_partitions = Vec::new();
+        pending_partitions.retain(|pending_partition| {
+            if pending_partition.revoked_member_id != member_id {
+                return true;
+            }
+            released_partitions.push(*pending_partition);
+            false
+        });
+        if released_partitions.is_empty() {
+            return;
+        }
+
+        // The assignment has changed, so the offsets committed with the previous generation are rejected.
+        self.generation_id += 1;
+
+        for pending_partition in released_partitions {
+            if let Some(member) = self.members.get(&pending_partition.revoked_member_id) {
+                member
+                    .write()
+                    .await
+                    .remove_partition(pending_partition.partition_id);
+            }
+            if let Some(member) = self.members.get(&pending_partition.assigned_member_id) {
+                let mut member = member.write().await;
+                member.add_partition(pending_partition.partition_id);
+                trace!("Released partition ID: {} revoked from member with ID: {} to member with ID: {} for topic with ID: {} in consumer group: {}",
+                    pending_partition.partition_id, member_id, member.id, self.topic_id, self.id)
+            }
+        }
+        self.assignment_notifier.send_replace(self.generation_id);
+    }
+
     async fn assign_partitions(&mut self) {
-        let mut members = self.members.values_mut().collect::<Vec<_>>();
-        if members.is_empty() {
+        self.generation_id += 1;
+        trace!(
+            "Rebalancing consumer group: {} for topic with ID: {}, generation ID: {}",
+            self.id,
+            self.topic_id,
+            self.generation_id
+        );
+        if self.members.is_empty() {
+            self.pending_partitions.get_mut().clear();
+            self.assignment_notifier.send_replace(self.generation_id);
             return;
         }
 
-        let members_count = members.len() as u32;
-        for member in members.iter_mut() {
-            let mut member = member.write().await;
-            member.current_partition_index = 0;
-            member.current_partition_id = 0;
-            member.partitions.clear();
+        let mut member_ids = self.members.keys().copied().collect::<Vec<_>>();
+        member_ids.sort();
+        let assignments = match self.assignment_strategy {
+            AssignmentStrategy::Range => self.assign_range(&member_ids),
+            AssignmentStrategy::RoundRobin => self.assign_round_robin(&member_ids),
+            AssignmentStrategy::Sticky | AssignmentStrategy::CooperativeSticky => {
+                self.assign_sticky(&member_ids)
+            }
+        };
+
+        let mut pending_partitions = Vec::new();
+        if self.assignment_strategy == AssignmentStrategy::CooperativeSticky {
+            let owners = self.get_partition_owners();
+            for (member_id, partitions) in assignments.iter() {
+                for partition_id in partitions {
+                    if let Some(owner_id) = owners.get(partition_id) {
+                        if owner_id != member_id {
+                            pending_partitions.push(PendingPartition {
+                                partition_id: *partition_id,
+                                revoked_member_id: *owner_id,
+                                assigned_member_id: *member_id,
+                            });
+                        }
+                    }
+                }
+            }
+        }
+
+        for (member_id, mut partitions) in assignments {
+            partitions.retain(|partition_id| {
+                !pending_partitions.iter().any(|pending_partition| {
+                    pending_partition.partition_id == *partition_id
+                        && pending_partition.assigned_member_id == member_id
+                })
+            });
+            partitions.extend(
+                pending_partitions
+                    .iter()
+                    .filter(|pending_partition| pending_partition.revoked_member_id == member_id)
+                    .map(|pending_partition| pending_partition.partition_id),
+            );
+            partitions.sort();
+            let member = self.members.get_mut(&member_id).unwrap().get_mut();
+            member.assign_partitions(partitions);
+            trace!("Assigned partitions: {:?} to member with ID: {} for topic with ID: {} in consumer group: {}",
+                member.get_partitions(), member_id, self.topic_id, self.id)
+        }
+        *self.pending_partitions.get_mut() = pending_partitions;
+        self.assignment_notifier.send_replace(self.generation_id);
+    }
+
+    fn assign_range(&self, member_ids: &[u32]) -> HashMap<u32, Vec<u32>> {
+        let members_count = member_ids.len() as u32;
+        let partitions_per_member = self.partitions_count / members_count;
+        let extra_partitions = self.partitions_count % members_count;
+        let mut assignments = HashMap::new();
+        let mut partition_id = 1;
+        for (member_index, member_id) in member_ids.iter().enumerate() {
+            let mut partitions_count = partitions_per_member;
+            if (member_index as u32) < extra_partitions {
+                partitions_count += 1;
+            }
+            let partitions = (partition_id..partition_id + partitions_count).collect();
+            partition_id += partitions_count;
+            assignments.insert(*member_id, partitions);
         }
+        assignments
+    }
 
+    fn assign_round_robin(&self, member_ids: &[u32]) -> HashMap<u32, Vec<u32>> {
+        let mut assignments = member_ids
+            .iter()
+            .map(|member_id| (*member_id, Vec::new()))
+            .collect::<HashMap<_, _>>();
         for partition_index in 0..self.partitions_count {
-            let partition_id = partition_index + 1;
-            let member_index = partition_index % members_count;
-            let member = members.get(member_index as usize).unwrap();
-            let mut member = member.write().await;
-            let member_partition_index = member.partitions.len() as u32;
-            member
-                .partitions
-                .insert(member_partition_index, partition_id);
-            trace!("Assigned partition ID: {} to member with ID: {} for topic with ID: {} in consumer group: {}",
-                partition_id, member.id, self.topic_id, self.id)
+            let member_id = member_ids[(partition_index as usize) % member_ids.len()];
+            assignments
+                .get_mut(&member_id)
+                .unwrap()
+                .push(partition_index + 1);
+        }
+        assignments
+    }
+
+    // Members keep as many of their current partitions as their fair share allows, only the remaining ones are moved.
+    fn assign_sticky(&mut self, member_ids: &[u32]) -> HashMap<u32, Vec<u32>> {
+        let mut current_assignments = member_ids
+            .iter()
+            .map(|member_id| (*member_id, self.get_current_partitions(*member_id)))
+            .collect::<Vec<_>>();
+        current_assignments.sort_by(|(first_id, first), (second_id, second)| {
+            second.len().cmp(&first.len()).then(first_id.cmp(second_id))
+        });
+
+        let members_count = member_ids.len() as u32;
+        let partitions_per_member = self.partitions_count / members_count;
+        let mut extra_partitions = self.partitions_count % members_count;
+        let mut assigned_partitions = vec![false; self.partitions_count as usize + 1];
+        let mut assignments = Vec::with_capacity(current_assignments.len());
+        for (member_id, partitions) in current_assignments {
+            let mut quota = partitions_per_member;
+            if extra_partitions > 0 {
+                quota += 1;
+                extra_partitions -= 1;
+            }
+            let mut kept_partitions = Vec::new();
+            for partition_id in partitions {
+                if kept_partitions.len() as u32 == quota {
+                    break;
+                }
+                if assigned_partitions[partition_id as usize] {
+                    continue;
+                }
+                assigned_partitions[partition_id as usize] = true;
+                kept_partitions.push(partition_id);
+            }
+            assignments.push((member_id, quota, kept_partitions));
+        }
+
+        let mut unassigned_partitions = (1..=self.partitions_count)
+            .filter(|partition_id| !assigned_partitions[*partition_id as usize]);
+        for (_, quota, partitions) in assignments.iter_mut() {
+            while (partitions.len() as u32) < *quota {
+                match unassigned_partitions.next() {
+                    Some(partition_id) => partitions.push(partition_id),
+                    None => break,
+                }
+            }
+            partitions.sort();
+        }
+
+        assignments
+            .into_iter()
+            .map(|(member_id, _, partitions)| (member_id, partitions))
+            .collect()
+    }
+
+    // The partitions which are still held by the member only until they're released, are not its own anymore.
+    fn get_current_partitions(&mut self, member_id: u32) -> Vec<u32> {
+        let mut partitions = self
+            .members
+            .get_mut(&member_id)
+            .unwrap()
+            .get_mut()
+            .get_partitions();
+        partitions.retain(|partition_id| {
+            !self
+                .pending_partitions
+                .get_mut()
+                .iter()
+                .any(|pending_partition| {
+                    pending_partition.partition_id == *partition_id
+                        && pending_partition.revoked_member_id == member_id
+                })
+        });
+        partitions.extend(
+            self.pending_partitions
+                .get_mut()
+                .iter()
+                .filter(|pending_partition| pending_partition.assigned_member_id == member_id)
+                .map(|pending_partition| pending_partition.partition_id),
+        );
+        partitions.retain(|partition_id| *partition_id <= self.partitions_count);
+        partitions.sort();
+        partitions
+    }
+
+    fn get_partition_owners(&mut self) -> HashMap<u32, u32> {
+        let mut owners = HashMap::new();
+        for (member_id, member) in self.members.iter_mut() {
+            for partition_id in member.get_mut().get_partitions() {
+                owners.insert(partition_id, *member_id);
+            }
         }
+        for pending_partition in self.pending_partitions.get_mut().iter() {
+            if self
+                .members
+                .contains_key(&pending_partition.revoked_member_id)
+            {
+                owners.insert(
+                    pending_partition.partition_id,
+                    pending_partition.revoked_member_id,
+                );
+            }
+        }
+        owners
     }
 }
 
 impl ConsumerGroupMember {
     pub fn get_partitions(&self) -> Vec<u32> {
-        self.partitions.values().copied().collect()
+        let mut partitions = self.partitions.values().copied().collect::<Vec<_>>();
+        partitions.sort();
+        partitions
     }
 
-    pub fn calculate_partition_id(&mut self) -> u32 {
+    pub fn calculate_partition_id(&mut self) -> Option<u32> {
         let partition_index = self.current_partition_index;
-        let partition_id = *self.partitions.get(&partition_index).unwrap();
+        let partition_id = *self.partitions.get(&partition_index)?;
         self.current_partition_id = partition_id;
         if self.partitions.len() == (partition_index + 1) as usize {
             self.current_partition_index = 0;
@@ -141,7 +483,40 @@ impl ConsumerGroupMember {
             partition_id,
             self.id
         );
-        partition_id
+        Some(partition_id)
+    }
+
+    fn assign_partitions(&mut self, partitions: Vec<u32>) {
+        if self.get_partitions() == partitions {
+            return;
+        }
+
+        self.partitions = partitions
+            .into_iter()
+            .enumerate()
+            .map(|(partition_index, partition_id)| (partition_index as u32, partition_id))
+            .collect();
+        self.current_partition_index = 0;
+        if !self
+            .partitions
+            .values()
+            .any(|partition_id| *partition_id == self.current_partition_id)
+        {
+            self.current_partition_id = 0;
+        }
+        self.assignment_changed = true;
+    }
+
+    fn add_partition(&mut self, partition_id: u32) {
+        let partition_index = self.partitions.len() as u32;
+        self.partitions.insert(partition_index, partition_id);
+        self.assignment_changed = true;
+    }
+
+    fn remove_partition(&mut self, partition_id: u32) {
+        let mut partitions = self.get_partitions();
+        partitions.retain(|id| *id != partition_id);
+        self.assign_partitions(partitions);
     }
 }
 
@@ -152,18 +527,14 @@ mod tests {
     #[tokio::test]
     async fn should_calculate_partition_id_using_round_robin() {
         let member_id = 123;
-        let mut consumer_group = ConsumerGroup {
-            topic_id: 1,
-            id: 1,
-            partitions_count: 3,
-            members: HashMap::new(),
-        };
+        let mut consumer_group = ConsumerGroup::new(1, 1, 3, AssignmentStrategy::RoundRobin, 0);
 
         consumer_group.add_member(member_id).await;
         for i in 0..1000 {
             let partition_id = consumer_group
                 .calculate_partition_id(member_id)
                 .await
+                .unwrap()
                 .unwrap();
             assert_eq!(partition_id, (i % consumer_group.partitions_count) + 1);
         }
@@ -172,12 +543,7 @@ mod tests {
     #[tokio::test]
     async fn should_assign_all_partitions_to_the_only_single_member() {
         let member_id = 123;
-        let mut consumer_group = ConsumerGroup {
-            topic_id: 1,
-            id: 1,
-            partitions_count: 3,
-            members: HashMap::new(),
-        };
+        let mut consumer_group = ConsumerGroup::new(1, 1, 3, AssignmentStrategy::RoundRobin, 0);
 
         consumer_group.add_member(member_id).await;
         let member = consumer_group.members.get(&member_id).unwrap();
@@ -196,12 +562,7 @@ mod tests {
     async fn should_assign_partitions_to_the_multiple_members() {
         let member1_id = 123;
         let member2_id = 456;
-        let mut consumer_group = ConsumerGroup {
-            topic_id: 1,
-            id: 1,
-            partitions_count: 3,
-            members: HashMap::new(),
-        };
+        let mut consumer_group = ConsumerGroup::new(1, 1, 3, AssignmentStrategy::RoundRobin, 0);
 
         consumer_group.add_member(member1_id).await;
         consumer_group.add_member(member2_id).await;
@@ -232,12 +593,7 @@ mod tests {
     async fn should_assign_only_single_partition_to_the_only_single_member() {
         let member1_id = 123;
         let member2_id = 456;
-        let mut consumer_group = ConsumerGroup {
-            topic_id: 1,
-            id: 1,
-            partitions_count: 1,
-            members: HashMap::new(),
-        };
+        let mut consumer_group = ConsumerGroup::new(1, 1, 1, AssignmentStrategy::RoundRobin, 0);
 
         consumer_group.add_member(member1_id).await;
         consumer_group.add_member(member2_id).await;
@@ -252,4 +608,133 @@ mod tests {
             assert_eq!(member2.partitions.len(), 1);
         }
     }
+
+    #[tokio::test]
+    async fn should_assign_contiguous_partitions_using_range() {
+        let mut consumer_group = ConsumerGroup::new(1, 1, 5, AssignmentStrategy::Range, 0);
+
+        consumer_group.add_member(2).await;
+        consumer_group.add_member(1).await;
+        let member1 = consumer_group.members.get(&1).unwrap().read().await;
+        let member2 = consumer_group.members.get(&2).unwrap().read().await;
+        assert_eq!(member1.get_partitions(), vec![1, 2, 3]);
+        assert_eq!(member2.get_partitions(), vec![4, 5]);
+    }
+
+    #[tokio::test]
+    async fn should_keep_existing_partitions_using_sticky() {
+        let mut consumer_group = ConsumerGroup::new(1, 1, 6, AssignmentStrategy::Sticky, 0);
+
+        consumer_group.add_member(1).await;
+        consumer_group.add_member(2).await;
+        consumer_group.add_member(3).await;
+        let member1_partitions = get_member_partitions(&consumer_group, 1).await;
+        let member2_partitions = get_member_partitions(&consumer_group, 2).await;
+        let member3_partitions = get_member_partitions(&consumer_group, 3).await;
+        assert_eq!(member1_partitions.len(), 2);
+        assert_eq!(member2_partitions.len(), 2);
+        assert_eq!(member3_partitions.len(), 2);
+
+        consumer_group.delete_member(2).await;
+        let member1_new_partitions = get_member_partitions(&consumer_group, 1).await;
+        let member3_new_partitions = get_member_partitions(&consumer_group, 3).await;
+        assert_eq!(member1_new_partitions.len(), 3);
+        assert_eq!(member3_new_partitions.len(), 3);
+        for partition_id in member1_partitions {
+            assert!(member1_new_partitions.contains(&partition_id));
+        }
+        for partition_id in member3_partitions {
+            assert!(member3_new_partitions.contains(&partition_id));
+        }
+    }
+
+    #[tokio::test]
+    async fn should_release_revoked_partitions_after_previous_member_polls_using_cooperative_sticky(
+    ) {
+        let mut consumer_group =
+            ConsumerGroup::new(1, 1, 2, AssignmentStrategy::CooperativeSticky, 0);
+
+        consumer_group.add_member(1).await;
+        consumer_group.add_member(2).await;
+        assert_eq!(get_member_partitions(&consumer_group, 1).await, vec![1, 2]);
+        assert!(get_member_partitions(&consumer_group, 2).await.is_empty());
+        assert!(consumer_group
+            .calculate_partition_id(2)
+            .await
+            .unwrap()
+            .is_none());
+
+        let generation_id = consumer_group.generation_id;
+        let partition_id = consumer_group.calculate_partition_id(1).await.unwrap();
+        assert_eq!(partition_id, Some(1));
+        assert_eq!(get_member_partitions(&consumer_group, 1).await, vec![1]);
+        assert_eq!(consumer_group.generation_id, generation_id + 1);
+        let partition_id = consumer_group.calculate_partition_id(2).await.unwrap();
+        assert_eq!(partition_id, Some(2));
+    }
+
+    #[tokio::test]
+    async fn should_release_revoked_partitions_when_previous_member_leaves_using_cooperative_sticky(
+    ) {
+        let mut consumer_group =
+            ConsumerGroup::new(1, 1, 2, AssignmentStrategy::CooperativeSticky, 0);
+
+        consumer_group.add_member(1).await;
+        consumer_group.add_member(2).await;
+        assert!(get_member_partitions(&consumer_group, 2).await.is_empty());
+
+        consumer_group.delete_member(1).await;
+        assert_eq!(get_member_partitions(&consumer_group, 2).await, vec![1, 2]);
+    }
+
+    async fn get_member_partitions(consumer_group: &ConsumerGroup, member_id: u32) -> Vec<u32> {
+        consumer_group
+            .members
+            .get(&member_id)
+            .unwrap()
+            .read()
+            .await
+            .get_partitions()
+    }
+
+    #[tokio::test]
+    async fn should_return_members_that_missed_the_session_timeout_as_expired() {
+        let mut consumer_group = ConsumerGroup::new(1, 1, 2, AssignmentStrategy::RoundRobin, 10);
+
+        consumer_group.add_member(1).await;
+        consumer_group.add_member(2).await;
+        let now = TimeStamp::now().to_micros();
+        assert!(consumer_group.get_expired_members(now).await.is_empty());
+
+        consumer_group
+            .members
+            .get(&1)
+            .unwrap()
+            .write()
+            .await
+            .last_heartbeat_at = now - 11_000_000;
+        assert_eq!(consumer_group.get_expired_members(now).await, vec![1]);
+
+        consumer_group.heartbeat(1).await.unwrap();
+        assert!(consumer_group.get_expired_members(now).await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn should_bump_generation_and_mark_assignment_as_changed_on_rebalance() {
+        let mut consumer_group = ConsumerGroup::new(1, 1, 2, AssignmentStrategy::RoundRobin, 0);
+
+        consumer_group.add_member(1).await;
+        let generation_id = consumer_group.generation_id;
+        assert!(consumer_group.acknowledge_assignment(1).await.unwrap());
+        assert!(!consumer_group.acknowledge_assignment(1).await.unwrap());
+        assert!(consumer_group.validate_generation(generation_id).is_ok());
+
+        consumer_group.add_member(2).await;
+        assert_eq!(consumer_group.generation_id, generation_id + 1);
+        assert!(consumer_group.validate_generation(generation_id).is_err());
+        let assignment = consumer_group.get_assignment(1).await.unwrap();
+        assert_eq!(assignment.generation_id, generation_id + 1);
+        assert_eq!(assignment.partitions, vec![1]);
+        assert!(!consumer_group.acknowledge_assignment(1).await.unwrap());
+    }
 }
@@ -1,6 +1,7 @@
 use crate::streaming::topics::consumer_group::ConsumerGroup;
 use crate::streaming::topics::topic::Topic;
 use iggy::error::Error;
+use iggy::models::assignment_strategy::AssignmentStrategy;
 use tokio::sync::RwLock;
 use tracing::info;
 
@@ -34,8 +35,19 @@ impl Topic {
         Ok(consumer_group.unwrap())
     }
 
-    pub async fn create_consumer_group(&mut self, id: u32) -> Result<(), Error> {
-        let consumer_group = ConsumerGroup::new(self.topic_id, id, self.partitions.len() as u32);
+    pub async fn create_consumer_group(
+        &mut self,
+        id: u32,
+        assignment_strategy: AssignmentStrategy,
+        session_timeout: u32,
+    ) -> Result<(), Error> {
+        let consumer_group = ConsumerGroup::new(
+            self.topic_id,
+            id,
+            self.partitions.len() as u32,
+            assignment_strategy,
+            session_timeout,
+        );
         if self
             .consumer_groups
             .insert(id, RwLock::new(consumer_group))
@@ -92,6 +104,16 @@ impl Topic {
         Ok(())
     }
 
+    pub async fn heartbeat_consumer_group(
+        &self,
+        consumer_group_id: u32,
+        member_id: u32,
+    ) -> Result<(), Error> {
+        let consumer_group = self.get_consumer_group(consumer_group_id)?;
+        let consumer_group = consumer_group.read().await;
+        consumer_group.heartbeat(member_id).await
+    }
+
     pub async fn leave_consumer_group(
         &self,
         consumer_group_id: u32,
@@ -119,7 +141,9 @@ mod tests {
     async fn should_be_created_given_valid_parameters() {
         let consumer_group_id = 1;
         let mut topic = get_topic();
-        let result = topic.create_consumer_group(consumer_group_id).await;
+        let result = topic
+            .create_consumer_group(consumer_group_id, AssignmentStrategy::default(), 0)
+            .await;
         assert!(result.is_ok());
     }
 
@@ -127,10 +151,14 @@ mod tests {
     async fn should_not_be_created_given_already_existing_group_with_same_id() {
         let consumer_group_id = 1;
         let mut topic = get_topic();
-        let result = topic.create_consumer_group(consumer_group_id).await;
+        let result = topic
+            .create_consumer_group(consumer_group_id, AssignmentStrategy::default(), 0)
+            .await;
         assert!(result.is_ok());
         assert_eq!(topic.consumer_groups.len(), 1);
-        let result = topic.create_consumer_group(consumer_group_id).await;
+        let result = topic
+            .create_consumer_group(consumer_group_id, AssignmentStrategy::default(), 0)
+            .await;
         assert!(result.is_err());
         assert_eq!(topic.consumer_groups.len(), 1);
         let err = result.unwrap_err();
@@ -141,7 +169,9 @@ mod tests {
     async fn should_be_deleted_given_already_existing_group_with_same_id() {
         let consumer_group_id = 1;
         let mut topic = get_topic();
-        let result = topic.create_consumer_group(consumer_group_id).await;
+        let result = topic
+            .create_consumer_group(consumer_group_id, AssignmentStrategy::default(), 0)
+            .await;
         assert!(result.is_ok());
         assert_eq!(topic.consumer_groups.len(), 1);
         let result = topic.delete_consumer_group(consumer_group_id).await;
@@ -153,7 +183,9 @@ mod tests {
     async fn should_not_be_deleted_given_non_existing_group_with_same_id() {
         let consumer_group_id = 1;
         let mut topic = get_topic();
-        let result = topic.create_consumer_group(consumer_group_id).await;
+        let result = topic
+            .create_consumer_group(consumer_group_id, AssignmentStrategy::default(), 0)
+            .await;
         assert!(result.is_ok());
         assert_eq!(topic.consumer_groups.len(), 1);
         let result = topic.delete_consumer_group(consumer_group_id + 1).await;
@@ -167,7 +199,7 @@ mod tests {
         let member_id = 1;
         let mut topic = get_topic();
         topic
-            .create_consumer_group(consumer_group_id)
+            .create_consumer_group(consumer_group_id, AssignmentStrategy::default(), 0)
             .await
             .unwrap();
         let result = topic
@@ -189,7 +221,7 @@ mod tests {
         let member_id = 1;
         let mut topic = get_topic();
         topic
-            .create_consumer_group(consumer_group_id)
+            .create_consumer_group(consumer_group_id, AssignmentStrategy::default(), 0)
             .await
             .unwrap();
         topic
@@ -1,8 +1,13 @@
 use crate::streaming::partitions::partition::Partition;
 use crate::streaming::polling_consumer::PollingConsumer;
+use crate::streaming::topics::consumer_group::ConsumerGroupGeneration;
 use crate::streaming::topics::topic::Topic;
+use iggy::consumer::{Consumer, ConsumerKind};
+use iggy::consumer_offsets::reset_consumer_offsets::ResetStrategy;
 use iggy::error::Error;
+use iggy::models::consumer_lag::ConsumerLag;
 use iggy::models::consumer_offset_info::ConsumerOffsetInfo;
+use iggy::models::consumer_offset_reset::ConsumerOffsetReset;
 use tokio::sync::RwLock;
 
 impl Topic {
@@ -16,6 +21,28 @@ impl Topic {
         partition.store_consumer_offset(consumer, offset).await
     }
 
+    // The consumer group stays locked until the offset is stored, so it can't be rebalanced in the meantime.
+    pub async fn store_consumer_offset_in_generation(
+        &self,
+        consumer: PollingConsumer,
+        offset: u64,
+        generation: ConsumerGroupGeneration,
+    ) -> Result<(), Error> {
+        let consumer_group = self
+            .get_consumer_group(generation.consumer_group_id)?
+            .read()
+            .await;
+        consumer_group.validate_generation(generation.generation_id)?;
+        let partition_id = match consumer {
+            PollingConsumer::Consumer(_, partition_id) => partition_id,
+            PollingConsumer::ConsumerGroup(_, member_id) => {
+                consumer_group.get_current_partition_id(member_id).await?
+            }
+        };
+        let partition = self.get_partition_by_id(partition_id)?.read().await;
+        partition.store_consumer_offset(consumer, offset).await
+    }
+
     pub async fn get_consumer_offset(
         &self,
         consumer: PollingConsumer,
@@ -30,6 +57,83 @@ impl Topic {
         })
     }
 
+    pub async fn get_consumer_lag(&self, consumer: &Consumer) -> Result<ConsumerLag, Error> {
+        if consumer.kind == ConsumerKind::ConsumerGroup {
+            self.get_consumer_group(consumer.id)?;
+        }
+
+        let mut partitions = Vec::with_capacity(self.partitions.len());
+        for partition in self.partitions.values() {
+            let partition = partition.read().await;
+            partitions.push(
+                partition
+                    .get_consumer_lag(consumer.kind, consumer.id)
+                    .await?,
+            );
+        }
+        partitions.sort_by_key(|partition| partition.partition_id);
+        Ok(ConsumerLag {
+            consumer_kind: consumer.kind,
+            consumer_id: consumer.id,
+            partitions,
+        })
+    }
+
+    pub async fn get_consumer_groups_lag(&self) -> Result<Vec<ConsumerLag>, Error> {
+        let mut consumer_group_ids = Vec::new();
+        for consumer_group in self.get_consumer_groups() {
+            consumer_group_ids.push(consumer_group.read().await.id);
+        }
+        consumer_group_ids.sort();
+
+        let mut lags = Vec::with_capacity(consumer_group_ids.len());
+        for consumer_group_id in consumer_group_ids {
+            lags.push(
+                self.get_consumer_lag(&Consumer::group(consumer_group_id))
+                    .await?,
+            );
+        }
+        Ok(lags)
+    }
+
+    pub async fn reset_consumer_offsets(
+        &self,
+        consumer: &Consumer,
+        partition_id: Option<u32>,
+        strategy: ResetStrategy,
+        dry_run: bool,
+    ) -> Result<Vec<ConsumerOffsetReset>, Error> {
+        if consumer.kind == ConsumerKind::ConsumerGroup {
+            self.get_consumer_group(consumer.id)?;
+        }
+
+        let partitions = match partition_id {
+            Some(partition_id) => match self.partitions.get(&partition_id) {
+                Some(partition) => vec![partition],
+                None => {
+                    return Err(Error::PartitionNotFound(
+                        partition_id,
+                        self.topic_id,
+                        self.stream_id,
+                    ))
+                }
+            },
+            None => self.partitions.values().collect(),
+        };
+
+        let mut resets = Vec::with_capacity(partitions.len());
+        for partition in partitions {
+            let partition = partition.read().await;
+            resets.push(
+                partition
+                    .reset_consumer_offset(consumer.kind, consumer.id, strategy, dry_run)
+                    .await?,
+            );
+        }
+        resets.sort_by_key(|reset| reset.partition_id);
+        Ok(resets)
+    }
+
     async fn resolve_partition(
         &self,
         consumer: PollingConsumer,
@@ -42,16 +146,16 @@ impl Topic {
             }
         }?;
 
-        let partition = self.partitions.get(&partition_id);
-        if partition.is_none() {
-            return Err(Error::PartitionNotFound(
+        self.get_partition_by_id(partition_id)
+    }
+
+    fn get_partition_by_id(&self, partition_id: u32) -> Result<&RwLock<Partition>, Error> {
+        self.partitions
+            .get(&partition_id)
+            .ok_or(Error::PartitionNotFound(
                 partition_id,
+                self.topic_id,
                 self.stream_id,
-                self.stream_id,
-            ));
-        }
-
-        let partition = partition.unwrap();
-        Ok(partition)
+            ))
     }
 }
@@ -0,0 +1,21 @@
+use crate::streaming::encryption::key_ring::KeyRing;
+use crate::streaming::topics::topic::Topic;
+use iggy::error::Error;
+
+impl Topic {
+    pub async fn reencrypt(&self, key_ring: &KeyRing, key_id: u32) -> Result<u64, Error> {
+        let mut reencrypted_messages = 0;
+        for partition in self.get_partitions() {
+            let start_offsets = partition.read().await.get_closed_segments_start_offsets();
+            // The partition is locked for a single segment at a time, so the other requests are not blocked for the whole rewrite.
+            for start_offset in start_offsets {
+                let partition = partition.write().await;
+                reencrypted_messages += partition
+                    .reencrypt_segment(start_offset, key_ring, key_id)
+                    .await?;
+            }
+        }
+
+        Ok(reencrypted_messages)
+    }
+}
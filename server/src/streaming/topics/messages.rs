@@ -1,14 +1,20 @@
 use crate::streaming::models::messages::PolledMessages;
+use crate::streaming::partitions::transactions::TransactionMarker;
+use crate::streaming::polling_args::PollingArgs;
 use crate::streaming::polling_consumer::PollingConsumer;
+use crate::streaming::topics::compaction::MESSAGES_KEY_HEADER;
 use crate::streaming::topics::topic::Topic;
 use crate::streaming::utils::hash;
 use iggy::error::Error;
-use iggy::messages::poll_messages::{PollingKind, PollingStrategy};
-use iggy::messages::send_messages::{Partitioning, PartitioningKind};
+use iggy::messages::poll_messages::PollingKind;
+use iggy::messages::send_messages::{Partitioning, PartitioningKind, ProducerSequence};
+use iggy::models::cleanup_policy::CleanupPolicy;
+use iggy::models::header::{HeaderKey, HeaderValue};
 use iggy::models::messages::Message;
 use ringbuffer::RingBuffer;
 use std::collections::HashMap;
 use std::sync::atomic::Ordering;
+use tokio::sync::watch;
 use tracing::trace;
 
 impl Topic {
@@ -16,8 +22,7 @@ impl Topic {
         &self,
         consumer: PollingConsumer,
         partition_id: u32,
-        strategy: PollingStrategy,
-        count: u32,
+        args: &PollingArgs,
     ) -> Result<PolledMessages, Error> {
         if !self.has_partitions() {
             return Err(Error::NoPartitions(self.topic_id, self.stream_id));
@@ -34,33 +39,48 @@ impl Topic {
 
         let partition = partition.unwrap();
         let partition = partition.read().await;
-        let value = strategy.value;
-        let messages = match strategy.kind {
+        let count = args.count;
+        let value = args.strategy.value;
+        let messages = match args.strategy.kind {
             PollingKind::Offset => partition.get_messages_by_offset(value, count).await,
             PollingKind::Timestamp => partition.get_messages_by_timestamp(value, count).await,
             PollingKind::First => partition.get_first_messages(count).await,
             PollingKind::Last => partition.get_last_messages(count).await,
             PollingKind::Next => partition.get_next_messages(consumer, count).await,
         }?;
+        let (messages, scanned_offset) = partition
+            .get_visible_messages(
+                messages,
+                count,
+                args.isolation_level,
+                args.include_all_states,
+                &args.filters,
+            )
+            .await?;
 
         Ok(PolledMessages {
             messages,
             partition_id,
             current_offset: partition.current_offset,
+            scanned_offset,
+            generation_id: None,
+            assignment_changed: false,
         })
     }
 
     pub async fn append_messages(
         &self,
         partitioning: &Partitioning,
-        messages: Vec<Message>,
-    ) -> Result<(), Error> {
+        producer: Option<&ProducerSequence>,
+        transaction_id: Option<u64>,
+        mut messages: Vec<Message>,
+    ) -> Result<Option<u32>, Error> {
         if !self.has_partitions() {
             return Err(Error::NoPartitions(self.topic_id, self.stream_id));
         }
 
         if messages.is_empty() {
-            return Ok(());
+            return Ok(None);
         }
 
         let partition_id = match partitioning.kind {
@@ -73,13 +93,66 @@ impl Topic {
             }
         };
 
-        self.append_messages_to_partition(partition_id, messages)
+        if partitioning.kind == PartitioningKind::MessagesKey && self.should_store_messages_key() {
+            let messages_key = HeaderKey::new(MESSAGES_KEY_HEADER)?;
+            let messages_key_value = HeaderValue::from_raw(&partitioning.value)?;
+            for message in messages.iter_mut() {
+                message
+                    .headers
+                    .get_or_insert_with(HashMap::new)
+                    .insert(messages_key.clone(), messages_key_value.clone());
+            }
+        }
+
+        self.append_messages_to_partition(partition_id, producer, transaction_id, messages)
+            .await?;
+        Ok(Some(partition_id))
+    }
+
+    pub async fn append_transaction_marker(
+        &self,
+        partition_id: u32,
+        transaction_id: u64,
+        marker: TransactionMarker,
+    ) -> Result<(), Error> {
+        let partition = self.partitions.get(&partition_id);
+        if partition.is_none() {
+            return Err(Error::PartitionNotFound(
+                partition_id,
+                self.topic_id,
+                self.stream_id,
+            ));
+        }
+
+        let partition = partition.unwrap();
+        let mut partition = partition.write().await;
+        partition
+            .append_transaction_marker(transaction_id, marker)
             .await
     }
 
+    pub async fn subscribe_to_new_messages(
+        &self,
+        partition_id: u32,
+    ) -> Result<watch::Receiver<u64>, Error> {
+        let partition = self.partitions.get(&partition_id);
+        if partition.is_none() {
+            return Err(Error::PartitionNotFound(
+                partition_id,
+                self.topic_id,
+                self.stream_id,
+            ));
+        }
+
+        let partition = partition.unwrap().read().await;
+        Ok(partition.subscribe_to_new_messages())
+    }
+
     async fn append_messages_to_partition(
         &self,
         partition_id: u32,
+        producer: Option<&ProducerSequence>,
+        transaction_id: Option<u64>,
         messages: Vec<Message>,
     ) -> Result<(), Error> {
         let partition = self.partitions.get(&partition_id);
@@ -93,7 +166,19 @@ impl Topic {
 
         let partition = partition.unwrap();
         let mut partition = partition.write().await;
-        partition.append_messages(messages).await?;
+        match (transaction_id, producer) {
+            (Some(transaction_id), _) => {
+                partition
+                    .append_transactional_messages(transaction_id, producer, messages)
+                    .await?
+            }
+            (None, Some(producer)) => {
+                partition
+                    .append_producer_messages(producer, messages)
+                    .await?
+            }
+            (None, None) => partition.append_messages(messages).await?,
+        }
         Ok(())
     }
 
@@ -159,10 +244,19 @@ impl Topic {
                 end_offset
             );
 
-            let messages = partition
+            let mut messages = partition
                 .get_messages_by_offset(start_offset, messages_count)
                 .await?;
 
+            // Compacted segments may have gaps, while the cached messages must have contiguous offsets.
+            let contiguous_messages_count = messages
+                .iter()
+                .rev()
+                .zip((0..=end_offset).rev())
+                .take_while(|(message, offset)| message.offset == *offset)
+                .count();
+            messages.drain(..messages.len() - contiguous_messages_count);
+
             if partition.messages.is_some() {
                 let partition_messages = partition.messages.as_mut().unwrap();
                 for message in messages {
@@ -189,7 +283,7 @@ impl Topic {
         now: u64,
     ) -> HashMap<u32, Vec<u64>> {
         let mut expired_segments = HashMap::new();
-        if self.message_expiry.is_none() {
+        if self.message_expiry.is_none() || self.cleanup_policy == CleanupPolicy::Compact {
             return expired_segments;
         }
 
@@ -203,6 +297,83 @@ impl Topic {
 
         expired_segments
     }
+
+    pub async fn get_oversized_segments_start_offsets_per_partition(
+        &self,
+    ) -> HashMap<u32, Vec<u64>> {
+        let mut oversized_segments = HashMap::new();
+        if self.max_topic_size_bytes.is_none() && self.max_messages.is_none() {
+            return oversized_segments;
+        }
+
+        let mut size_bytes = 0;
+        let mut messages_count = 0;
+        let mut closed_segments = Vec::new();
+        for (_, partition) in self.partitions.iter() {
+            let partition = partition.read().await;
+            size_bytes += partition.get_size_bytes();
+            messages_count += partition.get_messages_count();
+            // The last segment is still open for writes, so it's never deleted.
+            let segments = partition.get_segments();
+            let segments = segments
+                .iter()
+                .take(segments.len().saturating_sub(1))
+                .map(|segment| {
+                    (
+                        segment.start_offset,
+                        segment.current_size_bytes as u64,
+                        segment.get_messages_count(),
+                    )
+                })
+                .collect::<Vec<(u64, u64, u64)>>();
+            closed_segments.push((partition.partition_id, segments));
+        }
+        closed_segments.sort_by_key(|(partition_id, _)| *partition_id);
+
+        let mut segment_index = 0;
+        loop {
+            let mut has_more_segments = false;
+            for (partition_id, segments) in &closed_segments {
+                if self.is_within_retention_limits(size_bytes, messages_count) {
+                    return oversized_segments;
+                }
+
+                if let Some((start_offset, segment_size_bytes, segment_messages_count)) =
+                    segments.get(segment_index)
+                {
+                    has_more_segments = true;
+                    size_bytes -= segment_size_bytes;
+                    messages_count -= segment_messages_count;
+                    oversized_segments
+                        .entry(*partition_id)
+                        .or_insert_with(Vec::new)
+                        .push(*start_offset);
+                }
+            }
+
+            if !has_more_segments {
+                return oversized_segments;
+            }
+
+            segment_index += 1;
+        }
+    }
+
+    fn is_within_retention_limits(&self, size_bytes: u64, messages_count: u64) -> bool {
+        if let Some(max_topic_size_bytes) = self.max_topic_size_bytes {
+            if size_bytes > max_topic_size_bytes {
+                return false;
+            }
+        }
+
+        if let Some(max_messages) = self.max_messages {
+            if messages_count > max_messages {
+                return false;
+            }
+        }
+
+        true
+    }
 }
 
 #[cfg(test)]
@@ -233,7 +404,7 @@ mod tests {
                 None,
             )];
             topic
-                .append_messages(&partitioning, messages)
+                .append_messages(&partitioning, None, None, messages)
                 .await
                 .unwrap();
         }
@@ -269,7 +440,7 @@ mod tests {
                 None,
             )];
             topic
-                .append_messages(&partitioning, messages)
+                .append_messages(&partitioning, None, None, messages)
                 .await
                 .unwrap();
         }
@@ -287,6 +458,82 @@ mod tests {
         assert_eq!(read_messages_count, messages_count as usize);
     }
 
+    #[tokio::test]
+    async fn given_compact_cleanup_policy_messages_key_should_be_stored_in_the_message_headers() {
+        let partitions_count = 3;
+        let mut topic = init_topic(partitions_count);
+        topic.cleanup_policy = CleanupPolicy::Compact;
+        let partitioning = Partitioning::messages_key_u32(1);
+        let messages = vec![Message::empty(
+            1,
+            MessageState::Available,
+            1,
+            Bytes::from("test"),
+            1,
+            None,
+        )];
+        topic
+            .append_messages(&partitioning, None, None, messages)
+            .await
+            .unwrap();
+
+        let compaction_key = topic.get_compaction_key().unwrap();
+        let partition_id = topic.calculate_partition_id_by_messages_key_hash(&partitioning.value);
+        let partition = topic.get_partition(partition_id).unwrap().read().await;
+        let messages = partition.messages.as_ref().unwrap().to_vec();
+        assert_eq!(messages.len(), 1);
+        let messages_key = messages[0]
+            .headers
+            .as_ref()
+            .unwrap()
+            .get(&compaction_key)
+            .unwrap();
+        assert_eq!(messages_key.value, partitioning.value);
+    }
+
+    #[tokio::test]
+    async fn given_max_topic_size_bytes_oldest_closed_segments_should_be_returned_until_topic_fits()
+    {
+        let mut topic = init_topic(2);
+        init_segments(&topic, 3, 10, 100).await;
+        topic.max_topic_size_bytes = Some(400);
+
+        let oversized_segments = topic
+            .get_oversized_segments_start_offsets_per_partition()
+            .await;
+
+        assert_eq!(oversized_segments.len(), 2);
+        assert_eq!(oversized_segments[&1], vec![0]);
+        assert_eq!(oversized_segments[&2], vec![0]);
+    }
+
+    #[tokio::test]
+    async fn given_max_messages_last_segment_should_never_be_returned() {
+        let mut topic = init_topic(2);
+        init_segments(&topic, 3, 10, 100).await;
+        topic.max_messages = Some(5);
+
+        let oversized_segments = topic
+            .get_oversized_segments_start_offsets_per_partition()
+            .await;
+
+        assert_eq!(oversized_segments.len(), 2);
+        assert_eq!(oversized_segments[&1], vec![0, 10]);
+        assert_eq!(oversized_segments[&2], vec![0, 10]);
+    }
+
+    #[tokio::test]
+    async fn given_no_retention_limits_no_segments_should_be_returned() {
+        let topic = init_topic(2);
+        init_segments(&topic, 3, 10, 100).await;
+
+        let oversized_segments = topic
+            .get_oversized_segments_start_offsets_per_partition()
+            .await;
+
+        assert!(oversized_segments.is_empty());
+    }
+
     #[test]
     fn given_multiple_partitions_calculate_next_partition_id_should_return_next_partition_id_using_round_robin(
     ) {
@@ -334,4 +581,26 @@ mod tests {
 
         Topic::create(stream_id, id, name, partitions_count, config, storage, None).unwrap()
     }
+
+    async fn init_segments(
+        topic: &Topic,
+        segments_count: u64,
+        messages_per_segment: u64,
+        segment_size_bytes: u32,
+    ) {
+        for partition in topic.get_partitions() {
+            let mut partition = partition.write().await;
+            for start_offset in 1..segments_count {
+                partition
+                    .add_persisted_segment(start_offset * messages_per_segment)
+                    .await
+                    .unwrap();
+            }
+
+            for segment in partition.get_segments_mut() {
+                segment.current_offset = segment.start_offset + messages_per_segment - 1;
+                segment.current_size_bytes = segment_size_bytes;
+            }
+        }
+    }
 }
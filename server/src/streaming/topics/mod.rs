@@ -1,6 +1,8 @@
+pub mod compaction;
 pub mod consumer_group;
 pub mod consumer_groups;
 pub mod consumer_offsets;
+pub mod encryption;
 pub mod messages;
 pub mod partitions;
 pub mod persistence;
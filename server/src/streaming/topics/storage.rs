@@ -6,6 +6,9 @@ use crate::streaming::topics::topic::Topic;
 use async_trait::async_trait;
 use futures::future::join_all;
 use iggy::error::Error;
+use iggy::models::assignment_strategy::AssignmentStrategy;
+use iggy::models::cleanup_policy::CleanupPolicy;
+use iggy::models::header::HeaderKey;
 use serde::{Deserialize, Serialize};
 use sled::Db;
 use std::path::Path;
@@ -85,7 +88,12 @@ impl TopicStorage for FileTopicStorage {
             .persister
             .overwrite(
                 &topic.get_consumer_group_path(consumer_group.id),
-                &consumer_group.id.to_le_bytes(),
+                &[
+                    consumer_group.id.to_le_bytes().as_slice(),
+                    &[consumer_group.assignment_strategy.as_code()],
+                    consumer_group.session_timeout.to_le_bytes().as_slice(),
+                ]
+                .concat(),
             )
             .await
             .is_err()
@@ -134,12 +142,33 @@ impl TopicStorage for FileTopicStorage {
             }
 
             let consumer_group_id = consumer_group_id.unwrap();
+            let bytes = match fs::read(dir_entry.path()).await {
+                Ok(bytes) => bytes,
+                Err(_) => {
+                    error!(
+                        "Cannot read consumer group with ID: {} file.",
+                        consumer_group_id
+                    );
+                    continue;
+                }
+            };
+            // The assignment strategy and session timeout are missing for the consumer groups saved by the previous versions.
+            let assignment_strategy = match bytes.get(4) {
+                Some(code) => AssignmentStrategy::from_code(*code)?,
+                None => AssignmentStrategy::default(),
+            };
+            let session_timeout = match bytes.get(5..9) {
+                Some(session_timeout) => u32::from_le_bytes(session_timeout.try_into()?),
+                None => 0,
+            };
             topic.consumer_groups.insert(
                 consumer_group_id,
                 RwLock::new(ConsumerGroup::new(
                     topic.topic_id,
                     consumer_group_id,
                     topic.partitions.len() as u32,
+                    assignment_strategy,
+                    session_timeout,
                 )),
             );
         }
@@ -179,6 +208,16 @@ struct TopicData {
     name: String,
     created_at: u64,
     message_expiry: Option<u32>,
+    #[serde(default)]
+    cleanup_policy: CleanupPolicy,
+    #[serde(default)]
+    compaction_key: Option<HeaderKey>,
+    #[serde(default)]
+    max_topic_size_bytes: Option<u64>,
+    #[serde(default)]
+    max_messages: Option<u64>,
+    #[serde(default)]
+    dead_letter_topic_id: Option<u32>,
 }
 
 #[async_trait]
@@ -213,6 +252,11 @@ impl Storage<Topic> for FileTopicStorage {
         topic.name = topic_data.name;
         topic.created_at = topic_data.created_at;
         topic.message_expiry = topic_data.message_expiry;
+        topic.cleanup_policy = topic_data.cleanup_policy;
+        topic.compaction_key = topic_data.compaction_key;
+        topic.max_topic_size_bytes = topic_data.max_topic_size_bytes;
+        topic.max_messages = topic_data.max_messages;
+        topic.dead_letter_topic_id = topic_data.dead_letter_topic_id;
 
         let dir_entries = fs::read_dir(&topic.partitions_path).await;
         if dir_entries.is_err() {
@@ -275,8 +319,8 @@ impl Storage<Topic> for FileTopicStorage {
         topic.load_messages_to_cache().await?;
 
         info!(
-            "Loaded topic: '{}' with ID: {} for stream with ID: {} from disk. Message expiry: {:?}",
-            &topic.name, &topic.topic_id, topic.stream_id, topic.message_expiry
+            "Loaded topic: '{}' with ID: {} for stream with ID: {} from disk. Message expiry: {:?}, cleanup policy: {}",
+            &topic.name, &topic.topic_id, topic.stream_id, topic.message_expiry, topic.cleanup_policy
         );
 
         Ok(())
@@ -313,6 +357,11 @@ impl Storage<Topic> for FileTopicStorage {
             name: topic.name.clone(),
             created_at: topic.created_at,
             message_expiry: topic.message_expiry,
+            cleanup_policy: topic.cleanup_policy,
+            compaction_key: topic.compaction_key.clone(),
+            max_topic_size_bytes: topic.max_topic_size_bytes,
+            max_messages: topic.max_messages,
+            dead_letter_topic_id: topic.dead_letter_topic_id,
         }) {
             Ok(data) => {
                 if let Err(err) = self.db.insert(&key, data) {
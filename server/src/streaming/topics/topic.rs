@@ -3,6 +3,8 @@ use crate::streaming::partitions::partition::Partition;
 use crate::streaming::storage::SystemStorage;
 use crate::streaming::topics::consumer_group::ConsumerGroup;
 use iggy::error::Error;
+use iggy::models::cleanup_policy::CleanupPolicy;
+use iggy::models::header::HeaderKey;
 use iggy::utils::timestamp::TimeStamp;
 use std::collections::HashMap;
 use std::sync::atomic::AtomicU32;
@@ -22,9 +24,23 @@ pub struct Topic {
     pub(crate) consumer_groups: HashMap<u32, RwLock<ConsumerGroup>>,
     pub(crate) current_partition_id: AtomicU32,
     pub message_expiry: Option<u32>,
+    pub cleanup_policy: CleanupPolicy,
+    pub compaction_key: Option<HeaderKey>,
+    pub max_topic_size_bytes: Option<u64>,
+    pub max_messages: Option<u64>,
+    pub dead_letter_topic_id: Option<u32>,
     pub created_at: u64,
 }
 
+#[derive(Debug, Default)]
+pub struct TopicSettings {
+    pub cleanup_policy: CleanupPolicy,
+    pub compaction_key: Option<String>,
+    pub max_topic_size_bytes: Option<u64>,
+    pub max_messages: Option<u64>,
+    pub dead_letter_topic_id: Option<u32>,
+}
+
 impl Topic {
     pub fn empty(
         stream_id: u32,
@@ -66,6 +82,11 @@ impl Topic {
                     expiry => Some(expiry),
                 },
             },
+            cleanup_policy: CleanupPolicy::default(),
+            compaction_key: None,
+            max_topic_size_bytes: None,
+            max_messages: None,
+            dead_letter_topic_id: None,
             config,
             created_at: TimeStamp::now().to_micros(),
         };
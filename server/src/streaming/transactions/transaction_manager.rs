@@ -0,0 +1,144 @@
+use crate::streaming::polling_consumer::PollingConsumer;
+use crate::streaming::topics::consumer_group::ConsumerGroupGeneration;
+use iggy::error::Error;
+use std::collections::{HashMap, HashSet};
+
+#[derive(Debug, Default)]
+pub struct TransactionManager {
+    transactions: HashMap<u64, Transaction>,
+}
+
+#[derive(Debug)]
+pub struct Transaction {
+    pub transaction_id: u64,
+    pub user_id: u32,
+    pub client_id: Option<u32>,
+    pub started_at: u64,
+    pub partitions: HashSet<TransactionPartition>,
+    pub consumer_offsets: Vec<TransactionConsumerOffset>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TransactionPartition {
+    pub stream_id: u32,
+    pub topic_id: u32,
+    pub partition_id: u32,
+}
+
+#[derive(Debug)]
+pub struct TransactionConsumerOffset {
+    pub stream_id: u32,
+    pub topic_id: u32,
+    pub consumer: PollingConsumer,
+    pub offset: u64,
+    pub generation: Option<ConsumerGroupGeneration>,
+}
+
+impl TransactionManager {
+    pub fn begin_transaction(
+        &mut self,
+        transaction_id: u64,
+        user_id: u32,
+        client_id: Option<u32>,
+        started_at: u64,
+    ) {
+        self.transactions.insert(
+            transaction_id,
+            Transaction {
+                transaction_id,
+                user_id,
+                client_id,
+                started_at,
+                partitions: HashSet::new(),
+                consumer_offsets: Vec::new(),
+            },
+        );
+    }
+
+    // The transaction is visible only to the user who has started it.
+    pub fn verify_transaction(&self, transaction_id: u64, user_id: u32) -> Result<(), Error> {
+        match self.transactions.get(&transaction_id) {
+            Some(transaction) if transaction.user_id == user_id => Ok(()),
+            _ => Err(Error::TransactionNotFound(transaction_id)),
+        }
+    }
+
+    pub fn get_transaction_mut(&mut self, transaction_id: u64) -> Result<&mut Transaction, Error> {
+        match self.transactions.get_mut(&transaction_id) {
+            Some(transaction) => Ok(transaction),
+            None => Err(Error::TransactionNotFound(transaction_id)),
+        }
+    }
+
+    pub fn add_consumer_offset(
+        &mut self,
+        transaction_id: u64,
+        user_id: u32,
+        consumer_offset: TransactionConsumerOffset,
+    ) -> Result<(), Error> {
+        self.verify_transaction(transaction_id, user_id)?;
+        self.get_transaction_mut(transaction_id)?
+            .consumer_offsets
+            .push(consumer_offset);
+        Ok(())
+    }
+
+    pub fn get_expired_transactions(&self, now: u64, timeout: u64) -> Vec<(u64, u32)> {
+        let timeout = timeout.saturating_mul(1_000_000);
+        self.transactions
+            .values()
+            .filter(|transaction| transaction.started_at.saturating_add(timeout) <= now)
+            .map(|transaction| (transaction.transaction_id, transaction.user_id))
+            .collect()
+    }
+
+    pub fn get_client_transactions(&self, client_id: u32) -> Vec<(u64, u32)> {
+        self.transactions
+            .values()
+            .filter(|transaction| transaction.client_id == Some(client_id))
+            .map(|transaction| (transaction.transaction_id, transaction.user_id))
+            .collect()
+    }
+
+    pub fn remove_transaction(
+        &mut self,
+        transaction_id: u64,
+        user_id: u32,
+    ) -> Result<Transaction, Error> {
+        self.verify_transaction(transaction_id, user_id)?;
+        Ok(self.transactions.remove(&transaction_id).unwrap())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn transaction_should_be_available_only_for_its_user() {
+        let mut transaction_manager = TransactionManager::default();
+        transaction_manager.begin_transaction(1, 10, None, 0);
+
+        assert!(transaction_manager.verify_transaction(1, 10).is_ok());
+        assert!(transaction_manager.verify_transaction(1, 20).is_err());
+        assert!(transaction_manager.remove_transaction(1, 20).is_err());
+        assert!(transaction_manager.remove_transaction(1, 10).is_ok());
+        assert!(transaction_manager.verify_transaction(1, 10).is_err());
+    }
+
+    #[test]
+    fn only_transactions_started_before_the_timeout_should_be_expired() {
+        let mut transaction_manager = TransactionManager::default();
+        transaction_manager.begin_transaction(1, 10, Some(100), 1_000_000);
+        transaction_manager.begin_transaction(2, 10, Some(200), 5_000_000);
+
+        assert_eq!(
+            transaction_manager.get_expired_transactions(6_000_000, 5),
+            vec![(1, 10)]
+        );
+        assert_eq!(
+            transaction_manager.get_client_transactions(200),
+            vec![(2, 10)]
+        );
+    }
+}
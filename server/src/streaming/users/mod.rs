@@ -1,5 +1,7 @@
+pub mod password_policy;
 pub mod permissioner;
 pub mod permissioner_rules;
+pub mod scram;
 pub mod storage;
 pub mod user;
 pub mod user_context;
@@ -0,0 +1,94 @@
+use crate::configs::system::PasswordPolicyConfig;
+use iggy::error::Error;
+
+pub fn validate(
+    policy: &PasswordPolicyConfig,
+    username: &str,
+    password: &str,
+) -> Result<(), Error> {
+    let length = password.chars().count() as u32;
+    if length < policy.min_length || length > policy.max_length {
+        return Err(Error::PasswordPolicyViolation(format!(
+            "password length must be between {} and {} characters",
+            policy.min_length, policy.max_length
+        )));
+    }
+
+    if policy.require_uppercase && !password.chars().any(|c| c.is_uppercase()) {
+        return Err(Error::PasswordPolicyViolation(
+            "password must contain an uppercase letter".to_string(),
+        ));
+    }
+
+    if policy.require_lowercase && !password.chars().any(|c| c.is_lowercase()) {
+        return Err(Error::PasswordPolicyViolation(
+            "password must contain a lowercase letter".to_string(),
+        ));
+    }
+
+    if policy.require_digit && !password.chars().any(|c| c.is_ascii_digit()) {
+        return Err(Error::PasswordPolicyViolation(
+            "password must contain a digit".to_string(),
+        ));
+    }
+
+    if policy.require_special_character && password.chars().all(|c| c.is_alphanumeric()) {
+        return Err(Error::PasswordPolicyViolation(
+            "password must contain a special character".to_string(),
+        ));
+    }
+
+    if policy.disallow_username && password.eq_ignore_ascii_case(username) {
+        return Err(Error::PasswordPolicyViolation(
+            "password must not be equal to the username".to_string(),
+        ));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn strict_policy() -> PasswordPolicyConfig {
+        PasswordPolicyConfig {
+            min_length: 8,
+            max_length: 20,
+            require_uppercase: true,
+            require_lowercase: true,
+            require_digit: true,
+            require_special_character: true,
+            disallow_username: true,
+        }
+    }
+
+    #[test]
+    fn given_password_matching_policy_validation_should_succeed() {
+        assert!(validate(&strict_policy(), "user", "Secret-123").is_ok());
+    }
+
+    #[test]
+    fn given_password_not_matching_policy_validation_should_fail() {
+        let policy = strict_policy();
+        let invalid_passwords = [
+            "Se-1",
+            "Secret-123456789012345",
+            "secret-123",
+            "SECRET-123",
+            "Secret-abc",
+            "Secret123",
+        ];
+        for password in invalid_passwords {
+            let result = validate(&policy, "user", password);
+            assert!(matches!(result, Err(Error::PasswordPolicyViolation(_))));
+        }
+    }
+
+    #[test]
+    fn given_password_equal_to_username_validation_should_fail() {
+        let policy = PasswordPolicyConfig::default();
+        let result = validate(&policy, "secret", "Secret");
+        assert!(matches!(result, Err(Error::PasswordPolicyViolation(_))));
+    }
+}
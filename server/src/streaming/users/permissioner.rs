@@ -1,11 +1,15 @@
+use crate::streaming::roles::role::Role;
 use crate::streaming::users::user::User;
-use iggy::models::permissions::{GlobalPermissions, StreamPermissions};
+use iggy::models::permissions::{GlobalPermissions, Permissions, StreamPermissions};
 use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet};
 
 #[derive(Debug, Serialize, Deserialize, Default)]
 pub struct Permissioner {
     pub(super) enabled: bool,
+    pub(super) users_own_permissions: HashMap<u32, Permissions>,
+    pub(super) roles_permissions: HashMap<u32, Permissions>,
+    pub(super) users_roles: HashMap<u32, HashSet<u32>>,
     pub(super) users_permissions: HashMap<u32, GlobalPermissions>,
     pub(super) users_streams_permissions: HashMap<(u32, u32), StreamPermissions>,
     pub(super) users_that_can_poll_messages_from_all_streams: HashSet<u32>,
@@ -19,29 +23,105 @@ impl Permissioner {
         self.enabled = true;
     }
 
-    pub fn init(&mut self, users: Vec<User>) {
+    pub fn init(&mut self, users: Vec<User>, roles: Vec<Role>) {
+        for role in roles {
+            for member in &role.members {
+                self.users_roles.entry(*member).or_default().insert(role.id);
+            }
+            self.roles_permissions.insert(role.id, role.permissions);
+        }
+
         for user in users {
             self.init_permissions_for_user(user);
         }
     }
 
     pub fn init_permissions_for_user(&mut self, user: User) {
-        if user.permissions.is_none() {
+        match user.permissions {
+            Some(permissions) => self.users_own_permissions.insert(user.id, permissions),
+            None => self.users_own_permissions.remove(&user.id),
+        };
+        self.index_permissions_for_user(user.id);
+    }
+
+    pub fn update_permissions_for_user(&mut self, user: User) {
+        self.init_permissions_for_user(user);
+    }
+
+    pub fn delete_permissions_for_user(&mut self, user_id: u32) {
+        self.users_own_permissions.remove(&user_id);
+        self.users_roles.remove(&user_id);
+        self.clear_permissions_for_user(user_id);
+    }
+
+    pub fn update_permissions_for_role(&mut self, role: &Role) {
+        let mut affected_users = role.members.iter().copied().collect::<HashSet<u32>>();
+        for (user_id, roles) in self.users_roles.iter_mut() {
+            if roles.remove(&role.id) {
+                affected_users.insert(*user_id);
+            }
+        }
+        for member in &role.members {
+            self.users_roles.entry(*member).or_default().insert(role.id);
+        }
+        self.users_roles.retain(|_, roles| !roles.is_empty());
+        self.roles_permissions
+            .insert(role.id, role.permissions.clone());
+        for user_id in affected_users {
+            self.index_permissions_for_user(user_id);
+        }
+    }
+
+    pub fn delete_permissions_for_role(&mut self, role_id: u32) {
+        self.roles_permissions.remove(&role_id);
+        let mut affected_users = Vec::new();
+        for (user_id, roles) in self.users_roles.iter_mut() {
+            if roles.remove(&role_id) {
+                affected_users.push(*user_id);
+            }
+        }
+        self.users_roles.retain(|_, roles| !roles.is_empty());
+        for user_id in affected_users {
+            self.index_permissions_for_user(user_id);
+        }
+    }
+
+    // The effective permissions are the union of the user's own permissions and all of the assigned roles.
+    fn get_effective_permissions(&self, user_id: u32) -> Option<Permissions> {
+        let mut effective_permissions = self.users_own_permissions.get(&user_id).cloned();
+        if let Some(roles) = self.users_roles.get(&user_id) {
+            for role_id in roles {
+                if let Some(role_permissions) = self.roles_permissions.get(role_id) {
+                    effective_permissions = Some(match effective_permissions {
+                        Some(permissions) => permissions.union(role_permissions),
+                        None => role_permissions.clone(),
+                    });
+                }
+            }
+        }
+
+        effective_permissions
+    }
+
+    fn index_permissions_for_user(&mut self, user_id: u32) {
+        self.clear_permissions_for_user(user_id);
+        let permissions = self.get_effective_permissions(user_id);
+        if permissions.is_none() {
             return;
         }
 
-        let permissions = user.permissions.unwrap();
+        let permissions = permissions.unwrap();
         if permissions.global.poll_messages {
             self.users_that_can_poll_messages_from_all_streams
-                .insert(user.id);
+                .insert(user_id);
         }
 
         if permissions.global.send_messages {
             self.users_that_can_send_messages_to_all_streams
-                .insert(user.id);
+                .insert(user_id);
         }
 
-        self.users_permissions.insert(user.id, permissions.global);
+        self.users_permissions.insert(user_id, permissions.global);
         if permissions.streams.is_none() {
             return;
         }
@@ -50,25 +130,20 @@ impl Permissioner {
         for (stream_id, stream) in streams {
             if stream.poll_messages {
                 self.users_that_can_poll_messages_from_specific_streams
-                    .insert((user.id, stream_id));
+                    .insert((user_id, stream_id));
             }
 
             if stream.send_messages {
                 self.users_that_can_send_messages_to_specific_streams
-                    .insert((user.id, stream_id));
+                    .insert((user_id, stream_id));
             }
 
             self.users_streams_permissions
-                .insert((user.id, stream_id), stream);
+                .insert((user_id, stream_id), stream);
         }
     }
 
-    pub fn update_permissions_for_user(&mut self, user: User) {
-        self.delete_permissions_for_user(user.id);
-        self.init_permissions_for_user(user);
-    }
-
-    pub fn delete_permissions_for_user(&mut self, user_id: u32) {
+    fn clear_permissions_for_user(&mut self, user_id: u32) {
         self.users_permissions.remove(&user_id);
         self.users_that_can_poll_messages_from_all_streams
             .remove(&user_id);
@@ -82,3 +157,61 @@ impl Permissioner {
             .retain(|(id, _)| *id != user_id);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn effective_permissions_should_be_the_union_of_user_and_role_permissions() {
+        let mut permissioner = Permissioner::default();
+        permissioner.enable();
+        let user = User::new(2, "user", "secret", None);
+        let mut role = Role::new(
+            1,
+            "readers",
+            Permissions {
+                global: GlobalPermissions {
+                    read_users: true,
+                    poll_messages: true,
+                    ..Default::default()
+                },
+                streams: None,
+            },
+        );
+        permissioner.init(vec![user], vec![]);
+        assert!(permissioner.get_users(2).is_err());
+
+        role.add_member(2);
+        permissioner.update_permissions_for_role(&role);
+        assert!(permissioner.get_users(2).is_ok());
+        assert!(permissioner.create_user(2).is_err());
+        assert!(permissioner
+            .users_that_can_poll_messages_from_all_streams
+            .contains(&2));
+
+        let user = User::new(
+            2,
+            "user",
+            "secret",
+            Some(Permissions {
+                global: GlobalPermissions {
+                    manage_users: true,
+                    ..Default::default()
+                },
+                streams: None,
+            }),
+        );
+        permissioner.update_permissions_for_user(user);
+        assert!(permissioner.get_users(2).is_ok());
+        assert!(permissioner.create_user(2).is_ok());
+
+        role.remove_member(2);
+        permissioner.update_permissions_for_role(&role);
+        assert!(permissioner.get_users(2).is_err());
+        assert!(permissioner.create_user(2).is_ok());
+        assert!(!permissioner
+            .users_that_can_poll_messages_from_all_streams
+            .contains(&2));
+    }
+}
@@ -1,6 +1,7 @@
 mod consumer_groups;
 mod messages;
 mod partitions;
+mod roles;
 mod streams;
 mod system;
 mod topics;
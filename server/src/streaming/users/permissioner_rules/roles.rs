@@ -0,0 +1,32 @@
+use crate::streaming::users::permissioner::Permissioner;
+use iggy::error::Error;
+
+impl Permissioner {
+    pub fn get_role(&self, user_id: u32) -> Result<(), Error> {
+        self.read_users(user_id)
+    }
+
+    pub fn get_roles(&self, user_id: u32) -> Result<(), Error> {
+        self.read_users(user_id)
+    }
+
+    pub fn create_role(&self, user_id: u32) -> Result<(), Error> {
+        self.manager_users(user_id)
+    }
+
+    pub fn delete_role(&self, user_id: u32) -> Result<(), Error> {
+        self.manager_users(user_id)
+    }
+
+    pub fn update_role(&self, user_id: u32) -> Result<(), Error> {
+        self.manager_users(user_id)
+    }
+
+    pub fn add_role_member(&self, user_id: u32) -> Result<(), Error> {
+        self.manager_users(user_id)
+    }
+
+    pub fn remove_role_member(&self, user_id: u32) -> Result<(), Error> {
+        self.manager_users(user_id)
+    }
+}
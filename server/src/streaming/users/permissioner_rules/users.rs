@@ -18,6 +18,10 @@ impl Permissioner {
         self.manager_users(user_id)
     }
 
+    pub fn unlock_user(&self, user_id: u32) -> Result<(), Error> {
+        self.manager_users(user_id)
+    }
+
     pub fn update_user(&self, user_id: u32) -> Result<(), Error> {
         self.manager_users(user_id)
     }
@@ -30,7 +34,19 @@ impl Permissioner {
         self.manager_users(user_id)
     }
 
-    fn manager_users(&self, user_id: u32) -> Result<(), Error> {
+    pub fn update_quotas(&self, user_id: u32) -> Result<(), Error> {
+        self.manager_users(user_id)
+    }
+
+    pub fn get_sessions(&self, user_id: u32) -> Result<(), Error> {
+        self.read_users(user_id)
+    }
+
+    pub fn revoke_session(&self, user_id: u32) -> Result<(), Error> {
+        self.manager_users(user_id)
+    }
+
+    pub(super) fn manager_users(&self, user_id: u32) -> Result<(), Error> {
         if !self.enabled {
             return Ok(());
         }
@@ -44,7 +60,7 @@ impl Permissioner {
         Err(Error::Unauthorized)
     }
 
-    fn read_users(&self, user_id: u32) -> Result<(), Error> {
+    pub(super) fn read_users(&self, user_id: u32) -> Result<(), Error> {
         if !self.enabled {
             return Ok(());
         }
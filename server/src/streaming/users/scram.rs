@@ -0,0 +1,32 @@
+use iggy::utils::scram;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScramCredentials {
+    pub salt: Vec<u8>,
+    pub iterations: u32,
+    pub stored_key: Vec<u8>,
+    pub server_key: Vec<u8>,
+}
+
+// The state of the handshake kept on the connection between the client-first and client-final messages.
+#[derive(Debug)]
+pub struct ScramHandshake {
+    pub username: String,
+    pub client_nonce: String,
+    pub nonce: String,
+}
+
+impl ScramCredentials {
+    pub fn new(password: &str) -> Self {
+        let salt = scram::generate_salt();
+        let iterations = scram::SCRAM_ITERATIONS;
+        let salted_password = scram::salt_password(password, &salt, iterations);
+        Self {
+            stored_key: scram::stored_key(&scram::client_key(&salted_password)),
+            server_key: scram::server_key(&salted_password),
+            salt,
+            iterations,
+        }
+    }
+}
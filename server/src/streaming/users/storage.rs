@@ -98,6 +98,12 @@ impl Storage<User> for FileUserStorage {
         user.password = user_data.password;
         user.created_at = user_data.created_at;
         user.permissions = user_data.permissions;
+        user.quotas = user_data.quotas;
+        user.scram_credentials = user_data.scram_credentials;
+        user.failed_login_attempts = user_data.failed_login_attempts;
+        user.first_failed_login_at = user_data.first_failed_login_at;
+        user.locked_at = user_data.locked_at;
+        user.token_version = user_data.token_version;
         Ok(())
     }
 
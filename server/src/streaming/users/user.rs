@@ -1,5 +1,8 @@
+use crate::configs::system::LockoutConfig;
+use crate::streaming::users::scram::ScramCredentials;
 use crate::streaming::utils::crypto;
 use iggy::models::permissions::Permissions;
+use iggy::models::quotas::Quotas;
 use iggy::models::user_status::UserStatus;
 use iggy::utils::timestamp::TimeStamp;
 use serde::{Deserialize, Serialize};
@@ -16,6 +19,18 @@ pub struct User {
     pub password: String,
     pub created_at: u64,
     pub permissions: Option<Permissions>,
+    #[serde(default)]
+    pub quotas: Option<Quotas>,
+    #[serde(default)]
+    pub scram_credentials: Option<ScramCredentials>,
+    #[serde(default)]
+    pub failed_login_attempts: u32,
+    #[serde(default)]
+    pub first_failed_login_at: Option<u64>,
+    #[serde(default)]
+    pub locked_at: Option<u64>,
+    #[serde(default)]
+    pub token_version: u32,
 }
 
 impl Default for User {
@@ -27,11 +42,22 @@ impl Default for User {
             password: "secret".to_string(),
             created_at: TimeStamp::now().to_micros(),
             permissions: None,
+            quotas: None,
+            scram_credentials: None,
+            failed_login_attempts: 0,
+            first_failed_login_at: None,
+            locked_at: None,
+            token_version: 0,
         }
     }
 }
 
 impl User {
+    // The access tokens issued before are no longer valid, e.g. after the password change.
+    pub fn revoke_tokens(&mut self) {
+        self.token_version = self.token_version.wrapping_add(1);
+    }
+
     pub fn empty(id: u32) -> Self {
         Self {
             id,
@@ -47,6 +73,12 @@ impl User {
             created_at: TimeStamp::now().to_micros(),
             status: UserStatus::Active,
             permissions,
+            quotas: None,
+            scram_credentials: Some(ScramCredentials::new(password)),
+            failed_login_attempts: 0,
+            first_failed_login_at: None,
+            locked_at: None,
+            token_version: 0,
         }
     }
 
@@ -66,6 +98,59 @@ impl User {
     pub fn is_active(&self) -> bool {
         self.status == UserStatus::Active
     }
+
+    // The root user is never locked, as there would be nobody left to unlock it.
+    pub fn is_locked(&self, config: &LockoutConfig, now: u64) -> bool {
+        if !config.enabled || self.is_root() {
+            return false;
+        }
+
+        match self.locked_at {
+            Some(locked_at) => {
+                config.duration == 0
+                    || now < locked_at.saturating_add(config.duration.saturating_mul(1_000_000))
+            }
+            None => false,
+        }
+    }
+
+    pub fn register_failed_login(&mut self, config: &LockoutConfig, now: u64) {
+        // The lock is cleared only once it has expired, a concurrent failed login must not extend or undo it.
+        if self.is_locked(config, now) {
+            return;
+        }
+
+        let window_expired = match self.first_failed_login_at {
+            Some(first_failed_login_at) => {
+                now >= first_failed_login_at.saturating_add(config.window.saturating_mul(1_000_000))
+            }
+            None => true,
+        };
+        if window_expired || self.locked_at.is_some() {
+            self.failed_login_attempts = 0;
+            self.first_failed_login_at = Some(now);
+            self.locked_at = None;
+        }
+
+        self.failed_login_attempts = self.failed_login_attempts.saturating_add(1);
+        if config.enabled
+            && !self.is_root()
+            && self.failed_login_attempts >= config.max_failed_attempts
+        {
+            self.locked_at = Some(now);
+        }
+    }
+
+    pub fn reset_failed_logins(&mut self) -> bool {
+        if self.failed_login_attempts == 0 && self.locked_at.is_none() {
+            return false;
+        }
+
+        self.failed_login_attempts = 0;
+        self.first_failed_login_at = None;
+        self.locked_at = None;
+        true
+    }
 }
 
 #[cfg(test)]
@@ -79,7 +164,90 @@ mod tests {
         assert_eq!(user.username, ROOT_USERNAME);
         assert_ne!(user.password, ROOT_PASSWORD);
         assert!(crypto::verify_password(ROOT_PASSWORD, &user.password));
+        assert!(user.scram_credentials.is_some());
         assert_eq!(user.status, UserStatus::Active);
         assert!(user.created_at > 0);
     }
+
+    #[test]
+    fn given_max_failed_logins_within_window_user_should_be_locked_until_duration_passes() {
+        let config = LockoutConfig {
+            enabled: true,
+            max_failed_attempts: 3,
+            window: 10,
+            duration: 60,
+        };
+        let mut user = User::empty(2);
+        let now = 1_000_000_000;
+        user.register_failed_login(&config, now);
+        user.register_failed_login(&config, now + 1_000_000);
+        assert!(!user.is_locked(&config, now + 1_000_000));
+        user.register_failed_login(&config, now + 2_000_000);
+        assert_eq!(user.failed_login_attempts, 3);
+        assert!(user.is_locked(&config, now + 2_000_000));
+        assert!(!user.is_locked(&config, now + 62_000_000));
+        assert!(user.reset_failed_logins());
+        assert!(!user.is_locked(&config, now + 2_000_000));
+        assert_eq!(user.failed_login_attempts, 0);
+    }
+
+    #[test]
+    fn given_max_failed_logins_root_user_should_not_be_locked() {
+        let config = LockoutConfig {
+            enabled: true,
+            max_failed_attempts: 1,
+            window: 10,
+            duration: u64::MAX,
+        };
+        let mut root = User::root();
+        let now = 1_000_000_000;
+        root.register_failed_login(&config, now);
+        assert!(root.locked_at.is_none());
+        assert!(!root.is_locked(&config, now));
+
+        let mut user = User::empty(2);
+        user.register_failed_login(&config, now);
+        assert!(user.is_locked(&config, u64::MAX - 1));
+    }
+
+    #[test]
+    fn given_failed_logins_outside_window_user_should_not_be_locked() {
+        let config = LockoutConfig {
+            enabled: true,
+            max_failed_attempts: 2,
+            window: 10,
+            duration: 0,
+        };
+        let mut user = User::empty(2);
+        let now = 1_000_000_000;
+        user.register_failed_login(&config, now);
+        user.register_failed_login(&config, now + 11_000_000);
+        assert_eq!(user.failed_login_attempts, 1);
+        assert!(!user.is_locked(&config, now + 11_000_000));
+    }
+
+    #[test]
+    fn given_locked_user_failed_login_should_clear_the_lock_only_when_expired() {
+        let config = LockoutConfig {
+            enabled: true,
+            max_failed_attempts: 2,
+            window: 10,
+            duration: 60,
+        };
+        let mut user = User::empty(2);
+        let now = 1_000_000_000;
+        user.register_failed_login(&config, now);
+        user.register_failed_login(&config, now + 1_000_000);
+        assert!(user.is_locked(&config, now + 1_000_000));
+
+        user.register_failed_login(&config, now + 2_000_000);
+        assert_eq!(user.locked_at, Some(now + 1_000_000));
+        assert_eq!(user.failed_login_attempts, 2);
+        assert!(user.is_locked(&config, now + 2_000_000));
+
+        user.register_failed_login(&config, now + 61_000_000);
+        assert!(user.locked_at.is_none());
+        assert_eq!(user.failed_login_attempts, 1);
+        assert!(!user.is_locked(&config, now + 61_000_000));
+    }
 }
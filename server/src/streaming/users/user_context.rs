@@ -1,3 +1,4 @@
+use crate::streaming::users::scram::ScramHandshake;
 use std::fmt::Display;
 
 // This might be extended with more fields in the future e.g. custom name, permissions etc.
@@ -6,6 +7,7 @@ pub struct UserContext {
     pub user_id: u32,
     pub client_id: u32,
     authentication_enabled: bool,
+    scram_handshake: Option<ScramHandshake>,
 }
 
 impl UserContext {
@@ -14,6 +16,7 @@ impl UserContext {
             user_id,
             client_id,
             authentication_enabled: true,
+            scram_handshake: None,
         }
     }
 
@@ -34,6 +37,14 @@ impl UserContext {
         self.user_id = 0;
     }
 
+    pub fn set_scram_handshake(&mut self, handshake: ScramHandshake) {
+        self.scram_handshake = Some(handshake);
+    }
+
+    pub fn take_scram_handshake(&mut self) -> Option<ScramHandshake> {
+        self.scram_handshake.take()
+    }
+
     pub fn is_authenticated(&self) -> bool {
         !self.authentication_enabled || self.user_id > 0
     }
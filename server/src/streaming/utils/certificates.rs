@@ -0,0 +1,99 @@
+use openssl::pkcs12::Pkcs12;
+use rustls::server::{AllowAnyAuthenticatedClient, ClientCertVerifier};
+use rustls::{Certificate, PrivateKey, RootCertStore};
+use std::error::Error;
+use std::fs::File;
+use std::io::BufReader;
+use std::sync::Arc;
+use x509_parser::parse_x509_certificate;
+
+pub fn load_certificates(cert_file: &str) -> Result<Vec<Certificate>, Box<dyn Error>> {
+    let mut cert_chain_reader = BufReader::new(File::open(cert_file)?);
+    let certs = rustls_pemfile::certs(&mut cert_chain_reader)?
+        .into_iter()
+        .map(Certificate)
+        .collect();
+    Ok(certs)
+}
+
+pub fn load_private_key(key_file: &str) -> Result<PrivateKey, Box<dyn Error>> {
+    let mut key_reader = BufReader::new(File::open(key_file)?);
+    let mut keys = rustls_pemfile::pkcs8_private_keys(&mut key_reader)?;
+    if keys.is_empty() {
+        let mut key_reader = BufReader::new(File::open(key_file)?);
+        keys = rustls_pemfile::rsa_private_keys(&mut key_reader)?;
+    }
+    if keys.is_empty() {
+        return Err(format!("No private key found in: {key_file}").into());
+    }
+
+    Ok(PrivateKey(keys.remove(0)))
+}
+
+// The rustls acceptor used for the client authentication is created from the same PKCS#12 file as the native TLS one.
+pub fn load_pkcs12(
+    file: &str,
+    password: &str,
+) -> Result<(Vec<Certificate>, PrivateKey), Box<dyn Error>> {
+    let pkcs12 = Pkcs12::from_der(&std::fs::read(file)?)?.parse2(password)?;
+    let (Some(certificate), Some(private_key)) = (pkcs12.cert, pkcs12.pkey) else {
+        return Err(format!("No certificate or private key found in: {file}").into());
+    };
+
+    let mut certificates = vec![Certificate(certificate.to_der()?)];
+    if let Some(chain) = pkcs12.ca {
+        for certificate in chain {
+            certificates.push(Certificate(certificate.to_der()?));
+        }
+    }
+
+    Ok((
+        certificates,
+        PrivateKey(private_key.private_key_to_pkcs8()?),
+    ))
+}
+
+pub fn create_client_verifier(
+    ca_file: &str,
+) -> Result<Arc<dyn ClientCertVerifier>, Box<dyn Error>> {
+    let mut roots = RootCertStore::empty();
+    for certificate in load_certificates(ca_file)? {
+        roots.add(&certificate)?;
+    }
+
+    Ok(AllowAnyAuthenticatedClient::new(roots).boxed())
+}
+
+// The common name of the client certificate subject is mapped to the username.
+pub fn get_common_name(certificate: &Certificate) -> Option<String> {
+    let (_, certificate) = parse_x509_certificate(&certificate.0).ok()?;
+    let common_name = certificate.subject().iter_common_name().next()?;
+    common_name
+        .as_str()
+        .ok()
+        .map(|common_name| common_name.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rcgen::{CertificateParams, DnType};
+
+    #[test]
+    fn common_name_should_be_read_from_certificate_subject() {
+        let mut params = CertificateParams::new(vec!["localhost".to_string()]);
+        params.distinguished_name.push(DnType::CommonName, "user1");
+        let certificate = rcgen::Certificate::from_params(params).unwrap();
+        let certificate = Certificate(certificate.serialize_der().unwrap());
+
+        assert_eq!(get_common_name(&certificate), Some("user1".to_string()));
+    }
+
+    #[test]
+    fn certificate_and_private_key_should_be_loaded_from_pkcs12_file() {
+        let (certificates, private_key) = load_pkcs12("../certs/iggy.pfx", "iggy123").unwrap();
+
+        assert!(!certificates.is_empty());
+        assert!(!private_key.0.is_empty());
+    }
+}
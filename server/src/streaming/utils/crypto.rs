@@ -1,4 +1,7 @@
 use bcrypt::{hash, verify};
+use ring::rand::{SecureRandom, SystemRandom};
+
+const SECRET_LENGTH: usize = 32;
 
 pub fn hash_password(password: &str) -> String {
     hash(password, 4).unwrap()
@@ -7,3 +10,11 @@ pub fn hash_password(password: &str) -> String {
 pub fn verify_password(password: &str, hash: &str) -> bool {
     verify(password, hash).unwrap_or(false)
 }
+
+pub fn generate_secret() -> Vec<u8> {
+    let mut secret = vec![0u8; SECRET_LENGTH];
+    SystemRandom::new()
+        .fill(&mut secret)
+        .expect("System random generator is available");
+    secret
+}
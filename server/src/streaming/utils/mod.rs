@@ -1,3 +1,4 @@
+pub mod certificates;
 pub mod crypto;
 pub mod file;
 pub mod hash;
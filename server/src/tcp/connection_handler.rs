@@ -1,5 +1,6 @@
 use crate::binary::command;
 use crate::binary::sender::Sender;
+use crate::binary::subscriptions::Subscriptions;
 use crate::server_error::ServerError;
 use crate::streaming::clients::client_manager::Transport;
 use crate::streaming::systems::system::System;
@@ -17,9 +18,10 @@ const INITIAL_BYTES_LENGTH: usize = 4;
 pub(crate) async fn handle_connection(
     address: &SocketAddr,
     sender: &mut dyn Sender,
+    certificate_username: Option<String>,
     system: Arc<RwLock<System>>,
 ) -> Result<(), ServerError> {
-    let client_id = system
+    let (client_id, revocation) = system
         .read()
         .await
         .add_client(address, Transport::Tcp)
@@ -31,12 +33,31 @@ pub(crate) async fn handle_connection(
         if !system.config.user.authentication_enabled {
             user_context.disable_authentication();
         }
+        if let Some(username) = certificate_username {
+            command::login_with_certificate(&username, &mut user_context, &system).await;
+        }
     }
 
     let mut initial_buffer = [0u8; INITIAL_BYTES_LENGTH];
+    let mut subscriptions = Subscriptions::default();
 
     loop {
-        let read_length = sender.read(&mut initial_buffer).await?;
+        // Reading a single byte is cancel safe, so the pending pushes can be sent in between the requests.
+        let read_result = tokio::select! {
+            read_result = sender.read(&mut initial_buffer[..1]) => Some(read_result),
+            _ = subscriptions.wait_for_new_messages() => None,
+            _ = revocation.notified() => {
+                info!("Session for client with ID: {client_id} has been revoked, closing the TCP connection.");
+                system.read().await.delete_client(address).await;
+                return Ok(());
+            }
+        };
+        if read_result.is_none() {
+            push_messages(sender, &mut subscriptions, &system, &user_context).await?;
+            continue;
+        }
+
+        let read_length = read_result.unwrap()? + sender.read(&mut initial_buffer[1..]).await?;
         if read_length != INITIAL_BYTES_LENGTH {
             error!(
                 "Unable to read the TCP request length, expected: {} bytes, received: {} bytes.",
@@ -55,7 +76,14 @@ pub(crate) async fn handle_connection(
             command,
             length
         );
-        let result = command::handle(&command, sender, &mut user_context, system.clone()).await;
+        let result = command::handle(
+            &command,
+            sender,
+            &mut user_context,
+            &mut subscriptions,
+            system.clone(),
+        )
+        .await;
         if result.is_err() {
             error!("Error when handling the TCP request: {:?}", result.err());
             continue;
@@ -64,6 +92,22 @@ pub(crate) async fn handle_connection(
     }
 }
 
+async fn push_messages(
+    sender: &mut dyn Sender,
+    subscriptions: &mut Subscriptions,
+    system: &Arc<RwLock<System>>,
+    user_context: &UserContext,
+) -> Result<(), ServerError> {
+    for payload in subscriptions
+        .poll_pending_messages(system, user_context)
+        .await
+    {
+        sender.send_push(&payload).await?;
+    }
+    trace!("Pushed the messages for TCP subscriptions.");
+    Ok(())
+}
+
 pub(crate) fn handle_error(error: ServerError) {
     match error {
         ServerError::IoError(error) => match error.kind() {
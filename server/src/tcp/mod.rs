@@ -1,6 +1,7 @@
 pub mod connection_handler;
 pub mod sender;
 pub mod tcp_listener;
+mod tcp_mutual_tls_sender;
 mod tcp_sender;
 pub mod tcp_server;
 pub mod tcp_tls_listener;
@@ -1,3 +1,4 @@
+use iggy::binary::subscriptions::PUSH_STATUS;
 use iggy::error::Error;
 use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
 use tracing::trace;
@@ -37,6 +38,13 @@ where
     send_response(stream, &error.as_code().to_le_bytes(), &[]).await
 }
 
+pub(crate) async fn send_push<T>(stream: &mut T, payload: &[u8]) -> Result<(), Error>
+where
+    T: AsyncRead + AsyncWrite + Unpin,
+{
+    send_response(stream, &PUSH_STATUS.to_le_bytes(), payload).await
+}
+
 pub(crate) async fn send_response<T>(
     stream: &mut T,
     status: &[u8],
@@ -23,7 +23,7 @@ pub fn start(address: &str, system: Arc<RwLock<System>>) {
                     let mut sender = TcpSender { stream };
                     tokio::spawn(async move {
                         if let Err(error) =
-                            handle_connection(&address, &mut sender, system.clone()).await
+                            handle_connection(&address, &mut sender, None, system.clone()).await
                         {
                             handle_error(error);
                             system.read().await.delete_client(&address).await;
@@ -29,4 +29,8 @@ impl Sender for TcpSender {
     async fn send_error_response(&mut self, error: Error) -> Result<(), Error> {
         sender::send_error_response(&mut self.stream, error).await
     }
+
+    async fn send_push(&mut self, payload: &[u8]) -> Result<(), Error> {
+        sender::send_push(&mut self.stream, payload).await
+    }
 }
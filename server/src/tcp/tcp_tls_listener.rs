@@ -1,6 +1,8 @@
 use crate::configs::tcp::TcpTlsConfig;
 use crate::streaming::systems::system::System;
+use crate::streaming::utils::certificates;
 use crate::tcp::connection_handler::{handle_connection, handle_error};
+use crate::tcp::tcp_mutual_tls_sender::TcpMutualTlsSender;
 use crate::tcp::tcp_tls_sender::TcpTlsSender;
 use std::sync::Arc;
 use tokio::net::TcpListener;
@@ -10,6 +12,11 @@ use tokio_native_tls::native_tls::Identity;
 use tracing::{error, info};
 
 pub(crate) fn start(address: &str, config: TcpTlsConfig, system: Arc<RwLock<System>>) {
+    if config.client_auth.enabled {
+        start_with_client_auth(address, config, system);
+        return;
+    }
+
     let address = address.to_string();
     tokio::spawn(async move {
         let certificate = std::fs::read(config.certificate.clone());
@@ -44,7 +51,52 @@ pub(crate) fn start(address: &str, config: TcpTlsConfig, system: Arc<RwLock<Syst
                     let mut sender = TcpTlsSender { stream };
                     tokio::spawn(async move {
                         if let Err(error) =
-                            handle_connection(&address, &mut sender, system.clone()).await
+                            handle_connection(&address, &mut sender, None, system.clone()).await
+                        {
+                            handle_error(error);
+                            system.read().await.delete_client(&address).await;
+                        }
+                    });
+                }
+                Err(error) => error!("Unable to accept TCP TLS socket, error: {}", error),
+            }
+        }
+    });
+}
+
+fn start_with_client_auth(address: &str, config: TcpTlsConfig, system: Arc<RwLock<System>>) {
+    let address = address.to_string();
+    let acceptor = create_acceptor(&config);
+    tokio::spawn(async move {
+        let listener = TcpListener::bind(address).await;
+        if listener.is_err() {
+            panic!("Unable to start TCP TLS server.");
+        }
+
+        let listener = listener.unwrap();
+        loop {
+            match listener.accept().await {
+                Ok((stream, address)) => {
+                    info!("Accepted new TCP TLS connection: {}", address);
+                    let acceptor = acceptor.clone();
+                    let system = system.clone();
+                    tokio::spawn(async move {
+                        let stream = match acceptor.accept(stream).await {
+                            Ok(stream) => stream,
+                            Err(error) => {
+                                error!("Unable to complete TLS handshake with client: {address}, error: {error}");
+                                return;
+                            }
+                        };
+                        let username = stream
+                            .get_ref()
+                            .1
+                            .peer_certificates()
+                            .and_then(|certificates| certificates.first())
+                            .and_then(certificates::get_common_name);
+                        let mut sender = TcpMutualTlsSender { stream };
+                        if let Err(error) =
+                            handle_connection(&address, &mut sender, username, system.clone()).await
                         {
                             handle_error(error);
                             system.read().await.delete_client(&address).await;
@@ -56,3 +108,26 @@ pub(crate) fn start(address: &str, config: TcpTlsConfig, system: Arc<RwLock<Syst
         }
     });
 }
+
+fn create_acceptor(config: &TcpTlsConfig) -> tokio_rustls::TlsAcceptor {
+    let identity = certificates::load_pkcs12(&config.certificate, &config.password);
+    if identity.is_err() {
+        panic!("Unable to create identity from certificate.");
+    }
+
+    let (certificates, private_key) = identity.unwrap();
+    let client_verifier = certificates::create_client_verifier(&config.client_auth.ca_file);
+    if client_verifier.is_err() {
+        panic!("Unable to create client certificate verifier.");
+    }
+
+    let server_config = rustls::ServerConfig::builder()
+        .with_safe_defaults()
+        .with_client_cert_verifier(client_verifier.unwrap())
+        .with_single_cert(certificates, private_key);
+    if server_config.is_err() {
+        panic!("Unable to create TLS server config.");
+    }
+
+    tokio_rustls::TlsAcceptor::from(Arc::new(server_config.unwrap()))
+}
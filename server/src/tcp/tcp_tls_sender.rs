@@ -30,4 +30,8 @@ impl Sender for TcpTlsSender {
     async fn send_error_response(&mut self, error: Error) -> Result<(), Error> {
         sender::send_error_response(&mut self.stream, error).await
     }
+
+    async fn send_push(&mut self, payload: &[u8]) -> Result<(), Error> {
+        sender::send_push(&mut self.stream, payload).await
+    }
 }
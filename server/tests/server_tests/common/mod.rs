@@ -6,6 +6,7 @@ pub mod tcp;
 use assert_cmd::prelude::CommandCargoExt;
 use async_trait::async_trait;
 use iggy::client::Client;
+use std::collections::HashMap;
 use std::fs;
 use std::process::{Child, Command};
 use std::thread::sleep;
@@ -19,6 +20,7 @@ pub trait ClientFactory: Sync + Send {
 
 pub struct TestServer {
     files_path: String,
+    envs: HashMap<String, String>,
     child_handle: Option<Child>,
 }
 
@@ -26,10 +28,17 @@ impl TestServer {
     pub fn new(files_path: String) -> Self {
         Self {
             files_path,
+            envs: HashMap::new(),
             child_handle: None,
         }
     }
 
+    pub fn with_envs(envs: HashMap<String, String>) -> Self {
+        let mut test_server = Self::default();
+        test_server.envs = envs;
+        test_server
+    }
+
     pub fn start(&mut self) {
         // Sleep before starting server - it takes some time for the OS to release the port
         sleep(Duration::from_secs(3));
@@ -38,13 +47,15 @@ impl TestServer {
         let files_path = self.files_path.clone();
         let mut command = Command::cargo_bin("iggy-server").unwrap();
         command.env("IGGY_SYSTEM_PATH", files_path.clone());
+        command.envs(&self.envs);
 
         // When running action from github CI, binary needs to be started via QEMU.
         if let Ok(runner) = std::env::var("QEMU_RUNNER") {
             let mut runner_command = Command::new(runner);
             runner_command
                 .arg(command.get_program().to_str().unwrap())
-                .env("IGGY_SYSTEM_PATH", files_path);
+                .env("IGGY_SYSTEM_PATH", files_path)
+                .envs(&self.envs);
             command = runner_command;
         };
         self.child_handle = Some(command.spawn().unwrap());
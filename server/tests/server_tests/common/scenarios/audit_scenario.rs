@@ -0,0 +1,86 @@
+use crate::server_tests::common::{ClientFactory, TestServer};
+use iggy::client::{MessageClient, UserClient};
+use iggy::clients::client::{IggyClient, IggyClientConfig};
+use iggy::consumer::Consumer;
+use iggy::identifier::Identifier;
+use iggy::messages::poll_messages::{IsolationLevel, PollMessages, PollingStrategy};
+use iggy::users::login_user::LoginUser;
+use server::streaming::audit::audit_log::{AuditRecord, SUCCESS_RESULT};
+use std::collections::HashMap;
+
+const AUDIT_STREAM_NAME: &str = "iggy-audit";
+const AUDIT_TOPIC_ID: u32 = 1;
+const AUDIT_PARTITION_ID: u32 = 1;
+const ROOT_USER_ID: u32 = 1;
+const ROOT_USERNAME: &str = "iggy";
+const ROOT_PASSWORD: &str = "iggy";
+
+pub async fn run(client_factory: &dyn ClientFactory) {
+    let mut test_server = TestServer::with_envs(HashMap::from([(
+        "IGGY_SYSTEM_AUDIT_STREAM".to_string(),
+        AUDIT_STREAM_NAME.to_string(),
+    )]));
+    test_server.start();
+    let client = client_factory.create_client().await;
+    let client = IggyClient::create(client, IggyClientConfig::default(), None, None, None);
+
+    // 1. Login with the invalid password
+    assert!(client
+        .login_user(&LoginUser {
+            username: ROOT_USERNAME.to_string(),
+            password: "invalid".to_string(),
+        })
+        .await
+        .is_err());
+
+    // 2. Login with the valid password
+    client
+        .login_user(&LoginUser {
+            username: ROOT_USERNAME.to_string(),
+            password: ROOT_PASSWORD.to_string(),
+        })
+        .await
+        .unwrap();
+
+    // 3. Validate that both attempts are published to the audit stream, the client may log in in multiple steps
+    let records = poll_audit_records(&client).await;
+    assert!(records
+        .iter()
+        .all(|record| record.command.contains("login") && record.timestamp > 0));
+    let failed_records = records
+        .iter()
+        .filter(|record| record.result != SUCCESS_RESULT)
+        .collect::<Vec<_>>();
+    assert_eq!(failed_records.len(), 1);
+    let last_record = records.last().unwrap();
+    assert_eq!(last_record.result, SUCCESS_RESULT);
+    assert_eq!(last_record.user_id, ROOT_USER_ID);
+    assert!(!last_record.client_address.is_empty());
+    assert!(!last_record.transport.is_empty());
+
+    test_server.stop();
+}
+
+async fn poll_audit_records(client: &IggyClient) -> Vec<AuditRecord> {
+    let poll_messages = PollMessages {
+        consumer: Consumer::default(),
+        stream_id: Identifier::named(AUDIT_STREAM_NAME).unwrap(),
+        topic_id: Identifier::numeric(AUDIT_TOPIC_ID).unwrap(),
+        partition_id: Some(AUDIT_PARTITION_ID),
+        strategy: PollingStrategy::offset(0),
+        count: 100,
+        auto_commit: false,
+        include_all_states: false,
+        max_wait_ms: None,
+        isolation_level: IsolationLevel::default(),
+        filters: vec![],
+    };
+    client
+        .poll_messages(&poll_messages)
+        .await
+        .unwrap()
+        .messages
+        .iter()
+        .map(|message| serde_json::from_slice(&message.payload).unwrap())
+        .collect()
+}
@@ -0,0 +1,136 @@
+use crate::server_tests::common::{ClientFactory, TestServer};
+use iggy::client::{ConsumerGroupClient, StreamClient, SystemClient, TopicClient};
+use iggy::clients::client::{IggyClient, IggyClientConfig};
+use iggy::consumer_groups::create_consumer_group::CreateConsumerGroup;
+use iggy::consumer_groups::get_consumer_group::GetConsumerGroup;
+use iggy::consumer_groups::heartbeat_consumer_group::HeartbeatConsumerGroup;
+use iggy::consumer_groups::join_consumer_group::JoinConsumerGroup;
+use iggy::error::Error;
+use iggy::identifier::Identifier;
+use iggy::models::assignment_strategy::AssignmentStrategy;
+use iggy::models::cleanup_policy::CleanupPolicy;
+use iggy::models::consumer_group::ConsumerGroupDetails;
+use iggy::streams::create_stream::CreateStream;
+use iggy::system::get_me::GetMe;
+use iggy::topics::create_topic::CreateTopic;
+use std::time::Duration;
+use tokio::time::sleep;
+
+const STREAM_ID: u32 = 1;
+const TOPIC_ID: u32 = 1;
+const STREAM_NAME: &str = "test-stream";
+const TOPIC_NAME: &str = "test-topic";
+const PARTITIONS_COUNT: u32 = 2;
+const CONSUMER_GROUP_ID: u32 = 1;
+const SESSION_TIMEOUT: u32 = 1;
+const HEARTBEATS_COUNT: u32 = 8;
+
+pub async fn run(client_factory: &dyn ClientFactory) {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let system_client = create_client(client_factory).await;
+    let client1 = create_client(client_factory).await;
+    let client2 = create_client(client_factory).await;
+
+    // 1. Create the stream
+    let create_stream = CreateStream {
+        stream_id: STREAM_ID,
+        name: STREAM_NAME.to_string(),
+        encryption: None,
+    };
+    system_client.create_stream(&create_stream).await.unwrap();
+
+    // 2. Create the topic
+    let create_topic = CreateTopic {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: TOPIC_ID,
+        partitions_count: PARTITIONS_COUNT,
+        name: TOPIC_NAME.to_string(),
+        message_expiry: None,
+        cleanup_policy: CleanupPolicy::Delete,
+        compaction_key: None,
+        max_topic_size_bytes: None,
+        max_messages: None,
+        dead_letter_topic_id: None,
+    };
+    system_client.create_topic(&create_topic).await.unwrap();
+
+    // 3. Create the consumer group with the session timeout
+    let create_group = CreateConsumerGroup {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+        consumer_group_id: CONSUMER_GROUP_ID,
+        assignment_strategy: AssignmentStrategy::default(),
+        session_timeout: SESSION_TIMEOUT,
+    };
+    system_client
+        .create_consumer_group(&create_group)
+        .await
+        .unwrap();
+
+    // 4. Join the consumer group by both clients
+    join_consumer_group(&client1).await;
+    join_consumer_group(&client2).await;
+
+    // 5. Validate that both members have the last heartbeat set and the partitions distributed
+    let consumer_group = get_consumer_group(&system_client).await;
+    assert_eq!(consumer_group.session_timeout, SESSION_TIMEOUT);
+    assert_eq!(consumer_group.members_count, 2);
+    for member in &consumer_group.members {
+        assert!(member.last_heartbeat_at > 0);
+        assert_eq!(member.partitions_count, 1);
+    }
+
+    // 6. Send the heartbeats only by client 1 until the session of client 2 expires
+    for _ in 0..HEARTBEATS_COUNT {
+        send_heartbeat(&client1).await.unwrap();
+        sleep(Duration::from_secs(1)).await;
+    }
+
+    // 7. Validate that client 2 was evicted and client 1 has all the partitions assigned
+    let client1_info = client1.get_me(&GetMe {}).await.unwrap();
+    let consumer_group = get_consumer_group(&system_client).await;
+    assert_eq!(consumer_group.members_count, 1);
+    let member = &consumer_group.members[0];
+    assert_eq!(member.id, client1_info.client_id);
+    assert_eq!(member.partitions_count, PARTITIONS_COUNT);
+
+    // 8. Validate that client 2 is no longer the member of the consumer group
+    assert!(send_heartbeat(&client2).await.is_err());
+    let client2_info = client2.get_me(&GetMe {}).await.unwrap();
+    assert_eq!(client2_info.consumer_groups_count, 0);
+
+    test_server.stop();
+}
+
+async fn join_consumer_group(client: &IggyClient) {
+    let join_group = JoinConsumerGroup {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+        consumer_group_id: CONSUMER_GROUP_ID,
+    };
+    client.join_consumer_group(&join_group).await.unwrap();
+}
+
+async fn send_heartbeat(client: &IggyClient) -> Result<(), Error> {
+    let heartbeat = HeartbeatConsumerGroup {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+        consumer_group_id: CONSUMER_GROUP_ID,
+    };
+    client.heartbeat_consumer_group(&heartbeat).await
+}
+
+async fn get_consumer_group(client: &IggyClient) -> ConsumerGroupDetails {
+    let get_group = GetConsumerGroup {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+        consumer_group_id: CONSUMER_GROUP_ID,
+    };
+    client.get_consumer_group(&get_group).await.unwrap()
+}
+
+async fn create_client(client_factory: &dyn ClientFactory) -> IggyClient {
+    let client = client_factory.create_client().await;
+    IggyClient::create(client, IggyClientConfig::default(), None, None, None)
+}
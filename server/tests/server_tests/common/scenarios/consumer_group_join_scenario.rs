@@ -1,15 +1,27 @@
 use crate::server_tests::common::{ClientFactory, TestServer};
-use iggy::client::{ConsumerGroupClient, StreamClient, SystemClient, TopicClient};
+use iggy::client::{
+    ConsumerGroupClient, ConsumerOffsetClient, MessageClient, StreamClient, SystemClient,
+    TopicClient, TransactionClient,
+};
 use iggy::clients::client::{IggyClient, IggyClientConfig};
+use iggy::consumer::Consumer;
 use iggy::consumer_groups::create_consumer_group::CreateConsumerGroup;
 use iggy::consumer_groups::get_consumer_group::GetConsumerGroup;
+use iggy::consumer_groups::get_consumer_group_assignment::GetConsumerGroupAssignment;
 use iggy::consumer_groups::join_consumer_group::JoinConsumerGroup;
+use iggy::consumer_groups::leave_consumer_group::LeaveConsumerGroup;
+use iggy::consumer_offsets::store_consumer_offset::StoreConsumerOffset;
 use iggy::identifier::Identifier;
+use iggy::messages::poll_messages::{IsolationLevel, PollMessages, PollingStrategy};
+use iggy::models::assignment_strategy::AssignmentStrategy;
+use iggy::models::cleanup_policy::CleanupPolicy;
 use iggy::models::client_info::ClientInfoDetails;
 use iggy::models::consumer_group::ConsumerGroupDetails;
 use iggy::streams::create_stream::CreateStream;
 use iggy::system::get_me::GetMe;
 use iggy::topics::create_topic::CreateTopic;
+use iggy::transactions::begin_transaction::BeginTransaction;
+use iggy::transactions::commit_transaction::CommitTransaction;
 
 const STREAM_ID: u32 = 1;
 const TOPIC_ID: u32 = 1;
@@ -30,6 +42,7 @@ pub async fn run(client_factory: &dyn ClientFactory) {
     let create_stream = CreateStream {
         stream_id: STREAM_ID,
         name: STREAM_NAME.to_string(),
+        encryption: None,
     };
     system_client.create_stream(&create_stream).await.unwrap();
 
@@ -40,6 +53,11 @@ pub async fn run(client_factory: &dyn ClientFactory) {
         partitions_count: PARTITIONS_COUNT,
         name: TOPIC_NAME.to_string(),
         message_expiry: None,
+        cleanup_policy: CleanupPolicy::Delete,
+        compaction_key: None,
+        max_topic_size_bytes: None,
+        max_messages: None,
+        dead_letter_topic_id: None,
     };
     system_client.create_topic(&create_topic).await.unwrap();
 
@@ -48,6 +66,8 @@ pub async fn run(client_factory: &dyn ClientFactory) {
         stream_id: Identifier::numeric(STREAM_ID).unwrap(),
         topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
         consumer_group_id: CONSUMER_GROUP_ID,
+        assignment_strategy: AssignmentStrategy::default(),
+        session_timeout: 0,
     };
     system_client
         .create_consumer_group(&create_group)
@@ -102,6 +122,84 @@ pub async fn run(client_factory: &dyn ClientFactory) {
     assert_ne!(member1.partitions[0], member3.partitions[0]);
     assert_ne!(member2.partitions[0], member3.partitions[0]);
 
+    // 13. Validate that the assignment of client 1 matches the current generation of the consumer group
+    let get_assignment = GetConsumerGroupAssignment {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+        consumer_group_id: CONSUMER_GROUP_ID,
+    };
+    let assignment = client1
+        .get_consumer_group_assignment(&get_assignment)
+        .await
+        .unwrap();
+    let member1 = consumer_group
+        .members
+        .iter()
+        .find(|member| member.id == client1_info.client_id)
+        .unwrap();
+    assert_eq!(assignment.consumer_group_id, CONSUMER_GROUP_ID);
+    assert_eq!(assignment.member_id, client1_info.client_id);
+    assert_eq!(assignment.generation_id, consumer_group.generation_id);
+    assert_eq!(assignment.partitions_count, 1);
+    assert_eq!(assignment.partitions, member1.partitions);
+
+    // 14. Poll the messages by client 1 and validate that the assignment has been already acknowledged
+    let poll_messages = PollMessages {
+        consumer: Consumer::group(CONSUMER_GROUP_ID),
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+        partition_id: None,
+        strategy: PollingStrategy::next(),
+        count: 1,
+        auto_commit: false,
+        include_all_states: false,
+        max_wait_ms: None,
+        isolation_level: IsolationLevel::default(),
+        filters: vec![],
+    };
+    let polled_messages = client1.poll_messages(&poll_messages).await.unwrap();
+    assert_eq!(
+        polled_messages.generation_id,
+        Some(consumer_group.generation_id)
+    );
+    assert!(!polled_messages.assignment_changed);
+
+    // 15. Validate that the offset cannot be stored with the stale generation
+    let mut store_offset = StoreConsumerOffset {
+        consumer: Consumer::group(CONSUMER_GROUP_ID),
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+        partition_id: None,
+        offset: 0,
+        transaction_id: None,
+        generation_id: Some(consumer_group.generation_id - 1),
+    };
+    assert!(client1.store_consumer_offset(&store_offset).await.is_err());
+
+    store_offset.generation_id = Some(consumer_group.generation_id);
+    client1.store_consumer_offset(&store_offset).await.unwrap();
+
+    // 16. Validate that the transactional offset is not committed once the generation has changed
+    let transaction_id = client1
+        .begin_transaction(&BeginTransaction {})
+        .await
+        .unwrap()
+        .transaction_id;
+    store_offset.transaction_id = Some(transaction_id);
+    client1.store_consumer_offset(&store_offset).await.unwrap();
+    client3
+        .leave_consumer_group(&LeaveConsumerGroup {
+            stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+            topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+            consumer_group_id: CONSUMER_GROUP_ID,
+        })
+        .await
+        .unwrap();
+    assert!(client1
+        .commit_transaction(&CommitTransaction { transaction_id })
+        .await
+        .is_err());
+
     test_server.stop();
 }
 
@@ -6,8 +6,10 @@ use iggy::consumer_groups::create_consumer_group::CreateConsumerGroup;
 use iggy::consumer_groups::get_consumer_group::GetConsumerGroup;
 use iggy::consumer_groups::join_consumer_group::JoinConsumerGroup;
 use iggy::identifier::Identifier;
-use iggy::messages::poll_messages::{PollMessages, PollingStrategy};
+use iggy::messages::poll_messages::{IsolationLevel, PollMessages, PollingStrategy};
 use iggy::messages::send_messages::{Message, Partitioning, SendMessages};
+use iggy::models::assignment_strategy::AssignmentStrategy;
+use iggy::models::cleanup_policy::CleanupPolicy;
 use iggy::models::consumer_group::ConsumerGroupDetails;
 use iggy::streams::create_stream::CreateStream;
 use iggy::streams::delete_stream::DeleteStream;
@@ -59,6 +61,7 @@ async fn init_system(
     let create_stream = CreateStream {
         stream_id: STREAM_ID,
         name: STREAM_NAME.to_string(),
+        encryption: None,
     };
     system_client.create_stream(&create_stream).await.unwrap();
 
@@ -69,6 +72,11 @@ async fn init_system(
         partitions_count: PARTITIONS_COUNT,
         name: TOPIC_NAME.to_string(),
         message_expiry: None,
+        cleanup_policy: CleanupPolicy::Delete,
+        compaction_key: None,
+        max_topic_size_bytes: None,
+        max_messages: None,
+        dead_letter_topic_id: None,
     };
     system_client.create_topic(&create_topic).await.unwrap();
 
@@ -77,6 +85,8 @@ async fn init_system(
         stream_id: Identifier::numeric(STREAM_ID).unwrap(),
         topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
         consumer_group_id: CONSUMER_GROUP_ID,
+        assignment_strategy: AssignmentStrategy::default(),
+        session_timeout: 0,
     };
     system_client
         .create_consumer_group(&create_group)
@@ -123,6 +133,8 @@ async fn execute_using_messages_key_key(
             stream_id: Identifier::numeric(STREAM_ID).unwrap(),
             topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
             partitioning: Partitioning::messages_key_u32(entity_id),
+            producer: None,
+            transaction_id: None,
             messages,
         };
         system_client
@@ -149,6 +161,10 @@ async fn poll_messages(client: &IggyClient) -> u32 {
         strategy: PollingStrategy::next(),
         count: 1,
         auto_commit: true,
+        include_all_states: false,
+        max_wait_ms: None,
+        isolation_level: IsolationLevel::default(),
+        filters: vec![],
     };
 
     let mut total_read_messages_count = 0;
@@ -184,6 +200,8 @@ async fn execute_using_none_key(
             stream_id: Identifier::numeric(STREAM_ID).unwrap(),
             topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
             partitioning: Partitioning::balanced(),
+            producer: None,
+            transaction_id: None,
             messages,
         };
         system_client
@@ -232,6 +250,10 @@ async fn validate_message_polling(client: &IggyClient, consumer_group: &Consumer
         strategy: PollingStrategy::next(),
         count: 1,
         auto_commit: true,
+        include_all_states: false,
+        max_wait_ms: None,
+        isolation_level: IsolationLevel::default(),
+        filters: vec![],
     };
 
     for i in 1..=MESSAGES_COUNT {
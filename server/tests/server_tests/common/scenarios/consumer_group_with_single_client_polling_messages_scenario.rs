@@ -6,8 +6,10 @@ use iggy::consumer_groups::create_consumer_group::CreateConsumerGroup;
 use iggy::consumer_groups::get_consumer_group::GetConsumerGroup;
 use iggy::consumer_groups::join_consumer_group::JoinConsumerGroup;
 use iggy::identifier::Identifier;
-use iggy::messages::poll_messages::{PollMessages, PollingStrategy};
+use iggy::messages::poll_messages::{IsolationLevel, PollMessages, PollingStrategy};
 use iggy::messages::send_messages::{Message, Partitioning, SendMessages};
+use iggy::models::assignment_strategy::AssignmentStrategy;
+use iggy::models::cleanup_policy::CleanupPolicy;
 use iggy::streams::create_stream::CreateStream;
 use iggy::streams::delete_stream::DeleteStream;
 use iggy::system::get_me::GetMe;
@@ -45,6 +47,7 @@ async fn init_system(client: &IggyClient) {
     let create_stream = CreateStream {
         stream_id: STREAM_ID,
         name: STREAM_NAME.to_string(),
+        encryption: None,
     };
     client.create_stream(&create_stream).await.unwrap();
 
@@ -55,6 +58,11 @@ async fn init_system(client: &IggyClient) {
         partitions_count: PARTITIONS_COUNT,
         name: TOPIC_NAME.to_string(),
         message_expiry: None,
+        cleanup_policy: CleanupPolicy::Delete,
+        compaction_key: None,
+        max_topic_size_bytes: None,
+        max_messages: None,
+        dead_letter_topic_id: None,
     };
     client.create_topic(&create_topic).await.unwrap();
 
@@ -63,6 +71,8 @@ async fn init_system(client: &IggyClient) {
         stream_id: Identifier::numeric(STREAM_ID).unwrap(),
         topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
         consumer_group_id: CONSUMER_GROUP_ID,
+        assignment_strategy: AssignmentStrategy::default(),
+        session_timeout: 0,
     };
     client.create_consumer_group(&create_group).await.unwrap();
 
@@ -104,6 +114,8 @@ async fn execute_using_messages_key_key(client: &IggyClient) {
             stream_id: Identifier::numeric(STREAM_ID).unwrap(),
             topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
             partitioning: Partitioning::messages_key_u32(entity_id),
+            producer: None,
+            transaction_id: None,
             messages,
         };
         client.send_messages(&mut send_messages).await.unwrap();
@@ -118,6 +130,10 @@ async fn execute_using_messages_key_key(client: &IggyClient) {
         strategy: PollingStrategy::next(),
         count: 1,
         auto_commit: true,
+        include_all_states: false,
+        max_wait_ms: None,
+        isolation_level: IsolationLevel::default(),
+        filters: vec![],
     };
 
     let mut total_read_messages_count = 0;
@@ -148,6 +164,8 @@ async fn execute_using_none_key(client: &IggyClient) {
             stream_id: Identifier::numeric(STREAM_ID).unwrap(),
             topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
             partitioning: Partitioning::balanced(),
+            producer: None,
+            transaction_id: None,
             messages,
         };
         client.send_messages(&mut send_messages).await.unwrap();
@@ -162,6 +180,10 @@ async fn execute_using_none_key(client: &IggyClient) {
         strategy: PollingStrategy::next(),
         count: 1,
         auto_commit: true,
+        include_all_states: false,
+        max_wait_ms: None,
+        isolation_level: IsolationLevel::default(),
+        filters: vec![],
     };
 
     let mut partition_id = 1;
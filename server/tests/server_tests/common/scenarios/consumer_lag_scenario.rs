@@ -0,0 +1,162 @@
+use crate::server_tests::common::{ClientFactory, TestServer};
+use bytes::Bytes;
+use iggy::client::{
+    ConsumerGroupClient, ConsumerOffsetClient, MessageClient, StreamClient, TopicClient,
+};
+use iggy::clients::client::{IggyClient, IggyClientConfig};
+use iggy::consumer::{Consumer, ConsumerKind};
+use iggy::consumer_groups::create_consumer_group::CreateConsumerGroup;
+use iggy::consumer_offsets::get_consumer_lag::GetConsumerLag;
+use iggy::consumer_offsets::store_consumer_offset::StoreConsumerOffset;
+use iggy::identifier::Identifier;
+use iggy::messages::send_messages::{Message, Partitioning, SendMessages};
+use iggy::models::assignment_strategy::AssignmentStrategy;
+use iggy::models::cleanup_policy::CleanupPolicy;
+use iggy::streams::create_stream::CreateStream;
+use iggy::topics::create_topic::CreateTopic;
+use std::time::Duration;
+use tokio::time::sleep;
+
+const STREAM_ID: u32 = 1;
+const TOPIC_ID: u32 = 1;
+const STREAM_NAME: &str = "test-stream";
+const TOPIC_NAME: &str = "test-topic";
+const PARTITIONS_COUNT: u32 = 2;
+const PARTITION_ID: u32 = 1;
+const CONSUMER_ID: u32 = 1;
+const CONSUMER_GROUP_ID: u32 = 1;
+const MESSAGES_COUNT: u32 = 5;
+const BATCHES_INTERVAL_MS: u64 = 100;
+
+pub async fn run(client_factory: &dyn ClientFactory) {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let client = client_factory.create_client().await;
+    let client = IggyClient::create(client, IggyClientConfig::default(), None, None, None);
+    init_system(&client).await;
+
+    // 1. Send two batches of messages to the same partition with some delay in between
+    send_messages(&client, 0).await;
+    sleep(Duration::from_millis(BATCHES_INTERVAL_MS)).await;
+    send_messages(&client, MESSAGES_COUNT).await;
+
+    // 2. The consumer without the stored offset lags behind by all the messages
+    let mut get_consumer_lag = GetConsumerLag {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+        consumer: Some(Consumer::new(CONSUMER_ID)),
+    };
+    let lags = client.get_consumer_lag(&get_consumer_lag).await.unwrap();
+    assert_eq!(lags.len(), 1);
+    let lag = &lags[0];
+    assert_eq!(lag.consumer_kind, ConsumerKind::Consumer);
+    assert_eq!(lag.consumer_id, CONSUMER_ID);
+    assert_eq!(lag.partitions.len(), PARTITIONS_COUNT as usize);
+    let partition_lag = &lag.partitions[0];
+    assert_eq!(partition_lag.partition_id, PARTITION_ID);
+    assert_eq!(partition_lag.current_offset, 2 * MESSAGES_COUNT as u64 - 1);
+    assert!(partition_lag.stored_offset.is_none());
+    assert_eq!(partition_lag.messages_lag, 2 * MESSAGES_COUNT as u64);
+    assert!(partition_lag.time_lag >= BATCHES_INTERVAL_MS * 1000);
+    let empty_partition_lag = &lag.partitions[1];
+    assert_eq!(empty_partition_lag.messages_lag, 0);
+    assert_eq!(empty_partition_lag.time_lag, 0);
+
+    // 3. The consumer that has processed the first batch lags behind by the second one
+    store_offset(&client, MESSAGES_COUNT as u64 - 1).await;
+    let lags = client.get_consumer_lag(&get_consumer_lag).await.unwrap();
+    let partition_lag = &lags[0].partitions[0];
+    assert_eq!(partition_lag.stored_offset, Some(MESSAGES_COUNT as u64 - 1));
+    assert_eq!(partition_lag.messages_lag, MESSAGES_COUNT as u64);
+    assert!(partition_lag.time_lag >= BATCHES_INTERVAL_MS * 1000);
+
+    // 4. The consumer that has processed all the messages doesn't lag behind
+    store_offset(&client, 2 * MESSAGES_COUNT as u64 - 1).await;
+    let lags = client.get_consumer_lag(&get_consumer_lag).await.unwrap();
+    let partition_lag = &lags[0].partitions[0];
+    assert_eq!(partition_lag.messages_lag, 0);
+    assert_eq!(partition_lag.time_lag, 0);
+
+    // 5. The lag of all the consumer groups is returned if the consumer is not specified
+    let create_consumer_group = CreateConsumerGroup {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+        consumer_group_id: CONSUMER_GROUP_ID,
+        assignment_strategy: AssignmentStrategy::default(),
+        session_timeout: 0,
+    };
+    client
+        .create_consumer_group(&create_consumer_group)
+        .await
+        .unwrap();
+    get_consumer_lag.consumer = None;
+    let lags = client.get_consumer_lag(&get_consumer_lag).await.unwrap();
+    assert_eq!(lags.len(), 1);
+    let lag = &lags[0];
+    assert_eq!(lag.consumer_kind, ConsumerKind::ConsumerGroup);
+    assert_eq!(lag.consumer_id, CONSUMER_GROUP_ID);
+    assert_eq!(lag.partitions[0].messages_lag, 2 * MESSAGES_COUNT as u64);
+
+    test_server.stop();
+}
+
+async fn init_system(client: &IggyClient) {
+    // 1. Create the stream
+    let create_stream = CreateStream {
+        stream_id: STREAM_ID,
+        name: STREAM_NAME.to_string(),
+        encryption: None,
+    };
+    client.create_stream(&create_stream).await.unwrap();
+
+    // 2. Create the topic
+    let create_topic = CreateTopic {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: TOPIC_ID,
+        partitions_count: PARTITIONS_COUNT,
+        name: TOPIC_NAME.to_string(),
+        message_expiry: None,
+        cleanup_policy: CleanupPolicy::Delete,
+        compaction_key: None,
+        max_topic_size_bytes: None,
+        max_messages: None,
+        dead_letter_topic_id: None,
+    };
+    client.create_topic(&create_topic).await.unwrap();
+}
+
+async fn send_messages(client: &IggyClient, first_index: u32) {
+    let mut messages = Vec::new();
+    for index in first_index..first_index + MESSAGES_COUNT {
+        messages.push(Message::new(
+            None,
+            Bytes::from(format!("message {}", index)),
+            None,
+        ));
+    }
+    let mut send_messages = SendMessages {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+        partitioning: Partitioning::partition_id(PARTITION_ID),
+        producer: None,
+        transaction_id: None,
+        messages,
+    };
+    client.send_messages(&mut send_messages).await.unwrap();
+}
+
+async fn store_offset(client: &IggyClient, offset: u64) {
+    let store_consumer_offset = StoreConsumerOffset {
+        consumer: Consumer::new(CONSUMER_ID),
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+        partition_id: Some(PARTITION_ID),
+        offset,
+        transaction_id: None,
+        generation_id: None,
+    };
+    client
+        .store_consumer_offset(&store_consumer_offset)
+        .await
+        .unwrap();
+}
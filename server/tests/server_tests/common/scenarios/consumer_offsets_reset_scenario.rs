@@ -0,0 +1,223 @@
+use crate::server_tests::common::{ClientFactory, TestServer};
+use bytes::Bytes;
+use iggy::client::{
+    ConsumerGroupClient, ConsumerOffsetClient, MessageClient, StreamClient, TopicClient,
+};
+use iggy::clients::client::{IggyClient, IggyClientConfig};
+use iggy::consumer::Consumer;
+use iggy::consumer_groups::create_consumer_group::CreateConsumerGroup;
+use iggy::consumer_offsets::get_consumer_offset::GetConsumerOffset;
+use iggy::consumer_offsets::reset_consumer_offsets::{ResetConsumerOffsets, ResetStrategy};
+use iggy::consumer_offsets::store_consumer_offset::StoreConsumerOffset;
+use iggy::identifier::Identifier;
+use iggy::messages::poll_messages::{IsolationLevel, PollMessages, PollingStrategy};
+use iggy::messages::send_messages::{Message, Partitioning, SendMessages};
+use iggy::models::assignment_strategy::AssignmentStrategy;
+use iggy::models::cleanup_policy::CleanupPolicy;
+use iggy::models::consumer_offset_reset::ConsumerOffsetReset;
+use iggy::streams::create_stream::CreateStream;
+use iggy::topics::create_topic::CreateTopic;
+use iggy::utils::timestamp::TimeStamp;
+use std::time::Duration;
+use tokio::time::sleep;
+
+const STREAM_ID: u32 = 1;
+const TOPIC_ID: u32 = 1;
+const STREAM_NAME: &str = "test-stream";
+const TOPIC_NAME: &str = "test-topic";
+const PARTITION_ID: u32 = 1;
+const CONSUMER_ID: u32 = 1;
+const CONSUMER_GROUP_ID: u32 = 1;
+const MESSAGES_COUNT: u32 = 5;
+const BATCHES_INTERVAL_MS: u64 = 100;
+
+pub async fn run(client_factory: &dyn ClientFactory) {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let client = client_factory.create_client().await;
+    let client = IggyClient::create(client, IggyClientConfig::default(), None, None, None);
+    init_system(&client).await;
+
+    // 1. Send two batches of messages with some delay in between and remember when the second one was sent
+    send_messages(&client, 0).await;
+    sleep(Duration::from_millis(BATCHES_INTERVAL_MS)).await;
+    let second_batch_timestamp = TimeStamp::now().to_micros();
+    send_messages(&client, MESSAGES_COUNT).await;
+    let store_consumer_offset = StoreConsumerOffset {
+        consumer: Consumer::new(CONSUMER_ID),
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+        partition_id: Some(PARTITION_ID),
+        offset: 2,
+        transaction_id: None,
+        generation_id: None,
+    };
+    client
+        .store_consumer_offset(&store_consumer_offset)
+        .await
+        .unwrap();
+
+    // 2. The dry run returns the computed offset without storing it
+    let consumer = Consumer::new(CONSUMER_ID);
+    let reset = reset_offsets(&client, &consumer, ResetStrategy::Shift(2), true).await;
+    assert_eq!(reset.previous_offset, Some(2));
+    assert_eq!(reset.stored_offset, Some(4));
+    assert_eq!(get_stored_offset(&client).await, 2);
+
+    // 3. Shifting before the first message resets the offset to the earliest one
+    let reset = reset_offsets(&client, &consumer, ResetStrategy::Shift(-10), false).await;
+    assert_eq!(reset.previous_offset, Some(2));
+    assert!(reset.stored_offset.is_none());
+    assert_eq!(poll_next_offset(&client).await, Some(0));
+
+    // 4. Reset to the latest offset so that there's nothing left to consume
+    let reset = reset_offsets(&client, &consumer, ResetStrategy::Latest, false).await;
+    assert_eq!(reset.stored_offset, Some(2 * MESSAGES_COUNT as u64 - 1));
+    assert_eq!(poll_next_offset(&client).await, None);
+
+    // 5. Reset to the timestamp to consume the second batch again
+    let reset = reset_offsets(
+        &client,
+        &consumer,
+        ResetStrategy::Timestamp(second_batch_timestamp),
+        false,
+    )
+    .await;
+    assert_eq!(reset.stored_offset, Some(MESSAGES_COUNT as u64 - 1));
+    assert_eq!(poll_next_offset(&client).await, Some(MESSAGES_COUNT as u64));
+
+    // 6. Reset to the explicit offset of the next message to be consumed
+    let reset = reset_offsets(&client, &consumer, ResetStrategy::Offset(7), false).await;
+    assert_eq!(reset.stored_offset, Some(6));
+    assert_eq!(get_stored_offset(&client).await, 6);
+
+    // 7. The consumer group offsets can be reset as well
+    let create_consumer_group = CreateConsumerGroup {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+        consumer_group_id: CONSUMER_GROUP_ID,
+        assignment_strategy: AssignmentStrategy::default(),
+        session_timeout: 0,
+    };
+    client
+        .create_consumer_group(&create_consumer_group)
+        .await
+        .unwrap();
+    let consumer_group = Consumer::group(CONSUMER_GROUP_ID);
+    let reset = reset_offsets(&client, &consumer_group, ResetStrategy::Latest, false).await;
+    assert!(reset.previous_offset.is_none());
+    assert_eq!(reset.stored_offset, Some(2 * MESSAGES_COUNT as u64 - 1));
+    let reset = reset_offsets(&client, &consumer_group, ResetStrategy::Earliest, false).await;
+    assert_eq!(reset.previous_offset, Some(2 * MESSAGES_COUNT as u64 - 1));
+    assert!(reset.stored_offset.is_none());
+
+    test_server.stop();
+}
+
+async fn init_system(client: &IggyClient) {
+    // 1. Create the stream
+    let create_stream = CreateStream {
+        stream_id: STREAM_ID,
+        name: STREAM_NAME.to_string(),
+        encryption: None,
+    };
+    client.create_stream(&create_stream).await.unwrap();
+
+    // 2. Create the topic
+    let create_topic = CreateTopic {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: TOPIC_ID,
+        partitions_count: 1,
+        name: TOPIC_NAME.to_string(),
+        message_expiry: None,
+        cleanup_policy: CleanupPolicy::Delete,
+        compaction_key: None,
+        max_topic_size_bytes: None,
+        max_messages: None,
+        dead_letter_topic_id: None,
+    };
+    client.create_topic(&create_topic).await.unwrap();
+}
+
+async fn send_messages(client: &IggyClient, first_index: u32) {
+    let mut messages = Vec::new();
+    for index in first_index..first_index + MESSAGES_COUNT {
+        messages.push(Message::new(
+            None,
+            Bytes::from(format!("message {}", index)),
+            None,
+        ));
+    }
+    let mut send_messages = SendMessages {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+        partitioning: Partitioning::partition_id(PARTITION_ID),
+        producer: None,
+        transaction_id: None,
+        messages,
+    };
+    client.send_messages(&mut send_messages).await.unwrap();
+}
+
+async fn reset_offsets(
+    client: &IggyClient,
+    consumer: &Consumer,
+    strategy: ResetStrategy,
+    dry_run: bool,
+) -> ConsumerOffsetReset {
+    let reset_consumer_offsets = ResetConsumerOffsets {
+        consumer: Consumer {
+            kind: consumer.kind,
+            id: consumer.id,
+        },
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+        partition_id: None,
+        strategy,
+        dry_run,
+    };
+    let mut resets = client
+        .reset_consumer_offsets(&reset_consumer_offsets)
+        .await
+        .unwrap();
+    assert_eq!(resets.len(), 1);
+    let reset = resets.remove(0);
+    assert_eq!(reset.partition_id, PARTITION_ID);
+    assert_eq!(reset.current_offset, 2 * MESSAGES_COUNT as u64 - 1);
+    reset
+}
+
+async fn get_stored_offset(client: &IggyClient) -> u64 {
+    let get_consumer_offset = GetConsumerOffset {
+        consumer: Consumer::new(CONSUMER_ID),
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+        partition_id: Some(PARTITION_ID),
+    };
+    client
+        .get_consumer_offset(&get_consumer_offset)
+        .await
+        .unwrap()
+        .stored_offset
+}
+
+async fn poll_next_offset(client: &IggyClient) -> Option<u64> {
+    let poll_messages = PollMessages {
+        consumer: Consumer::new(CONSUMER_ID),
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+        partition_id: Some(PARTITION_ID),
+        strategy: PollingStrategy::next(),
+        count: 1,
+        auto_commit: false,
+        include_all_states: false,
+        max_wait_ms: None,
+        isolation_level: IsolationLevel::default(),
+        filters: vec![],
+    };
+    let polled_messages = client.poll_messages(&poll_messages).await.unwrap();
+    polled_messages
+        .messages
+        .first()
+        .map(|message| message.offset)
+}
@@ -0,0 +1,193 @@
+use crate::server_tests::common::{ClientFactory, TestServer};
+use bytes::Bytes;
+use iggy::client::{MessageClient, StreamClient, TopicClient};
+use iggy::clients::client::{IggyClient, IggyClientConfig};
+use iggy::consumer::Consumer;
+use iggy::identifier::Identifier;
+use iggy::messages::poison_message::PoisonMessage;
+use iggy::messages::poll_messages::{IsolationLevel, PollMessages, PollingStrategy};
+use iggy::messages::send_messages::{Message, Partitioning, SendMessages};
+use iggy::models::cleanup_policy::CleanupPolicy;
+use iggy::models::header::HeaderKey;
+use iggy::models::messages::MessageState;
+use iggy::streams::create_stream::CreateStream;
+use iggy::topics::create_topic::CreateTopic;
+use iggy::topics::get_topic::GetTopic;
+
+const STREAM_ID: u32 = 1;
+const TOPIC_ID: u32 = 1;
+const DEAD_LETTER_TOPIC_ID: u32 = 2;
+const STREAM_NAME: &str = "test-stream";
+const TOPIC_NAME: &str = "test-topic";
+const DEAD_LETTER_TOPIC_NAME: &str = "test-dead-letter-topic";
+const MESSAGES_COUNT: u32 = 10;
+const PARTITION_ID: u32 = 1;
+const POISONED_OFFSET: u64 = 3;
+
+pub async fn run(client_factory: &dyn ClientFactory) {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let client = client_factory.create_client().await;
+    let client = IggyClient::create(client, IggyClientConfig::default(), None, None, None);
+    init_system(&client).await;
+
+    // 1. Send the messages
+    let mut messages = Vec::new();
+    for offset in 0..MESSAGES_COUNT {
+        let id = (offset + 1) as u128;
+        let payload = get_message_payload(offset as u64);
+        messages.push(Message {
+            id,
+            length: payload.len() as u32,
+            payload,
+            headers: None,
+        });
+    }
+
+    let mut send_messages = SendMessages {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+        partitioning: Partitioning::partition_id(PARTITION_ID),
+        producer: None,
+        transaction_id: None,
+        messages,
+    };
+    client.send_messages(&mut send_messages).await.unwrap();
+
+    // 2. Poison the message
+    let poison_message = PoisonMessage {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+        partition_id: PARTITION_ID,
+        offset: POISONED_OFFSET,
+    };
+    client.poison_message(&poison_message).await.unwrap();
+
+    // 3. Poisoning the same message again should have no effect
+    client.poison_message(&poison_message).await.unwrap();
+
+    // 4. Poll messages and validate that the poisoned one is skipped
+    let mut poll_messages = PollMessages {
+        consumer: Consumer::default(),
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+        partition_id: Some(PARTITION_ID),
+        strategy: PollingStrategy::offset(0),
+        count: MESSAGES_COUNT,
+        auto_commit: false,
+        include_all_states: false,
+        max_wait_ms: None,
+        isolation_level: IsolationLevel::default(),
+        filters: vec![],
+    };
+
+    let polled_messages = client.poll_messages(&poll_messages).await.unwrap();
+    assert_eq!(polled_messages.messages.len() as u32, MESSAGES_COUNT - 1);
+    assert!(polled_messages
+        .messages
+        .iter()
+        .all(|message| message.offset != POISONED_OFFSET));
+
+    // 5. Poll messages including all the states
+    poll_messages.include_all_states = true;
+    let polled_messages = client.poll_messages(&poll_messages).await.unwrap();
+    assert_eq!(polled_messages.messages.len() as u32, MESSAGES_COUNT);
+    let poisoned_message = &polled_messages.messages[POISONED_OFFSET as usize];
+    assert_eq!(poisoned_message.offset, POISONED_OFFSET);
+    assert_eq!(poisoned_message.state, MessageState::Poisoned);
+
+    // 6. Validate that the poisoned message was moved to the dead letter topic only once
+    let dead_letter_topic = client
+        .get_topic(&GetTopic {
+            stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+            topic_id: Identifier::numeric(DEAD_LETTER_TOPIC_ID).unwrap(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(dead_letter_topic.messages_count, 1);
+
+    let poll_messages = PollMessages {
+        consumer: Consumer::default(),
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: Identifier::numeric(DEAD_LETTER_TOPIC_ID).unwrap(),
+        partition_id: Some(PARTITION_ID),
+        strategy: PollingStrategy::offset(0),
+        count: MESSAGES_COUNT,
+        auto_commit: false,
+        include_all_states: false,
+        max_wait_ms: None,
+        isolation_level: IsolationLevel::default(),
+        filters: vec![],
+    };
+    let polled_messages = client.poll_messages(&poll_messages).await.unwrap();
+    assert_eq!(polled_messages.messages.len(), 1);
+    let dead_letter_message = &polled_messages.messages[0];
+    assert_eq!(dead_letter_message.state, MessageState::Available);
+    assert_eq!(dead_letter_message.id, poisoned_message.id);
+    assert_eq!(dead_letter_message.payload, poisoned_message.payload);
+    let dead_letter_headers = dead_letter_message.headers.as_ref().unwrap();
+    assert_eq!(dead_letter_headers.len(), 3);
+    assert_eq!(
+        dead_letter_headers
+            .get(&HeaderKey::new("iggy-dead-letter-source-offset").unwrap())
+            .unwrap()
+            .as_uint64()
+            .unwrap(),
+        POISONED_OFFSET
+    );
+
+    // 7. Poisoning the missing message should fail
+    let poison_message = PoisonMessage {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+        partition_id: PARTITION_ID,
+        offset: MESSAGES_COUNT as u64,
+    };
+    assert!(client.poison_message(&poison_message).await.is_err());
+
+    test_server.stop();
+}
+
+async fn init_system(client: &IggyClient) {
+    // 1. Create the stream
+    let create_stream = CreateStream {
+        stream_id: STREAM_ID,
+        name: STREAM_NAME.to_string(),
+        encryption: None,
+    };
+    client.create_stream(&create_stream).await.unwrap();
+
+    // 2. Create the dead letter topic
+    let create_topic = CreateTopic {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: DEAD_LETTER_TOPIC_ID,
+        partitions_count: 1,
+        name: DEAD_LETTER_TOPIC_NAME.to_string(),
+        message_expiry: None,
+        cleanup_policy: CleanupPolicy::Delete,
+        compaction_key: None,
+        max_topic_size_bytes: None,
+        max_messages: None,
+        dead_letter_topic_id: None,
+    };
+    client.create_topic(&create_topic).await.unwrap();
+
+    // 3. Create the topic using the dead letter topic
+    let create_topic = CreateTopic {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: TOPIC_ID,
+        partitions_count: 1,
+        name: TOPIC_NAME.to_string(),
+        message_expiry: None,
+        cleanup_policy: CleanupPolicy::Delete,
+        compaction_key: None,
+        max_topic_size_bytes: None,
+        max_messages: None,
+        dead_letter_topic_id: Some(DEAD_LETTER_TOPIC_ID),
+    };
+    client.create_topic(&create_topic).await.unwrap();
+}
+
+fn get_message_payload(offset: u64) -> Bytes {
+    Bytes::from(format!("message {}", offset))
+}
@@ -0,0 +1,118 @@
+use crate::server_tests::common::{ClientFactory, TestServer};
+use bytes::Bytes;
+use iggy::client::{MessageClient, StreamClient, TopicClient};
+use iggy::clients::client::{IggyClient, IggyClientConfig};
+use iggy::consumer::Consumer;
+use iggy::identifier::Identifier;
+use iggy::messages::poll_messages::{IsolationLevel, PollMessages, PollingStrategy};
+use iggy::messages::send_messages::{Message, Partitioning, SendMessages, DELIVER_AT_HEADER};
+use iggy::models::cleanup_policy::CleanupPolicy;
+use iggy::models::header::{HeaderKey, HeaderValue};
+use iggy::streams::create_stream::CreateStream;
+use iggy::topics::create_topic::CreateTopic;
+use iggy::utils::timestamp::TimeStamp;
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+const STREAM_ID: u32 = 1;
+const TOPIC_ID: u32 = 1;
+const STREAM_NAME: &str = "test-stream";
+const TOPIC_NAME: &str = "test-topic";
+const PARTITION_ID: u32 = 1;
+const DELIVERY_DELAY_MS: u64 = 1000;
+const LONG_WAIT_MS: u64 = 10000;
+
+pub async fn run(client_factory: &dyn ClientFactory) {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let client = client_factory.create_client().await;
+    let client = IggyClient::create(client, IggyClientConfig::default(), None, None, None);
+    init_system(&client).await;
+
+    // 1. Send the regular message, the delayed one and another regular one
+    let deliver_at = TimeStamp::now().to_micros() + DELIVERY_DELAY_MS * 1000;
+    let now = Instant::now();
+    let mut send_messages = SendMessages {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+        partitioning: Partitioning::partition_id(PARTITION_ID),
+        producer: None,
+        transaction_id: None,
+        messages: vec![
+            get_message("first", None),
+            get_message("delayed", Some(deliver_at)),
+            get_message("last", None),
+        ],
+    };
+    client.send_messages(&mut send_messages).await.unwrap();
+
+    // 2. The delayed message shouldn't be visible before the delivery time, but the ones after it should
+    let mut poll_messages = PollMessages {
+        consumer: Consumer::default(),
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+        partition_id: Some(PARTITION_ID),
+        strategy: PollingStrategy::offset(0),
+        count: 10,
+        auto_commit: false,
+        include_all_states: false,
+        max_wait_ms: None,
+        isolation_level: IsolationLevel::default(),
+        filters: vec![],
+    };
+    let polled_messages = client.poll_messages(&poll_messages).await.unwrap();
+    assert_eq!(polled_messages.messages.len(), 2);
+    assert_eq!(polled_messages.messages[0].payload, Bytes::from("first"));
+    assert_eq!(polled_messages.messages[1].payload, Bytes::from("last"));
+
+    // 3. Long polling should return once the delayed message is delivered after the last one
+    poll_messages.strategy = PollingStrategy::offset(3);
+    poll_messages.max_wait_ms = Some(LONG_WAIT_MS);
+    let polled_messages = client.poll_messages(&poll_messages).await.unwrap();
+    assert!(now.elapsed() >= Duration::from_millis(DELIVERY_DELAY_MS));
+    assert!(now.elapsed() < Duration::from_millis(LONG_WAIT_MS));
+    assert_eq!(polled_messages.messages.len(), 1);
+    assert_eq!(polled_messages.messages[0].payload, Bytes::from("delayed"));
+    assert!(polled_messages.messages[0].timestamp >= deliver_at);
+
+    // 4. The delivery time beyond the max delay should be rejected
+    send_messages.messages = vec![get_message("too late", Some(u64::MAX))];
+    assert!(client.send_messages(&mut send_messages).await.is_err());
+
+    test_server.stop();
+}
+
+async fn init_system(client: &IggyClient) {
+    // 1. Create the stream
+    let create_stream = CreateStream {
+        stream_id: STREAM_ID,
+        name: STREAM_NAME.to_string(),
+        encryption: None,
+    };
+    client.create_stream(&create_stream).await.unwrap();
+
+    // 2. Create the topic
+    let create_topic = CreateTopic {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: TOPIC_ID,
+        partitions_count: 1,
+        name: TOPIC_NAME.to_string(),
+        message_expiry: None,
+        cleanup_policy: CleanupPolicy::Delete,
+        compaction_key: None,
+        max_topic_size_bytes: None,
+        max_messages: None,
+        dead_letter_topic_id: None,
+    };
+    client.create_topic(&create_topic).await.unwrap();
+}
+
+fn get_message(payload: &str, deliver_at: Option<u64>) -> Message {
+    let headers = deliver_at.map(|deliver_at| {
+        HashMap::from([(
+            HeaderKey::new(DELIVER_AT_HEADER).unwrap(),
+            HeaderValue::from_uint64(deliver_at).unwrap(),
+        )])
+    });
+    Message::new(None, Bytes::from(payload.to_string()), headers)
+}
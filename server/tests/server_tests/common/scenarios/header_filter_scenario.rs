@@ -0,0 +1,139 @@
+use crate::server_tests::common::{ClientFactory, TestServer};
+use bytes::Bytes;
+use iggy::client::{MessageClient, StreamClient, TopicClient};
+use iggy::clients::client::{IggyClient, IggyClientConfig};
+use iggy::consumer::Consumer;
+use iggy::identifier::Identifier;
+use iggy::messages::poll_messages::{IsolationLevel, PollMessages, PollingStrategy};
+use iggy::messages::send_messages::{Message, Partitioning, SendMessages};
+use iggy::models::cleanup_policy::CleanupPolicy;
+use iggy::models::header::{HeaderKey, HeaderValue};
+use iggy::models::header_filter::HeaderFilter;
+use iggy::streams::create_stream::CreateStream;
+use iggy::topics::create_topic::CreateTopic;
+use std::collections::HashMap;
+use std::str::FromStr;
+
+const STREAM_ID: u32 = 1;
+const TOPIC_ID: u32 = 1;
+const STREAM_NAME: &str = "test-stream";
+const TOPIC_NAME: &str = "test-topic";
+const PARTITION_ID: u32 = 1;
+const MESSAGES_COUNT: u32 = 10;
+
+pub async fn run(client_factory: &dyn ClientFactory) {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let client = client_factory.create_client().await;
+    let client = IggyClient::create(client, IggyClientConfig::default(), None, None, None);
+    init_system(&client).await;
+
+    // 1. Send the messages with the alternating types and the increasing priorities
+    let mut messages = Vec::new();
+    for index in 0..MESSAGES_COUNT {
+        let message_type = match index % 2 {
+            0 => "order_created",
+            _ => "payment_received",
+        };
+        messages.push(get_message(index, message_type));
+    }
+    let mut send_messages = SendMessages {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+        partitioning: Partitioning::partition_id(PARTITION_ID),
+        producer: None,
+        transaction_id: None,
+        messages,
+    };
+    client.send_messages(&mut send_messages).await.unwrap();
+
+    // 2. Poll only the orders with the priority of at least 2
+    let mut poll_messages = PollMessages {
+        consumer: Consumer::default(),
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+        partition_id: Some(PARTITION_ID),
+        strategy: PollingStrategy::offset(0),
+        count: 3,
+        auto_commit: false,
+        include_all_states: false,
+        max_wait_ms: None,
+        isolation_level: IsolationLevel::default(),
+        filters: vec![
+            HeaderFilter::prefix("type", "order_").unwrap(),
+            HeaderFilter::range("priority", Some(HeaderValue::from_uint32(2).unwrap()), None)
+                .unwrap(),
+        ],
+    };
+    let polled_messages = client.poll_messages(&poll_messages).await.unwrap();
+    let offsets = polled_messages
+        .messages
+        .iter()
+        .map(|message| message.offset)
+        .collect::<Vec<u64>>();
+    assert_eq!(offsets, vec![2, 4, 6]);
+    assert_eq!(polled_messages.scanned_offset, Some(6));
+
+    // 3. Continue from the scanned offset
+    poll_messages.strategy = PollingStrategy::offset(7);
+    let polled_messages = client.poll_messages(&poll_messages).await.unwrap();
+    assert_eq!(polled_messages.messages.len(), 1);
+    assert_eq!(polled_messages.messages[0].offset, 8);
+    assert_eq!(polled_messages.scanned_offset, Some(9));
+
+    // 4. The scanned offset is returned even if none of the messages match the filters
+    poll_messages.strategy = PollingStrategy::offset(0);
+    poll_messages.filters =
+        vec![
+            HeaderFilter::equals("type", HeaderValue::from_str("order_cancelled").unwrap())
+                .unwrap(),
+        ];
+    let polled_messages = client.poll_messages(&poll_messages).await.unwrap();
+    assert!(polled_messages.messages.is_empty());
+    assert_eq!(polled_messages.scanned_offset, Some(9));
+
+    test_server.stop();
+}
+
+async fn init_system(client: &IggyClient) {
+    // 1. Create the stream
+    let create_stream = CreateStream {
+        stream_id: STREAM_ID,
+        name: STREAM_NAME.to_string(),
+        encryption: None,
+    };
+    client.create_stream(&create_stream).await.unwrap();
+
+    // 2. Create the topic
+    let create_topic = CreateTopic {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: TOPIC_ID,
+        partitions_count: 1,
+        name: TOPIC_NAME.to_string(),
+        message_expiry: None,
+        cleanup_policy: CleanupPolicy::Delete,
+        compaction_key: None,
+        max_topic_size_bytes: None,
+        max_messages: None,
+        dead_letter_topic_id: None,
+    };
+    client.create_topic(&create_topic).await.unwrap();
+}
+
+fn get_message(priority: u32, message_type: &str) -> Message {
+    let headers = HashMap::from([
+        (
+            HeaderKey::new("type").unwrap(),
+            HeaderValue::from_str(message_type).unwrap(),
+        ),
+        (
+            HeaderKey::new("priority").unwrap(),
+            HeaderValue::from_uint32(priority).unwrap(),
+        ),
+    ]);
+    Message::new(
+        None,
+        Bytes::from(format!("message {}", priority)),
+        Some(headers),
+    )
+}
@@ -0,0 +1,159 @@
+use crate::server_tests::common::{ClientFactory, TestServer};
+use bytes::Bytes;
+use iggy::client::{MessageClient, StreamClient, TopicClient};
+use iggy::clients::client::{IggyClient, IggyClientConfig};
+use iggy::consumer::Consumer;
+use iggy::identifier::Identifier;
+use iggy::messages::init_producer::InitProducer;
+use iggy::messages::poll_messages::{IsolationLevel, PollMessages, PollingStrategy};
+use iggy::messages::send_messages::{Message, Partitioning, ProducerSequence, SendMessages};
+use iggy::models::cleanup_policy::CleanupPolicy;
+use iggy::models::header::HeaderKey;
+use iggy::streams::create_stream::CreateStream;
+use iggy::topics::create_topic::CreateTopic;
+
+const STREAM_ID: u32 = 1;
+const TOPIC_ID: u32 = 1;
+const STREAM_NAME: &str = "test-stream";
+const TOPIC_NAME: &str = "test-topic";
+const PARTITION_ID: u32 = 1;
+const BATCH_SIZE: u64 = 3;
+
+pub async fn run(client_factory: &dyn ClientFactory) {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let client = client_factory.create_client().await;
+    let client = IggyClient::create(client, IggyClientConfig::default(), None, None, None);
+    init_system(&client).await;
+
+    // 1. Initialize the producers and validate that they've got the unique IDs
+    let producer_id = client
+        .init_producer(&InitProducer {})
+        .await
+        .unwrap()
+        .producer_id;
+    let other_producer_id = client
+        .init_producer(&InitProducer {})
+        .await
+        .unwrap()
+        .producer_id;
+    assert!(producer_id > 0);
+    assert_ne!(producer_id, other_producer_id);
+
+    // 2. Send the first batch
+    let mut send_messages = get_send_messages(producer_id, 1);
+    client.send_messages(&mut send_messages).await.unwrap();
+
+    // 3. Retrying the same batch should succeed without appending it again
+    let mut send_messages = get_send_messages(producer_id, 1);
+    client.send_messages(&mut send_messages).await.unwrap();
+
+    // 4. Sending the batch overlapping with the already appended one should be rejected
+    let mut send_messages = get_send_messages(producer_id, BATCH_SIZE);
+    assert!(client.send_messages(&mut send_messages).await.is_err());
+
+    // 5. Sending the batch out of order should be rejected
+    let mut send_messages = get_send_messages(producer_id, BATCH_SIZE + 2);
+    assert!(client.send_messages(&mut send_messages).await.is_err());
+
+    // 6. Send the next batch in order
+    let mut send_messages = get_send_messages(producer_id, BATCH_SIZE + 1);
+    client.send_messages(&mut send_messages).await.unwrap();
+
+    // 7. The other producer keeps its own sequence
+    let mut send_messages = get_send_messages(other_producer_id, 1);
+    client.send_messages(&mut send_messages).await.unwrap();
+
+    // 8. The producer ID which hasn't been issued by the server should be rejected
+    let mut send_messages = get_send_messages(producer_id.max(other_producer_id) + 1000, 1);
+    assert!(client.send_messages(&mut send_messages).await.is_err());
+
+    // 9. Poll messages and validate that each one has been appended only once with its sequence
+    let poll_messages = PollMessages {
+        consumer: Consumer::default(),
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+        partition_id: Some(PARTITION_ID),
+        strategy: PollingStrategy::offset(0),
+        count: 100,
+        auto_commit: false,
+        include_all_states: false,
+        max_wait_ms: None,
+        isolation_level: IsolationLevel::default(),
+        filters: vec![],
+    };
+    let polled_messages = client.poll_messages(&poll_messages).await.unwrap();
+    assert_eq!(polled_messages.messages.len() as u64, 3 * BATCH_SIZE);
+
+    let producer_id_key = HeaderKey::new("iggy-producer-id").unwrap();
+    let producer_sequence_key = HeaderKey::new("iggy-producer-sequence").unwrap();
+    for (index, message) in polled_messages.messages.iter().enumerate() {
+        let headers = message.headers.as_ref().unwrap();
+        let message_producer_id = headers.get(&producer_id_key).unwrap().as_uint64().unwrap();
+        let sequence = headers
+            .get(&producer_sequence_key)
+            .unwrap()
+            .as_uint64()
+            .unwrap();
+        let index = index as u64;
+        if index < 2 * BATCH_SIZE {
+            assert_eq!(message_producer_id, producer_id);
+            assert_eq!(sequence, index + 1);
+        } else {
+            assert_eq!(message_producer_id, other_producer_id);
+            assert_eq!(sequence, index - 2 * BATCH_SIZE + 1);
+        }
+    }
+
+    test_server.stop();
+}
+
+async fn init_system(client: &IggyClient) {
+    // 1. Create the stream
+    let create_stream = CreateStream {
+        stream_id: STREAM_ID,
+        name: STREAM_NAME.to_string(),
+        encryption: None,
+    };
+    client.create_stream(&create_stream).await.unwrap();
+
+    // 2. Create the topic
+    let create_topic = CreateTopic {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: TOPIC_ID,
+        partitions_count: 1,
+        name: TOPIC_NAME.to_string(),
+        message_expiry: None,
+        cleanup_policy: CleanupPolicy::Delete,
+        compaction_key: None,
+        max_topic_size_bytes: None,
+        max_messages: None,
+        dead_letter_topic_id: None,
+    };
+    client.create_topic(&create_topic).await.unwrap();
+}
+
+fn get_send_messages(producer_id: u64, sequence: u64) -> SendMessages {
+    let mut messages = Vec::new();
+    for index in 0..BATCH_SIZE {
+        let payload = Bytes::from(format!("message {}", sequence + index));
+        messages.push(Message {
+            id: 0,
+            length: payload.len() as u32,
+            payload,
+            headers: None,
+        });
+    }
+
+    SendMessages {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+        partitioning: Partitioning::partition_id(PARTITION_ID),
+        producer: Some(ProducerSequence {
+            producer_id,
+            sequence,
+        }),
+        transaction_id: None,
+        messages,
+    }
+}
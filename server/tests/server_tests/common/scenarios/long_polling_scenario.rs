@@ -0,0 +1,137 @@
+use crate::server_tests::common::{ClientFactory, TestServer};
+use bytes::Bytes;
+use iggy::client::{MessageClient, StreamClient, TopicClient};
+use iggy::clients::client::{IggyClient, IggyClientConfig};
+use iggy::consumer::Consumer;
+use iggy::identifier::Identifier;
+use iggy::messages::poll_messages::{IsolationLevel, PollMessages, PollingStrategy};
+use iggy::messages::send_messages::{Message, Partitioning, SendMessages};
+use iggy::models::cleanup_policy::CleanupPolicy;
+use iggy::streams::create_stream::CreateStream;
+use iggy::topics::create_topic::CreateTopic;
+use std::time::{Duration, Instant};
+use tokio::time::sleep;
+
+const STREAM_ID: u32 = 1;
+const TOPIC_ID: u32 = 1;
+const STREAM_NAME: &str = "test-stream";
+const TOPIC_NAME: &str = "test-topic";
+const PARTITION_ID: u32 = 1;
+const MESSAGES_COUNT: u32 = 10;
+const SHORT_WAIT_MS: u64 = 100;
+const LONG_WAIT_MS: u64 = 10000;
+const SEND_DELAY_MS: u64 = 500;
+
+pub async fn run(client_factory: &dyn ClientFactory) {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let consumer_client = client_factory.create_client().await;
+    let consumer_client = IggyClient::create(
+        consumer_client,
+        IggyClientConfig::default(),
+        None,
+        None,
+        None,
+    );
+    let producer_client = client_factory.create_client().await;
+    let producer_client = IggyClient::create(
+        producer_client,
+        IggyClientConfig::default(),
+        None,
+        None,
+        None,
+    );
+    init_system(&consumer_client).await;
+
+    // 1. Polling the empty partition should wait until the timeout expires
+    let mut poll_messages = PollMessages {
+        consumer: Consumer::default(),
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+        partition_id: Some(PARTITION_ID),
+        strategy: PollingStrategy::offset(0),
+        count: MESSAGES_COUNT,
+        auto_commit: false,
+        include_all_states: false,
+        max_wait_ms: Some(SHORT_WAIT_MS),
+        isolation_level: IsolationLevel::default(),
+        filters: vec![],
+    };
+    let now = Instant::now();
+    let polled_messages = consumer_client.poll_messages(&poll_messages).await.unwrap();
+    assert!(polled_messages.messages.is_empty());
+    assert!(now.elapsed() >= Duration::from_millis(SHORT_WAIT_MS));
+
+    // 2. Polling should return as soon as the messages are appended to the partition
+    let send_messages_task = tokio::spawn(async move {
+        sleep(Duration::from_millis(SEND_DELAY_MS)).await;
+        let mut send_messages = SendMessages {
+            stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+            topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+            partitioning: Partitioning::partition_id(PARTITION_ID),
+            producer: None,
+            transaction_id: None,
+            messages: get_messages(),
+        };
+        producer_client
+            .send_messages(&mut send_messages)
+            .await
+            .unwrap();
+    });
+
+    poll_messages.max_wait_ms = Some(LONG_WAIT_MS);
+    let now = Instant::now();
+    let polled_messages = consumer_client.poll_messages(&poll_messages).await.unwrap();
+    let elapsed = now.elapsed();
+    send_messages_task.await.unwrap();
+    assert_eq!(polled_messages.messages.len() as u32, MESSAGES_COUNT);
+    assert!(elapsed >= Duration::from_millis(SEND_DELAY_MS));
+    assert!(elapsed < Duration::from_millis(LONG_WAIT_MS));
+
+    // 3. Polling the available messages should not wait at all
+    let now = Instant::now();
+    let polled_messages = consumer_client.poll_messages(&poll_messages).await.unwrap();
+    assert_eq!(polled_messages.messages.len() as u32, MESSAGES_COUNT);
+    assert!(now.elapsed() < Duration::from_millis(LONG_WAIT_MS));
+
+    test_server.stop();
+}
+
+async fn init_system(client: &IggyClient) {
+    // 1. Create the stream
+    let create_stream = CreateStream {
+        stream_id: STREAM_ID,
+        name: STREAM_NAME.to_string(),
+        encryption: None,
+    };
+    client.create_stream(&create_stream).await.unwrap();
+
+    // 2. Create the topic
+    let create_topic = CreateTopic {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: TOPIC_ID,
+        partitions_count: 1,
+        name: TOPIC_NAME.to_string(),
+        message_expiry: None,
+        cleanup_policy: CleanupPolicy::Delete,
+        compaction_key: None,
+        max_topic_size_bytes: None,
+        max_messages: None,
+        dead_letter_topic_id: None,
+    };
+    client.create_topic(&create_topic).await.unwrap();
+}
+
+fn get_messages() -> Vec<Message> {
+    let mut messages = Vec::new();
+    for offset in 0..MESSAGES_COUNT {
+        let payload = Bytes::from(format!("message {}", offset));
+        messages.push(Message {
+            id: (offset + 1) as u128,
+            length: payload.len() as u32,
+            payload,
+            headers: None,
+        });
+    }
+    messages
+}
@@ -1,5 +1,18 @@
+pub mod audit_scenario;
+pub mod consumer_group_heartbeat_scenario;
 pub mod consumer_group_join_scenario;
 pub mod consumer_group_with_multiple_clients_polling_messages_scenario;
 pub mod consumer_group_with_single_client_polling_messages_scenario;
+pub mod consumer_lag_scenario;
+pub mod consumer_offsets_reset_scenario;
+pub mod dead_letter_scenario;
+pub mod delayed_delivery_scenario;
+pub mod header_filter_scenario;
+pub mod idempotent_producer_scenario;
+pub mod long_polling_scenario;
 pub mod message_headers_scenario;
+pub mod personal_access_token_scenario;
+pub mod role_scenario;
+pub mod subscription_scenario;
 pub mod system_scenario;
+pub mod transaction_scenario;
@@ -0,0 +1,128 @@
+use crate::server_tests::common::{ClientFactory, TestServer};
+use iggy::client::{PersonalAccessTokenClient, UserClient};
+use iggy::clients::client::{IggyClient, IggyClientConfig};
+use iggy::personal_access_tokens::create_personal_access_token::CreatePersonalAccessToken;
+use iggy::personal_access_tokens::delete_personal_access_token::DeletePersonalAccessToken;
+use iggy::personal_access_tokens::get_personal_access_tokens::GetPersonalAccessTokens;
+use iggy::personal_access_tokens::login_with_token::LoginWithToken;
+use iggy::users::login_user::LoginUser;
+use iggy::users::logout_user::LogoutUser;
+use std::time::Duration;
+use tokio::time::sleep;
+
+const ROOT_USERNAME: &str = "iggy";
+const ROOT_PASSWORD: &str = "iggy";
+const TOKEN_NAME: &str = "service-token";
+const EXPIRING_TOKEN_NAME: &str = "expiring-token";
+
+pub async fn run(client_factory: &dyn ClientFactory) {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let client = client_factory.create_client().await;
+    let client = IggyClient::create(client, IggyClientConfig::default(), None, None, None);
+
+    // 1. Login as the root user and validate that there are no personal access tokens
+    client
+        .login_user(&LoginUser {
+            username: ROOT_USERNAME.to_string(),
+            password: ROOT_PASSWORD.to_string(),
+        })
+        .await
+        .unwrap();
+    let personal_access_tokens = client
+        .get_personal_access_tokens(&GetPersonalAccessTokens {})
+        .await
+        .unwrap();
+    assert!(personal_access_tokens.is_empty());
+
+    // 2. Create the personal access tokens, the name must be unique for the user
+    let raw_token = client
+        .create_personal_access_token(&CreatePersonalAccessToken {
+            name: TOKEN_NAME.to_string(),
+            expiry: None,
+        })
+        .await
+        .unwrap()
+        .token;
+    assert!(!raw_token.is_empty());
+
+    let create_duplicated_token = client
+        .create_personal_access_token(&CreatePersonalAccessToken {
+            name: TOKEN_NAME.to_string(),
+            expiry: None,
+        })
+        .await;
+    assert!(create_duplicated_token.is_err());
+
+    let raw_expiring_token = client
+        .create_personal_access_token(&CreatePersonalAccessToken {
+            name: EXPIRING_TOKEN_NAME.to_string(),
+            expiry: Some(1),
+        })
+        .await
+        .unwrap()
+        .token;
+
+    // 3. Validate that only the token names and expiry are returned
+    let personal_access_tokens = client
+        .get_personal_access_tokens(&GetPersonalAccessTokens {})
+        .await
+        .unwrap();
+    assert_eq!(personal_access_tokens.len(), 2);
+    assert_eq!(personal_access_tokens[0].name, EXPIRING_TOKEN_NAME);
+    assert!(personal_access_tokens[0].expiry.is_some());
+    assert_eq!(personal_access_tokens[1].name, TOKEN_NAME);
+    assert!(personal_access_tokens[1].expiry.is_none());
+
+    // 4. Login with the personal access token and validate that it belongs to the root user
+    client.logout_user(&LogoutUser {}).await.unwrap();
+    client
+        .login_with_token(&LoginWithToken {
+            token: raw_token.clone(),
+        })
+        .await
+        .unwrap();
+    let personal_access_tokens = client
+        .get_personal_access_tokens(&GetPersonalAccessTokens {})
+        .await
+        .unwrap();
+    assert_eq!(personal_access_tokens.len(), 2);
+
+    // 5. Validate that the expired and invalid tokens cannot be used to login
+    sleep(Duration::from_millis(1100)).await;
+    let login_with_expired_token = client
+        .login_with_token(&LoginWithToken {
+            token: raw_expiring_token,
+        })
+        .await;
+    assert!(login_with_expired_token.is_err());
+
+    let login_with_invalid_token = client
+        .login_with_token(&LoginWithToken {
+            token: "invalid".to_string(),
+        })
+        .await;
+    assert!(login_with_invalid_token.is_err());
+
+    // 6. Delete the personal access token and validate that it cannot be used to login anymore
+    client
+        .delete_personal_access_token(&DeletePersonalAccessToken {
+            name: TOKEN_NAME.to_string(),
+        })
+        .await
+        .unwrap();
+    let personal_access_tokens = client
+        .get_personal_access_tokens(&GetPersonalAccessTokens {})
+        .await
+        .unwrap();
+    assert_eq!(personal_access_tokens.len(), 1);
+    assert_eq!(personal_access_tokens[0].name, EXPIRING_TOKEN_NAME);
+
+    client.logout_user(&LogoutUser {}).await.unwrap();
+    let login_with_deleted_token = client
+        .login_with_token(&LoginWithToken { token: raw_token })
+        .await;
+    assert!(login_with_deleted_token.is_err());
+
+    test_server.stop();
+}
@@ -0,0 +1,190 @@
+use crate::server_tests::common::{ClientFactory, TestServer};
+use iggy::client::{RoleClient, UserClient};
+use iggy::clients::client::{IggyClient, IggyClientConfig};
+use iggy::identifier::Identifier;
+use iggy::models::permissions::{GlobalPermissions, Permissions};
+use iggy::roles::add_role_member::AddRoleMember;
+use iggy::roles::create_role::CreateRole;
+use iggy::roles::delete_role::DeleteRole;
+use iggy::roles::get_role::GetRole;
+use iggy::roles::get_roles::GetRoles;
+use iggy::roles::remove_role_member::RemoveRoleMember;
+use iggy::roles::update_role::UpdateRole;
+use iggy::users::create_user::CreateUser;
+use iggy::users::delete_user::DeleteUser;
+use iggy::users::get_user::GetUser;
+use iggy::users::login_user::LoginUser;
+
+const ROOT_USERNAME: &str = "iggy";
+const ROOT_PASSWORD: &str = "iggy";
+const USERNAME: &str = "role-member";
+const PASSWORD: &str = "secret";
+const ROLE_NAME: &str = "readers";
+
+pub async fn run(client_factory: &dyn ClientFactory) {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let client = client_factory.create_client().await;
+    let client = IggyClient::create(client, IggyClientConfig::default(), None, None, None);
+
+    // 1. Login as the root user and validate that there are no roles
+    client
+        .login_user(&LoginUser {
+            username: ROOT_USERNAME.to_string(),
+            password: ROOT_PASSWORD.to_string(),
+        })
+        .await
+        .unwrap();
+    let roles = client.get_roles(&GetRoles {}).await.unwrap();
+    assert!(roles.is_empty());
+
+    // 2. Create the role, the name must be unique
+    client
+        .create_role(&CreateRole {
+            name: ROLE_NAME.to_string(),
+            permissions: get_read_permissions(),
+        })
+        .await
+        .unwrap();
+    let create_duplicated_role = client
+        .create_role(&CreateRole {
+            name: ROLE_NAME.to_string(),
+            permissions: get_read_permissions(),
+        })
+        .await;
+    assert!(create_duplicated_role.is_err());
+
+    let roles = client.get_roles(&GetRoles {}).await.unwrap();
+    assert_eq!(roles.len(), 1);
+    let role = &roles[0];
+    assert_eq!(role.name, ROLE_NAME);
+    assert_eq!(role.members_count, 0);
+    let role_id = role.id;
+
+    // 3. Create the user and assign the role to the user
+    client
+        .create_user(&CreateUser {
+            username: USERNAME.to_string(),
+            password: PASSWORD.to_string(),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    let user = client
+        .get_user(&GetUser {
+            user_id: Identifier::named(USERNAME).unwrap(),
+        })
+        .await
+        .unwrap();
+    let user_id = user.id;
+    client
+        .add_role_member(&AddRoleMember {
+            role_id: Identifier::numeric(role_id).unwrap(),
+            user_id: Identifier::numeric(user_id).unwrap(),
+        })
+        .await
+        .unwrap();
+
+    let role = client
+        .get_role(&GetRole {
+            role_id: Identifier::named(ROLE_NAME).unwrap(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(role.members_count, 1);
+    assert_eq!(role.members, vec![user.id]);
+    assert_eq!(role.permissions, get_read_permissions());
+
+    // 4. Update the role permissions
+    let mut permissions = get_read_permissions();
+    permissions.global.send_messages = true;
+    client
+        .update_role(&UpdateRole {
+            role_id: Identifier::numeric(role_id).unwrap(),
+            permissions: permissions.clone(),
+        })
+        .await
+        .unwrap();
+    let role = client
+        .get_role(&GetRole {
+            role_id: Identifier::numeric(role_id).unwrap(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(role.permissions, permissions);
+
+    // 5. Remove the member from the role, it cannot be removed twice
+    client
+        .remove_role_member(&RemoveRoleMember {
+            role_id: Identifier::numeric(role_id).unwrap(),
+            user_id: Identifier::numeric(user_id).unwrap(),
+        })
+        .await
+        .unwrap();
+    let role = client
+        .get_role(&GetRole {
+            role_id: Identifier::numeric(role_id).unwrap(),
+        })
+        .await
+        .unwrap();
+    assert!(role.members.is_empty());
+    let remove_missing_member = client
+        .remove_role_member(&RemoveRoleMember {
+            role_id: Identifier::numeric(role_id).unwrap(),
+            user_id: Identifier::numeric(user_id).unwrap(),
+        })
+        .await;
+    assert!(remove_missing_member.is_err());
+
+    // 6. Deleting the user removes it from the role members
+    client
+        .add_role_member(&AddRoleMember {
+            role_id: Identifier::numeric(role_id).unwrap(),
+            user_id: Identifier::numeric(user_id).unwrap(),
+        })
+        .await
+        .unwrap();
+    client
+        .delete_user(&DeleteUser {
+            user_id: Identifier::numeric(user_id).unwrap(),
+        })
+        .await
+        .unwrap();
+    let role = client
+        .get_role(&GetRole {
+            role_id: Identifier::numeric(role_id).unwrap(),
+        })
+        .await
+        .unwrap();
+    assert!(role.members.is_empty());
+
+    // 7. Delete the role
+    client
+        .delete_role(&DeleteRole {
+            role_id: Identifier::numeric(role_id).unwrap(),
+        })
+        .await
+        .unwrap();
+    let roles = client.get_roles(&GetRoles {}).await.unwrap();
+    assert!(roles.is_empty());
+    let get_deleted_role = client
+        .get_role(&GetRole {
+            role_id: Identifier::numeric(role_id).unwrap(),
+        })
+        .await;
+    assert!(get_deleted_role.is_err());
+
+    test_server.stop();
+}
+
+fn get_read_permissions() -> Permissions {
+    Permissions {
+        global: GlobalPermissions {
+            read_streams: true,
+            read_topics: true,
+            poll_messages: true,
+            ..Default::default()
+        },
+        streams: None,
+    }
+}
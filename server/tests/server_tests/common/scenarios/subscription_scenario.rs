@@ -0,0 +1,146 @@
+use crate::server_tests::common::{ClientFactory, TestServer};
+use bytes::Bytes;
+use futures::StreamExt;
+use iggy::client::{MessageClient, StreamClient, TopicClient};
+use iggy::clients::client::{IggyClient, IggyClientConfig, MessageSubscription};
+use iggy::consumer::Consumer;
+use iggy::identifier::Identifier;
+use iggy::messages::grant_credits::GrantCredits;
+use iggy::messages::poll_messages::{IsolationLevel, PollingStrategy};
+use iggy::messages::send_messages::{Message, Partitioning, SendMessages};
+use iggy::messages::subscribe::Subscribe;
+use iggy::models::cleanup_policy::CleanupPolicy;
+use iggy::streams::create_stream::CreateStream;
+use iggy::topics::create_topic::CreateTopic;
+use std::time::Duration;
+use tokio::time::timeout;
+
+const STREAM_ID: u32 = 1;
+const TOPIC_ID: u32 = 1;
+const STREAM_NAME: &str = "test-stream";
+const TOPIC_NAME: &str = "test-topic";
+const PARTITION_ID: u32 = 1;
+const SUBSCRIPTION_ID: u32 = 1;
+const MESSAGES_COUNT: u32 = 10;
+const CREDITS: u32 = 4;
+const RECEIVE_TIMEOUT_MS: u64 = 5000;
+
+pub async fn run(client_factory: &dyn ClientFactory) {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let consumer_client = client_factory.create_client().await;
+    let consumer_client = IggyClient::create(
+        consumer_client,
+        IggyClientConfig::default(),
+        None,
+        None,
+        None,
+    );
+    let producer_client = client_factory.create_client().await;
+    let producer_client = IggyClient::create(
+        producer_client,
+        IggyClientConfig::default(),
+        None,
+        None,
+        None,
+    );
+    init_system(&producer_client).await;
+
+    // 1. Send the messages before subscribing
+    send_messages(&producer_client, 0).await;
+
+    // 2. Subscribe to the partition with fewer credits than the available messages
+    let mut subscription = consumer_client
+        .subscribe_to_messages(Subscribe {
+            subscription_id: SUBSCRIPTION_ID,
+            consumer: Consumer::default(),
+            stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+            topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+            partition_id: Some(PARTITION_ID),
+            strategy: PollingStrategy::offset(0),
+            auto_commit: false,
+            credits: CREDITS,
+            isolation_level: IsolationLevel::ReadCommitted,
+        })
+        .await
+        .unwrap();
+
+    // 3. All the existing messages should be pushed, as the consumed credits are granted back
+    assert_received_messages(&mut subscription, 0).await;
+
+    // 4. The newly appended messages should be pushed as well
+    send_messages(&producer_client, MESSAGES_COUNT).await;
+    assert_received_messages(&mut subscription, MESSAGES_COUNT as u64).await;
+
+    // 5. Granting the credits for the unknown subscription should fail
+    let grant_credits = consumer_client
+        .grant_credits(&GrantCredits {
+            subscription_id: SUBSCRIPTION_ID + 1,
+            credits: CREDITS,
+        })
+        .await;
+    assert!(grant_credits.is_err());
+
+    test_server.stop();
+}
+
+async fn assert_received_messages(subscription: &mut MessageSubscription, start_offset: u64) {
+    for offset in start_offset..start_offset + MESSAGES_COUNT as u64 {
+        let message = timeout(
+            Duration::from_millis(RECEIVE_TIMEOUT_MS),
+            subscription.next(),
+        )
+        .await
+        .unwrap()
+        .unwrap();
+        assert_eq!(message.offset, offset);
+        assert_eq!(message.payload, Bytes::from(format!("message {}", offset)));
+    }
+}
+
+async fn send_messages(client: &IggyClient, start_offset: u32) {
+    let mut messages = Vec::new();
+    for offset in start_offset..start_offset + MESSAGES_COUNT {
+        let payload = Bytes::from(format!("message {}", offset));
+        messages.push(Message {
+            id: (offset + 1) as u128,
+            length: payload.len() as u32,
+            payload,
+            headers: None,
+        });
+    }
+    let mut send_messages = SendMessages {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
+        partitioning: Partitioning::partition_id(PARTITION_ID),
+        producer: None,
+        transaction_id: None,
+        messages,
+    };
+    client.send_messages(&mut send_messages).await.unwrap();
+}
+
+async fn init_system(client: &IggyClient) {
+    // 1. Create the stream
+    let create_stream = CreateStream {
+        stream_id: STREAM_ID,
+        name: STREAM_NAME.to_string(),
+        encryption: None,
+    };
+    client.create_stream(&create_stream).await.unwrap();
+
+    // 2. Create the topic
+    let create_topic = CreateTopic {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: TOPIC_ID,
+        partitions_count: 1,
+        name: TOPIC_NAME.to_string(),
+        message_expiry: None,
+        cleanup_policy: CleanupPolicy::Delete,
+        compaction_key: None,
+        max_topic_size_bytes: None,
+        max_messages: None,
+        dead_letter_topic_id: None,
+    };
+    client.create_topic(&create_topic).await.unwrap();
+}
@@ -16,9 +16,12 @@ use iggy::consumer_offsets::get_consumer_offset::GetConsumerOffset;
 use iggy::consumer_offsets::store_consumer_offset::StoreConsumerOffset;
 use iggy::error::Error;
 use iggy::identifier::Identifier;
-use iggy::messages::poll_messages::{PollMessages, PollingStrategy};
+use iggy::messages::poll_messages::{IsolationLevel, PollMessages, PollingStrategy};
 use iggy::messages::send_messages::{Message, Partitioning, SendMessages};
+use iggy::models::assignment_strategy::AssignmentStrategy;
+use iggy::models::cleanup_policy::CleanupPolicy;
 use iggy::models::permissions::{GlobalPermissions, Permissions};
+use iggy::models::quotas::Quotas;
 use iggy::models::user_status::UserStatus;
 use iggy::partitions::create_partitions::CreatePartitions;
 use iggy::partitions::delete_partitions::DeletePartitions;
@@ -29,6 +32,7 @@ use iggy::streams::get_streams::GetStreams;
 use iggy::streams::update_stream::UpdateStream;
 use iggy::system::get_clients::GetClients;
 use iggy::system::get_me::GetMe;
+use iggy::system::get_sessions::GetSessions;
 use iggy::system::get_stats::GetStats;
 use iggy::system::ping::Ping;
 use iggy::topics::create_topic::CreateTopic;
@@ -43,7 +47,9 @@ use iggy::users::get_user::GetUser;
 use iggy::users::get_users::GetUsers;
 use iggy::users::login_user::LoginUser;
 use iggy::users::logout_user::LogoutUser;
+use iggy::users::unlock_user::UnlockUser;
 use iggy::users::update_permissions::UpdatePermissions;
+use iggy::users::update_quotas::UpdateQuotas;
 use iggy::users::update_user::UpdateUser;
 
 const STREAM_ID: u32 = 1;
@@ -134,6 +140,39 @@ pub async fn run(client_factory: &dyn ClientFactory) {
 
     client.logout_user(&LogoutUser {}).await.unwrap();
 
+    let invalid_login = client
+        .login_user(&LoginUser {
+            username: test_user.to_string(),
+            password: "invalid".to_string(),
+        })
+        .await;
+
+    assert!(invalid_login.is_err());
+
+    let user = client
+        .get_user(&GetUser {
+            user_id: Identifier::named(test_user).unwrap(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(user.failed_login_attempts, 1);
+    assert!(user.locked_at.is_none());
+
+    client
+        .unlock_user(&UnlockUser {
+            user_id: Identifier::named(test_user).unwrap(),
+        })
+        .await
+        .unwrap();
+
+    let user = client
+        .get_user(&GetUser {
+            user_id: Identifier::named(test_user).unwrap(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(user.failed_login_attempts, 0);
+
     let create_duplicated_user = client
         .create_user(&CreateUser {
             username: test_user.to_string(),
@@ -178,6 +217,28 @@ pub async fn run(client_factory: &dyn ClientFactory) {
         .await
         .unwrap();
 
+    let quotas = Quotas {
+        produce_bytes_per_second: 10_000_000,
+        produce_messages_per_second: 10_000,
+        consume_bytes_per_second: 0,
+        consume_messages_per_second: 0,
+    };
+    client
+        .update_quotas(&UpdateQuotas {
+            user_id: Identifier::named(updated_test_user).unwrap(),
+            quotas: Some(quotas),
+        })
+        .await
+        .unwrap();
+
+    let user = client
+        .get_user(&GetUser {
+            user_id: Identifier::named(updated_test_user).unwrap(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(user.quotas, Some(quotas));
+
     let updated_test_password = "topsecret";
 
     client
@@ -226,6 +287,7 @@ pub async fn run(client_factory: &dyn ClientFactory) {
     let mut create_stream = CreateStream {
         stream_id: STREAM_ID,
         name: STREAM_NAME.to_string(),
+        encryption: None,
     };
     client.create_stream(&create_stream).await.unwrap();
 
@@ -281,6 +343,11 @@ pub async fn run(client_factory: &dyn ClientFactory) {
         partitions_count: PARTITIONS_COUNT,
         name: TOPIC_NAME.to_string(),
         message_expiry: None,
+        cleanup_policy: CleanupPolicy::Delete,
+        compaction_key: None,
+        max_topic_size_bytes: None,
+        max_messages: None,
+        dead_letter_topic_id: None,
     };
     client.create_topic(&create_topic).await.unwrap();
 
@@ -299,6 +366,10 @@ pub async fn run(client_factory: &dyn ClientFactory) {
     assert_eq!(topic.size_bytes, 0);
     assert_eq!(topic.messages_count, 0);
     assert_eq!(topic.message_expiry, None);
+    assert_eq!(topic.cleanup_policy, CleanupPolicy::Delete);
+    assert_eq!(topic.compaction_key, None);
+    assert_eq!(topic.max_topic_size_bytes, None);
+    assert_eq!(topic.max_messages, None);
 
     // 11. Get topic details by ID
     let topic = client
@@ -382,6 +453,8 @@ pub async fn run(client_factory: &dyn ClientFactory) {
         stream_id: Identifier::numeric(STREAM_ID).unwrap(),
         topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
         partitioning: Partitioning::partition_id(PARTITION_ID),
+        producer: None,
+        transaction_id: None,
         messages,
     };
     client.send_messages(&mut send_messages).await.unwrap();
@@ -398,6 +471,10 @@ pub async fn run(client_factory: &dyn ClientFactory) {
         strategy: PollingStrategy::offset(0),
         count: MESSAGES_COUNT,
         auto_commit: false,
+        include_all_states: false,
+        max_wait_ms: None,
+        isolation_level: IsolationLevel::default(),
+        filters: vec![],
     };
 
     let polled_messages = client.poll_messages(&poll_messages).await.unwrap();
@@ -424,6 +501,10 @@ pub async fn run(client_factory: &dyn ClientFactory) {
             strategy: PollingStrategy::offset(start_offset),
             count: batch_size,
             auto_commit: false,
+            include_all_states: false,
+            max_wait_ms: None,
+            isolation_level: IsolationLevel::default(),
+            filters: vec![],
         };
 
         let polled_messages = client.poll_messages(&poll_messages).await.unwrap();
@@ -468,6 +549,10 @@ pub async fn run(client_factory: &dyn ClientFactory) {
         strategy: PollingStrategy::offset(0),
         count: MESSAGES_COUNT,
         auto_commit: false,
+        include_all_states: false,
+        max_wait_ms: None,
+        isolation_level: IsolationLevel::default(),
+        filters: vec![],
     };
     let polled_messages = client.poll_messages(&poll_messages).await.unwrap();
     assert!(polled_messages.messages.is_empty());
@@ -501,6 +586,8 @@ pub async fn run(client_factory: &dyn ClientFactory) {
             topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
             partition_id: Some(PARTITION_ID),
             offset: stored_offset,
+            transaction_id: None,
+            generation_id: None,
         })
         .await
         .unwrap();
@@ -535,6 +622,10 @@ pub async fn run(client_factory: &dyn ClientFactory) {
         strategy: PollingStrategy::next(),
         count: messages_count,
         auto_commit: true,
+        include_all_states: false,
+        max_wait_ms: None,
+        isolation_level: IsolationLevel::default(),
+        filters: vec![],
     };
 
     let polled_messages = client.poll_messages(&poll_messages).await.unwrap();
@@ -579,6 +670,8 @@ pub async fn run(client_factory: &dyn ClientFactory) {
             stream_id: Identifier::numeric(STREAM_ID).unwrap(),
             topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
             consumer_group_id: CONSUMER_GROUP_ID,
+            assignment_strategy: AssignmentStrategy::Range,
+            session_timeout: 0,
         })
         .await
         .unwrap();
@@ -597,6 +690,10 @@ pub async fn run(client_factory: &dyn ClientFactory) {
     assert_eq!(consumer_group.id, CONSUMER_GROUP_ID);
     assert_eq!(consumer_group.partitions_count, PARTITIONS_COUNT);
     assert_eq!(consumer_group.members_count, 0);
+    assert_eq!(
+        consumer_group.assignment_strategy,
+        AssignmentStrategy::Range
+    );
 
     // 30. Get the consumer group details
     let consumer_group = client
@@ -611,6 +708,10 @@ pub async fn run(client_factory: &dyn ClientFactory) {
     assert_eq!(consumer_group.id, CONSUMER_GROUP_ID);
     assert_eq!(consumer_group.partitions_count, PARTITIONS_COUNT);
     assert_eq!(consumer_group.members_count, 0);
+    assert_eq!(
+        consumer_group.assignment_strategy,
+        AssignmentStrategy::Range
+    );
     assert!(consumer_group.members.is_empty());
 
     // 31. Join the consumer group and then leave it if the feature is available
@@ -740,6 +841,9 @@ pub async fn run(client_factory: &dyn ClientFactory) {
     // 36. Update the existing topic and ensure it's updated
     let updated_topic_name = format!("{}-updated", TOPIC_NAME);
     let updated_message_expiry = 1000;
+    let updated_compaction_key = "key".to_string();
+    let updated_max_topic_size_bytes = 1_000_000;
+    let updated_max_messages = 1000;
 
     client
         .update_topic(&UpdateTopic {
@@ -747,6 +851,11 @@ pub async fn run(client_factory: &dyn ClientFactory) {
             topic_id: Identifier::numeric(TOPIC_ID).unwrap(),
             name: updated_topic_name.clone(),
             message_expiry: Some(updated_message_expiry),
+            cleanup_policy: CleanupPolicy::Compact,
+            compaction_key: Some(updated_compaction_key.clone()),
+            max_topic_size_bytes: Some(updated_max_topic_size_bytes),
+            max_messages: Some(updated_max_messages),
+            dead_letter_topic_id: None,
         })
         .await
         .unwrap();
@@ -761,6 +870,13 @@ pub async fn run(client_factory: &dyn ClientFactory) {
 
     assert_eq!(updated_topic.name, updated_topic_name);
     assert_eq!(updated_topic.message_expiry, Some(updated_message_expiry));
+    assert_eq!(updated_topic.cleanup_policy, CleanupPolicy::Compact);
+    assert_eq!(updated_topic.compaction_key, Some(updated_compaction_key));
+    assert_eq!(
+        updated_topic.max_topic_size_bytes,
+        Some(updated_max_topic_size_bytes)
+    );
+    assert_eq!(updated_topic.max_messages, Some(updated_max_messages));
 
     // 37. Delete the existing topic and ensure it doesn't exist anymore
     client
@@ -813,6 +929,15 @@ pub async fn run(client_factory: &dyn ClientFactory) {
 
     assert!(clients.len() <= 1);
 
+    // 41. Get the sessions of the root user and ensure that there's 0 (HTTP) or 1 (TCP, QUIC) session
+    let sessions = client
+        .get_sessions(&GetSessions { user_id: Some(1) })
+        .await
+        .unwrap();
+
+    assert!(sessions.len() <= 1);
+    assert!(sessions.iter().all(|session| session.user_id == Some(1)));
+
     test_server.stop();
 }
 
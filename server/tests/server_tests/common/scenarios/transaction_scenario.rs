@@ -0,0 +1,225 @@
+use crate::server_tests::common::{ClientFactory, TestServer};
+use bytes::Bytes;
+use iggy::client::{
+    ConsumerOffsetClient, MessageClient, StreamClient, TopicClient, TransactionClient,
+};
+use iggy::clients::client::{IggyClient, IggyClientConfig};
+use iggy::consumer::Consumer;
+use iggy::consumer_offsets::get_consumer_offset::GetConsumerOffset;
+use iggy::consumer_offsets::store_consumer_offset::StoreConsumerOffset;
+use iggy::identifier::Identifier;
+use iggy::messages::poll_messages::{IsolationLevel, PollMessages, PollingStrategy};
+use iggy::messages::send_messages::{Message, Partitioning, SendMessages};
+use iggy::models::cleanup_policy::CleanupPolicy;
+use iggy::streams::create_stream::CreateStream;
+use iggy::topics::create_topic::CreateTopic;
+use iggy::transactions::abort_transaction::AbortTransaction;
+use iggy::transactions::begin_transaction::BeginTransaction;
+use iggy::transactions::commit_transaction::CommitTransaction;
+
+const STREAM_ID: u32 = 1;
+const SOURCE_TOPIC_ID: u32 = 1;
+const TARGET_TOPIC_ID: u32 = 2;
+const STREAM_NAME: &str = "test-stream";
+const SOURCE_TOPIC_NAME: &str = "source-topic";
+const TARGET_TOPIC_NAME: &str = "target-topic";
+const PARTITION_ID: u32 = 1;
+const CONSUMER_ID: u32 = 1;
+
+pub async fn run(client_factory: &dyn ClientFactory) {
+    let mut test_server = TestServer::default();
+    test_server.start();
+    let client = client_factory.create_client().await;
+    let client = IggyClient::create(client, IggyClientConfig::default(), None, None, None);
+    init_system(&client).await;
+
+    // 1. Begin the transaction and send the messages to both topics
+    let transaction_id = client
+        .begin_transaction(&BeginTransaction {})
+        .await
+        .unwrap()
+        .transaction_id;
+    send_messages(&client, SOURCE_TOPIC_ID, Some(transaction_id), 3).await;
+    send_messages(&client, TARGET_TOPIC_ID, Some(transaction_id), 2).await;
+
+    // 2. Store the consumer offset within the transaction
+    client
+        .store_consumer_offset(&StoreConsumerOffset {
+            consumer: Consumer::new(CONSUMER_ID),
+            stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+            topic_id: Identifier::numeric(SOURCE_TOPIC_ID).unwrap(),
+            partition_id: Some(PARTITION_ID),
+            offset: 2,
+            transaction_id: Some(transaction_id),
+            generation_id: None,
+        })
+        .await
+        .unwrap();
+
+    // 3. Send the message outside of the transaction
+    send_messages(&client, SOURCE_TOPIC_ID, None, 1).await;
+
+    // 4. The uncommitted messages are visible only for the read uncommitted consumers
+    assert_eq!(
+        poll_messages_count(&client, SOURCE_TOPIC_ID, IsolationLevel::ReadUncommitted).await,
+        4
+    );
+    assert_eq!(
+        poll_messages_count(&client, SOURCE_TOPIC_ID, IsolationLevel::ReadCommitted).await,
+        0
+    );
+    assert_eq!(
+        poll_messages_count(&client, TARGET_TOPIC_ID, IsolationLevel::ReadCommitted).await,
+        0
+    );
+    assert_eq!(get_consumer_offset(&client).await, 0);
+
+    // 5. Commit the transaction and validate that the messages and the offset are visible
+    client
+        .commit_transaction(&CommitTransaction { transaction_id })
+        .await
+        .unwrap();
+    assert_eq!(
+        poll_messages_count(&client, SOURCE_TOPIC_ID, IsolationLevel::ReadCommitted).await,
+        4
+    );
+    assert_eq!(
+        poll_messages_count(&client, TARGET_TOPIC_ID, IsolationLevel::ReadCommitted).await,
+        2
+    );
+    assert_eq!(get_consumer_offset(&client).await, 2);
+
+    // 6. The completed transaction cannot be used anymore
+    assert!(client
+        .commit_transaction(&CommitTransaction { transaction_id })
+        .await
+        .is_err());
+
+    // 7. Begin another transaction, send the messages and abort it
+    let transaction_id = client
+        .begin_transaction(&BeginTransaction {})
+        .await
+        .unwrap()
+        .transaction_id;
+    send_messages(&client, SOURCE_TOPIC_ID, Some(transaction_id), 2).await;
+    client
+        .abort_transaction(&AbortTransaction { transaction_id })
+        .await
+        .unwrap();
+
+    // 8. The aborted messages are hidden for the read committed consumers, the markers for all of them
+    assert_eq!(
+        poll_messages_count(&client, SOURCE_TOPIC_ID, IsolationLevel::ReadCommitted).await,
+        4
+    );
+    assert_eq!(
+        poll_messages_count(&client, SOURCE_TOPIC_ID, IsolationLevel::ReadUncommitted).await,
+        6
+    );
+
+    // 9. The messages appended after the aborted transaction are visible again
+    send_messages(&client, SOURCE_TOPIC_ID, None, 1).await;
+    assert_eq!(
+        poll_messages_count(&client, SOURCE_TOPIC_ID, IsolationLevel::ReadCommitted).await,
+        5
+    );
+
+    test_server.stop();
+}
+
+async fn init_system(client: &IggyClient) {
+    // 1. Create the stream
+    let create_stream = CreateStream {
+        stream_id: STREAM_ID,
+        name: STREAM_NAME.to_string(),
+        encryption: None,
+    };
+    client.create_stream(&create_stream).await.unwrap();
+
+    // 2. Create the source and the target topics
+    for (topic_id, name) in [
+        (SOURCE_TOPIC_ID, SOURCE_TOPIC_NAME),
+        (TARGET_TOPIC_ID, TARGET_TOPIC_NAME),
+    ] {
+        let create_topic = CreateTopic {
+            stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+            topic_id,
+            partitions_count: 1,
+            name: name.to_string(),
+            message_expiry: None,
+            cleanup_policy: CleanupPolicy::Delete,
+            compaction_key: None,
+            max_topic_size_bytes: None,
+            max_messages: None,
+            dead_letter_topic_id: None,
+        };
+        client.create_topic(&create_topic).await.unwrap();
+    }
+}
+
+async fn send_messages(
+    client: &IggyClient,
+    topic_id: u32,
+    transaction_id: Option<u64>,
+    messages_count: u32,
+) {
+    let mut messages = Vec::new();
+    for index in 0..messages_count {
+        let payload = Bytes::from(format!("message {}", index));
+        messages.push(Message {
+            id: 0,
+            length: payload.len() as u32,
+            payload,
+            headers: None,
+        });
+    }
+
+    let mut send_messages = SendMessages {
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: Identifier::numeric(topic_id).unwrap(),
+        partitioning: Partitioning::partition_id(PARTITION_ID),
+        producer: None,
+        transaction_id,
+        messages,
+    };
+    client.send_messages(&mut send_messages).await.unwrap();
+}
+
+async fn poll_messages_count(
+    client: &IggyClient,
+    topic_id: u32,
+    isolation_level: IsolationLevel,
+) -> usize {
+    let poll_messages = PollMessages {
+        consumer: Consumer::default(),
+        stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+        topic_id: Identifier::numeric(topic_id).unwrap(),
+        partition_id: Some(PARTITION_ID),
+        strategy: PollingStrategy::offset(0),
+        count: 100,
+        auto_commit: false,
+        include_all_states: false,
+        max_wait_ms: None,
+        isolation_level,
+        filters: vec![],
+    };
+    client
+        .poll_messages(&poll_messages)
+        .await
+        .unwrap()
+        .messages
+        .len()
+}
+
+async fn get_consumer_offset(client: &IggyClient) -> u64 {
+    client
+        .get_consumer_offset(&GetConsumerOffset {
+            consumer: Consumer::new(CONSUMER_ID),
+            stream_id: Identifier::numeric(STREAM_ID).unwrap(),
+            topic_id: Identifier::numeric(SOURCE_TOPIC_ID).unwrap(),
+            partition_id: Some(PARTITION_ID),
+        })
+        .await
+        .unwrap()
+        .stored_offset
+}
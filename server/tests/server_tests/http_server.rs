@@ -1,5 +1,10 @@
 use crate::server_tests::common::http::HttpClientFactory;
-use crate::server_tests::common::scenarios::{message_headers_scenario, system_scenario};
+use crate::server_tests::common::scenarios::{
+    audit_scenario, consumer_lag_scenario, consumer_offsets_reset_scenario, dead_letter_scenario,
+    delayed_delivery_scenario, header_filter_scenario, idempotent_producer_scenario,
+    long_polling_scenario, message_headers_scenario, personal_access_token_scenario, role_scenario,
+    system_scenario, transaction_scenario,
+};
 use serial_test::serial;
 
 #[tokio::test]
@@ -7,5 +12,16 @@ use serial_test::serial;
 async fn system_scenario_should_be_valid() {
     let client_factory = HttpClientFactory {};
     system_scenario::run(&client_factory).await;
+    audit_scenario::run(&client_factory).await;
     message_headers_scenario::run(&client_factory).await;
+    dead_letter_scenario::run(&client_factory).await;
+    idempotent_producer_scenario::run(&client_factory).await;
+    transaction_scenario::run(&client_factory).await;
+    long_polling_scenario::run(&client_factory).await;
+    delayed_delivery_scenario::run(&client_factory).await;
+    header_filter_scenario::run(&client_factory).await;
+    consumer_lag_scenario::run(&client_factory).await;
+    consumer_offsets_reset_scenario::run(&client_factory).await;
+    personal_access_token_scenario::run(&client_factory).await;
+    role_scenario::run(&client_factory).await;
 }
@@ -2,3 +2,4 @@ mod common;
 mod http_server;
 mod quic_server;
 mod tcp_server;
+mod tcp_tls_server;
@@ -1,7 +1,11 @@
 use crate::server_tests::common::scenarios::{
-    consumer_group_join_scenario, consumer_group_with_multiple_clients_polling_messages_scenario,
-    consumer_group_with_single_client_polling_messages_scenario, message_headers_scenario,
-    system_scenario,
+    audit_scenario, consumer_group_heartbeat_scenario, consumer_group_join_scenario,
+    consumer_group_with_multiple_clients_polling_messages_scenario,
+    consumer_group_with_single_client_polling_messages_scenario, consumer_lag_scenario,
+    consumer_offsets_reset_scenario, dead_letter_scenario, delayed_delivery_scenario,
+    header_filter_scenario, idempotent_producer_scenario, long_polling_scenario,
+    message_headers_scenario, personal_access_token_scenario, role_scenario, subscription_scenario,
+    system_scenario, transaction_scenario,
 };
 use crate::server_tests::common::tcp::TcpClientFactory;
 use serial_test::serial;
@@ -11,8 +15,21 @@ use serial_test::serial;
 async fn system_and_consumer_group_scenarios_should_be_valid() {
     let client_factory = TcpClientFactory {};
     system_scenario::run(&client_factory).await;
+    audit_scenario::run(&client_factory).await;
     message_headers_scenario::run(&client_factory).await;
+    dead_letter_scenario::run(&client_factory).await;
+    idempotent_producer_scenario::run(&client_factory).await;
+    transaction_scenario::run(&client_factory).await;
+    long_polling_scenario::run(&client_factory).await;
+    delayed_delivery_scenario::run(&client_factory).await;
+    header_filter_scenario::run(&client_factory).await;
+    consumer_lag_scenario::run(&client_factory).await;
+    consumer_offsets_reset_scenario::run(&client_factory).await;
+    personal_access_token_scenario::run(&client_factory).await;
+    role_scenario::run(&client_factory).await;
+    subscription_scenario::run(&client_factory).await;
     consumer_group_join_scenario::run(&client_factory).await;
+    consumer_group_heartbeat_scenario::run(&client_factory).await;
     consumer_group_with_single_client_polling_messages_scenario::run(&client_factory).await;
     consumer_group_with_multiple_clients_polling_messages_scenario::run(&client_factory).await;
 }
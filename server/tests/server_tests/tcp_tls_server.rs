@@ -0,0 +1,142 @@
+use crate::server_tests::common::TestServer;
+use iggy::client::{Client, SystemClient};
+use iggy::system::get_me::GetMe;
+use iggy::tcp::client::TcpClient;
+use iggy::tcp::config::TcpClientConfig;
+use openssl::pkcs12::Pkcs12;
+use openssl::pkey::PKey;
+use openssl::x509::X509;
+use rcgen::{BasicConstraints, Certificate, CertificateParams, DnType, IsCa};
+use serial_test::serial;
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use std::sync::Arc;
+use uuid::Uuid;
+
+const CERTIFICATE_PASSWORD: &str = "iggy123";
+const ROOT_USER_ID: u32 = 1;
+
+#[tokio::test]
+#[serial]
+async fn client_certificate_should_authenticate_the_user() {
+    let certs_path =
+        std::env::temp_dir().join(format!("iggy_certs_{}", Uuid::new_v4().to_u128_le()));
+    fs::create_dir(&certs_path).unwrap();
+    let certs_path = certs_path.to_str().unwrap();
+    create_certificates(certs_path);
+    let mut test_server = TestServer::with_envs(HashMap::from([(
+        "IGGY_CONFIG_PATH".to_string(),
+        create_config(certs_path),
+    )]));
+    test_server.start();
+
+    // 1. The client certificate common name is mapped to the root user, the same way as the username
+    let mut client = create_client(certs_path, true);
+    client.connect().await.unwrap();
+    let me = client.get_me(&GetMe {}).await.unwrap();
+    assert_eq!(me.user_id, Some(ROOT_USER_ID));
+
+    // 2. The client without the certificate is rejected during the handshake
+    let mut client = create_client(certs_path, false);
+    assert!(client.connect().await.is_err() || client.get_me(&GetMe {}).await.is_err());
+
+    test_server.stop();
+    fs::remove_dir_all(certs_path).unwrap();
+}
+
+fn create_certificates(certs_path: &str) {
+    let mut ca_params = CertificateParams::new(vec![]);
+    ca_params.is_ca = IsCa::Ca(BasicConstraints::Unconstrained);
+    ca_params
+        .distinguished_name
+        .push(DnType::CommonName, "Iggy Test CA");
+    let ca = Certificate::from_params(ca_params).unwrap();
+    fs::write(
+        Path::new(certs_path).join("ca_cert.pem"),
+        ca.serialize_pem().unwrap(),
+    )
+    .unwrap();
+
+    let server =
+        Certificate::from_params(CertificateParams::new(vec!["localhost".to_string()])).unwrap();
+    let server_certificate =
+        X509::from_pem(server.serialize_pem_with_signer(&ca).unwrap().as_bytes()).unwrap();
+    let server_key =
+        PKey::private_key_from_pem(server.serialize_private_key_pem().as_bytes()).unwrap();
+    let pkcs12 = Pkcs12::builder()
+        .name("localhost")
+        .pkey(&server_key)
+        .cert(&server_certificate)
+        .build2(CERTIFICATE_PASSWORD)
+        .unwrap();
+    fs::write(
+        Path::new(certs_path).join("server.pfx"),
+        pkcs12.to_der().unwrap(),
+    )
+    .unwrap();
+
+    let mut client_params = CertificateParams::new(vec![]);
+    client_params
+        .distinguished_name
+        .push(DnType::CommonName, "IGGY");
+    let client = Certificate::from_params(client_params).unwrap();
+    fs::write(
+        Path::new(certs_path).join("client_cert.pem"),
+        client.serialize_pem_with_signer(&ca).unwrap(),
+    )
+    .unwrap();
+    fs::write(
+        Path::new(certs_path).join("client_key.pem"),
+        client.serialize_private_key_pem(),
+    )
+    .unwrap();
+}
+
+fn create_config(certs_path: &str) -> String {
+    let config = fs::read_to_string("../configs/server.toml").unwrap();
+    let tls_config = r#"[tcp.tls]
+enabled = false
+certificate = "certs/iggy.pfx"
+password = "iggy123"
+
+[tcp.tls.client_auth]
+enabled = false
+ca_file = "certs/iggy_ca_cert.pem""#;
+    assert!(config.contains(tls_config));
+    let config = config.replace(
+        tls_config,
+        &format!(
+            r#"[tcp.tls]
+enabled = true
+certificate = "{certs_path}/server.pfx"
+password = "{CERTIFICATE_PASSWORD}"
+
+[tcp.tls.client_auth]
+enabled = true
+ca_file = "{certs_path}/ca_cert.pem""#
+        ),
+    );
+    let config_path = format!("{certs_path}/server.toml");
+    fs::write(&config_path, config).unwrap();
+    config_path
+}
+
+fn create_client(certs_path: &str, with_certificate: bool) -> TcpClient {
+    let (certificate_file, key_file) = match with_certificate {
+        true => (
+            Some(format!("{certs_path}/client_cert.pem")),
+            Some(format!("{certs_path}/client_key.pem")),
+        ),
+        false => (None, None),
+    };
+    TcpClient::create(Arc::new(TcpClientConfig {
+        tls_enabled: true,
+        tls_certificate_file: certificate_file,
+        tls_key_file: key_file,
+        tls_ca_file: Some(format!("{certs_path}/ca_cert.pem")),
+        reconnection_retries: 0,
+        ..Default::default()
+    }))
+    .unwrap()
+}
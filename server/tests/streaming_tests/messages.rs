@@ -1,10 +1,14 @@
 use crate::streaming_tests::common::test_setup::TestSetup;
 use bytes::Bytes;
+use iggy::messages::poll_messages::IsolationLevel;
+use iggy::messages::send_messages::{ProducerSequence, DELIVER_AT_HEADER};
 use iggy::models::header::{HeaderKey, HeaderValue};
+use iggy::models::header_filter::HeaderFilter;
 use iggy::models::messages::{Message, MessageState};
 use iggy::utils::{checksum, timestamp::TimeStamp};
 use server::configs::system::{PartitionConfig, SystemConfig};
 use server::streaming::partitions::partition::Partition;
+use server::streaming::partitions::transactions::TransactionMarker;
 use std::collections::HashMap;
 use std::str::FromStr;
 use std::sync::Arc;
@@ -112,3 +116,467 @@ async fn should_persist_messages_and_then_load_them_from_disk() {
         assert_eq!(loaded_message.headers, appended_message.headers);
     }
 }
+
+#[tokio::test]
+async fn should_recover_producer_sequences_from_disk() {
+    let setup = TestSetup::init().await;
+    let stream_id = 1;
+    let topic_id = 1;
+    let partition_id = 1;
+    let config = Arc::new(SystemConfig {
+        path: setup.config.path.to_string(),
+        partition: PartitionConfig {
+            messages_required_to_save: 1,
+            ..Default::default()
+        },
+        ..Default::default()
+    });
+    let mut partition = Partition::create(
+        stream_id,
+        topic_id,
+        partition_id,
+        true,
+        config.clone(),
+        setup.storage.clone(),
+        None,
+    );
+    setup.create_partitions_directory(stream_id, topic_id).await;
+    partition.persist().await.unwrap();
+
+    let producer = ProducerSequence {
+        producer_id: 1,
+        sequence: 1,
+    };
+    partition
+        .append_producer_messages(&producer, get_producer_messages(3))
+        .await
+        .unwrap();
+    let current_offset = partition.current_offset;
+    partition
+        .append_producer_messages(&producer, get_producer_messages(3))
+        .await
+        .unwrap();
+    assert_eq!(partition.current_offset, current_offset);
+
+    let mut loaded_partition = Partition::create(
+        stream_id,
+        topic_id,
+        partition.partition_id,
+        false,
+        config.clone(),
+        setup.storage.clone(),
+        None,
+    );
+    loaded_partition.load().await.unwrap();
+    assert_eq!(
+        loaded_partition
+            .producer_sequences
+            .get(&1)
+            .map(|state| state.sequence),
+        Some(3)
+    );
+
+    let overlapping_producer = ProducerSequence {
+        producer_id: 1,
+        sequence: 3,
+    };
+    assert!(loaded_partition
+        .append_producer_messages(&overlapping_producer, get_producer_messages(2))
+        .await
+        .is_err());
+
+    let next_producer = ProducerSequence {
+        producer_id: 1,
+        sequence: 4,
+    };
+    loaded_partition
+        .append_producer_messages(&next_producer, get_producer_messages(1))
+        .await
+        .unwrap();
+    assert_eq!(
+        loaded_partition
+            .producer_sequences
+            .get(&1)
+            .map(|state| state.sequence),
+        Some(4)
+    );
+}
+
+#[tokio::test]
+async fn should_delete_expired_producers() {
+    let setup = TestSetup::init().await;
+    let stream_id = 1;
+    let topic_id = 1;
+    let partition_id = 1;
+    let config = Arc::new(SystemConfig {
+        path: setup.config.path.to_string(),
+        partition: PartitionConfig {
+            producer_expiry: 10,
+            ..Default::default()
+        },
+        ..Default::default()
+    });
+    let mut partition = Partition::create(
+        stream_id,
+        topic_id,
+        partition_id,
+        true,
+        config,
+        setup.storage.clone(),
+        None,
+    );
+    setup.create_partitions_directory(stream_id, topic_id).await;
+    partition.persist().await.unwrap();
+
+    let producer = ProducerSequence {
+        producer_id: 1,
+        sequence: 1,
+    };
+    partition
+        .append_producer_messages(&producer, get_producer_messages(3))
+        .await
+        .unwrap();
+
+    let now = TimeStamp::now().to_micros();
+    assert_eq!(partition.delete_expired_producers(now), 0);
+    assert_eq!(partition.delete_expired_producers(now + 10_000_000), 1);
+    assert!(partition.producer_sequences.is_empty());
+
+    let restarted_producer = ProducerSequence {
+        producer_id: 1,
+        sequence: 1,
+    };
+    partition
+        .append_producer_messages(&restarted_producer, get_producer_messages(1))
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn should_recover_transaction_states_from_disk() {
+    let setup = TestSetup::init().await;
+    let stream_id = 1;
+    let topic_id = 1;
+    let partition_id = 1;
+    let config = Arc::new(SystemConfig {
+        path: setup.config.path.to_string(),
+        partition: PartitionConfig {
+            messages_required_to_save: 1,
+            ..Default::default()
+        },
+        ..Default::default()
+    });
+    let mut partition = Partition::create(
+        stream_id,
+        topic_id,
+        partition_id,
+        true,
+        config.clone(),
+        setup.storage.clone(),
+        None,
+    );
+    setup.create_partitions_directory(stream_id, topic_id).await;
+    partition.persist().await.unwrap();
+
+    let committed_transaction_id = 1;
+    let aborted_transaction_id = 2;
+    let open_transaction_id = 3;
+    for transaction_id in [
+        committed_transaction_id,
+        aborted_transaction_id,
+        open_transaction_id,
+    ] {
+        partition
+            .append_transactional_messages(transaction_id, None, get_producer_messages(2))
+            .await
+            .unwrap();
+    }
+    partition
+        .append_transaction_marker(committed_transaction_id, TransactionMarker::Commit)
+        .await
+        .unwrap();
+    partition
+        .append_transaction_marker(aborted_transaction_id, TransactionMarker::Abort)
+        .await
+        .unwrap();
+    assert_eq!(partition.get_last_stable_offset(), Some(4));
+    // The transaction is completed by its first marker, the later ones are ignored.
+    partition
+        .append_transaction_marker(committed_transaction_id, TransactionMarker::Abort)
+        .await
+        .unwrap();
+
+    let mut loaded_partition = Partition::create(
+        stream_id,
+        topic_id,
+        partition.partition_id,
+        false,
+        config.clone(),
+        setup.storage.clone(),
+        None,
+    );
+    loaded_partition.load().await.unwrap();
+
+    // The transaction left open can't be committed after the restart, so it's treated as aborted.
+    assert!(loaded_partition.open_transactions.is_empty());
+    assert!(!loaded_partition
+        .aborted_transactions
+        .contains(&committed_transaction_id));
+    assert!(loaded_partition
+        .aborted_transactions
+        .contains(&aborted_transaction_id));
+    assert!(loaded_partition
+        .aborted_transactions
+        .contains(&open_transaction_id));
+
+    let messages = loaded_partition
+        .get_messages_by_offset(0, 100)
+        .await
+        .unwrap();
+    let (committed_messages, _) = loaded_partition
+        .get_visible_messages(
+            messages.clone(),
+            100,
+            IsolationLevel::ReadCommitted,
+            false,
+            &[],
+        )
+        .await
+        .unwrap();
+    let (uncommitted_messages, _) = loaded_partition
+        .get_visible_messages(messages, 100, IsolationLevel::ReadUncommitted, false, &[])
+        .await
+        .unwrap();
+    assert_eq!(committed_messages.len(), 2);
+    assert_eq!(uncommitted_messages.len(), 6);
+}
+
+#[tokio::test]
+async fn should_deliver_delayed_messages_after_loading_them_from_disk() {
+    let setup = TestSetup::init().await;
+    let stream_id = 1;
+    let topic_id = 1;
+    let partition_id = 1;
+    let config = Arc::new(SystemConfig {
+        path: setup.config.path.to_string(),
+        partition: PartitionConfig {
+            messages_required_to_save: 1,
+            ..Default::default()
+        },
+        ..Default::default()
+    });
+    let mut partition = Partition::create(
+        stream_id,
+        topic_id,
+        partition_id,
+        true,
+        config.clone(),
+        setup.storage.clone(),
+        None,
+    );
+    setup.create_partitions_directory(stream_id, topic_id).await;
+    partition.persist().await.unwrap();
+
+    let mut messages = get_producer_messages(3);
+    let deliver_at = TimeStamp::now().to_micros() + 3_600_000_000;
+    messages[1].headers = Some(HashMap::from([(
+        HeaderKey::new(DELIVER_AT_HEADER).unwrap(),
+        HeaderValue::from_uint64(deliver_at).unwrap(),
+    )]));
+    partition.append_messages(messages).await.unwrap();
+
+    let mut loaded_partition = Partition::create(
+        stream_id,
+        topic_id,
+        partition.partition_id,
+        false,
+        config.clone(),
+        setup.storage.clone(),
+        None,
+    );
+    loaded_partition.load().await.unwrap();
+
+    let messages = loaded_partition
+        .get_messages_by_offset(0, 100)
+        .await
+        .unwrap();
+    assert_eq!(messages.len(), 3);
+    let (visible_messages, scanned_offset) = loaded_partition
+        .get_visible_messages(messages, 100, IsolationLevel::ReadUncommitted, false, &[])
+        .await
+        .unwrap();
+    assert_eq!(visible_messages.len(), 2);
+    assert_eq!(visible_messages[0].offset, 0);
+    assert_eq!(visible_messages[1].offset, 2);
+    assert_eq!(scanned_offset, Some(2));
+
+    assert_eq!(
+        loaded_partition
+            .deliver_delayed_messages(deliver_at - 1)
+            .await
+            .unwrap(),
+        0
+    );
+    assert_eq!(
+        loaded_partition
+            .deliver_delayed_messages(deliver_at)
+            .await
+            .unwrap(),
+        1
+    );
+    let messages = loaded_partition
+        .get_messages_by_offset(3, 100)
+        .await
+        .unwrap();
+    let (visible_messages, _) = loaded_partition
+        .get_visible_messages(messages, 100, IsolationLevel::ReadUncommitted, false, &[])
+        .await
+        .unwrap();
+    assert_eq!(visible_messages.len(), 1);
+    assert_eq!(visible_messages[0].offset, 3);
+    assert_eq!(visible_messages[0].timestamp, deliver_at);
+
+    let mut reloaded_partition = Partition::create(
+        stream_id,
+        topic_id,
+        partition.partition_id,
+        false,
+        config.clone(),
+        setup.storage.clone(),
+        None,
+    );
+    reloaded_partition.load().await.unwrap();
+    assert!(!reloaded_partition.has_due_delayed_messages(u64::MAX));
+}
+
+#[tokio::test]
+async fn should_scan_the_partition_until_enough_messages_match_the_header_filters() {
+    let setup = TestSetup::init().await;
+    let stream_id = 1;
+    let topic_id = 1;
+    let partition_id = 1;
+    let config = Arc::new(SystemConfig {
+        path: setup.config.path.to_string(),
+        ..Default::default()
+    });
+    let mut partition = Partition::create(
+        stream_id,
+        topic_id,
+        partition_id,
+        true,
+        config,
+        setup.storage.clone(),
+        None,
+    );
+    setup.create_partitions_directory(stream_id, topic_id).await;
+    partition.persist().await.unwrap();
+
+    let mut messages = get_producer_messages(10);
+    for (priority, message) in messages.iter_mut().enumerate() {
+        message.headers = Some(HashMap::from([(
+            HeaderKey::new("priority").unwrap(),
+            HeaderValue::from_uint32(priority as u32).unwrap(),
+        )]));
+    }
+    partition.append_messages(messages).await.unwrap();
+
+    let filters =
+        vec![
+            HeaderFilter::range("priority", Some(HeaderValue::from_uint32(5).unwrap()), None)
+                .unwrap(),
+        ];
+    let messages = partition.get_messages_by_offset(0, 2).await.unwrap();
+    let (matching_messages, scanned_offset) = partition
+        .get_visible_messages(
+            messages,
+            2,
+            IsolationLevel::ReadUncommitted,
+            false,
+            &filters,
+        )
+        .await
+        .unwrap();
+    assert_eq!(matching_messages.len(), 2);
+    assert_eq!(matching_messages[0].offset, 5);
+    assert_eq!(matching_messages[1].offset, 6);
+    assert_eq!(scanned_offset, Some(6));
+
+    let filters = vec![HeaderFilter::exists("missing").unwrap()];
+    let messages = partition.get_messages_by_offset(0, 2).await.unwrap();
+    let (matching_messages, scanned_offset) = partition
+        .get_visible_messages(
+            messages,
+            2,
+            IsolationLevel::ReadUncommitted,
+            false,
+            &filters,
+        )
+        .await
+        .unwrap();
+    assert!(matching_messages.is_empty());
+    assert_eq!(scanned_offset, Some(9));
+}
+
+#[tokio::test]
+async fn should_skip_unavailable_messages_before_the_scanned_offset_is_returned() {
+    let setup = TestSetup::init().await;
+    let stream_id = 1;
+    let topic_id = 1;
+    let partition_id = 1;
+    let config = Arc::new(SystemConfig {
+        path: setup.config.path.to_string(),
+        ..Default::default()
+    });
+    let mut partition = Partition::create(
+        stream_id,
+        topic_id,
+        partition_id,
+        true,
+        config,
+        setup.storage.clone(),
+        None,
+    );
+    setup.create_partitions_directory(stream_id, topic_id).await;
+    partition.persist().await.unwrap();
+
+    let mut messages = get_producer_messages(4);
+    messages[0].state = MessageState::Poisoned;
+    messages[1].state = MessageState::Poisoned;
+    partition.append_messages(messages).await.unwrap();
+
+    let messages = partition.get_messages_by_offset(0, 2).await.unwrap();
+    let (available_messages, scanned_offset) = partition
+        .get_visible_messages(messages, 2, IsolationLevel::ReadUncommitted, false, &[])
+        .await
+        .unwrap();
+    assert_eq!(available_messages.len(), 2);
+    assert_eq!(available_messages[0].offset, 2);
+    assert_eq!(available_messages[1].offset, 3);
+    assert_eq!(scanned_offset, Some(3));
+
+    let messages = partition.get_messages_by_offset(0, 2).await.unwrap();
+    let (all_messages, scanned_offset) = partition
+        .get_visible_messages(messages, 2, IsolationLevel::ReadUncommitted, true, &[])
+        .await
+        .unwrap();
+    assert_eq!(all_messages.len(), 2);
+    assert_eq!(all_messages[0].offset, 0);
+    assert_eq!(scanned_offset, Some(1));
+}
+
+fn get_producer_messages(count: u32) -> Vec<Message> {
+    (0..count)
+        .map(|_| {
+            let payload = Bytes::from("message");
+            let checksum = checksum::calculate(&payload);
+            Message::empty(
+                TimeStamp::now().to_micros(),
+                MessageState::Available,
+                0,
+                payload,
+                checksum,
+                None,
+            )
+        })
+        .collect()
+}
@@ -1,7 +1,15 @@
 use crate::streaming_tests::common::test_setup::TestSetup;
+use bytes::Bytes;
+use iggy::models::header::{HeaderKey, HeaderValue};
+use iggy::models::messages::{Message, MessageState};
+use iggy::utils::checksum;
 use ringbuffer::RingBuffer;
+use server::configs::system::{CacheConfig, PartitionConfig, SegmentConfig, SystemConfig};
 use server::streaming::partitions::partition::Partition;
 use server::streaming::segments::segment::{INDEX_EXTENSION, LOG_EXTENSION, TIME_INDEX_EXTENSION};
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::sync::Arc;
 use tokio::fs;
 
 #[tokio::test]
@@ -116,6 +124,129 @@ async fn should_delete_existing_partition_from_disk() {
     }
 }
 
+#[tokio::test]
+async fn given_compaction_gap_wider_than_count_messages_after_the_gap_should_be_returned() {
+    let setup = TestSetup::init().await;
+    let stream_id = 1;
+    let topic_id = 2;
+    let partition_id = 3;
+    setup.create_partitions_directory(stream_id, topic_id).await;
+    // Every message is stored in its own segment, and the cache is disabled to read them from disk.
+    let config = Arc::new(SystemConfig {
+        path: setup.config.path.clone(),
+        cache: CacheConfig { messages_amount: 0 },
+        partition: PartitionConfig {
+            messages_required_to_save: 1,
+            ..Default::default()
+        },
+        segment: SegmentConfig {
+            size_bytes: 1,
+            ..Default::default()
+        },
+        ..Default::default()
+    });
+    let mut partition = Partition::create(
+        stream_id,
+        topic_id,
+        partition_id,
+        true,
+        config,
+        setup.storage.clone(),
+        None,
+    );
+    partition.persist().await.unwrap();
+    let compaction_key = HeaderKey::new("key").unwrap();
+    let messages_count = 10;
+    for i in 0..messages_count {
+        let payload = Bytes::from(format!("test-{i}"));
+        let checksum = checksum::calculate(payload.as_ref());
+        let headers = HashMap::from([(
+            compaction_key.clone(),
+            HeaderValue::from_str("key").unwrap(),
+        )]);
+        let message = Message::create(
+            0,
+            MessageState::Available,
+            0,
+            0,
+            payload,
+            checksum,
+            Some(headers),
+        );
+        partition.append_messages(vec![message]).await.unwrap();
+    }
+
+    let compacted_messages = partition.compact_segments(&compaction_key).await.unwrap();
+    assert!(compacted_messages > 0);
+
+    let messages = partition.get_messages_by_offset(0, 1).await.unwrap();
+    assert_eq!(messages.len(), 1);
+    assert!(messages[0].offset >= compacted_messages);
+}
+
+#[tokio::test]
+async fn given_malformed_server_headers_partition_should_be_loaded() {
+    let setup = TestSetup::init().await;
+    let stream_id = 1;
+    let topic_id = 2;
+    let partition_id = 3;
+    setup.create_partitions_directory(stream_id, topic_id).await;
+    let mut partition = Partition::create(
+        stream_id,
+        topic_id,
+        partition_id,
+        true,
+        setup.config.clone(),
+        setup.storage.clone(),
+        None,
+    );
+    partition.persist().await.unwrap();
+    let headers = [
+        "iggy-producer-id",
+        "iggy-producer-sequence",
+        "iggy-transaction-id",
+        "iggy-transaction-marker",
+        "iggy-delivered-from",
+    ]
+    .into_iter()
+    .map(|key| {
+        (
+            HeaderKey::new(key).unwrap(),
+            HeaderValue::from_str("malformed").unwrap(),
+        )
+    })
+    .collect::<HashMap<_, _>>();
+    let payload = Bytes::from("test");
+    let checksum = checksum::calculate(payload.as_ref());
+    let message = Message::create(
+        0,
+        MessageState::Available,
+        0,
+        0,
+        payload,
+        checksum,
+        Some(headers),
+    );
+    partition.append_messages(vec![message]).await.unwrap();
+    partition.persist().await.unwrap();
+
+    let mut loaded_partition = Partition::create(
+        stream_id,
+        topic_id,
+        partition_id,
+        false,
+        setup.config.clone(),
+        setup.storage.clone(),
+        None,
+    );
+    loaded_partition.load().await.unwrap();
+
+    assert_eq!(loaded_partition.current_offset, partition.current_offset);
+    assert!(loaded_partition.producer_sequences.is_empty());
+    assert!(loaded_partition.open_transactions.is_empty());
+    assert!(loaded_partition.aborted_transactions.is_empty());
+}
+
 async fn assert_persisted_partition(partition_path: &str, with_segment: bool) {
     let offsets_path = format!("{}/offsets", partition_path);
     let consumer_offsets_path = format!("{}/consumers", offsets_path);
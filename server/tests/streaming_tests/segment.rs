@@ -1,11 +1,17 @@
 use crate::streaming_tests::common::test_setup::TestSetup;
 use bytes::Bytes;
+use iggy::models::header::{HeaderKey, HeaderValue};
 use iggy::models::messages::{Message, MessageState};
+use iggy::utils::crypto::{Aes256GcmEncryptor, Encryptor};
 use iggy::utils::{checksum, timestamp::TimeStamp};
+use server::streaming::encryption::key_ring::KeyRing;
 use server::streaming::segments::segment;
 use server::streaming::segments::segment::{INDEX_EXTENSION, LOG_EXTENSION, TIME_INDEX_EXTENSION};
+use std::collections::{HashMap, HashSet};
+use std::str::FromStr;
 use std::sync::Arc;
 use tokio::fs;
+use tokio::io::AsyncWriteExt;
 
 #[tokio::test]
 async fn should_persist_segment() {
@@ -253,6 +259,282 @@ async fn given_at_least_one_not_expired_message_segment_should_not_be_expired()
     assert!(!is_expired);
 }
 
+#[tokio::test]
+async fn should_compact_segment_keeping_only_the_latest_message_per_key_and_stable_offsets() {
+    let setup = TestSetup::init().await;
+    let stream_id = 1;
+    let topic_id = 2;
+    let partition_id = 3;
+    let start_offset = 0;
+    let mut segment = segment::Segment::create(
+        stream_id,
+        topic_id,
+        partition_id,
+        start_offset,
+        setup.config.clone(),
+        setup.storage.clone(),
+        None,
+    );
+
+    setup
+        .create_partition_directory(stream_id, topic_id, partition_id)
+        .await;
+    segment.persist().await.unwrap();
+    let compaction_key = HeaderKey::new("key").unwrap();
+    let messages_count = 10;
+    let keys_count = 3;
+    for i in 0..messages_count {
+        let mut message = create_message(i, &format!("test-{i}"), TimeStamp::now().to_micros());
+        message.headers = Some(HashMap::from([(
+            compaction_key.clone(),
+            HeaderValue::from_str(&format!("key-{}", i % keys_count)).unwrap(),
+        )]));
+        segment.append_message(Arc::new(message)).await.unwrap();
+    }
+
+    segment
+        .persist_messages(setup.storage.segment.clone())
+        .await
+        .unwrap();
+
+    let keys = segment.load_message_keys(&compaction_key).await.unwrap();
+    assert_eq!(keys.len(), messages_count as usize);
+    let mut latest_offsets = HashMap::new();
+    for (offset, key) in &keys {
+        latest_offsets.insert(*key, *offset);
+    }
+    let removed_offsets = keys
+        .iter()
+        .filter(|(offset, key)| latest_offsets.get(key) != Some(offset))
+        .map(|(offset, _)| *offset)
+        .collect::<HashSet<u64>>();
+    segment.compact(&removed_offsets).await.unwrap();
+
+    let mut loaded_segment = segment::Segment::create(
+        stream_id,
+        topic_id,
+        partition_id,
+        start_offset,
+        setup.config.clone(),
+        setup.storage.clone(),
+        None,
+    );
+    loaded_segment.load().await.unwrap();
+    assert_eq!(loaded_segment.current_offset, messages_count - 1);
+    assert_eq!(
+        loaded_segment.current_size_bytes,
+        segment.current_size_bytes
+    );
+
+    let messages = loaded_segment
+        .get_messages(0, messages_count as u32)
+        .await
+        .unwrap();
+    let offsets = messages
+        .iter()
+        .map(|message| message.offset)
+        .collect::<Vec<u64>>();
+    assert_eq!(offsets, vec![7, 8, 9]);
+    for message in messages {
+        assert_eq!(
+            message.payload,
+            Bytes::from(format!("test-{}", message.offset))
+        );
+    }
+
+    let messages = loaded_segment.get_messages(2, 1).await.unwrap();
+    assert!(messages.is_empty());
+    let messages = loaded_segment.get_messages(5, 3).await.unwrap();
+    assert_eq!(messages.len(), 1);
+    assert_eq!(messages[0].offset, 7);
+}
+
+#[tokio::test]
+async fn should_persist_message_state_and_load_it_from_disk() {
+    let setup = TestSetup::init().await;
+    let stream_id = 1;
+    let topic_id = 2;
+    let partition_id = 3;
+    let start_offset = 0;
+    let mut segment = segment::Segment::create(
+        stream_id,
+        topic_id,
+        partition_id,
+        start_offset,
+        setup.config.clone(),
+        setup.storage.clone(),
+        None,
+    );
+
+    setup
+        .create_partition_directory(stream_id, topic_id, partition_id)
+        .await;
+    segment.persist().await.unwrap();
+    let messages_count = 10;
+    for i in 0..messages_count {
+        let message = create_message(i, "test", TimeStamp::now().to_micros());
+        segment.append_message(Arc::new(message)).await.unwrap();
+    }
+
+    segment
+        .persist_messages(setup.storage.segment.clone())
+        .await
+        .unwrap();
+
+    let poisoned_offset = 5;
+    let mut message = create_message(poisoned_offset, "test", TimeStamp::now().to_micros());
+    message.state = MessageState::Poisoned;
+    segment
+        .update_message_state(Arc::new(message))
+        .await
+        .unwrap();
+
+    let mut loaded_segment = segment::Segment::create(
+        stream_id,
+        topic_id,
+        partition_id,
+        start_offset,
+        setup.config.clone(),
+        setup.storage.clone(),
+        None,
+    );
+    loaded_segment.load().await.unwrap();
+    let messages = loaded_segment
+        .get_messages(0, messages_count as u32)
+        .await
+        .unwrap();
+    assert_eq!(messages.len(), messages_count as usize);
+    for message in messages {
+        if message.offset == poisoned_offset {
+            assert_eq!(message.state, MessageState::Poisoned);
+        } else {
+            assert_eq!(message.state, MessageState::Available);
+        }
+    }
+}
+
+#[tokio::test]
+async fn should_reencrypt_closed_segment_messages_with_active_key() {
+    let setup = TestSetup::init().await;
+    let stream_id = 1;
+    let topic_id = 2;
+    let partition_id = 3;
+    let start_offset = 0;
+    let mut segment = segment::Segment::create(
+        stream_id,
+        topic_id,
+        partition_id,
+        start_offset,
+        setup.config.clone(),
+        setup.storage.clone(),
+        None,
+    );
+
+    setup
+        .create_partition_directory(stream_id, topic_id, partition_id)
+        .await;
+    segment.persist().await.unwrap();
+    let old_key_ring = create_key_ring(1, &[1]);
+    let messages_count = 10;
+    for i in 0..messages_count {
+        let payload = old_key_ring
+            .encrypt(format!("test-{i}").as_bytes())
+            .unwrap();
+        let mut message = create_message(i, "", TimeStamp::now().to_micros());
+        message.checksum = checksum::calculate(&payload);
+        message.length = payload.len() as u32;
+        message.payload = Bytes::from(payload);
+        segment.append_message(Arc::new(message)).await.unwrap();
+    }
+
+    segment
+        .persist_messages(setup.storage.segment.clone())
+        .await
+        .unwrap();
+    segment.is_closed = true;
+
+    let key_ring = create_key_ring(2, &[1, 2]);
+    let reencrypted_messages = segment.reencrypt(&key_ring, 2).await.unwrap();
+    assert_eq!(reencrypted_messages, messages_count);
+    let reencrypted_messages = segment.reencrypt(&key_ring, 2).await.unwrap();
+    assert_eq!(reencrypted_messages, 0);
+
+    let mut loaded_segment = segment::Segment::create(
+        stream_id,
+        topic_id,
+        partition_id,
+        start_offset,
+        setup.config.clone(),
+        setup.storage.clone(),
+        None,
+    );
+    loaded_segment.load().await.unwrap();
+    assert_eq!(
+        loaded_segment.current_size_bytes,
+        segment.current_size_bytes
+    );
+
+    let messages = loaded_segment
+        .get_messages(0, messages_count as u32)
+        .await
+        .unwrap();
+    assert_eq!(messages.len(), messages_count as usize);
+    for message in messages {
+        assert_eq!(key_ring.get_key_id(&message.payload).unwrap(), 2);
+        assert_eq!(message.checksum, checksum::calculate(&message.payload));
+        let payload = key_ring.decrypt(&message.payload).unwrap();
+        assert_eq!(payload, format!("test-{}", message.offset).as_bytes());
+    }
+}
+
+#[tokio::test]
+async fn given_unparsable_messages_segment_reencryption_should_stop_and_keep_the_segment() {
+    let setup = TestSetup::init().await;
+    let stream_id = 1;
+    let topic_id = 2;
+    let partition_id = 3;
+    let start_offset = 0;
+    let mut segment = segment::Segment::create(
+        stream_id,
+        topic_id,
+        partition_id,
+        start_offset,
+        setup.config.clone(),
+        setup.storage.clone(),
+        None,
+    );
+
+    setup
+        .create_partition_directory(stream_id, topic_id, partition_id)
+        .await;
+    segment.persist().await.unwrap();
+    let old_key_ring = create_key_ring(1, &[1]);
+    let payload = old_key_ring.encrypt(b"test").unwrap();
+    let mut message = create_message(0, "", TimeStamp::now().to_micros());
+    message.checksum = checksum::calculate(&payload);
+    message.length = payload.len() as u32;
+    message.payload = Bytes::from(payload);
+    segment.append_message(Arc::new(message)).await.unwrap();
+    segment
+        .persist_messages(setup.storage.segment.clone())
+        .await
+        .unwrap();
+    segment.is_closed = true;
+
+    let mut log_file = fs::OpenOptions::new()
+        .append(true)
+        .open(&segment.log_path)
+        .await
+        .unwrap();
+    log_file.write_all(&[1, 2, 3]).await.unwrap();
+    log_file.sync_all().await.unwrap();
+    let log = fs::read(&segment.log_path).await.unwrap();
+
+    let key_ring = create_key_ring(2, &[1, 2]);
+    assert!(segment.reencrypt(&key_ring, 2).await.is_err());
+    assert_eq!(fs::read(&segment.log_path).await.unwrap(), log);
+}
+
 async fn assert_persisted_segment(partition_path: &str, start_offset: u64) {
     let segment_path = format!("{}/{:0>20}", partition_path, start_offset);
     let log_path = format!("{}.{}", segment_path, LOG_EXTENSION);
@@ -277,6 +559,15 @@ fn create_message(offset: u64, payload: &str, timestamp: u64) -> Message {
     )
 }
 
+fn create_key_ring(active_key_id: u32, key_ids: &[u32]) -> KeyRing {
+    let mut encryptors: HashMap<u32, Box<dyn Encryptor>> = HashMap::new();
+    for key_id in key_ids {
+        let key = [*key_id as u8; 32];
+        encryptors.insert(*key_id, Box::new(Aes256GcmEncryptor::new(&key).unwrap()));
+    }
+    KeyRing::new(active_key_id, encryptors).unwrap()
+}
+
 fn get_start_offsets() -> Vec<u64> {
     vec![
         0, 1, 2, 9, 10, 99, 100, 110, 200, 1000, 1234, 12345, 100000, 9999999,
@@ -1,6 +1,21 @@
 use crate::streaming_tests::common::test_setup::TestSetup;
+use bytes::Bytes;
+use iggy::error::Error;
 use iggy::identifier::Identifier;
+use iggy::messages::poll_messages::{IsolationLevel, PollingStrategy};
+use iggy::messages::send_messages::{Message, Partitioning};
+use iggy::models;
+use iggy::models::header::{HeaderKey, HeaderValue};
+use iggy::models::stream_encryption::StreamEncryption;
+use iggy::utils::{scram, text};
+use server::configs::system::{EncryptionConfig, EncryptionKeyConfig, SystemConfig};
+use server::streaming::polling_args::PollingArgs;
+use server::streaming::polling_consumer::PollingConsumer;
 use server::streaming::systems::system::System;
+use server::streaming::topics::topic::TopicSettings;
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::sync::Arc;
 use tokio::fs;
 
 #[tokio::test]
@@ -33,7 +48,7 @@ async fn should_create_and_persist_stream() {
     system.init().await.unwrap();
 
     system
-        .create_stream(user_id, stream_id, stream_name)
+        .create_stream(user_id, stream_id, stream_name, None)
         .await
         .unwrap();
 
@@ -49,7 +64,7 @@ async fn should_delete_persisted_stream() {
     let stream_name = "test";
     system.init().await.unwrap();
     system
-        .create_stream(user_id, stream_id, stream_name)
+        .create_stream(user_id, stream_id, stream_name, None)
         .await
         .unwrap();
     assert_persisted_stream(&setup.config.get_streams_path(), stream_id).await;
@@ -67,6 +82,298 @@ async fn should_delete_persisted_stream() {
     assert!(fs::metadata(stream_path).await.is_err());
 }
 
+#[tokio::test]
+async fn given_stream_with_own_key_and_header_encryption_messages_should_be_encrypted_and_decrypted(
+) {
+    let setup = TestSetup::init().await;
+    let config = Arc::new(SystemConfig {
+        path: setup.config.path.clone(),
+        encryption: EncryptionConfig {
+            enabled: true,
+            key: "".to_string(),
+            active_key_id: 1,
+            encrypt_headers: false,
+            keys: vec![
+                EncryptionKeyConfig {
+                    id: 1,
+                    key: "AQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQE=".to_string(),
+                },
+                EncryptionKeyConfig {
+                    id: 2,
+                    key: "AgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgI=".to_string(),
+                },
+            ],
+        },
+        ..Default::default()
+    });
+    let mut system = System::new(config, Some(setup.db.clone()));
+    let user_id = 1;
+    let stream_id = Identifier::numeric(1).unwrap();
+    let topic_id = Identifier::numeric(1).unwrap();
+    system.init().await.unwrap();
+
+    let encryption = StreamEncryption {
+        enabled: true,
+        key_id: Some(3),
+        encrypt_headers: true,
+    };
+    assert!(matches!(
+        system
+            .create_stream(user_id, 1, "test", Some(encryption))
+            .await,
+        Err(Error::EncryptionKeyNotFound(3))
+    ));
+
+    let encryption = StreamEncryption {
+        key_id: Some(2),
+        ..encryption
+    };
+    system
+        .create_stream(user_id, 1, "test", Some(encryption))
+        .await
+        .unwrap();
+    system
+        .get_stream_mut(&stream_id)
+        .unwrap()
+        .create_topic(1, "test", 1, None, TopicSettings::default())
+        .await
+        .unwrap();
+
+    let header_key = HeaderKey::new("tenant").unwrap();
+    let header_value = HeaderValue::from_str("tenant-1").unwrap();
+    let payload = Bytes::from("test");
+    let message = Message::new(
+        Some(1),
+        payload.clone(),
+        Some(HashMap::from([(header_key.clone(), header_value.clone())])),
+    );
+    system
+        .append_messages(
+            &stream_id,
+            &topic_id,
+            &Partitioning::partition_id(1),
+            None,
+            None,
+            &vec![message],
+        )
+        .await
+        .unwrap();
+
+    let consumer = PollingConsumer::Consumer(1, 1);
+    let stored_messages = system
+        .get_stream(&stream_id)
+        .unwrap()
+        .get_topic(&topic_id)
+        .unwrap()
+        .get_messages(
+            consumer,
+            1,
+            &PollingArgs::new(
+                PollingStrategy::offset(0),
+                1,
+                false,
+                true,
+                None,
+                IsolationLevel::ReadUncommitted,
+                vec![],
+            ),
+        )
+        .await
+        .unwrap();
+    let stored_message = &stored_messages.messages[0];
+    assert_ne!(stored_message.payload, payload);
+    assert_eq!(
+        u32::from_le_bytes(stored_message.payload[..4].try_into().unwrap()),
+        2
+    );
+    assert!(!stored_message
+        .headers
+        .as_ref()
+        .unwrap()
+        .contains_key(&header_key));
+
+    let args = PollingArgs::new(
+        PollingStrategy::offset(0),
+        1,
+        false,
+        false,
+        None,
+        IsolationLevel::ReadUncommitted,
+        vec![],
+    );
+    let polled_messages = system
+        .poll_messages(consumer, &stream_id, &topic_id, &args)
+        .await
+        .unwrap();
+    let polled_message = &polled_messages.messages[0];
+    assert_eq!(polled_message.payload, payload);
+    assert_eq!(
+        polled_message.headers,
+        Some(HashMap::from([(header_key, header_value)]))
+    );
+
+    // The offset is not stored automatically, when the polled message cannot be decrypted.
+    let topic = system
+        .get_stream(&stream_id)
+        .unwrap()
+        .get_topic(&topic_id)
+        .unwrap();
+    topic
+        .append_messages(
+            &Partitioning::partition_id(1),
+            None,
+            None,
+            vec![models::messages::Message::from_message(&Message::new(
+                Some(2),
+                Bytes::from("not encrypted"),
+                None,
+            ))],
+        )
+        .await
+        .unwrap();
+    let args = PollingArgs::new(
+        PollingStrategy::offset(0),
+        2,
+        true,
+        false,
+        None,
+        IsolationLevel::ReadUncommitted,
+        vec![],
+    );
+    assert!(matches!(
+        system
+            .poll_messages(consumer, &stream_id, &topic_id, &args)
+            .await,
+        Err(Error::CannotDecryptData)
+    ));
+    assert_eq!(
+        topic
+            .get_consumer_offset(consumer)
+            .await
+            .unwrap()
+            .stored_offset,
+        0
+    );
+}
+
+#[tokio::test]
+async fn given_valid_client_proof_scram_login_should_succeed_and_return_server_signature() {
+    let setup = TestSetup::init().await;
+    let mut system = System::new(setup.config.clone(), Some(setup.db.clone()));
+    system.init().await.unwrap();
+    let username = "iggy";
+    let password = "iggy";
+    let client_nonce = scram::generate_nonce();
+
+    let (handshake, challenge) = system
+        .start_scram_login(username, &client_nonce)
+        .await
+        .unwrap();
+    assert!(challenge.nonce.starts_with(&client_nonce));
+
+    let salted_password = scram::salt_password(password, &challenge.salt, challenge.iterations);
+    let auth_message = scram::auth_message(
+        username,
+        &client_nonce,
+        &challenge.nonce,
+        &challenge.salt,
+        challenge.iterations,
+    );
+    let invalid_salted_password =
+        scram::salt_password("invalid", &challenge.salt, challenge.iterations);
+    let invalid_client_proof = text::to_base64(&scram::client_proof(
+        &invalid_salted_password,
+        &auth_message,
+    ));
+    let result = system
+        .finish_scram_login(&handshake, &challenge.nonce, &invalid_client_proof, None)
+        .await;
+    assert!(matches!(result, Err(Error::InvalidCredentials)));
+
+    let client_proof = text::to_base64(&scram::client_proof(&salted_password, &auth_message));
+    let (user, server_signature) = system
+        .finish_scram_login(&handshake, &challenge.nonce, &client_proof, None)
+        .await
+        .unwrap();
+    assert_eq!(user.username, username);
+    assert!(scram::verify_server_signature(
+        &salted_password,
+        &auth_message,
+        &server_signature
+    ));
+}
+
+#[tokio::test]
+async fn given_unknown_user_scram_login_should_return_stable_challenge_and_fail_only_when_finished()
+{
+    let setup = TestSetup::init().await;
+    let mut system = System::new(setup.config.clone(), Some(setup.db.clone()));
+    system.init().await.unwrap();
+    let username = "unknown";
+    let client_nonce = scram::generate_nonce();
+
+    let (handshake, challenge) = system
+        .start_scram_login(username, &client_nonce)
+        .await
+        .unwrap();
+    let (_, next_challenge) = system
+        .start_scram_login(username, &client_nonce)
+        .await
+        .unwrap();
+    assert_eq!(challenge.salt, next_challenge.salt);
+    assert_eq!(challenge.iterations, scram::SCRAM_ITERATIONS);
+
+    let mut restarted_system = System::new(setup.config.clone(), Some(setup.db.clone()));
+    restarted_system.init().await.unwrap();
+    let (_, restarted_challenge) = restarted_system
+        .start_scram_login(username, &client_nonce)
+        .await
+        .unwrap();
+    assert_eq!(challenge.salt, restarted_challenge.salt);
+
+    let salted_password = scram::salt_password("secret", &challenge.salt, challenge.iterations);
+    let auth_message = scram::auth_message(
+        username,
+        &client_nonce,
+        &challenge.nonce,
+        &challenge.salt,
+        challenge.iterations,
+    );
+    let client_proof = text::to_base64(&scram::client_proof(&salted_password, &auth_message));
+    let result = system
+        .finish_scram_login(&handshake, &challenge.nonce, &client_proof, None)
+        .await;
+    assert!(matches!(result, Err(Error::InvalidCredentials)));
+}
+
+#[tokio::test]
+async fn given_changed_password_previous_token_version_should_be_revoked() {
+    let setup = TestSetup::init().await;
+    let mut system = System::new(setup.config.clone(), Some(setup.db.clone()));
+    system.init().await.unwrap();
+    let user_id = Identifier::numeric(1).unwrap();
+    let user = system.get_user(&user_id).await.unwrap();
+    system
+        .ensure_token_version(user.id, user.token_version)
+        .await
+        .unwrap();
+
+    system
+        .change_password(&user_id, "iggy", "secret")
+        .await
+        .unwrap();
+
+    let result = system
+        .ensure_token_version(user.id, user.token_version)
+        .await;
+    assert!(matches!(result, Err(Error::InvalidAccessToken)));
+    let user = system.get_user(&user_id).await.unwrap();
+    system
+        .ensure_token_version(user.id, user.token_version)
+        .await
+        .unwrap();
+}
+
 async fn assert_persisted_stream(streams_path: &str, stream_id: u32) {
     let streams_metadata = fs::metadata(streams_path).await.unwrap();
     assert!(streams_metadata.is_dir());
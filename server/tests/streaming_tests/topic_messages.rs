@@ -1,8 +1,9 @@
 use crate::streaming_tests::common::test_setup::TestSetup;
-use iggy::messages::poll_messages::PollingStrategy;
+use iggy::messages::poll_messages::{IsolationLevel, PollingStrategy};
 use iggy::messages::send_messages;
 use iggy::messages::send_messages::Partitioning;
 use iggy::models::messages::Message;
+use server::streaming::polling_args::PollingArgs;
 use server::streaming::polling_consumer::PollingConsumer;
 use server::streaming::topics::topic::Topic;
 use server::streaming::utils::hash;
@@ -19,7 +20,7 @@ async fn given_key_none_messages_should_be_appended_to_the_next_partition_using_
     for i in 1..=partitions_count * messages_per_partition_count {
         let payload = get_payload(i);
         topic
-            .append_messages(&partitioning, vec![get_message(&payload)])
+            .append_messages(&partitioning, None, None, vec![get_message(&payload)])
             .await
             .unwrap();
     }
@@ -39,7 +40,7 @@ async fn given_key_partition_id_messages_should_be_appended_to_the_chosen_partit
     for i in 1..=partitions_count * messages_per_partition_count {
         let payload = get_payload(i);
         topic
-            .append_messages(&partitioning, vec![get_message(&payload)])
+            .append_messages(&partitioning, None, None, vec![get_message(&payload)])
             .await
             .unwrap();
     }
@@ -63,7 +64,7 @@ async fn given_key_messages_key_messages_should_be_appended_to_the_calculated_pa
         let payload = get_payload(entity_id);
         let partitioning = Partitioning::messages_key_u32(entity_id);
         topic
-            .append_messages(&partitioning, vec![get_message(&payload)])
+            .append_messages(&partitioning, None, None, vec![get_message(&payload)])
             .await
             .unwrap();
     }
@@ -95,7 +96,19 @@ fn get_payload(id: u32) -> String {
 async fn assert_messages(topic: &Topic, partition_id: u32, expected_messages: u32) {
     let consumer = PollingConsumer::Consumer(0, partition_id);
     let polled_messages = topic
-        .get_messages(consumer, partition_id, PollingStrategy::offset(0), 1000)
+        .get_messages(
+            consumer,
+            partition_id,
+            &PollingArgs::new(
+                PollingStrategy::offset(0),
+                1000,
+                false,
+                true,
+                None,
+                IsolationLevel::default(),
+                vec![],
+            ),
+        )
         .await
         .unwrap();
     assert_eq!(polled_messages.messages.len() as u32, expected_messages);
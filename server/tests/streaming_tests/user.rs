@@ -2,8 +2,10 @@ use crate::streaming_tests::common::test_setup::TestSetup;
 use iggy::models::permissions::{
     GlobalPermissions, Permissions, StreamPermissions, TopicPermissions,
 };
+use iggy::models::quotas::Quotas;
 use iggy::models::user_status::UserStatus;
 use iggy::utils::timestamp::TimeStamp;
+use server::streaming::users::scram::ScramCredentials;
 use server::streaming::users::user::User;
 use std::collections::HashMap;
 
@@ -68,6 +70,18 @@ fn assert_user(user: &User, loaded_user: &User) {
     assert_eq!(loaded_user.password, user.password);
     assert_eq!(loaded_user.created_at, user.created_at);
     assert_eq!(loaded_user.status, user.status);
+    assert_eq!(loaded_user.quotas, user.quotas);
+    assert_eq!(loaded_user.scram_credentials, user.scram_credentials);
+    assert_eq!(
+        loaded_user.failed_login_attempts,
+        user.failed_login_attempts
+    );
+    assert_eq!(
+        loaded_user.first_failed_login_at,
+        user.first_failed_login_at
+    );
+    assert_eq!(loaded_user.locked_at, user.locked_at);
+    assert_eq!(loaded_user.token_version, user.token_version);
     if user.permissions.is_none() {
         assert!(loaded_user.permissions.is_none());
         return;
@@ -202,5 +216,16 @@ fn create_user(id: u32) -> User {
                 map
             }),
         }),
+        quotas: Some(Quotas {
+            produce_bytes_per_second: 1_000_000,
+            produce_messages_per_second: 1000,
+            consume_bytes_per_second: 0,
+            consume_messages_per_second: 0,
+        }),
+        scram_credentials: Some(ScramCredentials::new("secret")),
+        failed_login_attempts: 2,
+        first_failed_login_at: Some(1_000_000),
+        locked_at: None,
+        token_version: 1,
     }
 }
@@ -3,6 +3,7 @@ use iggy::clients::client::IggyClient;
 use iggy::error::Error;
 use iggy::identifier::Identifier;
 use iggy::messages::send_messages::{Message, Partitioning, SendMessages};
+use iggy::models::cleanup_policy::CleanupPolicy;
 use iggy::models::header::{HeaderKey, HeaderValue};
 use iggy::streams::create_stream::CreateStream;
 use iggy::topics::create_topic::CreateTopic;
@@ -27,18 +28,21 @@ async fn create_streams(client: &IggyClient) -> Result<(), Error> {
         .create_stream(&CreateStream {
             stream_id: PROD_STREAM_ID,
             name: "prod".to_string(),
+            encryption: None,
         })
         .await?;
     client
         .create_stream(&CreateStream {
             stream_id: TEST_STREAM_ID,
             name: "test".to_string(),
+            encryption: None,
         })
         .await?;
     client
         .create_stream(&CreateStream {
             stream_id: DEV_STREAM_ID,
             name: "dev".to_string(),
+            encryption: None,
         })
         .await?;
     Ok(())
@@ -54,6 +58,11 @@ async fn create_topics(client: &IggyClient) -> Result<(), Error> {
                 name: "orders".to_string(),
                 partitions_count: 1,
                 message_expiry: None,
+                cleanup_policy: CleanupPolicy::Delete,
+                compaction_key: None,
+                max_topic_size_bytes: None,
+                max_messages: None,
+                dead_letter_topic_id: None,
             })
             .await?;
 
@@ -64,6 +73,11 @@ async fn create_topics(client: &IggyClient) -> Result<(), Error> {
                 name: "users".to_string(),
                 partitions_count: 2,
                 message_expiry: None,
+                cleanup_policy: CleanupPolicy::Delete,
+                compaction_key: None,
+                max_topic_size_bytes: None,
+                max_messages: None,
+                dead_letter_topic_id: None,
             })
             .await?;
 
@@ -74,6 +88,11 @@ async fn create_topics(client: &IggyClient) -> Result<(), Error> {
                 name: "notifications".to_string(),
                 partitions_count: 3,
                 message_expiry: None,
+                cleanup_policy: CleanupPolicy::Delete,
+                compaction_key: None,
+                max_topic_size_bytes: None,
+                max_messages: None,
+                dead_letter_topic_id: None,
             })
             .await?;
 
@@ -84,6 +103,11 @@ async fn create_topics(client: &IggyClient) -> Result<(), Error> {
                 name: "payments".to_string(),
                 partitions_count: 2,
                 message_expiry: None,
+                cleanup_policy: CleanupPolicy::Delete,
+                compaction_key: None,
+                max_topic_size_bytes: None,
+                max_messages: None,
+                dead_letter_topic_id: None,
             })
             .await?;
 
@@ -94,6 +118,11 @@ async fn create_topics(client: &IggyClient) -> Result<(), Error> {
                 name: "deliveries".to_string(),
                 partitions_count: 1,
                 message_expiry: None,
+                cleanup_policy: CleanupPolicy::Delete,
+                compaction_key: None,
+                max_topic_size_bytes: None,
+                max_messages: None,
+                dead_letter_topic_id: None,
             })
             .await?;
     }
@@ -142,6 +171,8 @@ async fn send_messages(client: &IggyClient) -> Result<(), Error> {
                         stream_id: Identifier::numeric(stream_id)?,
                         topic_id: Identifier::numeric(topic.id)?,
                         partitioning: Partitioning::balanced(),
+                        producer: None,
+                        transaction_id: None,
                         messages,
                     })
                     .await?;